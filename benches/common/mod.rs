@@ -0,0 +1,110 @@
+//! Helpers shared by `pubnub` benchmarks.
+//!
+//! Benchmarks run against [`BenchTransport`], which responds with prepared
+//! responses without network access and without capturing sent requests, so
+//! measurements aren't affected by the network or growing mock state.
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use pubnub::{
+    core::{PubNubError, Transport, TransportRequest, TransportResponse},
+    providers::deserialization_serde::DeserializerSerde,
+    Keyset, PubNubClientBuilder, PubNubGenericClient,
+};
+
+/// Client type used by benchmarks.
+pub type BenchClient = PubNubGenericClient<BenchTransport, DeserializerSerde>;
+
+/// Transport which responds with prepared responses.
+///
+/// Response is picked by the request path prefix.
+#[derive(Clone, Default)]
+pub struct BenchTransport {
+    responses: Arc<Vec<(String, TransportResponse)>>,
+}
+
+impl BenchTransport {
+    /// Create transport without prepared responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Respond with `response` for requests which `path` starts with.
+    pub fn with_path_response<S>(mut self, path: S, response: TransportResponse) -> Self
+    where
+        S: Into<String>,
+    {
+        Arc::make_mut(&mut self.responses).push((path.into(), response));
+        self
+    }
+}
+
+impl Transport for BenchTransport {
+    // Error details are `&'static str` with `static_errors` feature.
+    #[cfg_attr(feature = "static_errors", allow(clippy::useless_conversion))]
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        self.responses
+            .iter()
+            .find(|(path, _)| request.path.starts_with(path))
+            .map(|(_, response)| response.clone())
+            .ok_or_else(|| PubNubError::Transport {
+                details: "No prepared response for request".into(),
+                response: None,
+            })
+    }
+}
+
+/// Create client which uses `transport`.
+pub fn client(transport: BenchTransport) -> BenchClient {
+    PubNubClientBuilder::with_transport(transport)
+        .with_keyset(Keyset {
+            subscribe_key: "demo",
+            publish_key: Some("demo"),
+            secret_key: None,
+        })
+        .with_user_id("bench-user")
+        .build()
+        .unwrap()
+}
+
+/// Successful publish response.
+pub fn publish_response() -> TransportResponse {
+    TransportResponse {
+        status: 200,
+        body: Some(br#"[1,"Sent","17000000000000000"]"#.to_vec().into()),
+        ..Default::default()
+    }
+}
+
+/// Subscribe response with `count` messages for `channel`.
+pub fn subscribe_response(channel: &str, count: usize) -> TransportResponse {
+    TransportResponse {
+        status: 200,
+        body: Some(subscribe_body(channel, count).into()),
+        ..Default::default()
+    }
+}
+
+/// Subscribe response body with `count` messages for `channel`.
+pub fn subscribe_body(channel: &str, count: usize) -> Vec<u8> {
+    let messages = (0..count)
+        .map(|idx| {
+            format!(
+                r#"{{"a":"1","f":0,"i":"bench-user","p":{{"t":"{}","r":4}},"k":"demo","c":"{channel}","d":{{"text":"message {idx}","index":{idx}}},"b":"{channel}"}}"#,
+                17000000000000000u64 + idx as u64
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(r#"{{"t":{{"t":"17000000000000000","r":4}},"m":[{messages}]}}"#).into_bytes()
+}
+
+/// JSON payload which is roughly `size` bytes long once serialized.
+pub fn payload(size: usize) -> serde_json::Value {
+    serde_json::json!({
+        "sender": "bench-user",
+        "text": "a".repeat(size.saturating_sub(40)),
+    })
+}
@@ -0,0 +1,30 @@
+//! Crypto module benchmarks.
+//!
+//! Measure data encryption and decryption with AES-CBC crypto module.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use pubnub::{core::CryptoProvider, providers::crypto::CryptoModule};
+
+fn crypto(c: &mut Criterion) {
+    let module = CryptoModule::new_aes_cbc_module("enigma", true).unwrap();
+    let mut group = c.benchmark_group("crypto");
+
+    for size in [64, 1024, 16 * 1024] {
+        let data = vec![b'a'; size];
+        let encrypted = module.encrypt(data.clone()).unwrap();
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("encrypt", size), &data, |b, data| {
+            b.iter(|| module.encrypt(data.clone()).unwrap())
+        });
+
+        group.bench_with_input(BenchmarkId::new("decrypt", size), &encrypted, |b, data| {
+            b.iter(|| module.decrypt(data.clone()).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, crypto);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Event fan-out benchmarks.
+//!
+//! Measure delivery of received real-time message to multiple subscription
+//! listeners (single iteration waits for next message in each listener's
+//! stream). Subscribe loop runs against prepared transport response, which
+//! contains batch of messages for each subscribe call.
+
+use std::time::Instant;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use futures::StreamExt;
+use pubnub::subscribe::{EventEmitter, EventSubscriber, Subscriber};
+
+mod common;
+use common::{client, subscribe_response, BenchTransport};
+
+/// Number of messages in single subscribe response.
+const MESSAGES: usize = 100;
+
+fn event_fanout(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let _runtime_guard = runtime.enter();
+    let mut group = c.benchmark_group("event_fanout");
+
+    for listeners in [1, 4, 16] {
+        group.throughput(Throughput::Elements(listeners as u64));
+
+        let client = client(BenchTransport::new().with_path_response(
+            "/v2/subscribe",
+            subscribe_response("bench-channel", MESSAGES),
+        ));
+        let subscription = client.channel("bench-channel").subscription(None);
+        let mut streams = (0..listeners)
+            .map(|_| subscription.messages_stream())
+            .collect::<Vec<_>>();
+        subscription.subscribe();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(listeners),
+            &listeners,
+            |b, _| {
+                b.iter_custom(|iters| {
+                    runtime.block_on(async {
+                        let start = Instant::now();
+                        for _ in 0..iters {
+                            for stream in streams.iter_mut() {
+                                stream.next().await;
+                            }
+                        }
+                        start.elapsed()
+                    })
+                });
+            },
+        );
+
+        client.unsubscribe_all();
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, event_fanout);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+//! Publish request building benchmarks.
+//!
+//! Measure publish call (message serialization, request path and query
+//! building and response parsing) against prepared transport response.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+mod common;
+use common::{client, payload, publish_response, BenchTransport};
+
+fn publish(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let client = client(BenchTransport::new().with_path_response("/publish", publish_response()));
+    let mut group = c.benchmark_group("publish");
+
+    for size in [64, 1024, 16 * 1024] {
+        let message = payload(size);
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("get", size), &message, |b, message| {
+            b.to_async(&runtime).iter(|| async {
+                client
+                    .publish_message(message.clone())
+                    .channel("bench-channel")
+                    .execute()
+                    .await
+                    .unwrap()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("post", size), &message, |b, message| {
+            b.to_async(&runtime).iter(|| async {
+                client
+                    .publish_message(message.clone())
+                    .channel("bench-channel")
+                    .use_post(true)
+                    .execute()
+                    .await
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, publish);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+//! Subscribe envelope parsing benchmarks.
+//!
+//! Measure subscribe response body deserialization and conversion of
+//! envelopes into real-time updates.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use pubnub::{
+    core::Deserializer,
+    providers::deserialization_serde::DeserializerSerde,
+    subscribe::{result::SubscribeResult, SubscribeResponseBody},
+};
+
+mod common;
+use common::subscribe_body;
+
+fn envelope_parsing(c: &mut Criterion) {
+    let deserializer = DeserializerSerde;
+    let mut group = c.benchmark_group("subscribe_envelope_parsing");
+
+    for count in [1, 10, 100] {
+        let body = subscribe_body("bench-channel", count);
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_with_input(BenchmarkId::from_parameter(count), &body, |b, body| {
+            b.iter(|| {
+                let response: SubscribeResponseBody = deserializer.deserialize(body).unwrap();
+                SubscribeResult::try_from(response).unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, envelope_parsing);
+criterion_main!(benches);
@@ -19,7 +19,7 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
 
     let channels_now = client
         .here_now()
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .include_state(true)
         .include_user_id(true)
         .execute()
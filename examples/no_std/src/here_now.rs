@@ -110,7 +110,7 @@ fn publish_example() -> Result<(), PubNubError> {
 
     let channels_now = client
         .here_now()
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .include_state(true)
         .include_user_id(true)
         .execute_blocking()?;
@@ -122,13 +122,13 @@ fn publish_example() -> Result<(), PubNubError> {
         .set_presence_state(State {
             is_doing: "Nothing... Just hanging around...".into(),
         })
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute_blocking()?;
 
     let states = client
         .get_presence_state()
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute_blocking()?;
 
@@ -48,6 +48,8 @@ fn custom_random(buf: &mut [u8]) -> Result<(), getrandom::Error> {
 // Many targets have very specific requirements for networking, so it's hard to
 // provide a generic implementation.
 // Depending on the target, you will probably need to implement `Transport` trait.
+// If your TCP stack implements `embedded-nal` traits, you can use
+// `pubnub::transport::TransportEmbeddedNal` (`embedded_nal` feature) instead.
 struct MyTransport;
 
 impl Transport for MyTransport {
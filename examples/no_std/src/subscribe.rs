@@ -126,7 +126,7 @@ fn publish_example() -> Result<(), PubNubError> {
     // Subscribe to the channels
     client
         .subscribe_raw()
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .heartbeat(10)
         .filter_expression("some_filter")
         .execute_blocking()?
@@ -1,5 +1,7 @@
-use pubnub::{access::*, core::PubNubError, parse_token, Keyset, PubNubClientBuilder, Token};
-use std::{collections::HashMap, env};
+use pubnub::{
+    access::*, core::PubNubError, metadata, parse_token, Keyset, PubNubClientBuilder, Token,
+};
+use std::env;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -27,7 +29,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .join()
             .read()
             .write()])
-        .meta(HashMap::from([("owner-role".into(), "admin".into())]))
+        .meta(metadata! { "owner-role" => "admin" })
         .execute()
         .await?;
 
@@ -39,7 +41,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Grant an authorized client different levels of access to various resources in a single call
     let grant_result_various_resources = client
         .grant_token(10)
-        .authorized_user_id("my-authorized-user_id")
         .resources(&[
             permissions::channel("channel-a").read(),
             permissions::channel_group("channel-group-b").read(),
@@ -49,6 +50,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             permissions::channel("channel-d").read().write(),
             permissions::user_id("uuid-d").get().update(),
         ])
+        .authorized_user_id("my-authorized-user_id")
         .execute()
         .await?;
 
@@ -60,8 +62,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Grant an authorized client multiple channels using RegEx
     let grant_result_multiple_channels_regex = client
         .grant_token(10)
-        .authorized_user_id("my-authorized-user_id")
         .patterns(&[permissions::channel("^channel-[A-Za-z0-9]$").read()])
+        .authorized_user_id("my-authorized-user_id")
         .execute()
         .await?;
 
@@ -74,7 +76,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let grant_result_different_levels_resources_patterns_regex = client
         .grant_token(10)
-        .authorized_user_id("my-authorized-user_id")
         .resources(&[
             permissions::channel("channel-a").read(),
             permissions::channel_group("channel-group-b").read(),
@@ -85,6 +86,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             permissions::user_id("uuid-d").get().update(),
         ])
         .patterns(&[permissions::channel("^channel-[A-Za-z0-9]$").read()])
+        .authorized_user_id("my-authorized-user_id")
         .execute()
         .await?;
 
@@ -35,7 +35,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Grant an authorized client different levels of access to various resources in a single call
     let grant_result_various_resources = client
         .grant_token(10)
-        .authorized_user_id("my-authorized-user_id")
         .resources(&[
             permissions::channel("channel-a").read(),
             permissions::channel_group("channel-group-b").read(),
@@ -45,6 +44,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             permissions::channel("channel-d").read().write(),
             permissions::user_id("uuid-d").get().update(),
         ])
+        .authorized_user_id("my-authorized-user_id")
         .execute_blocking()?;
 
     println!(
@@ -55,8 +55,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Grant an authorized client multiple channels using RegEx
     let grant_result_multiple_channels_regex = client
         .grant_token(10)
-        .authorized_user_id("my-authorized-user_id")
         .patterns(&[permissions::channel("^channel-[A-Za-z0-9]$").read()])
+        .authorized_user_id("my-authorized-user_id")
         .execute_blocking()?;
 
     println!(
@@ -68,7 +68,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let grant_result_different_levels_resources_patterns_regex = client
         .grant_token(10)
-        .authorized_user_id("my-authorized-user_id")
         .resources(&[
             permissions::channel("channel-a").read(),
             permissions::channel_group("channel-group-b").read(),
@@ -79,6 +78,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             permissions::user_id("uuid-d").get().update(),
         ])
         .patterns(&[permissions::channel("^channel-[A-Za-z0-9]$").read()])
+        .authorized_user_id("my-authorized-user_id")
         .execute_blocking()?;
 
     println!(
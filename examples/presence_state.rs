@@ -48,7 +48,7 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
                 },
             ),
         ]))
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute()
         .await?;
@@ -58,7 +58,7 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
             is_doing: "Nothing... Just hanging around...".into(),
             flag: false,
         })
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute()
         .await?;
@@ -68,7 +68,7 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
 
     let states = client
         .get_presence_state()
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute()
         .await?;
@@ -26,7 +26,7 @@ fn main() -> Result<(), Box<dyn snafu::Error>> {
         .set_presence_state(State {
             is_doing: "Nothing... Just hanging around...".into(),
         })
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute_blocking()?;
 
@@ -35,7 +35,7 @@ fn main() -> Result<(), Box<dyn snafu::Error>> {
 
     let states = client
         .get_presence_state()
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute_blocking()?;
 
@@ -2,12 +2,7 @@ use futures::StreamExt;
 use serde::Deserialize;
 use std::env;
 
-use pubnub::subscribe::{SubscriptionOptions, SubscriptionParams};
-use pubnub::{
-    dx::subscribe::Update,
-    subscribe::{EventEmitter, EventSubscriber},
-    Keyset, PubNubClientBuilder,
-};
+use pubnub::prelude::*;
 
 #[derive(Debug, Deserialize)]
 struct Message {
@@ -58,13 +53,16 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
     // Example of the "global" listener for multiplexed subscription object from
     // PubNub client.
     tokio::spawn(subscription.stream().for_each(|event| async move {
-        match event {
+        match event.as_ref() {
             Update::Message(message) | Update::Signal(message) => {
                 // Deserialize the message payload as you wish
                 match serde_json::from_slice::<Message>(&message.data) {
                     Ok(message) => println!("(a) defined message: {:?}", message),
                     Err(_) => {
-                        println!("(a) other message: {:?}", String::from_utf8(message.data))
+                        println!(
+                            "(a) other message: {:?}",
+                            String::from_utf8(message.data.to_vec())
+                        )
                     }
                 }
             }
@@ -92,7 +90,10 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
                 match serde_json::from_slice::<Message>(&message.data) {
                     Ok(message) => println!("(b) defined message: {:?}", message),
                     Err(_) => {
-                        println!("(b) other message: {:?}", String::from_utf8(message.data))
+                        println!(
+                            "(b) other message: {:?}",
+                            String::from_utf8(message.data.to_vec())
+                        )
                     }
                 }
             }),
@@ -34,7 +34,7 @@ fn main() -> Result<(), Box<dyn snafu::Error>> {
 
     client
         .subscribe_raw()
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .heartbeat(10)
         .filter_expression("some_filter")
         .execute_blocking()?
@@ -46,7 +46,10 @@ fn main() -> Result<(), Box<dyn snafu::Error>> {
                     match serde_json::from_slice::<Message>(&message.data) {
                         Ok(message) => println!("defined message: {:?}", message),
                         Err(_) => {
-                            println!("other message: {:?}", String::from_utf8(message.data))
+                            println!(
+                                "other message: {:?}",
+                                String::from_utf8(message.data.to_vec())
+                            )
                         }
                     }
                 }
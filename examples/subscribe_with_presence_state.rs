@@ -50,7 +50,7 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
             ),
             ("flag".to_string(), "false".to_string()),
         ]))
-        .channels(["my_channel".into(), "other_channel".into()].to_vec())
+        .channels(["my_channel", "other_channel"])
         .user_id("user_id")
         .execute()
         .await?;
@@ -73,13 +73,16 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
     );
 
     tokio::spawn(subscription.stream().for_each(|event| async move {
-        match event {
+        match event.as_ref() {
             Update::Message(message) | Update::Signal(message) => {
                 // Deserialize the message payload as you wish
                 match serde_json::from_slice::<Message>(&message.data) {
                     Ok(message) => println!("(a) defined message: {:?}", message),
                     Err(_) => {
-                        println!("(a) other message: {:?}", String::from_utf8(message.data))
+                        println!(
+                            "(a) other message: {:?}",
+                            String::from_utf8(message.data.to_vec())
+                        )
                     }
                 }
             }
@@ -107,7 +110,10 @@ async fn main() -> Result<(), Box<dyn snafu::Error>> {
                 match serde_json::from_slice::<Message>(&message.data) {
                     Ok(message) => println!("(b) defined message: {:?}", message),
                     Err(_) => {
-                        println!("(b) other message: {:?}", String::from_utf8(message.data))
+                        println!(
+                            "(b) other message: {:?}",
+                            String::from_utf8(message.data.to_vec())
+                        )
                     }
                 }
             }),
@@ -0,0 +1,148 @@
+//! # PubNub derive macros
+//!
+//! This crate contains the [`PubNubMessage`] derive macro for the [`pubnub`]
+//! crate. It shouldn't be used directly: enable `derive` feature of the
+//! [`pubnub`] crate and use macro re-exported from it.
+//!
+//! [`PubNubMessage`]: macro@PubNubMessage
+//! [`pubnub`]: https://docs.rs/pubnub
+
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+/// Minimum length of message type.
+const MESSAGE_TYPE_MIN_LENGTH: usize = 3;
+
+/// Maximum length of message type.
+const MESSAGE_TYPE_MAX_LENGTH: usize = 50;
+
+/// Derive `pubnub::core::PubNubMessage` implementation.
+///
+/// Message payload is serialized and deserialized with `serde`, so the type
+/// should implement `serde::Serialize` and `serde::Deserialize` as well.
+///
+/// Message type (sent with published message and used to match received
+/// messages) is the type name by default and can be changed with
+/// `#[pubnub(message_type = "...")]` attribute. Message type should contain
+/// from 3 to 50 alphanumeric characters (`-` and `_` are allowed except at
+/// the beginning) and shouldn't start with reserved `pn` prefix.
+///
+/// # Example
+/// ```ignore
+/// use pubnub::PubNubMessage;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(PubNubMessage, Serialize, Deserialize)]
+/// #[pubnub(message_type = "chat-message")]
+/// struct ChatMessage {
+///     text: String,
+/// }
+/// ```
+#[proc_macro_derive(PubNubMessage, attributes(pubnub))]
+pub fn derive_pubnub_message(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generate `PubNubMessage` implementation for the `input` type.
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let message_type = message_type(&input)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::pubnub::core::PubNubMessage for #name #ty_generics #where_clause {
+            const MESSAGE_TYPE: &'static str = #message_type;
+
+            fn to_payload(
+                &self,
+            ) -> ::core::result::Result<::pubnub::__private::Vec<u8>, ::pubnub::core::PubNubError> {
+                ::pubnub::__private::serialize_message(self)
+            }
+
+            fn from_payload(
+                payload: &[u8],
+            ) -> ::core::result::Result<Self, ::pubnub::core::PubNubError> {
+                ::pubnub::__private::deserialize_message(payload)
+            }
+        }
+    })
+}
+
+/// Message type from `#[pubnub(message_type = "...")]` attribute or type
+/// name.
+fn message_type(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut message_type = LitStr::new(&input.ident.to_string(), input.ident.span());
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("pubnub"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("message_type") {
+                message_type = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `pubnub` attribute"))
+            }
+        })?;
+    }
+
+    validate_message_type(&message_type.value())
+        .map_err(|details| syn::Error::new(message_type.span(), details))?;
+
+    Ok(message_type)
+}
+
+/// Check whether `message_type` can be used with [`PubNub API`] or not.
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+fn validate_message_type(message_type: &str) -> Result<(), String> {
+    let length = message_type.chars().count();
+
+    if !(MESSAGE_TYPE_MIN_LENGTH..=MESSAGE_TYPE_MAX_LENGTH).contains(&length) {
+        Err(format!(
+            "message type should contain from {MESSAGE_TYPE_MIN_LENGTH} to \
+             {MESSAGE_TYPE_MAX_LENGTH} characters"
+        ))
+    } else if message_type.starts_with(['-', '_']) {
+        Err("message type can't start with '-' or '_'".into())
+    } else if message_type.starts_with("pn-") || message_type.starts_with("pn_") {
+        Err("message type can't start with reserved 'pn' prefix".into())
+    } else if !message_type
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Err("message type can contain only alphanumeric characters, '-' and '_'".into())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn accept_valid_message_type() {
+        assert!(validate_message_type("ChatMessage").is_ok());
+        assert!(validate_message_type("chat-message_v2").is_ok());
+    }
+
+    #[test]
+    fn reject_invalid_message_type() {
+        assert!(validate_message_type("ab").is_err());
+        assert!(validate_message_type(&"a".repeat(51)).is_err());
+        assert!(validate_message_type("_chat").is_err());
+        assert!(validate_message_type("pn-chat").is_err());
+        assert!(validate_message_type("chat message").is_err());
+    }
+}
@@ -0,0 +1,236 @@
+//! # `async_trait` compatibility module
+//!
+//! This module contains [`AsyncTransport`], [`AsyncRuntime`] and
+//! [`AsyncNetworkMonitor`] traits which keep [`async_trait`] based signatures
+//! of the [`Transport`], [`Runtime`] and [`NetworkMonitor`] traits.
+//!
+//! Existing implementations can switch to the `async_trait_compat` feature
+//! traits without changes in method bodies. [`Transport`], [`Runtime`] and
+//! [`NetworkMonitor`] are implemented for every type which implements
+//! compatibility traits (each call will allocate boxed future).
+//!
+//! It requires the [`async_trait_compat` feature] to be enabled.
+//!
+//! [`async_trait`]: https://docs.rs/async-trait
+//! [`Runtime`]: crate::core::Runtime
+//! [`NetworkMonitor`]: crate::core::NetworkMonitor
+//! [`async_trait_compat` feature]: ../index.html#features
+
+use crate::{
+    core::{MaybeSend, PubNubError, Transport, TransportRequest, TransportResponse},
+    lib::{alloc::boxed::Box, core::future::Future},
+};
+
+#[cfg(all(feature = "std", feature = "subscribe"))]
+use crate::{
+    core::{NetworkMonitor, NetworkStatus, Runtime},
+    lib::core::fmt::Debug,
+};
+
+/// [`async_trait`] based [`Transport`] trait.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::{
+///     async_trait_compat::AsyncTransport, PubNubError, TransportRequest, TransportResponse,
+/// };
+///
+/// struct MyTransport;
+///
+/// #[async_trait::async_trait]
+/// impl AsyncTransport for MyTransport {
+///    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+///         // Send your request here
+///
+///         Ok(TransportResponse::default())
+///    }
+/// }
+/// ```
+///
+/// [`async_trait`]: https://docs.rs/async-trait
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+pub trait AsyncTransport: Send + Sync {
+    /// Send a request to the [`PubNub API`].
+    ///
+    /// See [`Transport::send`] for more information.
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError>;
+}
+
+impl<T> Transport for T
+where
+    T: AsyncTransport,
+{
+    fn send(
+        &self,
+        req: TransportRequest,
+    ) -> impl Future<Output = Result<TransportResponse, PubNubError>> + MaybeSend {
+        AsyncTransport::send(self, req)
+    }
+}
+
+/// [`async_trait`] based [`Runtime`] trait.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::async_trait_compat::AsyncRuntime;
+/// use std::future::Future;
+///
+/// #[derive(Clone)]
+/// struct MyRuntime;
+///
+/// #[async_trait::async_trait]
+/// impl AsyncRuntime for MyRuntime {
+///    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) {
+///       // spawn the Future
+///       // e.g. tokio::spawn(future);
+///    }
+///
+///    async fn sleep(self, _delay: u64) {
+///       // e.g. tokio::time::sleep(tokio::time::Duration::from_secs(delay)).await
+///    }
+///
+///    async fn sleep_microseconds(self, _delay: u64) {
+///       // e.g. tokio::time::sleep(tokio::time::Duration::from_micros(delay)).await
+///    }
+/// }
+/// ```
+///
+/// [`async_trait`]: https://docs.rs/async-trait
+#[cfg(all(feature = "std", feature = "subscribe"))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+pub trait AsyncRuntime: Clone + Send {
+    /// Spawn a task.
+    ///
+    /// See [`Runtime::spawn`] for more information.
+    fn spawn<R>(&self, future: impl Future<Output = R> + MaybeSend + 'static)
+    where
+        R: MaybeSend + 'static;
+
+    /// Put current task to "sleep".
+    ///
+    /// Sleep current task for specified amount of time (in seconds).
+    async fn sleep(self, delay: u64);
+
+    /// Put current task to "sleep".
+    ///
+    /// Sleep current task for specified amount of time (in microseconds).
+    async fn sleep_microseconds(self, delay: u64);
+}
+
+#[cfg(all(feature = "std", feature = "subscribe"))]
+impl<T> Runtime for T
+where
+    T: AsyncRuntime,
+{
+    fn spawn<R>(&self, future: impl Future<Output = R> + MaybeSend + 'static)
+    where
+        R: MaybeSend + 'static,
+    {
+        AsyncRuntime::spawn(self, future)
+    }
+
+    async fn sleep(self, delay: u64) {
+        AsyncRuntime::sleep(self, delay).await
+    }
+
+    async fn sleep_microseconds(self, delay: u64) {
+        AsyncRuntime::sleep_microseconds(self, delay).await
+    }
+}
+
+/// [`async_trait`] based [`NetworkMonitor`] trait.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::{async_trait_compat::AsyncNetworkMonitor, NetworkStatus};
+///
+/// #[derive(Debug)]
+/// struct MyMonitor;
+///
+/// #[async_trait::async_trait]
+/// impl AsyncNetworkMonitor for MyMonitor {
+///     async fn status_change(&self, current: NetworkStatus) -> NetworkStatus {
+///         // e.g. wait for OS notification about connectivity change
+///         current
+///     }
+/// }
+/// ```
+///
+/// [`async_trait`]: https://docs.rs/async-trait
+#[cfg(all(feature = "std", feature = "subscribe"))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+pub trait AsyncNetworkMonitor: Debug + Send + Sync {
+    /// Wait for network reachability change.
+    ///
+    /// See [`NetworkMonitor::status_change`] for more information.
+    async fn status_change(&self, current: NetworkStatus) -> NetworkStatus;
+}
+
+#[cfg(all(feature = "std", feature = "subscribe"))]
+impl<T> NetworkMonitor for T
+where
+    T: AsyncNetworkMonitor,
+{
+    fn status_change(
+        &self,
+        current: NetworkStatus,
+    ) -> impl Future<Output = NetworkStatus> + MaybeSend {
+        AsyncNetworkMonitor::status_change(self, current)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    struct LegacyTransport;
+
+    #[async_trait::async_trait]
+    impl AsyncTransport for LegacyTransport {
+        async fn send(&self, _req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            Ok(TransportResponse {
+                status: 204,
+                ..Default::default()
+            })
+        }
+    }
+
+    async fn send_with<T: Transport>(transport: &T) -> TransportResponse {
+        transport.send(TransportRequest::default()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn use_async_trait_implementation_as_transport() {
+        assert_eq!(send_with(&LegacyTransport).await.status, 204);
+    }
+
+    #[cfg(all(feature = "std", feature = "subscribe"))]
+    #[derive(Debug)]
+    struct LegacyMonitor;
+
+    #[cfg(all(feature = "std", feature = "subscribe"))]
+    #[async_trait::async_trait]
+    impl AsyncNetworkMonitor for LegacyMonitor {
+        async fn status_change(&self, _current: NetworkStatus) -> NetworkStatus {
+            NetworkStatus::Down
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "subscribe"))]
+    #[tokio::test]
+    async fn use_async_trait_implementation_as_network_monitor() {
+        async fn status_change_with<M: NetworkMonitor>(monitor: &M) -> NetworkStatus {
+            monitor.status_change(NetworkStatus::Up).await
+        }
+
+        assert_eq!(
+            status_change_with(&LegacyMonitor).await,
+            NetworkStatus::Down
+        );
+    }
+}
@@ -6,7 +6,7 @@
 //! [`PubNub API`]: https://www.pubnub.com/docs
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
-use spin::RwLock;
+use crate::lib::sync::RwLock;
 
 use crate::{
     core::PubNubEntity,
@@ -21,9 +21,13 @@ use crate::{
     },
 };
 
+#[cfg(any(feature = "publish", all(feature = "subscribe", feature = "std")))]
+use crate::core::Deserializer;
+#[cfg(feature = "publish")]
+use crate::{core::Serialize, dx::publish::PublishMessageViaChannelBuilder};
 #[cfg(all(feature = "subscribe", feature = "std"))]
 use crate::{
-    core::{Deserializer, Transport},
+    core::{ChannelName, Transport},
     lib::alloc::{format, sync::Weak, vec, vec::Vec},
     subscribe::{Subscribable, SubscribableType, Subscriber, Subscription, SubscriptionOptions},
 };
@@ -47,9 +51,10 @@ pub struct ChannelRef<T, D> {
     ///
     /// Client is used to support entity-specific actions like:
     /// * subscription
+    /// * publish
     ///
     /// [`PubNubClientInstance`]: PubNubClientInstance
-    #[allow(dead_code)] // Field used conditionally only for `subscription` feature.
+    #[allow(dead_code)] // Field used conditionally only for `subscription` and `publish`.
     client: Arc<PubNubClientInstance<T, D>>,
 
     /// Unique channel name.
@@ -189,9 +194,9 @@ impl<T, D> Debug for Channel<T, D> {
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
 impl<T, D> Subscribable<T, D> for Channel<T, D> {
-    fn names(&self, presence: bool) -> Vec<String> {
-        let mut names = vec![self.name.clone()];
-        presence.then(|| names.push(format!("{}-pnpres", self.name)));
+    fn names(&self, presence: bool) -> Vec<ChannelName> {
+        let mut names = vec![self.client.channel_name(&self.name)];
+        presence.then(|| names.push(self.client.channel_name(&format!("{}-pnpres", self.name))));
 
         names
     }
@@ -215,3 +220,68 @@ where
         Subscription::new(self.client(), self.clone().into(), options)
     }
 }
+
+#[cfg(feature = "publish")]
+impl<T, D> Channel<T, D>
+where
+    D: Deserializer,
+{
+    /// Create a publish message builder bound to this channel.
+    ///
+    /// Instance of [`PublishMessageViaChannelBuilder`] is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let pubnub = PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let channel = pubnub.channel("my_channel");
+    ///
+    /// channel.publish("Hello, world!").execute().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PublishMessageViaChannelBuilder`]: crate::dx::publish::PublishMessageViaChannelBuilder
+    pub fn publish<M>(&self, message: M) -> PublishMessageViaChannelBuilder<T, M, D>
+    where
+        M: Serialize,
+    {
+        self.client
+            .publish_message(message)
+            .channel(self.name.clone())
+    }
+
+    /// Create a signal builder bound to this channel.
+    ///
+    /// See [`PubNubClientInstance::signal`] for more information.
+    ///
+    /// [`PubNubClientInstance::signal`]: crate::dx::pubnub_client::PubNubClientInstance::signal
+    pub fn signal<M>(&self, message: M) -> PublishMessageViaChannelBuilder<T, M, D>
+    where
+        M: Serialize,
+    {
+        self.client.signal(message).channel(self.name.clone())
+    }
+
+    /// Create a fire builder bound to this channel.
+    ///
+    /// Fired message is sent only to the configured `Functions` event
+    /// handlers: it isn't stored in history, replicated or delivered to
+    /// subscribers.
+    pub fn fire<M>(&self, message: M) -> PublishMessageViaChannelBuilder<T, M, D>
+    where
+        M: Serialize,
+    {
+        self.publish(message).store(false).replicate(false)
+    }
+}
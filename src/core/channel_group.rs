@@ -6,7 +6,7 @@
 //! [`PubNub API`]: https://www.pubnub.com/docs
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
-use spin::RwLock;
+use crate::lib::sync::RwLock;
 
 use crate::{
     core::PubNubEntity,
@@ -23,7 +23,7 @@ use crate::{
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
 use crate::{
-    core::{Deserializer, Transport},
+    core::{ChannelName, Deserializer, Transport},
     lib::alloc::{format, sync::Weak, vec, vec::Vec},
     subscribe::{Subscribable, SubscribableType, Subscriber, Subscription, SubscriptionOptions},
 };
@@ -190,9 +190,9 @@ impl<T, D> Debug for ChannelGroup<T, D> {
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
 impl<T, D> Subscribable<T, D> for ChannelGroup<T, D> {
-    fn names(&self, presence: bool) -> Vec<String> {
-        let mut names = vec![self.name.clone()];
-        presence.then(|| names.push(format!("{}-pnpres", self.name)));
+    fn names(&self, presence: bool) -> Vec<ChannelName> {
+        let mut names = vec![self.client.channel_name(&self.name)];
+        presence.then(|| names.push(self.client.channel_name(&format!("{}-pnpres", self.name))));
 
         names
     }
@@ -6,7 +6,7 @@
 //! [`PubNub API`]: https://www.pubnub.com/docs
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
-use spin::RwLock;
+use crate::lib::sync::RwLock;
 
 use crate::{
     core::PubNubEntity,
@@ -21,9 +21,16 @@ use crate::{
     },
 };
 
+#[cfg(any(feature = "objects", all(feature = "subscribe", feature = "std")))]
+use crate::core::Deserializer;
+#[cfg(feature = "objects")]
+use crate::dx::objects::{
+    GetChannelMembersRequestBuilder, RemoveChannelMembersRequestBuilder,
+    SetChannelMembersRequestBuilder,
+};
 #[cfg(all(feature = "subscribe", feature = "std"))]
 use crate::{
-    core::{Deserializer, Transport},
+    core::{ChannelName, Transport},
     lib::alloc::{sync::Weak, vec, vec::Vec},
     subscribe::{Subscribable, SubscribableType, Subscriber, Subscription, SubscriptionOptions},
 };
@@ -48,9 +55,10 @@ pub struct ChannelMetadataRef<T, D> {
     ///
     /// Client is used to support entity-specific actions like:
     /// * subscription
+    /// * `channel` members management
     ///
     /// [`PubNubClientInstance`]: PubNubClientInstance
-    #[allow(dead_code)] // Field used conditionally only for `subscription` feature.
+    #[allow(dead_code)] // Field used conditionally only for `subscription` and `objects`.
     client: Arc<PubNubClientInstance<T, D>>,
 
     /// Unique channel metadata object identifier.
@@ -190,8 +198,8 @@ impl<T, D> Debug for ChannelMetadata<T, D> {
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
 impl<T, D> Subscribable<T, D> for ChannelMetadata<T, D> {
-    fn names(&self, _presence: bool) -> Vec<String> {
-        vec![self.id.clone()]
+    fn names(&self, _presence: bool) -> Vec<ChannelName> {
+        vec![self.client.channel_name(&self.id)]
     }
 
     fn r#type(&self) -> SubscribableType {
@@ -213,3 +221,57 @@ where
         Subscription::new(self.client(), self.clone().into(), options)
     }
 }
+
+#[cfg(feature = "objects")]
+impl<T, D> ChannelMetadata<T, D>
+where
+    D: Deserializer,
+{
+    /// Create a get `channel` members request builder bound to this entity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let pubnub = PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let channel = pubnub.channel_metadata("my_channel");
+    ///
+    /// let result = channel.get_members().execute().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_members(&self) -> GetChannelMembersRequestBuilder<T, D> {
+        self.client.get_channel_members().channel(self.id.clone())
+    }
+
+    /// Create a set `channel` members request builder bound to this entity.
+    ///
+    /// See [`PubNubClientInstance::set_channel_members`] for more information.
+    ///
+    /// [`PubNubClientInstance::set_channel_members`]: crate::dx::pubnub_client::PubNubClientInstance::set_channel_members
+    pub fn set_members(&self) -> SetChannelMembersRequestBuilder<T, D> {
+        self.client.set_channel_members().channel(self.id.clone())
+    }
+
+    /// Create a remove `channel` members request builder bound to this
+    /// entity.
+    ///
+    /// See [`PubNubClientInstance::remove_channel_members`] for more
+    /// information.
+    ///
+    /// [`PubNubClientInstance::remove_channel_members`]: crate::dx::pubnub_client::PubNubClientInstance::remove_channel_members
+    pub fn remove_members(&self) -> RemoveChannelMembersRequestBuilder<T, D> {
+        self.client
+            .remove_channel_members()
+            .channel(self.id.clone())
+    }
+}
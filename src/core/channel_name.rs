@@ -0,0 +1,150 @@
+//! # Channel name module
+//!
+//! This module contains the [`ChannelName`] type, which represents interned
+//! name of the channel or channel group.
+
+use crate::lib::{
+    alloc::{
+        string::{String, ToString},
+        sync::Arc,
+    },
+    core::{
+        borrow::Borrow,
+        fmt::{Debug, Display, Formatter, Result},
+        ops::Deref,
+    },
+};
+
+/// Interned channel name.
+///
+/// Name is stored in the reference-counted buffer, so clones are cheap, and
+/// names of entities created by the same [`PubNubClientInstance`] share one
+/// allocation.
+///
+/// [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChannelName(Arc<str>);
+
+impl ChannelName {
+    /// Create channel name from `name`.
+    pub fn new<S>(name: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self(Arc::from(name.as_ref()))
+    }
+
+    /// Channel name as string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether name is referenced only by this instance or not.
+    pub(crate) fn is_unique(&self) -> bool {
+        Arc::strong_count(&self.0) == 1
+    }
+
+    /// Whether `self` and `other` share same buffer or not.
+    #[cfg(test)]
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Deref for ChannelName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ChannelName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ChannelName {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for ChannelName {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for ChannelName {
+    fn from(value: String) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl From<&String> for ChannelName {
+    fn from(value: &String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<ChannelName> for String {
+    fn from(value: ChannelName) -> Self {
+        value.0.to_string()
+    }
+}
+
+impl PartialEq<str> for ChannelName {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl PartialEq<&str> for ChannelName {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(*other)
+    }
+}
+
+impl PartialEq<String> for ChannelName {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl Display for ChannelName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Debug for ChannelName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::lib::alloc::collections::BTreeSet;
+
+    #[test]
+    fn compare_with_strings() {
+        let name = ChannelName::from("channel");
+
+        assert_eq!(name, "channel");
+        assert_eq!(name, String::from("channel"));
+        assert_eq!(name.to_string(), "channel");
+        assert_eq!(String::from(name), "channel");
+    }
+
+    #[test]
+    fn lookup_by_string_slice() {
+        let set = BTreeSet::from([ChannelName::from("channel-a")]);
+
+        assert!(set.contains("channel-a"));
+        assert!(!set.contains("channel-b"));
+    }
+}
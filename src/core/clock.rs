@@ -0,0 +1,74 @@
+//! # Clock module
+//!
+//! This module contains the [`Clock`] trait, which is used by
+//! [`PubNubClientInstance`] to retrieve current time for requests which depend
+//! on it (like signed requests).
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+use crate::lib::core::fmt::Debug;
+
+/// Time source trait.
+///
+/// Clock used by the client to get current Unix timestamp which will be used
+/// with signed requests. Custom implementation can be used on devices with
+/// skewed system clock or in environments without real-time clock (`no_std`).
+///
+/// # Examples
+/// ```
+/// use pubnub::core::Clock;
+///
+/// #[derive(Debug)]
+/// struct MyClock {
+///     offset: i64,
+/// }
+///
+/// impl Clock for MyClock {
+///     fn unix_timestamp(&self) -> i64 {
+///         // e.g. read time from RTC and apply offset
+///         1_700_000_000 + self.offset
+///     }
+/// }
+/// ```
+pub trait Clock: Debug + Send + Sync {
+    /// Current Unix timestamp.
+    ///
+    /// Number of seconds elapsed since the Unix epoch.
+    fn unix_timestamp(&self) -> i64;
+}
+
+/// Function time source.
+///
+/// Allows to use function which returns current Unix timestamp (for example,
+/// read from RTC on embedded gateway) as [`Clock`].
+///
+/// # Examples
+/// ```
+/// use pubnub::core::Clock;
+///
+/// fn rtc_now() -> i64 {
+///     1_700_000_000
+/// }
+///
+/// let clock: fn() -> i64 = rtc_now;
+/// assert_eq!(clock.unix_timestamp(), 1_700_000_000);
+/// ```
+impl Clock for fn() -> i64 {
+    fn unix_timestamp(&self) -> i64 {
+        self()
+    }
+}
+
+/// System time source.
+///
+/// Default [`Clock`] implementation which uses system time.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn unix_timestamp(&self) -> i64 {
+        time::OffsetDateTime::now_utc().unix_timestamp()
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! This module contains the [`DataStream`] struct.
 
+use crate::lib::sync::RwLock;
 use futures::Stream;
-use spin::RwLock;
 
 use crate::lib::{
     alloc::{collections::VecDeque, sync::Arc},
@@ -146,32 +146,36 @@ impl<D> DataStream<D> {
         }
     }
 
-    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[cfg(feature = "std")]
     pub(crate) fn push_data(&self, data: D) {
         if !*self.is_valid.read() {
             return;
         }
 
-        let mut queue_data_slot = self.queue.write();
+        {
+            let mut queue_data_slot = self.queue.write();
 
-        // Dropping the earliest entry to prevent the queue from growing too large.
-        if queue_data_slot.len() == queue_data_slot.capacity() {
-            queue_data_slot.pop_front();
-        }
+            // Dropping the earliest entry to prevent the queue from growing too large.
+            if queue_data_slot.len() == queue_data_slot.capacity() {
+                queue_data_slot.pop_front();
+            }
 
-        queue_data_slot.push_back(data);
+            queue_data_slot.push_back(data);
+        }
 
+        // Queue lock should be released before stream wake up, because
+        // `poll_next` acquires locks in reverse order.
         self.wake_stream();
     }
 
-    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[cfg(feature = "std")]
     pub(crate) fn invalidate(&self) {
         let mut is_valid = self.is_valid.write();
         *is_valid = false;
         self.wake_stream();
     }
 
-    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[cfg(feature = "std")]
     fn wake_stream(&self) {
         if let Some(waker) = self.waker.write().take() {
             waker.wake();
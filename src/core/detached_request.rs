@@ -0,0 +1,89 @@
+//! # Detached request module
+//!
+//! This module contains the `impl_detached_request` macro which allows
+//! request builders to be created without [`PubNubClientInstance`] and bound
+//! to the client only when request should be executed.
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+/// Implement detached construction and execution for request builder.
+///
+/// Builder created with `builder()` doesn't have client and it is provided
+/// with `try_execute_with` (or `try_execute_blocking_with`). Optional `uuid`
+/// field name can be passed to use client's `user_id` when it hasn't been set
+/// (same as builders created by client).
+macro_rules! impl_detached_request {
+    ($request:ident, $builder:ident, $result:ty $(, $uuid:ident)?) => {
+        impl<T, D> $request<T, D> {
+            /// Create a new detached request builder.
+            ///
+            /// Request can be built without [`PubNubClient`] (for example, in
+            /// pure functions or tests) and client is provided only when
+            /// request should be executed with `try_execute_with`.
+            ///
+            /// [`PubNubClient`]: crate::dx::PubNubClient
+            pub fn builder() -> $builder<T, D> {
+                $builder::default()
+            }
+        }
+
+        impl<T, D> $builder<T, D> {
+            /// Bind request to the `client` which should be used to execute it.
+            fn bind(
+                mut self,
+                client: &$crate::dx::pubnub_client::PubNubClientInstance<T, D>,
+            ) -> Self {
+                $(
+                    if self.$uuid.is_none() {
+                        self.$uuid = Some(client.user_id());
+                    }
+                )?
+                self.pubnub_client = Some(client.clone());
+                self
+            }
+        }
+
+        impl<T, D> $builder<T, D>
+        where
+            T: $crate::core::Transport + 'static,
+            D: $crate::core::Deserializer + 'static,
+        {
+            /// Execute the request with provided `client` and return the
+            /// result.
+            ///
+            /// # Errors
+            /// Returns [`PubNubError`] if required request fields haven't been
+            /// set or request failed.
+            ///
+            /// [`PubNubError`]: crate::core::PubNubError
+            pub async fn try_execute_with(
+                self,
+                client: &$crate::dx::pubnub_client::PubNubClientInstance<T, D>,
+            ) -> Result<$result, $crate::core::PubNubError> {
+                self.bind(client).execute().await
+            }
+        }
+
+        #[cfg(feature = "blocking")]
+        impl<T, D> $builder<T, D>
+        where
+            T: $crate::core::blocking::Transport,
+            D: $crate::core::Deserializer + 'static,
+        {
+            /// Execute the request with provided `client` and return the
+            /// result.
+            ///
+            /// # Errors
+            /// Returns [`PubNubError`] if required request fields haven't been
+            /// set or request failed.
+            ///
+            /// [`PubNubError`]: crate::core::PubNubError
+            pub fn try_execute_blocking_with(
+                self,
+                client: &$crate::dx::pubnub_client::PubNubClientInstance<T, D>,
+            ) -> Result<$result, $crate::core::PubNubError> {
+                self.bind(client).execute_blocking()
+            }
+        }
+    };
+}
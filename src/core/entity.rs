@@ -7,9 +7,18 @@
 //! [`PubNub API`]: https://www.pubnub.com/docs
 
 use crate::{
-    lib::core::{
-        cmp::PartialEq,
-        fmt::{Debug, Formatter, Result},
+    core::ChannelName,
+    lib::{
+        alloc::{
+            collections::{BTreeMap, BTreeSet},
+            string::String,
+            vec::Vec,
+        },
+        collections::HashMap,
+        core::{
+            cmp::PartialEq,
+            fmt::{Debug, Formatter, Result},
+        },
     },
     Channel, ChannelGroup, ChannelMetadata, UserMetadata,
 };
@@ -17,7 +26,6 @@ use crate::{
 #[cfg(all(feature = "subscribe", feature = "std"))]
 use crate::{
     core::{Deserializer, Transport},
-    lib::alloc::{string::String, vec::Vec},
     subscribe::{Subscribable, SubscribableType, Subscriber, Subscription, SubscriptionOptions},
 };
 
@@ -30,7 +38,7 @@ pub(crate) enum PubNubEntity<T, D> {
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
 impl<T, D> PubNubEntity<T, D> {
-    pub(crate) fn names(&self, presence: bool) -> Vec<String> {
+    pub(crate) fn names(&self, presence: bool) -> Vec<ChannelName> {
         match self {
             Self::Channel(channel) => channel.names(presence),
             Self::ChannelGroup(channel_group) => channel_group.names(presence),
@@ -181,3 +189,172 @@ where
         }
     }
 }
+
+/// Created entities cache.
+///
+/// Cache keeps entities created to access [`PubNub API`] and (when `capacity`
+/// is set) evicts least recently used entities which is not used by any
+/// subscription.
+///
+/// Cache also interns channel and channel group names, so subscriptions and
+/// presence which use same entities share name buffers.
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+pub(crate) struct EntitiesCache<T, D> {
+    /// Maximum number of entities which can be stored in cache.
+    capacity: Option<usize>,
+
+    /// Monotonically increasing entity access counter.
+    tick: u64,
+
+    /// Cached entities with their last access tick.
+    entities: HashMap<String, (u64, PubNubEntity<T, D>)>,
+
+    /// Entity keys ordered by last access tick.
+    usage: BTreeMap<u64, String>,
+
+    /// Interned channel and channel group names.
+    names: BTreeSet<ChannelName>,
+}
+
+impl<T, D> EntitiesCache<T, D> {
+    /// Create entities cache with optional `capacity`.
+    pub(crate) fn new(capacity: Option<usize>) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entities: HashMap::new(),
+            usage: BTreeMap::new(),
+            names: BTreeSet::new(),
+        }
+    }
+
+    /// Number of cached entities.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Interned channel `name`.
+    ///
+    /// Returns previously interned instance (if any) or interns new one.
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    pub(crate) fn intern(&mut self, name: &str) -> ChannelName {
+        if let Some(interned) = self.names.get(name) {
+            return interned.clone();
+        }
+
+        let interned = ChannelName::from(name);
+        self.names.insert(interned.clone());
+        interned
+    }
+
+    /// Retrieve entity stored for `key` or store one created by `create`.
+    pub(crate) fn get_or_insert_with<F>(&mut self, key: String, create: F) -> PubNubEntity<T, D>
+    where
+        F: FnOnce() -> PubNubEntity<T, D>,
+    {
+        self.tick += 1;
+        let tick = self.tick;
+
+        if let Some((last_tick, entity)) = self.entities.get_mut(&key) {
+            self.usage.remove(last_tick);
+            *last_tick = tick;
+            self.usage.insert(tick, key);
+            return entity.clone();
+        }
+
+        let entity = create();
+        self.entities.insert(key.clone(), (tick, entity.clone()));
+        self.usage.insert(tick, key);
+        self.evict();
+
+        entity
+    }
+
+    /// Remove entity stored for `key`.
+    ///
+    /// Entity which is used by subscriptions won't be removed.
+    pub(crate) fn remove(&mut self, key: &str) -> bool {
+        let Some((tick, entity)) = self.entities.get(key) else {
+            return false;
+        };
+
+        if Self::is_in_use(entity) {
+            return false;
+        }
+
+        self.usage.remove(tick);
+        self.entities.remove(key);
+        self.release_names();
+        true
+    }
+
+    /// Evict least recently used entities which exceed cache `capacity`.
+    fn evict(&mut self) {
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+
+        let mut excess = self.entities.len().saturating_sub(capacity);
+        if excess == 0 {
+            return;
+        }
+
+        let mut evicted = Vec::with_capacity(excess);
+        for (tick, key) in self.usage.iter() {
+            if excess == 0 {
+                break;
+            }
+
+            if let Some((_, entity)) = self.entities.get(key) {
+                if Self::is_in_use(entity) {
+                    continue;
+                }
+            }
+
+            evicted.push((*tick, key.clone()));
+            excess -= 1;
+        }
+
+        evicted.into_iter().for_each(|(tick, key)| {
+            self.usage.remove(&tick);
+            self.entities.remove(&key);
+        });
+        self.release_names();
+    }
+
+    /// Release interned names which is not used by anyone except cache.
+    fn release_names(&mut self) {
+        self.names.retain(|name| !name.is_unique());
+    }
+
+    /// Whether `entity` is used by any subscription or not.
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    fn is_in_use(entity: &PubNubEntity<T, D>) -> bool {
+        entity.subscriptions_count() > 0
+    }
+
+    /// Whether `entity` is used by any subscription or not.
+    #[cfg(not(all(feature = "subscribe", feature = "std")))]
+    fn is_in_use(_entity: &PubNubEntity<T, D>) -> bool {
+        false
+    }
+}
+
+impl<T, D> Default for EntitiesCache<T, D> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T, D> Debug for EntitiesCache<T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "EntitiesCache {{ capacity: {:?}, entities: {:?} }}",
+            self.capacity,
+            self.entities.keys()
+        )
+    }
+}
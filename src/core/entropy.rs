@@ -0,0 +1,86 @@
+//! # Entropy source module
+//!
+//! This module contains [`set_entropy_source`] function which can be used to
+//! provide random bytes generator for targets which aren't supported by the
+//! [`getrandom`] crate (for example, microcontrollers with hardware RNG).
+//!
+//! Registered source is used by all random data consumers: identifiers
+//! generation, crypto module initialization vectors and retry delays jitter.
+//!
+//! > Note: Source is registered as [`getrandom` custom backend] and can't be
+//! > used together with another `register_custom_getrandom!` in the same
+//! > binary.
+//!
+//! [`getrandom`]: https://docs.rs/getrandom
+//! [`getrandom` custom backend]: https://docs.rs/getrandom/0.2/getrandom/macro.register_custom_getrandom.html
+
+use crate::lib::alloc::boxed::Box;
+use spin::Once;
+
+/// Registered random bytes generator.
+type EntropySource = Box<dyn Fn(&mut [u8]) + Send + Sync>;
+
+/// Process-wide entropy source.
+static ENTROPY_SOURCE: Once<EntropySource> = Once::new();
+
+/// Register random bytes generator.
+///
+/// `source` should fill whole provided buffer with random bytes. Only the
+/// first registered source is used and the function returns `false` if
+/// source has been registered before.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::entropy::set_entropy_source;
+///
+/// # fn hardware_rng_next() -> u8 { 4 }
+/// set_entropy_source(|buffer: &mut [u8]| {
+///     buffer.iter_mut().for_each(|byte| *byte = hardware_rng_next());
+/// });
+/// ```
+pub fn set_entropy_source<F>(source: F) -> bool
+where
+    F: Fn(&mut [u8]) + Send + Sync + 'static,
+{
+    let mut registered = false;
+    ENTROPY_SOURCE.call_once(|| {
+        registered = true;
+        Box::new(source)
+    });
+
+    registered
+}
+
+/// Whether entropy source has been registered or not.
+pub fn has_entropy_source() -> bool {
+    ENTROPY_SOURCE.is_completed()
+}
+
+/// Fill `buffer` with bytes from registered entropy source.
+fn fill_from_entropy_source(buffer: &mut [u8]) -> Result<(), getrandom::Error> {
+    let source = ENTROPY_SOURCE.get().ok_or(getrandom::Error::UNSUPPORTED)?;
+    source(buffer);
+
+    Ok(())
+}
+
+getrandom::register_custom_getrandom!(fill_from_entropy_source);
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn use_first_registered_source() {
+        let first = set_entropy_source(|buffer: &mut [u8]| buffer.fill(7));
+        let second = set_entropy_source(|buffer: &mut [u8]| buffer.fill(9));
+        let mut buffer = [0u8; 4];
+
+        fill_from_entropy_source(&mut buffer).unwrap();
+
+        assert!(first);
+        assert!(!second);
+        assert!(has_entropy_source());
+        assert_eq!(buffer, [7; 4]);
+    }
+}
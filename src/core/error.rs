@@ -10,6 +10,88 @@ use crate::{
 };
 use snafu::Snafu;
 
+/// Error details type.
+///
+/// Human-readable description of the failure reason. With the
+/// [`static_errors` feature] enabled, details are `&'static str` descriptions
+/// (message templates) which don't require heap allocation.
+///
+/// [`static_errors` feature]: ../index.html#features
+#[cfg(not(feature = "static_errors"))]
+pub type ErrorDetails = String;
+
+/// Error details type.
+///
+/// Human-readable description of the failure reason. With the
+/// [`static_errors` feature] enabled, details are `&'static str` descriptions
+/// (message templates) which don't require heap allocation.
+///
+/// [`static_errors` feature]: ../index.html#features
+#[cfg(feature = "static_errors")]
+pub type ErrorDetails = &'static str;
+
+/// Create [`ErrorDetails`] from format string and arguments.
+///
+/// With [`static_errors` feature] enabled, the format string itself is used as
+/// error details and arguments aren't formatted. When called with error
+/// instance, its string representation is used (or generic description with
+/// [`static_errors` feature]).
+///
+/// [`static_errors` feature]: ../index.html#features
+#[cfg(not(feature = "static_errors"))]
+macro_rules! error_details {
+    ($fmt:literal $($args:tt)*) => {
+        $crate::lib::alloc::format!($fmt $($args)*)
+    };
+    ($err:expr) => {
+        $crate::lib::alloc::string::ToString::to_string(&$err)
+    };
+}
+
+/// Create [`ErrorDetails`] from format string and arguments.
+///
+/// With [`static_errors` feature] enabled, the format string itself is used as
+/// error details and arguments aren't formatted. When called with error
+/// instance, its string representation is used (or generic description with
+/// [`static_errors` feature]).
+///
+/// [`static_errors` feature]: ../index.html#features
+#[cfg(feature = "static_errors")]
+macro_rules! error_details {
+    ($fmt:literal $($args:tt)*) => {{
+        let _ = $crate::lib::core::format_args!($fmt $($args)*);
+        $fmt
+    }};
+    ($err:expr) => {{
+        let _ = &$err;
+        "details unavailable (static_errors)"
+    }};
+}
+
+/// Implement conversion of `derive_builder` generated builder error into
+/// [`PubNubError`].
+///
+/// Uninitialized fields are reported with dedicated error variants (see
+/// `PubNubError::missing_builder_field`) and validation failures with
+/// [`PubNubError::API`].
+#[allow(unused_macros)]
+macro_rules! impl_from_builder_error {
+    ($error:ident) => {
+        impl From<$error> for $crate::core::PubNubError {
+            fn from(value: $error) -> Self {
+                match value {
+                    $error::UninitializedField(field) => {
+                        $crate::core::PubNubError::missing_builder_field(field)
+                    }
+                    $error::ValidationError(message) => {
+                        $crate::core::PubNubError::general_api_error(message, None, None)
+                    }
+                }
+            }
+        }
+    };
+}
+
 /// PubNub error type
 ///
 /// This type is used to represent errors that can occur in the PubNub protocol.
@@ -37,7 +119,7 @@ pub enum PubNubError {
     #[snafu(display("Transport error: {details}"))]
     Transport {
         ///docs
-        details: String,
+        details: ErrorDetails,
 
         /// Failed request HTTP status code.
         response: Option<Box<TransportResponse>>,
@@ -47,28 +129,28 @@ pub enum PubNubError {
     #[snafu(display("Request cancelled: {details}"))]
     RequestCancel {
         /// Information with reason why request has been cancelled.
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the publication of the request fails
     #[snafu(display("Publish error: {details}"))]
     PublishError {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the serialization of the response fails
     #[snafu(display("Serialization error: {details}"))]
     Serialization {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the serialization of the response fails
     #[snafu(display("Deserialization error: {details}"))]
     Deserialization {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the deserialization of the token fails
@@ -76,60 +158,111 @@ pub enum PubNubError {
     #[snafu(display("Token deserialization error: {details}"))]
     TokenDeserialization {
         ///docs
-        details: String,
+        details: ErrorDetails,
+    },
+
+    /// this error is returned when message doesn't conform to the configured
+    /// message schema
+    #[cfg(feature = "publish")]
+    #[snafu(display("Message validation error for '{channel}' channel: {details}"))]
+    MessageValidation {
+        /// Name of the channel to which message should be published.
+        channel: String,
+
+        /// Validation failure reason.
+        details: ErrorDetails,
     },
 
     /// this error is returned when one of the needed keys is missing
     #[snafu(display("No key provided error: {details}"))]
     NoKey {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the initialization of client fails
     #[snafu(display("Client initialization error: {details}"))]
     ClientInitialization {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the initialization of the cryptor fails
     #[snafu(display("Crypto initialization error: {details}"))]
     CryptoInitialization {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the cryptor is unable to encrypt data
     #[snafu(display("Data encryption error: {details}"))]
     Encryption {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error is returned when the cryptor is unable to decrypt data
     #[snafu(display("Data decryption error: {details}"))]
     Decryption {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     /// this error returned when suitable cryptor not found for data decryption.
     #[snafu(display("Unknown cryptor error: {details}"))]
     UnknownCryptor {
         /// docs
-        details: String,
+        details: ErrorDetails,
+    },
+
+    /// this error is returned when requests to the endpoint are suspended by
+    /// circuit breaker
+    #[snafu(display("Circuit breaker open error: {details}"))]
+    CircuitBreakerOpen {
+        ///docs
+        details: ErrorDetails,
     },
 
     /// this error is returned when the event engine effect is canceled
     #[snafu(display("Event engine effect has been canceled"))]
     EffectCanceled,
 
+    /// this error is returned when request channel hasn't been set
+    #[snafu(display("Missing channel error: {details}"))]
+    MissingChannel {
+        /// Hint with builder method which should be called.
+        details: ErrorDetails,
+    },
+
+    /// this error is returned when request message hasn't been set
+    #[snafu(display("Missing message error: {details}"))]
+    MissingMessage {
+        /// Hint with builder method which should be called.
+        details: ErrorDetails,
+    },
+
+    /// this error is returned when request TTL hasn't been set
+    #[snafu(display("Missing TTL error: {details}"))]
+    MissingTtl {
+        /// Hint with builder method which should be called.
+        details: ErrorDetails,
+    },
+
+    /// this error is returned when required request field hasn't been set
+    #[snafu(display("Missing '{field}' field error: {details}"))]
+    MissingField {
+        /// Name of field which hasn't been set.
+        field: &'static str,
+
+        /// Hint with builder method which should be called.
+        details: ErrorDetails,
+    },
+
     /// this error is returned when the subscription initialization fails
     #[snafu(display("Subscription initialization error: {details}"))]
     SubscribeInitialization {
         ///docs
-        details: String,
+        details: ErrorDetails,
     },
 
     ///this error is returned when REST API request can't be handled by
@@ -151,11 +284,68 @@ pub enum PubNubError {
         /// List of channel groups which is affected by error.
         affected_channel_groups: Option<Vec<String>>,
 
+        /// Structured service error.
+        ///
+        /// Information from standard [`PubNub API`] error envelope (if
+        /// service responded with it).
+        ///
+        /// [`PubNub API`]: https://www.pubnub.com/docs
+        server_error: Option<Box<ServerError>>,
+
         /// Raw service response.
         response: Option<Box<TransportResponse>>,
     },
 }
 
+/// PubNub service error.
+///
+/// Information about error from standard [`PubNub API`] error envelope.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::PubNubError;
+///
+/// # fn handle(error: PubNubError) {
+/// if let Some(server_error) = error.server_error() {
+///     match server_error.source.as_deref() {
+///         Some("grant") => println!("Access Manager error: {}", server_error.message),
+///         _ => println!("Service error: {}", server_error.message),
+///     }
+/// }
+/// # }
+/// ```
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerError {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// A message explaining what went wrong.
+    pub message: String,
+
+    /// Service / sub-system which reported an error.
+    pub source: Option<String>,
+
+    /// Additional information about failure reasons.
+    pub details: Vec<ServerErrorDetails>,
+}
+
+/// PubNub service error details.
+///
+/// Describes which part of the request caused an issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerErrorDetails {
+    /// A message explaining what went wrong.
+    pub message: String,
+
+    /// Which part of the request caused an issue (for example `signature`).
+    pub location: String,
+
+    /// Type of request part which caused an issue (for example `query`).
+    pub location_type: String,
+}
+
 impl PubNubError {
     /// Create general API call error.
     ///
@@ -176,10 +366,46 @@ impl PubNubError {
             service: None,
             affected_channels: None,
             affected_channel_groups: None,
+            server_error: None,
             response,
         }
     }
 
+    /// Create error for request builder field which hasn't been set.
+    ///
+    /// Known fields are mapped to the dedicated error variants with hint which
+    /// builder method should be called.
+    pub(crate) fn missing_builder_field(field: &'static str) -> Self {
+        match field {
+            "channel" | "channels" => Self::MissingChannel {
+                details: error_details!("channel is not set, call `channel()` builder method"),
+            },
+            "message" => Self::MissingMessage {
+                details: error_details!("message is not set, call `message()` builder method"),
+            },
+            "ttl" => Self::MissingTtl {
+                details: error_details!("ttl is not set, pass it to `grant_token()`"),
+            },
+            field => Self::MissingField {
+                field,
+                details: error_details!("call builder method with the same name as the field"),
+            },
+        }
+    }
+
+    /// Retrieve structured service error.
+    ///
+    /// Returns `None` if error hasn't been reported by [`PubNub API`] with
+    /// standard error envelope.
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    pub fn server_error(&self) -> Option<&ServerError> {
+        match self {
+            PubNubError::API { server_error, .. } => server_error.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Retrieve attached service response.
     #[cfg(all(
         feature = "std",
@@ -207,6 +433,7 @@ impl PubNubError {
                 service,
                 affected_channels,
                 affected_channel_groups,
+                server_error,
                 ..
             } => PubNubError::API {
                 status: *status,
@@ -214,13 +441,46 @@ impl PubNubError {
                 service: service.clone(),
                 affected_channels: affected_channels.clone(),
                 affected_channel_groups: affected_channel_groups.clone(),
+                server_error: server_error.clone(),
                 response: Some(Box::new(service_response)),
             },
             PubNubError::Transport { details, .. } => PubNubError::Transport {
-                details: details.clone(),
+                details: ErrorDetails::clone(details),
                 response: Some(Box::new(service_response)),
             },
             _ => self,
         }
     }
 }
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn create_error_details() {
+        let channel = "test-channel";
+        let details: ErrorDetails = error_details!("Unable to publish to {channel}");
+
+        #[cfg(not(feature = "static_errors"))]
+        assert_eq!(details, "Unable to publish to test-channel");
+        #[cfg(feature = "static_errors")]
+        assert_eq!(details, "Unable to publish to {channel}");
+    }
+
+    #[test]
+    fn map_missing_builder_field_to_dedicated_error() {
+        assert!(matches!(
+            PubNubError::missing_builder_field("channel"),
+            PubNubError::MissingChannel { .. }
+        ));
+        assert!(matches!(
+            PubNubError::missing_builder_field("ttl"),
+            PubNubError::MissingTtl { .. }
+        ));
+        assert!(matches!(
+            PubNubError::missing_builder_field("token"),
+            PubNubError::MissingField { field: "token", .. }
+        ));
+    }
+}
@@ -2,13 +2,44 @@
 //!
 //! This module provides [`CancellationTask`] which can be used to cancel
 //! managed effects.
-use async_channel::Receiver;
+use async_channel::{Receiver, Sender, TrySendError};
 
 use crate::{
-    core::PubNubError,
-    lib::alloc::{format, string::String},
+    core::{
+        maybe_send::{BoxFuture, MaybeSendFutureExt},
+        PubNubError,
+    },
+    lib::{alloc::string::String, log},
 };
 
+/// Send managed effect cancellation request.
+///
+/// Request is sent without blocking. If cancellation channel is full, returned
+/// future should be spawned to complete sending when space will be available.
+pub(crate) fn send_cancellation(
+    cancel_tx: &Sender<String>,
+    id: String,
+) -> Option<BoxFuture<'static, ()>> {
+    match cancel_tx.try_send(id) {
+        Ok(_) => None,
+        Err(TrySendError::Full(id)) => {
+            let cancel_tx = cancel_tx.clone();
+            Some(
+                async move {
+                    if let Err(error) = cancel_tx.send(id).await {
+                        log::debug!("Unable to send effect cancellation: {error:?}");
+                    }
+                }
+                .boxed_maybe_send(),
+            )
+        }
+        Err(error) => {
+            log::debug!("Unable to send effect cancellation: {error:?}");
+            None
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct CancellationTask {
     cancel_rx: Receiver<String>,
@@ -27,7 +58,7 @@ impl CancellationTask {
                 .recv()
                 .await
                 .map_err(|err| PubNubError::Transport {
-                    details: format!("Cancellation pipe failed: {err}"),
+                    details: error_details!("Cancellation pipe failed: {err}"),
                     response: None,
                 })?
                 .eq(&self.id)
@@ -57,4 +88,31 @@ mod should {
 
         cancel_task.wait_for_cancel().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn send_cancellation_when_channel_is_full() {
+        let (cancel_tx, cancel_rx) = async_channel::bounded(1);
+        assert!(send_cancellation(&cancel_tx, "id1".into()).is_none());
+
+        let fallback = send_cancellation(&cancel_tx, "id2".into());
+        assert!(fallback.is_some());
+
+        let receive = tokio::spawn(async move {
+            (
+                cancel_rx.recv().await.unwrap(),
+                cancel_rx.recv().await.unwrap(),
+            )
+        });
+        fallback.unwrap().await;
+
+        assert_eq!(receive.await.unwrap(), ("id1".into(), "id2".into()));
+    }
+
+    #[test]
+    fn not_panic_when_cancellation_channel_closed() {
+        let (cancel_tx, cancel_rx) = async_channel::bounded::<String>(1);
+        drop(cancel_rx);
+
+        assert!(send_cancellation(&cancel_tx, "id".into()).is_none());
+    }
 }
@@ -1,9 +1,11 @@
 use crate::{
-    core::event_engine::EffectInvocation,
-    lib::alloc::{boxed::Box, string::String, vec::Vec},
+    core::{event_engine::EffectInvocation, maybe_send::BoxFuture, MaybeSend},
+    lib::{
+        alloc::{string::String, vec::Vec},
+        core::future::Future,
+    },
 };
 
-#[async_trait::async_trait]
 pub(crate) trait Effect: Send + Sync {
     type Invocation: EffectInvocation;
 
@@ -14,10 +16,19 @@ pub(crate) trait Effect: Send + Sync {
     fn id(&self) -> String;
 
     /// Run actual effect implementation.
-    async fn run(&self) -> Vec<<Self::Invocation as EffectInvocation>::Event>;
+    fn run(
+        &self,
+    ) -> impl Future<Output = Vec<<Self::Invocation as EffectInvocation>::Event>> + MaybeSend;
 
     /// Cancel any ongoing effect's work.
-    fn cancel(&self);
+    ///
+    /// Cancellation is requested without blocking.
+    ///
+    /// # Returns
+    ///
+    /// Future which should be spawned to complete cancellation request if it
+    /// can't be sent immediately.
+    fn cancel(&self) -> Option<BoxFuture<'static, ()>>;
 
     /// Check whether effect has been cancelled.
     ///
@@ -1,3 +1,4 @@
+use crate::lib::{log, sync::RwLock};
 use crate::{
     core::{
         event_engine::{Effect, EffectHandler, EffectInvocation},
@@ -6,7 +7,6 @@ use crate::{
     lib::alloc::{string::String, sync::Arc, vec, vec::Vec},
 };
 use async_channel::Receiver;
-use spin::rwlock::RwLock;
 
 /// State machine effects dispatcher.
 #[derive(Debug)]
@@ -31,12 +31,15 @@ where
 
     /// `Effect invocation` handling channel.
     ///
-    /// Channel is used to receive submitted `invocations` for new effects
-    /// execution.
-    invocations_channel: Receiver<EI>,
+    /// Channel is used to receive batches of submitted `invocations` (one
+    /// batch per state transition) for new effects execution.
+    invocations_channel: Receiver<Vec<EI>>,
 
     /// Whether dispatcher already started or not.
     started: RwLock<bool>,
+
+    /// Number of spawned effects which are still running.
+    active_effects: RwLock<usize>,
 }
 
 impl<EH, EF, EI> EffectDispatcher<EH, EF, EI>
@@ -46,12 +49,13 @@ where
     EF: Effect<Invocation = EI> + 'static,
 {
     /// Create new an effects dispatcher.
-    pub fn new(handler: EH, channel: Receiver<EI>) -> Self {
+    pub fn new(handler: EH, channel: Receiver<Vec<EI>>) -> Self {
         EffectDispatcher {
             handler,
             managed: Arc::new(RwLock::new(vec![])),
             invocations_channel: channel,
             started: RwLock::new(false),
+            active_effects: RwLock::new(0),
         }
     }
 
@@ -63,43 +67,61 @@ where
     {
         let runtime_clone = runtime.clone();
         let cloned_self = self.clone();
+        *self.started.write() = true;
 
         runtime.spawn(async move {
             log::info!("Event engine has started!");
 
-            loop {
-                let invocation = cloned_self.invocations_channel.recv().await;
-                match invocation {
-                    Ok(invocation) => {
-                        if invocation.is_terminating() {
-                            log::debug!("Received event engine termination invocation");
-                            break;
-                        }
-
-                        log::debug!("Received invocation: {}", invocation.id());
-                        let effect = cloned_self.dispatch(&invocation);
-                        let task_completion = completion.clone();
-
-                        if let Some(effect) = effect {
-                            log::debug!("Dispatched effect: {}", effect.name());
-                            let cloned_self = cloned_self.clone();
-
-                            runtime_clone.spawn(async move {
-                                // There is no need to spawn effect which already has been
-                                // cancelled.
-                                if effect.is_cancelled() {
-                                    return;
-                                }
-                                let events = effect.run().await;
-
-                                if invocation.is_managed() {
-                                    cloned_self.remove_managed_effect(effect.id());
-                                }
-
-                                task_completion(events);
-                            });
-                        } else if invocation.is_cancelling() {
-                            log::debug!("Dispatched effect: {}", invocation.id());
+            'dispatch: loop {
+                let invocations = cloned_self.invocations_channel.recv().await;
+                match invocations {
+                    // Invocations from the same batch are dispatched in order in
+                    // which they have been created by transition.
+                    Ok(invocations) => {
+                        for invocation in invocations {
+                            if invocation.is_terminating() {
+                                log::debug!("Received event engine termination invocation");
+                                cloned_self.cancel_managed_effects(&runtime_clone);
+                                break 'dispatch;
+                            }
+
+                            log::debug!("Received invocation: {}", invocation.id());
+                            let effect = cloned_self.dispatch(&invocation, &runtime_clone);
+                            let task_completion = completion.clone();
+
+                            if let Some(effect) = effect {
+                                log::debug!("Dispatched effect: {}", effect.name());
+                                let cloned_self = cloned_self.clone();
+                                *cloned_self.active_effects.write() += 1;
+
+                                runtime_clone.spawn(async move {
+                                    // There is no need to spawn effect which already has been
+                                    // cancelled.
+                                    if !effect.is_cancelled() {
+                                        #[cfg(feature = "tracing")]
+                                        let events = tracing::Instrument::instrument(
+                                            effect.run(),
+                                            tracing::debug_span!(
+                                                "pubnub.event_engine.effect",
+                                                effect = %effect.name()
+                                            ),
+                                        )
+                                        .await;
+                                        #[cfg(not(feature = "tracing"))]
+                                        let events = effect.run().await;
+
+                                        if invocation.is_managed() {
+                                            cloned_self.remove_managed_effect(effect.id());
+                                        }
+
+                                        task_completion(events);
+                                    }
+
+                                    *cloned_self.active_effects.write() -= 1;
+                                });
+                            } else if invocation.is_cancelling() {
+                                log::debug!("Dispatched effect: {}", invocation.id());
+                            }
                         }
                     }
                     Err(err) => {
@@ -111,12 +133,21 @@ where
             *cloned_self.started.write() = false;
             log::info!("Event engine has stopped!");
         });
+    }
 
-        *self.started.write() = true;
+    /// Whether dispatcher stopped or not.
+    ///
+    /// Dispatcher considered stopped when `invocations` processing loop
+    /// completed and all spawned effects finished.
+    pub fn is_stopped(&self) -> bool {
+        !*self.started.read() && *self.active_effects.read() == 0
     }
 
     /// Dispatch effect associated with `invocation`.
-    pub fn dispatch(&self, invocation: &EI) -> Option<Arc<EF>> {
+    pub fn dispatch<R>(&self, invocation: &EI, runtime: &R) -> Option<Arc<EF>>
+    where
+        R: Runtime + 'static,
+    {
         if let Some(effect) = self.handler.create(invocation) {
             let effect = Arc::new(effect);
 
@@ -128,7 +159,7 @@ where
             Some(effect)
         } else {
             if invocation.is_cancelling() {
-                self.cancel_effect(invocation);
+                self.cancel_effect(invocation, runtime);
             }
 
             None
@@ -139,13 +170,30 @@ where
     ///
     /// Effects with managed lifecycle can be cancelled by corresponding effect
     /// invocations.
-    fn cancel_effect(&self, invocation: &EI) {
+    fn cancel_effect<R>(&self, invocation: &EI, runtime: &R)
+    where
+        R: Runtime + 'static,
+    {
         let mut managed = self.managed.write();
         if let Some(position) = managed.iter().position(|e| invocation.cancelling_effect(e)) {
-            managed.remove(position).cancel();
+            if let Some(cancellation) = managed.remove(position).cancel() {
+                runtime.spawn(cancellation);
+            }
         }
     }
 
+    /// Cancel all managed effects.
+    fn cancel_managed_effects<R>(&self, runtime: &R)
+    where
+        R: Runtime + 'static,
+    {
+        let mut managed = self.managed.write();
+        managed
+            .drain(..)
+            .filter_map(|effect| effect.cancel())
+            .for_each(|cancellation| runtime.spawn(cancellation));
+    }
+
     /// Remove managed effect.
     fn remove_managed_effect(&self, effect_id: String) {
         let mut managed = self.managed.write();
@@ -158,7 +206,7 @@ where
 #[cfg(test)]
 mod should {
     use super::*;
-    use crate::core::event_engine::Event;
+    use crate::core::{event_engine::Event, maybe_send::BoxFuture};
     use std::future::Future;
 
     struct TestEvent;
@@ -175,7 +223,6 @@ mod should {
         Three,
     }
 
-    #[async_trait::async_trait]
     impl Effect for TestEffect {
         type Invocation = TestInvocation;
 
@@ -199,8 +246,8 @@ mod should {
             vec![]
         }
 
-        fn cancel(&self) {
-            // Do nothing.
+        fn cancel(&self) -> Option<BoxFuture<'static, ()>> {
+            None
         }
 
         fn is_cancelled(&self) -> bool {
@@ -264,7 +311,6 @@ mod should {
     #[derive(Clone)]
     struct TestRuntime {}
 
-    #[async_trait::async_trait]
     impl Runtime for TestRuntime {
         fn spawn<R>(&self, _future: impl Future<Output = R> + Send + 'static)
         where
@@ -284,9 +330,9 @@ mod should {
 
     #[test]
     fn create_not_managed_effect() {
-        let (_tx, rx) = async_channel::bounded::<TestInvocation>(5);
+        let (_tx, rx) = async_channel::bounded::<Vec<TestInvocation>>(5);
         let dispatcher = Arc::new(EffectDispatcher::new(TestEffectHandler {}, rx));
-        let effect = dispatcher.dispatch(&TestInvocation::One);
+        let effect = dispatcher.dispatch(&TestInvocation::One, &TestRuntime {});
 
         assert_eq!(
             dispatcher.managed.read().len(),
@@ -299,9 +345,9 @@ mod should {
 
     #[tokio::test]
     async fn create_managed_effect() {
-        let (_tx, rx) = async_channel::bounded::<TestInvocation>(5);
+        let (_tx, rx) = async_channel::bounded::<Vec<TestInvocation>>(5);
         let dispatcher = Arc::new(EffectDispatcher::new(TestEffectHandler {}, rx));
-        let effect = dispatcher.dispatch(&TestInvocation::Two);
+        let effect = dispatcher.dispatch(&TestInvocation::Two, &TestRuntime {});
 
         assert_eq!(
             dispatcher.managed.read().len(),
@@ -314,10 +360,11 @@ mod should {
 
     #[test]
     fn cancel_managed_effect() {
-        let (_tx, rx) = async_channel::bounded::<TestInvocation>(5);
+        let (_tx, rx) = async_channel::bounded::<Vec<TestInvocation>>(5);
         let dispatcher = Arc::new(EffectDispatcher::new(TestEffectHandler {}, rx));
-        dispatcher.dispatch(&TestInvocation::Three);
-        let cancellation_effect = dispatcher.dispatch(&TestInvocation::CancelThree);
+        dispatcher.dispatch(&TestInvocation::Three, &TestRuntime {});
+        let cancellation_effect =
+            dispatcher.dispatch(&TestInvocation::CancelThree, &TestRuntime {});
 
         assert_eq!(
             dispatcher.managed.read().len(),
@@ -1,10 +1,17 @@
 //! Event Engine module
 
-use async_channel::Sender;
-use log::error;
-use spin::rwlock::RwLock;
+use crate::lib::log::{self, error};
+use crate::lib::sync::RwLock;
+use async_channel::{Sender, TrySendError};
 
-use crate::{core::runtime::Runtime, lib::alloc::sync::Arc};
+use crate::{
+    core::runtime::{Runtime, RuntimeSupport},
+    lib::alloc::{collections::VecDeque, sync::Arc, vec, vec::Vec},
+};
+
+/// Default capacity (in invocation batches) of the effect invocations
+/// channel.
+pub(crate) const DEFAULT_INVOCATIONS_CAPACITY: usize = 100;
 
 #[doc(inline)]
 pub(crate) use effect::Effect;
@@ -57,8 +64,18 @@ where
 
     /// `Effect invocation` submission channel.
     ///
-    /// Channel is used to submit `invocations` for new effects execution.
-    effect_dispatcher_channel: Sender<EI>,
+    /// Channel is used to submit batches of `invocations` (one batch per state
+    /// transition) for new effects execution.
+    effect_dispatcher_channel: Sender<Vec<EI>>,
+
+    /// Invocation batches which didn't fit into the submission channel.
+    ///
+    /// Batches are moved into the channel asynchronously (in order in which
+    /// they have been created) when space will be available.
+    overflow: Arc<RwLock<InvocationsOverflow<EI>>>,
+
+    /// Runtime environment used to drain overflow invocations.
+    runtime: RuntimeSupport,
 
     /// Current event engine state.
     current_state: RwLock<S>,
@@ -72,6 +89,16 @@ where
     active: RwLock<bool>,
 }
 
+/// Invocation batches waiting for space in submission channel.
+#[derive(Debug)]
+struct InvocationsOverflow<EI> {
+    /// Invocation batches in order of creation.
+    invocations: VecDeque<Vec<EI>>,
+
+    /// Whether overflow invocations are being moved into the channel or not.
+    draining: bool,
+}
+
 impl<S, EH, EF, EI> EventEngine<S, EH, EF, EI>
 where
     S: State<State = S, Invocation = EI> + Send + Sync + 'static,
@@ -80,16 +107,38 @@ where
     EI: EffectInvocation<Effect = EF> + Send + Sync + 'static,
 {
     /// Create [`EventEngine`] with initial state for state machine.
+    ///
+    /// > Note: Code actually used in tests.
+    #[allow(dead_code)]
     pub fn new<R>(handler: EH, state: S, runtime: R) -> Arc<Self>
     where
-        R: Runtime + 'static,
+        R: Runtime + Sync + 'static,
+    {
+        Self::with_capacity(handler, state, runtime, Some(DEFAULT_INVOCATIONS_CAPACITY))
+    }
+
+    /// Create [`EventEngine`] with initial state for state machine and
+    /// effect invocations channel `capacity` (in invocation batches).
+    ///
+    /// Channel will be unbounded if `capacity` is not set.
+    pub fn with_capacity<R>(handler: EH, state: S, runtime: R, capacity: Option<usize>) -> Arc<Self>
+    where
+        R: Runtime + Sync + 'static,
     {
-        let (channel_tx, channel_rx) = async_channel::bounded::<EI>(100);
+        let (channel_tx, channel_rx) = match capacity {
+            Some(capacity) => async_channel::bounded::<Vec<EI>>(capacity.max(1)),
+            None => async_channel::unbounded::<Vec<EI>>(),
+        };
         let effect_dispatcher = Arc::new(EffectDispatcher::new(handler, channel_rx));
 
         let engine = Arc::new(EventEngine {
             effect_dispatcher,
             effect_dispatcher_channel: channel_tx,
+            overflow: Arc::new(RwLock::new(InvocationsOverflow {
+                invocations: VecDeque::new(),
+                draining: false,
+            })),
+            runtime: RuntimeSupport::new(Arc::new(runtime.clone())),
             current_state: RwLock::new(state),
             active: RwLock::new(true),
         });
@@ -100,9 +149,6 @@ where
     }
 
     /// Retrieve current engine state.
-    ///
-    /// > Note: Code actually used in tests.
-    #[allow(dead_code)]
     pub(crate) fn current_state(&self) -> S {
         (*self.current_state.read()).clone()
     }
@@ -118,6 +164,13 @@ where
         };
 
         log::debug!("Processing event: {}", event.id());
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "pubnub.event_engine.transition",
+            event = event.id(),
+            invocations = tracing::field::Empty,
+        )
+        .entered();
 
         let transition = {
             let state = self.current_state.read();
@@ -133,23 +186,71 @@ where
     ///
     /// This method is responsible for transition maintenance:
     /// * update current state
-    /// * call effects dispatcher to process effect invocation
+    /// * submit transition's effect invocations to the effects dispatcher as
+    ///   a single batch
     fn process_transition(&self, transition: Transition<S::State, S::Invocation>) {
         if !*self.active.read() {
             log::debug!("Can't process transition because the event engine is not active.");
             return;
         };
 
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("invocations", transition.invocations.len());
+
         if let Some(state) = transition.state {
             let mut writable_state = self.current_state.write();
             *writable_state = state;
         }
 
-        transition.invocations.into_iter().for_each(|invocation| {
-            if let Err(error) = self.effect_dispatcher_channel.send_blocking(invocation) {
-                error!("Unable dispatch invocation: {error:?}")
+        if !transition.invocations.is_empty() {
+            self.dispatch(transition.invocations);
+        }
+    }
+
+    /// Submit batch of effect `invocations` for dispatch.
+    ///
+    /// Batch is sent without blocking. If submission channel is full, batch
+    /// will be queued and sent asynchronously when space will be available.
+    ///
+    /// Invocations from the batch are dispatched in order of their position in
+    /// the batch and before invocations from batches submitted later.
+    fn dispatch(&self, invocations: Vec<EI>) {
+        let mut overflow = self.overflow.write();
+
+        if !overflow.draining {
+            match self.effect_dispatcher_channel.try_send(invocations) {
+                Ok(_) => return,
+                Err(TrySendError::Full(invocations)) => {
+                    overflow.invocations.push_back(invocations);
+                    overflow.draining = true;
+                }
+                Err(error) => {
+                    error!("Unable dispatch invocation: {error:?}");
+                    return;
+                }
             }
-        });
+
+            let overflow = self.overflow.clone();
+            let channel = self.effect_dispatcher_channel.clone();
+            self.runtime.spawn(async move {
+                loop {
+                    let Some(invocations) = ({
+                        let mut overflow = overflow.write();
+                        let invocations = overflow.invocations.pop_front();
+                        overflow.draining = invocations.is_some();
+                        invocations
+                    }) else {
+                        break;
+                    };
+
+                    if let Err(error) = channel.send(invocations).await {
+                        error!("Unable dispatch invocation: {error:?}")
+                    }
+                }
+            });
+        } else {
+            overflow.invocations.push_back(invocations);
+        }
     }
 
     /// Start state machine.
@@ -171,6 +272,20 @@ where
         );
     }
 
+    /// Whether state machine stopped or not.
+    ///
+    /// State machine considered stopped when it has been deactivated and all
+    /// in-flight effects finished or cancelled.
+    pub fn is_stopped(&self) -> bool {
+        !*self.active.read() && self.effect_dispatcher.is_stopped()
+    }
+
+    /// Number of effect invocation batches waiting for dispatch.
+    #[cfg(feature = "metrics")]
+    pub fn queue_depth(&self) -> usize {
+        self.effect_dispatcher_channel.len() + self.overflow.read().invocations.len()
+    }
+
     /// Stop state machine using specific invocation.
     ///
     /// > Note: Should be provided effect information which respond with `true`
@@ -180,18 +295,19 @@ where
             *self.active.write() = false;
         }
 
-        if let Err(error) = self.effect_dispatcher_channel.send_blocking(invocation) {
-            error!("Unable dispatch invocation: {error:?}")
-        }
+        self.dispatch(vec![invocation]);
     }
 }
 
 #[cfg(test)]
 mod should {
     use super::*;
-    use crate::lib::{
-        alloc::{vec, vec::Vec},
-        core::future::Future,
+    use crate::{
+        core::maybe_send::BoxFuture,
+        lib::{
+            alloc::{vec, vec::Vec},
+            core::future::Future,
+        },
     };
 
     #[derive(Debug, Clone, PartialEq)]
@@ -286,7 +402,6 @@ mod should {
         Three,
     }
 
-    #[async_trait::async_trait]
     impl Effect for TestEffect {
         type Invocation = TestInvocation;
 
@@ -311,8 +426,8 @@ mod should {
             vec![]
         }
 
-        fn cancel(&self) {
-            // Do nothing.
+        fn cancel(&self) -> Option<BoxFuture<'static, ()>> {
+            None
         }
 
         fn is_cancelled(&self) -> bool {
@@ -370,7 +485,6 @@ mod should {
     #[derive(Clone)]
     struct TestRuntime {}
 
-    #[async_trait::async_trait]
     impl Runtime for TestRuntime {
         fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static)
         where
@@ -434,6 +548,41 @@ mod should {
         assert!(matches!(engine.current_state(), TestState::Started));
     }
 
+    #[tokio::test]
+    async fn not_block_when_invocations_channel_is_full() {
+        let engine = EventEngine::with_capacity(
+            TestEffectHandler {},
+            TestState::NotStarted,
+            TestRuntime {},
+            Some(1),
+        );
+
+        (0..10).for_each(|_| {
+            engine.process(&TestEvent::One);
+            engine.process(&TestEvent::Two);
+            engine.process(&TestEvent::Three);
+            engine.process(&TestEvent::One);
+        });
+
+        assert!(matches!(engine.current_state(), TestState::NotStarted));
+        assert!(!engine.overflow.read().invocations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn submit_transition_invocations_as_single_batch() {
+        let engine = EventEngine::with_capacity(
+            TestEffectHandler {},
+            TestState::NotStarted,
+            TestRuntime {},
+            Some(1),
+        );
+
+        engine.process(&TestEvent::One);
+
+        assert_eq!(engine.effect_dispatcher_channel.len(), 1);
+        assert!(engine.overflow.read().invocations.is_empty());
+    }
+
     #[tokio::test]
     async fn run_effect() {
         let _engine = EventEngine::new(TestEffectHandler {}, TestState::NotStarted, TestRuntime {});
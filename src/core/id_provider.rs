@@ -0,0 +1,101 @@
+//! # Identifier provider module
+//!
+//! This module contains the [`IdProvider`] trait, which is used by
+//! [`PubNubClientInstance`] to generate identifiers for client instance,
+//! requests and event engine effects.
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+use crate::lib::{
+    alloc::{
+        format,
+        string::{String, ToString},
+    },
+    core::fmt::Debug,
+    sync::Mutex,
+};
+
+/// Identifiers source trait.
+///
+/// Provider used by the client to generate unique identifiers (instance id,
+/// request id and event engine effect ids). Custom implementation can be used
+/// on devices without random numbers generator (`no_std`) or in tests which
+/// require stable identifiers.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::IdProvider;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// #[derive(Debug, Default)]
+/// struct MyIdProvider {
+///     counter: AtomicU64,
+/// }
+///
+/// impl IdProvider for MyIdProvider {
+///     fn next_id(&self) -> String {
+///         format!("device-1-{}", self.counter.fetch_add(1, Ordering::SeqCst))
+///     }
+/// }
+/// ```
+pub trait IdProvider: Debug + Send + Sync {
+    /// Generate new unique identifier.
+    fn next_id(&self) -> String;
+}
+
+/// Random UUID identifiers provider.
+///
+/// Default [`IdProvider`] implementation which generates random (v4) UUIDs.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UuidProvider;
+
+impl IdProvider for UuidProvider {
+    fn next_id(&self) -> String {
+        uuid::Uuid::new_v4().to_string()
+    }
+}
+
+/// Sequential identifiers provider.
+///
+/// Deterministic [`IdProvider`] implementation which generates identifiers
+/// from `prefix` and incrementing counter (`prefix-0`, `prefix-1`, ...).
+///
+/// # Examples
+/// ```
+/// use pubnub::core::{IdProvider, SequentialIdProvider};
+///
+/// let provider = SequentialIdProvider::new("test");
+///
+/// assert_eq!(provider.next_id(), "test-0");
+/// assert_eq!(provider.next_id(), "test-1");
+/// ```
+#[derive(Debug)]
+pub struct SequentialIdProvider {
+    /// Prefix of generated identifiers.
+    prefix: String,
+
+    /// Next identifier sequence number.
+    next: Mutex<u64>,
+}
+
+impl SequentialIdProvider {
+    /// Create provider which generates identifiers with `prefix`.
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            prefix: prefix.into(),
+            next: Mutex::new(0),
+        }
+    }
+}
+
+impl IdProvider for SequentialIdProvider {
+    fn next_id(&self) -> String {
+        let mut next = self.next.lock();
+        let id = format!("{}-{}", self.prefix, *next);
+        *next = next.wrapping_add(1);
+        id
+    }
+}
@@ -0,0 +1,57 @@
+//! # Maybe `Send` module
+//!
+//! This module contains [`MaybeSend`] trait and [`BoxFuture`] type which
+//! require `Send` only on targets where futures can be moved between threads.
+//!
+//! Browser APIs used on `wasm32` targets produce futures which aren't `Send`
+//! (they hold JavaScript objects), but there they are always polled on the
+//! same thread. With these abstractions the same Subscribe and Presence APIs
+//! can be used on native and `wasm32` targets.
+
+#[cfg(all(feature = "std", feature = "subscribe"))]
+use crate::lib::{alloc::boxed::Box, core::future::Future};
+
+/// Marker trait which is `Send` on native targets.
+///
+/// On `wasm32` targets trait is implemented for all types.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T> MaybeSend for T where T: Send + ?Sized {}
+
+/// Marker trait which is `Send` on native targets.
+///
+/// On `wasm32` targets trait is implemented for all types.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T where T: ?Sized {}
+
+/// Owned dynamically typed future.
+///
+/// Future is `Send` on native targets.
+#[cfg(all(feature = "std", feature = "subscribe", not(target_arch = "wasm32")))]
+pub type BoxFuture<'a, T> = futures::future::BoxFuture<'a, T>;
+
+/// Owned dynamically typed future.
+///
+/// Future is `Send` on native targets.
+#[cfg(all(feature = "std", feature = "subscribe", target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = futures::future::LocalBoxFuture<'a, T>;
+
+/// Extension trait to box futures into [`BoxFuture`].
+#[cfg(all(feature = "std", feature = "subscribe"))]
+pub(crate) trait MaybeSendFutureExt: Future {
+    /// Wrap the future in a box, pinning it.
+    fn boxed_maybe_send<'a>(self) -> BoxFuture<'a, Self::Output>
+    where
+        Self: Sized + MaybeSend + 'a,
+    {
+        Box::pin(self)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "subscribe"))]
+impl<F> MaybeSendFutureExt for F where F: Future + ?Sized {}
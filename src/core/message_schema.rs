@@ -0,0 +1,46 @@
+//! # Message schema module
+//!
+//! This module contains the [`MessageSchema`] trait, which is used by
+//! [`PubNubClientInstance`] to validate outgoing messages before publish
+//! request will be built.
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+use crate::lib::{alloc::string::String, core::fmt::Debug};
+
+/// Outgoing messages validator trait.
+///
+/// Validator receives serialized (not encrypted) message and name of the
+/// channel to which it will be published. Validation failure reason is
+/// returned to the caller with [`PubNubError::MessageValidation`] error.
+///
+/// Validator can be used to enforce message contracts (for example, with
+/// JSON Schema validator crate).
+///
+/// # Examples
+/// ```
+/// use pubnub::core::MessageSchema;
+///
+/// #[derive(Debug)]
+/// struct MaxSizeSchema(usize);
+///
+/// impl MessageSchema for MaxSizeSchema {
+///     fn validate(&self, _channel: &str, message: &[u8]) -> Result<(), String> {
+///         if message.len() > self.0 {
+///             return Err(format!("message is larger than {} bytes", self.0));
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// [`PubNubError::MessageValidation`]: crate::core::PubNubError::MessageValidation
+pub trait MessageSchema: Debug + Send + Sync {
+    /// Validate serialized `message` which will be published to `channel`.
+    ///
+    /// # Errors
+    ///
+    /// Should return validation failure reason if `message` doesn't conform
+    /// to the schema.
+    fn validate(&self, channel: &str, message: &[u8]) -> Result<(), String>;
+}
@@ -0,0 +1,229 @@
+//! # Metrics module
+//!
+//! This module contains the [`ClientMetrics`] snapshot and [`Recorder`] trait
+//! which can be used to observe [`PubNubClientInstance`] activity (requests
+//! count, latency and event engines load).
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+use crate::lib::sync::RwLock;
+
+use crate::{
+    core::retry_policy::Endpoint,
+    lib::{
+        alloc::{collections::VecDeque, sync::Arc, vec::Vec},
+        collections::HashMap,
+        core::fmt::Debug,
+    },
+};
+
+/// Maximum number of latency samples stored for each endpoint group.
+const LATENCY_SAMPLES: usize = 1000;
+
+/// Metrics recorder trait.
+///
+/// Recorder receives information about each completed request and can be used
+/// to pipe it into external metrics systems (like Prometheus or `metrics`
+/// crate).
+///
+/// # Examples
+/// ```
+/// use pubnub::core::{metrics::Recorder, retry_policy::Endpoint};
+///
+/// #[derive(Debug)]
+/// struct MyRecorder;
+///
+/// impl Recorder for MyRecorder {
+///     fn record_request(&self, endpoint: Endpoint, status: Option<u16>, latency: u64) {
+///         println!("{endpoint:?} responded with {status:?} in {latency}µs");
+///     }
+/// }
+/// ```
+pub trait Recorder: Debug + Send + Sync {
+    /// Record completed request.
+    ///
+    /// * `endpoint` - endpoint group to which request has been sent,
+    /// * `status` - HTTP status code (`None` if request failed before service
+    ///   response has been received),
+    /// * `latency` - request duration in microseconds.
+    fn record_request(&self, endpoint: Endpoint, status: Option<u16>, latency: u64);
+}
+
+/// Request latency percentiles.
+///
+/// Latency (in microseconds) calculated from the most recent requests to the
+/// endpoint group.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    /// Median request latency.
+    pub p50: u64,
+
+    /// 90th percentile of request latency.
+    pub p90: u64,
+
+    /// 99th percentile of request latency.
+    pub p99: u64,
+}
+
+/// Client metrics snapshot.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClientMetrics {
+    /// Number of completed requests by endpoint group and HTTP status code.
+    ///
+    /// Requests which failed before service response has been received are
+    /// counted with `None` status code.
+    pub requests: HashMap<(Endpoint, Option<u16>), u64>,
+
+    /// Request latency percentiles by endpoint group.
+    pub latency: HashMap<Endpoint, LatencyPercentiles>,
+
+    /// Number of active subscriptions and subscription sets.
+    pub active_subscriptions: usize,
+
+    /// Number of effect invocation batches (one per state transition) waiting
+    /// for dispatch by subscribe event engine.
+    pub subscribe_queue_depth: usize,
+
+    /// Number of effect invocation batches (one per state transition) waiting
+    /// for dispatch by presence event engine.
+    pub presence_queue_depth: usize,
+}
+
+/// Client metrics collector.
+///
+/// Aggregates requests information reported by transport middleware and
+/// forwards it to the registered recorders.
+#[derive(Debug, Default)]
+pub(crate) struct MetricsCollector {
+    /// Number of completed requests by endpoint group and status code.
+    requests: RwLock<HashMap<(Endpoint, Option<u16>), u64>>,
+
+    /// Most recent request latencies by endpoint group.
+    latencies: RwLock<HashMap<Endpoint, VecDeque<u64>>>,
+
+    /// Registered metrics recorders.
+    recorders: RwLock<Vec<Arc<dyn Recorder>>>,
+}
+
+impl MetricsCollector {
+    /// Register metrics `recorder`.
+    pub(crate) fn add_recorder(&self, recorder: Arc<dyn Recorder>) {
+        self.recorders.write().push(recorder);
+    }
+
+    /// Record completed request.
+    pub(crate) fn record_request(&self, endpoint: Endpoint, status: Option<u16>, latency: u64) {
+        {
+            *self.requests.write().entry((endpoint, status)).or_default() += 1;
+        }
+
+        {
+            let mut latencies = self.latencies.write();
+            let samples = latencies.entry(endpoint).or_default();
+            if samples.len() >= LATENCY_SAMPLES {
+                samples.pop_front();
+            }
+            samples.push_back(latency);
+        }
+
+        let recorders = self.recorders.read().clone();
+        recorders
+            .iter()
+            .for_each(|recorder| recorder.record_request(endpoint, status, latency));
+    }
+
+    /// Create snapshot of collected requests metrics.
+    pub(crate) fn snapshot(&self) -> ClientMetrics {
+        let latency = self
+            .latencies
+            .read()
+            .iter()
+            .map(|(endpoint, samples)| {
+                let mut samples = samples.iter().copied().collect::<Vec<u64>>();
+                samples.sort_unstable();
+
+                let percentiles = LatencyPercentiles {
+                    p50: Self::percentile(&samples, 50),
+                    p90: Self::percentile(&samples, 90),
+                    p99: Self::percentile(&samples, 99),
+                };
+                (*endpoint, percentiles)
+            })
+            .collect();
+
+        ClientMetrics {
+            requests: self.requests.read().clone(),
+            latency,
+            ..Default::default()
+        }
+    }
+
+    /// Nearest-rank percentile of sorted `samples`.
+    fn percentile(samples: &[u64], percentile: usize) -> u64 {
+        if samples.is_empty() {
+            return 0;
+        }
+
+        let rank = (percentile * samples.len()).div_ceil(100);
+        samples[rank.saturating_sub(1)]
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct CountingRecorder {
+        calls: AtomicUsize,
+    }
+
+    impl Recorder for CountingRecorder {
+        fn record_request(&self, _endpoint: Endpoint, _status: Option<u16>, _latency: u64) {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn count_requests_by_endpoint_and_status() {
+        let collector = MetricsCollector::default();
+        collector.record_request(Endpoint::MessageSend, Some(200), 10);
+        collector.record_request(Endpoint::MessageSend, Some(200), 20);
+        collector.record_request(Endpoint::MessageSend, None, 30);
+        collector.record_request(Endpoint::Presence, Some(403), 40);
+
+        let metrics = collector.snapshot();
+        assert_eq!(metrics.requests[&(Endpoint::MessageSend, Some(200))], 2);
+        assert_eq!(metrics.requests[&(Endpoint::MessageSend, None)], 1);
+        assert_eq!(metrics.requests[&(Endpoint::Presence, Some(403))], 1);
+    }
+
+    #[test]
+    fn calculate_latency_percentiles() {
+        let collector = MetricsCollector::default();
+        (1..=100)
+            .for_each(|latency| collector.record_request(Endpoint::Subscribe, Some(200), latency));
+
+        let metrics = collector.snapshot();
+        assert_eq!(
+            metrics.latency[&Endpoint::Subscribe],
+            LatencyPercentiles {
+                p50: 50,
+                p90: 90,
+                p99: 99
+            }
+        );
+    }
+
+    #[test]
+    fn forward_requests_to_recorders() {
+        let recorder = Arc::new(CountingRecorder::default());
+        let collector = MetricsCollector::default();
+        collector.add_recorder(recorder.clone());
+
+        collector.record_request(Endpoint::MessageSend, Some(200), 10);
+        collector.record_request(Endpoint::MessageSend, Some(200), 10);
+        assert_eq!(recorder.calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -10,14 +10,18 @@
 //! [`pubnub`]: ../index.html
 
 #[doc(inline)]
-pub use error::PubNubError;
+pub use error::{ErrorDetails, PubNubError, ServerError, ServerErrorDetails};
+#[macro_use]
 pub mod error;
 
 #[cfg(any(
     feature = "publish",
     feature = "access",
     feature = "subscribe",
-    feature = "presence"
+    feature = "presence",
+    feature = "history",
+    feature = "message_actions",
+    feature = "objects"
 ))]
 pub(crate) mod service_response;
 
@@ -28,14 +32,28 @@ pub use transport::blocking;
 pub use transport::Transport;
 pub mod transport;
 
+#[cfg(feature = "async_trait_compat")]
+pub mod async_trait_compat;
+
 #[doc(inline)]
 pub use transport_request::{TransportMethod, TransportRequest};
 pub mod transport_request;
 
+#[doc(no_inline)]
+pub use bytes::Bytes;
 #[doc(inline)]
 pub use transport_response::TransportResponse;
 pub mod transport_response;
 
+#[doc(inline)]
+pub use pubnub_response::{PubNubResponse, ResponseMetadata};
+#[macro_use]
+pub mod pubnub_response;
+
+#[cfg(any(feature = "history", feature = "objects"))]
+#[macro_use]
+pub(crate) mod detached_request;
+
 // TODO: Retry policy can be implemented for `no_std` subscribe
 //      when `no_std` event engine is implemented.
 #[cfg(feature = "std")]
@@ -52,12 +70,16 @@ pub use deserialize::Deserialize;
 pub mod deserialize;
 
 #[doc(inline)]
-pub use serializer::Serializer;
+pub use serializer::{SerializeWith, Serializer};
 pub mod serializer;
 #[doc(inline)]
 pub use serialize::Serialize;
 pub mod serialize;
 
+#[doc(inline)]
+pub use pubnub_message::{PubNubMessage, PubNubMessageSerializer};
+pub mod pubnub_message;
+
 #[doc(inline)]
 pub use crypto_provider::CryptoProvider;
 pub mod crypto_provider;
@@ -66,15 +88,55 @@ pub mod crypto_provider;
 pub use cryptor::{Cryptor, EncryptedData};
 pub mod cryptor;
 
+#[doc(inline)]
+pub use clock::Clock;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use clock::SystemClock;
+pub mod clock;
+
+#[doc(inline)]
+pub use timetoken::Timetoken;
+pub mod timetoken;
+
+#[doc(inline)]
+pub use id_provider::{IdProvider, SequentialIdProvider, UuidProvider};
+pub mod id_provider;
+
+#[cfg(feature = "publish")]
+#[doc(inline)]
+pub use message_schema::MessageSchema;
+#[cfg(feature = "publish")]
+pub mod message_schema;
+
 #[cfg(all(feature = "std", feature = "subscribe"))]
 pub(crate) mod event_engine;
 
+#[doc(inline)]
+pub use maybe_send::MaybeSend;
+pub mod maybe_send;
+
 #[cfg(all(feature = "std", feature = "subscribe"))]
 #[doc(inline)]
 pub use runtime::Runtime;
 #[cfg(all(feature = "std", feature = "subscribe"))]
 pub mod runtime;
 
+#[cfg(all(feature = "std", feature = "subscribe"))]
+#[doc(inline)]
+pub use network_monitor::{NetworkMonitor, NetworkStatus};
+#[cfg(all(feature = "std", feature = "subscribe"))]
+pub mod network_monitor;
+
+#[cfg(feature = "entropy_source")]
+pub mod entropy;
+
+#[cfg(feature = "metrics")]
+#[doc(inline)]
+pub use metrics::{ClientMetrics, Recorder};
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
 #[doc(inline)]
 pub use data_stream::DataStream;
 pub mod data_stream;
@@ -85,7 +147,11 @@ pub(crate) mod utils;
 pub use types::ScalarValue;
 
 #[doc(inline)]
-pub(crate) use entity::PubNubEntity;
+pub use channel_name::ChannelName;
+pub mod channel_name;
+
+#[doc(inline)]
+pub(crate) use entity::{EntitiesCache, PubNubEntity};
 pub(crate) mod entity;
 
 #[doc(inline)]
@@ -0,0 +1,201 @@
+//! # Network monitor module
+//!
+//! This module contains the [`NetworkMonitor`] trait, which is used by
+//! [`PubNubClientInstance`] to suspend real-time updates and presence event
+//! engines while network is unreachable and reconnect them as soon as
+//! connectivity returns.
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+use crate::{
+    core::{
+        maybe_send::{BoxFuture, MaybeSend, MaybeSendFutureExt},
+        runtime::{Runtime, RuntimeSupport},
+    },
+    lib::{
+        alloc::sync::Arc,
+        core::{
+            fmt::{Debug, Formatter, Result},
+            future::Future,
+        },
+    },
+};
+
+/// Network reachability status.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NetworkStatus {
+    /// Network is reachable.
+    Up,
+
+    /// Network is unreachable.
+    Down,
+}
+
+/// Network monitor trait.
+///
+/// Monitor used by the client to get notified about network reachability
+/// changes. When network goes down, real-time updates and presence event
+/// engines will be disconnected (no retry attempts while offline) and
+/// reconnected when network goes up.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::{NetworkMonitor, NetworkStatus};
+///
+/// #[derive(Debug)]
+/// struct MyMonitor;
+///
+/// impl NetworkMonitor for MyMonitor {
+///     async fn status_change(&self, current: NetworkStatus) -> NetworkStatus {
+///         // e.g. wait for OS notification about connectivity change
+///         current
+///     }
+/// }
+/// ```
+///
+/// On `wasm32` targets returned future isn't required to be `Send` (see
+/// [`MaybeSend`]).
+pub trait NetworkMonitor: Debug + Send + Sync {
+    /// Wait for network reachability change.
+    ///
+    /// Future should resolve with new network status when it is different
+    /// from `current`.
+    fn status_change(
+        &self,
+        current: NetworkStatus,
+    ) -> impl Future<Output = NetworkStatus> + MaybeSend;
+}
+
+/// Dynamically dispatched [`NetworkMonitor`].
+///
+/// [`NetworkMonitor`] returns `impl Future` and can't be used as trait object,
+/// so monitors are stored by the client behind this trait.
+pub(crate) trait DynNetworkMonitor: Debug + Send + Sync {
+    /// Wait for network reachability change.
+    ///
+    /// See [`NetworkMonitor::status_change`] for more information.
+    fn status_change_boxed(&self, current: NetworkStatus) -> BoxFuture<'_, NetworkStatus>;
+}
+
+impl<M> DynNetworkMonitor for M
+where
+    M: NetworkMonitor,
+{
+    fn status_change_boxed(&self, current: NetworkStatus) -> BoxFuture<'_, NetworkStatus> {
+        self.status_change(current).boxed_maybe_send()
+    }
+}
+
+/// Polling network monitor.
+///
+/// Default [`NetworkMonitor`] implementation which periodically probes
+/// [`PubNub API`] (`time` endpoint) to check whether it is reachable or not.
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[derive(Clone)]
+pub(crate) struct PollingNetworkMonitor {
+    /// Reachability probe.
+    ///
+    /// Resolves with `None` when probe can't be performed anymore (client has
+    /// been dropped).
+    probe: Arc<dyn Fn() -> BoxFuture<'static, Option<bool>> + Send + Sync>,
+
+    /// Runtime used to wait between probes.
+    runtime: RuntimeSupport,
+
+    /// Interval (in seconds) between probes.
+    interval: u64,
+}
+
+impl PollingNetworkMonitor {
+    pub(crate) fn new(
+        probe: Arc<dyn Fn() -> BoxFuture<'static, Option<bool>> + Send + Sync>,
+        runtime: RuntimeSupport,
+        interval: u64,
+    ) -> Self {
+        Self {
+            probe,
+            runtime,
+            interval,
+        }
+    }
+}
+
+impl NetworkMonitor for PollingNetworkMonitor {
+    async fn status_change(&self, current: NetworkStatus) -> NetworkStatus {
+        loop {
+            let status = match (self.probe)().await {
+                Some(true) => NetworkStatus::Up,
+                Some(false) => NetworkStatus::Down,
+                None => return current,
+            };
+
+            if status != current {
+                return status;
+            }
+
+            self.runtime.clone().sleep(self.interval).await;
+        }
+    }
+}
+
+impl Debug for PollingNetworkMonitor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "PollingNetworkMonitor {{ interval: {} }}", self.interval)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::lib::alloc::vec;
+    use crate::lib::sync::Mutex;
+    use futures::FutureExt;
+
+    #[derive(Clone, Copy)]
+    struct MockRuntime;
+
+    impl Runtime for MockRuntime {
+        fn spawn<R>(&self, _future: impl futures::Future<Output = R> + Send + 'static)
+        where
+            R: Send + 'static,
+        {
+        }
+
+        async fn sleep(self, _delay: u64) {}
+
+        async fn sleep_microseconds(self, _delay: u64) {}
+    }
+
+    #[tokio::test]
+    async fn resolve_with_changed_status() {
+        let results = Arc::new(Mutex::new(vec![true, true, false]));
+        let monitor = PollingNetworkMonitor::new(
+            Arc::new(move || {
+                let results = results.clone();
+                async move { Some(results.lock().remove(0)) }.boxed()
+            }),
+            RuntimeSupport::new(Arc::new(MockRuntime)),
+            1,
+        );
+
+        assert_eq!(
+            monitor.status_change(NetworkStatus::Up).await,
+            NetworkStatus::Down
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_with_current_status_when_probe_not_available() {
+        let monitor = PollingNetworkMonitor::new(
+            Arc::new(|| async { None }.boxed()),
+            RuntimeSupport::new(Arc::new(MockRuntime)),
+            1,
+        );
+
+        assert_eq!(
+            monitor.status_change(NetworkStatus::Down).await,
+            NetworkStatus::Down
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! # PubNub message module
+//!
+//! This module provides a [`PubNubMessage`] trait for typed messages which
+//! can be published with type tag and matched in received real-time updates.
+//!
+//! With `derive` feature enabled, the trait can be implemented with the
+//! [`PubNubMessage`](macro@crate::PubNubMessage) derive macro.
+//!
+//! [`PubNubMessage`]: trait.PubNubMessage.html
+
+use crate::{
+    core::{PubNubError, Serializer},
+    lib::alloc::vec::Vec,
+};
+
+/// Typed PubNub message.
+///
+/// Type which implements this trait can be published with
+/// [`publish_typed`] (message type tag will be sent along with the message)
+/// and received from typed messages stream which skips messages of other
+/// types.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::{PubNubError, PubNubMessage};
+///
+/// struct Ping;
+///
+/// impl PubNubMessage for Ping {
+///     const MESSAGE_TYPE: &'static str = "ping";
+///
+///     fn to_payload(&self) -> Result<Vec<u8>, PubNubError> {
+///         Ok(b"\"ping\"".to_vec())
+///     }
+///
+///     fn from_payload(payload: &[u8]) -> Result<Self, PubNubError> {
+///         (payload == b"\"ping\"")
+///             .then_some(Ping)
+///             .ok_or(PubNubError::Deserialization {
+///                 details: "Unexpected payload".into(),
+///             })
+///     }
+/// }
+/// ```
+///
+/// [`publish_typed`]: ../dx/pubnub_client/struct.PubNubClientInstance.html#method.publish_typed
+pub trait PubNubMessage: Sized {
+    /// Message type tag.
+    ///
+    /// Sent with published message as `type` and used to match received
+    /// messages.
+    const MESSAGE_TYPE: &'static str;
+
+    /// Serialize message into payload which will be published.
+    ///
+    /// # Errors
+    /// Should return an [`PubNubError::Serialization`] if the message cannot
+    /// be serialized.
+    fn to_payload(&self) -> Result<Vec<u8>, PubNubError>;
+
+    /// Deserialize message from received payload.
+    ///
+    /// # Errors
+    /// Should return an [`PubNubError::Deserialization`] if the payload
+    /// cannot be deserialized.
+    fn from_payload(payload: &[u8]) -> Result<Self, PubNubError>;
+}
+
+/// [`Serializer`] for [`PubNubMessage`] types.
+///
+/// Serializes typed message with its [`PubNubMessage::to_payload`]
+/// implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PubNubMessageSerializer;
+
+impl<'se, M> Serializer<'se, M> for PubNubMessageSerializer
+where
+    M: PubNubMessage,
+{
+    fn serialize(&self, object: &'se M) -> Result<Vec<u8>, PubNubError> {
+        object.to_payload()
+    }
+}
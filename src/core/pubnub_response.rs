@@ -0,0 +1,137 @@
+//! # PubNub response module
+//!
+//! This module contains the [`PubNubResponse`] trait which is implemented by
+//! all request results and [`ResponseMetadata`] with information about
+//! service response.
+
+use crate::{
+    core::TransportResponse,
+    lib::{
+        alloc::string::String,
+        core::{
+            cmp::Ordering,
+            hash::{Hash, Hasher},
+        },
+    },
+};
+use bytes::Bytes;
+
+/// Service response metadata.
+///
+/// Information about [`PubNub API`] response which has been used to create
+/// request result.
+///
+/// Metadata doesn't participate in results comparison and hashing, so results
+/// with same data are equal regardless of the response they came from.
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[derive(Debug, Clone, Default)]
+pub struct ResponseMetadata {
+    /// Response HTTP status code.
+    pub status: u16,
+
+    /// Unique identifier of request (`requestid` query parameter).
+    pub request_id: Option<String>,
+
+    /// Raw response body.
+    pub body: Option<Bytes>,
+}
+
+impl ResponseMetadata {
+    /// Create metadata from the transport `response`.
+    pub(crate) fn from_response(response: &TransportResponse) -> Self {
+        Self {
+            status: response.status,
+            request_id: response
+                .headers
+                .get(crate::core::utils::headers::REQUEST_ID)
+                .cloned(),
+            body: response.body.clone(),
+        }
+    }
+}
+
+impl PartialEq for ResponseMetadata {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ResponseMetadata {}
+
+impl PartialOrd for ResponseMetadata {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ResponseMetadata {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for ResponseMetadata {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// PubNub request result.
+///
+/// Trait implemented by results of all `execute()` calls, so generic code
+/// (logging, metrics or application level retries) can access response
+/// information uniformly.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::PubNubResponse;
+///
+/// fn log_response<R: PubNubResponse>(result: &R) {
+///     println!(
+///         "status: {}, request id: {:?}",
+///         result.status(),
+///         result.request_id()
+///     );
+/// }
+/// ```
+pub trait PubNubResponse {
+    /// Service response metadata.
+    fn metadata(&self) -> &ResponseMetadata;
+
+    /// Response HTTP status code.
+    fn status(&self) -> u16 {
+        self.metadata().status
+    }
+
+    /// Raw response body.
+    fn raw_body(&self) -> Option<&[u8]> {
+        self.metadata().body.as_deref()
+    }
+
+    /// Unique identifier of request.
+    fn request_id(&self) -> Option<&str> {
+        self.metadata().request_id.as_deref()
+    }
+}
+
+/// Request result which can store service response metadata.
+pub(crate) trait WithResponseMetadata {
+    /// Store service response `metadata`.
+    fn set_metadata(&mut self, metadata: ResponseMetadata);
+}
+
+/// Implement [`PubNubResponse`] for result type with `metadata` field.
+macro_rules! impl_pubnub_response {
+    ($result:ty) => {
+        impl $crate::core::PubNubResponse for $result {
+            fn metadata(&self) -> &$crate::core::ResponseMetadata {
+                &self.metadata
+            }
+        }
+
+        impl $crate::core::pubnub_response::WithResponseMetadata for $result {
+            fn set_metadata(&mut self, metadata: $crate::core::ResponseMetadata) {
+                self.metadata = metadata;
+            }
+        }
+    };
+}
@@ -8,12 +8,18 @@
 //! [`PubNub API`]: https://www.pubnub.com/docs
 //! [`pubnub`]: ../index.html
 
+use crate::lib::sync::RwLock;
 use getrandom::getrandom;
 
-use crate::{core::PubNubError, lib::alloc::vec::Vec};
+use crate::{
+    core::{DataStream, PubNubError},
+    lib::alloc::{vec, vec::Vec},
+};
 
 /// List of known endpoint groups (by context)
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Endpoint {
     /// Unknown endpoint.
     Unknown,
@@ -49,13 +55,107 @@ pub enum Endpoint {
     MessageReactions,
 }
 
+/// Retry delay randomization strategy.
+///
+/// Jitter spreads retry attempts of multiple clients in time, so they won't
+/// retry requests simultaneously (for example, after service outage).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RetryJitter {
+    /// Random offset (up to one second) added to the calculated delay.
+    #[default]
+    Offset,
+
+    /// Random delay between zero and calculated delay.
+    Full,
+
+    /// Half of calculated delay plus random delay between zero and another
+    /// half of calculated delay.
+    Equal,
+
+    /// Random delay between minimum delay and tripled calculated delay of the
+    /// previous attempt (limited by maximum delay).
+    Decorrelated,
+}
+
+/// Scheduled retry attempt information.
+///
+/// Information about failed request which will be retried after `delay`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryAttempt {
+    /// Endpoint group of the failed request.
+    pub endpoint: Endpoint,
+
+    /// Number of the scheduled retry attempt (starting from `1`).
+    pub attempt: u8,
+
+    /// Delay (in microseconds) before retry attempt.
+    pub delay: u64,
+
+    /// Error because of which request will be retried.
+    pub error: PubNubError,
+}
+
+/// Retry attempts notifier.
+///
+/// Delivers information about scheduled retry attempts to the registered
+/// streams.
+#[derive(Debug, Default)]
+pub(crate) struct RetryNotifier {
+    /// Scheduled retry attempts streams.
+    streams: RwLock<Option<Vec<DataStream<RetryAttempt>>>>,
+}
+
+impl RetryNotifier {
+    /// Stream used to notify about scheduled retry attempts.
+    pub(crate) fn stream(&self) -> DataStream<RetryAttempt> {
+        let stream = DataStream::new();
+        let mut streams_slot = self.streams.write();
+
+        if let Some(streams) = streams_slot.as_mut() {
+            streams.push(stream.clone())
+        } else {
+            *streams_slot = Some(vec![stream.clone()])
+        }
+
+        stream
+    }
+
+    /// Notify about scheduled retry attempt.
+    pub(crate) fn notify(&self, endpoint: Endpoint, attempt: u8, delay: u64, error: &PubNubError) {
+        let Some(streams) = self.streams.read().as_ref().cloned() else {
+            return;
+        };
+
+        let retry_attempt = RetryAttempt {
+            endpoint,
+            attempt,
+            delay,
+            error: error.clone(),
+        };
+        streams
+            .iter()
+            .for_each(|stream| stream.push_data(retry_attempt.clone()));
+    }
+}
+
 /// Request retry policy.
+///
+/// With `serde` feature, policy can be deserialized from object with `policy`
+/// tag (`none`, `linear` or `exponential`) and policy fields.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "policy", rename_all = "snake_case"))]
 pub enum RequestRetryConfiguration {
     /// Requests shouldn't be tried again.
     None,
 
     /// Retry the request after the same amount of time.
+    ///
+    /// Use [`RequestRetryConfiguration::linear`] to create policy outside of
+    /// the crate.
+    #[non_exhaustive]
     Linear {
         /// The delay between failed retry attempts in seconds.
         delay: u64,
@@ -63,6 +163,15 @@ pub enum RequestRetryConfiguration {
         /// Number of times a request can be retried.
         max_retry: u8,
 
+        /// Maximum total duration of request with retry attempts in seconds.
+        ///
+        /// Time is measured from the first request attempt. Retry attempt
+        /// won't be scheduled if its delay would exceed remaining time and
+        /// retried request timeout is limited by remaining time. Requests for
+        /// real-time updates and presence heartbeat are retried by event
+        /// engines and limited with `max_retry` only.
+        max_total_retry_duration: Option<u64>,
+
         /// Optional list of excluded endpoint groups.
         ///
         /// Endpoint groups for which automatic retry shouldn't be used.
@@ -70,6 +179,10 @@ pub enum RequestRetryConfiguration {
     },
 
     /// Retry the request using exponential amount of time.
+    ///
+    /// Use [`RequestRetryConfiguration::exponential`] to create policy outside
+    /// of the crate.
+    #[non_exhaustive]
     Exponential {
         /// Minimum delay between failed retry attempts in seconds.
         min_delay: u64,
@@ -80,6 +193,19 @@ pub enum RequestRetryConfiguration {
         /// Number of times a request can be retried.
         max_retry: u8,
 
+        /// Maximum total duration of request with retry attempts in seconds.
+        ///
+        /// Time is measured from the first request attempt. Retry attempt
+        /// won't be scheduled if its delay would exceed remaining time and
+        /// retried request timeout is limited by remaining time. Requests for
+        /// real-time updates and presence heartbeat are retried by event
+        /// engines and limited with `max_retry` only.
+        max_total_retry_duration: Option<u64>,
+
+        /// Retry delay randomization strategy.
+        #[cfg_attr(feature = "serde", serde(default))]
+        jitter: RetryJitter,
+
         /// Optional list of excluded endpoint groups.
         ///
         /// Endpoint groups for which automatic retry shouldn't be used.
@@ -122,6 +248,7 @@ impl RequestRetryConfiguration {
         Self::Linear {
             delay: 2,
             max_retry: 10,
+            max_total_retry_duration: None,
             excluded_endpoints: None,
         }
     }
@@ -158,10 +285,110 @@ impl RequestRetryConfiguration {
             min_delay: 2,
             max_delay: 150,
             max_retry: 6,
+            max_total_retry_duration: None,
+            jitter: RetryJitter::Offset,
+            excluded_endpoints: None,
+        }
+    }
+
+    /// Creates a new instance of the `RequestRetryConfiguration` enum with a
+    /// linear policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The delay between failed retry attempts in seconds.
+    /// * `max_retry` - Number of times a request can be retried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pubnub::{core::retry_policy::Endpoint, RequestRetryConfiguration};
+    ///
+    /// let retry_configuration = RequestRetryConfiguration::linear(3, 5)
+    ///     .with_max_total_retry_duration(30)
+    ///     .with_excluded_endpoints(vec![Endpoint::MessageSend]);
+    /// ```
+    pub fn linear(delay: u64, max_retry: u8) -> Self {
+        Self::Linear {
+            delay,
+            max_retry,
+            max_total_retry_duration: None,
+            excluded_endpoints: None,
+        }
+    }
+
+    /// Creates a new instance of the `RequestRetryConfiguration` enum with an
+    /// exponential backoff policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_delay` - Minimum delay between failed retry attempts in seconds.
+    /// * `max_delay` - Maximum delay between failed retry attempts in seconds.
+    /// * `max_retry` - Number of times a request can be retried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pubnub::{core::retry_policy::RetryJitter, RequestRetryConfiguration};
+    ///
+    /// let retry_configuration =
+    ///     RequestRetryConfiguration::exponential(2, 60, 6).with_jitter(RetryJitter::Full);
+    /// ```
+    pub fn exponential(min_delay: u64, max_delay: u64, max_retry: u8) -> Self {
+        Self::Exponential {
+            min_delay,
+            max_delay,
+            max_retry,
+            max_total_retry_duration: None,
+            jitter: RetryJitter::Offset,
             excluded_endpoints: None,
         }
     }
 
+    /// Set retry delay randomization strategy.
+    ///
+    /// Jitter is used only by [`RequestRetryConfiguration::Exponential`]
+    /// policy.
+    pub fn with_jitter(mut self, retry_jitter: RetryJitter) -> Self {
+        if let Self::Exponential { jitter, .. } = &mut self {
+            *jitter = retry_jitter;
+        }
+
+        self
+    }
+
+    /// Set maximum total duration of request with retry attempts in seconds.
+    pub fn with_max_total_retry_duration(mut self, duration: u64) -> Self {
+        match &mut self {
+            Self::Linear {
+                max_total_retry_duration,
+                ..
+            }
+            | Self::Exponential {
+                max_total_retry_duration,
+                ..
+            } => *max_total_retry_duration = Some(duration),
+            Self::None => {}
+        }
+
+        self
+    }
+
+    /// Set list of endpoint groups for which automatic retry shouldn't be used.
+    pub fn with_excluded_endpoints(mut self, endpoints: Vec<Endpoint>) -> Self {
+        match &mut self {
+            Self::Linear {
+                excluded_endpoints, ..
+            }
+            | Self::Exponential {
+                excluded_endpoints, ..
+            } => *excluded_endpoints = Some(endpoints),
+            Self::None => {}
+        }
+
+        self
+    }
+
     /// Check whether next retry `attempt` is allowed.
     ///
     /// # Arguments
@@ -228,27 +455,29 @@ impl RequestRetryConfiguration {
 
         error
             .and_then(|err| err.transport_response())
-            .map(|response| match response.status {
+            .and_then(|response| match response.status {
                 // Respect service requested delay.
                 429 if response.headers.contains_key("retry-after") => {
                     (!matches!(self, Self::None))
                         .then(|| response.headers.get("retry-after"))
                         .flatten()
                         .and_then(|value| value.parse::<u64>().ok())
+                        .and_then(|delay| Self::delay_in_microseconds(Some(delay)))
                 }
                 500..=599 => match self {
                     Self::None => None,
-                    Self::Linear { delay, .. } => Some(*delay),
+                    Self::Linear { delay, .. } => Self::delay_in_microseconds(Some(*delay)),
                     Self::Exponential {
                         min_delay,
                         max_delay,
+                        jitter,
                         ..
-                    } => Some((*min_delay * 2_u64.pow((*attempt - 1) as u32)).min(*max_delay)),
+                    } => Self::exponential_delay_in_microseconds(
+                        *min_delay, *max_delay, attempt, jitter,
+                    ),
                 },
                 _ => None,
             })
-            .map(Self::delay_in_microseconds)
-            .unwrap_or(None)
     }
 
     /// Check whether failed endpoint has been excluded or not.
@@ -293,6 +522,54 @@ impl RequestRetryConfiguration {
         }
     }
 
+    /// Check whether next retry attempt fits into configured retry duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `elapsed` - Time (in microseconds) elapsed since first request
+    ///   attempt.
+    /// * `delay` - Delay (in microseconds) before next retry attempt.
+    ///
+    /// # Returns
+    ///
+    /// `false` in case if next attempt will be started after configured
+    /// maximum total retry duration.
+    pub(crate) fn within_total_retry_duration(&self, elapsed: u64, delay: u64) -> bool {
+        self.remaining_retry_duration(elapsed)
+            .is_none_or(|remaining| delay < remaining)
+    }
+
+    /// Time left until configured maximum total retry duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `elapsed` - Time (in microseconds) elapsed since first request
+    ///   attempt.
+    ///
+    /// # Returns
+    ///
+    /// Remaining time in microseconds or `None` if maximum total retry duration
+    /// not configured.
+    pub(crate) fn remaining_retry_duration(&self, elapsed: u64) -> Option<u64> {
+        const MICROS_IN_SECOND: u64 = 1_000_000;
+
+        match self {
+            Self::Linear {
+                max_total_retry_duration: Some(duration),
+                ..
+            }
+            | Self::Exponential {
+                max_total_retry_duration: Some(duration),
+                ..
+            } => Some(
+                duration
+                    .saturating_mul(MICROS_IN_SECOND)
+                    .saturating_sub(elapsed),
+            ),
+            _ => None,
+        }
+    }
+
     /// Calculates the delay in microseconds given a delay in seconds.
     ///
     /// # Arguments
@@ -309,14 +586,70 @@ impl RequestRetryConfiguration {
         };
 
         const MICROS_IN_SECOND: u64 = 1_000_000;
-        let delay = delay_in_seconds * MICROS_IN_SECOND;
+        let delay = delay_in_seconds.saturating_mul(MICROS_IN_SECOND);
         let mut random_bytes = [0u8; 8];
 
         if getrandom(&mut random_bytes).is_err() {
             return Some(delay);
         }
 
-        Some(delay + u64::from_be_bytes(random_bytes) % MICROS_IN_SECOND)
+        Some(delay.saturating_add(u64::from_be_bytes(random_bytes) % MICROS_IN_SECOND))
+    }
+
+    /// Calculates exponential delay in microseconds for retry `attempt`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_delay` - Minimum delay between failed retry attempts in seconds.
+    /// * `max_delay` - Maximum delay between failed retry attempts in seconds.
+    /// * `attempt` - The attempt count of the request.
+    /// * `jitter` - Randomization strategy which should be applied to the
+    ///   calculated delay.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(delay_in_microseconds)` - The delay in microseconds.
+    fn exponential_delay_in_microseconds(
+        min_delay: u64,
+        max_delay: u64,
+        attempt: &u8,
+        jitter: &RetryJitter,
+    ) -> Option<u64> {
+        const MICROS_IN_SECOND: u64 = 1_000_000;
+        let exponent = attempt.saturating_sub(1) as u32;
+        let delay = min_delay
+            .saturating_mul(2_u64.saturating_pow(exponent))
+            .min(max_delay);
+
+        if matches!(jitter, RetryJitter::Offset) {
+            return Self::delay_in_microseconds(Some(delay));
+        }
+
+        let delay = delay.saturating_mul(MICROS_IN_SECOND);
+        let mut random_bytes = [0u8; 8];
+        if getrandom(&mut random_bytes).is_err() {
+            return Some(delay);
+        }
+        let random = u64::from_be_bytes(random_bytes);
+
+        Some(match jitter {
+            RetryJitter::Full => random % delay.saturating_add(1),
+            RetryJitter::Equal => delay / 2 + random % (delay - delay / 2 + 1),
+            RetryJitter::Decorrelated => {
+                let min_delay = min_delay.saturating_mul(MICROS_IN_SECOND);
+                let max_delay = max_delay.saturating_mul(MICROS_IN_SECOND);
+                let previous_delay = min_delay
+                    .saturating_mul(2_u64.saturating_pow(exponent.saturating_sub(1)))
+                    .min(max_delay);
+                let upper_bound = previous_delay
+                    .saturating_mul(3)
+                    .min(max_delay)
+                    .max(min_delay);
+
+                min_delay.saturating_add(random % (upper_bound - min_delay).saturating_add(1))
+            }
+            RetryJitter::Offset => delay,
+        })
     }
 }
 
@@ -464,6 +797,7 @@ mod should {
             let policy = RequestRetryConfiguration::Linear {
                 delay: 10,
                 max_retry: 5,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             };
 
@@ -487,6 +821,7 @@ mod should {
             let policy = RequestRetryConfiguration::Linear {
                 delay: expected_delay,
                 max_retry: 5,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             };
 
@@ -523,6 +858,7 @@ mod should {
             let policy = RequestRetryConfiguration::Linear {
                 delay: expected_delay,
                 max_retry: 3,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             };
 
@@ -558,6 +894,7 @@ mod should {
             let policy = RequestRetryConfiguration::Linear {
                 delay: 10,
                 max_retry: 2,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             };
 
@@ -587,6 +924,8 @@ mod should {
                 min_delay: expected_delay,
                 max_delay: 100,
                 max_retry: 2,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Offset,
                 excluded_endpoints: None,
             };
 
@@ -611,6 +950,8 @@ mod should {
                 min_delay: expected_delay,
                 max_delay: 100,
                 max_retry: 3,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Offset,
                 excluded_endpoints: None,
             };
 
@@ -648,6 +989,8 @@ mod should {
                 min_delay: expected_delay,
                 max_delay: 100,
                 max_retry: 3,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Offset,
                 excluded_endpoints: None,
             };
 
@@ -686,6 +1029,8 @@ mod should {
                 min_delay: expected_delay,
                 max_delay,
                 max_retry: 5,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Offset,
                 excluded_endpoints: None,
             };
 
@@ -722,6 +1067,8 @@ mod should {
                 min_delay: 10,
                 max_delay: 100,
                 max_retry: 2,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Offset,
                 excluded_endpoints: None,
             };
 
@@ -739,5 +1086,166 @@ mod should {
                 Some(150)
             ));
         }
+
+        #[test]
+        fn return_full_jitter_delay_for_server_error_response() {
+            let policy = RequestRetryConfiguration::Exponential {
+                min_delay: 8,
+                max_delay: 100,
+                max_retry: 3,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Full,
+                excluded_endpoints: None,
+            };
+
+            let delay = policy
+                .retry_delay(
+                    None,
+                    &2,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(server_error_response())),
+                    )),
+                )
+                .unwrap();
+            assert!((0..=16_000_000).contains(&delay));
+        }
+
+        #[test]
+        fn return_equal_jitter_delay_for_server_error_response() {
+            let policy = RequestRetryConfiguration::Exponential {
+                min_delay: 8,
+                max_delay: 100,
+                max_retry: 3,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Equal,
+                excluded_endpoints: None,
+            };
+
+            let delay = policy
+                .retry_delay(
+                    None,
+                    &2,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(server_error_response())),
+                    )),
+                )
+                .unwrap();
+            assert!((8_000_000..=16_000_000).contains(&delay));
+        }
+
+        #[test]
+        fn return_decorrelated_jitter_delay_for_server_error_response() {
+            let policy = RequestRetryConfiguration::Exponential {
+                min_delay: 8,
+                max_delay: 20,
+                max_retry: 5,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Decorrelated,
+                excluded_endpoints: None,
+            };
+
+            let delay = policy
+                .retry_delay(
+                    None,
+                    &3,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(server_error_response())),
+                    )),
+                )
+                .unwrap();
+            assert!((8_000_000..=20_000_000).contains(&delay));
+        }
+    }
+
+    #[test]
+    fn limit_total_retry_duration() {
+        let policy = RequestRetryConfiguration::Linear {
+            delay: 1,
+            max_retry: 10,
+            max_total_retry_duration: Some(3),
+            excluded_endpoints: None,
+        };
+
+        assert!(policy.within_total_retry_duration(1_500_000, 1_000_000));
+        assert!(!policy.within_total_retry_duration(2_500_000, 1_000_000));
+        assert!(!policy.within_total_retry_duration(4_000_000, 0));
+        assert_eq!(policy.remaining_retry_duration(2_500_000), Some(500_000));
+        assert!(RequestRetryConfiguration::default_linear()
+            .within_total_retry_duration(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn create_policy_with_constructors() {
+        assert_eq!(
+            RequestRetryConfiguration::linear(2, 5)
+                .with_max_total_retry_duration(30)
+                .with_excluded_endpoints(vec![Endpoint::MessageSend]),
+            RequestRetryConfiguration::Linear {
+                delay: 2,
+                max_retry: 5,
+                max_total_retry_duration: Some(30),
+                excluded_endpoints: Some(vec![Endpoint::MessageSend]),
+            }
+        );
+        assert_eq!(
+            RequestRetryConfiguration::exponential(2, 60, 6).with_jitter(RetryJitter::Full),
+            RequestRetryConfiguration::Exponential {
+                min_delay: 2,
+                max_delay: 60,
+                max_retry: 6,
+                max_total_retry_duration: None,
+                jitter: RetryJitter::Full,
+                excluded_endpoints: None,
+            }
+        );
+    }
+
+    #[test]
+    fn not_overflow_with_huge_delays() {
+        let error =
+            PubNubError::general_api_error("test", None, Some(Box::new(server_error_response())));
+
+        assert!(RequestRetryConfiguration::linear(u64::MAX, 5)
+            .retry_delay(None, &1, Some(&error))
+            .is_some());
+
+        for jitter in [
+            RetryJitter::Offset,
+            RetryJitter::Full,
+            RetryJitter::Equal,
+            RetryJitter::Decorrelated,
+        ] {
+            let policy =
+                RequestRetryConfiguration::exponential(u64::MAX, u64::MAX, 5).with_jitter(jitter);
+            assert!(policy.retry_delay(None, &3, Some(&error)).is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn notify_about_scheduled_retry_attempt() {
+        use futures::StreamExt;
+
+        let notifier = RetryNotifier::default();
+        let mut stream = notifier.stream();
+        let error =
+            PubNubError::general_api_error("test", None, Some(Box::new(server_error_response())));
+
+        notifier.notify(Endpoint::MessageSend, 1, 2_000_000, &error);
+
+        assert_eq!(
+            stream.next().await,
+            Some(RetryAttempt {
+                endpoint: Endpoint::MessageSend,
+                attempt: 1,
+                delay: 2_000_000,
+                error,
+            })
+        );
     }
 }
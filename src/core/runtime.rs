@@ -3,14 +3,16 @@
 //! The [`Spawner`] trait is used to spawn async tasks in work of the PubNub
 //! client.
 
-use crate::lib::{
-    alloc::{
-        fmt::{Debug, Formatter, Result},
-        sync::Arc,
+use crate::{
+    core::maybe_send::{BoxFuture, MaybeSend, MaybeSendFutureExt},
+    lib::{
+        alloc::{
+            fmt::{Debug, Formatter, Result},
+            sync::Arc,
+        },
+        core::future::Future,
     },
-    core::future::Future,
 };
-use futures::future::{BoxFuture, FutureExt};
 
 /// PubNub spawner trait.
 ///
@@ -26,7 +28,6 @@ use futures::future::{BoxFuture, FutureExt};
 /// #[derive(Clone)]
 /// struct MyRuntime;
 ///
-/// #[async_trait::async_trait]
 /// impl Runtime for MyRuntime {
 ///    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) {
 ///       // spawn the Future
@@ -42,24 +43,26 @@ use futures::future::{BoxFuture, FutureExt};
 ///    }
 /// }
 /// ```
-#[async_trait::async_trait]
+///
+/// On `wasm32` targets spawned and returned futures aren't required to be
+/// `Send` (see [`MaybeSend`]).
 pub trait Runtime: Clone + Send {
     /// Spawn a task.
     ///
     /// This method is used to spawn a task.
-    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static)
+    fn spawn<R>(&self, future: impl Future<Output = R> + MaybeSend + 'static)
     where
-        R: Send + 'static;
+        R: MaybeSend + 'static;
 
     /// Put current task to "sleep".
     ///
     /// Sleep current task for specified amount of time (in seconds).
-    async fn sleep(self, delay: u64);
+    fn sleep(self, delay: u64) -> impl Future<Output = ()> + MaybeSend;
 
     /// Put current task to "sleep".
     ///
     /// Sleep current task for specified amount of time (in microseconds).
-    async fn sleep_microseconds(self, delay: u64);
+    fn sleep_microseconds(self, delay: u64) -> impl Future<Output = ()> + MaybeSend;
 }
 
 #[derive(Clone)]
@@ -72,16 +75,26 @@ pub(crate) struct RuntimeSupport {
 impl RuntimeSupport {
     pub fn new<R>(runtime: Arc<R>) -> Self
     where
-        R: Runtime + Copy + Send + Sync + 'static,
+        R: Runtime + Send + Sync + 'static,
     {
         let spawn_runtime = runtime.clone();
         let sleep_runtime = runtime.clone();
         let sleep_microseconds_runtime = runtime.clone();
 
         Self {
-            sleeper: Arc::new(move |delay| sleep_runtime.sleep(delay).boxed()),
+            sleeper: Arc::new(move |delay| {
+                sleep_runtime
+                    .as_ref()
+                    .clone()
+                    .sleep(delay)
+                    .boxed_maybe_send()
+            }),
             sleeper_microseconds: Arc::new(move |delay| {
-                sleep_microseconds_runtime.sleep_microseconds(delay).boxed()
+                sleep_microseconds_runtime
+                    .as_ref()
+                    .clone()
+                    .sleep_microseconds(delay)
+                    .boxed_maybe_send()
             }),
             spawner: Arc::new(Box::new(move |future| {
                 spawn_runtime.spawn(future);
@@ -90,17 +103,16 @@ impl RuntimeSupport {
     }
 }
 
-#[async_trait::async_trait]
 impl Runtime for RuntimeSupport {
-    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static)
+    fn spawn<R>(&self, future: impl Future<Output = R> + MaybeSend + 'static)
     where
-        R: Send + 'static,
+        R: MaybeSend + 'static,
     {
         (self.spawner.clone())(
             async move {
                 future.await;
             }
-            .boxed(),
+            .boxed_maybe_send(),
         );
     }
 
@@ -8,7 +8,7 @@
 //! [`Serialize`]: trait.Serialize.html
 
 use super::PubNubError;
-use crate::lib::alloc::vec::Vec;
+use crate::{core::utils::headers::APPLICATION_JSON, lib::alloc::vec::Vec};
 
 /// Serialize values
 ///
@@ -58,4 +58,30 @@ pub trait Serialize {
     ///
     /// [`PubNubError::SerializeError`]: ../error/enum.PubNubError.html#variant.SerializeError
     fn serialize(&self) -> Result<Vec<u8>, PubNubError>;
+
+    /// Serialize the value into the `buffer`.
+    ///
+    /// Serialized value is appended to the `buffer`, which allows to reuse
+    /// already allocated memory. Default implementation appends result of the
+    /// [`serialize`] call.
+    ///
+    /// # Errors
+    /// Should return an [`PubNubError::SerializeError`] if the value cannot be
+    /// serialized.
+    ///
+    /// [`serialize`]: #tymethod.serialize
+    /// [`PubNubError::SerializeError`]: ../error/enum.PubNubError.html#variant.SerializeError
+    fn serialize_into(&self, buffer: &mut Vec<u8>) -> Result<(), PubNubError> {
+        buffer.extend_from_slice(&self.serialize()?);
+        Ok(())
+    }
+
+    /// Serialized value content type.
+    ///
+    /// Content type is sent with the value in the `Content-Type` header when
+    /// request has a body (for example, publish with POST). By default values
+    /// are expected to be serialized as JSON.
+    fn content_type(&self) -> &'static str {
+        APPLICATION_JSON
+    }
 }
@@ -3,7 +3,7 @@
 //! This module contains the `Serialize` trait which is used to implement
 //! serialization of Rust data structures.
 
-use super::PubNubError;
+use super::{PubNubError, Serialize};
 use crate::lib::alloc::vec::Vec;
 
 /// Trait for serializing Rust data structures.
@@ -56,3 +56,41 @@ pub trait Serializer<'se, T> {
     /// [`PubNubError::Serialization`]: ../enum.PubNubError.html#variant.Serialization
     fn serialize(&self, object: &'se T) -> Result<Vec<u8>, PubNubError>;
 }
+
+/// Value bundled with its serializer.
+///
+/// Wrapper implements [`Serialize`] trait for any value which can be
+/// serialized by provided [`Serializer`]. It can be used to publish values
+/// without implementing [`Serialize`] for each type by hand (for example, in
+/// builds without `serde` feature).
+///
+/// See [`publish_message_with`] for more information.
+///
+/// [`publish_message_with`]: crate::dx::pubnub_client::PubNubClientInstance::publish_message_with
+#[derive(Debug, Clone)]
+pub struct SerializeWith<V, S> {
+    /// Value which should be serialized.
+    value: V,
+
+    /// Serializer which should be used for `value` serialization.
+    serializer: S,
+}
+
+impl<V, S> SerializeWith<V, S>
+where
+    S: for<'se> Serializer<'se, V>,
+{
+    /// Bundle `value` with `serializer`.
+    pub fn new(value: V, serializer: S) -> Self {
+        Self { value, serializer }
+    }
+}
+
+impl<V, S> Serialize for SerializeWith<V, S>
+where
+    S: for<'se> Serializer<'se, V>,
+{
+    fn serialize(&self) -> Result<Vec<u8>, PubNubError> {
+        self.serializer.serialize(&self.value)
+    }
+}
@@ -7,10 +7,11 @@
 //! [`PubNub`]:https://www.pubnub.com/
 
 use crate::{
-    core::PubNubError,
+    core::{PubNubError, ServerError, ServerErrorDetails},
     lib::{
         alloc::{
             borrow::ToOwned,
+            boxed::Box,
             format,
             string::{String, ToString},
             vec::Vec,
@@ -436,6 +437,35 @@ impl APIErrorBody {
         }
     }
 
+    /// Retrieve structured service error from error body payload.
+    fn server_error(&self) -> Option<ServerError> {
+        match self {
+            APIErrorBody::AsObjectWithErrorObjectDetails { status, error, .. } => {
+                Some(ServerError {
+                    status: *status,
+                    message: error.message.clone(),
+                    source: Some(error.source.clone()),
+                    details: error
+                        .details
+                        .iter()
+                        .map(|detail| ServerErrorDetails {
+                            message: detail.message.clone(),
+                            location: detail.location.clone(),
+                            location_type: detail.location_type.clone(),
+                        })
+                        .collect(),
+                })
+            }
+            APIErrorBody::AsObjectWithErrorObject { status, error } => Some(ServerError {
+                status: *status,
+                message: error.message.clone(),
+                source: Some(error.source.clone()),
+                details: Vec::new(),
+            }),
+            _ => None,
+        }
+    }
+
     fn affected_channel_groups(&self) -> Option<Vec<String>> {
         match &self {
             APIErrorBody::AsObjectWithServiceAndErrorPayload { payload, .. } => {
@@ -456,7 +486,59 @@ impl From<APIErrorBody> for PubNubError {
             service: value.service(),
             affected_channels: value.affected_channels(),
             affected_channel_groups: value.affected_channel_groups(),
+            server_error: value.server_error().map(Box::new),
             response: None,
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod should {
+    use super::*;
+
+    #[test]
+    fn parse_error_envelope_into_server_error() {
+        let body: APIErrorBody = serde_json::from_str(
+            r#"{
+                "error": {
+                    "message": "Invalid signature",
+                    "source": "grant",
+                    "details": [{
+                        "message": "Client and server produced different signatures.",
+                        "location": "signature",
+                        "locationType": "query"
+                    }]
+                },
+                "service": "Access Manager",
+                "status": 403
+            }"#,
+        )
+        .unwrap();
+        let error: PubNubError = body.into();
+
+        assert_eq!(
+            error.server_error(),
+            Some(&ServerError {
+                status: 403,
+                message: "Invalid signature".into(),
+                source: Some("grant".into()),
+                details: vec![ServerErrorDetails {
+                    message: "Client and server produced different signatures.".into(),
+                    location: "signature".into(),
+                    location_type: "query".into(),
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn not_create_server_error_without_error_envelope() {
+        let body: APIErrorBody = serde_json::from_str(
+            r#"{"status": 429, "error": true, "message": "Too many requests."}"#,
+        )
+        .unwrap();
+        let error: PubNubError = body.into();
+
+        assert!(error.server_error().is_none());
+    }
+}
@@ -0,0 +1,241 @@
+//! # Timetoken module
+//!
+//! This module contains the [`Timetoken`] type, which represents [`PubNub`]
+//! high-precision timestamp.
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+use crate::{
+    core::PubNubError,
+    lib::{
+        alloc::format,
+        core::{
+            fmt::{Display, Formatter, Result as FmtResult},
+            str::FromStr,
+        },
+    },
+};
+
+/// Number of nanoseconds in single timetoken tick.
+const NANOS_PER_TICK: u128 = 100;
+
+/// Number of timetoken ticks in one second.
+const TICKS_PER_SECOND: u64 = 10_000_000;
+
+/// PubNub high-precision timestamp.
+///
+/// Timetoken is number of 100-nanosecond intervals since the Unix epoch
+/// (17-digits number for present dates). Timetokens are used to identify
+/// published messages and as cursors for real-time updates and history.
+///
+/// # Examples
+/// ```
+/// use pubnub::core::Timetoken;
+///
+/// # fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let timetoken: Timetoken = "17000000000000000".parse()?;
+///
+/// assert_eq!(timetoken.as_u64(), 17_000_000_000_000_000);
+/// assert_eq!(timetoken.unix_timestamp(), 1_700_000_000);
+/// assert!(timetoken < Timetoken::new(17_000_000_000_000_001));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timetoken(u64);
+
+impl Timetoken {
+    /// Create timetoken from number of 100-nanosecond intervals since the
+    /// Unix epoch.
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Create timetoken from number of nanoseconds since the Unix epoch.
+    pub const fn from_unix_nanos(nanos: u128) -> Self {
+        Self((nanos / NANOS_PER_TICK) as u64)
+    }
+
+    /// Create timetoken from number of seconds since the Unix epoch.
+    pub const fn from_unix_timestamp(seconds: u64) -> Self {
+        Self(seconds.saturating_mul(TICKS_PER_SECOND))
+    }
+
+    /// Number of 100-nanosecond intervals since the Unix epoch.
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Number of nanoseconds since the Unix epoch.
+    pub const fn unix_nanos(&self) -> u128 {
+        self.0 as u128 * NANOS_PER_TICK
+    }
+
+    /// Number of seconds since the Unix epoch.
+    pub const fn unix_timestamp(&self) -> u64 {
+        self.0 / TICKS_PER_SECOND
+    }
+
+    /// Convert timetoken to the system time.
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> std::time::SystemTime {
+        std::time::UNIX_EPOCH
+            + core::time::Duration::from_nanos(self.0.saturating_mul(NANOS_PER_TICK as u64))
+    }
+
+    /// Create timetoken from the system time.
+    ///
+    /// Time before the Unix epoch is represented with zero timetoken.
+    #[cfg(feature = "std")]
+    pub fn from_system_time(time: std::time::SystemTime) -> Self {
+        time.duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| Self::from_unix_nanos(duration.as_nanos()))
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Timetoken {
+    type Err = PubNubError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() || !value.chars().all(|char| char.is_ascii_digit()) {
+            return Err(PubNubError::Deserialization {
+                details: error_details!("Invalid timetoken: {value}"),
+            });
+        }
+
+        value
+            .parse::<u64>()
+            .map(Self)
+            .map_err(|err| PubNubError::Deserialization {
+                details: error_details!("Invalid timetoken: {value} ({err})"),
+            })
+    }
+}
+
+impl Display for Timetoken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for Timetoken {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Timetoken> for u64 {
+    fn from(value: Timetoken) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Timetoken {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Timetoken {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct TimetokenVisitor;
+
+        impl serde::de::Visitor<'_> for TimetokenVisitor {
+            type Value = Timetoken;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_str("timetoken as number or string")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Timetoken(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                u64::try_from(value)
+                    .map(Timetoken)
+                    .map_err(|_| E::custom(format!("Invalid timetoken: {value}")))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(TimetokenVisitor)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn parse_timetoken_string() {
+        let timetoken: Timetoken = "17000000000000000".parse().unwrap();
+
+        assert_eq!(timetoken, Timetoken::new(17_000_000_000_000_000));
+        assert_eq!(timetoken.to_string(), "17000000000000000");
+        assert!("-1".parse::<Timetoken>().is_err());
+        assert!("17000000000000000a".parse::<Timetoken>().is_err());
+        assert!("".parse::<Timetoken>().is_err());
+    }
+
+    #[test]
+    fn convert_timetoken_to_unix_time() {
+        let timetoken = Timetoken::new(17_000_000_001_234_567);
+
+        assert_eq!(timetoken.unix_timestamp(), 1_700_000_000);
+        assert_eq!(timetoken.unix_nanos(), 1_700_000_000_123_456_700);
+        assert_eq!(
+            Timetoken::from_unix_nanos(timetoken.unix_nanos()),
+            timetoken
+        );
+        assert_eq!(
+            Timetoken::from_unix_timestamp(1_700_000_000),
+            Timetoken::new(17_000_000_000_000_000)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn convert_timetoken_to_system_time() {
+        let timetoken = Timetoken::new(17_000_000_001_234_567);
+
+        assert_eq!(
+            Timetoken::from_system_time(timetoken.to_system_time()),
+            timetoken
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_timetoken_from_string_and_number() {
+        let from_string: Timetoken = serde_json::from_str("\"17000000000000000\"").unwrap();
+        let from_number: Timetoken = serde_json::from_str("17000000000000000").unwrap();
+
+        assert_eq!(from_string, from_number);
+        assert_eq!(
+            serde_json::to_string(&from_string).unwrap(),
+            "\"17000000000000000\""
+        );
+    }
+}
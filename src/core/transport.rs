@@ -8,8 +8,8 @@
 //!
 //! [`PubNub API`]: https://www.pubnub.com/docs
 
-use super::{transport_response::TransportResponse, PubNubError, TransportRequest};
-use crate::lib::alloc::boxed::Box;
+use super::{transport_response::TransportResponse, MaybeSend, PubNubError, TransportRequest};
+use crate::lib::core::future::Future;
 
 /// The default base URL for the [`PubNub API`].
 /// This is used for the transport layer.
@@ -30,7 +30,6 @@ pub const PUBNUB_DEFAULT_BASE_URL: &str = "https://ps.pndsn.com";
 ///
 /// struct MyTransport;
 ///
-/// #[async_trait::async_trait]
 /// impl Transport for MyTransport {
 ///    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
 ///         // Send your request here
@@ -40,9 +39,11 @@ pub const PUBNUB_DEFAULT_BASE_URL: &str = "https://ps.pndsn.com";
 /// }
 /// ```
 ///
+/// Implementations can use plain `async fn` (futures are polled without
+/// boxing). Returned future is required to be `Send` only on native targets
+/// (see [`MaybeSend`]).
+///
 /// [`PubNub API`]: https://www.pubnub.com/docs
-#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
-#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 pub trait Transport: Send + Sync {
     /// Send a request to the [`PubNub API`].
     ///
@@ -51,7 +52,10 @@ pub trait Transport: Send + Sync {
     /// sent.
     ///
     /// [`PubNub API`]: https://www.pubnub.com/docs
-    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError>;
+    fn send(
+        &self,
+        req: TransportRequest,
+    ) -> impl Future<Output = Result<TransportResponse, PubNubError>> + MaybeSend;
 }
 
 #[cfg(feature = "blocking")]
@@ -8,7 +8,7 @@
 //! [`pubnub`]: ../index.html
 
 use crate::{
-    core::PubNubError,
+    core::{pubnub_response::WithResponseMetadata, PubNubError, ResponseMetadata},
     lib::{
         alloc::{
             boxed::Box,
@@ -21,7 +21,11 @@ use crate::{
 };
 
 #[cfg(feature = "std")]
-use crate::core::{runtime::RuntimeSupport, RequestRetryConfiguration, Runtime};
+use crate::core::{
+    retry_policy::{Endpoint, RetryNotifier},
+    runtime::RuntimeSupport,
+    RequestRetryConfiguration, Runtime,
+};
 
 type DeserializerClosure<B> = Box<dyn FnOnce(&[u8]) -> Result<B, PubNubError>>;
 
@@ -32,6 +36,7 @@ type DeserializerClosure<B> = Box<dyn FnOnce(&[u8]) -> Result<B, PubNubError>>;
 ///
 /// [`TransportRequest`]: struct.TransportRequest.html
 #[derive(Clone, Eq, PartialEq, Debug, Default)]
+#[non_exhaustive]
 pub enum TransportMethod {
     /// The GET method.
     #[default]
@@ -42,6 +47,9 @@ pub enum TransportMethod {
 
     /// The DELETE method.
     Delete,
+
+    /// The PATCH method.
+    Patch,
 }
 
 impl Display for TransportMethod {
@@ -53,6 +61,7 @@ impl Display for TransportMethod {
                 TransportMethod::Get => "GET",
                 TransportMethod::Post => "POST",
                 TransportMethod::Delete => "DELETE",
+                TransportMethod::Patch => "PATCH",
             }
         )
     }
@@ -100,10 +109,11 @@ impl TransportRequest {
         deserializer: Arc<D>,
         #[cfg(feature = "std")] retry_configuration: RequestRetryConfiguration,
         #[cfg(feature = "std")] runtime: RuntimeSupport,
+        #[cfg(feature = "std")] retry_notifier: &RetryNotifier,
     ) -> Result<R, PubNubError>
     where
         B: for<'de> super::Deserialize<'de>,
-        R: TryFrom<B, Error = PubNubError>,
+        R: TryFrom<B, Error = PubNubError> + WithResponseMetadata,
         T: super::Transport,
         D: super::Deserializer + 'static,
     {
@@ -111,12 +121,16 @@ impl TransportRequest {
         {
             let mut last_result;
             let mut retry_attempt = 0_u8;
+            let started_at = std::time::Instant::now();
 
             loop {
                 let deserializer_clone = deserializer.clone();
+                let remaining = (retry_attempt > 0)
+                    .then(|| retry_configuration.remaining_retry_duration(elapsed(started_at)))
+                    .flatten();
 
                 // Request configured endpoint.
-                let response = transport.send(self.clone()).await;
+                let response = self.send_attempt(transport, retry_attempt, remaining).await;
                 last_result = Self::deserialize(
                     response?.clone(),
                     Box::new(move |bytes| deserializer_clone.deserialize(bytes)),
@@ -133,12 +147,19 @@ impl TransportRequest {
                     break;
                 }
 
-                if let Some(delay) = retry_configuration.retry_delay(
-                    Some(self.path.clone()),
-                    &retry_attempt,
-                    Some(error),
-                ) {
+                if let Some(delay) = retry_configuration
+                    .retry_delay(Some(self.path.clone()), &retry_attempt, Some(error))
+                    .filter(|delay| {
+                        retry_configuration.within_total_retry_duration(elapsed(started_at), *delay)
+                    })
+                {
                     retry_attempt += 1;
+                    retry_notifier.notify(
+                        Endpoint::from(self.path.clone()),
+                        retry_attempt,
+                        delay,
+                        error,
+                    );
                     runtime.clone().sleep_microseconds(delay).await;
                 } else {
                     break;
@@ -169,10 +190,11 @@ impl TransportRequest {
         deserializer: Arc<D>,
         #[cfg(feature = "std")] retry_configuration: &RequestRetryConfiguration,
         #[cfg(feature = "std")] runtime: &RuntimeSupport,
+        #[cfg(feature = "std")] retry_notifier: &RetryNotifier,
     ) -> Result<R, PubNubError>
     where
         B: for<'de> serde::Deserialize<'de>,
-        R: TryFrom<B, Error = PubNubError>,
+        R: TryFrom<B, Error = PubNubError> + WithResponseMetadata,
         T: super::Transport + 'static,
         D: super::Deserializer + 'static,
     {
@@ -180,12 +202,16 @@ impl TransportRequest {
         {
             let mut last_result;
             let mut retry_attempt = 0_u8;
+            let started_at = std::time::Instant::now();
 
             loop {
                 let deserializer_clone = deserializer.clone();
+                let remaining = (retry_attempt > 0)
+                    .then(|| retry_configuration.remaining_retry_duration(elapsed(started_at)))
+                    .flatten();
 
                 // Request configured endpoint.
-                let response = transport.send(self.clone()).await;
+                let response = self.send_attempt(transport, retry_attempt, remaining).await;
                 last_result = Self::deserialize(
                     response?.clone(),
                     Box::new(move |bytes| deserializer_clone.deserialize(bytes)),
@@ -202,12 +228,19 @@ impl TransportRequest {
                     break;
                 }
 
-                if let Some(delay) = retry_configuration.retry_delay(
-                    Some(self.path.clone()),
-                    &retry_attempt,
-                    Some(error),
-                ) {
+                if let Some(delay) = retry_configuration
+                    .retry_delay(Some(self.path.clone()), &retry_attempt, Some(error))
+                    .filter(|delay| {
+                        retry_configuration.within_total_retry_duration(elapsed(started_at), *delay)
+                    })
+                {
                     retry_attempt += 1;
+                    retry_notifier.notify(
+                        Endpoint::from(self.path.clone()),
+                        retry_attempt,
+                        delay,
+                        error,
+                    );
                     runtime.clone().sleep_microseconds(delay).await;
                 } else {
                     break;
@@ -228,6 +261,36 @@ impl TransportRequest {
         }
     }
 
+    /// Send single request attempt.
+    #[cfg(feature = "std")]
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    async fn send_attempt<T>(
+        &self,
+        transport: &T,
+        attempt: u8,
+        remaining: Option<u64>,
+    ) -> Result<super::TransportResponse, PubNubError>
+    where
+        T: super::Transport,
+    {
+        let mut request = self.clone();
+
+        // Retried request shouldn't outlive maximum total retry duration.
+        if let Some(remaining) = remaining {
+            request.timeout = request.timeout.min(remaining.div_ceil(1_000_000).max(1));
+        }
+
+        #[cfg(feature = "tracing")]
+        return tracing::Instrument::instrument(
+            transport.send(request),
+            tracing::debug_span!("pubnub.request_attempt", attempt),
+        )
+        .await;
+
+        #[cfg(not(feature = "tracing"))]
+        transport.send(request).await
+    }
+
     /// Send async request and process [`PubNub API`] response.
     ///
     /// [`PubNub API`]: https://www.pubnub.com/docs
@@ -239,7 +302,7 @@ impl TransportRequest {
     ) -> Result<R, PubNubError>
     where
         B: for<'de> super::Deserialize<'de>,
-        R: TryFrom<B, Error = PubNubError>,
+        R: TryFrom<B, Error = PubNubError> + WithResponseMetadata,
         T: super::blocking::Transport,
         D: super::Deserializer + 'static,
     {
@@ -262,7 +325,7 @@ impl TransportRequest {
     ) -> Result<R, PubNubError>
     where
         B: for<'de> serde::Deserialize<'de>,
-        R: TryFrom<B, Error = PubNubError>,
+        R: TryFrom<B, Error = PubNubError> + WithResponseMetadata,
         T: super::blocking::Transport,
         D: super::Deserializer + 'static,
     {
@@ -282,7 +345,7 @@ impl TransportRequest {
         des: DeserializerClosure<B>,
     ) -> Result<R, PubNubError>
     where
-        R: TryFrom<B, Error = PubNubError>,
+        R: TryFrom<B, Error = PubNubError> + WithResponseMetadata,
     {
         response
             .clone()
@@ -307,11 +370,23 @@ impl TransportRequest {
                 )),
                 |response_body| {
                     response_body.and_then::<R, _>(|body: B| {
-                        body.try_into().map_err(|response_error: PubNubError| {
-                            response_error.attach_response(response)
-                        })
+                        let metadata = ResponseMetadata::from_response(&response);
+                        body.try_into()
+                            .map(|mut result: R| {
+                                result.set_metadata(metadata);
+                                result
+                            })
+                            .map_err(|response_error: PubNubError| {
+                                response_error.attach_response(response)
+                            })
                     })
                 },
             )
     }
 }
+
+/// Time (in microseconds) elapsed since `started_at`.
+#[cfg(feature = "std")]
+fn elapsed(started_at: std::time::Instant) -> u64 {
+    u64::try_from(started_at.elapsed().as_micros()).unwrap_or(u64::MAX)
+}
@@ -8,10 +8,8 @@
 //! [`Transport`]: ../transport/trait.Transport.html
 //! [`PubNub API`]: https://www.pubnub.com/docs
 
-use crate::lib::{
-    alloc::{string::String, vec::Vec},
-    collections::HashMap,
-};
+use crate::lib::{alloc::string::String, collections::HashMap};
+use bytes::Bytes;
 
 /// This struct is used to represent the response from a request to the [`PubNub API`].
 /// It is used as the response type for the [`Transport`] trait.
@@ -27,5 +25,8 @@ pub struct TransportResponse {
     pub headers: HashMap<String, String>,
 
     /// body of the response
-    pub body: Option<Vec<u8>>,
+    ///
+    /// Body is reference counted, so it can be shared between response
+    /// consumers without copying.
+    pub body: Option<Bytes>,
 }
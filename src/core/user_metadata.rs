@@ -6,7 +6,7 @@
 //! [`PubNub API`]: https://www.pubnub.com/docs
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
-use spin::RwLock;
+use crate::lib::sync::RwLock;
 
 use crate::{
     core::PubNubEntity,
@@ -21,9 +21,16 @@ use crate::{
     },
 };
 
+#[cfg(any(feature = "objects", all(feature = "subscribe", feature = "std")))]
+use crate::core::Deserializer;
+#[cfg(feature = "objects")]
+use crate::dx::objects::{
+    GetMembershipsRequestBuilder, GetUuidMetadataRequestBuilder, RemoveMembershipsRequestBuilder,
+    RemoveUuidMetadataRequestBuilder, SetMembershipsRequestBuilder, SetUuidMetadataRequestBuilder,
+};
 #[cfg(all(feature = "subscribe", feature = "std"))]
 use crate::{
-    core::{Deserializer, Transport},
+    core::{ChannelName, Transport},
     lib::alloc::{sync::Weak, vec, vec::Vec},
     subscribe::{Subscribable, SubscribableType, Subscriber, Subscription, SubscriptionOptions},
 };
@@ -48,9 +55,10 @@ pub struct UserMetadataRef<T, D> {
     ///
     /// Client is used to support entity-specific actions like:
     /// * subscription
+    /// * `uuid` metadata management
     ///
     /// [`PubNubClientInstance`]: PubNubClientInstance
-    #[allow(dead_code)] // Field used conditionally only for `subscription` feature.
+    #[allow(dead_code)] // Field used conditionally only for `subscription` and `objects`.
     client: Arc<PubNubClientInstance<T, D>>,
 
     /// Unique user metadata object identifier.
@@ -190,8 +198,8 @@ impl<T, D> Debug for UserMetadata<T, D> {
 
 #[cfg(all(feature = "subscribe", feature = "std"))]
 impl<T, D> Subscribable<T, D> for UserMetadata<T, D> {
-    fn names(&self, _presence: bool) -> Vec<String> {
-        vec![self.id.clone()]
+    fn names(&self, _presence: bool) -> Vec<ChannelName> {
+        vec![self.client.channel_name(&self.id)]
     }
 
     fn r#type(&self) -> SubscribableType {
@@ -213,3 +221,81 @@ where
         Subscription::new(self.client(), self.clone().into(), options)
     }
 }
+
+#[cfg(feature = "objects")]
+impl<T, D> UserMetadata<T, D>
+where
+    D: Deserializer,
+{
+    /// Create a set `uuid` metadata request builder bound to this entity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let pubnub = PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let user = pubnub.user_metadata("john");
+    ///
+    /// user.set_metadata().name("John Doe").execute().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_metadata(&self) -> SetUuidMetadataRequestBuilder<T, D> {
+        self.client.set_uuid_metadata().uuid(self.id.clone())
+    }
+
+    /// Create a get `uuid` metadata request builder bound to this entity.
+    ///
+    /// See [`PubNubClientInstance::get_uuid_metadata`] for more information.
+    ///
+    /// [`PubNubClientInstance::get_uuid_metadata`]: crate::dx::pubnub_client::PubNubClientInstance::get_uuid_metadata
+    pub fn get_metadata(&self) -> GetUuidMetadataRequestBuilder<T, D> {
+        self.client.get_uuid_metadata().uuid(self.id.clone())
+    }
+
+    /// Create a remove `uuid` metadata request builder bound to this entity.
+    ///
+    /// See [`PubNubClientInstance::remove_uuid_metadata`] for more
+    /// information.
+    ///
+    /// [`PubNubClientInstance::remove_uuid_metadata`]: crate::dx::pubnub_client::PubNubClientInstance::remove_uuid_metadata
+    pub fn remove_metadata(&self) -> RemoveUuidMetadataRequestBuilder<T, D> {
+        self.client.remove_uuid_metadata().uuid(self.id.clone())
+    }
+
+    /// Create a get memberships request builder bound to this entity.
+    ///
+    /// See [`PubNubClientInstance::get_memberships`] for more information.
+    ///
+    /// [`PubNubClientInstance::get_memberships`]: crate::dx::pubnub_client::PubNubClientInstance::get_memberships
+    pub fn get_memberships(&self) -> GetMembershipsRequestBuilder<T, D> {
+        self.client.get_memberships().uuid(self.id.clone())
+    }
+
+    /// Create a set memberships request builder bound to this entity.
+    ///
+    /// See [`PubNubClientInstance::set_memberships`] for more information.
+    ///
+    /// [`PubNubClientInstance::set_memberships`]: crate::dx::pubnub_client::PubNubClientInstance::set_memberships
+    pub fn set_memberships(&self) -> SetMembershipsRequestBuilder<T, D> {
+        self.client.set_memberships().uuid(self.id.clone())
+    }
+
+    /// Create a remove memberships request builder bound to this entity.
+    ///
+    /// See [`PubNubClientInstance::remove_memberships`] for more information.
+    ///
+    /// [`PubNubClientInstance::remove_memberships`]: crate::dx::pubnub_client::PubNubClientInstance::remove_memberships
+    pub fn remove_memberships(&self) -> RemoveMembershipsRequestBuilder<T, D> {
+        self.client.remove_memberships().uuid(self.id.clone())
+    }
+}
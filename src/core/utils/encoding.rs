@@ -1,7 +1,4 @@
-use crate::lib::alloc::{
-    string::{String, ToString},
-    vec::Vec,
-};
+use crate::lib::alloc::{borrow::Cow, string::String, vec::Vec};
 use percent_encoding::{percent_encode, AsciiSet, CONTROLS};
 
 /// https://url.spec.whatwg.org/#fragment-percent-encode-set
@@ -29,6 +26,31 @@ const PUBNUB_SET: &AsciiSet = &USERINFO.add(b'+').add(b'%').add(b'!').add(b'$');
 /// Additional non-channel path component extension.
 const PUBNUB_NON_CHANNEL_PATH: &AsciiSet = &PUBNUB_SET.add(b',');
 
+/// Size of the chunk which is checked at once by [`is_unreserved`].
+const SCAN_CHUNK_SIZE: usize = 16;
+
+/// Lookup table of the [unreserved] characters.
+///
+/// None of these characters is percent-encoded by PubNub encode sets.
+///
+/// [unreserved]: https://datatracker.ietf.org/doc/html/rfc3986#section-2.3
+const UNRESERVED: [bool; 256] = {
+    let mut table = [false; 256];
+    let mut byte = 0;
+
+    while byte < 256 {
+        let char = byte as u8;
+        table[byte] = char.is_ascii_alphanumeric()
+            || char == b'-'
+            || char == b'.'
+            || char == b'_'
+            || char == b'~';
+        byte += 1;
+    }
+
+    table
+};
+
 pub enum UrlEncodeExtension {
     /// Default PubNub required encoding.
     Default,
@@ -40,20 +62,79 @@ pub enum UrlEncodeExtension {
 /// `percent_encoding` crate recommends you to create your own set for encoding.
 /// To be consistent in the whole codebase - we created a function that can be
 /// used for encoding related stuff.
-pub fn url_encode(data: &[u8]) -> String {
-    url_encode_extended(data, UrlEncodeExtension::Default).to_string()
+///
+/// `data` is borrowed without allocation if there is nothing to encode.
+pub fn url_encode(data: &[u8]) -> Cow<'_, str> {
+    url_encode_extended(data, UrlEncodeExtension::Default)
 }
 
 /// `percent_encoding` crate recommends you to create your own set for encoding.
 /// To be consistent in the whole codebase - we created a function that can be
 /// used for encoding related stuff.
-pub fn url_encode_extended(data: &[u8], extension: UrlEncodeExtension) -> String {
+///
+/// `data` is borrowed without allocation if there is nothing to encode.
+pub fn url_encode_extended(data: &[u8], extension: UrlEncodeExtension) -> Cow<'_, str> {
+    if is_unreserved(data) {
+        // Unreserved characters are ASCII and always valid UTF-8.
+        if let Ok(data) = crate::lib::core::str::from_utf8(data) {
+            return Cow::Borrowed(data);
+        }
+    }
+
+    let set = match extension {
+        UrlEncodeExtension::Default => PUBNUB_SET,
+        UrlEncodeExtension::NonChannelPath => PUBNUB_NON_CHANNEL_PATH,
+    };
+
+    percent_encode(data, set).fold(Cow::Borrowed(""), |mut encoded, chunk| {
+        if encoded.is_empty() {
+            Cow::Borrowed(chunk)
+        } else {
+            encoded.to_mut().push_str(chunk);
+            encoded
+        }
+    })
+}
+
+/// Check whether `data` consists only of [unreserved] characters.
+///
+/// Data is checked by fixed-size chunks without early return inside of chunk,
+/// so the compiler is able to vectorize lookups.
+///
+/// [unreserved]: https://datatracker.ietf.org/doc/html/rfc3986#section-2.3
+fn is_unreserved(data: &[u8]) -> bool {
+    let mut chunks = data.chunks_exact(SCAN_CHUNK_SIZE);
+    let chunks_unreserved = chunks.all(|chunk| {
+        chunk.iter().fold(true, |unreserved, byte| {
+            unreserved & UNRESERVED[*byte as usize]
+        })
+    });
+
+    chunks_unreserved
+        && chunks
+            .remainder()
+            .iter()
+            .all(|byte| UNRESERVED[*byte as usize])
+}
+
+/// URL-encode `data` directly into `writer`.
+///
+/// Unlike [`url_encode_extended`], encoded data is written without
+/// intermediate allocations.
+pub fn url_encode_into<W>(
+    writer: &mut W,
+    data: &[u8],
+    extension: UrlEncodeExtension,
+) -> crate::lib::core::fmt::Result
+where
+    W: crate::lib::core::fmt::Write,
+{
     let set = match extension {
         UrlEncodeExtension::Default => PUBNUB_SET,
         UrlEncodeExtension::NonChannelPath => PUBNUB_NON_CHANNEL_PATH,
     };
 
-    percent_encode(data, set).to_string()
+    percent_encode(data, set).try_for_each(|chunk| writer.write_str(chunk))
 }
 
 /// Join list of encoded strings.
@@ -66,7 +147,7 @@ pub fn join_url_encoded(strings: &[&str], sep: &str) -> Option<String> {
         strings
             .iter()
             .map(|val| url_encode(val.as_bytes()))
-            .collect::<Vec<String>>()
+            .collect::<Vec<Cow<'_, str>>>()
             .join(sep),
     )
 }
@@ -74,7 +155,7 @@ pub fn join_url_encoded(strings: &[&str], sep: &str) -> Option<String> {
 /// URL-encode channels list.
 ///
 /// Channels list used as part of URL path and therefore required.
-#[cfg(any(feature = "subscribe", feature = "presence"))]
+#[cfg(any(feature = "subscribe", feature = "presence", feature = "history"))]
 pub(crate) fn url_encoded_channels(channels: &[String]) -> String {
     join_url_encoded(
         channels
@@ -100,3 +181,37 @@ pub(crate) fn url_encoded_channel_groups(channel_groups: &[String]) -> Option<St
     )
     .filter(|string| !string.is_empty())
 }
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn borrow_data_without_reserved_characters() {
+        let channel = "channel-name_1.test~a-very-long-channel-name";
+
+        assert!(
+            matches!(url_encode(channel.as_bytes()), Cow::Borrowed(encoded) if encoded == channel)
+        );
+        assert!(matches!(
+            url_encode_extended(b"user-id", UrlEncodeExtension::NonChannelPath),
+            Cow::Borrowed("user-id")
+        ));
+    }
+
+    #[test]
+    fn encode_reserved_characters() {
+        let channel = "channel-name_1.test~a-very-long-channel name";
+
+        assert_eq!(
+            url_encode(channel.as_bytes()),
+            "channel-name_1.test~a-very-long-channel%20name"
+        );
+        assert_eq!(url_encode("a+b,c".as_bytes()), "a%2Bb,c");
+        assert_eq!(
+            url_encode_extended("a+b,c".as_bytes(), UrlEncodeExtension::NonChannelPath),
+            "a%2Bb%2Cc"
+        );
+        assert_eq!(url_encode("ch/ü".as_bytes()), "ch%2F%C3%BC");
+    }
+}
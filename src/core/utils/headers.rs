@@ -5,3 +5,8 @@
 
 pub(crate) const CONTENT_TYPE: &str = "Content-Type";
 pub(crate) const APPLICATION_JSON: &str = "application/json";
+#[cfg(feature = "msgpack")]
+pub(crate) const APPLICATION_MSGPACK: &str = "application/msgpack";
+
+/// Header used to pass `requestid` of the request along with its response.
+pub(crate) const REQUEST_ID: &str = "x-pubnub-request-id";
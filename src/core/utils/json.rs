@@ -0,0 +1,140 @@
+//! # JSON utilities module
+//!
+//! This module contains helpers which are used to build small JSON documents
+//! (like publish `meta` and presence `state`) without `serde`. Keys are sorted
+//! so the same map always produces the same document (important for request
+//! signatures and de-duplication caches).
+
+use crate::lib::alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(feature = "objects")]
+use crate::{core::ScalarValue, lib::alloc::string::ToString};
+
+/// Serialize `value` as JSON string literal.
+///
+/// Quotes, backslashes and control characters are escaped.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    value.chars().for_each(|char| match char {
+        '"' => result.push_str("\\\""),
+        '\\' => result.push_str("\\\\"),
+        '\n' => result.push_str("\\n"),
+        '\r' => result.push_str("\\r"),
+        '\t' => result.push_str("\\t"),
+        char if (char as u32) < 0x20 => {
+            result.push_str(&crate::lib::alloc::format!("\\u{:04x}", char as u32))
+        }
+        char => result.push(char),
+    });
+    result.push('"');
+    result
+}
+
+/// Serialize `value` as JSON scalar.
+///
+/// Non-finite floating point values can't be represented in JSON and
+/// serialized as `null`.
+#[cfg(feature = "objects")]
+pub(crate) fn json_scalar(value: &ScalarValue) -> String {
+    match value {
+        ScalarValue::String(value) => json_string(value),
+        ScalarValue::Boolean(value) => value.to_string(),
+        ScalarValue::Signed8(value) => value.to_string(),
+        ScalarValue::Unsigned8(value) => value.to_string(),
+        ScalarValue::Signed16(value) => value.to_string(),
+        ScalarValue::Unsigned16(value) => value.to_string(),
+        ScalarValue::Signed32(value) => value.to_string(),
+        ScalarValue::Unsigned32(value) => value.to_string(),
+        ScalarValue::Signed64(value) => value.to_string(),
+        ScalarValue::Unsigned64(value) => value.to_string(),
+        ScalarValue::Signed128(value) => value.to_string(),
+        ScalarValue::Unsigned128(value) => value.to_string(),
+        ScalarValue::Float32(value) if value.is_finite() => value.to_string(),
+        ScalarValue::Float64(value) if value.is_finite() => value.to_string(),
+        ScalarValue::Float32(_) | ScalarValue::Float64(_) => "null".into(),
+    }
+}
+
+/// Serialize `items` as JSON array.
+///
+/// Items should be already serialized JSON documents.
+#[cfg(feature = "objects")]
+pub(crate) fn json_array<'a, I>(items: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    let mut result = Vec::from([b'[']);
+    items.into_iter().enumerate().for_each(|(idx, item)| {
+        if idx > 0 {
+            result.push(b',');
+        }
+        result.extend_from_slice(item);
+    });
+    result.push(b']');
+    result
+}
+
+/// Serialize `entries` as JSON object.
+///
+/// Values should be already serialized JSON documents. Entries are sorted by
+/// key.
+pub(crate) fn json_object<'a, I>(entries: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = (&'a str, &'a [u8])>,
+{
+    let sorted = entries.into_iter().collect::<BTreeMap<_, _>>();
+    let mut result = Vec::from([b'{']);
+    sorted
+        .into_iter()
+        .enumerate()
+        .for_each(|(idx, (key, value))| {
+            if idx > 0 {
+                result.push(b',');
+            }
+            result.extend_from_slice(json_string(key).as_bytes());
+            result.push(b':');
+            result.extend_from_slice(value);
+        });
+    result.push(b'}');
+    result
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn escape_string() {
+        assert_eq!(json_string(r#"say "hi"\"#), r#""say \"hi\"\\""#);
+        assert_eq!(json_string("line\nbreak\u{1}"), r#""line\nbreak\u0001""#);
+    }
+
+    #[test]
+    fn serialize_object_with_sorted_keys() {
+        let result = json_object([("b", "2".as_bytes()), ("a", "\"1\"".as_bytes())]);
+
+        assert_eq!(result, br#"{"a":"1","b":2}"#.to_vec());
+    }
+
+    #[cfg(feature = "objects")]
+    #[test]
+    fn serialize_array() {
+        let result = json_array(["1".as_bytes(), "{}".as_bytes(), "\"a\"".as_bytes()]);
+
+        assert_eq!(result, br#"[1,{},"a"]"#.to_vec());
+        assert_eq!(json_array([]), b"[]".to_vec());
+    }
+
+    #[cfg(feature = "objects")]
+    #[test]
+    fn serialize_scalars() {
+        assert_eq!(
+            json_scalar(&ScalarValue::String("a\"b".into())),
+            r#""a\"b""#
+        );
+        assert_eq!(json_scalar(&ScalarValue::Boolean(true)), "true");
+        assert_eq!(json_scalar(&ScalarValue::Signed32(-10)), "-10");
+        assert_eq!(json_scalar(&ScalarValue::Float64(1.5)), "1.5");
+        assert_eq!(json_scalar(&ScalarValue::Float64(f64::NAN)), "null");
+    }
+}
@@ -2,15 +2,29 @@
     feature = "publish",
     feature = "access",
     feature = "subscribe",
-    feature = "presence"
+    feature = "presence",
+    feature = "history",
+    feature = "message_actions",
+    feature = "objects",
+    feature = "embedded_nal"
 ))]
 pub mod encoding;
 #[cfg(any(
     feature = "publish",
     feature = "access",
     feature = "subscribe",
-    feature = "presence"
+    feature = "presence",
+    feature = "history",
+    feature = "message_actions",
+    feature = "objects"
 ))]
 pub mod headers;
+#[cfg(any(
+    feature = "publish",
+    feature = "presence",
+    feature = "message_actions",
+    feature = "objects"
+))]
+pub(crate) mod json;
 
 pub mod metadata;
@@ -82,6 +82,40 @@ where
         default = "None"
     )]
     pub patterns: Option<&'pa [Box<dyn permissions::Permission>]>,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::access)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::access) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(GrantTokenRequestBuilderError);
+
+/// The [`GrantTokenPermissionsBuilder`] is the first step of the grant access
+/// token request build.
+///
+/// Token can't be generated without permissions, so request can be executed
+/// only after resource permissions (with [`resources`]) or pattern
+/// permissions (with [`patterns`]) have been provided (which returns
+/// [`GrantTokenRequestBuilder`]).
+///
+/// [`resources`]: GrantTokenPermissionsBuilder::resources
+/// [`patterns`]: GrantTokenPermissionsBuilder::patterns
+pub struct GrantTokenPermissionsBuilder<T, S, D> {
+    /// Current client which can provide transportation to perform the request.
+    pub(in crate::dx::access) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Request payload serializer.
+    pub(in crate::dx::access) serializer: S,
+
+    /// How long (in minutes) the generated token should be valid.
+    pub(in crate::dx::access) ttl: usize,
 }
 
 /// The [`GrantTokenRequestWithSerializerBuilder`] is used to build grant access
@@ -119,7 +153,9 @@ where
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             body: if !body.is_empty() { Some(body) } else { None },
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
         }
     }
 }
@@ -145,9 +181,7 @@ where
 {
     /// Build and call asynchronous request.
     pub async fn execute(self) -> Result<GrantTokenResult, PubNubError> {
-        let request = self
-            .build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+        let request = self.build().map_err(PubNubError::from)?;
 
         let transport_request = request.transport_request();
         let client = request.pubnub_client.clone();
@@ -158,9 +192,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -176,15 +212,13 @@ where
     /// Execute synchronous request and return the result.
     ///
     /// This method is synchronous and will return result which will resolve to
-    /// a [`RevokeTokenResult`] or [`PubNubError`].
+    /// a [`GrantTokenResult`] or [`PubNubError`].
     ///
     /// # Example
     /// ```no_run
-    /// # use pubnub::{PubNubClientBuilder, Keyset, access::*};
-    /// # use std::collections::HashMap;
+    /// # use pubnub::{metadata, PubNubClientBuilder, Keyset, access::*};
     ///
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut pubnub = // PubNubClient
     /// #     PubNubClientBuilder::with_reqwest_blocking_transport()
     /// #         .with_keyset(Keyset {
@@ -197,20 +231,18 @@ where
     /// pubnub
     ///     .grant_token(10)
     ///     .resources(&[permissions::channel("test-channel").read().write()])
-    ///     .meta(HashMap::from([
-    ///          ("role".to_string(), "administrator".into()),
-    ///          ("access-duration".to_string(), 2800.into()),
-    ///          ("ping-interval".to_string(), 1754.88.into()),
-    ///      ]))
+    ///     .meta(metadata! {
+    ///         "role" => "administrator",
+    ///         "access-duration" => 2800,
+    ///         "ping-interval" => 1754.88,
+    ///     })
     ///     .execute_blocking()?;
     /// #     Ok(())
     /// # }
     /// ```
     pub fn execute_blocking(self) -> Result<GrantTokenResult, PubNubError> {
         // Build request instance and report errors if any.
-        let request = self
-            .build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+        let request = self.build().map_err(PubNubError::from)?;
 
         let transport_request = request.transport_request();
         let client = request.pubnub_client.clone();
@@ -220,25 +252,52 @@ where
     }
 }
 
+impl<T, S, D> GrantTokenPermissionsBuilder<T, S, D>
+where
+    S: for<'se, 'rq> Serializer<'se, GrantTokenPayload<'rq>>,
+{
+    /// List of permissions mapped to resource identifiers.
+    pub fn resources(
+        self,
+        resources: &[Box<dyn permissions::Permission>],
+    ) -> GrantTokenRequestBuilder<'_, T, S, D> {
+        self.request_builder().resources(resources)
+    }
+
+    /// List of permissions mapped to RegExp match expressions.
+    pub fn patterns(
+        self,
+        patterns: &[Box<dyn permissions::Permission>],
+    ) -> GrantTokenRequestBuilder<'_, T, S, D> {
+        self.request_builder().patterns(patterns)
+    }
+
+    /// Create request builder with provided client, serializer and `ttl`.
+    fn request_builder<'pa>(self) -> GrantTokenRequestBuilder<'pa, T, S, D> {
+        GrantTokenRequestBuilder {
+            pubnub_client: Some(self.pubnub_client),
+            serializer: Some(self.serializer),
+            ttl: Some(self.ttl),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(not(feature = "serde"))]
 impl<T, D> GrantTokenRequestWithSerializerBuilder<T, D> {
     /// Add custom serializer.
     ///
     /// Adds the serializer to the [`GrantTokenRequestBuilder`].
     ///
-    /// Instance of [`GrantTokenRequestWithDeserializerBuilder`] returned.
-    pub fn serialize_with<'request, S>(
-        self,
-        serializer: S,
-    ) -> GrantTokenRequestBuilder<'request, T, S, D>
+    /// Instance of [`GrantTokenPermissionsBuilder`] returned.
+    pub fn serialize_with<S>(self, serializer: S) -> GrantTokenPermissionsBuilder<T, S, D>
     where
         S: for<'se, 'rq> Serializer<'se, GrantTokenPayload<'rq>>,
     {
-        GrantTokenRequestBuilder {
-            pubnub_client: Some(self.pubnub_client),
-            ttl: Some(self.ttl),
-            serializer: Some(serializer),
-            ..Default::default()
+        GrantTokenPermissionsBuilder {
+            pubnub_client: self.pubnub_client,
+            serializer,
+            ttl: self.ttl,
         }
     }
 }
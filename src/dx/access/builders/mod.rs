@@ -5,7 +5,7 @@
 use crate::{dx::pubnub_client::PubNubClientInstance, lib::alloc::string::String};
 
 #[doc(inline)]
-pub use grant_token::{GrantTokenRequest, GrantTokenRequestBuilder};
+pub use grant_token::{GrantTokenPermissionsBuilder, GrantTokenRequest, GrantTokenRequestBuilder};
 
 #[cfg(not(feature = "serde"))]
 #[doc(inline)]
@@ -42,8 +42,21 @@ pub struct RevokeTokenRequest<T, D> {
     /// Access token for which permissions should be revoked.
     #[builder(field(vis = "pub(in crate::dx::access)"), setter(custom))]
     pub(super) token: String,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::access)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::access) timeout: Option<u64>,
 }
 
+impl_from_builder_error!(RevokeTokenRequestBuilderError);
+
 impl<T, D> RevokeTokenRequest<T, D> {
     /// Create transport request from the request builder.
     pub(in crate::dx::access) fn transport_request(&self) -> TransportRequest {
@@ -58,7 +71,9 @@ impl<T, D> RevokeTokenRequest<T, D> {
             method: TransportMethod::Delete,
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
             ..Default::default()
         }
     }
@@ -82,9 +97,7 @@ where
     /// Build and call asynchronous request.
     pub async fn execute(self) -> Result<RevokeTokenResult, PubNubError> {
         // Build request instance and report errors if any.
-        let request = self
-            .build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+        let request = self.build().map_err(PubNubError::from)?;
 
         let transport_request = request.transport_request();
         let client = request.pubnub_client.clone();
@@ -95,9 +108,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -118,8 +133,7 @@ where
     /// ```no_run
     /// # use pubnub::{PubNubClientBuilder, Keyset};
     ///
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut pubnub = // PubNubClient
     /// #     PubNubClientBuilder::with_reqwest_blocking_transport()
     /// #         .with_keyset(Keyset {
@@ -137,9 +151,7 @@ where
     /// ```
     pub fn execute_blocking(self) -> Result<RevokeTokenResult, PubNubError> {
         // Build request instance and report errors if any.
-        let request = self
-            .build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+        let request = self.build().map_err(PubNubError::from)?;
 
         let transport_request = request.transport_request();
         let client = request.pubnub_client.clone();
@@ -41,16 +41,14 @@ use crate::providers::serialization_serde::SerdeSerializer;
 impl<T, D> PubNubClientInstance<T, D> {
     /// Create grant token permissions request builder.
     /// This method is used to generate token with required permissions.
-    ///
-    /// Instance of [`GrantTokenRequestBuilder`] returned.
+    /// Instance of [`GrantTokenPermissionsBuilder`] returned.
     ///
     /// # Example
     /// ```rust,no_run
     /// use pubnub::{
-    ///     access::*,
+    ///     access::*, metadata,
     /// #    PubNubClientBuilder, Keyset,
     /// };
-    /// # use std::collections::HashMap;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -66,23 +64,22 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// pubnub
     ///     .grant_token(10)
     ///     .resources(&[permissions::channel("test-channel").read().write()])
-    ///     .meta(HashMap::from([
-    ///          ("role".into(), "administrator".into()),
-    ///          ("access-duration".into(), 2800.into()),
-    ///          ("ping-interval".into(), 1754.88.into()),
-    ///      ]))
+    ///     .meta(metadata! {
+    ///         "role" => "administrator",
+    ///         "access-duration" => 2800,
+    ///         "ping-interval" => 1754.88,
+    ///     })
     ///     .execute()
     ///     .await?;
     /// #     Ok(())
     /// # }
     /// ```
     #[cfg(feature = "serde")]
-    pub fn grant_token(&self, ttl: usize) -> GrantTokenRequestBuilder<T, SerdeSerializer, D> {
-        GrantTokenRequestBuilder {
-            pubnub_client: Some(self.clone()),
-            serializer: Some(SerdeSerializer),
-            ttl: Some(ttl),
-            ..Default::default()
+    pub fn grant_token(&self, ttl: usize) -> GrantTokenPermissionsBuilder<T, SerdeSerializer, D> {
+        GrantTokenPermissionsBuilder {
+            pubnub_client: self.clone(),
+            serializer: SerdeSerializer,
+            ttl,
         }
     }
     /// Create grant token permissions request builder.
@@ -95,9 +92,9 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// use pubnub::{
     ///     access::*,
     ///     core::{Deserializer, PubNubError, Serializer},
+    ///     metadata,
     /// #    PubNubClientBuilder, Keyset,
     /// };
-    /// # use std::collections::HashMap;
     ///
     /// struct MySerializer;
     /// struct MyDeserializer;
@@ -133,11 +130,11 @@ impl<T, D> PubNubClientInstance<T, D> {
     ///     .serialize_with(MySerializer)
     ///     .deserialize_with(MyDeserializer)
     ///     .resources(&[permissions::channel("test-channel").read().write()])
-    ///     .meta(HashMap::from([
-    ///          ("role".into(), "administrator".into()),
-    ///          ("access-duration".into(), 2800.into()),
-    ///          ("ping-interval".into(), 1754.88.into()),
-    ///      ]))
+    ///     .meta(metadata! {
+    ///         "role" => "administrator",
+    ///         "access-duration" => 2800,
+    ///         "ping-interval" => 1754.88,
+    ///     })
     ///     .execute()
     ///     .await?;
     /// #     Ok(())
@@ -189,6 +186,7 @@ impl<T, D> PubNubClientInstance<T, D> {
         RevokeTokenRequestBuilder {
             pubnub_client: Some(self.clone()),
             token: Some(token.into()),
+            ..Default::default()
         }
     }
 }
@@ -197,7 +195,9 @@ impl<T, D> PubNubClientInstance<T, D> {
 mod it_should {
     use super::*;
     use crate::{
-        core::{PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse},
+        core::{
+            Bytes, PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse,
+        },
         dx::pubnub_client::PubNubClientInstance,
         lib::{
             alloc::{borrow::ToOwned, format, vec, vec::Vec},
@@ -223,7 +223,6 @@ mod it_should {
         request_handler: Option<RequestHandler>,
     }
 
-    #[async_trait::async_trait]
     impl Transport for MockTransport {
         async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
             // Calling request handler (if provided).
@@ -238,6 +237,21 @@ mod it_should {
         }
     }
 
+    #[cfg(feature = "blocking")]
+    impl crate::core::blocking::Transport for MockTransport {
+        fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            // Calling request handler (if provided).
+            if let Some(handler) = &self.request_handler {
+                handler(&req);
+            }
+
+            Ok(self
+                .response
+                .clone()
+                .unwrap_or(transport_response(200, None)))
+        }
+    }
+
     /// Service response payload.
     fn transport_response(status: u16, token: Option<String>) -> TransportResponse {
         let error = "\"error\":{{\"message\":\"Overall error\",\"source\":\"test\",\
@@ -249,7 +263,7 @@ mod it_should {
 
         TransportResponse {
             status,
-            body: Some(Vec::from(format!(
+            body: Some(Bytes::from(format!(
                 "{{\"status\":{},\"service\":\"Access Manager\",{}",
                 status,
                 if status < 400 { data } else { error.to_owned() }
@@ -343,6 +357,28 @@ mod it_should {
         }
     }
 
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn grant_token_blocking() {
+        let permissions = permissions();
+        let transport = MockTransport {
+            response: Some(transport_response(200, Some("test-token".into()))),
+            request_handler: Some(Box::new(|req| {
+                assert!(req.query_parameters.contains_key("signature"));
+            })),
+        };
+        let client = client(true, true, None, None, Some(transport));
+        let result = client
+            .grant_token(10)
+            .resources(&permissions)
+            .execute_blocking();
+
+        match result {
+            Ok(response) => assert_eq!(response.token, "test-token"),
+            Err(err) => panic!("Request should not fail: {}", err),
+        }
+    }
+
     #[tokio::test]
     async fn include_timestamp_in_query_for_grant_token() {
         let permissions = permissions();
@@ -445,6 +481,15 @@ mod it_should {
         assert!(result.is_ok());
     }
 
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn revoke_token_blocking() {
+        let client = client(true, true, None, None, None);
+        let result = client.revoke_token("test/to+en==").execute_blocking();
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn include_encoded_token_in_path_for_revoke_token() {
         let request = client(true, true, None, None, None)
@@ -6,7 +6,7 @@
 
 use crate::core::{
     service_response::{APIErrorBody, APISuccessBody},
-    PubNubError,
+    PubNubError, ResponseMetadata,
 };
 use crate::lib::alloc::string::String;
 
@@ -18,11 +18,21 @@ pub struct GrantTokenResult {
     ///
     /// The response includes a token with the requested permissions.
     pub token: String,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
 }
 
+impl_pubnub_response!(GrantTokenResult);
+
 /// The result of a revoke token operation.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct RevokeTokenResult;
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RevokeTokenResult {
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(RevokeTokenResult);
 
 /// [`PubNub API`] response for grant token operation request.
 ///
@@ -149,7 +159,7 @@ impl TryFrom<RevokeTokenResponseBody> for RevokeTokenResult {
 
     fn try_from(value: RevokeTokenResponseBody) -> Result<Self, Self::Error> {
         match value {
-            RevokeTokenResponseBody::SuccessResponse(_) => Ok(RevokeTokenResult),
+            RevokeTokenResponseBody::SuccessResponse(_) => Ok(RevokeTokenResult::default()),
             RevokeTokenResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
     }
@@ -162,6 +172,7 @@ impl TryFrom<GrantTokenResponseBody> for GrantTokenResult {
         match value {
             GrantTokenResponseBody::SuccessResponse(resp) => Ok(GrantTokenResult {
                 token: resp.data.token,
+                metadata: Default::default(),
             }),
             GrantTokenResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
@@ -0,0 +1,416 @@
+//! # Chat module
+//!
+//! This module contains the [`Chat`] and [`ChatRoom`] types which provide a
+//! high-level API for typical chat applications: join / leave room,
+//! send and receive typed messages, typing indicators, room memberships and
+//! list of room members.
+//!
+//! Chat is built on top of the [`publish`], [`subscribe`], [`presence`] and
+//! [`objects`] modules, and low-level API is still available through the
+//! client and [`ChatRoom::subscription`].
+//!
+//! [`publish`]: crate::dx::publish
+//! [`subscribe`]: crate::dx::subscribe
+//! [`presence`]: crate::dx::presence
+//! [`objects`]: crate::dx::objects
+
+use futures::{future::ready, Stream, StreamExt};
+
+use crate::{
+    core::{DataStream, Deserializer, PubNubError, PubNubMessage, Transport},
+    dx::{
+        objects::result::MembershipsResult, publish::PublishResult,
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{string::String, vec::Vec},
+    subscribe::{
+        EventEmitter, EventSubscriber, Presence, Subscriber, Subscription, SubscriptionOptions,
+        TypedMessagesStream,
+    },
+};
+
+/// Typing indicator signal payload.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct TypingPayload {
+    /// Whether user started or stopped typing.
+    typing: bool,
+}
+
+/// Typing indicator update.
+///
+/// Update delivered when one of the room members started or stopped typing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypingIndicator {
+    /// Identifier of user who changed typing state.
+    pub user_id: String,
+
+    /// Whether user is typing or not.
+    pub is_typing: bool,
+}
+
+/// High-level chat API entry point.
+///
+/// Use [`Chat::join`] to start receiving real-time updates from the chat
+/// room.
+///
+/// # Example
+/// ```no_run
+/// use futures::StreamExt;
+/// use pubnub::{chat::Chat, Keyset, PubNubClientBuilder, PubNubMessage};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PubNubMessage, Serialize, Deserialize)]
+/// struct Text {
+///     text: String,
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pubnub = // PubNubClient
+/// #     PubNubClientBuilder::with_reqwest_transport()
+/// #         .with_keyset(Keyset {
+/// #              subscribe_key: "demo",
+/// #              publish_key: Some("demo"),
+/// #              secret_key: None
+/// #          })
+/// #         .with_user_id("uuid")
+/// #         .build()?;
+/// let room = Chat::new(pubnub).join("lobby");
+/// let mut messages = room.messages::<Text>();
+///
+/// room.send(Text { text: "Hello!".into() }).await?;
+///
+/// while let Some(message) = messages.next().await {
+///     println!("{:?}: {}", message.message.sender, message.data.text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Chat<T, D> {
+    /// Client which is used to perform requests.
+    client: PubNubClientInstance<T, D>,
+}
+
+/// Chat room.
+///
+/// Room is backed by the channel with the same name and receives real-time
+/// updates from it while the room instance exists (or until [`leave`] is
+/// called).
+///
+/// Real-time updates don't change the list of room members, which is stored
+/// with App Context and managed with [`add_membership`] and
+/// [`remove_membership`].
+///
+/// [`leave`]: ChatRoom::leave
+/// [`add_membership`]: ChatRoom::add_membership
+/// [`remove_membership`]: ChatRoom::remove_membership
+#[derive(Debug)]
+pub struct ChatRoom<T: Transport + Send + Sync + 'static, D: Deserializer + Send + Sync + 'static> {
+    /// Client which is used to perform requests.
+    client: PubNubClientInstance<T, D>,
+
+    /// Name of the channel which is used by room.
+    name: String,
+
+    /// Room channel subscription.
+    subscription: Subscription<T, D>,
+}
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create high-level [`Chat`] API instance backed by this client.
+    pub fn chat(&self) -> Chat<T, D> {
+        Chat::new(self.clone())
+    }
+}
+
+impl<T, D> Chat<T, D> {
+    /// Create high-level chat API instance backed by the `client`.
+    pub fn new(client: PubNubClientInstance<T, D>) -> Self {
+        Self { client }
+    }
+}
+
+impl<T, D> Chat<T, D>
+where
+    T: Transport + Send + Sync + 'static,
+    D: Deserializer + Send + Sync + 'static,
+{
+    /// Join chat room.
+    ///
+    /// Subscribe to the room channel (with presence events) and announce
+    /// `user_id` presence.
+    pub fn join<S>(&self, room: S) -> ChatRoom<T, D>
+    where
+        S: Into<String>,
+    {
+        let name = room.into();
+        let subscription = self
+            .client
+            .channel(name.clone())
+            .subscription(Some(vec![SubscriptionOptions::ReceivePresenceEvents]));
+        subscription.subscribe();
+
+        ChatRoom {
+            client: self.client.clone(),
+            name,
+            subscription,
+        }
+    }
+}
+
+impl<T, D> ChatRoom<T, D>
+where
+    T: Transport + Send + Sync + 'static,
+    D: Deserializer + Send + Sync + 'static,
+{
+    /// Name of the room.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Room channel subscription.
+    ///
+    /// Subscription can be used to listen for other types of real-time
+    /// updates (for example, message actions or files).
+    pub fn subscription(&self) -> &Subscription<T, D> {
+        &self.subscription
+    }
+
+    /// Send typed `message` to the room.
+    pub async fn send<M>(&self, message: M) -> Result<PublishResult, PubNubError>
+    where
+        M: PubNubMessage,
+    {
+        self.client
+            .publish_typed(message)
+            .channel(self.name.clone())
+            .execute()
+            .await
+    }
+
+    /// Stream of room messages with `M` type.
+    pub fn messages<M>(&self) -> TypedMessagesStream<M>
+    where
+        M: PubNubMessage,
+    {
+        self.subscription.typed_messages_stream()
+    }
+
+    /// Notify room members that user started or stopped typing.
+    ///
+    /// Typing indicators are sent with signals, so they are not stored in
+    /// history.
+    pub async fn set_typing(&self, is_typing: bool) -> Result<PublishResult, PubNubError> {
+        self.client
+            .signal(TypingPayload { typing: is_typing })
+            .channel(self.name.clone())
+            .execute()
+            .await
+    }
+
+    /// Stream of room members' typing indicators.
+    ///
+    /// Signals with unknown payload are skipped.
+    pub fn typing_stream(&self) -> impl Stream<Item = TypingIndicator> {
+        self.subscription.signals_stream().filter_map(|signal| {
+            let indicator = serde_json::from_slice::<TypingPayload>(&signal.data)
+                .ok()
+                .zip(signal.sender)
+                .map(|(payload, user_id)| TypingIndicator {
+                    user_id,
+                    is_typing: payload.typing,
+                });
+
+            ready(indicator)
+        })
+    }
+
+    /// Stream of room members' presence changes (`join`, `leave`, etc.).
+    pub fn presence_stream(&self) -> DataStream<Presence> {
+        self.subscription.presence_stream()
+    }
+
+    /// Store `user_id` membership in the room.
+    ///
+    /// Membership is stored with App Context, so user stays room member after
+    /// [`leave`] and will be listed by [`members`].
+    ///
+    /// [`leave`]: ChatRoom::leave
+    /// [`members`]: ChatRoom::members
+    pub async fn add_membership(&self) -> Result<MembershipsResult, PubNubError> {
+        self.client
+            .set_memberships()
+            .channels([self.name.clone()])
+            .include_custom(false)
+            .execute()
+            .await
+    }
+
+    /// Remove `user_id` membership in the room.
+    pub async fn remove_membership(&self) -> Result<MembershipsResult, PubNubError> {
+        self.client
+            .remove_memberships()
+            .channels([self.name.clone()])
+            .include_custom(false)
+            .execute()
+            .await
+    }
+
+    /// Identifiers of room members.
+    ///
+    /// Members are retrieved with App Context channel members API (all pages
+    /// are fetched). Use [`online_members`] to get users which are currently
+    /// in the room.
+    ///
+    /// [`online_members`]: ChatRoom::online_members
+    pub async fn members(&self) -> Result<Vec<String>, PubNubError> {
+        let mut members = Vec::new();
+        let mut page = None;
+
+        loop {
+            let mut request = self
+                .client
+                .get_channel_members()
+                .channel(self.name.clone())
+                .include_custom(false);
+            if let Some(page) = page {
+                request = request.page(page);
+            }
+
+            let result = request.execute().await?;
+            let is_empty = result.data.is_empty();
+            members.extend(result.data.into_iter().map(|member| member.uuid.id));
+
+            match result.next {
+                Some(next) if !is_empty => page = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(members)
+    }
+
+    /// Identifiers of users which are currently in the room.
+    ///
+    /// Online users are retrieved with presence `here_now` API.
+    pub async fn online_members(&self) -> Result<Vec<String>, PubNubError> {
+        let result = self
+            .client
+            .here_now()
+            .channels([&self.name])
+            .execute()
+            .await?;
+
+        Ok(result
+            .iter()
+            .flat_map(|channel| channel.occupants.iter())
+            .map(|occupant| occupant.user_id.clone())
+            .collect())
+    }
+
+    /// Leave chat room.
+    ///
+    /// Stop receiving real-time updates from the room and announce `leave`
+    /// for `user_id`.
+    pub fn leave(self) {
+        self.subscription.unsubscribe();
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{
+        core::{TransportRequest, TransportResponse},
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    #[derive(Default)]
+    struct MockTransport;
+
+    impl Transport for MockTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            let body = if request.path.starts_with("/v2/presence") {
+                r#"{"status": 200, "message": "OK", "occupancy": 2, "uuids": ["user_a", "user_b"], "service": "Presence"}"#
+            } else if request.path == "/v2/objects/demo/channels/lobby/uuids" {
+                if request.query_parameters.contains_key("start") {
+                    r#"{"status":200,"data":[{"uuid":{"id":"user_c"},"updated":"2024-02-20T23:11:20.893755","eTag":"AZO/t53al7m8fx"}]}"#
+                } else {
+                    r#"{"status":200,"data":[{"uuid":{"id":"user_a"},"updated":"2024-02-20T23:11:20.893755","eTag":"AZO/t53al7m8fw"}],"next":"Mg"}"#
+                }
+            } else if request.path == "/v2/objects/demo/uuids/user_a/channels" {
+                r#"{"status":200,"data":[{"channel":{"id":"lobby"},"updated":"2024-02-20T23:11:20.893755","eTag":"AZO/t53al7m8fw"}]}"#
+            } else {
+                r#"[1, "Sent", "17000000000000000"]"#
+            };
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(body.as_bytes().to_vec().into()),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn client() -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo"),
+                secret_key: None,
+            })
+            .with_user_id("user_a")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn join_and_leave_room() {
+        let client = client();
+        let room = client.chat().join("lobby");
+
+        assert_eq!(room.name(), "lobby");
+        assert!(client
+            .subscribed_channels()
+            .channels
+            .contains(&"lobby".to_string()));
+
+        room.leave();
+        assert!(client.subscribed_channels().is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_room_members() {
+        let room = client().chat().join("lobby");
+
+        assert_eq!(room.members().await.unwrap(), vec!["user_a", "user_c"]);
+    }
+
+    #[tokio::test]
+    async fn list_online_room_members() {
+        let room = client().chat().join("lobby");
+
+        assert_eq!(
+            room.online_members().await.unwrap(),
+            vec!["user_a", "user_b"]
+        );
+    }
+
+    #[tokio::test]
+    async fn add_room_membership() {
+        let room = client().chat().join("lobby");
+        let result = room.add_membership().await.unwrap();
+
+        assert_eq!(result.data[0].channel.id, "lobby");
+    }
+
+    #[tokio::test]
+    async fn send_typing_indicator() {
+        let room = client().chat().join("lobby");
+
+        assert!(room.set_typing(true).await.is_ok());
+    }
+}
@@ -0,0 +1,308 @@
+//! # PubNub client pool module
+//!
+//! This module contains [`PubNubClientPool`] which manages [`PubNubClient`]
+//! instances for multiple keysets and user ids.
+//!
+//! [`PubNubClient`]: crate::dx::PubNubClient
+
+use crate::lib::sync::RwLock;
+
+#[cfg(all(feature = "serde", feature = "tokio"))]
+use crate::providers::{deserialization_serde::DeserializerSerde, futures_tokio::RuntimeTokio};
+use crate::{
+    core::{runtime::RuntimeSupport, PubNubError, Runtime},
+    dx::pubnub_client::{
+        Keyset, PubNubClientConfigBuilder, PubNubClientKeySetBuilder, PubNubGenericClient,
+    },
+    lib::{
+        alloc::{boxed::Box, string::String, sync::Arc},
+        collections::HashMap,
+        core::fmt::{Debug, Formatter, Result as FmtResult},
+    },
+};
+
+type ConfigurationClosure<T, D> =
+    dyn Fn(PubNubClientConfigBuilder<T, D>) -> PubNubClientConfigBuilder<T, D> + Send + Sync;
+
+/// Key used to identify client in [`PubNubClientPool`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PubNubClientPoolKey {
+    /// Keyset used by client.
+    pub keyset: Keyset<String>,
+
+    /// User ID used by client.
+    pub user_id: String,
+}
+
+/// PubNub clients pool.
+///
+/// Pool creates (on first request) and keeps [`PubNubClient`] instances for
+/// each unique keyset and user id pair. All clients share the same transport
+/// (cloned for each client) and runtime environment.
+///
+/// # Examples
+/// ```
+/// use pubnub::{
+///     dx::client_pool::PubNubClientPool, transport::TransportReqwest, Keyset,
+///     RequestRetryConfiguration,
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = PubNubClientPool::new(TransportReqwest::default())
+///     .with_configuration(|builder| {
+///         builder.with_retry_configuration(RequestRetryConfiguration::default_exponential())
+///     });
+///
+/// let client = pool.get_or_create(
+///     Keyset {
+///         subscribe_key: "sub-c-tenant-1",
+///         publish_key: Some("pub-c-tenant-1"),
+///         secret_key: None,
+///     },
+///     "user-1",
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`PubNubClient`]: crate::dx::PubNubClient
+pub struct PubNubClientPool<T, D> {
+    /// Transport layer shared by clients.
+    transport: T,
+
+    /// [`PubNub API`] responses deserializer.
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    deserializer: D,
+
+    /// Runtime environment shared by clients.
+    runtime: RuntimeSupport,
+
+    /// Additional configuration which should be applied to each new client.
+    configuration: Option<Arc<ConfigurationClosure<T, D>>>,
+
+    /// Created clients.
+    clients: RwLock<HashMap<PubNubClientPoolKey, PubNubGenericClient<T, D>>>,
+}
+
+#[cfg(all(feature = "serde", feature = "tokio"))]
+impl<T> PubNubClientPool<T, DeserializerSerde> {
+    /// Create clients pool with shared `transport`.
+    ///
+    /// Created clients will use `tokio` runtime environment and `serde`
+    /// responses deserializer.
+    pub fn new(transport: T) -> Self {
+        Self::with_runtime(transport, DeserializerSerde, RuntimeTokio)
+    }
+}
+
+impl<T, D> PubNubClientPool<T, D> {
+    /// Create clients pool with shared `transport`, `deserializer` and
+    /// `runtime` environment.
+    pub fn with_runtime<R>(transport: T, deserializer: D, runtime: R) -> Self
+    where
+        R: Runtime + Send + Sync + 'static,
+    {
+        Self {
+            transport,
+            deserializer,
+            runtime: RuntimeSupport::new(Arc::new(runtime)),
+            configuration: None,
+            clients: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Additional clients configuration.
+    ///
+    /// `configuration` closure will be called with builder of each new client
+    /// and can be used to set options which are common for all clients (like
+    /// retry policy or presence configuration).
+    pub fn with_configuration<F>(mut self, configuration: F) -> Self
+    where
+        F: Fn(PubNubClientConfigBuilder<T, D>) -> PubNubClientConfigBuilder<T, D>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.configuration = Some(Arc::new(configuration));
+        self
+    }
+
+    /// Number of clients in pool.
+    pub fn len(&self) -> usize {
+        self.clients.read().len()
+    }
+
+    /// Whether pool has any clients or not.
+    pub fn is_empty(&self) -> bool {
+        self.clients.read().is_empty()
+    }
+
+    /// Retrieve client for `keyset` and `user_id` if it has been created
+    /// before.
+    pub fn get<S, U>(&self, keyset: Keyset<S>, user_id: U) -> Option<PubNubGenericClient<T, D>>
+    where
+        S: Into<String>,
+        U: Into<String>,
+    {
+        self.clients
+            .read()
+            .get(&Self::key(keyset, user_id))
+            .cloned()
+    }
+
+    /// Remove client for `keyset` and `user_id` from pool.
+    ///
+    /// Removed client is returned, so it can be gracefully shut down.
+    pub fn remove<S, U>(&self, keyset: Keyset<S>, user_id: U) -> Option<PubNubGenericClient<T, D>>
+    where
+        S: Into<String>,
+        U: Into<String>,
+    {
+        self.clients.write().remove(&Self::key(keyset, user_id))
+    }
+
+    fn key<S, U>(keyset: Keyset<S>, user_id: U) -> PubNubClientPoolKey
+    where
+        S: Into<String>,
+        U: Into<String>,
+    {
+        PubNubClientPoolKey {
+            keyset: Keyset {
+                subscribe_key: keyset.subscribe_key.into(),
+                publish_key: keyset.publish_key.map(Into::into),
+                secret_key: keyset.secret_key.map(Into::into),
+            },
+            user_id: user_id.into(),
+        }
+    }
+}
+
+impl<T, D> PubNubClientPool<T, D>
+where
+    T: Clone,
+    D: Clone,
+{
+    /// Retrieve client for `keyset` and `user_id`.
+    ///
+    /// New client will be created and stored in pool if there is no client
+    /// for provided `keyset` and `user_id` yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::ClientInitialization`] if client can't be
+    /// created with provided `keyset` and configuration.
+    pub fn get_or_create<S, U>(
+        &self,
+        keyset: Keyset<S>,
+        user_id: U,
+    ) -> Result<PubNubGenericClient<T, D>, PubNubError>
+    where
+        S: Into<String>,
+        U: Into<String>,
+    {
+        let key = Self::key(keyset, user_id);
+        if let Some(client) = self.clients.read().get(&key) {
+            return Ok(client.clone());
+        }
+
+        let mut clients = self.clients.write();
+        if let Some(client) = clients.get(&key) {
+            return Ok(client.clone());
+        }
+
+        let mut builder = PubNubClientKeySetBuilder {
+            transport: self.transport.clone(),
+            deserializer: self.deserializer.clone(),
+            runtime: self.runtime.clone(),
+        }
+        .with_keyset(key.keyset.clone())
+        .with_user_id(key.user_id.clone());
+
+        if let Some(configuration) = &self.configuration {
+            builder = configuration(builder);
+        }
+
+        let client = builder.build()?;
+        clients.insert(key, client.clone());
+
+        Ok(client)
+    }
+}
+
+impl<T, D> Debug for PubNubClientPool<T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "PubNubClientPool {{ clients: {:?} }}",
+            self.clients.read().keys().collect::<Box<[_]>>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::core::{Transport, TransportRequest, TransportResponse};
+
+    #[derive(Default, Debug, Clone)]
+    struct MockTransport;
+
+    impl Transport for MockTransport {
+        async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            Ok(TransportResponse::default())
+        }
+    }
+
+    fn keyset(subscribe_key: &str) -> Keyset<&str> {
+        Keyset {
+            subscribe_key,
+            publish_key: Some("pub-key"),
+            secret_key: None,
+        }
+    }
+
+    #[test]
+    fn reuse_client_for_same_keyset_and_user_id() {
+        let pool = PubNubClientPool::new(MockTransport);
+
+        let client = pool.get_or_create(keyset("sub-1"), "user-1").unwrap();
+        let same_client = pool.get_or_create(keyset("sub-1"), "user-1").unwrap();
+
+        assert!(Arc::ptr_eq(&client.inner, &same_client.inner));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn create_client_for_each_keyset_and_user_id() {
+        let pool = PubNubClientPool::new(MockTransport);
+
+        pool.get_or_create(keyset("sub-1"), "user-1").unwrap();
+        pool.get_or_create(keyset("sub-1"), "user-2").unwrap();
+        let client = pool.get_or_create(keyset("sub-2"), "user-1").unwrap();
+
+        assert_eq!(pool.len(), 3);
+        assert_eq!(client.config.subscribe_key, "sub-2");
+        assert_eq!(client.user_id(), "user-1");
+    }
+
+    #[test]
+    fn apply_configuration_to_new_clients() {
+        let pool = PubNubClientPool::new(MockTransport)
+            .with_configuration(|builder| builder.with_auth_key("shared-auth"));
+
+        let client = pool.get_or_create(keyset("sub-1"), "user-1").unwrap();
+
+        assert_eq!(client.get_auth_key(), Some("shared-auth".into()));
+    }
+
+    #[test]
+    fn remove_client_from_pool() {
+        let pool = PubNubClientPool::new(MockTransport);
+        pool.get_or_create(keyset("sub-1"), "user-1").unwrap();
+
+        assert!(pool.remove(keyset("sub-1"), "user-1").is_some());
+        assert!(pool.get(keyset("sub-1"), "user-1").is_none());
+        assert!(pool.is_empty());
+    }
+}
@@ -0,0 +1,297 @@
+//! # Send file request builder.
+//!
+//! The [`SendFileRequestBuilder`] lets you make and execute a request which
+//! will upload browser [`Blob`] to the [`PubNub`] file storage and notify
+//! channel subscribers about it.
+//!
+//! [`Blob`]: https://developer.mozilla.org/en-US/docs/Web/API/Blob
+//! [`PubNub`]: https://www.pubnub.com
+
+use derive_builder::Builder;
+use js_sys::Promise;
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, FormData, Request, RequestInit, Response};
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode, url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+            json::{json_object, json_string},
+        },
+        Deserializer, PubNubError, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        files::result::{
+            FileUploadRequestBody, GenerateFileUploadUrlResponseBody, GenerateFileUploadUrlResult,
+            PublishFileMessageResponseBody, PublishFileMessageResult, SendFileResult,
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+};
+
+#[wasm_bindgen]
+extern "C" {
+    /// Global `fetch` function (available in windows and workers).
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch_with_request(input: &Request) -> Promise;
+}
+
+/// The [`SendFileRequestBuilder`] is used to build send file request that is
+/// sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`send_file`] method of the [`PubNubClient`].
+///
+/// [`send_file`]: crate::dx::pubnub_client::PubNubClientInstance::send_file
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::files)", validate = "Self::validate"),
+    no_std
+)]
+pub struct SendFileRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(custom))]
+    pub(in crate::dx::files) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of channel to which file should be shared.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) channel: String,
+
+    /// Name with which file should be stored.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) name: String,
+
+    /// File content.
+    #[builder(field(vis = "pub(in crate::dx::files)"))]
+    pub(in crate::dx::files) blob: Blob,
+
+    /// Message which should be sent along with file.
+    #[builder(
+        field(vis = "pub(in crate::dx::files)"),
+        setter(into),
+        default = "String::new()"
+    )]
+    pub(in crate::dx::files) message: String,
+}
+
+impl_from_builder_error!(SendFileRequestBuilderError);
+
+impl<T, D> SendFileRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// send file request instance.
+    fn validate(&self) -> Result<(), String> {
+        let client = self
+            .pubnub_client
+            .as_ref()
+            .unwrap_or_else(|| panic!("PubNub client instance not set."));
+
+        if client.config.subscribe_key.is_empty() {
+            Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into())
+        } else if client.config.publish_key.is_none() {
+            Err("Incomplete PubNub client configuration: 'publish_key' is not set.".into())
+        } else if self
+            .channel
+            .as_ref()
+            .is_none_or(|channel| channel.is_empty())
+        {
+            Err("Channel is missing".into())
+        } else if self.name.as_ref().is_none_or(|name| name.is_empty()) {
+            Err("File name is missing".into())
+        } else if self.blob.is_none() {
+            Err("File content is missing".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Build [`SendFileRequest`] from builder.
+    fn request(self) -> Result<SendFileRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> SendFileRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    ///
+    /// File shared in three steps: storage upload request generation,
+    /// `multipart/form-data` upload of the [`Blob`] with browser `fetch` and
+    /// file message publish which notifies channel subscribers.
+    ///
+    /// File and message are sent as is (without encryption with configured
+    /// cryptor).
+    pub async fn execute(self) -> Result<SendFileResult, PubNubError> {
+        let request = self.request()?;
+        let client = &request.pubnub_client;
+
+        let upload = generate_upload_url(client, &request.channel, &request.name).await?;
+        upload_file(&upload.upload_request, &upload.name, &request.blob).await?;
+        let published = publish_file_message(
+            client,
+            &request.channel,
+            &request.message,
+            &upload.id,
+            &upload.name,
+        )
+        .await?;
+
+        Ok(SendFileResult {
+            timetoken: published.timetoken,
+            id: upload.id,
+            name: upload.name,
+        })
+    }
+}
+
+/// Request storage upload request information for file with `name`.
+pub(in crate::dx::files) async fn generate_upload_url<T, D>(
+    client: &PubNubClientInstance<T, D>,
+    channel: &str,
+    name: &str,
+) -> Result<GenerateFileUploadUrlResult, PubNubError>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    let name = json_string(name);
+    let transport_request = TransportRequest {
+        path: format!(
+            "/v1/files/{}/channels/{}/generate-upload-url",
+            &client.config.subscribe_key,
+            url_encode(channel.as_bytes())
+        ),
+        query_parameters: HashMap::new(),
+        method: TransportMethod::Post,
+        headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+        body: Some(json_object([("name", name.as_bytes())])),
+        #[cfg(feature = "std")]
+        timeout: client.request_timeout(),
+    };
+
+    transport_request
+        .send::<GenerateFileUploadUrlResponseBody, _, _, _>(
+            &client.transport,
+            client.deserializer.clone(),
+            #[cfg(feature = "std")]
+            &client.retry_configuration(),
+            #[cfg(feature = "std")]
+            &client.runtime,
+            #[cfg(feature = "std")]
+            &client.retry_notifier,
+        )
+        .await
+}
+
+/// Publish message which notifies channel subscribers about shared file.
+pub(in crate::dx::files) async fn publish_file_message<T, D>(
+    client: &PubNubClientInstance<T, D>,
+    channel: &str,
+    message: &str,
+    id: &str,
+    name: &str,
+) -> Result<PublishFileMessageResult, PubNubError>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    let config = &client.config;
+    let pub_key = config
+        .publish_key
+        .as_ref()
+        .ok_or_else(|| PubNubError::general_api_error("Publish key is not set", None, None))?;
+    let (message, id, name) = (json_string(message), json_string(id), json_string(name));
+    let file = json_object([("id", id.as_bytes()), ("name", name.as_bytes())]);
+    let payload = json_object([("message", message.as_bytes()), ("file", file.as_slice())]);
+
+    let transport_request = TransportRequest {
+        path: format!(
+            "/v1/files/publish-file/{}/{}/0/{}/0/{}",
+            pub_key,
+            &config.subscribe_key,
+            url_encode(channel.as_bytes()),
+            url_encode_extended(&payload, UrlEncodeExtension::NonChannelPath)
+        ),
+        query_parameters: HashMap::new(),
+        method: TransportMethod::Get,
+        #[cfg(feature = "std")]
+        timeout: client.request_timeout(),
+        ..Default::default()
+    };
+
+    transport_request
+        .send::<PublishFileMessageResponseBody, _, _, _>(
+            &client.transport,
+            client.deserializer.clone(),
+            #[cfg(feature = "std")]
+            &client.retry_configuration(),
+            #[cfg(feature = "std")]
+            &client.runtime,
+            #[cfg(feature = "std")]
+            &client.retry_notifier,
+        )
+        .await
+}
+
+/// Upload `blob` to the storage with `multipart/form-data` request.
+///
+/// Storage expects file content to be the last field of the form.
+async fn upload_file(
+    upload_request: &FileUploadRequestBody,
+    name: &str,
+    blob: &Blob,
+) -> Result<(), PubNubError> {
+    let form = FormData::new().map_err(upload_error)?;
+    for field in &upload_request.form_fields {
+        form.append_with_str(&field.key, &field.value)
+            .map_err(upload_error)?;
+    }
+    form.append_with_blob_and_filename("file", blob, name)
+        .map_err(upload_error)?;
+
+    let init = RequestInit::new();
+    init.set_method(&upload_request.method);
+    init.set_body(&form);
+    let request =
+        Request::new_with_str_and_init(&upload_request.url, &init).map_err(upload_error)?;
+
+    let response: Response = JsFuture::from(fetch_with_request(&request))
+        .await
+        .map_err(upload_error)?
+        .unchecked_into();
+
+    if !response.ok() {
+        return Err(PubNubError::general_api_error(
+            format!("Unable to upload file: {}", response.status_text()),
+            Some(response.status()),
+            None,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Map JavaScript error of file upload.
+fn upload_error(error: JsValue) -> PubNubError {
+    PubNubError::Transport {
+        details: error_details!("Unable to upload file: {error:?}"),
+        response: None,
+    }
+}
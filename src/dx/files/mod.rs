@@ -0,0 +1,167 @@
+//! # Files module.
+//!
+//! The files module allows to share browser [`Blob`] (or `File`) with channel
+//! subscribers on `wasm32` targets. File is uploaded to the [`PubNub`] file
+//! storage with `fetch` and subscribers receive [`Update::File`] with
+//! information about it.
+//!
+//! [`Blob`]: https://developer.mozilla.org/en-US/docs/Web/API/Blob
+//! [`Update::File`]: crate::dx::subscribe::Update::File
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use result::SendFileResult;
+pub mod result;
+
+#[doc(inline)]
+pub use builders::{SendFileRequest, SendFileRequestBuilder};
+pub mod builders;
+
+use crate::{core::Deserializer, dx::pubnub_client::PubNubClientInstance};
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    D: Deserializer,
+{
+    /// Create a send file request builder.
+    ///
+    /// This method is used to upload browser [`Blob`] (or `File`) and share
+    /// it with channel subscribers.
+    ///
+    /// Instance of [`SendFileRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # async fn send(blob: web_sys::Blob) -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let result = pubnub
+    ///     .send_file()
+    ///     .channel("lobby")
+    ///     .name("cat.png")
+    ///     .blob(blob)
+    ///     .message("Look at this cat")
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// println!("file {} shared at: {}", result.id, result.timetoken);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Blob`]: https://developer.mozilla.org/en-US/docs/Web/API/Blob
+    pub fn send_file(&self) -> SendFileRequestBuilder<T, D> {
+        SendFileRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::builders::{generate_upload_url, publish_file_message};
+    use crate::{
+        core::{PubNubError, TransportMethod, TransportResponse},
+        test::{mock_client, MockTransport},
+    };
+
+    fn response(body: &str) -> TransportResponse {
+        TransportResponse {
+            status: 200,
+            body: Some(body.as_bytes().to_vec().into()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn generate_upload_url_for_file() {
+        let transport = MockTransport::new().with_default_response(response(
+            r#"{"status":200,"data":{"id":"file-id","name":"cat.png"},"file_upload_request":{"url":"https://storage.example.com/","method":"POST","expiration_date":"2024-01-01T00:01:00Z","form_fields":[{"key":"tagging","value":"<Tagging/>"},{"key":"key","value":"demo/cat.png"}]}}"#,
+        ));
+        let client = mock_client(transport.clone(), "user_id");
+
+        let result = generate_upload_url(&client, "room 1", "cat.png")
+            .await
+            .unwrap();
+
+        let request = transport.last_request().unwrap();
+        assert_eq!(
+            request.path,
+            "/v1/files/demo/channels/room%201/generate-upload-url"
+        );
+        assert!(matches!(request.method, TransportMethod::Post));
+        assert_eq!(request.body.unwrap(), br#"{"name":"cat.png"}"#);
+        assert_eq!(result.id, "file-id");
+        assert_eq!(result.upload_request.url, "https://storage.example.com/");
+        assert_eq!(
+            result
+                .upload_request
+                .form_fields
+                .iter()
+                .map(|field| field.key.as_str())
+                .collect::<Vec<_>>(),
+            ["tagging", "key"]
+        );
+    }
+
+    #[tokio::test]
+    async fn return_error_when_upload_url_not_generated() {
+        let transport = MockTransport::new().with_default_response(TransportResponse {
+            status: 403,
+            body: Some(
+                br#"{"status":403,"error":true,"service":"Access Manager","message":"Forbidden"}"#
+                    .to_vec()
+                    .into(),
+            ),
+            ..Default::default()
+        });
+        let client = mock_client(transport, "user_id");
+
+        let result = generate_upload_url(&client, "room", "cat.png").await;
+
+        assert!(matches!(result, Err(PubNubError::API { .. })));
+    }
+
+    #[cfg(feature = "subscribe")]
+    #[tokio::test]
+    async fn publish_file_message_receivable_as_file_update() {
+        use crate::dx::subscribe::{result::Envelope, Update};
+
+        let transport = MockTransport::new()
+            .with_default_response(response(r#"[1,"Sent","17000000000000000"]"#));
+        let client = mock_client(transport.clone(), "user_id");
+
+        let result = publish_file_message(&client, "room", "look", "file-id", "cat.png")
+            .await
+            .unwrap();
+
+        let request = transport.last_request().unwrap();
+        let (path, payload) = request.path.rsplit_once('/').unwrap();
+        assert_eq!(path, "/v1/files/publish-file/demo/demo/0/room/0");
+        assert_eq!(result.timetoken.as_u64(), 17_000_000_000_000_000);
+
+        // Published payload should be delivered to subscribers as file update.
+        let payload = percent_encoding::percent_decode_str(payload)
+            .decode_utf8()
+            .unwrap();
+        let envelope: Envelope = serde_json::from_str(&format!(
+            r#"{{"a":"1","f":0,"e":4,"i":"user_id","p":{{"t":"17000000000000000","r":31}},"c":"room","d":{payload}}}"#
+        ))
+        .unwrap();
+        let Ok(Update::File(file)) = Update::try_from(envelope) else {
+            panic!("Expected file update");
+        };
+        assert_eq!(file.id, "file-id");
+        assert_eq!(file.name, "cat.png");
+    }
+}
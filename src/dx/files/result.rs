@@ -0,0 +1,197 @@
+//! Files result module.
+//!
+//! This module contains the [`SendFileResult`] type and intermediate types
+//! which are used to represent the result of separate steps of file sharing.
+
+use crate::{
+    core::{service_response::APIErrorBody, PubNubError, ResponseMetadata, Timetoken},
+    lib::alloc::{string::String, vec::Vec},
+};
+
+/// The result of a send file operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SendFileResult {
+    /// Timetoken of published file message.
+    pub timetoken: Timetoken,
+
+    /// Unique identifier which has been assigned to the uploaded file.
+    pub id: String,
+
+    /// Name with which file has been stored.
+    pub name: String,
+}
+
+/// Information about request which should be used to upload file to the
+/// storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) struct GenerateFileUploadUrlResult {
+    /// Unique identifier which has been assigned to the file.
+    pub id: String,
+
+    /// Name with which file will be stored.
+    pub name: String,
+
+    /// File upload request information.
+    pub upload_request: FileUploadRequestBody,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(GenerateFileUploadUrlResult);
+
+/// The response body of a generate file upload URL operation.
+///
+/// It's used for deserialization of the generate file upload URL response.
+/// This type is an intermediate type between the raw response body and the
+/// [`GenerateFileUploadUrlResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) enum GenerateFileUploadUrlResponseBody {
+    /// This is a success response body for a generate file upload URL
+    /// operation in the Files service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {
+    ///         "id": "0c9a6f2e-8b4d-4f63-8e5a-1a2b3c4d5e6f",
+    ///         "name": "cat.png"
+    ///     },
+    ///     "file_upload_request": {
+    ///         "url": "https://pubnub-files.s3.amazonaws.com/",
+    ///         "method": "POST",
+    ///         "expiration_date": "2024-01-01T00:01:00Z",
+    ///         "form_fields": [
+    ///             { "key": "tagging", "value": "<Tagging>...</Tagging>" },
+    ///             { "key": "key", "value": "sub-c-.../cat.png" }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(GenerateFileUploadUrlSuccessBody),
+
+    /// This is an error response body for a generate file upload URL
+    /// operation in the Files service.
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful generate file upload URL REST API operation
+/// response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) struct GenerateFileUploadUrlSuccessBody {
+    /// Information about file which will be uploaded.
+    pub data: FileBody,
+
+    /// File upload request information.
+    pub file_upload_request: FileUploadRequestBody,
+}
+
+/// File information from Files service response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) struct FileBody {
+    /// Unique identifier which has been assigned to the file.
+    pub id: String,
+
+    /// Name with which file will be stored.
+    pub name: String,
+}
+
+/// File upload request information from Files service response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) struct FileUploadRequestBody {
+    /// Storage endpoint to which file should be uploaded.
+    pub url: String,
+
+    /// HTTP method which should be used for upload.
+    pub method: String,
+
+    /// Form fields which should be sent along with the file (in the same
+    /// order).
+    pub form_fields: Vec<FormFieldBody>,
+}
+
+/// Single form field of file upload request.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) struct FormFieldBody {
+    /// Form field name.
+    pub key: String,
+
+    /// Form field value.
+    pub value: String,
+}
+
+impl TryFrom<GenerateFileUploadUrlResponseBody> for GenerateFileUploadUrlResult {
+    type Error = PubNubError;
+
+    fn try_from(value: GenerateFileUploadUrlResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            GenerateFileUploadUrlResponseBody::SuccessResponse(resp) => Ok(Self {
+                id: resp.data.id,
+                name: resp.data.name,
+                upload_request: resp.file_upload_request,
+                metadata: Default::default(),
+            }),
+            GenerateFileUploadUrlResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a publish file message operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) struct PublishFileMessageResult {
+    /// Timetoken of published file message.
+    pub timetoken: Timetoken,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(PublishFileMessageResult);
+
+/// The response body of a publish file message operation.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::dx::files) enum PublishFileMessageResponseBody {
+    /// The response body of a publish file message operation in publish
+    /// service.
+    ///
+    /// # Example
+    /// ```json
+    /// [1, "Sent", "15815800000000000"]
+    /// ```
+    SuccessResponse(i32, String, Timetoken),
+
+    /// The response body of a publish file message operation in other
+    /// services.
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<PublishFileMessageResponseBody> for PublishFileMessageResult {
+    type Error = PubNubError;
+
+    fn try_from(value: PublishFileMessageResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            PublishFileMessageResponseBody::SuccessResponse(
+                error_indicator,
+                message,
+                timetoken,
+            ) => {
+                if error_indicator == 1 {
+                    Ok(Self {
+                        timetoken,
+                        metadata: Default::default(),
+                    })
+                } else {
+                    Err(PubNubError::general_api_error(message, None, None))
+                }
+            }
+            PublishFileMessageResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! # Health check module
+//!
+//! This module contains [`ClientHealth`] report which can be retrieved with
+//! [`PubNubClientInstance::health`] and used by liveness / readiness probes
+//! (for example, Kubernetes).
+//!
+//! [`PubNubClientInstance::health`]: crate::dx::pubnub_client::PubNubClientInstance::health
+
+use crate::{
+    core::{Deserializer, PubNubError, Timetoken, Transport},
+    dx::pubnub_client::PubNubClientInstance,
+};
+
+/// Event engine status.
+///
+/// Simplified state of subscribe or presence event engine.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EventEngineStatus {
+    /// Event engine hasn't been created yet (feature not used).
+    NotStarted,
+
+    /// Event engine doesn't have channels or groups to work with.
+    Idle,
+
+    /// Event engine performs initial request.
+    Connecting,
+
+    /// Event engine receives real-time updates or maintains presence.
+    Connected,
+
+    /// Event engine retries failed request.
+    Reconnecting,
+
+    /// Event engine has been stopped (disconnected) by user or network
+    /// monitor.
+    Stopped,
+
+    /// Event engine wasn't able to recover after failed request.
+    Failed,
+}
+
+/// Client health report.
+///
+/// Contains results of lightweight `time` probe request and event engines
+/// status at the moment of call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientHealth {
+    /// Server timetoken returned by `time` probe request.
+    ///
+    /// `None` if probe request failed.
+    pub server_timetoken: Option<Timetoken>,
+
+    /// `time` probe request failure reason.
+    pub probe_error: Option<PubNubError>,
+
+    /// `time` probe request duration.
+    pub probe_latency: core::time::Duration,
+
+    /// Subscribe event engine status.
+    #[cfg(feature = "subscribe")]
+    pub subscribe: EventEngineStatus,
+
+    /// Presence event engine status.
+    #[cfg(feature = "presence")]
+    pub presence: EventEngineStatus,
+
+    /// Unix timestamp (in seconds) of the last successful subscribe request.
+    ///
+    /// `None` if there were no successful subscribe requests yet.
+    #[cfg(feature = "subscribe")]
+    pub last_subscribe_timestamp: Option<i64>,
+}
+
+impl ClientHealth {
+    /// Whether [`PubNub API`] is reachable or not.
+    ///
+    /// Can be used as liveness probe result.
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    pub fn is_reachable(&self) -> bool {
+        self.probe_error.is_none()
+    }
+
+    /// Whether client is ready to work or not.
+    ///
+    /// Client is ready when [`PubNub API`] is reachable and none of event
+    /// engines failed. Can be used as readiness probe result.
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    pub fn is_ready(&self) -> bool {
+        #[allow(unused_mut)]
+        let mut ready = self.is_reachable();
+
+        #[cfg(feature = "subscribe")]
+        {
+            ready &= !matches!(self.subscribe, EventEngineStatus::Failed);
+        }
+
+        #[cfg(feature = "presence")]
+        {
+            ready &= !matches!(self.presence, EventEngineStatus::Failed);
+        }
+
+        ready
+    }
+}
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    T: Transport + Send + 'static,
+    D: Deserializer + Send + 'static,
+{
+    /// Check client health.
+    ///
+    /// Sends lightweight `time` probe request and reports it result together
+    /// with event engines status and timestamp of the last successful
+    /// subscribe request.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         subscribe_key: "demo",
+    ///         publish_key: Some("demo"),
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("user_id")
+    ///     .build()?;
+    ///
+    /// let health = client.health().await;
+    /// println!("Client is ready: {}", health.is_ready());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health(&self) -> ClientHealth {
+        let started_at = std::time::Instant::now();
+        let probe = self.time().execute().await;
+        let probe_latency = started_at.elapsed();
+
+        ClientHealth {
+            server_timetoken: probe.as_ref().ok().map(|result| result.timetoken),
+            probe_error: probe.err(),
+            probe_latency,
+
+            #[cfg(feature = "subscribe")]
+            subscribe: self
+                .subscription_manager(false)
+                .read()
+                .as_ref()
+                .map_or(EventEngineStatus::NotStarted, |manager| manager.status()),
+
+            #[cfg(feature = "presence")]
+            presence: self
+                .presence_manager(false)
+                .read()
+                .as_ref()
+                .map_or(EventEngineStatus::NotStarted, |manager| manager.status()),
+
+            #[cfg(feature = "subscribe")]
+            last_subscribe_timestamp: *self.last_subscribe_timestamp.read(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{
+        core::{TransportRequest, TransportResponse},
+        Keyset, PubNubClientBuilder,
+    };
+
+    #[derive(Default)]
+    struct MockTransport;
+
+    impl Transport for MockTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            if request.path.starts_with("/time") {
+                return Ok(TransportResponse {
+                    status: 200,
+                    body: Some(b"[17000000000000000]".to_vec().into()),
+                    ..Default::default()
+                });
+            }
+
+            Err(PubNubError::Transport {
+                details: error_details!("Network unreachable"),
+                response: None,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn report_reachable_client_without_event_engines() {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .build()
+            .unwrap();
+
+        let health = client.health().await;
+
+        assert!(health.is_reachable());
+        assert!(health.is_ready());
+        assert_eq!(
+            health.server_timetoken,
+            Some(Timetoken::new(17_000_000_000_000_000))
+        );
+        #[cfg(feature = "subscribe")]
+        {
+            assert_eq!(health.subscribe, EventEngineStatus::NotStarted);
+            assert_eq!(health.last_subscribe_timestamp, None);
+        }
+    }
+
+    #[test]
+    fn not_be_ready_when_event_engine_failed() {
+        let health = ClientHealth {
+            server_timetoken: Some(Timetoken::new(17_000_000_000_000_000)),
+            probe_error: None,
+            probe_latency: Default::default(),
+            #[cfg(feature = "subscribe")]
+            subscribe: EventEngineStatus::Failed,
+            #[cfg(feature = "presence")]
+            presence: EventEngineStatus::Idle,
+            #[cfg(feature = "subscribe")]
+            last_subscribe_timestamp: None,
+        };
+
+        assert!(health.is_reachable());
+        #[cfg(feature = "subscribe")]
+        assert!(!health.is_ready());
+    }
+}
@@ -0,0 +1,261 @@
+//! # Fetch messages request builder.
+//!
+//! The [`FetchMessagesRequestBuilder`] lets you make and execute a request
+//! which will return messages stored for specified channels.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::url_encoded_channels,
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Timetoken, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        history::result::{FetchMessagesResponseBody, FetchMessagesResult},
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The Fetch Messages request builder.
+///
+/// Allows you to build a Fetch Messages request that is sent to the
+/// [`PubNub`] network.
+///
+/// This struct is used by the [`fetch_messages`] method of the
+/// [`PubNubClient`].
+///
+/// [`fetch_messages`]: crate::dx::pubnub_client::PubNubClientInstance::fetch_messages
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::history)", validate = "Self::validate"),
+    no_std
+)]
+pub struct FetchMessagesRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::history)"), setter(custom))]
+    pub(in crate::dx::history) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channels from which stored messages should be retrieved.
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(custom),
+        default = "vec![]"
+    )]
+    pub(in crate::dx::history) channels: Vec<String>,
+
+    /// Timetoken delimiting the start (exclusive) of time slice from which
+    /// messages should be retrieved.
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::history) start: Option<Timetoken>,
+
+    /// Timetoken delimiting the end (inclusive) of time slice from which
+    /// messages should be retrieved.
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::history) end: Option<Timetoken>,
+
+    /// Maximum number of messages which should be returned for each channel.
+    ///
+    /// Service returns up to `100` messages for single channel and up to `25`
+    /// messages per channel for multiple channels.
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::history) count: Option<usize>,
+
+    /// Whether messages should be traversed from the oldest to the newest.
+    #[builder(field(vis = "pub(in crate::dx::history)"), default = "false")]
+    pub(in crate::dx::history) reverse: bool,
+
+    /// Whether actions added to the messages should be included.
+    ///
+    /// Messages with actions can be retrieved only for single channel.
+    #[builder(field(vis = "pub(in crate::dx::history)"), default = "false")]
+    pub(in crate::dx::history) include_message_actions: bool,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::history) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(FetchMessagesRequestBuilderError);
+impl_detached_request!(
+    FetchMessagesRequest,
+    FetchMessagesRequestBuilder,
+    FetchMessagesResult
+);
+
+impl<T, D> FetchMessagesRequestBuilder<T, D> {
+    /// Channels from which stored messages should be retrieved.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// fetch messages request instance.
+    fn validate(&self) -> Result<(), String> {
+        let Some(client) = self.pubnub_client.as_ref() else {
+            return Err("PubNub client instance not set.".into());
+        };
+
+        if client.config.subscribe_key.is_empty() {
+            return Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into());
+        }
+
+        let channels_len = self.channels.as_ref().map_or(0, |v| v.len());
+        if channels_len == 0 {
+            return Err("At least one channel should be provided".into());
+        }
+
+        if self.include_message_actions.unwrap_or(false) && channels_len > 1 {
+            return Err("Message actions can be included only for single channel".into());
+        }
+
+        if self.count.flatten().is_some_and(|count| count == 0) {
+            return Err("Number of messages should be greater than zero".into());
+        }
+
+        Ok(())
+    }
+
+    /// Build [`FetchMessagesRequest`] from builder.
+    fn request(self) -> Result<FetchMessagesRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> FetchMessagesRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::history) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        self.count
+            .and_then(|count| query.insert("max".into(), count.to_string()));
+        self.start
+            .and_then(|start| query.insert("start".into(), start.to_string()));
+        self.end
+            .and_then(|end| query.insert("end".into(), end.to_string()));
+        self.reverse
+            .then(|| query.insert("reverse".into(), "true".into()));
+        query.insert("include_uuid".into(), "true".into());
+        query.insert("include_message_type".into(), "true".into());
+        query.insert("include_custom_message_type".into(), "true".into());
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v3/{}/sub-key/{}/channel/{}",
+                if self.include_message_actions {
+                    "history-with-actions"
+                } else {
+                    "history"
+                },
+                &config.subscribe_key,
+                url_encoded_channels(&self.channels),
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: None,
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> FetchMessagesRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<FetchMessagesResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<FetchMessagesResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+            .map(|result: FetchMessagesResult| match &client.cryptor {
+                Some(cryptor) => result.decrypt(cryptor),
+                None => result,
+            })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> FetchMessagesRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<FetchMessagesResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<FetchMessagesResponseBody, _, _, _>(&client.transport, deserializer)
+            .map(|result: FetchMessagesResult| match &client.cryptor {
+                Some(cryptor) => result.decrypt(cryptor),
+                None => result,
+            })
+    }
+}
@@ -0,0 +1,253 @@
+//! # History module.
+//!
+//! The history module allows to retrieve messages which have been stored by
+//! [`PubNub`] Message Persistence for specified channels.
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use result::{
+    FetchMessagesResponseBody, FetchMessagesResult, HistoryMessage, HistoryMessageAction,
+    HistoryMessageType,
+};
+pub mod result;
+
+#[doc(inline)]
+pub use builders::{FetchMessagesRequest, FetchMessagesRequestBuilder};
+pub mod builders;
+
+use crate::{core::Deserializer, dx::pubnub_client::PubNubClientInstance};
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    D: Deserializer,
+{
+    /// Create a fetch messages request builder.
+    ///
+    /// This method is used to retrieve messages which have been stored for
+    /// channels.
+    ///
+    /// Instance of [`FetchMessagesRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: None,
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let result = pubnub
+    ///     .fetch_messages()
+    ///     .channels(["lobby"])
+    ///     .count(25)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// for message in result.channels.get("lobby").into_iter().flatten() {
+    ///     println!("{}: {:?}", message.timetoken, message.message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_messages(&self) -> FetchMessagesRequestBuilder<T, D> {
+        FetchMessagesRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, Timetoken, Transport, TransportRequest, TransportResponse},
+        providers::deserialization_serde::DeserializerSerde,
+        Keyset, PubNubClientBuilder, PubNubGenericClient,
+    };
+
+    struct MockTransport {
+        path: &'static str,
+        body: &'static str,
+    }
+
+    impl Transport for MockTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            assert_eq!(request.path, self.path);
+            assert_eq!(request.query_parameters.get("max"), Some(&"10".into()));
+            assert_eq!(
+                request.query_parameters.get("start"),
+                Some(&"17000000000000000".into())
+            );
+            assert_eq!(request.query_parameters.get("end"), None);
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(self.body.as_bytes().to_vec().into()),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn client(
+        path: &'static str,
+        body: &'static str,
+    ) -> PubNubGenericClient<MockTransport, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(MockTransport { path, body })
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .build()
+            .unwrap()
+    }
+
+    async fn fetch_messages(body: &'static str) -> Result<FetchMessagesResult, PubNubError> {
+        client(
+            "/v3/history/sub-key/demo/channel/channel-a,channel%20b",
+            body,
+        )
+        .fetch_messages()
+        .channels(["channel-a", "channel b"])
+        .start(17_000_000_000_000_000u64)
+        .count(10)
+        .reverse(true)
+        .execute()
+        .await
+    }
+
+    #[test]
+    fn not_build_request_without_channels() {
+        assert!(client("", "").fetch_messages().build().is_err());
+    }
+
+    #[test]
+    fn not_build_request_with_actions_for_multiple_channels() {
+        let request = client("", "")
+            .fetch_messages()
+            .channels(["channel-a", "channel-b"])
+            .include_message_actions(true)
+            .build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn return_messages_for_channels() {
+        let result = fetch_messages(
+            r#"{"status":200,"error":false,"error_message":"","channels":{
+                "channel-a":[
+                    {"message":{"text":"hello"},"timetoken":"17000000000000001","uuid":"user-1","message_type":null},
+                    {"message":{"file":{"id":"1","name":"a.txt"}},"timetoken":"17000000000000002","uuid":"user-2","message_type":4,"custom_message_type":"upload"}
+                ],
+                "channel b":[]
+            }}"#,
+        )
+        .await
+        .unwrap();
+
+        let messages = result.channels.get("channel-a").unwrap();
+        assert!(result.channels.get("channel b").unwrap().is_empty());
+        assert_eq!(messages.len(), 2);
+        assert_eq!(
+            messages[0].timetoken,
+            Timetoken::new(17_000_000_000_000_001)
+        );
+        assert_eq!(messages[0].message, br#"{"text":"hello"}"#.to_vec());
+        assert_eq!(messages[0].sender.as_deref(), Some("user-1"));
+        assert_eq!(messages[0].message_type, HistoryMessageType::Message);
+        assert!(messages[0].actions.is_empty());
+        assert_eq!(messages[1].message_type, HistoryMessageType::File);
+        assert_eq!(messages[1].r#type.as_deref(), Some("upload"));
+    }
+
+    #[tokio::test]
+    async fn return_messages_with_actions() {
+        let result = client(
+            "/v3/history-with-actions/sub-key/demo/channel/channel-a",
+            r#"{"status":200,"error":false,"error_message":"","channels":{
+                "channel-a":[
+                    {"message":"hello","timetoken":"17000000000000001","uuid":"user-1","actions":{
+                        "reaction":{
+                            "smiley_face":[{"uuid":"user-2","actionTimetoken":"17000000000000003"}],
+                            "thumbs_up":[{"uuid":"user-3","actionTimetoken":"17000000000000002"}]
+                        }
+                    }}
+                ]
+            },"more":{"url":"/v3/history-with-actions/sub-key/demo/channel/channel-a?start=17000000000000001&max=10","start":"17000000000000001","max":10}}"#,
+        )
+        .fetch_messages()
+        .channels(["channel-a"])
+        .start(17_000_000_000_000_000u64)
+        .count(10)
+        .include_message_actions(true)
+        .execute()
+        .await
+        .unwrap();
+
+        let actions = &result.channels.get("channel-a").unwrap()[0].actions;
+        assert_eq!(
+            actions,
+            &vec![
+                HistoryMessageAction {
+                    r#type: "reaction".into(),
+                    value: "thumbs_up".into(),
+                    user_id: "user-3".into(),
+                    action_timetoken: Timetoken::new(17_000_000_000_000_002),
+                },
+                HistoryMessageAction {
+                    r#type: "reaction".into(),
+                    value: "smiley_face".into(),
+                    user_id: "user-2".into(),
+                    action_timetoken: Timetoken::new(17_000_000_000_000_003),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn return_error_for_error_response() {
+        let result = fetch_messages(
+            r#"{"status":403,"error":true,"error_message":"Forbidden","channels":{}}"#,
+        )
+        .await;
+
+        assert!(matches!(result, Err(PubNubError::API { status: 403, .. })));
+    }
+
+    #[tokio::test]
+    async fn execute_detached_request_with_client() {
+        let request = FetchMessagesRequest::builder()
+            .channels(["channel-a", "channel b"])
+            .start(17_000_000_000_000_000u64)
+            .count(10);
+        let client = client(
+            "/v3/history/sub-key/demo/channel/channel-a,channel%20b",
+            r#"{"status":200,"error":false,"error_message":"","channels":{"channel-a":[],"channel b":[]}}"#,
+        );
+
+        let result = request.try_execute_with(&client).await.unwrap();
+
+        assert!(result.channels.get("channel-a").unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn return_err_for_detached_request_without_client() {
+        let result = FetchMessagesRequest::<MockTransport, DeserializerSerde>::builder()
+            .channels(["channel-a"])
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,362 @@
+//! History result module.
+//!
+//! This module contains the [`FetchMessagesResult`] type.
+//! The [`FetchMessagesResult`] type is used to represent the result of a fetch
+//! messages operation.
+
+use base64::{engine::general_purpose, Engine};
+
+use crate::{
+    core::{
+        service_response::APIErrorBody, Bytes, CryptoProvider, PubNubError, ResponseMetadata,
+        Timetoken,
+    },
+    lib::{
+        alloc::{string::String, sync::Arc, vec::Vec},
+        collections::HashMap,
+    },
+};
+
+/// Message type which is used by [`PubNub`] to mark shared files.
+///
+/// [`PubNub`]: https://www.pubnub.com
+const FILE_MESSAGE_TYPE: u32 = 4;
+
+/// The result of a fetch messages operation.
+///
+/// It contains messages which has been stored for each requested channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchMessagesResult {
+    /// Stored messages.
+    ///
+    /// Messages grouped by name of the channel in which they have been
+    /// published.
+    pub channels: HashMap<String, Vec<HistoryMessage>>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(FetchMessagesResult);
+
+/// Stored message information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryMessage {
+    /// Time when message has been published.
+    pub timetoken: Timetoken,
+
+    /// Data published along with message.
+    pub message: Bytes,
+
+    /// Identifier of client which sent message.
+    pub sender: Option<String>,
+
+    /// [`PubNub`] defined message type.
+    ///
+    /// [`PubNub`]: https://www.pubnub.com
+    pub message_type: HistoryMessageType,
+
+    /// User provided message type (set only when [`publish`] called with
+    /// `r#type`).
+    ///
+    /// [`publish`]: crate::dx::publish
+    pub r#type: Option<String>,
+
+    /// Actions which have been added to the message.
+    ///
+    /// Actions sorted by time when they have been added and set only when
+    /// messages requested with [`include_message_actions`].
+    ///
+    /// [`include_message_actions`]: crate::dx::history::FetchMessagesRequestBuilder::include_message_actions
+    pub actions: Vec<HistoryMessageAction>,
+
+    /// Decryption error details.
+    ///
+    /// Error is set when [`PubNubClient`] configured with cryptor, and it
+    /// wasn't able to decrypt [`message`] in this message.
+    ///
+    /// [`PubNubClient`]: crate::PubNubClient
+    /// [`message`]: HistoryMessage::message
+    pub decryption_error: Option<PubNubError>,
+}
+
+/// Action added to the stored message.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HistoryMessageAction {
+    /// Message action type (for example, `reaction`).
+    pub r#type: String,
+
+    /// Message action value (for example, `smiley_face`).
+    pub value: String,
+
+    /// Identifier of client which added action.
+    pub user_id: String,
+
+    /// Time when action has been added.
+    pub action_timetoken: Timetoken,
+}
+
+/// Stored message type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HistoryMessageType {
+    /// Regular published message.
+    Message,
+
+    /// Shared file information.
+    File,
+}
+
+/// The response body of a fetch messages operation.
+///
+/// It's used for deserialization of the fetch messages response. This type is
+/// an intermediate type between the raw response body and the
+/// [`FetchMessagesResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchMessagesResponseBody {
+    /// This is a success response body for a fetch messages operation in the
+    /// Storage service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "error": false,
+    ///     "error_message": "",
+    ///     "channels": {
+    ///         "my-channel": [
+    ///             {
+    ///                 "message": "hello",
+    ///                 "timetoken": "16939278954738214",
+    ///                 "uuid": "user-1",
+    ///                 "message_type": null
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(FetchMessagesSuccessBody),
+
+    /// This is an error response body for a fetch messages operation in the
+    /// Storage service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "message": "Forbidden",
+    ///     "payload": {
+    ///         "channels": [
+    ///             "test-channel1"
+    ///         ]
+    ///     },
+    ///     "error": true,
+    ///     "service": "Access Manager",
+    ///     "status": 403
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of fetch messages REST API operation response.
+///
+/// Storage service uses same structure for error responses (with `error` flag
+/// set and empty `channels` dictionary).
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchMessagesSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// There is a flag that tells if this is an error response.
+    pub error: bool,
+
+    /// A message explaining what went wrong.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub error_message: String,
+
+    /// Stored messages grouped by channel name.
+    pub channels: HashMap<String, Vec<HistoryMessageBody>>,
+}
+
+/// Single stored message from fetch messages response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryMessageBody {
+    /// Published message payload.
+    #[cfg(feature = "serde")]
+    pub message: serde_json::Value,
+
+    /// Published message payload.
+    #[cfg(not(feature = "serde"))]
+    pub message: Vec<u8>,
+
+    /// Time when message has been published.
+    pub timetoken: Timetoken,
+
+    /// Identifier of client which sent message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub uuid: Option<String>,
+
+    /// Numeric [`PubNub`] defined message type (`null` for regular messages).
+    ///
+    /// [`PubNub`]: https://www.pubnub.com
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message_type: Option<u32>,
+
+    /// User provided message type.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom_message_type: Option<String>,
+
+    /// Actions added to the message grouped by action type and value.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "reaction": {
+    ///         "smiley_face": [
+    ///             {
+    ///                 "uuid": "user-1",
+    ///                 "actionTimetoken": "16939278954738215"
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub actions: Option<HashMap<String, HashMap<String, Vec<HistoryMessageActionBody>>>>,
+}
+
+/// Information about user who added action to the stored message.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryMessageActionBody {
+    /// Identifier of client which added action.
+    pub uuid: String,
+
+    /// Time when action has been added.
+    #[cfg_attr(feature = "serde", serde(rename = "actionTimetoken"))]
+    pub action_timetoken: Timetoken,
+}
+
+impl TryFrom<FetchMessagesResponseBody> for FetchMessagesResult {
+    type Error = PubNubError;
+
+    fn try_from(value: FetchMessagesResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            FetchMessagesResponseBody::SuccessResponse(resp) if resp.error => {
+                Err(APIErrorBody::AsObjectWithErrorMessage {
+                    status: resp.status,
+                    error: resp.error,
+                    error_message: resp.error_message,
+                }
+                .into())
+            }
+            FetchMessagesResponseBody::SuccessResponse(resp) => Ok(FetchMessagesResult {
+                channels: resp
+                    .channels
+                    .into_iter()
+                    .map(|(channel, messages)| {
+                        (channel, messages.into_iter().map(Into::into).collect())
+                    })
+                    .collect(),
+                metadata: Default::default(),
+            }),
+            FetchMessagesResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+impl From<HistoryMessageBody> for HistoryMessage {
+    fn from(value: HistoryMessageBody) -> Self {
+        let message_type = match value.message_type {
+            Some(FILE_MESSAGE_TYPE) => HistoryMessageType::File,
+            _ => HistoryMessageType::Message,
+        };
+        let mut actions = value
+            .actions
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|(r#type, values)| {
+                values.into_iter().flat_map(move |(value, users)| {
+                    let r#type = r#type.clone();
+                    users.into_iter().map(move |user| HistoryMessageAction {
+                        r#type: r#type.clone(),
+                        value: value.clone(),
+                        user_id: user.uuid,
+                        action_timetoken: user.action_timetoken,
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        actions.sort_by_key(|action| action.action_timetoken);
+
+        Self {
+            timetoken: value.timetoken,
+            #[cfg(feature = "serde")]
+            message: serde_json::to_vec(&value.message)
+                .unwrap_or_default()
+                .into(),
+            #[cfg(not(feature = "serde"))]
+            message: value.message.into(),
+            sender: value.uuid,
+            message_type,
+            r#type: value.custom_message_type,
+            actions,
+            decryption_error: None,
+        }
+    }
+}
+
+impl FetchMessagesResult {
+    /// Decrypt payload of all messages if possible.
+    pub(in crate::dx::history) fn decrypt(
+        mut self,
+        cryptor: &Arc<dyn CryptoProvider + Send + Sync>,
+    ) -> Self {
+        self.channels
+            .values_mut()
+            .flat_map(|messages| messages.iter_mut())
+            .for_each(|message| message.decrypt(cryptor));
+
+        self
+    }
+}
+
+impl HistoryMessage {
+    /// Deserialize [`message`] into `T`.
+    ///
+    /// # Errors
+    /// Returns [`PubNubError::Deserialization`] if [`message`] can't be
+    /// deserialized as `T`.
+    ///
+    /// [`message`]: HistoryMessage::message
+    #[cfg(feature = "serde")]
+    pub fn message_as<T>(&self) -> Result<T, PubNubError>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        serde_json::from_slice(&self.message).map_err(|err| PubNubError::Deserialization {
+            details: error_details!(err),
+        })
+    }
+
+    /// Decrypt message payload if possible.
+    fn decrypt(&mut self, cryptor: &Arc<dyn CryptoProvider + Send + Sync>) {
+        let lossy_string = String::from_utf8_lossy(&self.message);
+        let trimmed = lossy_string.trim_matches('"');
+        let decryption_result = general_purpose::STANDARD
+            .decode(trimmed)
+            .map_err(|err| PubNubError::Decryption {
+                details: error_details!(err),
+            })
+            .and_then(|base64_bytes| cryptor.decrypt(base64_bytes));
+
+        match decryption_result {
+            Ok(bytes) => self.message = bytes.into(),
+            Err(error) => self.decryption_error = Some(error),
+        };
+    }
+}
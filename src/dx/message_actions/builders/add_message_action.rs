@@ -0,0 +1,214 @@
+//! # PubNub add message action module.
+//!
+//! The [`AddMessageActionRequestBuilder`] lets you make and execute a request
+//! which will add action (like reaction or read receipt) to the published
+//! message.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+            json::{json_object, json_string},
+        },
+        Deserializer, PubNubError, Timetoken, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        message_actions::{
+            builders,
+            result::{AddMessageActionResponseBody, AddMessageActionResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+};
+
+/// Maximum length of message action type accepted by [`PubNub`] service.
+///
+/// [`PubNub`]: https://www.pubnub.com
+const MAX_ACTION_TYPE_LENGTH: usize = 15;
+
+/// The [`AddMessageActionRequestBuilder`] is used to build add message action
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`add_message_action`] method of the
+/// [`PubNubClient`].
+///
+/// [`add_message_action`]: crate::dx::pubnub_client::PubNubClientInstance::add_message_action
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(
+        vis = "pub(in crate::dx::message_actions)",
+        validate = "Self::validate"
+    ),
+    no_std
+)]
+pub struct AddMessageActionRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(custom))]
+    pub(in crate::dx::message_actions) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of channel in which target message has been published.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) channel: String,
+
+    /// Timetoken of message to which action should be added.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) message_timetoken: Timetoken,
+
+    /// Message action type (for example, `reaction`).
+    ///
+    /// Type can't be longer than `15` characters.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) action_type: String,
+
+    /// Value associated with message action type (for example,
+    /// `smiley_face`).
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) value: String,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(AddMessageActionRequestBuilderError);
+
+impl<T, D> AddMessageActionRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// add message action request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client).and_then(|_| {
+            if self
+                .channel
+                .as_ref()
+                .is_none_or(|channel| channel.is_empty())
+            {
+                Err("Channel is missing".into())
+            } else if self.message_timetoken.is_none() {
+                Err("Message timetoken is missing".into())
+            } else if self
+                .action_type
+                .as_ref()
+                .is_none_or(|action_type| action_type.is_empty())
+            {
+                Err("Action type is missing".into())
+            } else if self
+                .action_type
+                .as_ref()
+                .is_some_and(|action_type| action_type.chars().count() > MAX_ACTION_TYPE_LENGTH)
+            {
+                Err(format!(
+                    "Action type can't be longer than {MAX_ACTION_TYPE_LENGTH} characters"
+                ))
+            } else if self.value.as_ref().is_none_or(|value| value.is_empty()) {
+                Err("Action value is missing".into())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Build [`AddMessageActionRequest`] from builder.
+    fn request(self) -> Result<AddMessageActionRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> AddMessageActionRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::message_actions) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let action_type = json_string(&self.action_type);
+        let value = json_string(&self.value);
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/message-actions/{}/channel/{}/message/{}",
+                &config.subscribe_key,
+                url_encode_extended(self.channel.as_bytes(), UrlEncodeExtension::NonChannelPath),
+                self.message_timetoken
+            ),
+            query_parameters: HashMap::new(),
+            method: TransportMethod::Post,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(json_object([
+                ("type", action_type.as_bytes()),
+                ("value", value.as_bytes()),
+            ])),
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> AddMessageActionRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<AddMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AddMessageActionResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> AddMessageActionRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<AddMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<AddMessageActionResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
@@ -0,0 +1,236 @@
+//! # PubNub get message actions module.
+//!
+//! The [`GetMessageActionsRequestBuilder`] lets you make and execute a request
+//! which will return list of actions which have been added to the messages
+//! published in channel.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Timetoken, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        message_actions::{
+            builders,
+            result::{GetMessageActionsResponseBody, GetMessageActionsResult, MessageActionsPage},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+};
+
+/// Maximum number of message actions which can be returned by [`PubNub`]
+/// service in single response.
+///
+/// [`PubNub`]: https://www.pubnub.com
+const MAX_ACTIONS_LIMIT: usize = 100;
+
+/// The [`GetMessageActionsRequestBuilder`] is used to build get message
+/// actions request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_message_actions`] method of the
+/// [`PubNubClient`].
+///
+/// [`get_message_actions`]: crate::dx::pubnub_client::PubNubClientInstance::get_message_actions
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(
+        vis = "pub(in crate::dx::message_actions)",
+        validate = "Self::validate"
+    ),
+    no_std
+)]
+pub struct GetMessageActionsRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(custom))]
+    pub(in crate::dx::message_actions) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of channel from which message actions should be retrieved.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) channel: String,
+
+    /// Message action timetoken delimiting the start (exclusive) of time slice
+    /// from which actions should be retrieved.
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) start: Option<Timetoken>,
+
+    /// Message action timetoken delimiting the end (inclusive) of time slice
+    /// from which actions should be retrieved.
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) end: Option<Timetoken>,
+
+    /// Maximum number of message actions which should be returned.
+    ///
+    /// Service returns up to `100` actions in single response.
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) limit: Option<usize>,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(GetMessageActionsRequestBuilderError);
+
+impl<T, D> GetMessageActionsRequestBuilder<T, D> {
+    /// Continue listing from the page returned with previous response.
+    ///
+    /// Sets [`start`], [`end`] and [`limit`] from the [`more`] information.
+    ///
+    /// [`start`]: GetMessageActionsRequestBuilder::start
+    /// [`end`]: GetMessageActionsRequestBuilder::end
+    /// [`limit`]: GetMessageActionsRequestBuilder::limit
+    /// [`more`]: crate::dx::message_actions::GetMessageActionsResult::more
+    pub fn page(mut self, page: MessageActionsPage) -> Self {
+        self.start = Some(Some(page.start));
+        self.end = Some(page.end);
+        self.limit = Some(Some(page.limit));
+        self
+    }
+
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get message actions request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client).and_then(|_| {
+            if self
+                .channel
+                .as_ref()
+                .is_none_or(|channel| channel.is_empty())
+            {
+                Err("Channel is missing".into())
+            } else if self
+                .limit
+                .flatten()
+                .is_some_and(|limit| limit == 0 || limit > MAX_ACTIONS_LIMIT)
+            {
+                Err(format!(
+                    "Limit should be in range from 1 to {MAX_ACTIONS_LIMIT}"
+                ))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Build [`GetMessageActionsRequest`] from builder.
+    fn request(self) -> Result<GetMessageActionsRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> GetMessageActionsRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::message_actions) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        self.start
+            .and_then(|start| query.insert("start".into(), start.to_string()));
+        self.end
+            .and_then(|end| query.insert("end".into(), end.to_string()));
+        self.limit
+            .and_then(|limit| query.insert("limit".into(), limit.to_string()));
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/message-actions/{}/channel/{}",
+                &config.subscribe_key,
+                url_encode_extended(self.channel.as_bytes(), UrlEncodeExtension::NonChannelPath),
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: None,
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> GetMessageActionsRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<GetMessageActionsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<GetMessageActionsResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetMessageActionsRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<GetMessageActionsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request.send_blocking::<GetMessageActionsResponseBody, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,185 @@
+//! # PubNub remove message action module.
+//!
+//! The [`RemoveMessageActionRequestBuilder`] lets you make and execute a
+//! request which will remove previously added action from the published
+//! message.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Timetoken, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        message_actions::{
+            builders,
+            result::{RemoveMessageActionResponseBody, RemoveMessageActionResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`RemoveMessageActionRequestBuilder`] is used to build remove message
+/// action request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`remove_message_action`] method of the
+/// [`PubNubClient`].
+///
+/// [`remove_message_action`]: crate::dx::pubnub_client::PubNubClientInstance::remove_message_action
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(
+        vis = "pub(in crate::dx::message_actions)",
+        validate = "Self::validate"
+    ),
+    no_std
+)]
+pub struct RemoveMessageActionRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(custom))]
+    pub(in crate::dx::message_actions) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of channel in which target message has been published.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) channel: String,
+
+    /// Timetoken of message from which action should be removed.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) message_timetoken: Timetoken,
+
+    /// Timetoken of message action which should be removed.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) action_timetoken: Timetoken,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(RemoveMessageActionRequestBuilderError);
+
+impl<T, D> RemoveMessageActionRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// remove message action request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client).and_then(|_| {
+            if self
+                .channel
+                .as_ref()
+                .is_none_or(|channel| channel.is_empty())
+            {
+                Err("Channel is missing".into())
+            } else if self.message_timetoken.is_none() {
+                Err("Message timetoken is missing".into())
+            } else if self.action_timetoken.is_none() {
+                Err("Action timetoken is missing".into())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Build [`RemoveMessageActionRequest`] from builder.
+    fn request(self) -> Result<RemoveMessageActionRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> RemoveMessageActionRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::message_actions) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/message-actions/{}/channel/{}/message/{}/action/{}",
+                &config.subscribe_key,
+                url_encode_extended(self.channel.as_bytes(), UrlEncodeExtension::NonChannelPath),
+                self.message_timetoken,
+                self.action_timetoken
+            ),
+            query_parameters: HashMap::new(),
+            method: TransportMethod::Delete,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: None,
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> RemoveMessageActionRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<RemoveMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<RemoveMessageActionResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> RemoveMessageActionRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<RemoveMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request.send_blocking::<RemoveMessageActionResponseBody, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,392 @@
+//! # Message Actions module.
+//!
+//! The message actions module allows to manage actions (like reactions or read
+//! receipts) which are attached to the messages published with [`PubNub`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use result::{
+    AddMessageActionResponseBody, AddMessageActionResult, GetMessageActionsResponseBody,
+    GetMessageActionsResult, MessageActionBody, MessageActionData, MessageActionsPage,
+    RemoveMessageActionResponseBody, RemoveMessageActionResult,
+};
+pub mod result;
+
+#[doc(inline)]
+pub use builders::*;
+pub mod builders;
+
+use crate::{core::Deserializer, dx::pubnub_client::PubNubClientInstance};
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    D: Deserializer,
+{
+    /// Create an add message action request builder.
+    ///
+    /// This method is used to add action (like reaction or read receipt) to
+    /// the published message.
+    ///
+    /// Instance of [`AddMessageActionRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let result = pubnub
+    ///     .add_message_action()
+    ///     .channel("lobby")
+    ///     .message_timetoken(16939278954738214u64)
+    ///     .action_type("reaction")
+    ///     .value("smiley_face")
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// println!("action added at: {}", result.action_timetoken());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_message_action(&self) -> AddMessageActionRequestBuilder<T, D> {
+        AddMessageActionRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove message action request builder.
+    ///
+    /// This method is used to remove previously added action from the
+    /// published message.
+    ///
+    /// Instance of [`RemoveMessageActionRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// pubnub
+    ///     .remove_message_action()
+    ///     .channel("lobby")
+    ///     .message_timetoken(16939278954738214u64)
+    ///     .action_timetoken(16939278954738215u64)
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_message_action(&self) -> RemoveMessageActionRequestBuilder<T, D> {
+        RemoveMessageActionRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get message actions request builder.
+    ///
+    /// This method is used to retrieve actions which have been added to the
+    /// messages published in channel.
+    ///
+    /// Instance of [`GetMessageActionsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let mut result = pubnub
+    ///     .get_message_actions()
+    ///     .channel("lobby")
+    ///     .limit(25)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// while let Some(page) = result.more {
+    ///     result = pubnub
+    ///         .get_message_actions()
+    ///         .channel("lobby")
+    ///         .page(page)
+    ///         .execute()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_message_actions(&self) -> GetMessageActionsRequestBuilder<T, D> {
+        GetMessageActionsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, Timetoken, TransportMethod, TransportRequest, TransportResponse},
+        providers::deserialization_serde::DeserializerSerde,
+        test::{mock_client, MockTransport},
+        PubNubGenericClient,
+    };
+
+    fn client(
+        status: u16,
+        body: &'static str,
+        verify: fn(&TransportRequest),
+    ) -> PubNubGenericClient<MockTransport, DeserializerSerde> {
+        let transport = MockTransport::new()
+            .with_default_response(TransportResponse {
+                status,
+                body: Some(body.as_bytes().to_vec().into()),
+                ..Default::default()
+            })
+            .with_request_check(verify);
+
+        mock_client(transport, "user_id")
+    }
+
+    async fn add_message_action(
+        status: u16,
+        body: &'static str,
+    ) -> Result<AddMessageActionResult, PubNubError> {
+        client(status, body, |request| {
+            assert_eq!(
+                request.path,
+                "/v1/message-actions/demo/channel/channel%20a/message/17000000000000000"
+            );
+            assert_eq!(request.method, TransportMethod::Post);
+            assert_eq!(
+                request.body,
+                Some(br#"{"type":"reaction","value":"smiley_face"}"#.to_vec())
+            );
+        })
+        .add_message_action()
+        .channel("channel a")
+        .message_timetoken(17_000_000_000_000_000u64)
+        .action_type("reaction")
+        .value("smiley_face")
+        .execute()
+        .await
+    }
+
+    async fn remove_message_action(
+        status: u16,
+        body: &'static str,
+    ) -> Result<RemoveMessageActionResult, PubNubError> {
+        client(status, body, |request| {
+            assert_eq!(
+                request.path,
+                "/v1/message-actions/demo/channel/channel%20a/message/17000000000000000/action/17000000000000001"
+            );
+            assert_eq!(request.method, TransportMethod::Delete);
+            assert_eq!(request.body, None);
+        })
+        .remove_message_action()
+        .channel("channel a")
+        .message_timetoken(17_000_000_000_000_000u64)
+        .action_timetoken(17_000_000_000_000_001u64)
+        .execute()
+        .await
+    }
+
+    #[test]
+    fn not_build_request_without_value() {
+        let request = client(200, "", |_| {})
+            .add_message_action()
+            .channel("channel a")
+            .message_timetoken(17_000_000_000_000_000u64)
+            .action_type("reaction")
+            .build();
+
+        assert!(request.is_err());
+    }
+
+    #[test]
+    fn not_build_request_with_long_action_type() {
+        let request = client(200, "", |_| {})
+            .add_message_action()
+            .channel("channel a")
+            .message_timetoken(17_000_000_000_000_000u64)
+            .action_type("very_long_action_type")
+            .value("smiley_face")
+            .build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn return_created_action() {
+        let result = add_message_action(
+            200,
+            r#"{"status":200,"data":{"type":"reaction","value":"smiley_face","uuid":"user_id","actionTimetoken":"17000000000000001","messageTimetoken":"17000000000000000"}}"#,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.action_timetoken(),
+            Timetoken::new(17_000_000_000_000_001)
+        );
+        assert_eq!(
+            result.action,
+            MessageActionData {
+                r#type: "reaction".into(),
+                value: "smiley_face".into(),
+                user_id: "user_id".into(),
+                action_timetoken: Timetoken::new(17_000_000_000_000_001),
+                message_timetoken: Timetoken::new(17_000_000_000_000_000),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn return_error_for_already_added_action() {
+        let result = add_message_action(
+            409,
+            r#"{"status":409,"error":{"source":"actions","message":"Action Already Added"}}"#,
+        )
+        .await;
+
+        assert!(matches!(result, Err(PubNubError::API { status: 409, .. })));
+    }
+
+    #[test]
+    fn not_build_remove_request_without_action_timetoken() {
+        let request = client(200, "", |_| {})
+            .remove_message_action()
+            .channel("channel a")
+            .message_timetoken(17_000_000_000_000_000u64)
+            .build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn remove_action() {
+        let result = remove_message_action(200, r#"{"status":200,"data":{}}"#).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn return_error_for_remove_with_invalid_timetoken() {
+        let result = remove_message_action(
+            400,
+            r#"{"status":400,"error":{"source":"actions","message":"Invalid timetoken"}}"#,
+        )
+        .await;
+
+        assert!(matches!(result, Err(PubNubError::API { status: 400, .. })));
+    }
+
+    #[test]
+    fn not_build_get_request_with_too_large_limit() {
+        let request = client(200, "", |_| {})
+            .get_message_actions()
+            .channel("channel a")
+            .limit(101)
+            .build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn return_actions_with_next_page() {
+        let result = client(
+            200,
+            r#"{"status":200,"data":[
+                {"type":"reaction","value":"smiley_face","uuid":"user-1","actionTimetoken":"17000000000000002","messageTimetoken":"17000000000000000"},
+                {"type":"receipt","value":"read","uuid":"user-2","actionTimetoken":"17000000000000003","messageTimetoken":"17000000000000000"}
+            ],"more":{"url":"/v1/message-actions/demo/channel/channel%20a?start=17000000000000002&limit=2","start":"17000000000000002","limit":2}}"#,
+            |request| {
+                assert_eq!(request.path, "/v1/message-actions/demo/channel/channel%20a");
+                assert_eq!(request.method, TransportMethod::Get);
+                assert_eq!(
+                    request.query_parameters.get("start"),
+                    Some(&"17000000000000005".into())
+                );
+                assert_eq!(request.query_parameters.get("limit"), Some(&"2".into()));
+                assert_eq!(request.query_parameters.get("end"), None);
+            },
+        )
+        .get_message_actions()
+        .channel("channel a")
+        .start(17_000_000_000_000_005u64)
+        .limit(2)
+        .execute()
+        .await
+        .unwrap();
+
+        assert_eq!(result.actions.len(), 2);
+        assert_eq!(result.actions[1].value, "read");
+        assert_eq!(
+            result.more,
+            Some(MessageActionsPage {
+                start: Timetoken::new(17_000_000_000_000_002),
+                end: None,
+                limit: 2,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn request_next_page() {
+        let result = client(200, r#"{"status":200,"data":[]}"#, |request| {
+            assert_eq!(
+                request.query_parameters.get("start"),
+                Some(&"17000000000000002".into())
+            );
+            assert_eq!(
+                request.query_parameters.get("end"),
+                Some(&"17000000000000001".into())
+            );
+            assert_eq!(request.query_parameters.get("limit"), Some(&"2".into()));
+        })
+        .get_message_actions()
+        .channel("channel a")
+        .page(MessageActionsPage {
+            start: Timetoken::new(17_000_000_000_000_002),
+            end: Some(Timetoken::new(17_000_000_000_000_001)),
+            limit: 2,
+        })
+        .execute()
+        .await
+        .unwrap();
+
+        assert!(result.actions.is_empty());
+        assert!(result.more.is_none());
+    }
+}
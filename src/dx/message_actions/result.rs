@@ -0,0 +1,345 @@
+//! Message Actions result module.
+//!
+//! This module contains the [`AddMessageActionResult`],
+//! [`RemoveMessageActionResult`] and [`GetMessageActionsResult`] types which
+//! are used to represent the result of message actions operations.
+
+use crate::{
+    core::{service_response::APIErrorBody, PubNubError, ResponseMetadata, Timetoken},
+    lib::alloc::{string::String, vec::Vec},
+};
+
+/// Message action information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MessageActionData {
+    /// Message action type (for example, `reaction`).
+    pub r#type: String,
+
+    /// Value associated with message action `type`.
+    pub value: String,
+
+    /// Identifier of client which added action.
+    pub user_id: String,
+
+    /// Time when action has been added.
+    pub action_timetoken: Timetoken,
+
+    /// Timetoken of message to which action has been added.
+    pub message_timetoken: Timetoken,
+}
+
+/// The result of an add message action operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddMessageActionResult {
+    /// Information about created message action.
+    pub action: MessageActionData,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(AddMessageActionResult);
+
+impl AddMessageActionResult {
+    /// Timetoken of created message action.
+    pub fn action_timetoken(&self) -> Timetoken {
+        self.action.action_timetoken
+    }
+}
+
+/// The response body of an add message action operation.
+///
+/// It's used for deserialization of the add message action response. This
+/// type is an intermediate type between the raw response body and the
+/// [`AddMessageActionResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddMessageActionResponseBody {
+    /// This is a success response body for an add message action operation
+    /// in the Message Actions service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {
+    ///         "type": "reaction",
+    ///         "value": "smiley_face",
+    ///         "uuid": "user-1",
+    ///         "actionTimetoken": "15610547826970050",
+    ///         "messageTimetoken": "15610547826969050"
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(AddMessageActionSuccessBody),
+
+    /// This is an error response body for an add message action operation in
+    /// the Message Actions service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 409,
+    ///     "error": {
+    ///         "source": "actions",
+    ///         "message": "Action Already Added"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful add message action REST API operation response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddMessageActionSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// Information about created message action.
+    pub data: MessageActionBody,
+}
+
+/// Message action information from Message Actions service response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageActionBody {
+    /// Message action type.
+    pub r#type: String,
+
+    /// Value associated with message action `type`.
+    pub value: String,
+
+    /// Identifier of client which added action.
+    pub uuid: String,
+
+    /// Time when action has been added.
+    pub action_timetoken: Timetoken,
+
+    /// Timetoken of message to which action has been added.
+    pub message_timetoken: Timetoken,
+}
+
+impl From<MessageActionBody> for MessageActionData {
+    fn from(value: MessageActionBody) -> Self {
+        Self {
+            r#type: value.r#type,
+            value: value.value,
+            user_id: value.uuid,
+            action_timetoken: value.action_timetoken,
+            message_timetoken: value.message_timetoken,
+        }
+    }
+}
+
+impl TryFrom<AddMessageActionResponseBody> for AddMessageActionResult {
+    type Error = PubNubError;
+
+    fn try_from(value: AddMessageActionResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            AddMessageActionResponseBody::SuccessResponse(resp) => Ok(AddMessageActionResult {
+                action: resp.data.into(),
+                metadata: Default::default(),
+            }),
+            AddMessageActionResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a remove message action operation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RemoveMessageActionResult {
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(RemoveMessageActionResult);
+
+/// The response body of a remove message action operation.
+///
+/// It's used for deserialization of the remove message action response. This
+/// type is an intermediate type between the raw response body and the
+/// [`RemoveMessageActionResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoveMessageActionResponseBody {
+    /// This is a success response body for a remove message action operation
+    /// in the Message Actions service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {}
+    /// }
+    /// ```
+    SuccessResponse(RemoveMessageActionSuccessBody),
+
+    /// This is an error response body for a remove message action operation
+    /// in the Message Actions service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": {
+    ///         "source": "actions",
+    ///         "message": "Invalid timetoken"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful remove message action REST API operation response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveMessageActionSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// Empty object which is returned by service on success.
+    pub data: RemovedMessageActionBody,
+}
+
+/// Empty data object from remove message action response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovedMessageActionBody {}
+
+impl TryFrom<RemoveMessageActionResponseBody> for RemoveMessageActionResult {
+    type Error = PubNubError;
+
+    fn try_from(value: RemoveMessageActionResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            RemoveMessageActionResponseBody::SuccessResponse(_) => {
+                Ok(RemoveMessageActionResult::default())
+            }
+            RemoveMessageActionResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a get message actions operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GetMessageActionsResult {
+    /// List of message actions added in requested time slice.
+    pub actions: Vec<MessageActionData>,
+
+    /// Information about next page of message actions.
+    ///
+    /// Set only when there are more message actions in requested time slice,
+    /// which can be retrieved using [`page`].
+    ///
+    /// [`page`]: crate::dx::message_actions::GetMessageActionsRequestBuilder::page
+    pub more: Option<MessageActionsPage>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(GetMessageActionsResult);
+
+/// Next page of message actions information.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageActionsPage {
+    /// Message action timetoken from which next page starts (exclusive).
+    pub start: Timetoken,
+
+    /// Message action timetoken at which next page ends (inclusive).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub end: Option<Timetoken>,
+
+    /// Maximum number of message actions in next page.
+    pub limit: usize,
+}
+
+/// The response body of a get message actions operation.
+///
+/// It's used for deserialization of the get message actions response. This
+/// type is an intermediate type between the raw response body and the
+/// [`GetMessageActionsResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GetMessageActionsResponseBody {
+    /// This is a success response body for a get message actions operation in
+    /// the Message Actions service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": [
+    ///         {
+    ///             "type": "reaction",
+    ///             "value": "smiley_face",
+    ///             "uuid": "user-1",
+    ///             "actionTimetoken": "15610547826970050",
+    ///             "messageTimetoken": "15610547826969050"
+    ///         }
+    ///     ],
+    ///     "more": {
+    ///         "url": "/v1/message-actions/demo/channel/lobby?start=15610547826970050&limit=1",
+    ///         "start": "15610547826970050",
+    ///         "limit": 1
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(GetMessageActionsSuccessBody),
+
+    /// This is an error response body for a get message actions operation in
+    /// the Message Actions service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": {
+    ///         "source": "actions",
+    ///         "message": "Invalid limit"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful get message actions REST API operation response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetMessageActionsSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// List of message actions.
+    pub data: Vec<MessageActionBody>,
+
+    /// Information about next page of message actions.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub more: Option<MessageActionsPage>,
+}
+
+impl TryFrom<GetMessageActionsResponseBody> for GetMessageActionsResult {
+    type Error = PubNubError;
+
+    fn try_from(value: GetMessageActionsResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            GetMessageActionsResponseBody::SuccessResponse(resp) => Ok(GetMessageActionsResult {
+                actions: resp.data.into_iter().map(Into::into).collect(),
+                more: resp.more,
+                metadata: Default::default(),
+            }),
+            GetMessageActionsResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
@@ -18,6 +18,32 @@ pub mod subscribe;
 #[cfg(feature = "presence")]
 pub mod presence;
 
+#[cfg(feature = "history")]
+pub mod history;
+
+#[cfg(feature = "message_actions")]
+pub mod message_actions;
+
+#[cfg(feature = "objects")]
+pub mod objects;
+
+#[cfg(feature = "chat")]
+pub mod chat;
+
+#[cfg(all(feature = "wasm", feature = "serde", feature = "std"))]
+pub mod files;
+
+#[cfg(any(
+    feature = "publish",
+    feature = "access",
+    feature = "subscribe",
+    feature = "presence",
+    feature = "history",
+    feature = "message_actions",
+    feature = "objects"
+))]
+pub mod time;
+
 #[cfg(all(feature = "parse_token", feature = "serde"))]
 pub use parse_token::parse_token;
 #[cfg(feature = "parse_token")]
@@ -28,5 +54,32 @@ pub mod parse_token;
 pub use pubnub_client::{Keyset, PubNubClientBuilder, PubNubGenericClient};
 pub mod pubnub_client;
 
+#[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+pub use client_pool::PubNubClientPool;
+#[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+pub mod client_pool;
+
+#[cfg(all(
+    any(
+        feature = "publish",
+        feature = "access",
+        feature = "subscribe",
+        feature = "presence",
+        feature = "history",
+        feature = "message_actions",
+        feature = "objects"
+    ),
+    feature = "std"
+))]
+pub mod health;
+
 #[cfg(feature = "reqwest")]
 pub use pubnub_client::PubNubClient;
+
+#[cfg(all(
+    feature = "reqwest",
+    feature = "serde",
+    feature = "blocking",
+    not(target_arch = "wasm32")
+))]
+pub use pubnub_client::PubNubBlockingClient;
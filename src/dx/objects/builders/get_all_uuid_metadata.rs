@@ -0,0 +1,240 @@
+//! # PubNub get all `uuid` metadata module.
+//!
+//! The [`GetAllUuidMetadataRequestBuilder`] lets you make and execute a
+//! request which will return a page of `uuid` metadata objects.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::headers::{APPLICATION_JSON, CONTENT_TYPE},
+        Deserializer, PubNubError, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        objects::{
+            builders,
+            result::{GetAllUuidMetadataResponseBody, GetAllUuidMetadataResult, Page},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`GetAllUuidMetadataRequestBuilder`] is used to build get all `uuid`
+/// metadata request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_all_uuid_metadata`] method of the
+/// [`PubNubClient`].
+///
+/// [`get_all_uuid_metadata`]: crate::dx::pubnub_client::PubNubClientInstance::get_all_uuid_metadata
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::objects)", validate = "Self::validate"),
+    no_std
+)]
+pub struct GetAllUuidMetadataRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(custom))]
+    pub(in crate::dx::objects) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Expression which is used by service to filter returned objects.
+    ///
+    /// # Example
+    /// ```text
+    /// name LIKE 'John*' && custom.vip == true
+    /// ```
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) filter: Option<String>,
+
+    /// List of fields by which returned objects should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example, `name:desc`
+    /// or `updated:asc`).
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(custom),
+        default = "vec![]"
+    )]
+    pub(in crate::dx::objects) sort: Vec<String>,
+
+    /// Maximum number of objects which should be returned.
+    ///
+    /// Service returns up to `100` objects in single response.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) limit: Option<usize>,
+
+    /// Cursor of page which should be returned.
+    ///
+    /// Cursors are returned in [`next`] and [`prev`] fields of the previous
+    /// response.
+    ///
+    /// [`next`]: crate::dx::objects::GetAllUuidMetadataResult::next
+    /// [`prev`]: crate::dx::objects::GetAllUuidMetadataResult::prev
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) page: Option<Page>,
+
+    /// Whether `custom` field should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "true")]
+    pub(in crate::dx::objects) include_custom: bool,
+
+    /// Whether total number of objects should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_total_count: bool,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(GetAllUuidMetadataRequestBuilderError);
+impl_detached_request!(
+    GetAllUuidMetadataRequest,
+    GetAllUuidMetadataRequestBuilder,
+    GetAllUuidMetadataResult
+);
+
+impl<T, D> GetAllUuidMetadataRequestBuilder<T, D> {
+    /// List of fields by which returned objects should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example, `name:desc`
+    /// or `updated:asc`).
+    pub fn sort<L, S>(mut self, sort: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sort = Some(sort.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get all `uuid` metadata request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_limit(&self.limit))
+    }
+
+    /// Build [`GetAllUuidMetadataRequest`] from builder.
+    fn request(self) -> Result<GetAllUuidMetadataRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> GetAllUuidMetadataRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::objects) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        query.insert(
+            "include".into(),
+            builders::include_fields(self.include_custom),
+        );
+        self.filter
+            .as_ref()
+            .and_then(|filter| query.insert("filter".into(), filter.clone()));
+        builders::insert_list_query(
+            &mut query,
+            &self.sort,
+            self.limit,
+            self.page.as_ref(),
+            self.include_total_count,
+        );
+
+        Ok(TransportRequest {
+            path: format!("/v2/objects/{}/uuids", &config.subscribe_key),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: None,
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> GetAllUuidMetadataRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<GetAllUuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<GetAllUuidMetadataResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetAllUuidMetadataRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<GetAllUuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request.send_blocking::<GetAllUuidMetadataResponseBody, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,254 @@
+//! # PubNub get channel members module.
+//!
+//! The [`GetChannelMembersRequestBuilder`] lets you make and execute a
+//! request which will return a page of `uuid` members of the `channel`.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        objects::{
+            builders,
+            result::{MembersResponseBody, MembersResult, Page},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`GetChannelMembersRequestBuilder`] is used to build get `channel`
+/// members request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_channel_members`] method of the
+/// [`PubNubClient`].
+///
+/// [`get_channel_members`]: crate::dx::pubnub_client::PubNubClientInstance::get_channel_members
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::objects)", validate = "Self::validate"),
+    no_std
+)]
+pub struct GetChannelMembersRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(custom))]
+    pub(in crate::dx::objects) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of `channel` for which members should be retrieved.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(into))]
+    pub(in crate::dx::objects) channel: String,
+
+    /// Expression which is used by service to filter returned members.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) filter: Option<String>,
+
+    /// List of fields by which returned members should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example,
+    /// `uuid.name:desc` or `updated:asc`).
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(custom),
+        default = "vec![]"
+    )]
+    pub(in crate::dx::objects) sort: Vec<String>,
+
+    /// Maximum number of members which should be returned.
+    ///
+    /// Service returns up to `100` members in single response.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) limit: Option<usize>,
+
+    /// Cursor of page which should be returned.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) page: Option<Page>,
+
+    /// Whether member `custom` field should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "true")]
+    pub(in crate::dx::objects) include_custom: bool,
+
+    /// Whether `uuid` metadata fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_uuid_fields: bool,
+
+    /// Whether `uuid` metadata `custom` field should be included in response
+    /// or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_uuid_custom_fields: bool,
+
+    /// Whether total number of members should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_total_count: bool,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(GetChannelMembersRequestBuilderError);
+impl_detached_request!(
+    GetChannelMembersRequest,
+    GetChannelMembersRequestBuilder,
+    MembersResult
+);
+
+impl<T, D> GetChannelMembersRequestBuilder<T, D> {
+    /// List of fields by which returned members should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example,
+    /// `uuid.name:desc` or `updated:asc`).
+    pub fn sort<L, S>(mut self, sort: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sort = Some(sort.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get members request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_channel(&self.channel))
+            .and_then(|_| builders::validate_limit(&self.limit))
+    }
+
+    /// Build [`GetChannelMembersRequest`] from builder.
+    fn request(self) -> Result<GetChannelMembersRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> GetChannelMembersRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::objects) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        query.insert(
+            "include".into(),
+            builders::relation_include_fields(
+                "uuid",
+                self.include_custom,
+                self.include_uuid_fields,
+                self.include_uuid_custom_fields,
+            ),
+        );
+        self.filter
+            .as_ref()
+            .and_then(|filter| query.insert("filter".into(), filter.clone()));
+        builders::insert_list_query(
+            &mut query,
+            &self.sort,
+            self.limit,
+            self.page.as_ref(),
+            self.include_total_count,
+        );
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/channels/{}/uuids",
+                &config.subscribe_key,
+                url_encode_extended(self.channel.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: None,
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> GetChannelMembersRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<MembersResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<MembersResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetChannelMembersRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<MembersResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<MembersResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
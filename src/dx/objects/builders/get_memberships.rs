@@ -0,0 +1,258 @@
+//! # PubNub get memberships module.
+//!
+//! The [`GetMembershipsRequestBuilder`] lets you make and execute a request
+//! which will return a page of `channel` memberships of the `uuid`.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        objects::{
+            builders,
+            result::{MembershipsResponseBody, MembershipsResult, Page},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`GetMembershipsRequestBuilder`] is used to build get memberships
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_memberships`] method of the
+/// [`PubNubClient`].
+///
+/// [`get_memberships`]: crate::dx::pubnub_client::PubNubClientInstance::get_memberships
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::objects)", validate = "Self::validate"),
+    no_std
+)]
+pub struct GetMembershipsRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(custom))]
+    pub(in crate::dx::objects) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Identifier of `uuid` for which memberships should be retrieved.
+    ///
+    /// Client's `user_id` is used by default.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(into))]
+    pub(in crate::dx::objects) uuid: String,
+
+    /// Expression which is used by service to filter returned memberships.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) filter: Option<String>,
+
+    /// List of fields by which returned memberships should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example,
+    /// `channel.name:desc` or `updated:asc`).
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(custom),
+        default = "vec![]"
+    )]
+    pub(in crate::dx::objects) sort: Vec<String>,
+
+    /// Maximum number of memberships which should be returned.
+    ///
+    /// Service returns up to `100` memberships in single response.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) limit: Option<usize>,
+
+    /// Cursor of page which should be returned.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) page: Option<Page>,
+
+    /// Whether membership `custom` field should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "true")]
+    pub(in crate::dx::objects) include_custom: bool,
+
+    /// Whether `channel` metadata fields should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_channel_fields: bool,
+
+    /// Whether `channel` metadata `custom` field should be included in
+    /// response or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_channel_custom_fields: bool,
+
+    /// Whether total number of memberships should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_total_count: bool,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(GetMembershipsRequestBuilderError);
+impl_detached_request!(
+    GetMembershipsRequest,
+    GetMembershipsRequestBuilder,
+    MembershipsResult,
+    uuid
+);
+
+impl<T, D> GetMembershipsRequestBuilder<T, D> {
+    /// List of fields by which returned memberships should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example,
+    /// `channel.name:desc` or `updated:asc`).
+    pub fn sort<L, S>(mut self, sort: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sort = Some(sort.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get memberships request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_uuid(&self.uuid))
+            .and_then(|_| builders::validate_limit(&self.limit))
+    }
+
+    /// Build [`GetMembershipsRequest`] from builder.
+    fn request(self) -> Result<GetMembershipsRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> GetMembershipsRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::objects) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        query.insert(
+            "include".into(),
+            builders::relation_include_fields(
+                "channel",
+                self.include_custom,
+                self.include_channel_fields,
+                self.include_channel_custom_fields,
+            ),
+        );
+        self.filter
+            .as_ref()
+            .and_then(|filter| query.insert("filter".into(), filter.clone()));
+        builders::insert_list_query(
+            &mut query,
+            &self.sort,
+            self.limit,
+            self.page.as_ref(),
+            self.include_total_count,
+        );
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/uuids/{}/channels",
+                &config.subscribe_key,
+                url_encode_extended(self.uuid.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: None,
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> GetMembershipsRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<MembershipsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<MembershipsResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetMembershipsRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<MembershipsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<MembershipsResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
@@ -0,0 +1,169 @@
+//! # PubNub get `uuid` metadata module.
+//!
+//! The [`GetUuidMetadataRequestBuilder`] lets you make and execute a request
+//! which will return `uuid` metadata object.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        objects::{
+            builders,
+            result::{UuidMetadataResponseBody, UuidMetadataResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+    },
+};
+
+/// The [`GetUuidMetadataRequestBuilder`] is used to build get `uuid` metadata
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_uuid_metadata`] method of the
+/// [`PubNubClient`].
+///
+/// [`get_uuid_metadata`]: crate::dx::pubnub_client::PubNubClientInstance::get_uuid_metadata
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::objects)", validate = "Self::validate"),
+    no_std
+)]
+pub struct GetUuidMetadataRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(custom))]
+    pub(in crate::dx::objects) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Identifier of `uuid` object which should be retrieved.
+    ///
+    /// Client's `user_id` is used by default.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(into))]
+    pub(in crate::dx::objects) uuid: String,
+
+    /// Whether `custom` field should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "true")]
+    pub(in crate::dx::objects) include_custom: bool,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(GetUuidMetadataRequestBuilderError);
+impl_detached_request!(
+    GetUuidMetadataRequest,
+    GetUuidMetadataRequestBuilder,
+    UuidMetadataResult,
+    uuid
+);
+
+impl<T, D> GetUuidMetadataRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get `uuid` metadata request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_uuid(&self.uuid))
+    }
+
+    /// Build [`GetUuidMetadataRequest`] from builder.
+    fn request(self) -> Result<GetUuidMetadataRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> GetUuidMetadataRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::objects) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/uuids/{}",
+                &config.subscribe_key,
+                url_encode_extended(self.uuid.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            ),
+            query_parameters: [(
+                "include".into(),
+                builders::include_fields(self.include_custom),
+            )]
+            .into(),
+            method: TransportMethod::Get,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: None,
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+}
+
+impl<T, D> GetUuidMetadataRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<UuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<UuidMetadataResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetUuidMetadataRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<UuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<UuidMetadataResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
@@ -0,0 +1,189 @@
+//! # App Context API builders module.
+//!
+//! Module contains set of builders which provide access to [`PubNub`] App
+//! Context API: [`SetUuidMetadataRequestBuilder`],
+//! [`GetUuidMetadataRequestBuilder`], [`GetAllUuidMetadataRequestBuilder`],
+//! [`RemoveUuidMetadataRequestBuilder`], [`GetMembershipsRequestBuilder`],
+//! [`SetMembershipsRequestBuilder`], [`RemoveMembershipsRequestBuilder`],
+//! [`GetChannelMembersRequestBuilder`], [`SetChannelMembersRequestBuilder`] and
+//! [`RemoveChannelMembersRequestBuilder`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use set_uuid_metadata::{SetUuidMetadataRequest, SetUuidMetadataRequestBuilder};
+pub mod set_uuid_metadata;
+
+#[doc(inline)]
+pub use get_uuid_metadata::{GetUuidMetadataRequest, GetUuidMetadataRequestBuilder};
+pub mod get_uuid_metadata;
+
+#[doc(inline)]
+pub use get_all_uuid_metadata::{GetAllUuidMetadataRequest, GetAllUuidMetadataRequestBuilder};
+pub mod get_all_uuid_metadata;
+
+#[doc(inline)]
+pub use remove_uuid_metadata::{RemoveUuidMetadataRequest, RemoveUuidMetadataRequestBuilder};
+pub mod remove_uuid_metadata;
+
+#[doc(inline)]
+pub use get_memberships::{GetMembershipsRequest, GetMembershipsRequestBuilder};
+pub mod get_memberships;
+
+#[doc(inline)]
+pub use set_memberships::{SetMembershipsRequest, SetMembershipsRequestBuilder};
+pub mod set_memberships;
+
+#[doc(inline)]
+pub use remove_memberships::{RemoveMembershipsRequest, RemoveMembershipsRequestBuilder};
+pub mod remove_memberships;
+
+#[doc(inline)]
+pub use get_channel_members::{GetChannelMembersRequest, GetChannelMembersRequestBuilder};
+pub mod get_channel_members;
+
+#[doc(inline)]
+pub use set_channel_members::{SetChannelMembersRequest, SetChannelMembersRequestBuilder};
+pub mod set_channel_members;
+
+#[doc(inline)]
+pub use remove_channel_members::{RemoveChannelMembersRequest, RemoveChannelMembersRequestBuilder};
+pub mod remove_channel_members;
+
+use crate::{
+    core::{
+        utils::json::{json_object, json_scalar},
+        ScalarValue,
+    },
+    dx::{objects::result::Page, pubnub_client::PubNubClientInstance},
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// Maximum number of objects which can be returned by [`PubNub`] service in
+/// single response.
+///
+/// [`PubNub`]: https://www.pubnub.com
+const MAX_OBJECTS_LIMIT: usize = 100;
+
+/// Validate [`PubNubClient`] configuration.
+///
+/// Check whether if the [`PubNubConfig`] contains all the required fields set
+/// for App Context endpoint usage or not.
+pub(in crate::dx::objects::builders) fn validate_configuration<T, D>(
+    client: &Option<PubNubClientInstance<T, D>>,
+) -> Result<(), String> {
+    let Some(client) = client.as_ref() else {
+        return Err("PubNub client instance not set.".into());
+    };
+
+    if client.config.subscribe_key.is_empty() {
+        return Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into());
+    }
+
+    Ok(())
+}
+
+/// Validate that `uuid` has been provided.
+pub(in crate::dx::objects::builders) fn validate_uuid(uuid: &Option<String>) -> Result<(), String> {
+    if uuid.as_ref().is_none_or(|uuid| uuid.is_empty()) {
+        return Err("UUID is missing".into());
+    }
+
+    Ok(())
+}
+
+/// Validate that `channel` for App Context request has been provided.
+pub(in crate::dx::objects::builders) fn validate_channel(
+    channel: &Option<String>,
+) -> Result<(), String> {
+    if channel.as_ref().is_none_or(|channel| channel.is_empty()) {
+        return Err("Channel is missing".into());
+    }
+
+    Ok(())
+}
+
+/// Validate that requested number of objects is within service limits.
+pub(in crate::dx::objects::builders) fn validate_limit(
+    limit: &Option<Option<usize>>,
+) -> Result<(), String> {
+    if limit
+        .flatten()
+        .is_some_and(|limit| limit == 0 || limit > MAX_OBJECTS_LIMIT)
+    {
+        return Err(format!(
+            "Limit should be in range from 1 to {MAX_OBJECTS_LIMIT}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Add objects list sorting and pagination parameters to the `query`.
+pub(in crate::dx::objects::builders) fn insert_list_query(
+    query: &mut HashMap<String, String>,
+    sort: &[String],
+    limit: Option<usize>,
+    page: Option<&Page>,
+    include_total_count: bool,
+) {
+    (!sort.is_empty()).then(|| query.insert("sort".into(), sort.join(",")));
+    limit.and_then(|limit| query.insert("limit".into(), limit.to_string()));
+    page.and_then(|page| match page {
+        Page::Next(cursor) => query.insert("start".into(), cursor.clone()),
+        Page::Prev(cursor) => query.insert("end".into(), cursor.clone()),
+    });
+    include_total_count.then(|| query.insert("count".into(), "true".into()));
+}
+
+/// Value for `include` query parameter.
+///
+/// Object `status` and `type` fields are always requested, while `custom` is
+/// requested only if `include_custom` is set.
+pub(in crate::dx::objects::builders) fn include_fields(include_custom: bool) -> String {
+    let mut fields: Vec<&str> = vec!["status", "type"];
+    if include_custom {
+        fields.insert(0, "custom");
+    }
+    fields.join(",")
+}
+
+/// Value for `include` query parameter of relation (membership or member)
+/// endpoints.
+///
+/// Relation `status` and `type` fields are always requested, while `custom`
+/// fields of relation and related `object` are requested only if
+/// corresponding flag is set.
+pub(in crate::dx::objects::builders) fn relation_include_fields(
+    object: &str,
+    include_custom: bool,
+    include_object: bool,
+    include_object_custom: bool,
+) -> String {
+    let mut fields: Vec<String> = vec![];
+    include_custom.then(|| fields.push("custom".into()));
+    include_object.then(|| fields.push(object.into()));
+    include_object_custom.then(|| fields.push(format!("{object}.custom")));
+    fields.extend(["status".into(), "type".into()]);
+    fields.join(",")
+}
+
+/// Serialize flatten `custom` object as JSON object.
+pub(in crate::dx::objects::builders) fn serialize_custom(
+    custom: &HashMap<String, ScalarValue>,
+) -> Vec<u8> {
+    let values = custom
+        .iter()
+        .map(|(key, value)| (key.as_str(), json_scalar(value)))
+        .collect::<Vec<_>>();
+
+    json_object(values.iter().map(|(key, value)| (*key, value.as_bytes())))
+}
@@ -0,0 +1,295 @@
+//! # PubNub set channel members module.
+//!
+//! The [`SetChannelMembersRequestBuilder`] lets you make and execute a
+//! request which will add or update `uuid` members of the `channel`.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+            json::{json_array, json_object, json_string},
+        },
+        Deserializer, PubNubError, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        objects::{
+            builders,
+            result::{MembersResponseBody, MembersResult},
+            types::ChannelMember,
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`SetChannelMembersRequestBuilder`] is used to build set `channel`
+/// members request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`set_channel_members`] method of the
+/// [`PubNubClient`].
+///
+/// [`set_channel_members`]: crate::dx::pubnub_client::PubNubClientInstance::set_channel_members
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::objects)", validate = "Self::validate"),
+    no_std
+)]
+pub struct SetChannelMembersRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(custom))]
+    pub(in crate::dx::objects) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of `channel` for which members should be updated.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(into))]
+    pub(in crate::dx::objects) channel: String,
+
+    /// List of `channel` members which should be added or updated.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(custom),
+        default = "vec![]"
+    )]
+    pub(in crate::dx::objects) uuids: Vec<ChannelMember>,
+
+    /// List of fields by which returned members should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example,
+    /// `uuid.name:desc` or `updated:asc`).
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(custom),
+        default = "vec![]"
+    )]
+    pub(in crate::dx::objects) sort: Vec<String>,
+
+    /// Maximum number of members which should be returned after update.
+    ///
+    /// Service returns up to `100` members in single response.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) limit: Option<usize>,
+
+    /// Whether member `custom` field should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "true")]
+    pub(in crate::dx::objects) include_custom: bool,
+
+    /// Whether `uuid` metadata fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_uuid_fields: bool,
+
+    /// Whether `uuid` metadata `custom` field should be included in response
+    /// or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_uuid_custom_fields: bool,
+
+    /// Whether total number of members should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "false")]
+    pub(in crate::dx::objects) include_total_count: bool,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(SetChannelMembersRequestBuilderError);
+impl_detached_request!(
+    SetChannelMembersRequest,
+    SetChannelMembersRequestBuilder,
+    MembersResult
+);
+
+impl<T, D> SetChannelMembersRequestBuilder<T, D> {
+    /// List of `channel` members which should be added or updated.
+    ///
+    /// `uuid` identifiers can be used for members without additional
+    /// information.
+    pub fn uuids<L, M>(mut self, uuids: L) -> Self
+    where
+        L: IntoIterator<Item = M>,
+        M: Into<ChannelMember>,
+    {
+        self.uuids = Some(uuids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// List of fields by which returned members should be sorted.
+    ///
+    /// Direction can be appended to the field name (for example,
+    /// `uuid.name:desc` or `updated:asc`).
+    pub fn sort<L, S>(mut self, sort: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sort = Some(sort.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// set members request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_channel(&self.channel))
+            .and_then(|_| builders::validate_limit(&self.limit))
+            .and_then(|_| {
+                if self.uuids.as_ref().is_none_or(|uuids| uuids.is_empty()) {
+                    Err("At least one uuid should be provided".into())
+                } else {
+                    Ok(())
+                }
+            })
+    }
+
+    /// Build [`SetChannelMembersRequest`] from builder.
+    fn request(self) -> Result<SetChannelMembersRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> SetChannelMembersRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::objects) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        query.insert(
+            "include".into(),
+            builders::relation_include_fields(
+                "uuid",
+                self.include_custom,
+                self.include_uuid_fields,
+                self.include_uuid_custom_fields,
+            ),
+        );
+        builders::insert_list_query(
+            &mut query,
+            &self.sort,
+            self.limit,
+            None,
+            self.include_total_count,
+        );
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/channels/{}/uuids",
+                &config.subscribe_key,
+                url_encode_extended(self.channel.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            ),
+            query_parameters: query,
+            method: TransportMethod::Patch,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(self.body()),
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+
+    /// Serialize list of members as request body.
+    fn body(&self) -> Vec<u8> {
+        let members = self
+            .uuids
+            .iter()
+            .map(|member| {
+                let uuid = json_object([("id", json_string(&member.uuid).as_bytes())]);
+                let custom = member.custom.as_ref().map(builders::serialize_custom);
+                let status = member.status.as_deref().map(json_string);
+                let r#type = member.r#type.as_deref().map(json_string);
+
+                json_object(
+                    [
+                        Some(("uuid", uuid.as_slice())),
+                        custom.as_deref().map(|custom| ("custom", custom)),
+                        status.as_ref().map(|status| ("status", status.as_bytes())),
+                        r#type.as_ref().map(|r#type| ("type", r#type.as_bytes())),
+                    ]
+                    .into_iter()
+                    .flatten(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        json_object([(
+            "set",
+            json_array(members.iter().map(Vec::as_slice)).as_slice(),
+        )])
+    }
+}
+
+impl<T, D> SetChannelMembersRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<MembersResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<MembersResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> SetChannelMembersRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<MembersResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<MembersResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
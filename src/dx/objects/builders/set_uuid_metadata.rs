@@ -0,0 +1,268 @@
+//! # PubNub set `uuid` metadata module.
+//!
+//! The [`SetUuidMetadataRequestBuilder`] lets you make and execute a request
+//! which will create or update `uuid` metadata object.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+            json::{json_object, json_string},
+        },
+        Deserializer, PubNubError, ScalarValue, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        objects::{
+            builders,
+            result::{UuidMetadataResponseBody, UuidMetadataResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`SetUuidMetadataRequestBuilder`] is used to build set `uuid` metadata
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`set_uuid_metadata`] method of the
+/// [`PubNubClient`].
+///
+/// [`set_uuid_metadata`]: crate::dx::pubnub_client::PubNubClientInstance::set_uuid_metadata
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::objects)", validate = "Self::validate"),
+    no_std
+)]
+pub struct SetUuidMetadataRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(custom))]
+    pub(in crate::dx::objects) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Identifier of `uuid` object which should be created or updated.
+    ///
+    /// Client's `user_id` is used by default.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), setter(into))]
+    pub(in crate::dx::objects) uuid: String,
+
+    /// Given `uuid` object name.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) name: Option<String>,
+
+    /// Email address associated with `uuid` object.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) email: Option<String>,
+
+    /// `uuid` object identifier in external systems.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) external_id: Option<String>,
+
+    /// `uuid` object external profile URL.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) profile_url: Option<String>,
+
+    /// `uuid` object type information.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) r#type: Option<String>,
+
+    /// `uuid` object current status.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) status: Option<String>,
+
+    /// Flatten `HashMap` with additional information which should be
+    /// associated with `uuid` object.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Whether `custom` field should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::objects)"), default = "true")]
+    pub(in crate::dx::objects) include_custom: bool,
+
+    /// `uuid` object state hash which should match current one to perform
+    /// update.
+    ///
+    /// Request will fail with `412` status code if object has been modified
+    /// since `tag` has been received.
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) if_matches_etag: Option<String>,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::objects)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::objects) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(SetUuidMetadataRequestBuilderError);
+impl_detached_request!(
+    SetUuidMetadataRequest,
+    SetUuidMetadataRequestBuilder,
+    UuidMetadataResult,
+    uuid
+);
+
+impl<T, D> SetUuidMetadataRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// set `uuid` metadata request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_uuid(&self.uuid))
+    }
+
+    /// Build [`SetUuidMetadataRequest`] from builder.
+    fn request(self) -> Result<SetUuidMetadataRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> SetUuidMetadataRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::objects) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut headers: HashMap<String, String> =
+            [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into();
+        self.if_matches_etag
+            .as_ref()
+            .and_then(|etag| headers.insert("If-Match".into(), etag.clone()));
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/uuids/{}",
+                &config.subscribe_key,
+                url_encode_extended(self.uuid.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            ),
+            query_parameters: [(
+                "include".into(),
+                builders::include_fields(self.include_custom),
+            )]
+            .into(),
+            method: TransportMethod::Patch,
+            headers,
+            body: Some(self.body()),
+            #[cfg(feature = "std")]
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
+        })
+    }
+
+    /// Serialize `uuid` object fields as request body.
+    fn body(&self) -> Vec<u8> {
+        let custom = self.custom.as_ref().map(builders::serialize_custom);
+        let fields = [
+            ("name", self.name.as_deref().map(json_string)),
+            ("email", self.email.as_deref().map(json_string)),
+            ("externalId", self.external_id.as_deref().map(json_string)),
+            ("profileUrl", self.profile_url.as_deref().map(json_string)),
+            ("type", self.r#type.as_deref().map(json_string)),
+            ("status", self.status.as_deref().map(json_string)),
+        ];
+
+        json_object(
+            fields
+                .iter()
+                .filter_map(|(key, value)| value.as_ref().map(|value| (*key, value.as_bytes())))
+                .chain(custom.as_deref().map(|custom| ("custom", custom))),
+        )
+    }
+}
+
+impl<T, D> SetUuidMetadataRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<UuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<UuidMetadataResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> SetUuidMetadataRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<UuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<UuidMetadataResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
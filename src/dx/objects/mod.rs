@@ -0,0 +1,915 @@
+//! # App Context module.
+//!
+//! The App Context (objects) module allows to manage metadata of `uuid`
+//! objects, their `channel` memberships and `channel` members which are
+//! stored by [`PubNub`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use result::{
+    ChannelMetadataBody, ChannelMetadataObject, GetAllUuidMetadataResponseBody,
+    GetAllUuidMetadataResult, Member, MemberBody, MembersResponseBody, MembersResult, Membership,
+    MembershipBody, MembershipsResponseBody, MembershipsResult, Page,
+    RemoveUuidMetadataResponseBody, RemoveUuidMetadataResult, UuidMetadata, UuidMetadataBody,
+    UuidMetadataResponseBody, UuidMetadataResult,
+};
+pub mod result;
+
+#[doc(inline)]
+pub use types::{ChannelMember, ChannelMembership};
+pub mod types;
+
+#[doc(inline)]
+pub use builders::*;
+pub mod builders;
+
+use crate::{core::Deserializer, dx::pubnub_client::PubNubClientInstance};
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    D: Deserializer,
+{
+    /// Create a set `uuid` metadata request builder.
+    ///
+    /// This method is used to create or update `uuid` metadata object. The
+    /// client's `user_id` is used as `uuid` unless another one is set with
+    /// [`uuid`].
+    ///
+    /// Instance of [`SetUuidMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let result = pubnub
+    ///     .set_uuid_metadata()
+    ///     .name("John Doe")
+    ///     .email("john@example.com")
+    ///     .custom(HashMap::from([("age".into(), 42.into())]))
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// println!("updated: {}", result.data.updated);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`uuid`]: SetUuidMetadataRequestBuilder::uuid
+    pub fn set_uuid_metadata(&self) -> SetUuidMetadataRequestBuilder<T, D> {
+        SetUuidMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            uuid: Some(self.user_id()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get `uuid` metadata request builder.
+    ///
+    /// This method is used to retrieve `uuid` metadata object. The client's
+    /// `user_id` is used as `uuid` unless another one is set with [`uuid`].
+    ///
+    /// Instance of [`GetUuidMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let result = pubnub
+    ///     .get_uuid_metadata()
+    ///     .uuid("john")
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// println!("name: {:?}", result.data.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`uuid`]: GetUuidMetadataRequestBuilder::uuid
+    pub fn get_uuid_metadata(&self) -> GetUuidMetadataRequestBuilder<T, D> {
+        GetUuidMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            uuid: Some(self.user_id()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get all `uuid` metadata request builder.
+    ///
+    /// This method is used to retrieve a page of `uuid` metadata objects which
+    /// match the optional filter expression.
+    ///
+    /// Instance of [`GetAllUuidMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let mut result = pubnub
+    ///     .get_all_uuid_metadata()
+    ///     .filter("name LIKE 'John*'")
+    ///     .sort(["name:desc"])
+    ///     .limit(50)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// while let Some(page) = result.next {
+    ///     result = pubnub
+    ///         .get_all_uuid_metadata()
+    ///         .filter("name LIKE 'John*'")
+    ///         .sort(["name:desc"])
+    ///         .limit(50)
+    ///         .page(page)
+    ///         .execute()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_all_uuid_metadata(&self) -> GetAllUuidMetadataRequestBuilder<T, D> {
+        GetAllUuidMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove `uuid` metadata request builder.
+    ///
+    /// This method is used to remove `uuid` metadata object. The client's
+    /// `user_id` is used as `uuid` unless another one is set with [`uuid`].
+    ///
+    /// Instance of [`RemoveUuidMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// pubnub
+    ///     .remove_uuid_metadata()
+    ///     .uuid("john")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`uuid`]: RemoveUuidMetadataRequestBuilder::uuid
+    pub fn remove_uuid_metadata(&self) -> RemoveUuidMetadataRequestBuilder<T, D> {
+        RemoveUuidMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            uuid: Some(self.user_id()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get memberships request builder.
+    ///
+    /// This method is used to retrieve list of `channel` memberships of the
+    /// `uuid`. The client's `user_id` is used as `uuid` unless another one is
+    /// set with [`uuid`].
+    ///
+    /// Instance of [`GetMembershipsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let result = pubnub
+    ///     .get_memberships()
+    ///     .include_channel_fields(true)
+    ///     .limit(10)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// for membership in result.data {
+    ///     println!("member of: {}", membership.channel.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`uuid`]: GetMembershipsRequestBuilder::uuid
+    pub fn get_memberships(&self) -> GetMembershipsRequestBuilder<T, D> {
+        GetMembershipsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            uuid: Some(self.user_id()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a set memberships request builder.
+    ///
+    /// This method is used to add or update `channel` memberships of the
+    /// `uuid`. The client's `user_id` is used as `uuid` unless another one is
+    /// set with [`uuid`].
+    ///
+    /// Instance of [`SetMembershipsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::dx::objects::ChannelMembership;
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// pubnub
+    ///     .set_memberships()
+    ///     .channels([
+    ///         ChannelMembership::new("my_channel"),
+    ///         ChannelMembership {
+    ///             status: Some("active".into()),
+    ///             ..ChannelMembership::new("other_channel")
+    ///         },
+    ///     ])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`uuid`]: SetMembershipsRequestBuilder::uuid
+    pub fn set_memberships(&self) -> SetMembershipsRequestBuilder<T, D> {
+        SetMembershipsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            uuid: Some(self.user_id()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove memberships request builder.
+    ///
+    /// This method is used to remove `channel` memberships of the `uuid`. The
+    /// client's `user_id` is used as `uuid` unless another one is set with
+    /// [`uuid`].
+    ///
+    /// Instance of [`RemoveMembershipsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// pubnub
+    ///     .remove_memberships()
+    ///     .channels(["my_channel"])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`uuid`]: RemoveMembershipsRequestBuilder::uuid
+    pub fn remove_memberships(&self) -> RemoveMembershipsRequestBuilder<T, D> {
+        RemoveMembershipsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            uuid: Some(self.user_id()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get `channel` members request builder.
+    ///
+    /// This method is used to retrieve list of `uuid` members of the
+    /// `channel`.
+    ///
+    /// Instance of [`GetChannelMembersRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let mut result = pubnub
+    ///     .get_channel_members()
+    ///     .channel("my_channel")
+    ///     .include_uuid_fields(true)
+    ///     .limit(10)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// while let Some(page) = result.next.clone() {
+    ///     for member in &result.data {
+    ///         println!("member: {}", member.uuid.id);
+    ///     }
+    ///
+    ///     result = pubnub
+    ///         .get_channel_members()
+    ///         .channel("my_channel")
+    ///         .page(page)
+    ///         .execute()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_channel_members(&self) -> GetChannelMembersRequestBuilder<T, D> {
+        GetChannelMembersRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a set `channel` members request builder.
+    ///
+    /// This method is used to add or update `uuid` members of the `channel`.
+    ///
+    /// Instance of [`SetChannelMembersRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::dx::objects::ChannelMember;
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// pubnub
+    ///     .set_channel_members()
+    ///     .channel("my_channel")
+    ///     .uuids([
+    ///         ChannelMember::new("john"),
+    ///         ChannelMember {
+    ///             r#type: Some("moderator".into()),
+    ///             ..ChannelMember::new("jane")
+    ///         },
+    ///     ])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_channel_members(&self) -> SetChannelMembersRequestBuilder<T, D> {
+        SetChannelMembersRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove `channel` members request builder.
+    ///
+    /// This method is used to remove `uuid` members of the `channel`.
+    ///
+    /// Instance of [`RemoveChannelMembersRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// pubnub
+    ///     .remove_channel_members()
+    ///     .channel("my_channel")
+    ///     .uuids(["john"])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_channel_members(&self) -> RemoveChannelMembersRequestBuilder<T, D> {
+        RemoveChannelMembersRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, ScalarValue, TransportMethod, TransportRequest, TransportResponse},
+        lib::collections::HashMap,
+        providers::deserialization_serde::DeserializerSerde,
+        test::{mock_client, MockTransport},
+        PubNubGenericClient,
+    };
+
+    const UUID_METADATA: &str = r#"{"status":200,"data":{"id":"john","name":"John Doe","externalId":null,"profileUrl":null,"email":"john@example.com","type":"admin","status":"active","custom":{"age":42,"vip":true},"updated":"2024-02-20T23:11:20.893755","eTag":"AbCdEf"}}"#;
+
+    fn client(
+        status: u16,
+        body: &'static str,
+        verify: fn(&TransportRequest),
+    ) -> PubNubGenericClient<MockTransport, DeserializerSerde> {
+        let transport = MockTransport::new()
+            .with_default_response(TransportResponse {
+                status,
+                body: Some(body.as_bytes().to_vec().into()),
+                ..Default::default()
+            })
+            .with_request_check(verify);
+
+        mock_client(transport, "john")
+    }
+
+    #[test]
+    fn not_build_request_with_empty_uuid() {
+        let request = client(200, "", |_| {}).get_uuid_metadata().uuid("").build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn set_uuid_metadata() {
+        let result = client(200, UUID_METADATA, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/uuids/john");
+            assert_eq!(request.method, TransportMethod::Patch);
+            assert_eq!(
+                request.query_parameters.get("include"),
+                Some(&"custom,status,type".into())
+            );
+            assert_eq!(request.headers.get("If-Match"), Some(&"AbCdEf".into()));
+            assert_eq!(
+                String::from_utf8(request.body.clone().unwrap()).unwrap(),
+                r#"{"custom":{"age":42},"email":"john@example.com","name":"John Doe"}"#
+            );
+        })
+        .set_uuid_metadata()
+        .name("John Doe")
+        .email("john@example.com")
+        .custom(HashMap::from([("age".into(), ScalarValue::from(42))]))
+        .if_matches_etag("AbCdEf")
+        .execute()
+        .await
+        .unwrap();
+
+        assert_eq!(result.data.id, "john");
+        assert_eq!(result.data.tag, "AbCdEf");
+    }
+
+    #[tokio::test]
+    async fn execute_detached_request_with_client_user_id() {
+        let request = GetUuidMetadataRequest::builder().include_custom(false);
+        let client = client(200, UUID_METADATA, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/uuids/john");
+        });
+
+        let result = request.try_execute_with(&client).await.unwrap();
+
+        assert_eq!(result.data.id, "john");
+    }
+
+    #[tokio::test]
+    async fn return_err_for_detached_request_without_client() {
+        let result = GetChannelMembersRequest::<MockTransport, DeserializerSerde>::builder()
+            .channel("channel-a")
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn get_uuid_metadata() {
+        let result = client(200, UUID_METADATA, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/uuids/john%20doe");
+            assert_eq!(request.method, TransportMethod::Get);
+            assert_eq!(
+                request.query_parameters.get("include"),
+                Some(&"status,type".into())
+            );
+        })
+        .get_uuid_metadata()
+        .uuid("john doe")
+        .include_custom(false)
+        .execute()
+        .await
+        .unwrap();
+
+        assert_eq!(result.data.name.as_deref(), Some("John Doe"));
+        assert_eq!(result.data.r#type.as_deref(), Some("admin"));
+        assert_eq!(result.data.external_id, None);
+        assert_eq!(
+            result.data.custom.unwrap().get("vip"),
+            Some(&ScalarValue::Boolean(true))
+        );
+    }
+
+    #[tokio::test]
+    async fn return_error_for_missing_uuid_metadata() {
+        let result = client(
+            404,
+            r#"{"status":404,"error":{"source":"objects","message":"Requested object was not found."}}"#,
+            |_| {},
+        )
+        .get_uuid_metadata()
+        .execute()
+        .await;
+
+        assert!(matches!(result, Err(PubNubError::API { status: 404, .. })));
+    }
+
+    #[tokio::test]
+    async fn remove_uuid_metadata() {
+        let result = client(200, r#"{"status":200,"data":null}"#, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/uuids/john");
+            assert_eq!(request.method, TransportMethod::Delete);
+        })
+        .remove_uuid_metadata()
+        .execute()
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn return_error_for_forbidden_remove() {
+        let result = client(
+            403,
+            r#"{"status":403,"error":{"source":"objects","message":"Forbidden"}}"#,
+            |_| {},
+        )
+        .remove_uuid_metadata()
+        .execute()
+        .await;
+
+        assert!(matches!(result, Err(PubNubError::API { status: 403, .. })));
+    }
+
+    #[test]
+    fn not_build_get_all_request_with_too_large_limit() {
+        let request = client(200, "", |_| {})
+            .get_all_uuid_metadata()
+            .limit(101)
+            .build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn get_all_uuid_metadata_page() {
+        let result = client(
+            200,
+            r#"{"status":200,"data":[
+                {"id":"john","name":"John Doe","updated":"2024-02-20T23:11:20.893755","eTag":"AbCdEf"},
+                {"id":"jane","name":"Jane Doe","custom":null,"updated":"2024-02-21T23:11:20.893755","eTag":"FeDcBa"}
+            ],"totalCount":4,"next":"Mg","prev":"MQ"}"#,
+            |request| {
+                assert_eq!(request.path, "/v2/objects/demo/uuids");
+                assert_eq!(
+                    request.query_parameters.get("include"),
+                    Some(&"custom,status,type".into())
+                );
+                assert_eq!(
+                    request.query_parameters.get("filter"),
+                    Some(&"name LIKE '*Doe'".into())
+                );
+                assert_eq!(
+                    request.query_parameters.get("sort"),
+                    Some(&"name:desc,updated".into())
+                );
+                assert_eq!(request.query_parameters.get("limit"), Some(&"2".into()));
+                assert_eq!(request.query_parameters.get("start"), Some(&"MQ".into()));
+                assert_eq!(request.query_parameters.get("end"), None);
+                assert_eq!(request.query_parameters.get("count"), Some(&"true".into()));
+            },
+        )
+        .get_all_uuid_metadata()
+        .filter("name LIKE '*Doe'")
+        .sort(["name:desc", "updated"])
+        .limit(2)
+        .page(Page::Next("MQ".into()))
+        .include_total_count(true)
+        .execute()
+        .await
+        .unwrap();
+
+        assert_eq!(result.data.len(), 2);
+        assert_eq!(result.data[1].id, "jane");
+        assert_eq!(result.total_count, Some(4));
+        assert_eq!(result.next, Some(Page::Next("Mg".into())));
+        assert_eq!(result.prev, Some(Page::Prev("MQ".into())));
+    }
+
+    #[tokio::test]
+    async fn get_all_uuid_metadata_last_page() {
+        let result = client(200, r#"{"status":200,"data":[],"prev":"Mg"}"#, |request| {
+            assert_eq!(request.query_parameters.get("end"), Some(&"Mg".into()));
+            assert_eq!(request.query_parameters.get("count"), None);
+        })
+        .get_all_uuid_metadata()
+        .page(Page::Prev("Mg".into()))
+        .execute()
+        .await
+        .unwrap();
+
+        assert!(result.data.is_empty());
+        assert_eq!(result.total_count, None);
+        assert_eq!(result.next, None);
+    }
+
+    #[tokio::test]
+    async fn get_memberships() {
+        let result = client(
+            200,
+            r#"{"status":200,"data":[
+                {"channel":{"id":"main","name":"Main channel","description":null,"custom":{"public":true},"updated":"2024-02-20T23:11:20.893755","eTag":"RTc1NUQwNUItNjk"},"custom":{"starred":true},"status":"active","updated":"2024-02-20T23:11:20.893755","eTag":"AZO/t53al7m8fw"},
+                {"channel":{"id":"support"},"updated":"2024-02-21T23:11:20.893755","eTag":"AZO/t53al7m8fx"}
+            ],"totalCount":2,"next":"Mg"}"#,
+            |request| {
+                assert_eq!(request.path, "/v2/objects/demo/uuids/john/channels");
+                assert_eq!(request.method, TransportMethod::Get);
+                assert_eq!(
+                    request.query_parameters.get("include"),
+                    Some(&"custom,channel,channel.custom,status,type".into())
+                );
+                assert_eq!(
+                    request.query_parameters.get("filter"),
+                    Some(&"channel.name LIKE 'M*'".into())
+                );
+                assert_eq!(request.query_parameters.get("count"), Some(&"true".into()));
+            },
+        )
+        .get_memberships()
+        .filter("channel.name LIKE 'M*'")
+        .include_channel_fields(true)
+        .include_channel_custom_fields(true)
+        .include_total_count(true)
+        .execute()
+        .await
+        .unwrap();
+
+        assert_eq!(result.data.len(), 2);
+        assert_eq!(result.data[0].channel.name.as_deref(), Some("Main channel"));
+        assert_eq!(result.data[0].status.as_deref(), Some("active"));
+        assert_eq!(result.data[1].channel.id, "support");
+        assert_eq!(result.data[1].custom, None);
+        assert_eq!(result.total_count, Some(2));
+        assert_eq!(result.next, Some(Page::Next("Mg".into())));
+    }
+
+    #[test]
+    fn not_build_set_memberships_request_without_channels() {
+        let request = client(200, "", |_| {}).set_memberships().build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn set_memberships() {
+        let result = client(200, r#"{"status":200,"data":[]}"#, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/uuids/jane/channels");
+            assert_eq!(request.method, TransportMethod::Patch);
+            assert_eq!(
+                request.query_parameters.get("include"),
+                Some(&"status,type".into())
+            );
+            assert_eq!(
+                String::from_utf8(request.body.clone().unwrap()).unwrap(),
+                r#"{"set":[{"channel":{"id":"main"}},{"channel":{"id":"support"},"custom":{"starred":true},"status":"active"}]}"#
+            );
+        })
+        .set_memberships()
+        .uuid("jane")
+        .channels([
+            ChannelMembership::new("main"),
+            ChannelMembership {
+                custom: Some(HashMap::from([("starred".into(), ScalarValue::from(true))])),
+                status: Some("active".into()),
+                ..ChannelMembership::new("support")
+            },
+        ])
+        .include_custom(false)
+        .execute()
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn remove_memberships() {
+        let result = client(200, r#"{"status":200,"data":[]}"#, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/uuids/john/channels");
+            assert_eq!(request.method, TransportMethod::Patch);
+            assert_eq!(
+                String::from_utf8(request.body.clone().unwrap()).unwrap(),
+                r#"{"delete":[{"channel":{"id":"main"}},{"channel":{"id":"support"}}]}"#
+            );
+        })
+        .remove_memberships()
+        .channels(["main", "support"])
+        .execute()
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn return_error_for_failed_memberships_update() {
+        let result = client(
+            400,
+            r#"{"status":400,"error":{"source":"objects","message":"Request payload contained invalid input."}}"#,
+            |_| {},
+        )
+        .remove_memberships()
+        .channels(["main"])
+        .execute()
+        .await;
+
+        assert!(matches!(result, Err(PubNubError::API { status: 400, .. })));
+    }
+
+    #[test]
+    fn not_build_channel_members_request_without_channel() {
+        let request = client(200, "", |_| {}).get_channel_members().build();
+
+        assert!(request.is_err());
+    }
+
+    #[tokio::test]
+    async fn get_channel_members() {
+        let result = client(
+            200,
+            r#"{"status":200,"data":[
+                {"uuid":{"id":"john","name":"John Doe","updated":"2024-02-20T23:11:20.893755","eTag":"AbCdEf"},"custom":{"role":"admin"},"type":"moderator","updated":"2024-02-20T23:11:20.893755","eTag":"AZO/t53al7m8fw"},
+                {"uuid":{"id":"jane"},"updated":"2024-02-21T23:11:20.893755","eTag":"AZO/t53al7m8fx"}
+            ],"totalCount":3,"next":"Mg","prev":"MQ"}"#,
+            |request| {
+                assert_eq!(request.path, "/v2/objects/demo/channels/my%20channel/uuids");
+                assert_eq!(request.method, TransportMethod::Get);
+                assert_eq!(
+                    request.query_parameters.get("include"),
+                    Some(&"custom,uuid,status,type".into())
+                );
+                assert_eq!(
+                    request.query_parameters.get("sort"),
+                    Some(&"uuid.name".into())
+                );
+                assert_eq!(request.query_parameters.get("limit"), Some(&"2".into()));
+                assert_eq!(request.query_parameters.get("start"), Some(&"MQ".into()));
+            },
+        )
+        .get_channel_members()
+        .channel("my channel")
+        .sort(["uuid.name"])
+        .limit(2)
+        .page(Page::Next("MQ".into()))
+        .include_uuid_fields(true)
+        .execute()
+        .await
+        .unwrap();
+
+        assert_eq!(result.data.len(), 2);
+        assert_eq!(result.data[0].uuid.name.as_deref(), Some("John Doe"));
+        assert_eq!(result.data[0].r#type.as_deref(), Some("moderator"));
+        assert_eq!(result.data[1].uuid.id, "jane");
+        assert!(result.data[1].uuid.tag.is_empty());
+        assert_eq!(result.total_count, Some(3));
+        assert_eq!(result.prev, Some(Page::Prev("MQ".into())));
+    }
+
+    #[tokio::test]
+    async fn set_channel_members() {
+        let result = client(200, r#"{"status":200,"data":[]}"#, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/channels/main/uuids");
+            assert_eq!(request.method, TransportMethod::Patch);
+            assert_eq!(
+                request.query_parameters.get("include"),
+                Some(&"custom,uuid,uuid.custom,status,type".into())
+            );
+            assert_eq!(
+                String::from_utf8(request.body.clone().unwrap()).unwrap(),
+                r#"{"set":[{"uuid":{"id":"john"}},{"custom":{"role":"admin"},"type":"moderator","uuid":{"id":"jane"}}]}"#
+            );
+        })
+        .set_channel_members()
+        .channel("main")
+        .uuids([
+            ChannelMember::new("john"),
+            ChannelMember {
+                custom: Some(HashMap::from([("role".into(), ScalarValue::from(String::from("admin")))])),
+                r#type: Some("moderator".into()),
+                ..ChannelMember::new("jane")
+            },
+        ])
+        .include_uuid_fields(true)
+        .include_uuid_custom_fields(true)
+        .execute()
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn remove_channel_members() {
+        let result = client(200, r#"{"status":200,"data":[]}"#, |request| {
+            assert_eq!(request.path, "/v2/objects/demo/channels/main/uuids");
+            assert_eq!(request.method, TransportMethod::Patch);
+            assert_eq!(
+                String::from_utf8(request.body.clone().unwrap()).unwrap(),
+                r#"{"delete":[{"uuid":{"id":"john"}}]}"#
+            );
+        })
+        .remove_channel_members()
+        .channel("main")
+        .uuids(["john"])
+        .execute()
+        .await;
+
+        assert!(result.is_ok());
+    }
+}
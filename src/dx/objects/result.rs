@@ -0,0 +1,881 @@
+//! App Context result module.
+//!
+//! This module contains the [`UuidMetadataResult`],
+//! [`GetAllUuidMetadataResult`], [`RemoveUuidMetadataResult`],
+//! [`MembershipsResult`] and [`MembersResult`] types which are used to
+//! represent the result of App Context operations.
+
+use crate::{
+    core::{service_response::APIErrorBody, PubNubError, ResponseMetadata, ScalarValue},
+    lib::{
+        alloc::{string::String, vec::Vec},
+        collections::HashMap,
+    },
+};
+
+/// `UUID` metadata object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UuidMetadata {
+    /// Unique `uuid` object identifier.
+    pub id: String,
+
+    /// Given `uuid` object name.
+    pub name: Option<String>,
+
+    /// Email address associated with `uuid` object.
+    pub email: Option<String>,
+
+    /// `uuid` object identifier in external systems.
+    pub external_id: Option<String>,
+
+    /// `uuid` object external profile URL.
+    pub profile_url: Option<String>,
+
+    /// `uuid` object type information.
+    pub r#type: Option<String>,
+
+    /// `uuid` object current status.
+    pub status: Option<String>,
+
+    /// Flatten `HashMap` with additional information associated with `uuid`
+    /// object.
+    ///
+    /// Set only when request has been made with `include_custom` flag.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Recent `uuid` object modification date.
+    ///
+    /// Empty if `uuid` object fields haven't been requested for channel
+    /// member.
+    pub updated: String,
+
+    /// Current `uuid` object state hash.
+    ///
+    /// Empty if `uuid` object fields haven't been requested for channel
+    /// member.
+    pub tag: String,
+}
+
+/// The result of a set or get `uuid` metadata operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UuidMetadataResult {
+    /// `uuid` metadata object.
+    pub data: UuidMetadata,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(UuidMetadataResult);
+
+/// `channel` metadata object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelMetadataObject {
+    /// Unique `channel` object identifier.
+    pub id: String,
+
+    /// Given `channel` object name.
+    pub name: Option<String>,
+
+    /// `channel` object description.
+    pub description: Option<String>,
+
+    /// `channel` object type information.
+    pub r#type: Option<String>,
+
+    /// `channel` object current status.
+    pub status: Option<String>,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// `channel` object.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Recent `channel` object modification date.
+    ///
+    /// Empty if `channel` object fields haven't been requested.
+    pub updated: String,
+
+    /// Current `channel` object state hash.
+    ///
+    /// Empty if `channel` object fields haven't been requested.
+    pub tag: String,
+}
+
+/// `uuid` membership in `channel` information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Membership {
+    /// `channel` in which `uuid` is a member.
+    ///
+    /// Only `id` is set unless request has been made with
+    /// `include_channel_fields` flag.
+    pub channel: ChannelMetadataObject,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// membership.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Membership current status.
+    pub status: Option<String>,
+
+    /// Membership type information.
+    pub r#type: Option<String>,
+
+    /// Recent membership modification date.
+    pub updated: String,
+
+    /// Current membership state hash.
+    pub tag: String,
+}
+
+/// `channel` member information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    /// `uuid` which is a member of `channel`.
+    ///
+    /// Only `id` is set unless request has been made with
+    /// `include_uuid_fields` flag.
+    pub uuid: UuidMetadata,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// member.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Member current status.
+    pub status: Option<String>,
+
+    /// Member type information.
+    pub r#type: Option<String>,
+
+    /// Recent member modification date.
+    pub updated: String,
+
+    /// Current member state hash.
+    pub tag: String,
+}
+
+/// Cursor of App Context objects list page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Page {
+    /// Cursor of page which follows current one.
+    Next(String),
+
+    /// Cursor of page which precedes current one.
+    Prev(String),
+}
+
+/// The result of a get all `uuid` metadata operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetAllUuidMetadataResult {
+    /// List of `uuid` metadata objects on requested page.
+    pub data: Vec<UuidMetadata>,
+
+    /// Total number of `uuid` metadata objects which match request filter.
+    ///
+    /// Set only when request has been made with `include_total_count` flag.
+    pub total_count: Option<usize>,
+
+    /// Cursor of the next page.
+    ///
+    /// Not set if there are no more objects.
+    pub next: Option<Page>,
+
+    /// Cursor of the previous page.
+    ///
+    /// Not set if current page is the first one.
+    pub prev: Option<Page>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(GetAllUuidMetadataResult);
+
+/// The result of a get, set or remove memberships operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembershipsResult {
+    /// List of `uuid` memberships on requested page.
+    pub data: Vec<Membership>,
+
+    /// Total number of `uuid` memberships which match request filter.
+    ///
+    /// Set only when request has been made with `include_total_count` flag.
+    pub total_count: Option<usize>,
+
+    /// Cursor of the next page.
+    ///
+    /// Not set if there are no more memberships.
+    pub next: Option<Page>,
+
+    /// Cursor of the previous page.
+    ///
+    /// Not set if current page is the first one.
+    pub prev: Option<Page>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(MembershipsResult);
+
+/// The result of a get, set or remove `channel` members operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembersResult {
+    /// List of `channel` members on requested page.
+    pub data: Vec<Member>,
+
+    /// Total number of `channel` members which match request filter.
+    ///
+    /// Set only when request has been made with `include_total_count` flag.
+    pub total_count: Option<usize>,
+
+    /// Cursor of the next page.
+    ///
+    /// Not set if there are no more members.
+    pub next: Option<Page>,
+
+    /// Cursor of the previous page.
+    ///
+    /// Not set if current page is the first one.
+    pub prev: Option<Page>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(MembersResult);
+
+/// The result of a remove `uuid` metadata operation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RemoveUuidMetadataResult {
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(RemoveUuidMetadataResult);
+
+/// The response body of a set or get `uuid` metadata operation.
+///
+/// It's used for deserialization of the `uuid` metadata response. This type is
+/// an intermediate type between the raw response body and the
+/// [`UuidMetadataResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum UuidMetadataResponseBody {
+    /// This is a success response body for a set or get `uuid` metadata
+    /// operation in the App Context service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {
+    ///         "id": "user-1",
+    ///         "name": "John Doe",
+    ///         "externalId": null,
+    ///         "profileUrl": null,
+    ///         "email": "jack@twitter.com",
+    ///         "type": "admin",
+    ///         "status": "active",
+    ///         "custom": {
+    ///             "age": 42
+    ///         },
+    ///         "updated": "2019-02-20T23:11:20.893755",
+    ///         "eTag": "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(UuidMetadataSuccessBody),
+
+    /// This is an error response body for a set or get `uuid` metadata
+    /// operation in the App Context service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 404,
+    ///     "error": {
+    ///         "source": "objects",
+    ///         "message": "Requested object was not found."
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful set or get `uuid` metadata REST API operation
+/// response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UuidMetadataSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// `uuid` metadata object.
+    pub data: UuidMetadataBody,
+}
+
+/// `uuid` metadata object from App Context service response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UuidMetadataBody {
+    /// Unique `uuid` object identifier.
+    pub id: String,
+
+    /// Given `uuid` object name.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+
+    /// Email address associated with `uuid` object.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub email: Option<String>,
+
+    /// `uuid` object identifier in external systems.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub external_id: Option<String>,
+
+    /// `uuid` object external profile URL.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub profile_url: Option<String>,
+
+    /// `uuid` object type information.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub r#type: Option<String>,
+
+    /// `uuid` object current status.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status: Option<String>,
+
+    /// Additional information associated with `uuid` object.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Recent `uuid` object modification date.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub updated: String,
+
+    /// Current `uuid` object state hash.
+    #[cfg_attr(feature = "serde", serde(default, rename = "eTag"))]
+    pub e_tag: String,
+}
+
+/// The response body of a get all `uuid` metadata operation.
+///
+/// It's used for deserialization of the get all `uuid` metadata response. This
+/// type is an intermediate type between the raw response body and the
+/// [`GetAllUuidMetadataResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetAllUuidMetadataResponseBody {
+    /// This is a success response body for a get all `uuid` metadata
+    /// operation in the App Context service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": [
+    ///         {
+    ///             "id": "user-1",
+    ///             "name": "John Doe",
+    ///             "updated": "2019-02-20T23:11:20.893755",
+    ///             "eTag": "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="
+    ///         }
+    ///     ],
+    ///     "totalCount": 9,
+    ///     "next": "MUIwQTAwMUItQkRBRC00NDkyLTgyMEMtMDJGOEUxNUY4QUNF"
+    /// }
+    /// ```
+    SuccessResponse(GetAllUuidMetadataSuccessBody),
+
+    /// This is an error response body for a get all `uuid` metadata
+    /// operation in the App Context service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": {
+    ///         "source": "objects",
+    ///         "message": "Invalid filter expression."
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful get all `uuid` metadata REST API operation response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetAllUuidMetadataSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// List of `uuid` metadata objects.
+    pub data: Vec<UuidMetadataBody>,
+
+    /// Total number of `uuid` metadata objects which match request filter.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub total_count: Option<usize>,
+
+    /// Cursor of the next page.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub next: Option<String>,
+
+    /// Cursor of the previous page.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub prev: Option<String>,
+}
+
+/// The response body of a get, set or remove memberships operation.
+///
+/// It's used for deserialization of the memberships response. This type is an
+/// intermediate type between the raw response body and the
+/// [`MembershipsResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MembershipsResponseBody {
+    /// This is a success response body for a memberships operation in the App
+    /// Context service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": [
+    ///         {
+    ///             "channel": {
+    ///                 "id": "my-channel",
+    ///                 "name": "My channel",
+    ///                 "updated": "2019-02-20T23:11:20.893755",
+    ///                 "eTag": "RTc1NUQwNUItNDEyNy00RkFBLUJCMDEtQjEyM0I4NDMxNzAzCg=="
+    ///             },
+    ///             "custom": {
+    ///                 "starred": true
+    ///             },
+    ///             "updated": "2019-02-20T23:11:20.893755",
+    ///             "eTag": "RUNDMDUwNjktNUYwRC00RTI0LUI1M0QtNUUzNkE2NkU0MEVFCg=="
+    ///         }
+    ///     ],
+    ///     "totalCount": 1,
+    ///     "next": "MUIwQTAwMUItQkRBRC00NDkyLTgyMEMtMDJGOEUxNUY4QUNF"
+    /// }
+    /// ```
+    SuccessResponse(MembershipsSuccessBody),
+
+    /// This is an error response body for a memberships operation in the App
+    /// Context service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": {
+    ///         "source": "objects",
+    ///         "message": "Request payload contained invalid input."
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful memberships REST API operation response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembershipsSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// List of `uuid` memberships.
+    pub data: Vec<MembershipBody>,
+
+    /// Total number of `uuid` memberships which match request filter.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub total_count: Option<usize>,
+
+    /// Cursor of the next page.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub next: Option<String>,
+
+    /// Cursor of the previous page.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub prev: Option<String>,
+}
+
+/// `uuid` membership from App Context service response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembershipBody {
+    /// `channel` in which `uuid` is a member.
+    pub channel: ChannelMetadataBody,
+
+    /// Additional information associated with membership.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Membership current status.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status: Option<String>,
+
+    /// Membership type information.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub r#type: Option<String>,
+
+    /// Recent membership modification date.
+    pub updated: String,
+
+    /// Current membership state hash.
+    #[cfg_attr(feature = "serde", serde(rename = "eTag"))]
+    pub e_tag: String,
+}
+
+/// `channel` metadata object from App Context service response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelMetadataBody {
+    /// Unique `channel` object identifier.
+    pub id: String,
+
+    /// Given `channel` object name.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+
+    /// `channel` object description.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: Option<String>,
+
+    /// `channel` object type information.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub r#type: Option<String>,
+
+    /// `channel` object current status.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status: Option<String>,
+
+    /// Additional information associated with `channel` object.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Recent `channel` object modification date.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub updated: String,
+
+    /// Current `channel` object state hash.
+    #[cfg_attr(feature = "serde", serde(default, rename = "eTag"))]
+    pub e_tag: String,
+}
+
+/// The response body of a `channel` members operation.
+///
+/// It's used for deserialization of the `channel` members response. This type
+/// is an intermediate type between the raw response body and the
+/// [`MembersResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MembersResponseBody {
+    /// This is a success response body for a `channel` members operation in
+    /// the App Context service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": [
+    ///         {
+    ///             "uuid": {
+    ///                 "id": "john",
+    ///                 "name": "John Doe",
+    ///                 "updated": "2019-02-20T23:11:20.893755",
+    ///                 "eTag": "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="
+    ///             },
+    ///             "custom": {
+    ///                 "role": "admin"
+    ///             },
+    ///             "updated": "2019-02-20T23:11:20.893755",
+    ///             "eTag": "QkRENDA5MjItMUZCNC00REI5LUE4QTktRjJGNUMxNTc2MzE3Cg=="
+    ///         }
+    ///     ],
+    ///     "totalCount": 1,
+    ///     "next": "MUIwQTAwMUItQkRBRC00NDkyLTgyMEMtMDJGOEUxNUY4QUNF"
+    /// }
+    /// ```
+    SuccessResponse(MembersSuccessBody),
+
+    /// This is an error response body for a `channel` members operation in
+    /// the App Context service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": {
+    ///         "source": "objects",
+    ///         "message": "Request payload contained invalid input."
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful `channel` members REST API operation response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembersSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// List of `channel` members.
+    pub data: Vec<MemberBody>,
+
+    /// Total number of `channel` members which match request filter.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub total_count: Option<usize>,
+
+    /// Cursor of the next page.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub next: Option<String>,
+
+    /// Cursor of the previous page.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub prev: Option<String>,
+}
+
+/// `channel` member from App Context service response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberBody {
+    /// `uuid` which is a member of `channel`.
+    pub uuid: UuidMetadataBody,
+
+    /// Additional information associated with member.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Member current status.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status: Option<String>,
+
+    /// Member type information.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub r#type: Option<String>,
+
+    /// Recent member modification date.
+    pub updated: String,
+
+    /// Current member state hash.
+    #[cfg_attr(feature = "serde", serde(rename = "eTag"))]
+    pub e_tag: String,
+}
+
+/// The response body of a remove `uuid` metadata operation.
+///
+/// It's used for deserialization of the remove `uuid` metadata response. This
+/// type is an intermediate type between the raw response body and the
+/// [`RemoveUuidMetadataResult`] type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoveUuidMetadataResponseBody {
+    /// This is a success response body for a remove `uuid` metadata operation
+    /// in the App Context service.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": null
+    /// }
+    /// ```
+    SuccessResponse(RemoveUuidMetadataSuccessBody),
+
+    /// This is an error response body for a remove `uuid` metadata operation
+    /// in the App Context service.
+    ///
+    /// It contains information about the service that provided the response and
+    /// details of what exactly was wrong.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 403,
+    ///     "error": {
+    ///         "source": "objects",
+    ///         "message": "Forbidden"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+/// Content of successful remove `uuid` metadata REST API operation response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveUuidMetadataSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// Empty data which is returned by service on success.
+    ///
+    /// Field is required (even if `null`) to distinguish success response
+    /// from error response.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "serde::Deserialize::deserialize")
+    )]
+    pub data: Option<RemovedUuidMetadataBody>,
+}
+
+/// Empty data object from remove `uuid` metadata response.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovedUuidMetadataBody {}
+
+impl From<UuidMetadataBody> for UuidMetadata {
+    fn from(value: UuidMetadataBody) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            email: value.email,
+            external_id: value.external_id,
+            profile_url: value.profile_url,
+            r#type: value.r#type,
+            status: value.status,
+            custom: value.custom,
+            updated: value.updated,
+            tag: value.e_tag,
+        }
+    }
+}
+
+impl From<ChannelMetadataBody> for ChannelMetadataObject {
+    fn from(value: ChannelMetadataBody) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            description: value.description,
+            r#type: value.r#type,
+            status: value.status,
+            custom: value.custom,
+            updated: value.updated,
+            tag: value.e_tag,
+        }
+    }
+}
+
+impl From<MembershipBody> for Membership {
+    fn from(value: MembershipBody) -> Self {
+        Self {
+            channel: value.channel.into(),
+            custom: value.custom,
+            status: value.status,
+            r#type: value.r#type,
+            updated: value.updated,
+            tag: value.e_tag,
+        }
+    }
+}
+
+impl From<MemberBody> for Member {
+    fn from(value: MemberBody) -> Self {
+        Self {
+            uuid: value.uuid.into(),
+            custom: value.custom,
+            status: value.status,
+            r#type: value.r#type,
+            updated: value.updated,
+            tag: value.e_tag,
+        }
+    }
+}
+
+impl TryFrom<UuidMetadataResponseBody> for UuidMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(value: UuidMetadataResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            UuidMetadataResponseBody::SuccessResponse(resp) => Ok(UuidMetadataResult {
+                data: resp.data.into(),
+                metadata: Default::default(),
+            }),
+            UuidMetadataResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+impl TryFrom<GetAllUuidMetadataResponseBody> for GetAllUuidMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(value: GetAllUuidMetadataResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            GetAllUuidMetadataResponseBody::SuccessResponse(resp) => Ok(GetAllUuidMetadataResult {
+                data: resp.data.into_iter().map(Into::into).collect(),
+                total_count: resp.total_count,
+                next: resp.next.filter(|next| !next.is_empty()).map(Page::Next),
+                prev: resp.prev.filter(|prev| !prev.is_empty()).map(Page::Prev),
+                metadata: Default::default(),
+            }),
+            GetAllUuidMetadataResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+impl TryFrom<RemoveUuidMetadataResponseBody> for RemoveUuidMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(value: RemoveUuidMetadataResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            RemoveUuidMetadataResponseBody::SuccessResponse(_) => {
+                Ok(RemoveUuidMetadataResult::default())
+            }
+            RemoveUuidMetadataResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+impl TryFrom<MembershipsResponseBody> for MembershipsResult {
+    type Error = PubNubError;
+
+    fn try_from(value: MembershipsResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            MembershipsResponseBody::SuccessResponse(resp) => Ok(MembershipsResult {
+                data: resp.data.into_iter().map(Into::into).collect(),
+                total_count: resp.total_count,
+                next: resp.next.filter(|next| !next.is_empty()).map(Page::Next),
+                prev: resp.prev.filter(|prev| !prev.is_empty()).map(Page::Prev),
+                metadata: Default::default(),
+            }),
+            MembershipsResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+impl TryFrom<MembersResponseBody> for MembersResult {
+    type Error = PubNubError;
+
+    fn try_from(value: MembersResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            MembersResponseBody::SuccessResponse(resp) => Ok(MembersResult {
+                data: resp.data.into_iter().map(Into::into).collect(),
+                total_count: resp.total_count,
+                next: resp.next.filter(|next| !next.is_empty()).map(Page::Next),
+                prev: resp.prev.filter(|prev| !prev.is_empty()).map(Page::Prev),
+                metadata: Default::default(),
+            }),
+            MembersResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
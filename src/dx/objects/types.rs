@@ -0,0 +1,101 @@
+//! # App Context types module.
+//!
+//! This module contains types which are used to describe App Context objects
+//! relations in requests.
+
+use crate::{
+    core::ScalarValue,
+    lib::{alloc::string::String, collections::HashMap},
+};
+
+/// `uuid` membership in `channel`.
+///
+/// Describes `channel` which should be added to the `uuid` memberships list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelMembership {
+    /// Name of `channel` in which `uuid` is a member.
+    pub channel: String,
+
+    /// Flatten `HashMap` with additional information which should be
+    /// associated with membership.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Membership current status.
+    pub status: Option<String>,
+
+    /// Membership type information.
+    pub r#type: Option<String>,
+}
+
+impl ChannelMembership {
+    /// Create membership in `channel` without additional information.
+    pub fn new<S>(channel: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            channel: channel.into(),
+            custom: None,
+            status: None,
+            r#type: None,
+        }
+    }
+}
+
+impl From<&str> for ChannelMembership {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for ChannelMembership {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+/// `channel` member.
+///
+/// Describes `uuid` which should be added to the `channel` members list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelMember {
+    /// Identifier of `uuid` which is a member of `channel`.
+    pub uuid: String,
+
+    /// Flatten `HashMap` with additional information which should be
+    /// associated with member.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Member current status.
+    pub status: Option<String>,
+
+    /// Member type information.
+    pub r#type: Option<String>,
+}
+
+impl ChannelMember {
+    /// Create `channel` member without additional information.
+    pub fn new<S>(uuid: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            uuid: uuid.into(),
+            custom: None,
+            status: None,
+            r#type: None,
+        }
+    }
+}
+
+impl From<&str> for ChannelMember {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for ChannelMember {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
@@ -4,34 +4,16 @@
 //! [`Token`] with information about the permissions granted to the token.
 
 use crate::core::Deserializer;
+#[cfg(feature = "serde")]
+use crate::providers::deserialization_cbor::DeserializerCbor;
 use crate::{
     core::PubNubError,
     lib::{
-        alloc::{
-            format,
-            string::{String, ToString},
-        },
+        alloc::{format, string::String},
         collections::HashMap,
     },
 };
 use base64::{engine::general_purpose, Engine};
-#[cfg(feature = "serde")]
-use ciborium::de::from_reader;
-
-#[cfg(feature = "serde")]
-struct CiboriumDeserializer;
-
-#[cfg(feature = "serde")]
-impl Deserializer for CiboriumDeserializer {
-    fn deserialize<Token: for<'de> serde::Deserialize<'de>>(
-        &self,
-        bytes: &[u8],
-    ) -> Result<Token, PubNubError> {
-        from_reader(bytes).map_err(|e| PubNubError::TokenDeserialization {
-            details: e.to_string(),
-        })
-    }
-}
 
 /// The [`parse_token`] function decodes an existing token and returns the
 /// struct containing permissions embedded in that token.
@@ -39,7 +21,10 @@ impl Deserializer for CiboriumDeserializer {
 /// resources.
 #[cfg(feature = "serde")]
 pub fn parse_token(token: &str) -> Result<Token, PubNubError> {
-    parse_token_with(token, CiboriumDeserializer)
+    parse_token_with(token, DeserializerCbor).map_err(|err| match err {
+        PubNubError::Deserialization { details } => PubNubError::TokenDeserialization { details },
+        err => err,
+    })
 }
 
 /// The [`parse_token`] function decodes an existing token and returns the
@@ -53,7 +38,7 @@ where
     let token_bytes = general_purpose::URL_SAFE
         .decode(format!("{token}{}", "=".repeat(token.len() % 4)).as_bytes())
         .map_err(|e| PubNubError::TokenDeserialization {
-            details: e.to_string(),
+            details: error_details!(e),
         })?;
 
     deserializer.deserialize(&token_bytes)
@@ -59,7 +59,7 @@ pub struct GetStateRequest<T, D> {
     /// Channels with which state will be associated.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(strip_option, into),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channels: Vec<String>,
@@ -70,7 +70,7 @@ pub struct GetStateRequest<T, D> {
     /// included in the specified target channel groups.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(into, strip_option),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channel_groups: Vec<String>,
@@ -79,9 +79,45 @@ pub struct GetStateRequest<T, D> {
     /// Identifier for which `state` should be associated for provided list of
     /// channels and groups.
     pub(in crate::dx::presence) user_id: String,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::presence)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::presence) timeout: Option<u64>,
 }
 
+impl_from_builder_error!(GetStateRequestBuilderError);
+
 impl<T, D> GetStateRequestBuilder<T, D> {
+    /// Channels with which state will be associated.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Channel groups with which state will be associated.
+    ///
+    /// The specified state will be associated with channels that have been
+    /// included in the specified target channel groups.
+    pub fn channel_groups<L, S>(mut self, channel_groups: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channel_groups = Some(channel_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Validate user-provided data for request builder.
     ///
     /// Validator ensure that list of provided data is enough to build valid
@@ -103,8 +139,7 @@ impl<T, D> GetStateRequestBuilder<T, D> {
 
     /// Build [`GetStateRequest`] from builder.
     fn request(self) -> Result<GetStateRequest<T, D>, PubNubError> {
-        self.build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+        self.build().map_err(PubNubError::from)
     }
 }
 
@@ -132,7 +167,9 @@ impl<T, D> GetStateRequest<T, D> {
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             body: None,
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
         })
     }
 }
@@ -154,9 +191,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -5,7 +5,7 @@
 
 use derive_builder::Builder;
 #[cfg(feature = "std")]
-use futures::{future::BoxFuture, select_biased, FutureExt};
+use futures::{select_biased, FutureExt};
 
 use crate::{
     core::{
@@ -13,6 +13,7 @@ use crate::{
         utils::{
             encoding::{url_encoded_channel_groups, url_encoded_channels},
             headers::{APPLICATION_JSON, CONTENT_TYPE},
+            json::json_object,
         },
         Deserializer, PubNubError, Transport, TransportMethod, TransportRequest,
     },
@@ -32,7 +33,10 @@ use crate::{
 };
 
 #[cfg(feature = "std")]
-use crate::{core::event_engine::cancel::CancellationTask, lib::alloc::sync::Arc};
+use crate::{
+    core::{event_engine::cancel::CancellationTask, maybe_send::BoxFuture},
+    lib::alloc::sync::Arc,
+};
 
 /// The [`HeartbeatRequestsBuilder`] is used to build a `user_id` presence
 /// announcement request that is sent to the [`PubNub`] network.
@@ -61,7 +65,7 @@ pub struct HeartbeatRequest<T, D> {
     /// Channel(s) for announcement.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(strip_option, into),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channels: Vec<String>,
@@ -69,7 +73,7 @@ pub struct HeartbeatRequest<T, D> {
     /// Channel group(s) for announcement.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(into, strip_option),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channel_groups: Vec<String>,
@@ -122,9 +126,85 @@ pub struct HeartbeatRequest<T, D> {
     /// announced.
     #[builder(field(vis = "pub(in crate::dx::presence)"), setter(strip_option, into))]
     pub(in crate::dx::presence) user_id: String,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::presence)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::presence) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(HeartbeatRequestBuilderError);
+
+/// The [`HeartbeatTargetBuilder`] is the first step of the `user_id` presence
+/// announcement request build.
+///
+/// Presence can't be announced without channels or channel groups, so request
+/// can be executed only after [`channels`] or [`channel_groups`] have been
+/// provided (which returns [`HeartbeatRequestBuilder`]).
+///
+/// [`channels`]: HeartbeatTargetBuilder::channels
+/// [`channel_groups`]: HeartbeatTargetBuilder::channel_groups
+pub struct HeartbeatTargetBuilder<T, D> {
+    /// Request builder with preset client configuration.
+    pub(in crate::dx::presence) builder: HeartbeatRequestBuilder<T, D>,
+}
+
+impl<T, D> HeartbeatTargetBuilder<T, D> {
+    /// Channel(s) for announcement.
+    pub fn channels<L, S>(self, channels: L) -> HeartbeatRequestBuilder<T, D>
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.builder.channels(channels)
+    }
+
+    /// Channel group(s) for announcement.
+    pub fn channel_groups<L, S>(self, channel_groups: L) -> HeartbeatRequestBuilder<T, D>
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.builder.channel_groups(channel_groups)
+    }
+
+    /// A state that should be associated with the `user_id`.
+    ///
+    /// See [`HeartbeatRequestBuilder::state`] for more information.
+    pub fn state(self, state: HashMap<String, Vec<u8>>) -> Self {
+        Self {
+            builder: self.builder.state(state),
+        }
+    }
 }
 
 impl<T, D> HeartbeatRequestBuilder<T, D> {
+    /// Channel(s) for announcement.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Channel group(s) for announcement.
+    pub fn channel_groups<L, S>(mut self, channel_groups: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channel_groups = Some(channel_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Validate user-provided data for request builder.
     ///
     /// Validator ensure that provided information is enough to build valid
@@ -146,8 +226,7 @@ impl<T, D> HeartbeatRequestBuilder<T, D> {
 
     /// Build [`HeartbeatRequest`] from builder.
     fn request(self) -> Result<HeartbeatRequest<T, D>, PubNubError> {
-        self.build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+        self.build().map_err(PubNubError::from)
     }
 }
 
@@ -168,7 +247,7 @@ impl<T, D> HeartbeatRequest<T, D> {
         if let Some(state) = self.state.as_ref() {
             let state_json =
                 String::from_utf8(state.clone()).map_err(|err| PubNubError::Serialization {
-                    details: err.to_string(),
+                    details: error_details!(err),
                 })?;
             query.insert("state".into(), state_json);
         }
@@ -184,7 +263,9 @@ impl<T, D> HeartbeatRequest<T, D> {
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             body: None,
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
         })
     }
 }
@@ -212,16 +293,11 @@ impl<T, D> HeartbeatRequestBuilder<T, D> {
     /// # }
     /// ```
     pub fn state(mut self, state: HashMap<String, Vec<u8>>) -> Self {
-        let mut serialized_state = vec![b'{'];
-        for (key, mut value) in state {
-            serialized_state.append(&mut format!("\"{}\":", key).as_bytes().to_vec());
-            serialized_state.append(&mut value);
-            serialized_state.push(b',');
-        }
-        if serialized_state.last() == Some(&b',') {
-            serialized_state.pop();
-        }
-        serialized_state.push(b'}');
+        let serialized_state = json_object(
+            state
+                .iter()
+                .map(|(channel, value)| (channel.as_str(), value.as_slice())),
+        );
 
         self.state = Some(Some(serialized_state));
         self
@@ -245,9 +321,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -315,7 +393,6 @@ mod it_should {
     async fn be_able_to_cancel_delayed_heartbeat_call() {
         struct MockTransport;
 
-        #[async_trait::async_trait]
         impl Transport for MockTransport {
             async fn send(&self, _req: TransportRequest) -> Result<TransportResponse, PubNubError> {
                 tokio::time::sleep(tokio::time::Duration::from_secs(5)).await; // Simulate long request.
@@ -340,7 +417,7 @@ mod it_should {
             .build()
             .unwrap()
             .heartbeat()
-            .channels(vec!["test".into()])
+            .channels(["test"])
             .execute_with_cancel_and_delay(Arc::new(|| ready(()).boxed()), cancel_task)
             .await;
 
@@ -52,7 +52,7 @@ pub struct HereNowRequest<T, D> {
     /// Channels for which to retrieve occupancy information.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(strip_option, into),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channels: Vec<String>,
@@ -60,7 +60,7 @@ pub struct HereNowRequest<T, D> {
     /// Channel groups for which to retrieve occupancy information.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(into, strip_option),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channel_groups: Vec<String>,
@@ -81,9 +81,42 @@ pub struct HereNowRequest<T, D> {
         default = "false"
     )]
     pub(in crate::dx::presence) include_state: bool,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::presence)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::presence) timeout: Option<u64>,
 }
 
+impl_from_builder_error!(HereNowRequestBuilderError);
+
 impl<T, D> HereNowRequestBuilder<T, D> {
+    /// Channels for which to retrieve occupancy information.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Channel groups for which to retrieve occupancy information.
+    pub fn channel_groups<L, S>(mut self, channel_groups: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channel_groups = Some(channel_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Validate user-provided data for request builder.
     ///
     /// Validator ensure that list of provided data is enough to build valid
@@ -103,8 +136,7 @@ impl<T, D> HereNowRequestBuilder<T, D> {
 
     /// Build [`SetStateRequest`] from builder.
     fn request(self) -> Result<HereNowRequest<T, D>, PubNubError> {
-        self.build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+        self.build().map_err(PubNubError::from)
     }
 }
 
@@ -138,7 +170,9 @@ impl<T, D> HereNowRequest<T, D> {
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             body: None,
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
         })
     }
 }
@@ -165,9 +199,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
             .map(|mut result: HereNowResult| {
@@ -188,12 +224,24 @@ where
 {
     /// Build and call synchronous request.
     pub fn execute_blocking(self) -> Result<HereNowResult, PubNubError> {
+        let name_replacement = self
+            .channels
+            .as_ref()
+            .and_then(|channels| (channels.len() == 1).then(|| channels[0].clone()));
+
         let request = self.request()?;
         let transport_request = request.transport_request()?;
         let client = request.pubnub_client.clone();
         let deserializer = client.deserializer.clone();
         transport_request
             .send_blocking::<HereNowResponseBody, _, _, _>(&client.transport, deserializer)
+            .map(|mut result: HereNowResult| {
+                name_replacement.is_some().then(|| {
+                    result.channels[0].name = name_replacement.expect("Cannot be None");
+                });
+
+                result
+            })
     }
 }
 
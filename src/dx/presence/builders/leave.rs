@@ -56,7 +56,7 @@ pub struct LeaveRequest<T, D> {
     /// Channels for announcement.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(strip_option, into),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channels: Vec<String>,
@@ -64,7 +64,7 @@ pub struct LeaveRequest<T, D> {
     /// Channel groups for announcement.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(into, strip_option),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channel_groups: Vec<String>,
@@ -73,9 +73,42 @@ pub struct LeaveRequest<T, D> {
     /// Identifier for which `leave` in channels and/or channel groups will be
     /// announced.
     pub(in crate::dx::presence) user_id: String,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::presence)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::presence) timeout: Option<u64>,
 }
 
+impl_from_builder_error!(LeaveRequestBuilderError);
+
 impl<T, D> LeaveRequestBuilder<T, D> {
+    /// Channels for announcement.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Channel groups for announcement.
+    pub fn channel_groups<L, S>(mut self, channel_groups: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channel_groups = Some(channel_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Validate user-provided data for request builder.
     ///
     /// Validator ensure that list of provided data is enough to build valid
@@ -97,8 +130,7 @@ impl<T, D> LeaveRequestBuilder<T, D> {
 
     /// Build [`LeaveRequest`] from builder.
     fn request(self) -> Result<LeaveRequest<T, D>, PubNubError> {
-        self.build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+        self.build().map_err(PubNubError::from)
     }
 }
 
@@ -126,7 +158,9 @@ impl<T, D> LeaveRequest<T, D> {
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             body: None,
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
         })
     }
 }
@@ -148,9 +182,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -6,7 +6,7 @@
 //! [`PubNub`]: https://www.pubnub.com
 
 #[doc(inline)]
-pub(crate) use heartbeat::HeartbeatRequestBuilder;
+pub(crate) use heartbeat::{HeartbeatRequestBuilder, HeartbeatTargetBuilder};
 pub(crate) mod heartbeat;
 
 #[doc(inline)]
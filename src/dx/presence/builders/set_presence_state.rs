@@ -66,7 +66,7 @@ pub struct SetStateRequest<T, D> {
     /// Channels with which state will be associated.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(strip_option, into),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channels: Vec<String>,
@@ -77,7 +77,7 @@ pub struct SetStateRequest<T, D> {
     /// included in the specified target channel groups.
     #[builder(
         field(vis = "pub(in crate::dx::presence)"),
-        setter(into, strip_option),
+        setter(custom),
         default = "vec![]"
     )]
     pub(in crate::dx::presence) channel_groups: Vec<String>,
@@ -117,9 +117,45 @@ pub struct SetStateRequest<T, D> {
     )]
     /// Set presence state request execution callback.
     pub(in crate::dx::presence) on_execute: SetStateExecuteCall,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::presence)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::presence) timeout: Option<u64>,
 }
 
+impl_from_builder_error!(SetStateRequestBuilderError);
+
 impl<T, D> SetStateRequestBuilder<T, D> {
+    /// Channels with which state will be associated.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Channel groups with which state will be associated.
+    ///
+    /// The specified state will be associated with channels that have been
+    /// included in the specified target channel groups.
+    pub fn channel_groups<L, S>(mut self, channel_groups: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channel_groups = Some(channel_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Validate user-provided data for request builder.
     ///
     /// Validator ensure that list of provided data is enough to build valid
@@ -143,8 +179,7 @@ impl<T, D> SetStateRequestBuilder<T, D> {
 
     /// Build [`SetStateRequest`] from builder.
     fn request(self) -> Result<SetStateRequest<T, D>, PubNubError> {
-        self.build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+        self.build().map_err(PubNubError::from)
     }
 }
 
@@ -163,7 +198,7 @@ impl<T, D> SetStateRequest<T, D> {
         if let Some(state) = self.state.as_ref() {
             let serialized_state =
                 String::from_utf8(state.clone()).map_err(|err| PubNubError::Serialization {
-                    details: err.to_string(),
+                    details: error_details!(err),
                 })?;
             query.insert("state".into(), serialized_state);
         }
@@ -180,7 +215,9 @@ impl<T, D> SetStateRequest<T, D> {
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             body: None,
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
         })
     }
 }
@@ -208,9 +245,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -225,6 +264,12 @@ where
     /// Build and call synchronous request.
     pub fn execute_blocking(self) -> Result<SetStateResult, PubNubError> {
         let request = self.request()?;
+
+        #[cfg(feature = "std")]
+        if !request.channels.is_empty() {
+            (request.on_execute)(request.channels.clone(), request.state.clone());
+        }
+
         let transport_request = request.transport_request()?;
         let client = request.pubnub_client.clone();
         let deserializer = client.deserializer.clone();
@@ -55,8 +55,21 @@ pub struct WhereNowRequest<T, D> {
     /// Identifier for which `state` should be associated for provided list of
     /// channels and groups.
     pub(in crate::dx::presence) user_id: String,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(
+        field(vis = "pub(in crate::dx::presence)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::presence) timeout: Option<u64>,
 }
 
+impl_from_builder_error!(WhereNowRequestBuilderError);
+
 impl<T, D> WhereNowRequestBuilder<T, D> {
     /// Validate user-provided data for request builder.
     ///
@@ -68,8 +81,7 @@ impl<T, D> WhereNowRequestBuilder<T, D> {
 
     /// Build [`SetStateRequest`] from builder.
     fn request(self) -> Result<WhereNowRequest<T, D>, PubNubError> {
-        self.build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+        self.build().map_err(PubNubError::from)
     }
 }
 
@@ -81,9 +93,9 @@ impl<T, D> WhereNowRequest<T, D> {
         let config = &self.pubnub_client.config;
 
         let user_id = if self.user_id.is_empty() {
-            &*self.pubnub_client.config.user_id
+            self.pubnub_client.user_id()
         } else {
-            &self.user_id
+            self.user_id.clone()
         };
 
         Ok(TransportRequest {
@@ -97,7 +109,9 @@ impl<T, D> WhereNowRequest<T, D> {
             headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
             body: None,
             #[cfg(feature = "std")]
-            timeout: config.transport.request_timeout,
+            timeout: self
+                .timeout
+                .unwrap_or_else(|| self.pubnub_client.request_timeout()),
         })
     }
 }
@@ -119,9 +133,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -3,12 +3,11 @@
 //! The module contains the [`PresenceEffectHandler`] type, which is used by
 //! event engine for
 
+use crate::lib::sync::RwLock;
 use async_channel::Sender;
-use spin::RwLock;
-use uuid::Uuid;
 
 use crate::{
-    core::{event_engine::EffectHandler, RequestRetryConfiguration},
+    core::{event_engine::EffectHandler, IdProvider, RequestRetryConfiguration},
     lib::{
         alloc::sync::Arc,
         core::fmt::{Debug, Formatter, Result},
@@ -37,10 +36,16 @@ pub(crate) struct PresenceEffectHandler {
     wait_call: Arc<WaitEffectExecutor>,
 
     /// Retry policy.
-    retry_policy: RequestRetryConfiguration,
+    ///
+    /// Shared with client, so retry policy changes will be used with next
+    /// created effects.
+    retry_policy: Arc<RwLock<RequestRetryConfiguration>>,
 
     /// Cancellation channel.
     cancellation_channel: Sender<String>,
+
+    /// Effect identifiers provider.
+    id_provider: Arc<dyn IdProvider>,
 }
 
 impl PresenceEffectHandler {
@@ -50,8 +55,9 @@ impl PresenceEffectHandler {
         delayed_heartbeat_call: Arc<HeartbeatEffectExecutor>,
         leave_call: Arc<LeaveEffectExecutor>,
         wait_call: Arc<WaitEffectExecutor>,
-        retry_policy: RequestRetryConfiguration,
+        retry_policy: Arc<RwLock<RequestRetryConfiguration>>,
         cancellation_channel: Sender<String>,
+        id_provider: Arc<dyn IdProvider>,
     ) -> Self {
         Self {
             heartbeat_call,
@@ -60,6 +66,7 @@ impl PresenceEffectHandler {
             wait_call,
             retry_policy,
             cancellation_channel,
+            id_provider,
         }
     }
 }
@@ -68,7 +75,7 @@ impl EffectHandler<PresenceEffectInvocation, PresenceEffect> for PresenceEffectH
     fn create(&self, invocation: &PresenceEffectInvocation) -> Option<PresenceEffect> {
         match invocation {
             PresenceEffectInvocation::Heartbeat { input } => Some(PresenceEffect::Heartbeat {
-                id: Uuid::new_v4().to_string(),
+                id: self.id_provider.next_id(),
                 input: input.clone(),
                 executor: self.heartbeat_call.clone(),
             }),
@@ -77,22 +84,22 @@ impl EffectHandler<PresenceEffectInvocation, PresenceEffect> for PresenceEffectH
                 attempts,
                 reason,
             } => Some(PresenceEffect::DelayedHeartbeat {
-                id: Uuid::new_v4().to_string(),
+                id: self.id_provider.next_id(),
                 cancelled: RwLock::new(false),
                 input: input.clone(),
                 attempts: *attempts,
                 reason: reason.clone(),
-                retry_policy: self.retry_policy.clone(),
+                retry_policy: self.retry_policy.read().clone(),
                 executor: self.delayed_heartbeat_call.clone(),
                 cancellation_channel: self.cancellation_channel.clone(),
             }),
             PresenceEffectInvocation::Leave { input } => Some(PresenceEffect::Leave {
-                id: Uuid::new_v4().to_string(),
+                id: self.id_provider.next_id(),
                 input: input.clone(),
                 executor: self.leave_call.clone(),
             }),
             PresenceEffectInvocation::Wait { input } => Some(PresenceEffect::Wait {
-                id: Uuid::new_v4().to_string(),
+                id: self.id_provider.next_id(),
                 cancelled: RwLock::new(false),
                 input: input.clone(),
                 executor: self.wait_call.clone(),
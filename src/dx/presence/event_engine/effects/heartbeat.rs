@@ -4,8 +4,8 @@
 //! which is used to announce `user_id` presence on specified channels and
 //! groups.
 
+use crate::lib::log::{self, info};
 use futures::TryFutureExt;
-use log::info;
 
 use crate::{
     core::{PubNubError, RequestRetryConfiguration},
@@ -77,7 +77,7 @@ mod it_should {
             assert_eq!(parameters.reason, None);
             assert_eq!(parameters.effect_id, "id");
 
-            async move { Ok(HeartbeatResult) }.boxed()
+            async move { Ok(HeartbeatResult::default()) }.boxed()
         });
 
         let result = execute(
@@ -105,7 +105,7 @@ mod it_should {
         let mocked_heartbeat_function: Arc<HeartbeatEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -122,7 +122,7 @@ mod it_should {
             ),
             0,
             Some(PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -131,6 +131,7 @@ mod it_should {
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 5,
+                max_total_retry_duration: None,
                 delay: 2,
                 excluded_endpoints: None,
             },
@@ -150,7 +151,7 @@ mod it_should {
         let mocked_heartbeat_function: Arc<HeartbeatEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -167,7 +168,7 @@ mod it_should {
             ),
             5,
             Some(PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -177,6 +178,7 @@ mod it_should {
             &RequestRetryConfiguration::Linear {
                 delay: 0,
                 max_retry: 1,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             },
             &mocked_heartbeat_function,
@@ -202,7 +204,7 @@ mod it_should {
             ),
             5,
             Some(PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -211,6 +213,7 @@ mod it_should {
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 5,
+                max_total_retry_duration: None,
                 delay: 2,
                 excluded_endpoints: None,
             },
@@ -226,7 +229,7 @@ mod it_should {
         let mocked_heartbeat_function: Arc<HeartbeatEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -243,7 +246,7 @@ mod it_should {
             ),
             5,
             Some(PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -253,6 +256,7 @@ mod it_should {
             &RequestRetryConfiguration::Linear {
                 delay: 0,
                 max_retry: 1,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             },
             &mocked_heartbeat_function,
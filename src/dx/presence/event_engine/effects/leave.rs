@@ -3,7 +3,7 @@
 //! Module contains implementation of `Leave` effect which is used to announce
 //! `user_id` `leave` from specified channels and groups.
 
-use log::info;
+use crate::lib::log::info;
 
 use crate::{
     lib::alloc::{sync::Arc, vec, vec::Vec},
@@ -57,7 +57,7 @@ mod it_should {
             assert_eq!(parameters.reason, None);
             assert_eq!(parameters.effect_id, "id");
 
-            async move { Ok(LeaveResult) }.boxed()
+            async move { Ok(LeaveResult::default()) }.boxed()
         });
 
         let result = execute(
@@ -78,7 +78,7 @@ mod it_should {
         let mocked_leave_function: Arc<LeaveEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
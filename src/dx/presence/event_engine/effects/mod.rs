@@ -1,12 +1,10 @@
 //! # Presence event engine effect module.
 
-use async_channel::Sender;
-use futures::future::BoxFuture;
-use spin::RwLock;
-
+use crate::lib::sync::RwLock;
 use crate::{
     core::{
-        event_engine::{Effect, EffectInvocation},
+        event_engine::{cancel::send_cancellation, Effect, EffectInvocation},
+        maybe_send::BoxFuture,
         PubNubError, RequestRetryConfiguration,
     },
     lib::{
@@ -18,6 +16,7 @@ use crate::{
         HeartbeatResult, LeaveResult,
     },
 };
+use async_channel::Sender;
 
 mod heartbeat;
 mod leave;
@@ -179,7 +178,6 @@ impl Debug for PresenceEffect {
     }
 }
 
-#[async_trait::async_trait]
 impl Effect for PresenceEffect {
     type Invocation = PresenceEffectInvocation;
 
@@ -248,7 +246,7 @@ impl Effect for PresenceEffect {
         }
     }
 
-    fn cancel(&self) {
+    fn cancel(&self) -> Option<BoxFuture<'static, ()>> {
         match self {
             PresenceEffect::DelayedHeartbeat {
                 id,
@@ -266,11 +264,9 @@ impl Effect for PresenceEffect {
                     let mut cancelled_slot = cancelled.write();
                     *cancelled_slot = true;
                 }
-                cancellation_channel
-                    .send_blocking(id.clone())
-                    .expect("Cancellation pipe is broken!");
+                send_cancellation(cancellation_channel, id.clone())
             }
-            _ => { /* cannot cancel other effects */ }
+            _ => None, /* cannot cancel other effects */
         }
     }
 
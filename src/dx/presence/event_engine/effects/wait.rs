@@ -5,8 +5,8 @@ use crate::{
     presence::event_engine::{effects::WaitEffectExecutor, PresenceEvent},
 };
 
+use crate::lib::log::info;
 use futures::TryFutureExt;
-use log::info;
 
 pub(super) async fn execute(
     effect_id: &str,
@@ -5,8 +5,12 @@
 
 use crate::core::{event_engine::Event, PubNubError};
 
+/// Presence events.
+///
+/// Presence state machine behaviour depends from external events which it
+/// receives.
 #[derive(Debug)]
-pub(crate) enum PresenceEvent {
+pub enum PresenceEvent {
     /// Announce join to channels and groups.
     ///
     /// Announce `user_id` presence on new channels and groups.
@@ -71,14 +75,20 @@ pub(crate) enum PresenceEvent {
     /// response from [`PubNub`] network (network or permission issues).
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    HeartbeatFailure { reason: PubNubError },
+    HeartbeatFailure {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// All heartbeat attempts was unsuccessful.
     ///
     /// Emitted when heartbeat attempts reached maximum allowed count (according
     /// to retry / reconnection policy) and all following attempts should be
     /// stopped.
-    HeartbeatGiveUp { reason: PubNubError },
+    HeartbeatGiveUp {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// Restore heartbeating.
     ///
@@ -409,10 +409,11 @@ impl State for PresenceState {
 #[cfg(test)]
 mod it_should {
     use super::*;
+    use crate::lib::sync::RwLock;
     use crate::presence::event_engine::effects::LeaveEffectExecutor;
     use crate::presence::LeaveResult;
     use crate::{
-        core::{event_engine::EventEngine, RequestRetryConfiguration},
+        core::{event_engine::EventEngine, RequestRetryConfiguration, UuidProvider},
         lib::alloc::sync::Arc,
         presence::{
             event_engine::{
@@ -428,11 +429,11 @@ mod it_should {
 
     fn event_engine(start_state: PresenceState) -> Arc<PresenceEventEngine> {
         let heartbeat_call: Arc<HeartbeatEffectExecutor> =
-            Arc::new(|_| async move { Ok(HeartbeatResult) }.boxed());
+            Arc::new(|_| async move { Ok(HeartbeatResult::default()) }.boxed());
         let delayed_heartbeat_call: Arc<HeartbeatEffectExecutor> =
-            Arc::new(|_| async move { Ok(HeartbeatResult) }.boxed());
+            Arc::new(|_| async move { Ok(HeartbeatResult::default()) }.boxed());
         let leave_call: Arc<LeaveEffectExecutor> =
-            Arc::new(|_| async move { Ok(LeaveResult) }.boxed());
+            Arc::new(|_| async move { Ok(LeaveResult::default()) }.boxed());
         let wait_call: Arc<WaitEffectExecutor> = Arc::new(|_| async move { Ok(()) }.boxed());
 
         let (tx, _) = async_channel::bounded(1);
@@ -443,8 +444,9 @@ mod it_should {
                 delayed_heartbeat_call,
                 leave_call,
                 wait_call,
-                RequestRetryConfiguration::None,
+                Arc::new(RwLock::new(RequestRetryConfiguration::None)),
                 tx,
+                Arc::new(UuidProvider),
             ),
             start_state,
             RuntimeTokio,
@@ -469,7 +471,7 @@ mod it_should {
     #[test_case(
         PresenceState::Inactive,
         PresenceEvent::HeartbeatFailure {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: error_details!("Test"), response: None }
         },
         PresenceState::Inactive;
         "to not change on unexpected event"
@@ -579,7 +581,7 @@ mod it_should {
             )
         },
         PresenceEvent::HeartbeatFailure {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: error_details!("Test"), response: None }
         },
         PresenceState::Reconnecting {
             input: PresenceInput::new(
@@ -587,7 +589,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: error_details!("Test"), response: None }
         };
         "to reconnect on heartbeat failure"
     )]
@@ -668,7 +670,7 @@ mod it_should {
             )
         },
         PresenceEvent::HeartbeatGiveUp {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: error_details!("Test"), response: None }
         },
         PresenceState::Heartbeating {
             input: PresenceInput::new(
@@ -842,7 +844,7 @@ mod it_should {
             )
         },
         PresenceEvent::HeartbeatGiveUp {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: error_details!("Test"), response: None }
         },
         PresenceState::Cooldown {
             input: PresenceInput::new(
@@ -875,10 +877,10 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::HeartbeatFailure {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         },
         PresenceState::Reconnecting {
             input: PresenceInput::new(
@@ -886,7 +888,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         };
         "to heartbeat reconnecting on heartbeat failure"
     )]
@@ -897,7 +899,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -919,7 +921,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -941,7 +943,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -958,7 +960,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::HeartbeatSuccess,
         PresenceState::Cooldown {
@@ -976,17 +978,17 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::HeartbeatGiveUp {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         },
         PresenceState::Failed {
             input: PresenceInput::new(
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         };
         "to failed on heartbeat give up"
     )]
@@ -997,7 +999,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Disconnect,
         PresenceState::Stopped {
@@ -1015,7 +1017,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::LeftAll {
             suppress_leave_events: false,
@@ -1030,7 +1032,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -1043,7 +1045,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on joined with same channels and groups"
     )]
@@ -1054,7 +1056,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1067,7 +1069,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on left with unknown channels and groups"
     )]
@@ -1078,7 +1080,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Reconnect,
         PresenceState::Reconnecting {
@@ -1087,7 +1089,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on unexpected event"
     )]
@@ -1269,7 +1271,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -1290,7 +1292,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string(), "ch2".to_string()]), 
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1311,7 +1313,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string(), "ch2".to_string()]), 
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1327,7 +1329,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Reconnect,
         PresenceState::Heartbeating {
@@ -1344,7 +1346,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Disconnect,
         PresenceState::Stopped {
@@ -1361,7 +1363,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::LeftAll {
             suppress_leave_events: false,
@@ -1375,7 +1377,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -1387,7 +1389,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on joined with same channels and groups"
     )]
@@ -1397,7 +1399,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1409,7 +1411,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on left with unknown channels and groups"
     )]
@@ -1419,7 +1421,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         PresenceEvent::HeartbeatSuccess,
         PresenceState::Failed {
@@ -1427,7 +1429,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on unexpected event"
     )]
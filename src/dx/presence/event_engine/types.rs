@@ -5,9 +5,13 @@
 //! managed.
 
 use crate::{
-    core::PubNubError,
+    core::{ChannelName, PubNubError},
     lib::{
-        alloc::{collections::HashSet, string::String, vec::Vec},
+        alloc::{
+            collections::HashSet,
+            string::{String, ToString},
+            vec::Vec,
+        },
         core::ops::{Add, Sub},
     },
 };
@@ -21,12 +25,12 @@ pub struct PresenceInput {
     /// Optional list of channels.
     ///
     /// List of channels for which `user_id` presence should be managed.
-    pub channels: Option<HashSet<String>>,
+    pub channels: Option<HashSet<ChannelName>>,
 
     /// Optional list of channel groups.
     ///
     /// List of channel groups for which `user_id` presence should be managed.
-    pub channel_groups: Option<HashSet<String>>,
+    pub channel_groups: Option<HashSet<ChannelName>>,
 
     /// Whether user input is empty or not.
     pub is_empty: bool,
@@ -35,17 +39,14 @@ pub struct PresenceInput {
 impl PresenceInput {
     pub fn new(channels: &Option<Vec<String>>, channel_groups: &Option<Vec<String>>) -> Self {
         let channels = channels.as_ref().map(|channels| {
-            channels.iter().fold(HashSet::new(), |mut acc, channel| {
-                acc.insert(channel.clone());
-                acc
-            })
-        });
-        let channel_groups = channel_groups.as_ref().map(|groups| {
-            groups.iter().fold(HashSet::new(), |mut acc, group| {
-                acc.insert(group.clone());
-                acc
-            })
+            channels
+                .iter()
+                .map(ChannelName::from)
+                .collect::<HashSet<_>>()
         });
+        let channel_groups = channel_groups
+            .as_ref()
+            .map(|groups| groups.iter().map(ChannelName::from).collect::<HashSet<_>>());
 
         let channel_groups_is_empty = channel_groups.as_ref().map_or(true, |set| set.is_empty());
         let channels_is_empty = channels.as_ref().map_or(true, |set| set.is_empty());
@@ -58,20 +59,22 @@ impl PresenceInput {
     }
 
     pub fn channels(&self) -> Option<Vec<String>> {
-        self.channels.clone().map(|ch| ch.into_iter().collect())
+        self.channels
+            .as_ref()
+            .map(|ch| ch.iter().map(ToString::to_string).collect())
     }
 
     pub fn channel_groups(&self) -> Option<Vec<String>> {
         self.channel_groups
-            .clone()
-            .map(|ch| ch.into_iter().collect())
+            .as_ref()
+            .map(|ch| ch.iter().map(ToString::to_string).collect())
     }
 
     fn join_sets(
         &self,
-        lhs: &Option<HashSet<String>>,
-        rhs: &Option<HashSet<String>>,
-    ) -> Option<HashSet<String>> {
+        lhs: &Option<HashSet<ChannelName>>,
+        rhs: &Option<HashSet<ChannelName>>,
+    ) -> Option<HashSet<ChannelName>> {
         match (lhs, rhs) {
             (Some(lhs), Some(rhs)) => Some(lhs.iter().cloned().chain(rhs.to_owned()).collect()),
             (Some(lhs), None) => Some(lhs.to_owned()),
@@ -82,9 +85,9 @@ impl PresenceInput {
 
     fn sub_sets(
         &self,
-        lhs: &Option<HashSet<String>>,
-        rhs: &Option<HashSet<String>>,
-    ) -> Option<HashSet<String>> {
+        lhs: &Option<HashSet<ChannelName>>,
+        rhs: &Option<HashSet<ChannelName>>,
+    ) -> Option<HashSet<ChannelName>> {
         match (lhs.to_owned(), rhs.to_owned()) {
             (Some(lhs), Some(rhs)) => Some(&lhs - &rhs).filter(|diff| !diff.is_empty()),
             (Some(lhs), None) => Some(lhs),
@@ -6,12 +6,12 @@
 
 #[cfg(feature = "std")]
 use futures::{
-    future::{ready, BoxFuture},
+    future::ready,
     {select_biased, FutureExt},
 };
 
 #[cfg(feature = "std")]
-use spin::RwLock;
+use crate::lib::sync::RwLock;
 
 #[doc(inline)]
 pub use builders::*;
@@ -39,6 +39,8 @@ pub(crate) mod event_engine;
 use crate::{
     core::{
         event_engine::{cancel::CancellationTask, EventEngine},
+        maybe_send::{BoxFuture, MaybeSendFutureExt},
+        retry_policy::Endpoint,
         Deserializer, PubNubError, Runtime, Transport,
     },
     lib::alloc::sync::Arc,
@@ -48,10 +50,7 @@ use crate::{
     core::Serialize,
     dx::pubnub_client::PubNubClientInstance,
     lib::{
-        alloc::{
-            string::{String, ToString},
-            vec::Vec,
-        },
+        alloc::{string::String, vec::Vec},
         collections::HashMap,
     },
 };
@@ -62,7 +61,7 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// This method is used to announce the presence of `user_id` on the
     /// provided list of channels and/or groups.
     ///
-    /// Instance of [`HeartbeatRequestsBuilder`] returned.
+    /// Instance of [`HeartbeatTargetBuilder`] returned.
     ///
     /// # Example
     /// ```rust
@@ -83,8 +82,8 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// #         .build()?;
     /// pubnub
     ///     .heartbeat()
-    ///     .channels(["lobby".into(), "announce".into()])
-    ///     .channel_groups(["area-51".into()])
+    ///     .channels(["lobby", "announce"])
+    ///     .channel_groups(["area-51"])
     ///     .state(HashMap::<String, Vec<u8>>::from(
     ///         [(
     ///             String::from("lobby"),
@@ -96,11 +95,18 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn heartbeat(&self) -> HeartbeatRequestBuilder<T, D> {
+    pub fn heartbeat(&self) -> HeartbeatTargetBuilder<T, D> {
+        HeartbeatTargetBuilder {
+            builder: self.heartbeat_builder(),
+        }
+    }
+
+    /// Create a heartbeat request builder without announcement target.
+    pub(crate) fn heartbeat_builder(&self) -> HeartbeatRequestBuilder<T, D> {
         HeartbeatRequestBuilder {
             pubnub_client: Some(self.clone()),
             heartbeat: Some(self.config.presence.heartbeat_value),
-            user_id: Some(self.config.user_id.clone().to_string()),
+            user_id: Some(self.user_id()),
             ..Default::default()
         }
     }
@@ -131,8 +137,8 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// #             .build()?;
     /// pubnub
     ///     .leave()
-    ///     .channels(["lobby".into(), "announce".into()])
-    ///     .channel_groups(["area-51".into()])
+    ///     .channels(["lobby", "announce"])
+    ///     .channel_groups(["area-51"])
     ///     .execute()
     ///     .await?;
     /// # Ok(())
@@ -141,7 +147,7 @@ impl<T, D> PubNubClientInstance<T, D> {
     pub fn leave(&self) -> LeaveRequestBuilder<T, D> {
         LeaveRequestBuilder {
             pubnub_client: Some(self.clone()),
-            user_id: Some(self.config.user_id.clone().to_string()),
+            user_id: Some(self.user_id()),
             ..Default::default()
         }
     }
@@ -174,8 +180,8 @@ impl<T, D> PubNubClientInstance<T, D> {
     ///     .set_presence_state(HashMap::<String, bool>::from(
     ///          [(String::from("is_admin"), false)]
     ///      ))
-    ///     .channels(["lobby".into(), "announce".into()])
-    ///     .channel_groups(["area-51".into()])
+    ///     .channels(["lobby", "announce"])
+    ///     .channel_groups(["area-51"])
     ///     .execute()
     ///     .await?;
     /// # Ok(())
@@ -194,7 +200,7 @@ impl<T, D> PubNubClientInstance<T, D> {
         SetStateRequestBuilder {
             pubnub_client: Some(self.clone()),
             state: Some(serde_json::to_vec(&state).ok()),
-            user_id: Some(self.config.user_id.clone().to_string()),
+            user_id: Some(self.user_id()),
 
             #[cfg(feature = "std")]
             on_execute: Some(Arc::new(move |channels, state| {
@@ -242,8 +248,8 @@ impl<T, D> PubNubClientInstance<T, D> {
     ///     .set_presence_state(HashMap::<String, bool>::from(
     ///          [(String::from("is_admin"), false)]
     ///      ))
-    ///     .channels(["lobby".into(), "announce".into()])
-    ///     .channel_groups(["area-51".into()])
+    ///     .channels(["lobby", "announce"])
+    ///     .channel_groups(["area-51"])
     ///     .execute()
     ///     .await?;
     /// # Ok(())
@@ -262,7 +268,7 @@ impl<T, D> PubNubClientInstance<T, D> {
         SetStateRequestBuilder {
             pubnub_client: Some(self.clone()),
             state: Some(state.serialize().ok()),
-            user_id: Some(self.config.user_id.clone().to_string()),
+            user_id: Some(self.user_id()),
 
             #[cfg(feature = "std")]
             on_execute: Some(Arc::new(move |channels, state| {
@@ -288,7 +294,7 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// channels using `heartbeat` operation endpoint. State with heartbeat can
     /// be set **only** for channels.
     ///
-    /// Instance of [`HeartbeatRequestsBuilder`] returned.
+    /// Instance of [`HeartbeatTargetBuilder`] returned.
     ///
     /// # Example
     /// ```rust
@@ -312,8 +318,8 @@ impl<T, D> PubNubClientInstance<T, D> {
     ///          ("lobby".to_string(), HashMap::from([("key".to_string(), "value".to_string())])),
     ///          ("announce".to_string(), HashMap::from([("key".to_string(), "value".to_string())])),
     ///     ]))
-    ///     .channels(["lobby".into(), "announce".into()])
-    ///     .channel_groups(["area-51".into()])
+    ///     .channels(["lobby", "announce"])
+    ///     .channel_groups(["area-51"])
     ///     .execute()
     ///     .await?;
     /// # Ok(())
@@ -322,7 +328,7 @@ impl<T, D> PubNubClientInstance<T, D> {
     pub fn set_presence_state_with_heartbeat<S>(
         &self,
         state: HashMap<String, S>,
-    ) -> HeartbeatRequestBuilder<T, D>
+    ) -> HeartbeatTargetBuilder<T, D>
     where
         S: Serialize,
     {
@@ -364,8 +370,8 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// #             .build()?;
     /// pubnub
     ///     .get_presence_state()
-    ///     .channels(["lobby".into(), "announce".into()])
-    ///     .channel_groups(["area-51".into()])
+    ///     .channels(["lobby", "announce"])
+    ///     .channel_groups(["area-51"])
     ///     .execute()
     ///     .await?;
     /// # Ok(())
@@ -374,7 +380,7 @@ impl<T, D> PubNubClientInstance<T, D> {
     pub fn get_presence_state(&self) -> GetStateRequestBuilder<T, D> {
         GetStateRequestBuilder {
             pubnub_client: Some(self.clone()),
-            user_id: Some(self.config.user_id.clone().to_string()),
+            user_id: Some(self.user_id()),
             ..Default::default()
         }
     }
@@ -404,7 +410,7 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// #             .with_user_id("uuid")
     /// #             .build()?;
     /// let response = pubnub.here_now()
-    ///         .channels(["lobby".into()])
+    ///         .channels(["lobby"])
     ///         .include_state(true)
     ///         .include_user_id(true)
     ///         .execute()
@@ -554,7 +560,7 @@ where
     /// Prepare presence event engine instance which will be used for `user_id`
     /// presence announcement and management.
     fn presence_event_engine(&self) -> Arc<PresenceEventEngine> {
-        let channel_bound = 3;
+        let channel_bound = self.config.event_engine.presence_cancel_capacity.max(1);
         let (cancel_tx, cancel_rx) = async_channel::bounded::<String>(channel_bound);
         let delayed_heartbeat_cancel_rx = cancel_rx.clone();
         let wait_cancel_rx = cancel_rx.clone();
@@ -563,22 +569,33 @@ where
         let heartbeat_call_client = self.clone();
         let leave_call_client = self.clone();
         let wait_call_client = self.clone();
-        let request_retry = self.config.transport.retry_configuration.clone();
+        let request_retry = self.inner.retry_configuration.clone();
         let request_delayed_retry = request_retry.clone();
+        let retry_notifier = self.retry_notifier.clone();
         let delayed_heartbeat_runtime_sleep = runtime.clone();
         let wait_runtime_sleep = runtime.clone();
 
-        EventEngine::new(
+        EventEngine::with_capacity(
             PresenceEffectHandler::new(
                 Arc::new(move |parameters| {
                     Self::heartbeat_call(heartbeat_call_client.clone(), parameters.clone())
                 }),
                 Arc::new(move |parameters| {
-                    let delay_in_microseconds = request_delayed_retry.retry_delay(
+                    let delay_in_microseconds = request_delayed_retry.read().retry_delay(
                         Some("/v2/presence".to_string()),
                         &parameters.attempt,
                         parameters.reason.as_ref(),
                     );
+                    if let Some((delay, reason)) =
+                        delay_in_microseconds.zip(parameters.reason.as_ref())
+                    {
+                        retry_notifier.notify(
+                            Endpoint::Presence,
+                            parameters.attempt,
+                            delay,
+                            reason,
+                        );
+                    }
                     let inner_runtime_sleep = delayed_heartbeat_runtime_sleep.clone();
 
                     Self::delayed_heartbeat_call(
@@ -589,9 +606,9 @@ where
                                 inner_runtime_sleep
                                     .clone()
                                     .sleep_microseconds(delay)
-                                    .boxed()
+                                    .boxed_maybe_send()
                             } else {
-                                ready(()).boxed()
+                                ready(()).boxed_maybe_send()
                             }
                         }),
                         delayed_heartbeat_cancel_rx.clone(),
@@ -608,9 +625,9 @@ where
                         effect_id,
                         Arc::new(move || {
                             if let Some(delay) = delay_in_secs {
-                                inner_runtime_sleep.clone().sleep(delay).boxed()
+                                inner_runtime_sleep.clone().sleep(delay).boxed_maybe_send()
                             } else {
-                                ready(()).boxed()
+                                ready(()).boxed_maybe_send()
                             }
                         }),
                         wait_cancel_rx.clone(),
@@ -618,9 +635,11 @@ where
                 }),
                 request_retry,
                 cancel_tx,
+                self.id_provider.clone(),
             ),
             PresenceState::Inactive,
             runtime,
+            self.config.event_engine.invocations_capacity,
         )
     }
 
@@ -635,7 +654,7 @@ where
             request = request.state(state.clone());
         }
 
-        request.execute().boxed()
+        request.execute().boxed_maybe_send()
     }
 
     /// Call delayed announce of `user_id` presence.
@@ -654,7 +673,7 @@ where
         client
             .heartbeat_request(params)
             .execute_with_cancel_and_delay(delay, cancel_task)
-            .boxed()
+            .boxed_maybe_send()
     }
 
     /// Call announce `leave` for `user_id`.
@@ -663,7 +682,7 @@ where
         params: PresenceParameters,
     ) -> BoxFuture<'static, Result<LeaveResult, PubNubError>> {
         if client.config.presence.suppress_leave_events {
-            return ready(Ok(LeaveResult)).boxed();
+            return ready(Ok(LeaveResult::default())).boxed_maybe_send();
         }
 
         let mut request = client.leave();
@@ -676,7 +695,7 @@ where
             request = request.channel_groups(channel_groups);
         }
 
-        request.execute().boxed()
+        request.execute().boxed_maybe_send()
     }
 
     /// Heartbeat idle.
@@ -698,14 +717,14 @@ where
                 _ = delay().fuse() => Ok(())
             }
         }
-        .boxed()
+        .boxed_maybe_send()
     }
 
     pub(crate) fn heartbeat_request(
         &self,
         params: PresenceParameters,
     ) -> HeartbeatRequestBuilder<T, D> {
-        let mut request = self.heartbeat();
+        let mut request = self.heartbeat_builder();
 
         if let Some(channels) = params.channels.clone() {
             request = request.channels(channels);
@@ -726,7 +745,7 @@ where
 #[cfg(test)]
 mod it_should {
     use super::*;
-    use crate::core::{PubNubError, Transport, TransportRequest, TransportResponse};
+    use crate::core::{Bytes, PubNubError, Transport, TransportRequest, TransportResponse};
     use crate::providers::deserialization_serde::DeserializerSerde;
     use crate::transport::middleware::PubNubMiddleware;
     use crate::{
@@ -749,7 +768,6 @@ mod it_should {
         request_handler: Option<RequestHandler>,
     }
 
-    #[async_trait::async_trait]
     impl Transport for MockTransport {
         async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
             // Calling request handler (if provided).
@@ -761,11 +779,23 @@ mod it_should {
         }
     }
 
+    #[cfg(feature = "blocking")]
+    impl crate::core::blocking::Transport for MockTransport {
+        fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            // Calling request handler (if provided).
+            if let Some(handler) = &self.request_handler {
+                handler(&req);
+            }
+
+            Ok(self.response.clone().unwrap_or(transport_response(200)))
+        }
+    }
+
     /// Service response payload.
     fn transport_response(status: u16) -> TransportResponse {
         TransportResponse {
             status,
-            body: Some(Vec::from(if status < 400 {
+            body: Some(Bytes::from(if status < 400 {
                 "{\"status\": 200, \"message\": \"OK\", \"service\": \"Presence\"}"
             } else {
                 "\"error\":{{\"message\":\"Overall error\",\"source\":\"test\",\"details\":[{{\"message\":\"Error\",\"location\":\"signature\",\"locationType\":\"query\"}}]}}"
@@ -796,12 +826,36 @@ mod it_should {
     #[test]
     fn not_heartbeat_when_subscribe_key_missing() {
         let client = client(false, None);
-        let request = client.heartbeat().channels(["test".into()]).build();
+        let request = client.heartbeat().channels(["test"]).build();
 
         assert!(&client.config.subscribe_key.is_empty());
         assert!(request.is_err())
     }
 
+    #[test]
+    fn accept_different_list_types_for_channels() {
+        let client = client(true, None);
+        let owned = vec![String::from("channel_b")];
+
+        let request = client
+            .here_now()
+            .channels(["channel_a"])
+            .channel_groups(&owned)
+            .build()
+            .unwrap();
+        assert_eq!(request.channels, vec!["channel_a"]);
+        assert_eq!(request.channel_groups, vec!["channel_b"]);
+
+        let request = client
+            .here_now()
+            .channels(owned)
+            .channel_groups(vec!["group_a", "group_b"])
+            .build()
+            .unwrap();
+        assert_eq!(request.channels, vec!["channel_b"]);
+        assert_eq!(request.channel_groups, vec!["group_a", "group_b"]);
+    }
+
     #[tokio::test]
     async fn send_heartbeat() {
         let client = PubNubClientBuilder::with_reqwest_transport()
@@ -823,7 +877,7 @@ mod it_should {
                     .ok()
                     .unwrap(),
             )]))
-            .channels(["hello".into()])
+            .channels(["hello"])
             .user_id("my_user")
             .execute()
             .await;
@@ -834,6 +888,29 @@ mod it_should {
         }
     }
 
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn set_channel_name_for_single_channel_here_now_blocking() {
+        let transport = MockTransport {
+            response: Some(TransportResponse {
+                status: 200,
+                body: Some(Bytes::from(
+                    "{\"status\": 200, \"message\": \"OK\", \"occupancy\": 1, \"uuids\": [\"user\"], \"service\": \"Presence\"}",
+                )),
+                ..Default::default()
+            }),
+            request_handler: None,
+        };
+
+        let result = client(true, Some(transport))
+            .here_now()
+            .channels(["lobby"])
+            .execute_blocking()
+            .unwrap();
+
+        assert_eq!(result.first().unwrap().name, "lobby");
+    }
+
     #[tokio::test]
     async fn include_state_in_query() {
         let transport = MockTransport {
@@ -872,7 +949,7 @@ mod it_should {
                     .unwrap(),
                 ),
             ]))
-            .channels(["channel_a".into(), "channel_b".into(), "channel_c".into()])
+            .channels(["channel_a", "channel_b", "channel_c"])
             .execute()
             .await;
     }
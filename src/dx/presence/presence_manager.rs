@@ -5,7 +5,10 @@
 
 use crate::presence::event_engine::PresenceEffectInvocation;
 use crate::{
-    dx::presence::event_engine::{PresenceEvent, PresenceEventEngine},
+    dx::{
+        health::EventEngineStatus,
+        presence::event_engine::{PresenceEvent, PresenceEventEngine, PresenceState},
+    },
     lib::{
         alloc::{string::String, sync::Arc, vec::Vec},
         core::{
@@ -47,6 +50,32 @@ impl PresenceManager {
         self.event_engine
             .stop(PresenceEffectInvocation::TerminateEventEngine);
     }
+
+    /// Whether presence event engine stopped and all in-flight effects
+    /// finished or not.
+    pub fn is_terminated(&self) -> bool {
+        self.event_engine.is_stopped()
+    }
+
+    /// Current presence event engine status.
+    pub(crate) fn status(&self) -> EventEngineStatus {
+        match self.event_engine.current_state() {
+            PresenceState::Inactive => EventEngineStatus::Idle,
+            PresenceState::Heartbeating { .. } | PresenceState::Cooldown { .. } => {
+                EventEngineStatus::Connected
+            }
+            PresenceState::Reconnecting { .. } => EventEngineStatus::Reconnecting,
+            PresenceState::Stopped { .. } => EventEngineStatus::Stopped,
+            PresenceState::Failed { .. } => EventEngineStatus::Failed,
+        }
+    }
+
+    /// Number of effect invocations waiting for dispatch by presence event
+    /// engine.
+    #[cfg(feature = "metrics")]
+    pub fn queue_depth(&self) -> usize {
+        self.event_engine.queue_depth()
+    }
 }
 
 impl Deref for PresenceManager {
@@ -8,7 +8,7 @@ use crate::{
             APIErrorBody, APISuccessBodyWithFlattenedPayload, APISuccessBodyWithMessage,
             APISuccessBodyWithPayload,
         },
-        PubNubError,
+        PubNubError, ResponseMetadata,
     },
     lib::{
         alloc::{string::String, vec, vec::Vec},
@@ -18,8 +18,13 @@ use crate::{
 };
 
 /// The result of a heartbeat announcement operation.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct HeartbeatResult;
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HeartbeatResult {
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(HeartbeatResult);
 
 /// Presence service response body for heartbeat.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
@@ -72,15 +77,20 @@ impl TryFrom<HeartbeatResponseBody> for HeartbeatResult {
 
     fn try_from(value: HeartbeatResponseBody) -> Result<Self, Self::Error> {
         match value {
-            HeartbeatResponseBody::SuccessResponse(_) => Ok(HeartbeatResult),
+            HeartbeatResponseBody::SuccessResponse(_) => Ok(HeartbeatResult::default()),
             HeartbeatResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
     }
 }
 
 /// The result of a leave announcement operation.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct LeaveResult;
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LeaveResult {
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(LeaveResult);
 
 /// Presence service response body for leave.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
@@ -133,7 +143,7 @@ impl TryFrom<LeaveResponseBody> for LeaveResult {
 
     fn try_from(value: LeaveResponseBody) -> Result<Self, Self::Error> {
         match value {
-            LeaveResponseBody::SuccessResponse(_) => Ok(LeaveResult),
+            LeaveResponseBody::SuccessResponse(_) => Ok(LeaveResult::default()),
             LeaveResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
     }
@@ -151,8 +161,13 @@ pub struct SetStateResult {
     /// group(s).
     #[cfg(not(feature = "serde"))]
     state: Vec<u8>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
 }
 
+impl_pubnub_response!(SetStateResult);
+
 /// Set state service response body for heartbeat.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -235,6 +250,7 @@ impl TryFrom<SetStateResponseBody> for SetStateResult {
         match value {
             SetStateResponseBody::SuccessResponse(response) => Ok(SetStateResult {
                 state: response.payload,
+                metadata: Default::default(),
             }),
             SetStateResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
@@ -247,8 +263,13 @@ pub struct GetStateResult {
     /// State which has been associated for `user_id` with channel(s) or channel
     /// group(s).
     pub states: Vec<GetStateInfo>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
 }
 
+impl_pubnub_response!(GetStateResult);
+
 /// Get state info for a user.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GetStateInfo {
@@ -364,6 +385,7 @@ impl TryFrom<GetStateResponseBody> for GetStateResult {
                         state: v,
                     })
                     .collect(),
+                metadata: Default::default(),
             }),
             GetStateResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
@@ -387,8 +409,13 @@ pub struct HereNowResult {
     pub total_channels: u32,
     /// Amount of all users in all provided channels.
     pub total_occupancy: u32,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
 }
 
+impl_pubnub_response!(HereNowResult);
+
 /// The here now channel data.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HereNowChannel {
@@ -697,6 +724,7 @@ impl TryFrom<HereNowResponseBody> for HereNowResult {
                         channels,
                         total_channels: 1,
                         total_occupancy: occupancy,
+                        metadata: Default::default(),
                     }
                 }
                 HereNowResponseSuccessBody::MultipleChannels(multiple) => {
@@ -752,6 +780,7 @@ impl TryFrom<HereNowResponseBody> for HereNowResult {
                         channels,
                         total_channels,
                         total_occupancy,
+                        metadata: Default::default(),
                     }
                 }
             }),
@@ -773,8 +802,13 @@ impl Deref for HereNowResult {
 pub struct WhereNowResult {
     /// Here now channels.
     pub channels: Vec<String>,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
 }
 
+impl_pubnub_response!(WhereNowResult);
+
 /// Where now service response body for where now.
 /// This is a success response body for a where now  operation in The
 /// Presence service.
@@ -852,6 +886,7 @@ impl TryFrom<WhereNowResponseBody> for WhereNowResult {
         match value {
             WhereNowResponseBody::SuccessResponse(resp) => Ok(Self {
                 channels: resp.payload.channels,
+                metadata: Default::default(),
             }),
             WhereNowResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
@@ -881,7 +916,7 @@ mod it_should {
         });
         let result: Result<HeartbeatResult, PubNubError> = body.try_into();
 
-        assert_eq!(result.unwrap(), HeartbeatResult);
+        assert_eq!(result.unwrap(), HeartbeatResult::default());
     }
 
     #[test]
@@ -906,7 +941,7 @@ mod it_should {
         });
         let result: Result<LeaveResult, PubNubError> = body.try_into();
 
-        assert_eq!(result.unwrap(), LeaveResult);
+        assert_eq!(result.unwrap(), LeaveResult::default());
     }
 
     #[test]
@@ -942,7 +977,8 @@ mod it_should {
         assert_eq!(
             result.unwrap(),
             SetStateResult {
-                state: payload_value
+                state: payload_value,
+                metadata: Default::default(),
             }
         );
     }
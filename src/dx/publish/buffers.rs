@@ -0,0 +1,110 @@
+//! # Publish buffers module
+//!
+//! This module contains reusable buffers which are used to serialize published
+//! messages and build request path.
+//!
+//! With `std` feature buffers are allocated once per thread and reused by
+//! subsequent publish calls from the same thread, so high-frequency publishers
+//! don't pay for intermediate allocations on each message.
+
+use crate::lib::alloc::{string::String, vec::Vec};
+
+/// Initial capacity of the message serialization buffer.
+const MESSAGE_BUFFER_CAPACITY: usize = 1024;
+
+/// Initial capacity of the request path buffer.
+const PATH_BUFFER_CAPACITY: usize = 2048;
+
+/// Maximum capacity which buffer can retain between publish calls.
+///
+/// Buffers which grew larger (for example, to publish single large message)
+/// are shrunk to not hold memory for the thread lifetime.
+#[cfg(feature = "std")]
+const MAX_RETAINED_CAPACITY: usize = 32 * 1024;
+
+/// Reusable publish buffers.
+#[derive(Debug)]
+pub(crate) struct PublishBuffers {
+    /// Serialized message buffer.
+    pub message: Vec<u8>,
+
+    /// Request path buffer.
+    pub path: String,
+}
+
+impl PublishBuffers {
+    fn new() -> Self {
+        Self {
+            message: Vec::with_capacity(MESSAGE_BUFFER_CAPACITY),
+            path: String::with_capacity(PATH_BUFFER_CAPACITY),
+        }
+    }
+
+    /// Reset buffers for next publish call.
+    #[cfg(feature = "std")]
+    fn reset(&mut self) {
+        self.message.clear();
+        self.path.clear();
+        self.message.shrink_to(MAX_RETAINED_CAPACITY);
+        self.path.shrink_to(MAX_RETAINED_CAPACITY);
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static BUFFERS: core::cell::RefCell<PublishBuffers> =
+        core::cell::RefCell::new(PublishBuffers::new());
+}
+
+/// Call `f` with empty publish buffers.
+///
+/// Buffers of the current thread are used when available. Nested calls (and
+/// builds without `std` feature) receive newly allocated buffers.
+pub(crate) fn with_publish_buffers<F, R>(f: F) -> R
+where
+    F: FnOnce(&mut PublishBuffers) -> R,
+{
+    #[cfg(feature = "std")]
+    return BUFFERS.with(|buffers| match buffers.try_borrow_mut() {
+        Ok(mut buffers) => {
+            buffers.reset();
+            f(&mut buffers)
+        }
+        Err(_) => f(&mut PublishBuffers::new()),
+    });
+
+    #[cfg(not(feature = "std"))]
+    f(&mut PublishBuffers::new())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod should {
+    use super::*;
+
+    #[test]
+    fn reuse_buffers_between_calls() {
+        let address = with_publish_buffers(|buffers| {
+            buffers.message.extend_from_slice(b"\"message\"");
+            buffers.path.push_str("/publish");
+            buffers.message.as_ptr()
+        });
+
+        with_publish_buffers(|buffers| {
+            assert!(buffers.message.is_empty());
+            assert!(buffers.path.is_empty());
+            assert_eq!(buffers.message.as_ptr(), address);
+        });
+    }
+
+    #[test]
+    fn use_new_buffers_for_nested_calls() {
+        with_publish_buffers(|outer| {
+            outer.path.push_str("/publish");
+
+            with_publish_buffers(|inner| {
+                assert!(inner.path.is_empty());
+                assert_ne!(inner.message.as_ptr(), outer.message.as_ptr());
+            });
+        });
+    }
+}
@@ -5,7 +5,7 @@
 use derive_builder::Builder;
 
 use crate::{
-    core::Serialize,
+    core::{Deserializer, PubNubError, Serialize},
     dx::pubnub_client::PubNubClientInstance,
     lib::{alloc::string::String, collections::HashMap},
 };
@@ -56,6 +56,12 @@ where
     pub(super) pub_nub_client: PubNubClientInstance<T, D>,
     pub(super) message: M,
     pub(super) seqn: u16,
+
+    /// Whether message should be sent as signal or not.
+    pub(super) signal: bool,
+
+    /// Message type which should be sent with message.
+    pub(super) r#type: Option<String>,
 }
 
 impl<T, M, D> PublishMessageBuilder<T, M, D>
@@ -73,6 +79,8 @@ where
         PublishMessageViaChannelBuilder::<T, M, D> {
             pub_nub_client: Some(self.pub_nub_client),
             seqn: Some(self.seqn),
+            signal: Some(self.signal),
+            r#type: Some(self.r#type),
             ..Default::default()
         }
         .message(self.message)
@@ -131,6 +139,10 @@ where
     #[builder(setter(custom))]
     pub(super) seqn: u16,
 
+    /// Whether message should be sent as signal or not.
+    #[builder(setter(custom), default = "false")]
+    pub(super) signal: bool,
+
     /// Message to publish
     pub(super) message: M,
 
@@ -170,4 +182,153 @@ where
     /// Message type to publish.
     #[builder(setter(strip_option, into), default = "None")]
     pub(super) r#type: Option<String>,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(setter(strip_option), default = "None")]
+    pub(super) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(PublishMessageViaChannelBuilderError);
+
+/// The [`PublishMessageRequest`] is a publish request which is not bound to
+/// any client.
+///
+/// Request can be built without [`PubNubClient`] (for example, in pure
+/// functions or tests) and client is provided only when request should be
+/// executed with [`try_execute_with`].
+///
+/// # Examples
+/// ```no_run
+/// # use pubnub::{Keyset, PubNubClientBuilder};
+/// use pubnub::publish::PublishMessageRequest;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let request = PublishMessageRequest::builder()
+///     .message("hello world!")
+///     .channel("my_channel")
+///     .store(false);
+///
+/// let pubnub = // PubNubClient
+/// # PubNubClientBuilder::with_reqwest_transport()
+/// #     .with_keyset(Keyset{
+/// #         subscribe_key: "demo",
+/// #         publish_key: Some("demo"),
+/// #         secret_key: None,
+/// #     })
+/// #     .with_user_id("user_id")
+/// #     .build()?;
+///
+/// request.try_execute_with(&pubnub).await?;
+///
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`PubNubClient`]: crate::dx::PubNubClient
+/// [`try_execute_with`]: PublishMessageRequestBuilder::try_execute_with
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(vis = "pub(super)"))]
+#[cfg_attr(not(feature = "std"), builder(no_std))]
+pub struct PublishMessageRequest<M>
+where
+    M: Serialize,
+{
+    /// Message to publish
+    pub(super) message: M,
+
+    /// Channel to publish to
+    #[builder(setter(into))]
+    pub(super) channel: String,
+
+    /// Switch that decides if the message should be stored in history
+    #[builder(setter(strip_option), default = "None")]
+    pub(super) store: Option<bool>,
+
+    /// Switch that decides if the transaction should be replicated
+    /// following the PubNub replication rules.
+    ///
+    /// See more at [`PubNub replication rules`]
+    ///
+    /// [`PubNub replication rules`]:https://www.pubnub.com/pricing/transaction-classification/
+    #[builder(default = "true")]
+    pub(super) replicate: bool,
+
+    /// Set a per-message TTL time to live in Message Persistence.
+    #[builder(setter(strip_option), default = "None")]
+    pub(super) ttl: Option<u32>,
+
+    /// Switch that decide if the message should be published using POST method.
+    #[builder(setter(strip_option), default = "false")]
+    pub(super) use_post: bool,
+
+    /// Object to send additional information about the message.
+    #[builder(setter(strip_option), default = "None")]
+    pub(super) meta: Option<HashMap<String, String>>,
+
+    /// Space ID to publish to.
+    #[builder(setter(strip_option, into), default = "None")]
+    pub(super) space_id: Option<String>,
+
+    /// Message type to publish.
+    #[builder(setter(strip_option, into), default = "None")]
+    pub(super) r#type: Option<String>,
+
+    /// Request timeout (in seconds).
+    ///
+    /// Overrides client's request timeout for this call.
+    #[cfg(feature = "std")]
+    #[builder(setter(strip_option), default = "None")]
+    pub(super) timeout: Option<u64>,
+}
+
+impl_from_builder_error!(PublishMessageRequestBuilderError);
+
+impl<M> PublishMessageRequest<M>
+where
+    M: Serialize,
+{
+    /// Create a new detached publish request builder.
+    pub fn builder() -> PublishMessageRequestBuilder<M> {
+        PublishMessageRequestBuilder::default()
+    }
+}
+
+impl<M> PublishMessageRequestBuilder<M>
+where
+    M: Serialize,
+{
+    /// Bind request to the `client` which should be used to execute it.
+    ///
+    /// # Errors
+    /// Returns [`PubNubError`] if required request fields haven't been set.
+    pub(super) fn bind<T, D>(
+        self,
+        client: &PubNubClientInstance<T, D>,
+    ) -> Result<PublishMessageViaChannelBuilder<T, M, D>, PubNubError>
+    where
+        D: Deserializer,
+    {
+        let request = self.build().map_err(PubNubError::from)?;
+
+        Ok(PublishMessageViaChannelBuilder {
+            pub_nub_client: Some(client.clone()),
+            seqn: Some(client.seqn()),
+            signal: Some(false),
+            message: Some(request.message),
+            channel: Some(request.channel),
+            store: Some(request.store),
+            replicate: Some(request.replicate),
+            ttl: Some(request.ttl),
+            use_post: Some(request.use_post),
+            meta: Some(request.meta),
+            space_id: Some(request.space_id),
+            r#type: Some(request.r#type),
+            #[cfg(feature = "std")]
+            timeout: Some(request.timeout),
+        })
+    }
 }
@@ -18,28 +18,36 @@ pub mod result;
 
 #[doc(inline)]
 pub use builders::{
-    PublishMessageBuilder, PublishMessageViaChannel, PublishMessageViaChannelBuilder,
+    PublishMessageBuilder, PublishMessageRequest, PublishMessageRequestBuilder,
+    PublishMessageViaChannel, PublishMessageViaChannelBuilder,
 };
 pub mod builders;
 
+#[cfg(feature = "heapless")]
+pub mod static_buffer;
+
+use buffers::{with_publish_buffers, PublishBuffers};
+mod buffers;
+
 use crate::{
     core::{
         utils::{
-            encoding::{url_encode, url_encode_extended, UrlEncodeExtension},
+            encoding::{url_encode_into, UrlEncodeExtension},
             headers::{APPLICATION_JSON, CONTENT_TYPE},
+            json::{json_object, json_string},
         },
-        CryptoProvider, Deserializer, PubNubError, Serialize, Transport, TransportMethod,
-        TransportRequest,
+        Deserializer, PubNubError, PubNubMessage, PubNubMessageSerializer, Serialize,
+        SerializeWith, Serializer, Transport, TransportMethod, TransportRequest,
     },
-    dx::pubnub_client::{PubNubClientInstance, PubNubConfig},
+    dx::pubnub_client::PubNubClientInstance,
     lib::{
         alloc::{
             format,
             string::{String, ToString},
-            sync::Arc,
+            vec::Vec,
         },
         collections::HashMap,
-        core::ops::Not,
+        core::{ops::Not, str::from_utf8},
     },
 };
 
@@ -89,6 +97,173 @@ where
             message,
             pub_nub_client: self.clone(),
             seqn,
+            signal: false,
+            r#type: None,
+        }
+    }
+
+    /// Create a new signal builder.
+    /// This method is used to send a signal to a channel.
+    ///
+    /// Signals are small messages (up to 64 bytes payload) which aren't
+    /// stored in history and are delivered to subscribers with the same
+    /// latency as published messages.
+    ///
+    /// Instance of [`PublishMessageBuilder`] is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset{
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #      })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    ///
+    /// pubnub.signal("typing")
+    ///    .channel("my_channel")
+    ///    .execute()
+    ///    .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PublishMessageBuilder`]: crate::dx::publish::PublishMessageBuilder
+    pub fn signal<M>(&self, message: M) -> PublishMessageBuilder<T, M, D>
+    where
+        M: Serialize,
+    {
+        PublishMessageBuilder {
+            signal: true,
+            ..self.publish_message(message)
+        }
+    }
+
+    /// Create a new publish message builder with custom serializer.
+    ///
+    /// `message` will be serialized with provided `serializer`, so there is no
+    /// need to implement [`Serialize`] for message type by hand (for example,
+    /// in builds without `serde` feature).
+    ///
+    /// Instance of [`PublishMessageBuilder`] is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    /// use pubnub::core::{PubNubError, Serializer};
+    ///
+    /// struct Telemetry {
+    ///     temperature: i32,
+    /// }
+    ///
+    /// struct TelemetrySerializer;
+    ///
+    /// impl<'se> Serializer<'se, Telemetry> for TelemetrySerializer {
+    ///     fn serialize(&self, object: &'se Telemetry) -> Result<Vec<u8>, PubNubError> {
+    ///         Ok(format!("{{\"temperature\":{}}}", object.temperature).into_bytes())
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset{
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #      })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    ///
+    /// pubnub.publish_message_with(Telemetry { temperature: 21 }, TelemetrySerializer)
+    ///    .channel("my_channel")
+    ///    .execute()
+    ///    .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PublishMessageBuilder`]: crate::dx::publish::PublishMessageBuilder
+    pub fn publish_message_with<M, S>(
+        &self,
+        message: M,
+        serializer: S,
+    ) -> PublishMessageBuilder<T, SerializeWith<M, S>, D>
+    where
+        S: for<'se> Serializer<'se, M>,
+    {
+        self.publish_message(SerializeWith::new(message, serializer))
+    }
+
+    /// Create a new publish message builder for typed message.
+    ///
+    /// `message` will be serialized with its [`PubNubMessage`] implementation
+    /// and [`PubNubMessage::MESSAGE_TYPE`] will be sent as message `type`, so
+    /// receivers can match it with typed messages stream.
+    ///
+    /// Instance of [`PublishMessageBuilder`] is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    /// use pubnub::core::{PubNubError, PubNubMessage};
+    ///
+    /// struct Ping;
+    ///
+    /// impl PubNubMessage for Ping {
+    ///     const MESSAGE_TYPE: &'static str = "ping";
+    ///
+    ///     fn to_payload(&self) -> Result<Vec<u8>, PubNubError> {
+    ///         Ok(b"{}".to_vec())
+    ///     }
+    ///
+    ///     fn from_payload(_payload: &[u8]) -> Result<Self, PubNubError> {
+    ///         Ok(Ping)
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset{
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #      })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    ///
+    /// pubnub.publish_typed(Ping)
+    ///    .channel("my_channel")
+    ///    .execute()
+    ///    .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PublishMessageBuilder`]: crate::dx::publish::PublishMessageBuilder
+    pub fn publish_typed<M>(
+        &self,
+        message: M,
+    ) -> PublishMessageBuilder<T, SerializeWith<M, PubNubMessageSerializer>, D>
+    where
+        M: PubNubMessage,
+    {
+        PublishMessageBuilder {
+            r#type: Some(M::MESSAGE_TYPE.into()),
+            ..self.publish_message_with(message, PubNubMessageSerializer)
         }
     }
 
@@ -118,9 +293,7 @@ where
             .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
 
         PublishMessageContext::from(instance)
-            .map_data(|client, params| {
-                params.create_transport_request(&client.config, &client.cryptor.clone())
-            })
+            .map_data(|client, params| params.create_transport_request(&client))
             .map(|ctx| {
                 Ok(PublishMessageContext {
                     client: ctx.client,
@@ -177,9 +350,11 @@ where
                         &some.client.transport,
                         deserializer,
                         #[cfg(feature = "std")]
-                        &some.client.config.transport.retry_configuration,
+                        &some.client.retry_configuration(),
                         #[cfg(feature = "std")]
                         &some.client.runtime,
+                        #[cfg(feature = "std")]
+                        &some.client.retry_notifier,
                     )
                     .await
             })
@@ -236,6 +411,106 @@ where
     }
 }
 
+impl<M> PublishMessageRequestBuilder<M>
+where
+    M: Serialize,
+{
+    /// Execute the request with provided `client` and return the result.
+    /// This method is asynchronous and will return a future.
+    /// The future will resolve to a [`PublishResult`] or [`PubNubError`].
+    ///
+    /// # Errors
+    /// Returns [`PubNubError`] if required request fields haven't been set or
+    /// request failed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    /// use pubnub::publish::{PublishMessageRequest, PublishMessageRequestBuilder};
+    ///
+    /// fn greeting(channel: &str) -> PublishMessageRequestBuilder<&'static str> {
+    ///     PublishMessageRequest::builder()
+    ///         .message("Hello, world!")
+    ///         .channel(channel)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset{
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #      })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    ///
+    /// greeting("my_channel").try_execute_with(&pubnub).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PublishResult`]: struct.PublishResult.html
+    /// [`PubNubError`]: enum.PubNubError.html
+    pub async fn try_execute_with<T, D>(
+        self,
+        client: &PubNubClientInstance<T, D>,
+    ) -> Result<PublishResult, PubNubError>
+    where
+        T: Transport + 'static,
+        D: Deserializer + 'static,
+    {
+        self.bind(client)?.execute().await
+    }
+
+    /// Execute the request with provided `client` and return the result.
+    ///
+    /// # Errors
+    /// Returns [`PubNubError`] if required request fields haven't been set or
+    /// request failed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    /// use pubnub::publish::PublishMessageRequest;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_blocking_transport()
+    /// #     .with_keyset(Keyset{
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #      })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    ///
+    /// PublishMessageRequest::builder()
+    ///     .message("Hello, world!")
+    ///     .channel("my_channel")
+    ///     .try_execute_blocking_with(&pubnub)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PublishResult`]: struct.PublishResult.html
+    /// [`PubNubError`]: enum.PubNubError.html
+    #[cfg(feature = "blocking")]
+    pub fn try_execute_blocking_with<T, D>(
+        self,
+        client: &PubNubClientInstance<T, D>,
+    ) -> Result<PublishResult, PubNubError>
+    where
+        T: crate::core::blocking::Transport,
+        D: Deserializer + 'static,
+    {
+        self.bind(client)?.execute_blocking()
+    }
+}
+
 impl<M> PublishMessageParams<M>
 where
     M: Serialize,
@@ -271,11 +546,11 @@ where
         query_params
     }
 
-    fn create_transport_request(
+    fn create_transport_request<T, D>(
         self,
-        config: &PubNubConfig,
-        cryptor: &Option<Arc<dyn CryptoProvider + Send + Sync>>,
+        client: &PubNubClientInstance<T, D>,
     ) -> Result<TransportRequest, PubNubError> {
+        let config = &client.config;
         let query_params = self.prepare_publish_query_params();
 
         let pub_key = config
@@ -283,47 +558,84 @@ where
             .as_ref()
             .ok_or_else(|| PubNubError::general_api_error("Publish key is not set", None, None))?;
         let sub_key = &config.subscribe_key;
-
-        let mut m_vec = self.message.serialize()?;
-        if let Some(cryptor) = cryptor {
-            if let Ok(encrypted) = cryptor.encrypt(m_vec.to_vec()) {
-                m_vec = format!("\"{}\"", general_purpose::STANDARD.encode(encrypted)).into_bytes();
+        let endpoint = if self.signal { "signal" } else { "publish" };
+
+        with_publish_buffers(|buffers| {
+            let PublishBuffers { message, path } = buffers;
+            self.message.serialize_into(message)?;
+
+            let mut content_type = self.message.content_type();
+            if let Some(schema) = &client.message_schema {
+                schema.validate(&self.channel, message).map_err(|details| {
+                    PubNubError::MessageValidation {
+                        channel: self.channel.clone(),
+                        details: error_details!(details),
+                    }
+                })?;
+            }
+            if let Some(cryptor) = &client.cryptor {
+                if let Ok(encrypted) = cryptor.encrypt(message.to_vec()) {
+                    message.clear();
+                    message.push(b'"');
+                    message
+                        .extend_from_slice(general_purpose::STANDARD.encode(encrypted).as_bytes());
+                    message.push(b'"');
+                    content_type = APPLICATION_JSON;
+                }
             }
-        }
 
-        if self.use_post {
-            Ok(TransportRequest {
-                path: format!(
-                    "/publish/{pub_key}/{sub_key}/0/{}/0",
-                    url_encode(self.channel.as_bytes())
-                ),
-                method: TransportMethod::Post,
-                query_parameters: query_params,
-                body: Some(m_vec),
-                headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
-                #[cfg(feature = "std")]
-                timeout: config.transport.request_timeout,
-            })
-        } else {
-            String::from_utf8(m_vec)
-                .map_err(|e| PubNubError::Serialization {
-                    details: e.to_string(),
+            [endpoint, pub_key, sub_key, "0"]
+                .iter()
+                .for_each(|component| {
+                    path.push('/');
+                    path.push_str(component);
+                });
+            path.push('/');
+            url_encode_into(path, self.channel.as_bytes(), UrlEncodeExtension::Default).map_err(
+                |e| PubNubError::Serialization {
+                    details: error_details!(e),
+                },
+            )?;
+            path.push_str("/0");
+
+            if self.use_post {
+                Ok(TransportRequest {
+                    path: path.clone(),
+                    method: TransportMethod::Post,
+                    query_parameters: query_params,
+                    body: Some(message.to_vec()),
+                    headers: [(CONTENT_TYPE.to_string(), content_type.to_string())].into(),
+                    #[cfg(feature = "std")]
+                    timeout: self.timeout.unwrap_or_else(|| client.request_timeout()),
                 })
-                .map(|m_str| TransportRequest {
-                    path: format!(
-                        "/publish/{}/{}/0/{}/0/{}",
-                        pub_key,
-                        sub_key,
-                        url_encode(self.channel.as_bytes()),
-                        url_encode_extended(m_str.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            } else if content_type != APPLICATION_JSON {
+                Err(PubNubError::general_api_error(
+                    format!(
+                        "Message with '{content_type}' content type can be published only with POST"
                     ),
+                    None,
+                    None,
+                ))
+            } else {
+                let m_str = from_utf8(message).map_err(|e| PubNubError::Serialization {
+                    details: error_details!(e),
+                })?;
+                path.push('/');
+                url_encode_into(path, m_str.as_bytes(), UrlEncodeExtension::NonChannelPath)
+                    .map_err(|e| PubNubError::Serialization {
+                        details: error_details!(e),
+                    })?;
+
+                Ok(TransportRequest {
+                    path: path.clone(),
                     method: TransportMethod::Get,
                     query_parameters: query_params,
                     #[cfg(feature = "std")]
-                    timeout: config.transport.request_timeout,
+                    timeout: self.timeout.unwrap_or_else(|| client.request_timeout()),
                     ..Default::default()
                 })
-        }
+            }
+        })
     }
 }
 
@@ -348,10 +660,13 @@ where
                 ttl: value.ttl,
                 meta: value.meta,
                 seqn: value.seqn,
+                signal: value.signal,
                 replicate: value.replicate,
                 use_post: value.use_post,
                 space_id: value.space_id,
                 r#type: value.r#type,
+                #[cfg(feature = "std")]
+                timeout: value.timeout,
             },
         }
     }
@@ -379,6 +694,7 @@ impl<T, D, X> PublishMessageContext<T, D, X> {
 struct PublishMessageParams<M> {
     message: M,
     seqn: u16,
+    signal: bool,
     channel: String,
     store: Option<bool>,
     replicate: bool,
@@ -387,6 +703,8 @@ struct PublishMessageParams<M> {
     meta: Option<HashMap<String, String>>,
     space_id: Option<String>,
     r#type: Option<String>,
+    #[cfg(feature = "std")]
+    timeout: Option<u64>,
 }
 
 fn bool_to_numeric(value: bool) -> String {
@@ -394,16 +712,13 @@ fn bool_to_numeric(value: bool) -> String {
 }
 
 fn serialize_meta(meta: &HashMap<String, String>) -> String {
-    let mut result = String::new();
-    result.push('{');
-    meta.iter().for_each(|k| {
-        result.push_str(format!("\"{}\":\"{}\",", k.0.as_str(), k.1.as_str()).as_str());
-    });
-    if result.ends_with(',') {
-        result.remove(result.len() - 1);
-    }
-    result.push('}');
-    result
+    let values = meta
+        .iter()
+        .map(|(key, value)| (key.as_str(), json_string(value)))
+        .collect::<Vec<_>>();
+    let object = json_object(values.iter().map(|(key, value)| (*key, value.as_bytes())));
+
+    String::from_utf8(object).unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -411,9 +726,12 @@ mod should {
     use super::*;
     use crate::providers::deserialization_serde::DeserializerSerde;
     use crate::{
-        core::TransportResponse,
+        core::{utils::encoding::url_encode_extended, PubNubResponse, TransportResponse},
         dx::pubnub_client::PubNubClientInstance,
-        lib::{alloc::vec, collections::HashMap},
+        lib::{
+            alloc::{sync::Arc, vec},
+            collections::HashMap,
+        },
         transport::middleware::PubNubMiddleware,
         Keyset, PubNubClientBuilder,
     };
@@ -423,7 +741,6 @@ mod should {
     struct MockTransport;
 
     fn client() -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
-        #[async_trait::async_trait]
         impl Transport for MockTransport {
             async fn send(
                 &self,
@@ -431,7 +748,7 @@ mod should {
             ) -> Result<TransportResponse, PubNubError> {
                 Ok(TransportResponse {
                     status: 200,
-                    body: Some(b"[1, \"Sent\", \"1234567890\"]".to_vec()),
+                    body: Some(b"[1, \"Sent\", \"1234567890\"]".to_vec().into()),
                     ..Default::default()
                 })
             }
@@ -453,7 +770,6 @@ mod should {
         #[derive(Default, Clone)]
         struct MockTransport;
 
-        #[async_trait::async_trait]
         impl Transport for MockTransport {
             async fn send(
                 &self,
@@ -475,6 +791,23 @@ mod should {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn provide_response_metadata_with_result() {
+        let result = client()
+            .publish_message("First message")
+            .channel("IGuess")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.status(), 200);
+        assert_eq!(
+            result.raw_body(),
+            Some(b"[1, \"Sent\", \"1234567890\"]".as_slice())
+        );
+        assert!(result.request_id().is_some());
+    }
+
     #[test]
     fn verify_all_query_parameters() {
         let client = client();
@@ -616,6 +949,119 @@ mod should {
         );
     }
 
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn send_msgpack_message_with_content_type_only_with_post() {
+        use crate::{
+            core::utils::headers::APPLICATION_MSGPACK, providers::deserialization_msgpack::MsgPack,
+        };
+
+        let client = client();
+        let message = HashMap::from([("number", 7)]);
+
+        let result = client
+            .publish_message(MsgPack(message.clone()))
+            .channel("ch")
+            .use_post(true)
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(
+            result.data.headers.get(CONTENT_TYPE),
+            Some(&APPLICATION_MSGPACK.to_string())
+        );
+        assert_eq!(
+            result.data.body.unwrap(),
+            rmp_serde::to_vec_named(&message).unwrap()
+        );
+        assert!(client
+            .publish_message(MsgPack(message))
+            .channel("ch")
+            .prepare_context_with_request()
+            .is_err());
+    }
+
+    #[test]
+    fn publish_json_value_message() {
+        let client = client();
+
+        let result = client
+            .publish_message(serde_json::json!({"text": "hello", "count": 2}))
+            .channel("ch")
+            .use_post(true)
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&result.data.body.unwrap()).unwrap(),
+            serde_json::json!({"text": "hello", "count": 2})
+        );
+    }
+
+    #[test]
+    fn publish_message_with_custom_serializer() {
+        struct Point(u8, u8);
+        struct PointSerializer;
+
+        impl<'se> Serializer<'se, Point> for PointSerializer {
+            fn serialize(&self, object: &'se Point) -> Result<Vec<u8>, PubNubError> {
+                Ok(format!("[{},{}]", object.0, object.1).into_bytes())
+            }
+        }
+
+        let client = client();
+
+        let result = client
+            .publish_message_with(Point(1, 2), PointSerializer)
+            .channel("ch")
+            .use_post(true)
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(result.data.body.unwrap(), b"[1,2]".to_vec());
+    }
+
+    #[test]
+    fn validate_message_with_schema() {
+        #[derive(Debug)]
+        struct ObjectSchema;
+
+        impl crate::core::MessageSchema for ObjectSchema {
+            fn validate(&self, _channel: &str, message: &[u8]) -> Result<(), String> {
+                message
+                    .starts_with(b"{")
+                    .then_some(())
+                    .ok_or_else(|| "message should be an object".into())
+            }
+        }
+
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                publish_key: Some(""),
+                subscribe_key: "",
+                secret_key: None,
+            })
+            .with_user_id("")
+            .with_message_schema(ObjectSchema)
+            .build()
+            .unwrap();
+
+        let result = client
+            .publish_message("text")
+            .channel("ch")
+            .prepare_context_with_request();
+
+        assert!(matches!(
+            result,
+            Err(PubNubError::MessageValidation { channel, .. }) if channel == "ch"
+        ));
+        assert!(client
+            .publish_message(HashMap::from([("text", "hello")]))
+            .channel("ch")
+            .prepare_context_with_request()
+            .is_ok());
+    }
+
     #[test]
     fn test_path_segments_get() {
         let client = client();
@@ -656,10 +1102,133 @@ mod should {
         assert_eq!(format!("/publish///0/{}/0", channel), result.data.path);
     }
 
+    #[test]
+    fn send_signal_to_signal_endpoint() {
+        let client = client();
+
+        let result = client
+            .signal("typing")
+            .channel("channel_name")
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!("/signal///0/channel_name/0/%22typing%22", result.data.path);
+    }
+
+    #[test]
+    fn publish_with_channel_entity() {
+        let client = client();
+        let channel = client.channel("channel_name");
+
+        let published = channel
+            .publish("hello")
+            .prepare_context_with_request()
+            .unwrap();
+        let fired = channel
+            .fire("hello")
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert!(published
+            .data
+            .path
+            .starts_with("/publish///0/channel_name/0/"));
+        assert!(channel
+            .signal("hello")
+            .prepare_context_with_request()
+            .unwrap()
+            .data
+            .path
+            .starts_with("/signal///0/channel_name/0/"));
+        assert_eq!(fired.data.query_parameters.get("store"), Some(&"0".into()));
+        assert_eq!(
+            fired.data.query_parameters.get("norep"),
+            Some(&"true".into())
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn publish_typed_message_with_type() {
+        #[derive(crate::PubNubMessage, serde::Serialize, serde::Deserialize)]
+        #[pubnub(message_type = "chat-message")]
+        struct ChatMessage {
+            text: String,
+        }
+
+        let client = client();
+
+        let result = client
+            .publish_typed(ChatMessage {
+                text: "hello".into(),
+            })
+            .channel("channel_name")
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(
+            "/publish///0/channel_name/0/%7B%22text%22%3A%22hello%22%7D",
+            result.data.path
+        );
+        assert_eq!(
+            result.data.query_parameters.get("type"),
+            Some(&"chat-message".into())
+        );
+    }
+
+    #[test]
+    fn publish_with_metadata_macro() {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .meta(crate::metadata! { "k" => "v", "role" => String::from("admin") })
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(
+            result.data.query_parameters.get("meta"),
+            Some(&"{\"k\":\"v\",\"role\":\"admin\"}".to_string())
+        );
+    }
+
+    #[test]
+    fn bind_detached_request_to_client() {
+        let client = client();
+        let request = PublishMessageRequest::builder()
+            .message("hello")
+            .channel("channel_name")
+            .store(false);
+
+        let result = request
+            .bind(&client)
+            .unwrap()
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!("/publish///0/channel_name/0/%22hello%22", result.data.path);
+        assert_eq!(result.data.query_parameters.get("store"), Some(&"0".into()));
+    }
+
+    #[tokio::test]
+    async fn return_err_when_detached_request_is_incomplete() {
+        let client = client();
+
+        let result = PublishMessageRequest::builder()
+            .message("hello")
+            .try_execute_with(&client)
+            .await;
+
+        assert!(matches!(result, Err(PubNubError::MissingChannel { .. })));
+    }
+
     #[test_case(HashMap::from([("k".to_string(), "v".to_string())]), "{\"k\":\"v\"}" ; "hash map with elements")]
     #[test_case(HashMap::new(), "{}" ; "empty hash map")]
     #[test_case(HashMap::from([("k".to_string(), "".to_string())]), "{\"k\":\"\"}" ; "empty value")]
     #[test_case(HashMap::from([("".to_string(), "v".to_string())]), "{\"\":\"v\"}" ; "empty key")]
+    #[test_case(HashMap::from([("k".to_string(), "say \"hi\"".to_string())]), "{\"k\":\"say \\\"hi\\\"\"}" ; "quotes in value")]
+    #[test_case(HashMap::from([("b".to_string(), "2".to_string()), ("a".to_string(), "1".to_string())]), "{\"a\":\"1\",\"b\":\"2\"}" ; "sorted keys")]
     fn this_test_should_test_an_fn_itself(map: HashMap<String, String>, expected_json: &str) {
         let result = serialize_meta(&map);
 
@@ -671,7 +1240,6 @@ mod should {
         #[derive(Default)]
         struct MockTransport;
 
-        #[async_trait::async_trait]
         impl Transport for MockTransport {
             async fn send(
                 &self,
@@ -706,4 +1274,176 @@ mod should {
 
         assert!(result.is_err());
     }
+
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[tokio::test]
+    async fn retry_with_mocked_runtime_delays() {
+        use crate::lib::sync::Mutex;
+        use crate::{core::Runtime, RequestRetryConfiguration};
+
+        #[derive(Default)]
+        struct MockTransport {
+            calls: Mutex<u8>,
+        }
+
+        impl Transport for MockTransport {
+            async fn send(
+                &self,
+                _request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                let mut calls = self.calls.lock();
+                *calls += 1;
+
+                Ok(if *calls < 3 {
+                    TransportResponse {
+                        status: 500,
+                        body: Some("{\"error\":true,\"message\":\"error message\"}".into()),
+                        ..Default::default()
+                    }
+                } else {
+                    TransportResponse {
+                        status: 200,
+                        body: Some(b"[1, \"Sent\", \"1234567890\"]".to_vec().into()),
+                        ..Default::default()
+                    }
+                })
+            }
+        }
+
+        #[derive(Clone, Default)]
+        struct MockRuntime {
+            delays: Arc<Mutex<Vec<u64>>>,
+        }
+
+        impl Runtime for MockRuntime {
+            fn spawn<R>(&self, future: impl futures::Future<Output = R> + Send + 'static)
+            where
+                R: Send + 'static,
+            {
+                tokio::spawn(future);
+            }
+
+            async fn sleep(self, delay: u64) {
+                self.delays.lock().push(delay * 1_000_000);
+            }
+
+            async fn sleep_microseconds(self, delay: u64) {
+                self.delays.lock().push(delay);
+            }
+        }
+
+        let runtime = MockRuntime::default();
+        let client = PubNubClientBuilder::with_transport(MockTransport::default())
+            .with_keyset(Keyset {
+                publish_key: Some(""),
+                subscribe_key: "",
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .with_retry_configuration(RequestRetryConfiguration::Linear {
+                delay: 30,
+                max_retry: 5,
+                max_total_retry_duration: None,
+                excluded_endpoints: None,
+            })
+            .with_runtime(runtime.clone())
+            .build()
+            .unwrap();
+
+        let result = client
+            .publish_message("this is message")
+            .channel("ch")
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+        let delays = runtime.delays.lock();
+        assert_eq!(delays.len(), 2);
+        assert!(delays.iter().all(|delay| *delay >= 30_000_000));
+    }
+
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[tokio::test]
+    async fn limit_retries_with_elapsed_time() {
+        use crate::lib::sync::Mutex;
+        use crate::{core::Runtime, RequestRetryConfiguration};
+
+        #[derive(Clone, Default)]
+        struct MockTransport {
+            timeouts: Arc<Mutex<Vec<u64>>>,
+        }
+
+        impl Transport for MockTransport {
+            async fn send(
+                &self,
+                request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                let calls = {
+                    let mut timeouts = self.timeouts.lock();
+                    timeouts.push(request.timeout);
+                    timeouts.len()
+                };
+
+                // Only retried requests are slow.
+                if calls > 1 {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(1100)).await;
+                }
+
+                Ok(TransportResponse {
+                    status: 500,
+                    body: Some("{\"error\":true,\"message\":\"error message\"}".into()),
+                    ..Default::default()
+                })
+            }
+        }
+
+        #[derive(Clone, Default)]
+        struct MockRuntime;
+
+        impl Runtime for MockRuntime {
+            fn spawn<R>(&self, future: impl futures::Future<Output = R> + Send + 'static)
+            where
+                R: Send + 'static,
+            {
+                tokio::spawn(future);
+            }
+
+            async fn sleep(self, _delay: u64) {
+                // Do nothing.
+            }
+
+            async fn sleep_microseconds(self, _delay: u64) {
+                // Do nothing.
+            }
+        }
+
+        let transport = MockTransport::default();
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                publish_key: Some(""),
+                subscribe_key: "",
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .with_retry_configuration(RequestRetryConfiguration::Linear {
+                delay: 0,
+                max_retry: 5,
+                max_total_retry_duration: Some(1),
+                excluded_endpoints: None,
+            })
+            .with_runtime(MockRuntime)
+            .build()
+            .unwrap();
+
+        let result = client
+            .publish_message("this is message")
+            .channel("ch")
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+        let timeouts = transport.timeouts.lock();
+        assert_eq!(timeouts.len(), 2);
+        assert_eq!(timeouts[1], 1);
+    }
 }
@@ -4,7 +4,7 @@
 //! The `PublishResult` type is used to represent the result of a publish operation.
 
 use crate::{
-    core::{service_response::APIErrorBody, PubNubError},
+    core::{service_response::APIErrorBody, PubNubError, ResponseMetadata, Timetoken},
     lib::alloc::string::String,
 };
 
@@ -13,9 +13,14 @@ use crate::{
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PublishResult {
     /// The timetoken of the published message.
-    pub timetoken: String,
+    pub timetoken: Timetoken,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
 }
 
+impl_pubnub_response!(PublishResult);
+
 /// The response body of a publish operation.
 /// It can be either a tuple with data from the Publish service
 /// or an [`OtherResponse`] from other services.
@@ -41,7 +46,7 @@ pub enum PublishResponseBody {
     /// ```json
     /// [1, "Sent", "15815800000000000"]
     /// ```
-    SuccessResponse(i32, String, String),
+    SuccessResponse(i32, String, Timetoken),
     /// The response body of a publish operation in other services.
     ErrorResponse(APIErrorBody),
 }
@@ -53,7 +58,10 @@ impl TryFrom<PublishResponseBody> for PublishResult {
         match value {
             PublishResponseBody::SuccessResponse(error_indicator, message, timetoken) => {
                 if error_indicator == 1 {
-                    Ok(PublishResult { timetoken })
+                    Ok(PublishResult {
+                        timetoken,
+                        metadata: Default::default(),
+                    })
                 } else {
                     Err(PubNubError::general_api_error(message, None, None))
                 }
@@ -75,7 +83,10 @@ mod should {
         match body {
             PublishResponseBody::SuccessResponse(error_indicator, message, timetoken) => {
                 if error_indicator == 1 {
-                    Ok(PublishResult { timetoken })
+                    Ok(PublishResult {
+                        timetoken,
+                        metadata: Default::default(),
+                    })
                 } else {
                     Err(PubNubError::general_api_error(
                         message,
@@ -93,8 +104,11 @@ mod should {
 
     #[test]
     fn parse_publish_response() {
-        let body =
-            PublishResponseBody::SuccessResponse(1, "Sent".into(), "15815800000000000".into());
+        let body = PublishResponseBody::SuccessResponse(
+            1,
+            "Sent".into(),
+            Timetoken::new(15815800000000000),
+        );
         let result = body_to_result(
             body,
             TransportResponse {
@@ -104,7 +118,7 @@ mod should {
         )
         .unwrap();
 
-        assert_eq!(result.timetoken, "15815800000000000");
+        assert_eq!(result.timetoken.to_string(), "15815800000000000");
     }
 
     #[test]
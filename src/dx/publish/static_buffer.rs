@@ -0,0 +1,250 @@
+//! # Static buffer publish module
+//!
+//! This module contains [`StaticPublishRequest`] which assembles publish
+//! request path and query into caller-provided fixed-size buffers
+//! ([`heapless::String`]) instead of heap allocated strings.
+//!
+//! It is intended for small microcontrollers where heap fragmentation caused
+//! by repeated request building is a problem. Assembled request can be sent
+//! with any HTTP client available on the target.
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode_into, UrlEncodeExtension},
+            metadata::{PKG_VERSION, SDK_ID},
+        },
+        PubNubError,
+    },
+    lib::core::fmt::{Result as FmtResult, Write},
+};
+
+/// Publish request which is built into fixed-size buffers.
+///
+/// Message (and optional metadata) should be already serialized to `JSON`
+/// string, because serializers allocate memory for serialized data.
+///
+/// # Examples
+/// ```
+/// use heapless::String;
+/// use pubnub::dx::publish::static_buffer::StaticPublishRequest;
+///
+/// # fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let mut path: String<128> = String::new();
+/// let mut query: String<128> = String::new();
+///
+/// let request = StaticPublishRequest::new("pub-key", "sub-key", "sensor-1")
+///     .with_store(false)
+///     .with_seqn(42);
+/// request.write_path("telemetry", r#"{"t":21.5}"#, &mut path)?;
+/// request.write_query(&mut query)?;
+///
+/// assert_eq!(
+///     path.as_str(),
+///     "/publish/pub-key/sub-key/0/telemetry/0/%7B%22t%22%3A21.5%7D"
+/// );
+/// assert!(query.starts_with("uuid=sensor-1&seqn=42&store=0"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticPublishRequest<'a> {
+    /// Key used to publish messages.
+    publish_key: &'a str,
+
+    /// Key used to subscribe for messages.
+    subscribe_key: &'a str,
+
+    /// Identifier of the publishing client.
+    user_id: &'a str,
+
+    /// Whether message should be stored in history or not.
+    store: Option<bool>,
+
+    /// How long (in hours) message should be stored in history.
+    ttl: Option<u32>,
+
+    /// Serialized `JSON` object with message metadata.
+    meta: Option<&'a str>,
+
+    /// Message sequence number.
+    seqn: u16,
+}
+
+impl<'a> StaticPublishRequest<'a> {
+    /// Create publish request for the keyset and client with `user_id`.
+    pub fn new(publish_key: &'a str, subscribe_key: &'a str, user_id: &'a str) -> Self {
+        Self {
+            publish_key,
+            subscribe_key,
+            user_id,
+            store: None,
+            ttl: None,
+            meta: None,
+            seqn: 1,
+        }
+    }
+
+    /// Set whether message should be stored in history or not.
+    pub fn with_store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Set how long (in hours) message should be stored in history.
+    pub fn with_ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Set serialized `JSON` object with message metadata.
+    pub fn with_meta(mut self, meta: &'a str) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+
+    /// Set message sequence number.
+    ///
+    /// Sequence number should be incremented by the caller for each published
+    /// message.
+    pub fn with_seqn(mut self, seqn: u16) -> Self {
+        self.seqn = seqn;
+        self
+    }
+
+    /// Write request path for `message` publish to `channel` into `buffer`.
+    ///
+    /// `message` should be serialized `JSON` value. Previous `buffer` content
+    /// is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Serialization`] if path doesn't fit into
+    /// `buffer`.
+    pub fn write_path<const N: usize>(
+        &self,
+        channel: &str,
+        message: &str,
+        buffer: &mut heapless::String<N>,
+    ) -> Result<(), PubNubError> {
+        buffer.clear();
+        self.path(channel, message, buffer)
+            .map_err(|_| overflow_error("path", N))
+    }
+
+    /// Write request query into `buffer`.
+    ///
+    /// Previous `buffer` content is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Serialization`] if query doesn't fit into
+    /// `buffer`.
+    pub fn write_query<const N: usize>(
+        &self,
+        buffer: &mut heapless::String<N>,
+    ) -> Result<(), PubNubError> {
+        buffer.clear();
+        self.query(buffer).map_err(|_| overflow_error("query", N))
+    }
+
+    /// Write request path into `writer`.
+    fn path<W>(&self, channel: &str, message: &str, writer: &mut W) -> FmtResult
+    where
+        W: Write,
+    {
+        write!(
+            writer,
+            "/publish/{}/{}/0/",
+            self.publish_key, self.subscribe_key
+        )?;
+        url_encode_into(writer, channel.as_bytes(), UrlEncodeExtension::Default)?;
+        writer.write_str("/0/")?;
+        url_encode_into(
+            writer,
+            message.as_bytes(),
+            UrlEncodeExtension::NonChannelPath,
+        )
+    }
+
+    /// Write request query into `writer`.
+    fn query<W>(&self, writer: &mut W) -> FmtResult
+    where
+        W: Write,
+    {
+        writer.write_str("uuid=")?;
+        url_encode_into(writer, self.user_id.as_bytes(), UrlEncodeExtension::Default)?;
+        write!(writer, "&seqn={}", self.seqn)?;
+
+        if let Some(store) = self.store {
+            write!(writer, "&store={}", if store { "1" } else { "0" })?;
+        }
+
+        if let Some(ttl) = self.ttl {
+            write!(writer, "&ttl={ttl}")?;
+        }
+
+        if let Some(meta) = self.meta {
+            writer.write_str("&meta=")?;
+            url_encode_into(writer, meta.as_bytes(), UrlEncodeExtension::Default)?;
+        }
+
+        writer.write_str("&pnsdk=")?;
+        url_encode_into(writer, SDK_ID.as_bytes(), UrlEncodeExtension::Default)?;
+        writer.write_str("%2F")?;
+        url_encode_into(writer, PKG_VERSION.as_bytes(), UrlEncodeExtension::Default)
+    }
+}
+
+/// Error for request `component` which doesn't fit into `capacity` bytes.
+fn overflow_error(component: &str, capacity: usize) -> PubNubError {
+    PubNubError::Serialization {
+        details: error_details!(
+            "Publish request {component} doesn't fit into {capacity} bytes buffer"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use heapless::String;
+
+    #[test]
+    fn write_request_into_buffers() {
+        let mut path: String<64> = String::new();
+        let mut query: String<128> = String::new();
+        let request = StaticPublishRequest::new("pub", "sub", "user id")
+            .with_ttl(10)
+            .with_meta(r#"{"k":"v"}"#);
+
+        request.write_path("ch/1", "\"hi\"", &mut path).unwrap();
+        request.write_query(&mut query).unwrap();
+
+        assert_eq!(path.as_str(), "/publish/pub/sub/0/ch%2F1/0/%22hi%22");
+        assert!(query.starts_with("uuid=user%20id&seqn=1&ttl=10&meta=%7B%22k%22%3A%22v%22%7D"));
+        assert!(query.contains("&pnsdk=PubNub-Rust%2F"));
+    }
+
+    #[test]
+    fn reuse_buffer() {
+        let mut path: String<64> = String::new();
+        let request = StaticPublishRequest::new("pub", "sub", "user");
+
+        request.write_path("a", "1", &mut path).unwrap();
+        request.write_path("b", "2", &mut path).unwrap();
+
+        assert_eq!(path.as_str(), "/publish/pub/sub/0/b/0/2");
+    }
+
+    #[test]
+    fn not_write_into_small_buffer() {
+        let mut path: String<16> = String::new();
+        let request = StaticPublishRequest::new("pub", "sub", "user");
+
+        assert!(matches!(
+            request.write_path("channel", "\"message\"", &mut path),
+            Err(PubNubError::Serialization { .. })
+        ));
+    }
+}
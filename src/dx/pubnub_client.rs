@@ -7,11 +7,16 @@
 //! [`PubNub API`]: https://www.pubnub.com/docs
 //! [`pubnub`]: ../index.html
 
+use crate::lib::log::info;
+use crate::lib::sync::{Mutex, RwLock};
 use derive_builder::Builder;
-use log::info;
-use spin::{Mutex, RwLock};
-use uuid::Uuid;
 
+#[cfg(all(
+    any(feature = "subscribe", feature = "presence"),
+    feature = "std",
+    feature = "blocking"
+))]
+use crate::providers::futures_blocking::RuntimeBlocking;
 #[cfg(all(
     any(feature = "subscribe", feature = "presence"),
     feature = "std",
@@ -21,13 +26,18 @@ use crate::providers::futures_tokio::RuntimeTokio;
 #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
 use crate::subscribe::{EventDispatcher, SubscriptionCursor, SubscriptionManager};
 
-#[cfg(feature = "presence")]
-use crate::lib::alloc::vec::Vec;
+#[cfg(feature = "metrics")]
+use crate::core::metrics::{ClientMetrics, MetricsCollector, Recorder};
+#[cfg(feature = "publish")]
+use crate::core::MessageSchema;
 #[cfg(all(feature = "presence", feature = "std"))]
 use crate::presence::PresenceManager;
+#[cfg(all(feature = "subscribe", feature = "std"))]
+use crate::{
+    core::{network_monitor::DynNetworkMonitor, ChannelName, NetworkMonitor},
+    subscribe::EncodedSubscribeInput,
+};
 
-#[cfg(not(feature = "serde"))]
-use crate::core::Deserializer;
 #[cfg(feature = "serde")]
 use crate::providers::deserialization_serde::DeserializerSerde;
 #[cfg(feature = "reqwest")]
@@ -35,24 +45,38 @@ use crate::transport::TransportReqwest;
 
 // TODO: Retry policy would be implemented for `no_std` event engine
 #[cfg(feature = "std")]
-use crate::core::{runtime::RuntimeSupport, RequestRetryConfiguration};
+use crate::{
+    core::{
+        retry_policy::{RetryAttempt, RetryNotifier},
+        runtime::RuntimeSupport,
+        DataStream, RequestRetryConfiguration, SystemClock, TransportResponse,
+    },
+    transport::circuit_breaker::{
+        CircuitBreaker, CircuitBreakerConfiguration, CircuitBreakerStatus,
+    },
+};
 
 use crate::{
-    core::{CryptoProvider, PubNubEntity, PubNubError},
+    core::{
+        Clock, CryptoProvider, Deserializer, EntitiesCache, IdProvider, PubNubEntity, PubNubError,
+        TransportRequest, UuidProvider,
+    },
     lib::{
         alloc::{
             borrow::ToOwned,
             format,
             string::{String, ToString},
             sync::Arc,
+            vec::Vec,
         },
         collections::HashMap,
         core::{
             cmp::max,
             ops::{Deref, DerefMut},
+            str::FromStr,
         },
     },
-    transport::middleware::{PubNubMiddleware, SignatureKeySet},
+    transport::middleware::{PubNubMiddleware, RequestHooks, SignatureKeySet},
     Channel, ChannelGroup, ChannelMetadata, UserMetadata,
 };
 
@@ -96,7 +120,6 @@ use crate::{
 ///
 /// # use pubnub::core::{Transport, TransportRequest, TransportResponse, PubNubError};
 /// # struct MyTransport;
-/// # #[async_trait::async_trait]
 /// # impl Transport for MyTransport {
 /// #     async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, PubNubError> {
 /// #         unimplemented!()
@@ -181,7 +204,6 @@ pub type PubNubGenericClient<T, D> = PubNubClientInstance<PubNubMiddleware<T>, D
 /// use pubnub::{Keyset, PubNubClientBuilder};
 ///
 /// # struct MyTransport;
-/// # #[async_trait::async_trait]
 /// # impl Transport for MyTransport {
 /// #     async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, PubNubError> {
 /// #         unimplemented!()
@@ -228,6 +250,53 @@ pub type PubNubGenericClient<T, D> = PubNubClientInstance<PubNubMiddleware<T>, D
 #[cfg(all(feature = "reqwest", feature = "serde"))]
 pub type PubNubClient = PubNubGenericClient<TransportReqwest, DeserializerSerde>;
 
+/// PubNub blocking client
+///
+/// Client for PubNub API with support for blocking execution (`execute_blocking`
+/// methods and raw subscription iterators) of all [`selected`] PubNub
+/// features. The client uses [`reqwest`] blocking client as a transport layer
+/// and [`serde`] for responses deserialization.
+///
+/// You can create clients using the
+/// [`PubNubClientBuilder::with_reqwest_blocking_transport`] method. Builder
+/// steps are the same for all enabled features.
+///
+/// # Examples
+/// ```
+/// use pubnub::{Keyset, PubNubBlockingClient, PubNubClientBuilder};
+///
+/// # fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let pubnub: PubNubBlockingClient = PubNubClientBuilder::with_reqwest_blocking_transport()
+///    .with_keyset(Keyset {
+///         publish_key: Some("pub-c-abc123"),
+///         subscribe_key: "sub-c-abc123",
+///         secret_key: None,
+///    })
+///    .with_user_id("my-user-id")
+///    .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Runtime
+///
+/// The SDK doesn't start asynchronous runtime (like `tokio`) for blocking
+/// client even if `tokio` feature is enabled: requests are sent on the calling
+/// thread. Note that [`reqwest`] blocking client manages its own internal
+/// background thread and must not be used from within asynchronous context.
+///
+/// [`selected`]: ../index.html#features
+/// [`reqwest`]: https://crates.io/crates/reqwest
+/// [`PubNubClientBuilder::with_reqwest_blocking_transport`]: struct.PubNubClientBuilder.html#method.with_reqwest_blocking_transport
+#[cfg(all(
+    feature = "reqwest",
+    feature = "serde",
+    feature = "blocking",
+    not(target_arch = "wasm32")
+))]
+pub type PubNubBlockingClient =
+    PubNubGenericClient<crate::transport::reqwest::blocking::TransportReqwest, DeserializerSerde>;
+
 /// PubNub client raw instance.
 ///
 /// This struct contains the actual client state.
@@ -254,10 +323,50 @@ impl<T, D> Deref for PubNubClientInstance<T, D> {
     }
 }
 
-impl<T, D> DerefMut for PubNubClientInstance<T, D> {
+/// Mutable access to the client's state.
+///
+/// Guard returned by [`PubNubClientInstance::config_mut`], which keeps request
+/// timeouts from `config.transport` and the ones used by client in sync.
+#[derive(Debug)]
+pub struct PubNubClientRefMut<'client, T, D> {
+    inner: &'client mut PubNubClientRef<T, D>,
+}
+
+impl<'client, T, D> PubNubClientRefMut<'client, T, D> {
+    fn new(inner: &'client mut PubNubClientRef<T, D>) -> Self {
+        #[cfg(feature = "std")]
+        {
+            inner.config.transport.request_timeout = *inner.request_timeout.read();
+            inner.config.transport.subscribe_request_timeout =
+                *inner.subscribe_request_timeout.read();
+        }
+
+        Self { inner }
+    }
+}
+
+impl<T, D> Deref for PubNubClientRefMut<'_, T, D> {
+    type Target = PubNubClientRef<T, D>;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<T, D> DerefMut for PubNubClientRefMut<'_, T, D> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        Arc::get_mut(&mut self.inner)
-            .expect("Multiple mutable references to PubNubClientInstance are not allowed")
+        self.inner
+    }
+}
+
+impl<T, D> Drop for PubNubClientRefMut<'_, T, D> {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        {
+            *self.inner.request_timeout.write() = self.inner.config.transport.request_timeout;
+            *self.inner.subscribe_request_timeout.write() =
+                self.inner.config.transport.subscribe_request_timeout;
+        }
     }
 }
 
@@ -307,13 +416,64 @@ pub struct PubNubClientRef<T, D> {
     )]
     pub(crate) cryptor: Option<Arc<dyn CryptoProvider + Send + Sync>>,
 
+    /// Time source
+    ///
+    /// Clock used to get current time for signed requests. [`SystemClock`] is
+    /// used by default when `std` feature is enabled.
+    ///
+    /// [`SystemClock`]: crate::core::SystemClock
+    #[builder(
+        setter(custom, strip_option),
+        field(vis = "pub(crate)"),
+        default = "None"
+    )]
+    pub(crate) clock: Option<Arc<dyn Clock + Send + Sync>>,
+
     /// Instance ID
+    ///
+    /// Identifier which is sent with each request as `instanceid` query
+    /// parameter (random identifier is generated if not set).
     #[builder(
-        setter(into),
-        field(type = "String", build = "Arc::new(Some(Uuid::new_v4().to_string()))")
+        setter(custom),
+        field(vis = "pub(crate)"),
+        default = "Arc::new(Some(UuidProvider.next_id()))"
     )]
     pub(crate) instance_id: Arc<Option<String>>,
 
+    /// Identifiers provider
+    ///
+    /// Provider used to generate identifiers for client instance, requests
+    /// and event engine effects. [`UuidProvider`] is used by default.
+    ///
+    /// [`UuidProvider`]: crate::core::UuidProvider
+    #[builder(
+        setter(custom),
+        field(vis = "pub(crate)"),
+        default = "Arc::new(UuidProvider)"
+    )]
+    pub(crate) id_provider: Arc<dyn IdProvider>,
+
+    /// `pnsdk` and `User-Agent` suffix.
+    ///
+    /// Suffix which is appended to the SDK identifier (for example, name and
+    /// version of the application which embeds SDK).
+    #[builder(
+        setter(custom, strip_option),
+        field(vis = "pub(crate)"),
+        default = "None"
+    )]
+    pub(crate) user_agent_suffix: Option<String>,
+
+    /// Custom query parameters.
+    ///
+    /// Parameters which are appended to the query of each request.
+    #[builder(
+        setter(custom),
+        field(vis = "pub(crate)"),
+        default = "Arc::new(HashMap::new())"
+    )]
+    pub(crate) query_params: Arc<HashMap<String, String>>,
+
     /// Sequence number for the publish requests
     #[builder(default = "Mutex::new(1)")]
     pub(crate) next_seqn: Mutex<u16>,
@@ -325,16 +485,30 @@ pub struct PubNubClientRef<T, D> {
     #[builder(
         setter(custom),
         field(vis = "pub(crate)"),
-        default = "Arc::new(spin::RwLock::new(String::new()))"
+        default = "Arc::new(RwLock::new(String::new()))"
     )]
     pub(crate) auth_token: Arc<RwLock<String>>,
 
+    /// Legacy authorization key.
+    ///
+    /// > **Important**: Use `.get_auth_key()` and `.set_auth_key()` to access
+    /// > it instead of field.
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) auth_key: Arc<RwLock<String>>,
+
+    /// Current `user_id`.
+    ///
+    /// > **Important**: Use `.user_id()` and `.set_user_id()` to access it
+    /// > instead of field.
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) user_id: Arc<RwLock<String>>,
+
     /// Real-time data filtering expression.
     #[cfg(feature = "subscribe")]
     #[builder(
         setter(custom, strip_option),
         field(vis = "pub(crate)"),
-        default = "Arc::new(spin::RwLock::new(String::new()))"
+        default = "Arc::new(RwLock::new(String::new()))"
     )]
     pub(crate) filter_expression: Arc<RwLock<String>>,
 
@@ -364,7 +538,7 @@ pub struct PubNubClientRef<T, D> {
     #[builder(
         setter(custom),
         field(vis = "pub(crate)"),
-        default = "Arc::new(spin::RwLock::new(HashMap::new()))"
+        default = "Arc::new(RwLock::new(HashMap::new()))"
     )]
     pub(crate) state: Arc<RwLock<HashMap<String, Vec<u8>>>>,
 
@@ -391,13 +565,115 @@ pub struct PubNubClientRef<T, D> {
     #[builder(setter(skip), field(vis = "pub(crate)"))]
     pub(crate) presence: Arc<RwLock<Option<PresenceManager>>>,
 
+    /// Network reachability monitor.
+    ///
+    /// Monitor used to suspend real-time updates and presence event engines
+    /// while network is unreachable.
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[builder(
+        setter(custom, strip_option),
+        field(vis = "pub(crate)"),
+        default = "None"
+    )]
+    pub(crate) network_monitor: Option<Arc<dyn DynNetworkMonitor>>,
+
+    /// Unix timestamp (in seconds) of the last successful subscribe request.
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) last_subscribe_timestamp: RwLock<Option<i64>>,
+
+    /// URL-encoded channels and groups used by the last subscribe request.
+    ///
+    /// Encoded lists are re-used by following subscribe requests until the
+    /// list of channels or groups will change.
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) subscribe_encoded_input: RwLock<Option<Arc<EncodedSubscribeInput>>>,
+
+    /// Circuit breaker used by transport middleware.
+    #[cfg(feature = "std")]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
+
+    /// Request automatic retry configuration.
+    ///
+    /// Shared with event engines, so changes will be used with next retry
+    /// attempts.
+    ///
+    /// > **Important**: Use `.retry_configuration()` and
+    /// > `.set_retry_configuration()` to access it instead of field.
+    #[cfg(feature = "std")]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) retry_configuration: Arc<RwLock<RequestRetryConfiguration>>,
+
+    /// Scheduled retry attempts notifier.
+    #[cfg(feature = "std")]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) retry_notifier: Arc<RetryNotifier>,
+
+    /// Non-subscribe request timeout.
+    ///
+    /// > **Important**: Use `.request_timeout()` and `.set_request_timeout()`
+    /// > to access it instead of field.
+    #[cfg(feature = "std")]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) request_timeout: RwLock<u64>,
+
+    /// Subscribe request timeout.
+    ///
+    /// > **Important**: Use `.subscribe_request_timeout()` and
+    /// > `.set_subscribe_request_timeout()` to access it instead of field.
+    #[cfg(feature = "std")]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) subscribe_request_timeout: RwLock<u64>,
+
+    /// Client metrics collector.
+    ///
+    /// > **Important**: Use `.metrics()` to access collected metrics instead
+    /// > of field.
+    #[cfg(feature = "metrics")]
+    #[builder(
+        setter(custom),
+        field(vis = "pub(crate)"),
+        default = "Default::default()"
+    )]
+    pub(crate) metrics: Arc<MetricsCollector>,
+
     /// Created entities.
     ///
-    /// Map of entities which has been created to access [`PubNub API`].
+    /// Cache of entities which has been created to access [`PubNub API`].
     ///
     /// [`PubNub API`]: https://www.pubnub.com/docs
     #[builder(setter(skip), field(vis = "pub(crate)"))]
-    pub(crate) entities: RwLock<HashMap<String, PubNubEntity<T, D>>>,
+    pub(crate) entities: RwLock<EntitiesCache<T, D>>,
+
+    /// Created entities cache size.
+    ///
+    /// Maximum number of entities which is kept in [`entities`] cache
+    /// (unlimited if not set).
+    ///
+    /// [`entities`]: PubNubClientRef::entities
+    #[builder(setter(custom), field(vis = "pub(crate)"), default = "None")]
+    pub(crate) entities_cache_size: Option<usize>,
+
+    /// Request lifecycle hooks.
+    ///
+    /// Hooks which is called before request will be sent and after response
+    /// has been received.
+    #[builder(
+        setter(custom),
+        field(vis = "pub(crate)"),
+        default = "Default::default()"
+    )]
+    pub(crate) hooks: RequestHooks,
+
+    /// Outgoing messages validator.
+    ///
+    /// Validator which is used to check published messages before publish
+    /// request will be built.
+    #[cfg(feature = "publish")]
+    #[builder(setter(custom), field(vis = "pub(crate)"), default = "None")]
+    pub(crate) message_schema: Option<Arc<dyn MessageSchema>>,
 }
 
 impl<T, D> PubNubClientInstance<T, D> {
@@ -439,11 +715,10 @@ impl<T, D> PubNubClientInstance<T, D> {
         let mut entities_slot = self.entities.write();
         let name = name.into();
         let entity = entities_slot
-            .entry(format!("{}_ch", &name))
-            .or_insert(Channel::new(self, name).into());
+            .get_or_insert_with(format!("{}_ch", &name), || Channel::new(self, name).into());
 
         match entity {
-            PubNubEntity::Channel(channel) => channel.clone(),
+            PubNubEntity::Channel(channel) => channel,
             _ => panic!("Unexpected entry type for Channel"),
         }
     }
@@ -489,11 +764,10 @@ impl<T, D> PubNubClientInstance<T, D> {
         for name in names.iter() {
             let name = name.to_owned().into();
             let entity = entities_slot
-                .entry(format!("{}_ch", name))
-                .or_insert(Channel::new(self, name).into());
+                .get_or_insert_with(format!("{}_ch", name), || Channel::new(self, name).into());
 
             match entity {
-                PubNubEntity::Channel(channel) => channels.push(channel.clone()),
+                PubNubEntity::Channel(channel) => channels.push(channel),
                 _ => panic!("Unexpected entry type for Channel"),
             }
         }
@@ -538,12 +812,12 @@ impl<T, D> PubNubClientInstance<T, D> {
     {
         let mut entities_slot = self.entities.write();
         let name = name.into();
-        let entity = entities_slot
-            .entry(format!("{}_chg", &name))
-            .or_insert(ChannelGroup::new(self, name).into());
+        let entity = entities_slot.get_or_insert_with(format!("{}_chg", &name), || {
+            ChannelGroup::new(self, name).into()
+        });
 
         match entity {
-            PubNubEntity::ChannelGroup(channel_group) => channel_group.clone(),
+            PubNubEntity::ChannelGroup(channel_group) => channel_group,
             _ => panic!("Unexpected entry type for ChannelGroup"),
         }
     }
@@ -589,14 +863,12 @@ impl<T, D> PubNubClientInstance<T, D> {
 
         for name in names.iter() {
             let name = name.clone().into();
-            let entity = entities_slot
-                .entry(format!("{}_chg", name))
-                .or_insert(ChannelGroup::new(self, name).into());
+            let entity = entities_slot.get_or_insert_with(format!("{}_chg", name), || {
+                ChannelGroup::new(self, name).into()
+            });
 
             match entity {
-                PubNubEntity::ChannelGroup(channel_group) => {
-                    channel_groups.push(channel_group.clone())
-                }
+                PubNubEntity::ChannelGroup(channel_group) => channel_groups.push(channel_group),
                 _ => panic!("Unexpected entry type for ChannelGroup"),
             }
         }
@@ -641,12 +913,12 @@ impl<T, D> PubNubClientInstance<T, D> {
     {
         let mut entities_slot = self.entities.write();
         let id = id.into();
-        let entity = entities_slot
-            .entry(format!("{}_chm", &id))
-            .or_insert(ChannelMetadata::new(self, id).into());
+        let entity = entities_slot.get_or_insert_with(format!("{}_chm", &id), || {
+            ChannelMetadata::new(self, id).into()
+        });
 
         match entity {
-            PubNubEntity::ChannelMetadata(channel_metadata) => channel_metadata.clone(),
+            PubNubEntity::ChannelMetadata(channel_metadata) => channel_metadata,
             _ => panic!("Unexpected entry type for ChannelMetadata"),
         }
     }
@@ -696,13 +968,13 @@ impl<T, D> PubNubClientInstance<T, D> {
 
         for id in ids.iter() {
             let id = id.clone().into();
-            let entity = entities_slot
-                .entry(format!("{}_chm", id))
-                .or_insert(ChannelMetadata::new(self, id).into());
+            let entity = entities_slot.get_or_insert_with(format!("{}_chm", id), || {
+                ChannelMetadata::new(self, id).into()
+            });
 
             match entity {
                 PubNubEntity::ChannelMetadata(channel_metadata) => {
-                    channels_metadata.push(channel_metadata.clone())
+                    channels_metadata.push(channel_metadata)
                 }
                 _ => panic!("Unexpected entry type for ChannelMetadata"),
             }
@@ -748,12 +1020,12 @@ impl<T, D> PubNubClientInstance<T, D> {
     {
         let mut entities_slot = self.entities.write();
         let id = id.into();
-        let entity = entities_slot
-            .entry(format!("{}_uidm", &id))
-            .or_insert(UserMetadata::new(self, id).into());
+        let entity = entities_slot.get_or_insert_with(format!("{}_uidm", &id), || {
+            UserMetadata::new(self, id).into()
+        });
 
         match entity {
-            PubNubEntity::UserMetadata(user_metadata) => user_metadata.clone(),
+            PubNubEntity::UserMetadata(user_metadata) => user_metadata,
             _ => panic!("Unexpected entry type for UserMetadata"),
         }
     }
@@ -800,14 +1072,12 @@ impl<T, D> PubNubClientInstance<T, D> {
 
         for id in ids.iter() {
             let id = id.clone().into();
-            let entity = entities_slot
-                .entry(format!("{}_uidm", id))
-                .or_insert(UserMetadata::new(self, id).into());
+            let entity = entities_slot.get_or_insert_with(format!("{}_uidm", id), || {
+                UserMetadata::new(self, id).into()
+            });
 
             match entity {
-                PubNubEntity::UserMetadata(user_metadata) => {
-                    users_metadata.push(user_metadata.clone())
-                }
+                PubNubEntity::UserMetadata(user_metadata) => users_metadata.push(user_metadata),
                 _ => panic!("Unexpected entry type for UserMetadata"),
             }
         }
@@ -815,6 +1085,92 @@ impl<T, D> PubNubClientInstance<T, D> {
         users_metadata
     }
 
+    /// Remove channel with the specified name from created entities.
+    ///
+    /// Client keeps created entities to reuse them for subsequent calls.
+    /// Removing channel which is not needed anymore allows to free memory
+    /// used by it.
+    ///
+    /// Returns `true` if channel has been removed. Channel which is used by
+    /// active subscriptions won't be removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{PubNubClient, PubNubClientBuilder, Keyset};
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: Some("demo")
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// let channel = pubnub.channel("my_channel");
+    /// assert!(pubnub.remove_channel("my_channel"));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remove_channel<S>(&self, name: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.remove_entity(format!("{}_ch", name.as_ref()))
+    }
+
+    /// Remove channel group with the specified name from created entities.
+    ///
+    /// Returns `true` if channel group has been removed. Channel group which
+    /// is used by active subscriptions won't be removed.
+    pub fn remove_channel_group<S>(&self, name: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.remove_entity(format!("{}_chg", name.as_ref()))
+    }
+
+    /// Remove channel metadata object with the specified identifier from
+    /// created entities.
+    ///
+    /// Returns `true` if channel metadata object has been removed. Channel
+    /// metadata object which is used by active subscriptions won't be
+    /// removed.
+    pub fn remove_channel_metadata<S>(&self, id: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.remove_entity(format!("{}_chm", id.as_ref()))
+    }
+
+    /// Remove user metadata object with the specified identifier from created
+    /// entities.
+    ///
+    /// Returns `true` if user metadata object has been removed. User metadata
+    /// object which is used by active subscriptions won't be removed.
+    pub fn remove_user_metadata<S>(&self, id: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.remove_entity(format!("{}_uidm", id.as_ref()))
+    }
+
+    /// Remove entity stored with `key` from created entities.
+    fn remove_entity(&self, key: String) -> bool {
+        self.entities.write().remove(&key)
+    }
+
+    /// Interned channel or channel group `name`.
+    ///
+    /// Names are interned in the created entities cache, so all subscriptions
+    /// for the same entity share single name buffer.
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    pub(crate) fn channel_name(&self, name: &str) -> ChannelName {
+        self.entities.write().intern(name)
+    }
+
     /// Update currently used authentication token.
     ///
     /// # Examples
@@ -874,62 +1230,711 @@ impl<T, D> PubNubClientInstance<T, D> {
         let token = self.auth_token.read().deref().clone();
         (!token.is_empty()).then_some(token)
     }
-}
-
-impl<T, D> PubNubClientInstance<T, D>
-where
-    T: crate::core::Transport + Send + Sync + 'static,
-    D: crate::core::Deserializer + Send + Sync + 'static,
-{
-    /// Terminates the subscription and presence managers if the corresponding
-    /// features are enabled.
-    #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
-    pub fn terminate(&self) {
-        #[cfg(feature = "subscribe")]
-        {
-            let manager = self.subscription_manager(false);
-            let mut manager_slot = manager.write();
-            if let Some(manager) = manager_slot.as_ref() {
-                manager.terminate();
-            }
-            // Free up resources used by subscription event engine.
-            *manager_slot = None;
-        }
-        #[cfg(feature = "presence")]
-        {
-            let manager = self.presence_manager(false);
-            let mut manager_slot = manager.write();
-            if let Some(manager) = manager_slot.as_ref() {
-                manager.terminate();
-            }
-            *manager_slot = None;
-        }
-    }
-}
 
-impl<T, D> PubNubClientConfigBuilder<T, D> {
-    /// Set client authentication key.
+    /// Update legacy authorization key.
     ///
-    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
-    /// configuration for the client. This is a part of the
-    /// [`PubNubClientConfigBuilder`].
-    pub fn with_auth_key<S>(mut self, auth_key: S) -> Self
-    where
-        S: Into<String>,
-    {
-        if let Some(configuration) = self.config.as_mut() {
-            configuration.auth_key = Some(Arc::new(auth_key.into()));
-        }
-
-        self
-    }
-
-    /// `user_id` presence heartbeat.
+    /// New `auth_key` will be used with next requests (unless access token
+    /// has been set with [`set_token`]). Empty `auth_key` disables it.
     ///
-    /// Used to set the presence timeout period. It overrides the default value
-    /// of 300 seconds for Presence timeout.
+    /// # Examples
+    /// ```rust
+    /// use pubnub::{PubNubClient, PubNubClientBuilder, Keyset};
     ///
-    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: None
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .with_auth_key("expired-auth-key")
+    /// #         .build()?;
+    /// pubnub.set_auth_key("renewed-auth-key");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`set_token`]: PubNubClientInstance::set_token
+    pub fn set_auth_key<S>(&self, auth_key: S)
+    where
+        S: Into<String>,
+    {
+        *self.inner.auth_key.write() = auth_key.into();
+    }
+
+    /// Retrieve currently used legacy authorization key.
+    pub fn get_auth_key(&self) -> Option<String> {
+        let auth_key = self.inner.auth_key.read().deref().clone();
+        (!auth_key.is_empty()).then_some(auth_key)
+    }
+
+    /// Retrieve currently used `user_id`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use pubnub::{PubNubClient, PubNubClientBuilder, Keyset};
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: None
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// println!("Current user id: {}", pubnub.user_id());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn user_id(&self) -> String {
+        self.inner.user_id.read().deref().clone()
+    }
+
+    /// Update `user_id` used by client.
+    ///
+    /// New `user_id` will be used with next requests.
+    #[cfg(not(all(feature = "subscribe", feature = "std")))]
+    pub fn set_user_id<S>(&self, user_id: S)
+    where
+        S: Into<String>,
+    {
+        *self.inner.user_id.write() = user_id.into();
+    }
+
+    /// Stream used to notify circuit breaker state changes.
+    ///
+    /// Returns `None` if circuit breaker hasn't been configured with
+    /// [`PubNubClientConfigBuilder::with_circuit_breaker`].
+    #[cfg(feature = "std")]
+    pub fn circuit_breaker_status_stream(&self) -> Option<DataStream<CircuitBreakerStatus>> {
+        self.circuit_breaker
+            .as_ref()
+            .map(|circuit_breaker| circuit_breaker.status_stream())
+    }
+
+    /// Mutable access to the client's state.
+    ///
+    /// Replacement for removed `DerefMut` implementation. Unlike it, `None`
+    /// returned instead of panic when client instance shared with clones.
+    ///
+    /// Request timeouts changed in `config.transport` are applied to the
+    /// client when returned guard is dropped.
+    #[deprecated(
+        since = "0.6.0",
+        note = "use interior-mutable setters (like `set_request_timeout`) instead"
+    )]
+    pub fn config_mut(&mut self) -> Option<PubNubClientRefMut<'_, T, D>> {
+        Arc::get_mut(&mut self.inner).map(PubNubClientRefMut::new)
+    }
+
+    /// Current non-subscribe request timeout (in seconds).
+    #[cfg(feature = "std")]
+    pub fn request_timeout(&self) -> u64 {
+        *self.inner.request_timeout.read()
+    }
+
+    /// Update non-subscribe request timeout (in seconds).
+    ///
+    /// New timeout will be used with next non-subscribe requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         subscribe_key: "sub-c-abc123",
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("my-user-id")
+    ///     .build()?;
+    ///
+    /// client.set_request_timeout(5);
+    /// #    Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_request_timeout(&self, timeout: u64) {
+        *self.inner.request_timeout.write() = timeout;
+    }
+
+    /// Current subscribe request timeout (in seconds).
+    #[cfg(feature = "std")]
+    pub fn subscribe_request_timeout(&self) -> u64 {
+        *self.inner.subscribe_request_timeout.read()
+    }
+
+    /// Update subscribe request timeout (in seconds).
+    ///
+    /// New timeout will be used with next subscribe long-poll request.
+    #[cfg(feature = "std")]
+    pub fn set_subscribe_request_timeout(&self, timeout: u64) {
+        *self.inner.subscribe_request_timeout.write() = timeout;
+    }
+
+    /// Current requests automatic retry configuration.
+    #[cfg(feature = "std")]
+    pub fn retry_configuration(&self) -> RequestRetryConfiguration {
+        self.inner.retry_configuration.read().clone()
+    }
+
+    /// Update requests automatic retry configuration.
+    ///
+    /// New configuration will be used for next retry attempts of any requests,
+    /// including real-time updates and presence event engines which already
+    /// running.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{Keyset, PubNubClientBuilder, RequestRetryConfiguration};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         subscribe_key: "sub-c-abc123",
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("my-user-id")
+    ///     .build()?;
+    ///
+    /// client.set_retry_configuration(RequestRetryConfiguration::default_exponential());
+    /// #    Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_retry_configuration(&self, retry_configuration: RequestRetryConfiguration) {
+        *self.inner.retry_configuration.write() = retry_configuration;
+    }
+
+    /// Stream used to notify about scheduled request retry attempts.
+    ///
+    /// Each time when failed request is scheduled for retry (according to the
+    /// [`RequestRetryConfiguration`]) stream will receive [`RetryAttempt`]
+    /// with endpoint group, attempt number, delay and error which caused it.
+    #[cfg(feature = "std")]
+    pub fn retry_stream(&self) -> DataStream<RetryAttempt> {
+        self.retry_notifier.stream()
+    }
+}
+
+impl<T, D> PubNubClientInstance<PubNubMiddleware<T>, D>
+where
+    T: Clone,
+{
+    /// Create sibling client with overridden configuration.
+    ///
+    /// Sibling client shares transport, deserializer and runtime environment
+    /// with this client, but has its own configuration, which is copied from
+    /// this client and passed to the `configure` closure for adjustments.
+    /// Current `user_id`, authorization key, access token, retry configuration
+    /// and request timeouts are copied as well.
+    ///
+    /// Sibling client has its own subscription and presence event engines.
+    /// Metrics collector (if enabled) is shared with this client.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         subscribe_key: "sub-c-abc123",
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("my-user-id")
+    ///     .build()?;
+    ///
+    /// let presence_client = client.clone_with(|config| {
+    ///     config.presence.heartbeat_value = 60;
+    /// })?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::ClientInitialization`] if client can't be
+    /// created with adjusted configuration.
+    pub fn clone_with<F>(&self, configure: F) -> Result<Self, PubNubError>
+    where
+        F: FnOnce(&mut PubNubConfig),
+    {
+        let mut config = self.config.clone();
+        config.user_id = Arc::new(self.user_id());
+        config.auth_key = self.get_auth_key().map(Arc::new);
+
+        #[cfg(feature = "std")]
+        {
+            config.transport.retry_configuration = self.retry_configuration();
+            config.transport.request_timeout = self.request_timeout();
+            config.transport.subscribe_request_timeout = self.subscribe_request_timeout();
+        }
+
+        configure(&mut config);
+
+        PubNubClientConfigBuilder {
+            transport: Some(self.transport.transport.clone()),
+            deserializer: Some(self.deserializer.clone()),
+            config: Some(config),
+            cryptor: Some(self.cryptor.clone()),
+            clock: Some(self.clock.clone()),
+            instance_id: Some(self.instance_id.clone()),
+            id_provider: Some(self.id_provider.clone()),
+            user_agent_suffix: Some(self.user_agent_suffix.clone()),
+            query_params: Some(self.query_params.clone()),
+
+            #[cfg(feature = "subscribe")]
+            filter_expression: Some(Arc::new(RwLock::new(self.filter_expression.read().clone()))),
+
+            #[cfg(feature = "std")]
+            runtime: Some(self.runtime.clone()),
+
+            #[cfg(all(feature = "subscribe", feature = "std"))]
+            network_monitor: Some(self.network_monitor.clone()),
+
+            #[cfg(feature = "metrics")]
+            metrics: Some(self.inner.metrics.clone()),
+
+            entities_cache_size: Some(self.entities_cache_size),
+            hooks: Some(self.hooks.clone()),
+
+            #[cfg(feature = "publish")]
+            message_schema: Some(self.message_schema.clone()),
+            ..Default::default()
+        }
+        .build()
+        .inspect(|client| {
+            if let Some(token) = self.get_token() {
+                client.set_token(token);
+            }
+        })
+    }
+}
+
+impl<T, D> PubNubClientInstance<PubNubMiddleware<T>, D> {
+    /// Create client which uses `transport` and shares state with this client.
+    ///
+    /// Created client uses the same configuration, deserializer, cryptor and
+    /// middleware state (`user_id`, authorization key and token, request
+    /// hooks, circuit breaker and metrics collector) as this client, so
+    /// changes made through one client are visible to another. Subscription
+    /// and presence event engines aren't shared.
+    #[allow(dead_code)]
+    pub(crate) fn with_shared_state<U>(
+        &self,
+        transport: U,
+        #[cfg(feature = "std")] runtime: RuntimeSupport,
+    ) -> PubNubClientInstance<PubNubMiddleware<U>, D> {
+        let middleware = &self.transport;
+
+        PubNubClientInstance {
+            inner: Arc::new(PubNubClientRef {
+                transport: PubNubMiddleware {
+                    transport,
+                    instance_id: middleware.instance_id.clone(),
+                    user_agent_suffix: middleware.user_agent_suffix.clone(),
+                    query_params: middleware.query_params.clone(),
+                    user_id: middleware.user_id.clone(),
+                    auth_key: middleware.auth_key.clone(),
+                    auth_token: middleware.auth_token.clone(),
+                    signature_keys: middleware.signature_keys.clone(),
+                    id_provider: middleware.id_provider.clone(),
+                    clock: middleware.clock.clone(),
+                    #[cfg(feature = "std")]
+                    circuit_breaker: middleware.circuit_breaker.clone(),
+                    #[cfg(feature = "metrics")]
+                    metrics: middleware.metrics.clone(),
+                    hooks: middleware.hooks.clone(),
+                },
+                deserializer: self.deserializer.clone(),
+                instance_id: self.instance_id.clone(),
+                id_provider: self.id_provider.clone(),
+                user_agent_suffix: self.user_agent_suffix.clone(),
+                query_params: self.query_params.clone(),
+                next_seqn: Mutex::new(*self.next_seqn.lock()),
+                auth_token: self.auth_token.clone(),
+                auth_key: self.auth_key.clone(),
+                user_id: self.user_id.clone(),
+                config: self.config.clone(),
+                cryptor: self.cryptor.clone(),
+                clock: self.clock.clone(),
+
+                #[cfg(feature = "subscribe")]
+                filter_expression: self.filter_expression.clone(),
+
+                #[cfg(feature = "presence")]
+                state: self.state.clone(),
+
+                #[cfg(feature = "std")]
+                runtime,
+
+                #[cfg(all(feature = "subscribe", feature = "std"))]
+                subscription: Arc::new(RwLock::new(None)),
+
+                #[cfg(all(feature = "presence", feature = "std"))]
+                presence: Arc::new(RwLock::new(None)),
+
+                #[cfg(all(feature = "subscribe", feature = "std"))]
+                network_monitor: self.network_monitor.clone(),
+
+                #[cfg(all(feature = "subscribe", feature = "std"))]
+                last_subscribe_timestamp: RwLock::new(None),
+
+                #[cfg(all(feature = "subscribe", feature = "std"))]
+                subscribe_encoded_input: RwLock::new(None),
+
+                #[cfg(feature = "std")]
+                circuit_breaker: self.circuit_breaker.clone(),
+
+                #[cfg(feature = "std")]
+                retry_configuration: self.inner.retry_configuration.clone(),
+
+                #[cfg(feature = "std")]
+                retry_notifier: self.retry_notifier.clone(),
+
+                #[cfg(feature = "std")]
+                request_timeout: RwLock::new(self.request_timeout()),
+
+                #[cfg(feature = "std")]
+                subscribe_request_timeout: RwLock::new(self.subscribe_request_timeout()),
+
+                #[cfg(feature = "metrics")]
+                metrics: self.inner.metrics.clone(),
+
+                entities: RwLock::new(EntitiesCache::new(self.entities_cache_size)),
+                entities_cache_size: self.entities_cache_size,
+                hooks: self.hooks.clone(),
+
+                #[cfg(feature = "publish")]
+                message_schema: self.message_schema.clone(),
+            }),
+
+            #[cfg(all(feature = "subscribe", feature = "std"))]
+            cursor: Default::default(),
+
+            #[cfg(all(feature = "subscribe", feature = "std"))]
+            event_dispatcher: Default::default(),
+        }
+    }
+}
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    T: crate::core::Transport + Send + Sync + 'static,
+    D: crate::core::Deserializer + Send + Sync + 'static,
+{
+    /// Terminates the subscription and presence managers if the corresponding
+    /// features are enabled.
+    #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+    pub fn terminate(&self) {
+        #[cfg(feature = "subscribe")]
+        {
+            let manager = self.subscription_manager(false);
+            let mut manager_slot = manager.write();
+            if let Some(manager) = manager_slot.as_ref() {
+                manager.terminate();
+            }
+            // Free up resources used by subscription event engine.
+            *manager_slot = None;
+        }
+        #[cfg(feature = "presence")]
+        {
+            let manager = self.presence_manager(false);
+            let mut manager_slot = manager.write();
+            if let Some(manager) = manager_slot.as_ref() {
+                manager.terminate();
+            }
+            *manager_slot = None;
+        }
+    }
+
+    /// Gracefully shutdown client.
+    ///
+    /// Unsubscribe from all real-time updates (announce `leave` unless
+    /// suppressed), stop subscription and presence event engines and wait
+    /// until all in-flight effects finish or cancel.
+    ///
+    /// Unlike [`terminate`], returned future resolves only when all tasks
+    /// spawned by event engines exit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         subscribe_key: "demo",
+    ///         publish_key: Some("demo"),
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("user_id")
+    ///     .build()?;
+    ///
+    /// // ... use client
+    ///
+    /// client.shutdown().await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`terminate`]: PubNubClientInstance::terminate
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    pub async fn shutdown(&self) {
+        #[cfg(feature = "presence")]
+        let input = self
+            .subscription_manager(false)
+            .read()
+            .as_ref()
+            .map(|manager| manager.current_input())
+            .filter(|input| !input.is_empty);
+
+        self.unsubscribe_all();
+
+        // Presence event engine announce `leave` by itself.
+        #[cfg(feature = "presence")]
+        if let Some(input) = input.filter(|_| {
+            self.config.presence.heartbeat_interval.is_none()
+                && !self.config.presence.suppress_leave_events
+        }) {
+            let mut request = self.leave();
+            if let Some(channels) = input.channels() {
+                request = request.channels(channels);
+            }
+            if let Some(channel_groups) = input.channel_groups() {
+                request = request.channel_groups(channel_groups);
+            }
+
+            if let Err(error) = request.execute().await {
+                crate::lib::log::error!("Unable to announce leave on shutdown: {error:?}");
+            }
+        }
+
+        let subscription = self.subscription_manager(false).read().clone();
+        #[cfg(feature = "presence")]
+        let presence = self.presence_manager(false).read().clone();
+
+        self.terminate();
+
+        loop {
+            let subscription_terminated = subscription
+                .as_ref()
+                .is_none_or(|manager| manager.is_terminated());
+            #[cfg(feature = "presence")]
+            let presence_terminated = presence
+                .as_ref()
+                .is_none_or(|manager| manager.is_terminated());
+            #[cfg(not(feature = "presence"))]
+            let presence_terminated = true;
+
+            if subscription_terminated && presence_terminated {
+                break;
+            }
+
+            crate::core::Runtime::sleep_microseconds(self.runtime.clone(), 10_000).await;
+        }
+    }
+
+    /// Client metrics snapshot.
+    ///
+    /// Snapshot contains number of completed requests (by endpoint group and
+    /// status code), request latency percentiles, number of active
+    /// subscriptions and event engines queue depths at the moment of call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{core::retry_policy::Endpoint, Keyset, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         subscribe_key: "sub-c-abc123",
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("my-user-id")
+    ///     .build()?;
+    ///
+    /// let metrics = client.metrics();
+    /// println!("Publish latency: {:?}", metrics.latency.get(&Endpoint::MessageSend));
+    /// #    Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> ClientMetrics {
+        #[allow(unused_mut)]
+        let mut metrics = self.inner.metrics.snapshot();
+
+        #[cfg(feature = "subscribe")]
+        if let Some(manager) = self.subscription_manager(false).read().as_ref() {
+            metrics.active_subscriptions = manager.active_handlers_count();
+            metrics.subscribe_queue_depth = manager.queue_depth();
+        }
+
+        #[cfg(feature = "presence")]
+        if let Some(manager) = self.presence_manager(false).read().as_ref() {
+            metrics.presence_queue_depth = manager.queue_depth();
+        }
+
+        metrics
+    }
+}
+
+impl<T, D> PubNubClientConfigBuilder<T, D> {
+    /// Random bytes generator.
+    ///
+    /// Generator is used instead of the [`getrandom`] crate on targets which
+    /// it doesn't support (for example, hardware RNG on microcontrollers).
+    /// Generator is registered for the whole process and only the first
+    /// registered generator is used.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    ///
+    /// See [`set_entropy_source`] for more information.
+    ///
+    /// [`getrandom`]: https://docs.rs/getrandom
+    /// [`set_entropy_source`]: crate::core::entropy::set_entropy_source
+    #[cfg(feature = "entropy_source")]
+    pub fn with_entropy_source<F>(self, source: F) -> Self
+    where
+        F: Fn(&mut [u8]) + Send + Sync + 'static,
+    {
+        if !crate::core::entropy::set_entropy_source(source) {
+            crate::lib::log::warn!(
+                "Entropy source already registered. New source will be ignored."
+            );
+        }
+
+        self
+    }
+
+    /// Stable client instance identifier.
+    ///
+    /// Identifier is sent with each request as `instanceid` query parameter.
+    /// By default, a random identifier is generated for each client, so
+    /// stable value can be used to identify the same instance across
+    /// restarts.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_instance_id<S>(mut self, instance_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.instance_id = Some(Arc::new(Some(instance_id.into())));
+        self
+    }
+
+    /// Don't send instance identifier with requests.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn without_instance_id(mut self) -> Self {
+        self.instance_id = Some(Arc::new(None));
+        self
+    }
+
+    /// Identifiers provider.
+    ///
+    /// `provider` will be used to generate identifiers for client instance
+    /// (if it hasn't been set with [`with_instance_id`]), requests and event
+    /// engine effects. Custom provider may be used on devices without random
+    /// numbers generator or in tests which require stable identifiers.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    ///
+    /// [`with_instance_id`]: PubNubClientConfigBuilder::with_instance_id
+    pub fn with_id_provider<P>(mut self, provider: P) -> Self
+    where
+        P: IdProvider + 'static,
+    {
+        self.id_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Instance identifier generated with configured identifiers provider.
+    fn default_instance_id(&self) -> Arc<Option<String>> {
+        let id = match &self.id_provider {
+            Some(provider) => provider.next_id(),
+            None => UuidProvider.next_id(),
+        };
+
+        Arc::new(Some(id))
+    }
+
+    /// SDK identifier suffix.
+    ///
+    /// `suffix` (for example, `my-app/1.2.0`) will be appended to the SDK
+    /// identifier in `pnsdk` query parameter and `User-Agent` header.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_user_agent_suffix<S>(mut self, suffix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.user_agent_suffix = Some(Some(suffix.into()));
+        self
+    }
+
+    /// Custom query parameters.
+    ///
+    /// `query_params` will be appended to the query of each request before it
+    /// is signed (for example, tenant identifier required by the API gateway).
+    /// Parameters set by the SDK itself (like `uuid` or `auth`) can't be
+    /// overridden.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_query_params(mut self, query_params: HashMap<String, String>) -> Self {
+        self.query_params = Some(Arc::new(query_params));
+        self
+    }
+
+    /// Set client authentication key.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_auth_key<S>(mut self, auth_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.auth_key = Some(Arc::new(auth_key.into()));
+        }
+
+        self
+    }
+
+    /// `user_id` presence heartbeat.
+    ///
+    /// Used to set the presence timeout period. It overrides the default value
+    /// of 300 seconds for Presence timeout.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
     /// configuration for the client. This is a part of the
     /// [`PubNubClientConfigBuilder`].
     #[cfg(any(feature = "subscribe", feature = "presence"))]
@@ -999,23 +2004,309 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
         self
     }
 
-    /// Data encryption / decryption
+    /// Override runtime environment.
+    ///
+    /// Runtime is used by the client to spawn tasks and to wait between
+    /// request retry attempts and event engines effects. Custom runtime with
+    /// mocked `sleep` can be used to test reconnection logic without
+    /// real-time delays.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    ///
+    /// See [`Runtime`] trait for more information.
+    ///
+    /// [`Runtime`]: crate::core::Runtime
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    pub fn with_runtime<R>(mut self, runtime: R) -> Self
+    where
+        R: crate::core::Runtime + Send + Sync + 'static,
+    {
+        self.runtime = Some(RuntimeSupport::new(Arc::new(runtime)));
+
+        self
+    }
+
+    /// Circuit breaker configuration.
+    ///
+    /// Circuit breaker stops sending requests to the endpoint group for
+    /// configured cool-down period after a number of consecutive failures.
+    /// Suspended requests fail with [`PubNubError::CircuitBreakerOpen`].
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "std")]
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreakerConfiguration) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.transport.circuit_breaker = Some(circuit_breaker);
+        }
+
+        self
+    }
+
+    /// Network reachability monitor.
+    ///
+    /// Real-time updates and presence event engines will be disconnected
+    /// (without retry attempts) when `monitor` reports that network is down
+    /// and reconnected as soon as network is up again.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    pub fn with_network_monitor<M>(mut self, monitor: M) -> Self
+    where
+        M: NetworkMonitor + 'static,
+    {
+        self.network_monitor = Some(Some(Arc::new(monitor)));
+
+        self
+    }
+
+    /// Metrics recorder.
+    ///
+    /// Recorder will be notified about each completed request and can be
+    /// used to pipe client metrics into external metrics system. Multiple
+    /// recorders can be registered.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics_recorder<R>(mut self, recorder: R) -> Self
+    where
+        R: Recorder + 'static,
+    {
+        self.metrics
+            .get_or_insert_with(Default::default)
+            .add_recorder(Arc::new(recorder));
+
+        self
+    }
+
+    /// Created entities cache size.
+    ///
+    /// Limit number of entities (channels, channel groups and App Context
+    /// objects) which client keeps after creation. Least recently used
+    /// entities which is not used by any subscription will be evicted when
+    /// limit is reached.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_entities_cache_size(mut self, size: usize) -> Self {
+        self.entities_cache_size = Some(Some(size));
+        self
+    }
+
+    /// Outgoing messages validator.
+    ///
+    /// `schema` will be used to validate each published message (serialized,
+    /// but not encrypted yet) before publish request will be built. Publish
+    /// fails with [`PubNubError::MessageValidation`] if message doesn't conform
+    /// to the schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{core::MessageSchema, Keyset, PubNubClientBuilder};
+    ///
+    /// #[derive(Debug)]
+    /// struct ObjectSchema;
+    ///
+    /// impl MessageSchema for ObjectSchema {
+    ///     fn validate(&self, _channel: &str, message: &[u8]) -> Result<(), String> {
+    ///         message
+    ///             .starts_with(b"{")
+    ///             .then_some(())
+    ///             .ok_or_else(|| "message should be an object".into())
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         subscribe_key: "sub-c-abc123",
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("my-user-id")
+    ///     .with_message_schema(ObjectSchema)
+    ///     .build()?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "publish")]
+    pub fn with_message_schema<S>(mut self, schema: S) -> Self
+    where
+        S: MessageSchema + 'static,
+    {
+        self.message_schema = Some(Some(Arc::new(schema)));
+        self
+    }
+
+    /// Request hook.
+    ///
+    /// `hook` will be called with each prepared (signed) request right before
+    /// it will be sent and can be used for audit logging or custom telemetry.
+    /// Multiple hooks can be registered.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_request_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&TransportRequest) + Send + Sync + 'static,
+    {
+        self.hooks
+            .get_or_insert_with(Default::default)
+            .request
+            .push(Arc::new(hook));
+
+        self
+    }
+
+    /// Response hook.
+    ///
+    /// `hook` will be called with sent request, received response and request
+    /// duration. Hook isn't called if request failed before service response
+    /// has been received. Multiple hooks can be registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         subscribe_key: "sub-c-abc123",
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("my-user-id")
+    ///     .with_response_hook(|request, response, duration| {
+    ///         println!("{} -> {} in {duration:?}", request.path, response.status);
+    ///     })
+    ///     .build()?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "std")]
+    pub fn with_response_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&TransportRequest, &TransportResponse, core::time::Duration) + Send + Sync + 'static,
+    {
+        self.hooks
+            .get_or_insert_with(Default::default)
+            .response
+            .push(Arc::new(hook));
+
+        self
+    }
+
+    /// Event engines configuration.
+    ///
+    /// Capacity of channels used by subscribe and presence event engines.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+    pub fn with_event_engine_configuration(
+        mut self,
+        event_engine: EventEngineConfiguration,
+    ) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.event_engine = event_engine;
+        }
+
+        self
+    }
+
+    /// Default network reachability monitor.
+    ///
+    /// Use default network monitor which probes [`PubNub API`] `time`
+    /// endpoint every `interval` seconds. Monitor set with
+    /// [`PubNubClientConfigBuilder::with_network_monitor`] takes precedence.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    pub fn with_default_network_monitor(mut self, interval: u64) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.transport.network_monitor_interval = Some(max(1, interval));
+        }
+
+        self
+    }
+
+    /// Data encryption / decryption
+    ///
+    /// Crypto module used by client when publish messages / signals and receive
+    /// them as real-time updates from subscription module.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_cryptor<C>(mut self, cryptor: C) -> Self
+    where
+        C: CryptoProvider + Send + Sync + 'static,
+    {
+        self.cryptor = Some(Some(Arc::new(cryptor)));
+
+        self
+    }
+
+    /// Time source.
     ///
-    /// Crypto module used by client when publish messages / signals and receive
-    /// them as real-time updates from subscription module.
+    /// Clock used by client to get current time for requests signature. Custom
+    /// clock may be used to correct skewed device time or to provide time in
+    /// `no_std` environment (without it requests won't be signed).
     ///
     /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
     /// configuration for the client. This is a part of the
     /// [`PubNubClientConfigBuilder`].
-    pub fn with_cryptor<C>(mut self, cryptor: C) -> Self
+    pub fn with_clock<C>(mut self, clock: C) -> Self
     where
-        C: CryptoProvider + Send + Sync + 'static,
+        C: Clock + Send + Sync + 'static,
     {
-        self.cryptor = Some(Some(Arc::new(cryptor)));
+        self.clock = Some(Some(Arc::new(clock)));
 
         self
     }
 
+    /// Time source function.
+    ///
+    /// Function should return current Unix timestamp (number of seconds
+    /// elapsed since the Unix epoch) and will be used by client for requests
+    /// signature. This allows to use Access Manager APIs (like grant and
+    /// revoke) in `no_std` environment with real-time clock.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    ///
+    /// See [`with_clock`] for more information.
+    ///
+    /// [`with_clock`]: PubNubClientConfigBuilder::with_clock
+    pub fn with_time_source(self, now: fn() -> i64) -> Self {
+        self.with_clock(now)
+    }
+
     /// Real-time events filtering expression.
     ///
     /// # Arguments
@@ -1040,13 +2331,26 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
     }
 
     /// Build a [`PubNubClient`] from the builder
-    pub fn build(self) -> Result<PubNubClientInstance<PubNubMiddleware<T>, D>, PubNubError> {
+    pub fn build(mut self) -> Result<PubNubClientInstance<PubNubMiddleware<T>, D>, PubNubError> {
+        if self.instance_id.is_none() {
+            self.instance_id = Some(self.default_instance_id());
+        }
+
         self.build_internal()
             .map_err(|err| PubNubError::ClientInitialization {
-                details: err.to_string(),
+                details: error_details!(err),
             })
             .and_then(|pre_build| {
                 let token = Arc::new(RwLock::new(String::new()));
+                let user_id = Arc::new(RwLock::new(pre_build.config.user_id.to_string()));
+                let auth_key = Arc::new(RwLock::new(
+                    pre_build
+                        .config
+                        .auth_key
+                        .as_deref()
+                        .cloned()
+                        .unwrap_or_default(),
+                ));
                 #[cfg(all(feature = "subscribe", feature = "std"))]
                 let subscription = Arc::new(RwLock::new(None));
                 #[cfg(all(feature = "presence", feature = "std"))]
@@ -1060,21 +2364,64 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
                     pre_build.instance_id
                 );
 
+                #[cfg(feature = "std")]
+                let clock = pre_build
+                    .clock
+                    .or_else(|| Some(Arc::new(SystemClock) as Arc<dyn Clock + Send + Sync>));
+                #[cfg(not(feature = "std"))]
+                let clock = pre_build.clock;
+
+                #[cfg(feature = "std")]
+                let circuit_breaker = pre_build
+                    .config
+                    .transport
+                    .circuit_breaker
+                    .zip(clock.clone())
+                    .map(|(configuration, clock)| {
+                        Arc::new(CircuitBreaker::new(configuration, clock))
+                    });
+
+                #[cfg(feature = "std")]
+                let retry_configuration = Arc::new(RwLock::new(
+                    pre_build.config.transport.retry_configuration.clone(),
+                ));
+
+                #[cfg(feature = "std")]
+                let request_timeout = RwLock::new(pre_build.config.transport.request_timeout);
+                #[cfg(feature = "std")]
+                let subscribe_request_timeout =
+                    RwLock::new(pre_build.config.transport.subscribe_request_timeout);
+
                 Ok(PubNubClientRef {
                     transport: PubNubMiddleware {
                         signature_keys: pre_build.config.clone().signature_key_set()?,
-                        auth_key: pre_build.config.auth_key.clone(),
+                        auth_key: auth_key.clone(),
                         instance_id: pre_build.instance_id.clone(),
-                        user_id: pre_build.config.user_id.clone(),
+                        id_provider: pre_build.id_provider.clone(),
+                        user_agent_suffix: pre_build.user_agent_suffix.clone(),
+                        query_params: pre_build.query_params.clone(),
+                        user_id: user_id.clone(),
                         transport: pre_build.transport,
                         auth_token: token.clone(),
+                        clock: clock.clone(),
+                        #[cfg(feature = "std")]
+                        circuit_breaker: circuit_breaker.clone(),
+                        #[cfg(feature = "metrics")]
+                        metrics: Some(pre_build.metrics.clone()),
+                        hooks: pre_build.hooks.clone(),
                     },
                     deserializer: pre_build.deserializer,
                     instance_id: pre_build.instance_id,
+                    id_provider: pre_build.id_provider,
+                    user_agent_suffix: pre_build.user_agent_suffix,
+                    query_params: pre_build.query_params,
                     next_seqn: pre_build.next_seqn,
                     auth_token: token,
+                    auth_key,
+                    user_id,
                     config: pre_build.config,
                     cryptor: pre_build.cryptor.clone(),
+                    clock,
 
                     #[cfg(feature = "subscribe")]
                     filter_expression: pre_build.filter_expression,
@@ -1091,7 +2438,39 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
                     #[cfg(all(feature = "presence", feature = "std"))]
                     presence: presence.clone(),
 
-                    entities: RwLock::new(HashMap::new()),
+                    #[cfg(all(feature = "subscribe", feature = "std"))]
+                    network_monitor: pre_build.network_monitor,
+
+                    #[cfg(all(feature = "subscribe", feature = "std"))]
+                    last_subscribe_timestamp: RwLock::new(None),
+
+                    #[cfg(all(feature = "subscribe", feature = "std"))]
+                    subscribe_encoded_input: RwLock::new(None),
+
+                    #[cfg(feature = "std")]
+                    circuit_breaker,
+
+                    #[cfg(feature = "std")]
+                    retry_configuration,
+
+                    #[cfg(feature = "std")]
+                    retry_notifier: Default::default(),
+
+                    #[cfg(feature = "std")]
+                    request_timeout,
+
+                    #[cfg(feature = "std")]
+                    subscribe_request_timeout,
+
+                    #[cfg(feature = "metrics")]
+                    metrics: pre_build.metrics,
+
+                    entities: RwLock::new(EntitiesCache::new(pre_build.entities_cache_size)),
+                    entities_cache_size: pre_build.entities_cache_size,
+                    hooks: pre_build.hooks,
+
+                    #[cfg(feature = "publish")]
+                    message_schema: pre_build.message_schema,
                 })
             })
             .map(|client| {
@@ -1115,6 +2494,8 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
 /// * `non-subscribe` - any non-subscribe requests.
 #[cfg(feature = "std")]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TransportConfiguration {
     /// Timeout after which subscribe request will be cancelled by timeout.
     pub subscribe_request_timeout: u64,
@@ -1123,12 +2504,31 @@ pub struct TransportConfiguration {
     /// timeout.
     pub request_timeout: u64,
 
+    /// Custom [`PubNub API`] origin.
+    ///
+    /// Origin (for example `https://ps.pndsn.com`) which should be used by
+    /// transport created from configuration.
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    pub origin: Option<String>,
+
     /// Request automatic retry configuration.
     ///
     /// Automatic retry configuration contains a retry policy that should be
     /// used to calculate retry delays and the number of attempts that
     /// should be made.
     pub(crate) retry_configuration: RequestRetryConfiguration,
+
+    /// Circuit breaker configuration.
+    ///
+    /// Circuit breaker is disabled when configuration is not set.
+    pub(crate) circuit_breaker: Option<CircuitBreakerConfiguration>,
+
+    /// Interval (in seconds) between default network monitor probes.
+    ///
+    /// Default network monitor is disabled when interval is not set.
+    #[cfg(feature = "subscribe")]
+    pub(crate) network_monitor_interval: Option<u64>,
 }
 
 #[cfg(feature = "std")]
@@ -1137,7 +2537,11 @@ impl Default for TransportConfiguration {
         Self {
             subscribe_request_timeout: 310,
             request_timeout: 10,
+            origin: None,
             retry_configuration: RequestRetryConfiguration::None,
+            circuit_breaker: None,
+            #[cfg(feature = "subscribe")]
+            network_monitor_interval: None,
         }
     }
 }
@@ -1148,6 +2552,8 @@ impl Default for TransportConfiguration {
 /// or whether any updates should be sent when leaving.
 #[cfg(any(feature = "subscribe", feature = "presence"))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PresenceConfiguration {
     /// `user_id` presence heartbeat.
     ///
@@ -1185,25 +2591,108 @@ impl Default for PresenceConfiguration {
     }
 }
 
+/// Event engines configuration.
+///
+/// The configuration contains capacity of channels used by subscribe and
+/// presence event engines. Bigger capacity allows to handle bursts of events
+/// without queueing effect invocations outside of channel.
+#[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct EventEngineConfiguration {
+    /// Capacity of effect invocations channel.
+    ///
+    /// Channel is unbounded if capacity is not set.
+    ///
+    /// **Default:** `100`
+    pub invocations_capacity: Option<usize>,
+
+    /// Capacity of subscribe effects cancellation channel.
+    ///
+    /// Capacity can't be less than `1`.
+    ///
+    /// **Default:** `10`
+    pub subscribe_cancel_capacity: usize,
+
+    /// Capacity of presence effects cancellation channel.
+    ///
+    /// Capacity can't be less than `1`.
+    ///
+    /// **Default:** `3`
+    pub presence_cancel_capacity: usize,
+}
+
+#[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+impl Default for EventEngineConfiguration {
+    fn default() -> Self {
+        Self {
+            invocations_capacity: Some(100),
+            subscribe_cancel_capacity: 10,
+            presence_cancel_capacity: 3,
+        }
+    }
+}
+
 /// PubNub configuration
 ///
 /// Configuration for [`PubNubClient`].
 /// This struct separates the configuration from the actual client.
+///
+/// With `serde` feature configuration can be deserialized (for example, from
+/// configuration file) and passed to the
+/// [`PubNubClientKeySetBuilder::with_config`]. Configuration also can be
+/// loaded from environment variables with [`PubNubConfig::from_env`].
+///
+/// # Examples
+/// ```
+/// use pubnub::dx::pubnub_client::PubNubConfig;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let config: PubNubConfig = serde_json::from_str(
+///     r#"{
+///         "subscribe_key": "sub-c-abc123",
+///         "publish_key": "pub-c-abc123",
+///         "user_id": "my-user-id",
+///         "transport": {
+///             "request_timeout": 5,
+///             "retry_configuration": { "policy": "linear", "delay": 2, "max_retry": 3 }
+///         }
+///     }"#,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct PubNubConfig {
     /// Subscribe key
     pub(crate) subscribe_key: String,
 
     /// Publish key
+    #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) publish_key: Option<String>,
 
     /// Secret key
+    #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) secret_key: Option<String>,
 
     /// User ID
+    ///
+    /// `user_id` which has been used during client configuration. Current
+    /// `user_id` can be retrieved with [`PubNubClientInstance::user_id`].
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_user_id"))]
     pub(crate) user_id: Arc<String>,
 
     /// Authorization key
+    ///
+    /// Authorization key which has been used during client configuration.
+    /// Current key can be retrieved with
+    /// [`PubNubClientInstance::get_auth_key`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_auth_key")
+    )]
     pub(crate) auth_key: Option<Arc<String>>,
 
     /// Transport configuration.
@@ -1212,6 +2701,7 @@ pub struct PubNubConfig {
     /// * timeout
     /// * automatic retry.
     #[cfg(feature = "std")]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub transport: TransportConfiguration,
 
     /// Presence configuration.
@@ -1221,18 +2711,116 @@ pub struct PubNubConfig {
     /// * how often the server should be notified about user presence
     /// * whether `user_id` _leave_ event should be announced or not.
     #[cfg(any(feature = "subscribe", feature = "presence"))]
+    #[cfg_attr(feature = "serde", serde(default))]
     pub presence: PresenceConfiguration,
+
+    /// Event engines configuration.
+    ///
+    /// The configuration allows you to set up capacity of channels used by
+    /// subscribe and presence event engines.
+    #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub event_engine: EventEngineConfiguration,
 }
 
 impl PubNubConfig {
+    /// Load configuration from environment variables.
+    ///
+    /// Following environment variables are used:
+    /// * `PUBNUB_SUBSCRIBE_KEY` - subscribe key (required),
+    /// * `PUBNUB_PUBLISH_KEY` - publish key,
+    /// * `PUBNUB_SECRET_KEY` - secret key,
+    /// * `PUBNUB_USER_ID` - user id (required),
+    /// * `PUBNUB_AUTH_KEY` - authorization key,
+    /// * `PUBNUB_ORIGIN` - [`PubNub API`] origin,
+    /// * `PUBNUB_REQUEST_TIMEOUT` - non-subscribe request timeout in seconds,
+    /// * `PUBNUB_SUBSCRIBE_REQUEST_TIMEOUT` - subscribe request timeout in
+    ///   seconds,
+    /// * `PUBNUB_RETRY_POLICY` - default retry policy (`none`, `linear` or
+    ///   `exponential`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::ClientInitialization`] if required variable is
+    /// missing or variable has unexpected value.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use pubnub::{dx::pubnub_client::PubNubConfig, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_config(PubNubConfig::from_env()?)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Result<Self, PubNubError> {
+        let variable = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let required = |name: &str| {
+            variable(name).ok_or(PubNubError::ClientInitialization {
+                details: error_details!("Environment variable {name} is not set"),
+            })
+        };
+        let timeout = |name: &str, default: u64| {
+            variable(name)
+                .map(|value| {
+                    value
+                        .parse::<u64>()
+                        .map_err(|_| PubNubError::ClientInitialization {
+                            details: error_details!(
+                                "Environment variable {name} should be a number"
+                            ),
+                        })
+                })
+                .unwrap_or(Ok(default))
+        };
+
+        let mut transport = TransportConfiguration::default();
+        transport.origin = variable("PUBNUB_ORIGIN");
+        transport.request_timeout = timeout("PUBNUB_REQUEST_TIMEOUT", transport.request_timeout)?;
+        transport.subscribe_request_timeout = timeout(
+            "PUBNUB_SUBSCRIBE_REQUEST_TIMEOUT",
+            transport.subscribe_request_timeout,
+        )?;
+        if let Some(policy) = variable("PUBNUB_RETRY_POLICY") {
+            transport.retry_configuration = match policy.to_lowercase().as_str() {
+                "none" => RequestRetryConfiguration::None,
+                "linear" => RequestRetryConfiguration::default_linear(),
+                "exponential" => RequestRetryConfiguration::default_exponential(),
+                _ => {
+                    return Err(PubNubError::ClientInitialization {
+                        details: error_details!("Unknown retry policy: {policy}"),
+                    })
+                }
+            };
+        }
+
+        Ok(Self {
+            subscribe_key: required("PUBNUB_SUBSCRIBE_KEY")?,
+            publish_key: variable("PUBNUB_PUBLISH_KEY"),
+            secret_key: variable("PUBNUB_SECRET_KEY"),
+            user_id: Arc::new(required("PUBNUB_USER_ID")?),
+            auth_key: variable("PUBNUB_AUTH_KEY").map(Arc::new),
+            transport,
+
+            #[cfg(any(feature = "subscribe", feature = "presence"))]
+            presence: Default::default(),
+
+            #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+            event_engine: Default::default(),
+        })
+    }
+
     fn signature_key_set(self) -> Result<Option<SignatureKeySet>, PubNubError> {
         if let Some(secret_key) = self.secret_key {
-            #[cfg(not(feature = "std"))]
-            log::warn!("Signature calculation is not supported in `no_std`` environment!");
-
             let publish_key = self.publish_key.ok_or(PubNubError::ClientInitialization {
-                details: "You must also provide the publish key if you use the secret key."
-                    .to_string(),
+                details: error_details!(
+                    "You must also provide the publish key if you use the secret key."
+                ),
             })?;
             Ok(Some(SignatureKeySet {
                 secret_key,
@@ -1245,6 +2833,23 @@ impl PubNubConfig {
     }
 }
 
+#[cfg(feature = "serde")]
+fn deserialize_user_id<'de, De>(deserializer: De) -> Result<Arc<String>, De::Error>
+where
+    De: serde::Deserializer<'de>,
+{
+    <String as serde::Deserialize>::deserialize(deserializer).map(Arc::new)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_auth_key<'de, De>(deserializer: De) -> Result<Option<Arc<String>>, De::Error>
+where
+    De: serde::Deserializer<'de>,
+{
+    <Option<String> as serde::Deserialize>::deserialize(deserializer)
+        .map(|auth_key| auth_key.map(Arc::new))
+}
+
 /// PubNub builder for [`PubNubClient`]
 ///
 /// Builder for [`PubNubClient`] that is a first step to create a client.
@@ -1277,7 +2882,6 @@ impl PubNubClientBuilder {
     /// use pubnub::{Keyset, PubNubClientBuilder};
     /// #
     /// # struct MyTransport;
-    /// # #[async_trait::async_trait]
     /// # impl Transport for MyTransport {
     /// #     async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, PubNubError> {
     /// #         unimplemented!()
@@ -1327,7 +2931,6 @@ impl PubNubClientBuilder {
     /// use pubnub::{Keyset, PubNubClientBuilder};
     /// #
     /// # struct MyTransport;
-    /// # #[async_trait::async_trait]
     /// # impl Transport for MyTransport {
     /// #     async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, PubNubError> {
     /// #         unimplemented!()
@@ -1366,11 +2969,15 @@ impl PubNubClientBuilder {
 
     /// Set the blocking transport layer for the client.
     ///
-    /// Returns [`PubNubClientRuntimeBuilder`] where depending on from enabled
-    /// `features` following can be set:
-    /// * runtime environment
+    /// Returns [`PubNubClientDeserializerBuilder`] where depending on from
+    /// enabled `features` following can be set:
+    /// * [`PubNub API`] response deserializer
     /// * API ket set to access [`PubNub API`].
     ///
+    /// Client with blocking transport doesn't start or require asynchronous
+    /// runtime (even if `tokio` feature is enabled): requests are sent on the
+    /// calling thread and [`RuntimeBlocking`] is used as runtime environment.
+    ///
     /// # Examples
     /// ```
     /// # use pubnub::core::{blocking::Transport, TransportRequest, TransportResponse, PubNubError};
@@ -1405,16 +3012,20 @@ impl PubNubClientBuilder {
     /// ```
     ///
     /// [`PubNub API`]: https://www.pubnub.com/docs
+    /// [`RuntimeBlocking`]: crate::providers::futures_blocking::RuntimeBlocking
     #[cfg(all(
         any(feature = "subscribe", feature = "presence"),
         feature = "std",
         feature = "blocking"
     ))]
-    pub fn with_blocking_transport<T>(transport: T) -> PubNubClientRuntimeBuilder<T>
+    pub fn with_blocking_transport<T>(transport: T) -> PubNubClientDeserializerBuilder<T>
     where
         T: crate::core::blocking::Transport + Send + Sync,
     {
-        PubNubClientRuntimeBuilder { transport }
+        PubNubClientDeserializerBuilder {
+            transport,
+            runtime: RuntimeSupport::new(Arc::new(RuntimeBlocking)),
+        }
     }
 
     /// Set the blocking transport layer for the client.
@@ -1533,6 +3144,26 @@ impl<T, D> PubNubClientKeySetBuilder<T, D> {
             runtime: self.runtime,
         }
     }
+
+    /// Set the complete configuration for the client.
+    ///
+    /// Configuration (keys, user id and other options) can be deserialized or
+    /// loaded with [`PubNubConfig::from_env`].
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// rest of the client options.
+    pub fn with_config(self, config: PubNubConfig) -> PubNubClientConfigBuilder<T, D> {
+        PubNubClientConfigBuilder {
+            transport: Some(self.transport),
+            config: Some(config),
+
+            #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+            runtime: Some(self.runtime),
+
+            deserializer: Some(Arc::new(self.deserializer)),
+            ..Default::default()
+        }
+    }
 }
 
 /// PubNub builder for [`PubNubClient`] used to set runtime environment.
@@ -1572,7 +3203,6 @@ impl<T> PubNubClientRuntimeBuilder<T> {
     /// # #[derive(Clone)]
     /// # struct MyRuntime;
     /// #
-    /// # #[async_trait::async_trait]
     /// # impl Runtime for MyRuntime {
     /// #     fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) {
     /// #         // spawn the Future
@@ -1610,7 +3240,7 @@ impl<T> PubNubClientRuntimeBuilder<T> {
     #[cfg(all(not(feature = "serde"), not(feature = "tokio")))]
     pub fn with_runtime<R>(self, runtime: R) -> PubNubClientDeserializerBuilder<T>
     where
-        R: Runtime + Send + Sync + 'static,
+        R: crate::core::Runtime + Send + Sync + 'static,
     {
         PubNubClientDeserializerBuilder {
             transport: self.transport,
@@ -1634,7 +3264,6 @@ impl<T> PubNubClientRuntimeBuilder<T> {
     /// # #[derive(Clone)]
     /// # struct MyRuntime;
     /// #
-    /// # #[async_trait::async_trait]
     /// # impl Runtime for MyRuntime {
     /// #     fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) {
     /// #         // spawn the Future
@@ -1672,7 +3301,7 @@ impl<T> PubNubClientRuntimeBuilder<T> {
     #[cfg(all(feature = "serde", not(feature = "tokio")))]
     pub fn with_runtime<R>(self, runtime: R) -> PubNubClientKeySetBuilder<T, DeserializerSerde>
     where
-        R: Runtime + Send + Sync + 'static,
+        R: crate::core::Runtime + Send + Sync + 'static,
     {
         PubNubClientKeySetBuilder {
             transport: self.transport,
@@ -1722,6 +3351,25 @@ impl<T> PubNubClientRuntimeBuilder<T> {
             runtime: RuntimeSupport::new(Arc::new(RuntimeTokio)),
         }
     }
+
+    /// Set the complete configuration for the client.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// rest of the client options.
+    ///
+    /// See [`PubNubClientKeySetBuilder::with_config`] for more information.
+    #[cfg(all(feature = "serde", feature = "tokio"))]
+    pub fn with_config(
+        self,
+        config: PubNubConfig,
+    ) -> PubNubClientConfigBuilder<T, DeserializerSerde> {
+        PubNubClientKeySetBuilder {
+            transport: self.transport,
+            deserializer: DeserializerSerde,
+            runtime: RuntimeSupport::new(Arc::new(RuntimeTokio)),
+        }
+        .with_config(config)
+    }
 }
 
 /// PubNub builder for [`PubNubClient`] used to set custom deserializer.
@@ -1765,7 +3413,7 @@ impl<T> PubNubClientDeserializerBuilder<T> {
     /// #         &self, response: &[u8]
     /// #     ) -> Result<HeartbeatResult, PubNubError> {
     /// #         // ...
-    /// #         Ok(HeartbeatResult)
+    /// #         Ok(HeartbeatResult::default())
     /// #     }
     /// # }
     ///
@@ -1842,6 +3490,27 @@ impl<T> PubNubClientDeserializerBuilder<T> {
             runtime: self.runtime,
         }
     }
+
+    /// Set the complete configuration for the client.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// rest of the client options.
+    ///
+    /// See [`PubNubClientKeySetBuilder::with_config`] for more information.
+    #[cfg(feature = "serde")]
+    pub fn with_config(
+        self,
+        config: PubNubConfig,
+    ) -> PubNubClientConfigBuilder<T, DeserializerSerde> {
+        PubNubClientKeySetBuilder {
+            transport: self.transport,
+            deserializer: DeserializerSerde,
+
+            #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+            runtime: self.runtime,
+        }
+        .with_config(config)
+    }
 }
 
 /// PubNub builder for [`PubNubClient`] used to set the User ID
@@ -1886,6 +3555,42 @@ impl<T, S, D> PubNubClientUserIdBuilder<T, S, D>
 where
     S: Into<String>,
 {
+    /// Set [`PubNub API`] responses deserializer.
+    ///
+    /// Replaces deserializer selected by enabled `features` (for example,
+    /// with [`DeserializerSimdJson`] for faster subscribe responses
+    /// processing).
+    ///
+    /// # Examples
+    /// ```
+    /// use pubnub::{providers::deserialization_serde::DeserializerSerde, Keyset, PubNubClientBuilder};
+    ///
+    /// let builder = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         subscribe_key: "sub-c-abc123",
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         secret_key: None,
+    ///     })
+    ///     .with_deserializer(DeserializerSerde)
+    ///     .with_user_id("my-user_id");
+    /// ```
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    /// [`DeserializerSimdJson`]: crate::providers::deserialization_simd_json::DeserializerSimdJson
+    pub fn with_deserializer<D2>(self, deserializer: D2) -> PubNubClientUserIdBuilder<T, S, D2>
+    where
+        D2: Deserializer,
+    {
+        PubNubClientUserIdBuilder {
+            transport: self.transport,
+            deserializer,
+            keyset: self.keyset,
+
+            #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+            runtime: self.runtime,
+        }
+    }
+
     /// Set user id for the client.
     ///
     /// It returns [`PubNubClientConfigBuilder`] that you can use
@@ -1914,6 +3619,9 @@ where
 
                 #[cfg(any(feature = "subscribe", feature = "presence"))]
                 presence: Default::default(),
+
+                #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+                event_engine: Default::default(),
             }),
 
             #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
@@ -1925,31 +3633,165 @@ where
     }
 }
 
-/// Keyset for the PubNub client
-///
-/// # Examples
-/// ```
-/// use pubnub::Keyset;
-///
-/// Keyset {
-///    subscribe_key: "sub-c-abc123",
-///    publish_key: Some("pub-c-abc123"),
-///    secret_key: Some("sec-c-abc123"),
-/// };
-/// ```
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Keyset<S>
-where
-    S: Into<String>,
-{
-    /// Subscribe key
-    pub subscribe_key: S,
+/// Keyset for the PubNub client
+///
+/// # Examples
+/// ```
+/// use pubnub::Keyset;
+///
+/// Keyset {
+///    subscribe_key: "sub-c-abc123",
+///    publish_key: Some("pub-c-abc123"),
+///    secret_key: Some("sec-c-abc123"),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Keyset<S>
+where
+    S: Into<String>,
+{
+    /// Subscribe key
+    pub subscribe_key: S,
+
+    /// Publish key
+    pub publish_key: Option<S>,
+
+    /// Secret key
+    pub secret_key: Option<S>,
+}
+
+impl Keyset<String> {
+    /// Load keyset from environment variables.
+    ///
+    /// Following environment variables are used:
+    /// * `PUBNUB_SUBSCRIBE_KEY` - subscribe key (required),
+    /// * `PUBNUB_PUBLISH_KEY` - publish key,
+    /// * `PUBNUB_SECRET_KEY` - secret key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::ClientInitialization`] if subscribe key variable
+    /// is missing.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use pubnub::Keyset;
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let keyset = Keyset::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Result<Self, PubNubError> {
+        let variable = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        Ok(Self {
+            subscribe_key: variable("PUBNUB_SUBSCRIBE_KEY").ok_or(
+                PubNubError::ClientInitialization {
+                    details: error_details!("Environment variable PUBNUB_SUBSCRIBE_KEY is not set"),
+                },
+            )?,
+            publish_key: variable("PUBNUB_PUBLISH_KEY"),
+            secret_key: variable("PUBNUB_SECRET_KEY"),
+        })
+    }
+
+    /// Load keyset from connection string stored in `name` environment
+    /// variable.
+    ///
+    /// See [`Keyset::from_str`] for the connection string format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::ClientInitialization`] if variable is missing or
+    /// has malformed connection string.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use pubnub::Keyset;
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// // PUBNUB_KEYSET="pub=pub-c-abc123;sub=sub-c-abc123"
+    /// let keyset = Keyset::from_env_var("PUBNUB_KEYSET")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_env_var(name: &str) -> Result<Self, PubNubError> {
+        std::env::var(name)
+            .map_err(|_| PubNubError::ClientInitialization {
+                details: error_details!("Environment variable {name} is not set"),
+            })?
+            .parse()
+    }
+}
+
+impl FromStr for Keyset<String> {
+    type Err = PubNubError;
 
-    /// Publish key
-    pub publish_key: Option<S>,
+    /// Parse keyset from connection string.
+    ///
+    /// Connection string is a list of `;` separated `key=value` pairs where
+    /// `key` is one of: `pub` (publish key), `sub` (subscribe key, required)
+    /// or `sec` (secret key).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::ClientInitialization`] if connection string is
+    /// malformed or subscribe key is missing.
+    ///
+    /// # Examples
+    /// ```
+    /// use pubnub::Keyset;
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let keyset: Keyset<String> = "pub=pub-c-abc123;sub=sub-c-abc123".parse()?;
+    ///
+    /// assert_eq!(keyset.subscribe_key, "sub-c-abc123");
+    /// assert_eq!(keyset.publish_key, Some("pub-c-abc123".into()));
+    /// assert_eq!(keyset.secret_key, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut subscribe_key = None;
+        let mut publish_key = None;
+        let mut secret_key = None;
+
+        for pair in value
+            .split(';')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+        {
+            let Some((key, value)) = pair.split_once('=') else {
+                return Err(PubNubError::ClientInitialization {
+                    details: error_details!("Malformed keyset entry: {pair}"),
+                });
+            };
+            let value = value.trim();
+            let slot = match key.trim() {
+                "sub" => &mut subscribe_key,
+                "pub" => &mut publish_key,
+                "sec" => &mut secret_key,
+                key => {
+                    return Err(PubNubError::ClientInitialization {
+                        details: error_details!("Unknown keyset entry: {key}"),
+                    })
+                }
+            };
 
-    /// Secret key
-    pub secret_key: Option<S>,
+            *slot = (!value.is_empty()).then(|| value.to_string());
+        }
+
+        Ok(Self {
+            subscribe_key: subscribe_key.ok_or(PubNubError::ClientInitialization {
+                details: error_details!("Subscribe key is missing in keyset"),
+            })?,
+            publish_key,
+            secret_key,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1958,12 +3800,19 @@ mod should {
     use crate::core::{TransportRequest, TransportResponse};
     use std::any::type_name;
 
+    /// Transport which responds with `200` status to all requests.
+    fn transport() -> crate::test::MockTransport {
+        crate::test::MockTransport::new().with_default_response(TransportResponse {
+            status: 200,
+            ..Default::default()
+        })
+    }
+
     #[test]
     fn include_pubnub_middleware() {
         #[derive(Default)]
         struct MockTransport;
 
-        #[async_trait::async_trait]
         impl crate::core::Transport for MockTransport {
             async fn send(
                 &self,
@@ -1993,6 +3842,27 @@ mod should {
         );
     }
 
+    #[test]
+    fn parse_keyset_from_connection_string() {
+        let keyset: Keyset<String> = " pub=pub-key; sub=sub-key;sec=sec-key; ".parse().unwrap();
+
+        assert_eq!(
+            keyset,
+            Keyset {
+                subscribe_key: "sub-key".into(),
+                publish_key: Some("pub-key".into()),
+                secret_key: Some("sec-key".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn not_parse_malformed_keyset_connection_string() {
+        assert!("pub=pub-key".parse::<Keyset<String>>().is_err());
+        assert!("sub=sub-key;auth=key".parse::<Keyset<String>>().is_err());
+        assert!("sub-key".parse::<Keyset<String>>().is_err());
+    }
+
     #[test]
     fn publish_key_is_required_if_secret_is_set() {
         let config = PubNubConfig {
@@ -2007,8 +3877,423 @@ mod should {
 
             #[cfg(any(feature = "subscribe", feature = "presence"))]
             presence: Default::default(),
+
+            #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+            event_engine: Default::default(),
         };
 
         assert!(config.signature_key_set().is_err());
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn update_retry_configuration() {
+        let transport = transport();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .with_retry_configuration(RequestRetryConfiguration::default_linear())
+            .build()
+            .unwrap();
+        let client_clone = client.clone();
+
+        client.set_retry_configuration(RequestRetryConfiguration::None);
+
+        assert_eq!(
+            client_clone.retry_configuration(),
+            RequestRetryConfiguration::None
+        );
+    }
+
+    #[tokio::test]
+    async fn use_updated_request_timeout_with_shared_client() {
+        let transport = transport();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .build()
+            .unwrap();
+        let client_clone = client.clone();
+
+        let _ = client.time().execute().await;
+        client_clone.set_request_timeout(5);
+        let _ = client.time().execute().await;
+
+        assert_eq!(client.request_timeout(), 5);
+        assert_eq!(
+            transport
+                .requests()
+                .iter()
+                .map(|request| request.timeout)
+                .collect::<Vec<_>>(),
+            vec![10, 5]
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn not_provide_mutable_access_to_shared_client() {
+        let transport = transport();
+
+        let mut client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .build()
+            .unwrap();
+
+        assert!(client.config_mut().is_some());
+
+        let _client_clone = client.clone();
+
+        assert!(client.config_mut().is_none());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn apply_timeouts_changed_with_mutable_access() {
+        let transport = transport();
+
+        let mut client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .build()
+            .unwrap();
+        client.set_subscribe_request_timeout(200);
+
+        {
+            let mut inner = client.config_mut().unwrap();
+            assert_eq!(inner.config.transport.subscribe_request_timeout, 200);
+            inner.config.transport.request_timeout = 3;
+        }
+
+        assert_eq!(client.request_timeout(), 3);
+        assert_eq!(client.subscribe_request_timeout(), 200);
+    }
+
+    #[tokio::test]
+    async fn create_sibling_client_with_overridden_configuration() {
+        let transport = transport();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .build()
+            .unwrap();
+        client.set_user_id("updated-user_id");
+
+        let sibling = client
+            .clone_with(|config| {
+                config.presence.heartbeat_value = 60;
+                config.transport.request_timeout = 5;
+            })
+            .unwrap();
+
+        let _ = client.time().execute().await;
+        let _ = sibling.time().execute().await;
+
+        assert!(!Arc::ptr_eq(&client.inner, &sibling.inner));
+        assert_eq!(sibling.user_id(), "updated-user_id");
+        assert_eq!(sibling.config.presence.heartbeat_value, 60);
+        assert_eq!(client.config.presence.heartbeat_value, 300);
+        assert_eq!(
+            transport
+                .requests()
+                .iter()
+                .map(|request| request.timeout)
+                .collect::<Vec<_>>(),
+            vec![10, 5]
+        );
+    }
+
+    #[tokio::test]
+    async fn keep_access_token_in_sibling_client() {
+        let transport = transport();
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .build()
+            .unwrap();
+        client.set_token("access-token");
+
+        let sibling = client.clone_with(|_| {}).unwrap();
+        let _ = sibling.time().execute().await;
+
+        assert_eq!(sibling.get_token(), Some("access-token".into()));
+        assert_eq!(
+            transport
+                .last_request()
+                .unwrap()
+                .query_parameters
+                .get("auth"),
+            Some(&"access-token".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn call_request_lifecycle_hooks() {
+        let transport = transport();
+
+        let requests = Arc::new(RwLock::new(Vec::new()));
+        let responses = Arc::new(RwLock::new(Vec::new()));
+        let requests_clone = requests.clone();
+        let responses_clone = responses.clone();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .with_request_hook(move |request| {
+                requests_clone.write().push(request.path.clone());
+            })
+            .with_response_hook(move |request, response, _duration| {
+                assert!(request.query_parameters.contains_key("requestid"));
+                responses_clone.write().push(response.status);
+            })
+            .build()
+            .unwrap();
+
+        let _ = client.time().execute().await;
+
+        assert_eq!(*requests.read(), vec!["/time/0".to_string()]);
+        assert_eq!(*responses.read(), vec![200]);
+    }
+
+    #[tokio::test]
+    async fn use_custom_id_provider_for_instance_and_request_ids() {
+        let transport = transport();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .with_id_provider(crate::core::SequentialIdProvider::new("test"))
+            .build()
+            .unwrap();
+
+        let _ = client.time().execute().await;
+
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.query_parameters["instanceid"], "test-0");
+        assert_eq!(request.query_parameters["requestid"], "test-1");
+    }
+
+    #[tokio::test]
+    async fn use_updated_user_id_with_next_requests() {
+        use crate::core::Transport;
+
+        let transport = transport();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("anonymous")
+            .build()
+            .unwrap();
+
+        let _ = client.transport.send(TransportRequest::default()).await;
+        client.set_user_id("user-1234");
+        let _ = client.transport.send(TransportRequest::default()).await;
+
+        assert_eq!(client.user_id(), "user-1234");
+        assert_eq!(
+            transport
+                .requests()
+                .iter()
+                .map(|request| request.query_parameters["uuid"].clone())
+                .collect::<Vec<_>>(),
+            vec!["anonymous".to_string(), "user-1234".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn use_updated_auth_key_with_next_requests() {
+        use crate::core::Transport;
+
+        let transport = transport();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .with_auth_key("old-key")
+            .build()
+            .unwrap();
+
+        let _ = client.transport.send(TransportRequest::default()).await;
+        client.set_auth_key("new-key");
+        let _ = client.transport.send(TransportRequest::default()).await;
+        client.set_auth_key("");
+        let _ = client.transport.send(TransportRequest::default()).await;
+
+        assert_eq!(client.get_auth_key(), None);
+        assert_eq!(
+            transport
+                .requests()
+                .iter()
+                .map(|request| request.query_parameters.get("auth").cloned())
+                .collect::<Vec<_>>(),
+            vec![
+                Some("old-key".to_string()),
+                Some("new-key".to_string()),
+                None
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_requests_with_time_source_timestamp() {
+        use crate::core::Transport;
+
+        let transport = transport();
+
+        fn rtc_now() -> i64 {
+            1_700_000_000
+        }
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "sub",
+                publish_key: Some("pub"),
+                secret_key: Some("sec"),
+            })
+            .with_user_id("user")
+            .with_time_source(rtc_now)
+            .build()
+            .unwrap();
+
+        let _ = client.transport.send(TransportRequest::default()).await;
+
+        assert_eq!(
+            transport
+                .requests()
+                .iter()
+                .map(|request| request.query_parameters.get("timestamp").cloned())
+                .collect::<Vec<_>>(),
+            vec![Some("1700000000".to_string())]
+        );
+    }
+
+    #[test]
+    fn evict_least_recently_used_entities() {
+        let transport = transport();
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .with_entities_cache_size(2)
+            .build()
+            .unwrap();
+
+        client.channel("channel-a");
+        client.channel("channel-b");
+        client.channel("channel-a");
+        client.channel_group("group-a");
+
+        assert_eq!(client.entities.read().len(), 2);
+        assert!(!client.remove_channel("channel-b"));
+        assert!(client.remove_channel("channel-a"));
+        assert!(client.remove_channel_group("group-a"));
+        assert_eq!(client.entities.read().len(), 0);
+    }
+
+    #[test]
+    fn build_client_from_deserialized_config() {
+        let transport = transport();
+
+        let config: PubNubConfig = serde_json::from_str(
+            r#"{
+                "subscribe_key": "sub-key",
+                "publish_key": "pub-key",
+                "user_id": "user",
+                "auth_key": "auth",
+                "transport": {
+                    "request_timeout": 5,
+                    "origin": "https://custom.pubnub.com",
+                    "retry_configuration": { "policy": "linear", "delay": 3, "max_retry": 2 }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.transport.retry_configuration,
+            RequestRetryConfiguration::Linear {
+                delay: 3,
+                max_retry: 2,
+                max_total_retry_duration: None,
+                excluded_endpoints: None,
+            }
+        );
+        assert_eq!(config.transport.subscribe_request_timeout, 310);
+
+        let client = PubNubClientBuilder::with_transport(transport.clone())
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.user_id(), "user");
+        assert_eq!(client.get_auth_key(), Some("auth".into()));
+        assert_eq!(client.request_timeout(), 5);
+    }
+
+    #[test]
+    fn load_config_from_env() {
+        std::env::set_var("PUBNUB_SUBSCRIBE_KEY", "sub-key");
+        std::env::set_var("PUBNUB_USER_ID", "user");
+        std::env::set_var("PUBNUB_REQUEST_TIMEOUT", "7");
+        std::env::set_var("PUBNUB_RETRY_POLICY", "exponential");
+
+        let config = PubNubConfig::from_env().unwrap();
+
+        assert_eq!(config.subscribe_key, "sub-key");
+        assert_eq!(*config.user_id, "user");
+        assert_eq!(config.publish_key, None);
+        assert_eq!(config.transport.request_timeout, 7);
+        assert_eq!(
+            config.transport.retry_configuration,
+            RequestRetryConfiguration::default_exponential()
+        );
+    }
 }
@@ -0,0 +1,289 @@
+//! # PubNub bounded subscribe module.
+//!
+//! This module contains [`BoundedSubscription`] which is a variant of
+//! [`RawSubscription`] for RAM-constrained targets: number of channels,
+//! channel groups and buffered real-time updates is capped at compile time
+//! and stored in fixed-capacity [`heapless`] collections (without `Vec`
+//! growth).
+//!
+//! [`RawSubscription`]: crate::dx::subscribe::raw::RawSubscription
+
+use crate::{
+    core::{blocking, Deserializer, PubNubError},
+    dx::{
+        pubnub_client::PubNubClientInstance,
+        subscribe::{raw::RawSubscription, SubscriptionCursor, Update},
+    },
+    lib::{
+        alloc::{
+            string::{String, ToString},
+            vec::Vec,
+        },
+        log,
+    },
+};
+use heapless::{Deque, Vec as BoundedVec};
+
+/// Bounded subscription.
+///
+/// Subscription receives real-time updates for up to `N` channels and `N`
+/// channel groups and buffers up to `N` updates from single subscribe
+/// response. Updates which don't fit into buffer are dropped (see
+/// [`BoundedSubscription::dropped_updates`]), so `N` should be chosen
+/// according to the expected channels traffic.
+///
+/// Subscription is created from [`RawSubscription`] with
+/// [`RawSubscription::bounded`] and works as blocking iterator over received
+/// updates.
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{Keyset, PubNubClientBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let pubnub = PubNubClientBuilder::with_reqwest_blocking_transport()
+/// #     .with_keyset(Keyset {
+/// #         subscribe_key: "demo",
+/// #         publish_key: Some("demo"),
+/// #         secret_key: None,
+/// #     })
+/// #     .with_user_id("user_id")
+/// #     .build()?;
+/// let subscription = pubnub
+///     .subscribe_raw()
+///     .channels(["sensors"])
+///     .execute_blocking()?
+///     .bounded::<4>()?;
+///
+/// for update in subscription.take(10) {
+///     println!("Received update: {:?}", update);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct BoundedSubscription<T, D, const N: usize> {
+    /// Current client which can provide transportation to perform the request.
+    pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channels from which real-time updates should be received.
+    channels: BoundedVec<String, N>,
+
+    /// Channel groups from which real-time updates should be received.
+    channel_groups: BoundedVec<String, N>,
+
+    /// Time cursor.
+    cursor: SubscriptionCursor,
+
+    /// `user_id`presence timeout period.
+    heartbeat: u64,
+
+    /// Message filtering predicate.
+    filter_expression: Option<String>,
+
+    /// Received and not yet consumed real-time updates.
+    updates: Deque<Result<Update, PubNubError>, N>,
+
+    /// Number of real-time updates which didn't fit into buffer.
+    dropped_updates: usize,
+}
+
+impl<T, D> RawSubscription<T, D> {
+    /// Convert into [`BoundedSubscription`] with capacity `N`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::SubscribeInitialization`] if subscription has
+    /// more than `N` channels or channel groups.
+    pub fn bounded<const N: usize>(self) -> Result<BoundedSubscription<T, D, N>, PubNubError> {
+        let cursor = self
+            .cursor
+            .map(|tt| SubscriptionCursor {
+                timetoken: tt.to_string(),
+                region: 0,
+            })
+            .unwrap_or_default();
+
+        Ok(BoundedSubscription {
+            pubnub_client: self.pubnub_client,
+            channels: bounded_list(self.channels, "channels")?,
+            channel_groups: bounded_list(self.channel_groups, "channel groups")?,
+            cursor,
+            heartbeat: self.heartbeat,
+            filter_expression: self.filter_expression,
+            updates: Deque::new(),
+            dropped_updates: 0,
+        })
+    }
+}
+
+impl<T, D, const N: usize> BoundedSubscription<T, D, N> {
+    /// Maximum number of channels, channel groups and buffered updates.
+    pub const CAPACITY: usize = N;
+
+    /// Channels from which real-time updates are received.
+    pub fn channels(&self) -> &[String] {
+        &self.channels
+    }
+
+    /// Channel groups from which real-time updates are received.
+    pub fn channel_groups(&self) -> &[String] {
+        &self.channel_groups
+    }
+
+    /// Number of real-time updates which have been dropped because they
+    /// didn't fit into buffer.
+    pub fn dropped_updates(&self) -> usize {
+        self.dropped_updates
+    }
+}
+
+impl<T, D, const N: usize> Iterator for BoundedSubscription<T, D, N>
+where
+    T: blocking::Transport,
+    D: Deserializer + 'static,
+{
+    type Item = Result<Update, PubNubError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.updates.is_empty() {
+            let mut request = self
+                .pubnub_client
+                .subscribe_request()
+                .cursor(self.cursor.clone())
+                .channels(self.channels.to_vec())
+                .channel_groups(self.channel_groups.to_vec())
+                .heartbeat(self.heartbeat);
+
+            if let Some(filter_expr) = self.filter_expression.clone() {
+                request = request.filter_expression(filter_expr);
+            }
+
+            let response = match request.execute_blocking() {
+                Ok(response) => response,
+                Err(e) => {
+                    return Some(Err(PubNubError::general_api_error(
+                        e.to_string(),
+                        None,
+                        None,
+                    )))
+                }
+            };
+
+            self.cursor = response.cursor;
+            let dropped_updates = self.dropped_updates;
+            for update in response.messages {
+                let update = update.map(|update| match &self.pubnub_client.cryptor {
+                    Some(cryptor) => update.decrypt(cryptor),
+                    None => update,
+                });
+
+                if self.updates.push_back(update).is_err() {
+                    self.dropped_updates += 1;
+                }
+            }
+
+            if self.dropped_updates > dropped_updates {
+                log::warn!(
+                    "Bounded subscription buffer is full: {} updates dropped.",
+                    self.dropped_updates - dropped_updates
+                );
+            }
+        }
+
+        self.updates.pop_front()
+    }
+}
+
+/// Move `list` of subscription `entities` into fixed-capacity list.
+fn bounded_list<const N: usize>(
+    list: Vec<String>,
+    entities: &str,
+) -> Result<BoundedVec<String, N>, PubNubError> {
+    if list.len() > N {
+        return Err(PubNubError::SubscribeInitialization {
+            details: error_details!(
+                "Bounded subscription supports up to {N} {entities}, but {} provided",
+                list.len()
+            ),
+        });
+    }
+
+    Ok(list.into_iter().collect())
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod should {
+    use super::*;
+    use crate::{
+        providers::deserialization_serde::DeserializerSerde,
+        test::{fixtures::SubscribeResponseFixture, MockTransport},
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+    use serde_json::json;
+
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("rust-test-user")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn not_create_subscription_with_too_many_channels() {
+        let result = client(MockTransport::new())
+            .subscribe_raw()
+            .channels(["ch1", "ch2", "ch3"])
+            .execute_blocking()
+            .unwrap()
+            .bounded::<2>();
+
+        assert!(matches!(
+            result,
+            Err(PubNubError::SubscribeInitialization { .. })
+        ));
+    }
+
+    #[test]
+    fn drop_updates_which_do_not_fit_into_buffer() {
+        let transport = MockTransport::new()
+            .with_response(
+                SubscribeResponseFixture::new("10")
+                    .message("ch1", json!("one"))
+                    .message("ch1", json!("two"))
+                    .message("ch1", json!("three"))
+                    .into_response(),
+            )
+            .with_response(
+                SubscribeResponseFixture::new("20")
+                    .message("ch1", json!("four"))
+                    .into_response(),
+            );
+        let mut subscription = client(transport.clone())
+            .subscribe_raw()
+            .channels(["ch1"])
+            .execute_blocking()
+            .unwrap()
+            .bounded::<2>()
+            .unwrap();
+
+        let updates = subscription.by_ref().take(3).collect::<Vec<_>>();
+
+        assert_eq!(updates.len(), 3);
+        assert!(updates.iter().all(|update| update.is_ok()));
+        assert_eq!(subscription.dropped_updates(), 1);
+        assert!(transport
+            .last_request()
+            .unwrap()
+            .query_parameters
+            .get("tt")
+            .is_some_and(|tt| tt == "10"));
+    }
+}
@@ -6,11 +6,25 @@
 //! [`PubNub`]: https://www.pubnub.com
 
 #[doc(inline)]
+#[cfg(feature = "std")]
+pub(crate) use subscribe::EncodedSubscribeInput;
 pub(crate) use subscribe::SubscribeRequestBuilder;
 pub(crate) mod subscribe;
 
 pub mod raw;
 
+#[cfg(all(feature = "std", feature = "blocking"))]
+#[doc(inline)]
+pub use reconnecting::{ReconnectingSubscription, SubscriptionEvent};
+#[cfg(all(feature = "std", feature = "blocking"))]
+pub mod reconnecting;
+
+#[cfg(feature = "heapless")]
+#[doc(inline)]
+pub use bounded::BoundedSubscription;
+#[cfg(feature = "heapless")]
+pub mod bounded;
+
 use crate::{dx::pubnub_client::PubNubClientInstance, lib::alloc::string::String};
 
 /// Validate [`PubNubClient`] configuration.
@@ -18,9 +18,9 @@ use crate::{
     core::{blocking, Deserializer, PubNubError, Transport},
     dx::{
         pubnub_client::PubNubClientInstance,
-        subscribe::{SubscriptionCursor, Update},
+        subscribe::{SubscriptionCursor, Update, Updates},
     },
-    lib::alloc::{collections::VecDeque, string::String, string::ToString, vec::Vec},
+    lib::alloc::{string::String, string::ToString, vec::Vec},
 };
 
 /// Raw subscription that is responsible for getting messages from PubNub.
@@ -54,7 +54,7 @@ pub struct RawSubscription<T, D> {
     /// about received real-time updates.
     #[builder(
         field(vis = "pub(in crate::dx::subscribe)"),
-        setter(into, strip_option),
+        setter(custom),
         default = "Vec::new()"
     )]
     pub(in crate::dx::subscribe) channels: Vec<String>,
@@ -65,7 +65,7 @@ pub struct RawSubscription<T, D> {
     /// notify about received real-time updates.
     #[builder(
         field(vis = "pub(in crate::dx::subscribe)"),
-        setter(into, strip_option),
+        setter(custom),
         default = "Vec::new()"
     )]
     pub(in crate::dx::subscribe) channel_groups: Vec<String>,
@@ -110,6 +110,32 @@ pub struct RawSubscription<T, D> {
 }
 
 impl<T, D> RawSubscriptionBuilder<T, D> {
+    /// Channels from which real-time updates should be received.
+    ///
+    /// List of channels on which [`PubNubClient`] will subscribe and notify
+    /// about received real-time updates.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Channel groups from which real-time updates should be received.
+    ///
+    /// List of groups of channels on which [`PubNubClient`] will subscribe and
+    /// notify about received real-time updates.
+    pub fn channel_groups<L, S>(mut self, channel_groups: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channel_groups = Some(channel_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Validate user-provided data for request builder.
     ///
     /// Validator ensure that list of provided data is enough to build valid
@@ -141,7 +167,7 @@ where
     pub fn execute(self) -> Result<RawSubscription<T, D>, PubNubError> {
         self.build_internal()
             .map_err(|e| PubNubError::SubscribeInitialization {
-                details: e.to_string(),
+                details: error_details!(e),
             })
     }
 }
@@ -160,7 +186,7 @@ where
     pub fn execute_blocking(self) -> Result<RawSubscription<T, D>, PubNubError> {
         self.build_internal()
             .map_err(|e| PubNubError::SubscribeInitialization {
-                details: e.to_string(),
+                details: error_details!(e),
             })
     }
 }
@@ -188,7 +214,7 @@ where
         let context = SubscriptionContext {
             subscription: self,
             cursor,
-            messages: VecDeque::new(),
+            messages: Updates::default(),
         };
 
         futures::stream::unfold(context, |mut ctx| async {
@@ -218,10 +244,10 @@ where
                 let response = response.expect("Should be Ok");
 
                 ctx.cursor = response.cursor;
-                ctx.messages.extend(response.messages.into_iter().map(Ok));
+                ctx.messages = response.messages;
             }
 
-            Some((ctx.messages.pop_front().expect("Shouldn't be empty!"), ctx))
+            Some((ctx.messages.next().expect("Shouldn't be empty!"), ctx))
         })
     }
 }
@@ -248,7 +274,7 @@ where
         let context = SubscriptionContext {
             subscription: self,
             cursor,
-            messages: VecDeque::new(),
+            messages: Updates::default(),
         };
 
         RawSubscriptionIter(context)
@@ -291,29 +317,24 @@ where
 
             let response = response.expect("Should be Ok");
 
-            let messages: Vec<_> = if let Some(cryptor) = &ctx.subscription.pubnub_client.cryptor {
-                response
-                    .messages
-                    .into_iter()
-                    .map(|update| update.decrypt(cryptor))
-                    .map(Ok)
-                    .collect()
-            } else {
-                response.messages.into_iter().map(Ok).collect()
-            };
-
             ctx.cursor = response.cursor;
-            ctx.messages.extend(messages);
+            ctx.messages = response.messages;
         }
 
-        Some(ctx.messages.pop_front().expect("Shouldn't be empty!"))
+        let update = ctx.messages.next().expect("Shouldn't be empty!");
+        Some(
+            update.map(|update| match &ctx.subscription.pubnub_client.cryptor {
+                Some(cryptor) => update.decrypt(cryptor),
+                None => update,
+            }),
+        )
     }
 }
 
 struct SubscriptionContext<T, D> {
     subscription: RawSubscription<T, D>,
     cursor: SubscriptionCursor,
-    messages: VecDeque<Result<Update, PubNubError>>,
+    messages: Updates,
 }
 
 /// Iterator over messages received from PubNub.
@@ -335,7 +356,6 @@ mod should {
 
     struct MockTransport;
 
-    #[async_trait::async_trait]
     impl Transport for MockTransport {
         async fn send(&self, _req: TransportRequest) -> Result<TransportResponse, PubNubError> {
             // Send your request here
@@ -377,10 +397,10 @@ mod should {
         let builder = sut();
         assert!(builder.validate().is_err());
 
-        let builder = sut().channels(vec!["ch1".into()]);
+        let builder = sut().channels(["ch1"]);
         assert!(builder.validate().is_ok());
 
-        let builder = sut().channel_groups(vec!["cg1".into()]);
+        let builder = sut().channel_groups(["cg1"]);
         assert!(builder.validate().is_ok());
     }
 
@@ -388,7 +408,7 @@ mod should {
     async fn call_subscribe_endpoint_async() {
         use futures::StreamExt;
         let message = sut()
-            .channels(vec!["ch1".into()])
+            .channels(["ch1"])
             .execute()
             .unwrap()
             .stream()
@@ -402,7 +422,7 @@ mod should {
     #[test]
     fn call_subscribe_endpoint_blocking() {
         let message = sut()
-            .channels(vec!["ch1".into()])
+            .channels(["ch1"])
             .execute_blocking()
             .unwrap()
             .iter()
@@ -0,0 +1,400 @@
+//! # PubNub reconnecting subscribe module.
+//!
+//! This module contains [`ReconnectingSubscription`] which is a variant of
+//! [`RawSubscription`] for synchronous applications: failed subscribe requests
+//! are retried according to the client's [`RequestRetryConfiguration`] and
+//! connection status changes are reported along with received real-time
+//! updates.
+//!
+//! [`RawSubscription`]: crate::dx::subscribe::raw::RawSubscription
+//! [`RequestRetryConfiguration`]: crate::RequestRetryConfiguration
+
+use crate::{
+    core::{
+        blocking, retry_policy::Endpoint, Deserializer, PubNubError, RequestRetryConfiguration,
+    },
+    dx::{
+        pubnub_client::PubNubClientInstance,
+        subscribe::{raw::RawSubscription, ConnectionStatus, SubscriptionCursor, Update, Updates},
+    },
+    lib::{
+        alloc::{
+            string::{String, ToString},
+            vec::Vec,
+        },
+        core::{thread, time::Duration},
+    },
+};
+
+/// Reconnecting subscription event.
+#[derive(Debug, Clone)]
+pub enum SubscriptionEvent {
+    /// Real-time update.
+    Update(Update),
+
+    /// Subscription connection status change.
+    Status(ConnectionStatus),
+}
+
+/// Reconnecting subscription.
+///
+/// Subscription works as blocking iterator over received real-time updates
+/// with [`ConnectionStatus`] changes interleaved:
+/// * [`ConnectionStatus::Connected`] - first subscribe request succeeded,
+/// * [`ConnectionStatus::Reconnected`] - subscribe request succeeded after
+///   retry attempts,
+/// * [`ConnectionStatus::ConnectionError`] /
+///   [`ConnectionStatus::DisconnectedUnexpectedly`] - request can't be retried
+///   anymore (followed by error and end of iteration).
+///
+/// Failed requests are retried with delays calculated by the client's
+/// [`RequestRetryConfiguration`] (current thread is blocked while waiting).
+///
+/// Subscription is created from [`RawSubscription`] with
+/// [`RawSubscription::reconnecting`].
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{
+///     subscribe::SubscriptionEvent, Keyset, PubNubClientBuilder, RequestRetryConfiguration,
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let pubnub = PubNubClientBuilder::with_reqwest_blocking_transport()
+/// #     .with_keyset(Keyset {
+/// #         subscribe_key: "demo",
+/// #         publish_key: Some("demo"),
+/// #         secret_key: None,
+/// #     })
+/// #     .with_user_id("user_id")
+/// #     .with_retry_configuration(RequestRetryConfiguration::default_exponential())
+/// #     .build()?;
+/// let subscription = pubnub
+///     .subscribe_raw()
+///     .channels(["sensors"])
+///     .execute_blocking()?
+///     .reconnecting();
+///
+/// for event in subscription {
+///     match event? {
+///         SubscriptionEvent::Update(update) => println!("Received update: {:?}", update),
+///         SubscriptionEvent::Status(status) => println!("Status changed: {:?}", status),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`RequestRetryConfiguration`]: crate::RequestRetryConfiguration
+pub struct ReconnectingSubscription<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channels from which real-time updates should be received.
+    channels: Vec<String>,
+
+    /// Channel groups from which real-time updates should be received.
+    channel_groups: Vec<String>,
+
+    /// Time cursor.
+    cursor: SubscriptionCursor,
+
+    /// `user_id`presence timeout period.
+    heartbeat: u64,
+
+    /// Message filtering predicate.
+    filter_expression: Option<String>,
+
+    /// Failed subscribe requests retry policy.
+    retry_configuration: RequestRetryConfiguration,
+
+    /// Received and not yet consumed real-time updates.
+    updates: Updates,
+
+    /// Whether at least one subscribe request succeeded or not.
+    connected: bool,
+
+    /// Number of retry attempts since last successful subscribe request.
+    retry_attempt: u8,
+
+    /// Error which stopped subscription.
+    ///
+    /// Error reported after corresponding status change and ends iteration.
+    error: Option<PubNubError>,
+
+    /// Whether subscription has been stopped or not.
+    stopped: bool,
+}
+
+impl<T, D> RawSubscription<T, D> {
+    /// Convert into [`ReconnectingSubscription`].
+    ///
+    /// Failed subscribe requests will be retried according to the client's
+    /// retry configuration.
+    pub fn reconnecting(self) -> ReconnectingSubscription<T, D> {
+        let cursor = self
+            .cursor
+            .map(|tt| SubscriptionCursor {
+                timetoken: tt.to_string(),
+                region: 0,
+            })
+            .unwrap_or_default();
+        let retry_configuration = self.pubnub_client.retry_configuration();
+
+        ReconnectingSubscription {
+            pubnub_client: self.pubnub_client,
+            channels: self.channels,
+            channel_groups: self.channel_groups,
+            cursor,
+            heartbeat: self.heartbeat,
+            filter_expression: self.filter_expression,
+            retry_configuration,
+            updates: Updates::default(),
+            connected: false,
+            retry_attempt: 0,
+            error: None,
+            stopped: false,
+        }
+    }
+}
+
+impl<T, D> ReconnectingSubscription<T, D>
+where
+    T: blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Make subscribe request and buffer received real-time updates.
+    fn receive(&mut self) -> Result<(), PubNubError> {
+        let mut request = self
+            .pubnub_client
+            .subscribe_request()
+            .cursor(self.cursor.clone())
+            .channels(self.channels.clone())
+            .channel_groups(self.channel_groups.clone())
+            .heartbeat(self.heartbeat);
+
+        if let Some(filter_expr) = self.filter_expression.clone() {
+            request = request.filter_expression(filter_expr);
+        }
+
+        let response = request.execute_blocking()?;
+        self.cursor = response.cursor;
+        self.updates = response.messages;
+
+        Ok(())
+    }
+
+    /// Connection status after successful subscribe request.
+    fn connection_restored(&mut self) -> Option<ConnectionStatus> {
+        let status = if !self.connected {
+            Some(ConnectionStatus::Connected)
+        } else if self.retry_attempt > 0 {
+            Some(ConnectionStatus::Reconnected)
+        } else {
+            None
+        };
+
+        self.connected = true;
+        self.retry_attempt = 0;
+        status
+    }
+
+    /// Connection status after failed subscribe request which can't be
+    /// retried.
+    fn connection_lost(&mut self, error: PubNubError) -> ConnectionStatus {
+        self.stopped = true;
+        self.error = Some(error.clone());
+
+        if self.connected {
+            ConnectionStatus::DisconnectedUnexpectedly(error)
+        } else {
+            ConnectionStatus::ConnectionError(error)
+        }
+    }
+}
+
+impl<T, D> Iterator for ReconnectingSubscription<T, D>
+where
+    T: blocking::Transport,
+    D: Deserializer + 'static,
+{
+    type Item = Result<SubscriptionEvent, PubNubError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return self.error.take().map(Err);
+        }
+
+        loop {
+            if let Some(update) = self.updates.next() {
+                match update {
+                    Ok(update) => {
+                        let update = match &self.pubnub_client.cryptor {
+                            Some(cryptor) => update.decrypt(cryptor),
+                            None => update,
+                        };
+                        return Some(Ok(SubscriptionEvent::Update(update)));
+                    }
+                    Err(error) => {
+                        log::error!("Unable to decode real-time update: {error}");
+                        continue;
+                    }
+                }
+            }
+
+            let status = match self.receive() {
+                Ok(_) => self.connection_restored(),
+                Err(error) => {
+                    let Some(delay) = self.retry_configuration.retry_delay(
+                        Some("/v2/subscribe".to_string()),
+                        &self.retry_attempt,
+                        Some(&error),
+                    ) else {
+                        return Some(Ok(SubscriptionEvent::Status(self.connection_lost(error))));
+                    };
+
+                    self.retry_attempt += 1;
+                    self.pubnub_client.retry_notifier.notify(
+                        Endpoint::Subscribe,
+                        self.retry_attempt,
+                        delay,
+                        &error,
+                    );
+                    thread::sleep(Duration::from_micros(delay));
+                    None
+                }
+            };
+
+            if let Some(status) = status {
+                return Some(Ok(SubscriptionEvent::Status(status)));
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod should {
+    use super::*;
+    use crate::{
+        core::TransportResponse,
+        providers::deserialization_serde::DeserializerSerde,
+        test::{fixtures::SubscribeResponseFixture, MockTransport},
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+    use serde_json::json;
+
+    fn client(
+        transport: MockTransport,
+        retry_configuration: RequestRetryConfiguration,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("rust-test-user")
+            .with_retry_configuration(retry_configuration)
+            .build()
+            .unwrap()
+    }
+
+    fn server_error() -> TransportResponse {
+        TransportResponse {
+            status: 500,
+            body: Some(b"{}".to_vec().into()),
+            ..Default::default()
+        }
+    }
+
+    fn subscription(
+        transport: MockTransport,
+        retry_configuration: RequestRetryConfiguration,
+    ) -> ReconnectingSubscription<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        client(transport, retry_configuration)
+            .subscribe_raw()
+            .channels(["ch1"])
+            .execute_blocking()
+            .unwrap()
+            .reconnecting()
+    }
+
+    #[test]
+    fn report_connected_status_before_updates() {
+        let transport = MockTransport::new().with_response(
+            SubscribeResponseFixture::new("10")
+                .message("ch1", json!("one"))
+                .into_response(),
+        );
+        let mut subscription = subscription(transport, RequestRetryConfiguration::None);
+
+        assert!(matches!(
+            subscription.next(),
+            Some(Ok(SubscriptionEvent::Status(ConnectionStatus::Connected)))
+        ));
+        assert!(matches!(
+            subscription.next(),
+            Some(Ok(SubscriptionEvent::Update(Update::Message(_))))
+        ));
+    }
+
+    #[test]
+    fn retry_failed_request_and_report_reconnection() {
+        let transport = MockTransport::new()
+            .with_response(
+                SubscribeResponseFixture::new("10")
+                    .message("ch1", json!("one"))
+                    .into_response(),
+            )
+            .with_response(server_error())
+            .with_response(
+                SubscribeResponseFixture::new("20")
+                    .message("ch1", json!("two"))
+                    .into_response(),
+            );
+        let subscription = subscription(
+            transport.clone(),
+            RequestRetryConfiguration::Linear {
+                delay: 0,
+                max_retry: 2,
+                max_total_retry_duration: None,
+                excluded_endpoints: None,
+            },
+        );
+
+        let events = subscription.take(4).collect::<Vec<_>>();
+
+        assert!(matches!(
+            events[0],
+            Ok(SubscriptionEvent::Status(ConnectionStatus::Connected))
+        ));
+        assert!(matches!(events[1], Ok(SubscriptionEvent::Update(_))));
+        assert!(matches!(
+            events[2],
+            Ok(SubscriptionEvent::Status(ConnectionStatus::Reconnected))
+        ));
+        assert!(matches!(events[3], Ok(SubscriptionEvent::Update(_))));
+        assert!(transport
+            .last_request()
+            .unwrap()
+            .query_parameters
+            .get("tt")
+            .is_some_and(|tt| tt == "10"));
+    }
+
+    #[test]
+    fn stop_when_request_can_not_be_retried() {
+        let transport = MockTransport::new().with_response(server_error());
+        let mut subscription = subscription(transport, RequestRetryConfiguration::None);
+
+        assert!(matches!(
+            subscription.next(),
+            Some(Ok(SubscriptionEvent::Status(
+                ConnectionStatus::ConnectionError(_)
+            )))
+        ));
+        assert!(matches!(subscription.next(), Some(Err(_))));
+        assert!(subscription.next().is_none());
+    }
+}
@@ -5,10 +5,7 @@
 
 use derive_builder::Builder;
 #[cfg(feature = "std")]
-use futures::{
-    future::BoxFuture,
-    {select_biased, FutureExt},
-};
+use futures::{select_biased, FutureExt};
 
 use crate::{
     core::{
@@ -27,6 +24,7 @@ use crate::{
         alloc::{
             format,
             string::{String, ToString},
+            sync::Arc,
             vec::Vec,
         },
         collections::HashMap,
@@ -36,7 +34,12 @@ use crate::{
 #[cfg(all(feature = "presence", feature = "std"))]
 use crate::lib::alloc::vec;
 #[cfg(feature = "std")]
-use crate::{core::event_engine::cancel::CancellationTask, lib::alloc::sync::Arc};
+use crate::{
+    core::{event_engine::cancel::CancellationTask, maybe_send::BoxFuture},
+    lib::core::hash::{Hash, Hasher},
+};
+#[cfg(feature = "std")]
+use std::collections::hash_map::DefaultHasher;
 
 /// The [`SubscribeRequestBuilder`] is used to build subscribe request which
 /// will be used for real-time updates notification from the [`PubNub`] network.
@@ -63,7 +66,11 @@ pub(crate) struct SubscribeRequest<T, D> {
     ///
     /// List of channels on which [`PubNubClient`] will subscribe and notify
     /// about received real-time updates.
-    #[builder(field(vis = "pub(in crate::dx::subscribe)"), default = "Vec::new()")]
+    #[builder(
+        field(vis = "pub(in crate::dx::subscribe)"),
+        setter(custom),
+        default = "Vec::new()"
+    )]
     pub(in crate::dx::subscribe) channels: Vec<String>,
 
     /// Channel groups from which real-time updates should be received.
@@ -72,7 +79,7 @@ pub(crate) struct SubscribeRequest<T, D> {
     /// notify about received real-time updates.
     #[builder(
         field(vis = "pub(in crate::dx::subscribe)"),
-        setter(strip_option),
+        setter(custom),
         default = "Vec::new()"
     )]
     pub(in crate::dx::subscribe) channel_groups: Vec<String>,
@@ -145,7 +152,101 @@ pub(crate) struct SubscribeRequest<T, D> {
     pub(in crate::dx::subscribe) filter_expression: Option<String>,
 }
 
+impl_from_builder_error!(SubscribeRequestBuilderError);
+
+/// URL-encoded subscribe request channels and groups.
+///
+/// Subscribe loop sends requests with same list of channels and groups until
+/// subscription membership will change, so encoded lists are cached by
+/// [`PubNubClientInstance`] and re-used between long-poll cycles.
+#[derive(Debug)]
+pub(crate) struct EncodedSubscribeInput {
+    /// Hash of channels and groups lists which has been encoded.
+    ///
+    /// Used as fast pre-check before source lists comparison.
+    #[cfg(feature = "std")]
+    key: u64,
+
+    /// Channels list which has been encoded.
+    #[cfg(feature = "std")]
+    source_channels: Vec<String>,
+
+    /// Channel groups list which has been encoded.
+    #[cfg(feature = "std")]
+    source_channel_groups: Vec<String>,
+
+    /// URL-encoded channels path segment.
+    channels: String,
+
+    /// URL-encoded channel groups query parameter value.
+    channel_groups: Option<String>,
+}
+
+impl EncodedSubscribeInput {
+    /// Encode `channels` and `channel_groups` lists.
+    fn new(
+        #[cfg(feature = "std")] key: u64,
+        channels: &[String],
+        channel_groups: &[String],
+    ) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            key,
+            #[cfg(feature = "std")]
+            source_channels: channels.to_vec(),
+            #[cfg(feature = "std")]
+            source_channel_groups: channel_groups.to_vec(),
+            channels: url_encoded_channels(channels),
+            channel_groups: url_encoded_channel_groups(channel_groups),
+        }
+    }
+
+    /// Compute cache key for `channels` and `channel_groups` lists.
+    #[cfg(feature = "std")]
+    fn key(channels: &[String], channel_groups: &[String]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        channels.hash(&mut hasher);
+        channel_groups.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Check whether `channels` and `channel_groups` lists (with `key` hash)
+    /// has been encoded by this instance.
+    #[cfg(feature = "std")]
+    fn matches(&self, key: u64, channels: &[String], channel_groups: &[String]) -> bool {
+        self.key == key
+            && self.source_channels == channels
+            && self.source_channel_groups == channel_groups
+    }
+}
+
 impl<T, D> SubscribeRequestBuilder<T, D> {
+    /// Channels from which real-time updates should be received.
+    ///
+    /// List of channels on which [`PubNubClient`] will subscribe and notify
+    /// about received real-time updates.
+    pub fn channels<L, S>(mut self, channels: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Channel groups from which real-time updates should be received.
+    ///
+    /// List of groups of channels on which [`PubNubClient`] will subscribe and
+    /// notify about received real-time updates.
+    pub fn channel_groups<L, S>(mut self, channel_groups: L) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channel_groups = Some(channel_groups.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// A state that should be associated with the `user_id`.
     ///
     /// `state` object should be a `HashMap` with channel names as keys and
@@ -187,8 +288,7 @@ impl<T, D> SubscribeRequestBuilder<T, D> {
 
     /// Build [`HeartbeatRequest`] from builder.
     fn request(self) -> Result<SubscribeRequest<T, D>, PubNubError> {
-        self.build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+        self.build().map_err(PubNubError::from)
     }
 }
 
@@ -201,16 +301,19 @@ impl<T, D> SubscribeRequest<T, D> {
         let sub_key = &config.subscribe_key;
         let mut query: HashMap<String, String> = HashMap::new();
         query.extend::<HashMap<String, String>>(self.cursor.clone().into());
+        let encoded = self.encoded_channels();
 
         // Serialize list of channel groups and add into query parameters list.
-        url_encoded_channel_groups(&self.channel_groups)
+        encoded
+            .channel_groups
+            .clone()
             .and_then(|groups| query.insert("channel-group".into(), groups));
 
         #[cfg(feature = "presence")]
         if let Some(state) = self.state.as_ref() {
             let state_json =
                 String::from_utf8(state.clone()).map_err(|err| PubNubError::Serialization {
-                    details: err.to_string(),
+                    details: error_details!(err),
                 })?;
             query.insert("state".into(), state_json);
         }
@@ -221,24 +324,55 @@ impl<T, D> SubscribeRequest<T, D> {
             .and_then(|e| {
                 query.insert(
                     "filter-expr".into(),
-                    url_encode_extended(e.as_bytes(), UrlEncodeExtension::NonChannelPath),
+                    url_encode_extended(e.as_bytes(), UrlEncodeExtension::NonChannelPath)
+                        .into_owned(),
                 )
             });
 
         query.insert("heartbeat".into(), self.heartbeat.to_string());
 
         Ok(TransportRequest {
-            path: format!(
-                "/v2/subscribe/{sub_key}/{}/0",
-                url_encoded_channels(&self.channels)
-            ),
+            path: format!("/v2/subscribe/{sub_key}/{}/0", encoded.channels),
             query_parameters: query,
             method: TransportMethod::Get,
             #[cfg(feature = "std")]
-            timeout: config.transport.subscribe_request_timeout,
+            timeout: self.pubnub_client.subscribe_request_timeout(),
             ..Default::default()
         })
     }
+
+    /// URL-encoded channels and channel groups.
+    ///
+    /// Encoded lists are taken from the client's cache when channels and
+    /// groups didn't change since the last subscribe request.
+    #[cfg(feature = "std")]
+    fn encoded_channels(&self) -> Arc<EncodedSubscribeInput> {
+        let key = EncodedSubscribeInput::key(&self.channels, &self.channel_groups);
+        let cached = self.pubnub_client.subscribe_encoded_input.read().clone();
+        if let Some(encoded) =
+            cached.filter(|encoded| encoded.matches(key, &self.channels, &self.channel_groups))
+        {
+            return encoded;
+        }
+
+        let encoded = Arc::new(EncodedSubscribeInput::new(
+            key,
+            &self.channels,
+            &self.channel_groups,
+        ));
+        *self.pubnub_client.subscribe_encoded_input.write() = Some(encoded.clone());
+
+        encoded
+    }
+
+    /// URL-encoded channels and channel groups.
+    #[cfg(not(feature = "std"))]
+    fn encoded_channels(&self) -> Arc<EncodedSubscribeInput> {
+        Arc::new(EncodedSubscribeInput::new(
+            &self.channels,
+            &self.channel_groups,
+        ))
+    }
 }
 
 impl<T, D> SubscribeRequestBuilder<T, D>
@@ -258,9 +392,11 @@ where
                 &client.transport,
                 deserializer,
                 #[cfg(feature = "std")]
-                &client.config.transport.retry_configuration,
+                &client.retry_configuration(),
                 #[cfg(feature = "std")]
                 &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
             )
             .await
     }
@@ -268,6 +404,8 @@ where
     /// Build and call asynchronous request after delay.
     ///
     /// Perform delayed request call with ability to cancel it before call.
+    /// Cancelled in-flight request is dropped along with transport's `send`
+    /// future.
     #[cfg(feature = "std")]
     pub async fn execute_with_cancel_and_delay<F>(
         self,
@@ -308,9 +446,7 @@ where
     /// Build and call synchronous request.
     pub fn execute_blocking(self) -> Result<SubscribeResult, PubNubError> {
         // Build request instance and report errors if any.
-        let request = self
-            .build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+        let request = self.build().map_err(PubNubError::from)?;
 
         let transport_request = request.transport_request()?;
         let client = request.pubnub_client.clone();
@@ -324,14 +460,36 @@ where
 #[cfg(test)]
 mod should {
     use super::*;
-    use crate::{core::TransportResponse, PubNubClientBuilder};
+    use crate::{
+        core::TransportResponse, providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware, PubNubClientBuilder,
+    };
     use futures::future::ready;
 
+    struct MockTransport;
+
+    impl Transport for MockTransport {
+        async fn send(&self, _req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            Ok(TransportResponse::default())
+        }
+    }
+
+    fn client() -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(crate::Keyset {
+                subscribe_key: "test",
+                publish_key: Some("test"),
+                secret_key: None,
+            })
+            .with_user_id("test")
+            .build()
+            .unwrap()
+    }
+
     #[tokio::test]
     async fn be_able_to_cancel_subscribe_call() {
         struct MockTransport;
 
-        #[async_trait::async_trait]
         impl Transport for MockTransport {
             async fn send(&self, _req: TransportRequest) -> Result<TransportResponse, PubNubError> {
                 tokio::time::sleep(tokio::time::Duration::from_secs(5)).await; // Simulate long request.
@@ -356,10 +514,122 @@ mod should {
             .build()
             .unwrap()
             .subscribe_request()
-            .channels(vec!["test".into()])
+            .channels(["test"])
             .execute_with_cancel_and_delay(Arc::new(|| ready(()).boxed()), cancel_task)
             .await;
 
         assert!(matches!(result, Err(PubNubError::EffectCanceled)));
     }
+
+    #[tokio::test]
+    async fn drop_in_flight_request_when_cancelled() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        struct InFlight(Arc<AtomicBool>);
+
+        impl Drop for InFlight {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        struct MockTransport {
+            started: async_channel::Sender<()>,
+            dropped: Arc<AtomicBool>,
+        }
+
+        impl Transport for MockTransport {
+            async fn send(&self, _req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+                let _in_flight = InFlight(self.dropped.clone());
+                self.started.send(()).await.ok();
+                tokio::time::sleep(tokio::time::Duration::from_secs(5)).await; // Simulate long-poll.
+
+                Ok(TransportResponse::default())
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let (started_tx, started_rx) = async_channel::bounded(1);
+        let (tx, rx) = async_channel::bounded(1);
+        let cancel_task = CancellationTask::new(rx, "test".into());
+
+        let client = PubNubClientBuilder::with_transport(MockTransport {
+            started: started_tx,
+            dropped: dropped.clone(),
+        })
+        .with_keyset(crate::Keyset {
+            subscribe_key: "test",
+            publish_key: Some("test"),
+            secret_key: None,
+        })
+        .with_user_id("test")
+        .build()
+        .unwrap();
+        let call = client
+            .subscribe_request()
+            .channels(["test"])
+            .execute_with_cancel_and_delay(Arc::new(|| ready(()).boxed()), cancel_task);
+        let cancel = async {
+            started_rx.recv().await.unwrap();
+            tx.send("test".into()).await.unwrap();
+        };
+
+        let (result, _) = tokio::time::timeout(
+            tokio::time::Duration::from_secs(1),
+            futures::future::join(call, cancel),
+        )
+        .await
+        .expect("Request should be cancelled without waiting for response");
+
+        assert!(matches!(result, Err(PubNubError::EffectCanceled)));
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn reuse_encoded_channels_until_they_change() {
+        let client = client();
+        let request = |channels: &[&str]| {
+            client
+                .subscribe_request()
+                .channels(channels.iter().copied())
+                .channel_groups(["group a"])
+                .request()
+                .unwrap()
+        };
+
+        let encoded = request(&["channel a", "b"]).encoded_channels();
+        assert_eq!(encoded.channels, "channel%20a,b");
+        assert_eq!(encoded.channel_groups, Some("group%20a".into()));
+        assert!(Arc::ptr_eq(
+            &encoded,
+            &request(&["channel a", "b"]).encoded_channels()
+        ));
+
+        let changed = request(&["c"]).encoded_channels();
+        assert!(!Arc::ptr_eq(&encoded, &changed));
+        assert_eq!(changed.channels, "c");
+        assert_eq!(
+            request(&["c"]).transport_request().unwrap().path,
+            "/v2/subscribe/test/c/0"
+        );
+    }
+
+    #[test]
+    fn not_reuse_encoded_channels_with_same_hash() {
+        let client = client();
+        let request = client
+            .subscribe_request()
+            .channels(["b"])
+            .request()
+            .unwrap();
+
+        // Cached lists with colliding hash.
+        let key = EncodedSubscribeInput::key(&request.channels, &request.channel_groups);
+        let collision = Arc::new(EncodedSubscribeInput::new(key, &["a".into()], &[]));
+        *client.subscribe_encoded_input.write() = Some(collision.clone());
+
+        let encoded = request.encoded_channels();
+        assert!(!Arc::ptr_eq(&encoded, &collision));
+        assert_eq!(encoded.channels, "b");
+    }
 }
@@ -4,14 +4,19 @@
 //! [`PubNubClientInstance`], [`Subscription`] and [`SubscriptionSet`] to let
 //! users attach listeners to the specific event types.
 
-use spin::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use arc_swap::ArcSwap;
 use std::fmt::Debug;
 
 use crate::{
     core::DataStream,
     lib::{
-        alloc::{collections::VecDeque, vec::Vec},
-        core::{default::Default, ops::Drop},
+        alloc::{collections::VecDeque, sync::Arc, vec::Vec},
+        core::{
+            default::Default,
+            ops::Drop,
+            sync::atomic::{AtomicBool, Ordering},
+        },
+        sync::RwLock,
     },
     subscribe::{
         AppContext, ConnectionStatus, EventEmitter, File, Message, MessageAction, Presence,
@@ -19,35 +24,41 @@ use crate::{
     },
 };
 
+/// List of listener streams.
+///
+/// List is replaced with an updated copy when a listener is added, so event
+/// delivery reads a snapshot without taking locks.
+type Streams<S> = ArcSwap<Vec<DataStream<S>>>;
+
 #[derive(Debug)]
 pub(crate) struct EventDispatcher {
     /// Whether listener streams has been created or not.
-    has_streams: RwLock<bool>,
+    has_streams: AtomicBool,
 
     /// A collection of data streams for message events.
     ///
     /// This struct holds a vector of `DataStream<Message>` instances, which
     /// provide a way to handle message events in a streaming fashion.
-    pub(crate) message_streams: RwLock<Option<Vec<DataStream<Message>>>>,
+    pub(crate) message_streams: Streams<Message>,
 
     /// A collection of data streams for signal events.
     ///
     /// This struct holds a vector of `DataStream<Message>` instances, which
     /// provide a way to handle signal events in a streaming fashion.
-    pub(crate) signal_streams: RwLock<Option<Vec<DataStream<Message>>>>,
+    pub(crate) signal_streams: Streams<Message>,
 
     /// A collection of data streams for message reaction events.
     ///
     /// This struct holds a vector of `DataStream<MessageAction>` instances,
     /// which provide a way to handle message reactions events in a streaming
     /// fashion.
-    pub(crate) message_reaction_streams: RwLock<Option<Vec<DataStream<MessageAction>>>>,
+    pub(crate) message_reaction_streams: Streams<MessageAction>,
 
     /// A collection of data streams for file events.
     ///
     /// This struct holds a vector of `DataStream<File>` instances, which
     /// provide a way to handle file events in a streaming fashion.
-    pub(crate) file_streams: RwLock<Option<Vec<DataStream<File>>>>,
+    pub(crate) file_streams: Streams<File>,
 
     /// A collection of data streams for application context (Channel and User)
     /// events.
@@ -55,26 +66,28 @@ pub(crate) struct EventDispatcher {
     /// This struct holds a vector of `DataStream<AppContext>` instances,
     /// which allow for handling application context events in a streaming
     /// fashion.
-    pub(crate) app_context_streams: RwLock<Option<Vec<DataStream<AppContext>>>>,
+    pub(crate) app_context_streams: Streams<AppContext>,
 
     /// A collection of data streams for presence events.
     ///
     /// This struct holds a vector of `DataStream<Presence>` instances, which
     /// provide a way to handle presence events in a streaming fashion.
-    pub(crate) presence_streams: RwLock<Option<Vec<DataStream<Presence>>>>,
+    pub(crate) presence_streams: Streams<Presence>,
 
     /// A collection of data streams for connection status change events.
     ///
     /// This struct holds a vector of `DataStream<ConnectionStatus>` instances,
     /// which provide a way to handle connection status change events in a
     /// streaming fashion.
-    pub(crate) status_streams: RwLock<Option<Vec<DataStream<ConnectionStatus>>>>,
+    pub(crate) status_streams: Streams<ConnectionStatus>,
 
     /// A collection of data streams for update events.
     ///
-    /// This struct holds a vector of `DataStream<Update>` instances, which
-    /// provide a way to handle update events in a streaming fashion.
-    pub(crate) streams: RwLock<Option<Vec<DataStream<Update>>>>,
+    /// This struct holds a vector of `DataStream<Arc<Update>>` instances,
+    /// which provide a way to handle update events in a streaming fashion.
+    /// Updates are shared between streams, so each listener receives a
+    /// pointer copy instead of a deep clone.
+    pub(crate) streams: Streams<Arc<Update>>,
 
     /// List of updates to be delivered to stream listener.
     pub(crate) updates: RwLock<VecDeque<SubscribeStreamEvent>>,
@@ -95,7 +108,7 @@ impl EventDispatcher {
     /// streams.
     pub(crate) fn new() -> Self {
         Self {
-            has_streams: Default::default(),
+            has_streams: AtomicBool::new(false),
             message_streams: Default::default(),
             signal_streams: Default::default(),
             message_reaction_streams: Default::default(),
@@ -113,64 +126,68 @@ impl EventDispatcher {
             _ => None,
         });
 
-        self.create_stream_in_list(self.status_streams.write(), statuses)
+        self.create_stream_in_list(&self.status_streams, statuses)
     }
 
     /// Dispatch received connection status change.
     ///
     /// Dispatch events to the designated stream types.
     pub fn handle_status(&self, status: ConnectionStatus) {
-        if !*self.has_streams.read() {
+        if !self.has_streams.load(Ordering::Acquire) {
             let mut updates_slot = self.updates.write();
             updates_slot.push_back(SubscribeStreamEvent::Status(status));
             return;
         }
 
-        self.push_event_to_stream(&status, &self.status_streams.read());
+        self.push_event_to_stream(&status, &self.status_streams.load());
     }
 
     /// Dispatch received updates.
     ///
     /// Dispatch events to the designated stream types.
-    pub fn handle_events(&self, events: Vec<Update>) {
-        if !*self.has_streams.read() {
+    pub fn handle_events(&self, events: &[Arc<Update>]) {
+        if !self.has_streams.load(Ordering::Acquire) {
             let mut updates_slot = self.updates.write();
-            updates_slot.extend(events.into_iter().map(SubscribeStreamEvent::Update));
+            updates_slot.extend(
+                events
+                    .iter()
+                    .map(|event| SubscribeStreamEvent::Update(event.as_ref().clone())),
+            );
             return;
         }
 
-        let message_streams = self.message_streams.read();
-        let signal_streams = self.signal_streams.read();
-        let message_reactions_streams = self.message_reaction_streams.read();
-        let file_streams = self.file_streams.read();
-        let app_context_streams = self.app_context_streams.read();
-        let presence_streams = self.presence_streams.read();
-        let streams = self.streams.read();
+        let message_streams = self.message_streams.load();
+        let signal_streams = self.signal_streams.load();
+        let message_reactions_streams = self.message_reaction_streams.load();
+        let file_streams = self.file_streams.load();
+        let app_context_streams = self.app_context_streams.load();
+        let presence_streams = self.presence_streams.load();
+        let streams = self.streams.load();
 
         for event in events {
-            match event.clone() {
-                Update::Message(message) if message_streams.is_some() => {
-                    self.push_event_to_stream(&message, &message_streams)
+            match event.as_ref() {
+                Update::Message(message) if !message_streams.is_empty() => {
+                    self.push_event_to_stream(message, &message_streams)
                 }
-                Update::Signal(signal) if signal_streams.is_some() => {
-                    self.push_event_to_stream(&signal, &signal_streams)
+                Update::Signal(signal) if !signal_streams.is_empty() => {
+                    self.push_event_to_stream(signal, &signal_streams)
                 }
-                Update::MessageAction(action) if message_reactions_streams.is_some() => {
-                    self.push_event_to_stream(&action, &message_reactions_streams)
+                Update::MessageAction(action) if !message_reactions_streams.is_empty() => {
+                    self.push_event_to_stream(action, &message_reactions_streams)
                 }
-                Update::File(file) if file_streams.is_some() => {
-                    self.push_event_to_stream(&file, &file_streams)
+                Update::File(file) if !file_streams.is_empty() => {
+                    self.push_event_to_stream(file, &file_streams)
                 }
-                Update::AppContext(object) if app_context_streams.is_some() => {
-                    self.push_event_to_stream(&object, &app_context_streams)
+                Update::AppContext(object) if !app_context_streams.is_empty() => {
+                    self.push_event_to_stream(object, &app_context_streams)
                 }
-                Update::Presence(presence) if presence_streams.is_some() => {
-                    self.push_event_to_stream(&presence, &presence_streams)
+                Update::Presence(presence) if !presence_streams.is_empty() => {
+                    self.push_event_to_stream(presence, &presence_streams)
                 }
                 _ => {}
             }
 
-            self.push_event_to_stream(&event, &streams);
+            self.push_event_to_stream(event, &streams);
         }
     }
 
@@ -178,33 +195,32 @@ impl EventDispatcher {
     ///
     /// # Arguments
     ///
-    /// - `streams`: A mutable reference to an `Option<Vec<DataStream<S>>>`,
-    ///   representing the list of streams.
+    /// - `streams`: A reference to the list of streams. List is replaced with
+    ///   its copy which contains new stream.
     ///
     /// # Returns
     ///
     /// Returns the newly created `DataStream<S>`.
     fn create_stream_in_list<S>(
         &self,
-        mut streams: RwLockWriteGuard<Option<Vec<DataStream<S>>>>,
+        streams: &Streams<S>,
         data: Option<VecDeque<S>>,
     ) -> DataStream<S>
     where
         S: Debug,
     {
-        let mut has_streams_slot = self.has_streams.write();
-        *has_streams_slot = true;
         let stream = if let Some(data) = data {
             DataStream::with_queue_data(data, 100)
         } else {
             DataStream::new()
         };
 
-        if let Some(streams) = streams.as_mut() {
-            streams.push(stream.clone())
-        } else {
-            *streams = Some(vec![stream.clone()])
-        }
+        streams.rcu(|streams| {
+            let mut streams = Vec::clone(streams);
+            streams.push(stream.clone());
+            streams
+        });
+        self.has_streams.store(true, Ordering::Release);
 
         stream
     }
@@ -214,19 +230,11 @@ impl EventDispatcher {
     /// # Arguments
     ///
     /// * `event` - A reference to the event to be pushed to the streams.
-    /// * `streams` - A read-only lock guard that provides access to the option
-    ///   containing the list of data streams.
-    fn push_event_to_stream<S>(
-        &self,
-        event: &S,
-        streams: &RwLockReadGuard<Option<Vec<DataStream<S>>>>,
-    ) where
+    /// * `streams` - A snapshot of the list of data streams.
+    fn push_event_to_stream<S>(&self, event: &S, streams: &[DataStream<S>])
+    where
         S: Clone,
     {
-        let Some(streams) = streams.as_ref() else {
-            return;
-        };
-
         streams
             .iter()
             .for_each(|stream| stream.push_data(event.clone()))
@@ -287,51 +295,26 @@ impl EventDispatcher {
 
     /// Invalidates all streams in the instance.
     pub(crate) fn invalidate(&self) {
-        let mut has_streams_slot = self.has_streams.write();
-        if !*has_streams_slot {
+        if !self.has_streams.swap(false, Ordering::AcqRel) {
             return;
         }
-        *has_streams_slot = false;
-
-        if let Some(streams) = self.message_streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
-
-        if let Some(streams) = self.signal_streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
-
-        if let Some(streams) = self.message_reaction_streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
-
-        if let Some(streams) = self.file_streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
-
-        if let Some(streams) = self.app_context_streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
-
-        if let Some(streams) = self.presence_streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
 
-        if let Some(streams) = self.status_streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
+        Self::invalidate_streams(&self.message_streams);
+        Self::invalidate_streams(&self.signal_streams);
+        Self::invalidate_streams(&self.message_reaction_streams);
+        Self::invalidate_streams(&self.file_streams);
+        Self::invalidate_streams(&self.app_context_streams);
+        Self::invalidate_streams(&self.presence_streams);
+        Self::invalidate_streams(&self.status_streams);
+        Self::invalidate_streams(&self.streams);
+    }
 
-        if let Some(streams) = self.streams.write().as_mut() {
-            streams.iter_mut().for_each(|stream| stream.invalidate());
-            streams.clear();
-        }
+    /// Invalidates and removes all streams from the list.
+    fn invalidate_streams<S>(streams: &Streams<S>) {
+        streams
+            .swap(Default::default())
+            .iter()
+            .for_each(|stream| stream.invalidate());
     }
 }
 
@@ -354,7 +337,7 @@ impl EventEmitter for EventDispatcher {
             _ => None,
         });
 
-        self.create_stream_in_list(self.message_streams.write(), messages)
+        self.create_stream_in_list(&self.message_streams, messages)
     }
 
     fn signals_stream(&self) -> DataStream<Message> {
@@ -363,7 +346,7 @@ impl EventEmitter for EventDispatcher {
             _ => None,
         });
 
-        self.create_stream_in_list(self.signal_streams.write(), signals)
+        self.create_stream_in_list(&self.signal_streams, signals)
     }
 
     fn message_actions_stream(&self) -> DataStream<MessageAction> {
@@ -372,7 +355,7 @@ impl EventEmitter for EventDispatcher {
             _ => None,
         });
 
-        self.create_stream_in_list(self.message_reaction_streams.write(), reactions)
+        self.create_stream_in_list(&self.message_reaction_streams, reactions)
     }
 
     fn files_stream(&self) -> DataStream<File> {
@@ -381,7 +364,7 @@ impl EventEmitter for EventDispatcher {
             _ => None,
         });
 
-        self.create_stream_in_list(self.file_streams.write(), files)
+        self.create_stream_in_list(&self.file_streams, files)
     }
 
     fn app_context_stream(&self) -> DataStream<AppContext> {
@@ -392,7 +375,7 @@ impl EventEmitter for EventDispatcher {
             _ => None,
         });
 
-        self.create_stream_in_list(self.app_context_streams.write(), app_context)
+        self.create_stream_in_list(&self.app_context_streams, app_context)
     }
 
     fn presence_stream(&self) -> DataStream<Presence> {
@@ -401,16 +384,16 @@ impl EventEmitter for EventDispatcher {
             _ => None,
         });
 
-        self.create_stream_in_list(self.presence_streams.write(), presence)
+        self.create_stream_in_list(&self.presence_streams, presence)
     }
 
-    fn stream(&self) -> DataStream<Update> {
+    fn stream(&self) -> DataStream<Arc<Update>> {
         let updates = self.dequeue_matching_events(|event| match event {
-            SubscribeStreamEvent::Update(update) => Some(update.clone()),
+            SubscribeStreamEvent::Update(update) => Some(Arc::new(update.clone())),
             _ => None,
         });
 
-        self.create_stream_in_list(self.streams.write(), updates)
+        self.create_stream_in_list(&self.streams, updates)
     }
 }
 
@@ -422,14 +405,14 @@ mod it_should {
     use super::*;
     use crate::core::PubNubError;
 
-    fn events() -> Vec<Update> {
-        vec![
+    fn events() -> Vec<Arc<Update>> {
+        [
             Update::Message(Message {
                 sender: Some("test-user-a".into()),
                 timestamp: 0,
                 channel: "test-channel".to_string(),
                 subscription: "test-channel".to_string(),
-                data: "Test message 1".to_string().into_bytes(),
+                data: "Test message 1".to_string().into_bytes().into(),
                 r#type: None,
                 space_id: None,
                 decryption_error: None,
@@ -439,7 +422,7 @@ mod it_should {
                 timestamp: 0,
                 channel: "test-channel".to_string(),
                 subscription: "test-channel".to_string(),
-                data: "Test signal 1".to_string().into_bytes(),
+                data: "Test signal 1".to_string().into_bytes().into(),
                 r#type: None,
                 space_id: None,
                 decryption_error: None,
@@ -458,18 +441,21 @@ mod it_should {
                 timestamp: 0,
                 channel: "test-channel".to_string(),
                 subscription: "test-channel".to_string(),
-                data: "Test message 2".to_string().into_bytes(),
+                data: "Test message 2".to_string().into_bytes().into(),
                 r#type: None,
                 space_id: None,
                 decryption_error: None,
             }),
         ]
+        .into_iter()
+        .map(Arc::new)
+        .collect()
     }
 
     #[test]
     fn create_event_dispatcher() {
         let dispatcher = EventDispatcher::new();
-        assert!(!*dispatcher.has_streams.read());
+        assert!(!dispatcher.has_streams.load(Ordering::Acquire));
     }
 
     #[test]
@@ -478,7 +464,7 @@ mod it_should {
         let events = events();
 
         dispatcher.handle_status(ConnectionStatus::Connected);
-        dispatcher.handle_events(events.clone());
+        dispatcher.handle_events(&events);
 
         assert_eq!(dispatcher.updates.read().len(), events.len() + 1);
     }
@@ -489,7 +475,7 @@ mod it_should {
         let events = events();
 
         dispatcher.handle_status(ConnectionStatus::Connected);
-        dispatcher.handle_events(events);
+        dispatcher.handle_events(&events);
 
         let mut events_count = 0;
         let mut stream = dispatcher.messages_stream().take(10);
@@ -547,4 +533,112 @@ mod it_should {
         assert_eq!(events_count, 0);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn share_message_payload_between_listener_streams() {
+        let dispatcher = EventDispatcher::new();
+        let mut stream_a = dispatcher.messages_stream();
+        let mut stream_b = dispatcher.messages_stream();
+
+        dispatcher.handle_events(&events());
+
+        let message_a = timeout(Duration::from_millis(500), stream_a.next())
+            .await
+            .unwrap()
+            .unwrap();
+        let message_b = timeout(Duration::from_millis(500), stream_b.next())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message_a.data, message_b.data);
+        assert_eq!(message_a.data.as_ptr(), message_b.data.as_ptr());
+    }
+
+    #[tokio::test]
+    async fn share_updates_between_listener_streams() {
+        let dispatcher = EventDispatcher::new();
+        let mut stream_a = dispatcher.stream();
+        let mut stream_b = dispatcher.stream();
+        let events = events();
+
+        dispatcher.handle_events(&events);
+
+        let update_a = timeout(Duration::from_millis(500), stream_a.next())
+            .await
+            .unwrap()
+            .unwrap();
+        let update_b = timeout(Duration::from_millis(500), stream_b.next())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&update_a, &events[0]));
+        assert!(Arc::ptr_eq(&update_a, &update_b));
+    }
+
+    #[test]
+    fn add_listeners_while_events_delivered() {
+        let dispatcher = Arc::new(EventDispatcher::new());
+        let _stream = dispatcher.messages_stream();
+        let events = events();
+
+        let delivery_dispatcher = dispatcher.clone();
+        let delivery = std::thread::spawn(move || {
+            (0..200).for_each(|_| delivery_dispatcher.handle_events(&events));
+        });
+        let streams = (0..50)
+            .map(|_| dispatcher.messages_stream())
+            .collect::<Vec<_>>();
+        delivery.join().unwrap();
+
+        assert_eq!(dispatcher.message_streams.load().len(), streams.len() + 1);
+
+        dispatcher.invalidate();
+        assert!(dispatcher.message_streams.load().is_empty());
+    }
+
+    #[cfg(feature = "derive")]
+    #[tokio::test]
+    async fn dequeue_typed_messages_into_typed_stream() {
+        #[derive(crate::PubNubMessage, serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+        #[pubnub(message_type = "chat-message")]
+        struct ChatMessage {
+            text: String,
+        }
+
+        let message = |r#type: Option<&str>, data: &str| {
+            Arc::new(Update::Message(Message {
+                channel: "test-channel".to_string(),
+                subscription: "test-channel".to_string(),
+                data: data.as_bytes().to_vec().into(),
+                r#type: r#type.map(String::from),
+                ..Default::default()
+            }))
+        };
+        let dispatcher = EventDispatcher::new();
+        dispatcher.handle_events(&[
+            message(None, "{\"text\":\"untyped\"}"),
+            message(Some("chat-message"), "\"malformed\""),
+            message(Some("other-message"), "{\"text\":\"other\"}"),
+            message(Some("chat-message"), "{\"text\":\"hello\"}"),
+        ]);
+
+        let mut stream = dispatcher.typed_messages_stream::<ChatMessage>();
+        let received = timeout(Duration::from_millis(500), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            received.data,
+            ChatMessage {
+                text: "hello".into()
+            }
+        );
+        assert_eq!(received.message.r#type, Some("chat-message".into()));
+        assert!(timeout(Duration::from_millis(100), stream.next())
+            .await
+            .is_err());
+    }
 }
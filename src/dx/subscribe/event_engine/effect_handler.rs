@@ -1,10 +1,9 @@
+use crate::lib::sync::RwLock;
 use async_channel::Sender;
-use spin::rwlock::RwLock;
-use uuid::Uuid;
 
 use crate::core::RequestRetryConfiguration;
 use crate::{
-    core::event_engine::EffectHandler,
+    core::{event_engine::EffectHandler, IdProvider},
     dx::subscribe::event_engine::{
         effects::{EmitMessagesEffectExecutor, EmitStatusEffectExecutor, SubscribeEffectExecutor},
         SubscribeEffect, SubscribeEffectInvocation,
@@ -30,10 +29,16 @@ pub(crate) struct SubscribeEffectHandler {
     emit_messages: Arc<EmitMessagesEffectExecutor>,
 
     /// Retry policy.
-    retry_policy: RequestRetryConfiguration,
+    ///
+    /// Shared with client, so retry policy changes will be used with next
+    /// created effects.
+    retry_policy: Arc<RwLock<RequestRetryConfiguration>>,
 
     /// Cancellation channel.
     cancellation_channel: Sender<String>,
+
+    /// Effect identifiers provider.
+    id_provider: Arc<dyn IdProvider>,
 }
 
 impl SubscribeEffectHandler {
@@ -42,8 +47,9 @@ impl SubscribeEffectHandler {
         subscribe_call: Arc<SubscribeEffectExecutor>,
         emit_status: Arc<EmitStatusEffectExecutor>,
         emit_messages: Arc<EmitMessagesEffectExecutor>,
-        retry_policy: RequestRetryConfiguration,
+        retry_policy: Arc<RwLock<RequestRetryConfiguration>>,
         cancellation_channel: Sender<String>,
+        id_provider: Arc<dyn IdProvider>,
     ) -> Self {
         Self {
             subscribe_call,
@@ -51,6 +57,7 @@ impl SubscribeEffectHandler {
             emit_messages,
             retry_policy,
             cancellation_channel,
+            id_provider,
         }
     }
 }
@@ -60,7 +67,7 @@ impl EffectHandler<SubscribeEffectInvocation, SubscribeEffect> for SubscribeEffe
         match invocation {
             SubscribeEffectInvocation::Handshake { input, cursor } => {
                 Some(SubscribeEffect::Handshake {
-                    id: Uuid::new_v4().to_string(),
+                    id: self.id_provider.next_id(),
                     cancelled: RwLock::new(false),
                     input: input.clone(),
                     cursor: cursor.clone(),
@@ -74,19 +81,19 @@ impl EffectHandler<SubscribeEffectInvocation, SubscribeEffect> for SubscribeEffe
                 attempts,
                 reason,
             } => Some(SubscribeEffect::HandshakeReconnect {
-                id: Uuid::new_v4().to_string(),
+                id: self.id_provider.next_id(),
                 cancelled: RwLock::new(false),
                 input: input.clone(),
                 cursor: cursor.clone(),
                 attempts: *attempts,
                 reason: reason.clone(),
-                retry_policy: self.retry_policy.clone(),
+                retry_policy: self.retry_policy.read().clone(),
                 executor: self.subscribe_call.clone(),
                 cancellation_channel: self.cancellation_channel.clone(),
             }),
             SubscribeEffectInvocation::Receive { input, cursor } => {
                 Some(SubscribeEffect::Receive {
-                    id: Uuid::new_v4().to_string(),
+                    id: self.id_provider.next_id(),
                     cancelled: RwLock::new(false),
                     input: input.clone(),
                     cursor: cursor.clone(),
@@ -100,24 +107,24 @@ impl EffectHandler<SubscribeEffectInvocation, SubscribeEffect> for SubscribeEffe
                 attempts,
                 reason,
             } => Some(SubscribeEffect::ReceiveReconnect {
-                id: Uuid::new_v4().to_string(),
+                id: self.id_provider.next_id(),
                 cancelled: RwLock::new(false),
                 input: input.clone(),
                 cursor: cursor.clone(),
                 attempts: *attempts,
                 reason: reason.clone(),
-                retry_policy: self.retry_policy.clone(),
+                retry_policy: self.retry_policy.read().clone(),
                 executor: self.subscribe_call.clone(),
                 cancellation_channel: self.cancellation_channel.clone(),
             }),
             SubscribeEffectInvocation::EmitStatus(status) => Some(SubscribeEffect::EmitStatus {
-                id: Uuid::new_v4().to_string(),
+                id: self.id_provider.next_id(),
                 status: status.clone(),
                 executor: self.emit_status.clone(),
             }),
             SubscribeEffectInvocation::EmitMessages(messages, cursor) => {
                 Some(SubscribeEffect::EmitMessages {
-                    id: Uuid::new_v4().to_string(),
+                    id: self.id_provider.next_id(),
                     next_cursor: cursor.clone(),
                     updates: messages.clone(),
                     executor: self.emit_messages.clone(),
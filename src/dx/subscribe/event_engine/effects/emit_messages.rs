@@ -1,9 +1,9 @@
-use log::info;
+use crate::lib::log::info;
 
 use crate::{
     dx::subscribe::{
         event_engine::{effects::EmitMessagesEffectExecutor, SubscribeEvent},
-        result::Update,
+        result::Updates,
         SubscriptionCursor,
     },
     lib::alloc::{sync::Arc, vec, vec::Vec},
@@ -11,7 +11,7 @@ use crate::{
 
 pub(super) async fn execute(
     cursor: SubscriptionCursor,
-    updates: Vec<Update>,
+    updates: Updates,
     executor: &Arc<EmitMessagesEffectExecutor>,
 ) -> Vec<SubscribeEvent> {
     info!("Emit updates: {updates:?}");
@@ -24,35 +24,29 @@ pub(super) async fn execute(
 #[cfg(test)]
 mod should {
     use super::*;
-    use crate::dx::subscribe::types::Message;
+    use crate::dx::subscribe::result::Update;
 
     #[tokio::test]
     async fn emit_expected_status() {
-        let message = Message {
-            sender: Some("test-user".into()),
-            timestamp: 1234567890,
-            channel: "test".to_string(),
-            subscription: "test-group".to_string(),
-            data: vec![],
-            r#type: None,
-            space_id: None,
-            decryption_error: None,
-        };
-
-        let emit_message_function: Arc<EmitMessagesEffectExecutor> = Arc::new(|updates, _| {
-            let emitted_update = updates.first().expect("update should be passed");
-            assert!(matches!(emitted_update, Update::Message(_)));
-
-            if let Update::Message(message) = emitted_update {
-                assert_eq!(*message, message.clone());
-            }
-        });
-
-        execute(
-            Default::default(),
-            vec![Update::Message(message.clone())],
-            &emit_message_function,
+        let updates: Updates = serde_json::from_str(
+            r#"[{"a":"1","f":0,"i":"test-user","p":{"t":"1234567890","r":31},"c":"test","b":"test-group","d":"hello"}]"#,
         )
-        .await;
+        .unwrap();
+
+        let emit_message_function: Arc<EmitMessagesEffectExecutor> =
+            Arc::new(|mut updates: Updates, _| {
+                let emitted_update = updates
+                    .next()
+                    .expect("update should be passed")
+                    .expect("update should be decoded");
+
+                let Update::Message(message) = emitted_update else {
+                    panic!("Expected message update");
+                };
+                assert_eq!(message.sender, Some("test-user".into()));
+                assert_eq!(message.subscription, "test-group");
+            });
+
+        execute(Default::default(), updates, &emit_message_function).await;
     }
 }
@@ -1,3 +1,4 @@
+use crate::lib::log::info;
 use crate::{
     dx::subscribe::{
         event_engine::{effects::EmitStatusEffectExecutor, SubscribeEvent},
@@ -5,7 +6,6 @@ use crate::{
     },
     lib::alloc::{sync::Arc, vec, vec::Vec},
 };
-use log::info;
 
 pub(super) async fn execute(
     status: ConnectionStatus,
@@ -1,5 +1,5 @@
+use crate::lib::log::{self, info};
 use futures::TryFutureExt;
-use log::info;
 
 use crate::{
     core::{PubNubError, RequestRetryConfiguration},
@@ -86,7 +86,7 @@ mod should {
             assert_eq!(
                 params.reason.unwrap(),
                 PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -98,7 +98,8 @@ mod should {
             async move {
                 Ok(SubscribeResult {
                     cursor: Default::default(),
-                    messages: vec![],
+                    messages: Default::default(),
+                    metadata: Default::default(),
                 })
             }
             .boxed()
@@ -112,7 +113,7 @@ mod should {
             &None,
             1,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -122,6 +123,7 @@ mod should {
             &RequestRetryConfiguration::Linear {
                 delay: 0,
                 max_retry: 1,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             },
             &mock_handshake_function,
@@ -140,7 +142,7 @@ mod should {
         let mock_handshake_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -158,12 +160,13 @@ mod should {
             &None,
             11,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: None,
             },
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 10,
+                max_total_retry_duration: None,
                 delay: 0,
                 excluded_endpoints: None,
             },
@@ -191,7 +194,7 @@ mod should {
             &None,
             1,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -201,6 +204,7 @@ mod should {
             &RequestRetryConfiguration::Linear {
                 delay: 0,
                 max_retry: 1,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             },
             &mock_handshake_function,
@@ -215,7 +219,7 @@ mod should {
         let mock_handshake_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -233,7 +237,7 @@ mod should {
             &None,
             1,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: None,
             },
             "id",
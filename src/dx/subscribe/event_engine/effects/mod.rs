@@ -1,17 +1,18 @@
 //! # Subscribe event engine effect module.
 
-use async_channel::Sender;
-use futures::future::BoxFuture;
-use spin::RwLock;
-
+use crate::lib::sync::RwLock;
 use crate::{
-    core::{event_engine::Effect, PubNubError, RequestRetryConfiguration},
+    core::{
+        event_engine::{cancel::send_cancellation, Effect},
+        maybe_send::BoxFuture,
+        PubNubError, RequestRetryConfiguration,
+    },
     dx::subscribe::{
         event_engine::{
             types::{SubscriptionInput, SubscriptionParams},
             SubscribeEffectInvocation, SubscribeEvent,
         },
-        result::{SubscribeResult, Update},
+        result::{SubscribeResult, Updates},
         ConnectionStatus, SubscriptionCursor,
     },
     lib::{
@@ -19,6 +20,7 @@ use crate::{
         core::fmt::{Debug, Formatter},
     },
 };
+use async_channel::Sender;
 
 mod emit_messages;
 mod emit_status;
@@ -51,13 +53,13 @@ pub(in crate::dx::subscribe) type EmitStatusEffectExecutor = dyn Fn(ConnectionSt
 /// `EmitMessagesEffectExecutor` is a trait alias representing a type that
 /// executes the effect of emitting messages.
 ///
-/// It takes a vector of `Update` objects as input and does not return any
+/// It takes received `Updates` as input and does not return any
 /// value.
 ///
 /// This trait alias is `Send` and `Sync`, allowing it to be used across
 /// multiple threads safely.
 pub(in crate::dx::subscribe) type EmitMessagesEffectExecutor =
-    dyn Fn(Vec<Update>, SubscriptionCursor) + Send + Sync;
+    dyn Fn(Updates, SubscriptionCursor) + Send + Sync;
 
 // TODO: maybe move executor and cancellation_channel to super struct?
 pub(crate) enum SubscribeEffect {
@@ -232,7 +234,7 @@ pub(crate) enum SubscribeEffect {
         next_cursor: SubscriptionCursor,
 
         /// Updates which should be emitted.
-        updates: Vec<Update>,
+        updates: Updates,
 
         /// Executor function.
         ///
@@ -294,7 +296,6 @@ impl Debug for SubscribeEffect {
     }
 }
 
-#[async_trait::async_trait]
 impl Effect for SubscribeEffect {
     type Invocation = SubscribeEffectInvocation;
 
@@ -394,7 +395,7 @@ impl Effect for SubscribeEffect {
         }
     }
 
-    fn cancel(&self) {
+    fn cancel(&self) -> Option<BoxFuture<'static, ()>> {
         match self {
             Self::Handshake {
                 id,
@@ -424,11 +425,9 @@ impl Effect for SubscribeEffect {
                     let mut cancelled_slot = cancelled.write();
                     *cancelled_slot = true;
                 }
-                cancellation_channel
-                    .send_blocking(id.clone())
-                    .expect("cancellation pipe is broken!");
+                send_cancellation(cancellation_channel, id.clone())
             }
-            _ => { /* cannot cancel other effects */ }
+            _ => None, /* cannot cancel other effects */
         }
     }
 
@@ -462,7 +461,8 @@ mod should {
                 async move {
                     Ok(SubscribeResult {
                         cursor: SubscriptionCursor::default(),
-                        messages: vec![],
+                        messages: Default::default(),
+                        metadata: Default::default(),
                     })
                 }
                 .boxed()
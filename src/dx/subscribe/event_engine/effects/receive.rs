@@ -1,5 +1,5 @@
+use crate::lib::log::{self, info};
 use futures::TryFutureExt;
-use log::info;
 
 use crate::{
     core::PubNubError,
@@ -75,7 +75,8 @@ mod should {
             async move {
                 Ok(SubscribeResult {
                     cursor: Default::default(),
-                    messages: vec![],
+                    messages: Default::default(),
+                    metadata: Default::default(),
                 })
             }
             .boxed()
@@ -104,7 +105,7 @@ mod should {
         let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: None,
                 })
             }
@@ -1,5 +1,5 @@
+use crate::lib::log::{self, info};
 use futures::TryFutureExt;
-use log::info;
 
 use crate::{
     core::{PubNubError, RequestRetryConfiguration},
@@ -85,7 +85,7 @@ mod should {
             assert_eq!(
                 params.reason,
                 Some(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -98,7 +98,8 @@ mod should {
             async move {
                 Ok(SubscribeResult {
                     cursor: Default::default(),
-                    messages: vec![],
+                    messages: Default::default(),
+                    metadata: Default::default(),
                 })
             }
             .boxed()
@@ -112,7 +113,7 @@ mod should {
             &Default::default(),
             10,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -121,6 +122,7 @@ mod should {
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 20,
+                max_total_retry_duration: None,
                 delay: 0,
                 excluded_endpoints: None,
             },
@@ -140,7 +142,7 @@ mod should {
         let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -158,7 +160,7 @@ mod should {
             &Default::default(),
             5,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -167,6 +169,7 @@ mod should {
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 10,
+                max_total_retry_duration: None,
                 delay: 0,
                 excluded_endpoints: None,
             },
@@ -186,7 +189,7 @@ mod should {
         let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -204,7 +207,7 @@ mod should {
             &Default::default(),
             10,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -214,6 +217,7 @@ mod should {
             &RequestRetryConfiguration::Linear {
                 delay: 0,
                 max_retry: 1,
+                max_total_retry_duration: None,
                 excluded_endpoints: None,
             },
             &mock_receive_function,
@@ -240,7 +244,7 @@ mod should {
             &Default::default(),
             10,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -249,6 +253,7 @@ mod should {
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 20,
+                max_total_retry_duration: None,
                 delay: 0,
                 excluded_endpoints: None,
             },
@@ -264,7 +269,7 @@ mod should {
         let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
-                    details: "test".into(),
+                    details: error_details!("test"),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -282,7 +287,7 @@ mod should {
             &Default::default(),
             10,
             PubNubError::Transport {
-                details: "test".into(),
+                details: error_details!("test"),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
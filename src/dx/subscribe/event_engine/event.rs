@@ -1,4 +1,4 @@
-use crate::dx::subscribe::result::Update;
+use crate::dx::subscribe::result::Updates;
 use crate::{
     core::{event_engine::Event, PubNubError},
     dx::subscribe::SubscriptionCursor,
@@ -10,13 +10,15 @@ use crate::{
 /// Subscribe state machine behaviour depends from external events which it
 /// receives.
 #[derive(Debug)]
-pub(crate) enum SubscribeEvent {
+pub enum SubscribeEvent {
     /// Current list of channels / groups has been changed.
     ///
     /// Emitted when updates list of channels / groups has been passed for
     /// subscription.
     SubscriptionChanged {
+        /// Optional list of channels.
         channels: Option<Vec<String>>,
+        /// Optional list of channel groups.
         channel_groups: Option<Vec<String>>,
     },
 
@@ -25,8 +27,11 @@ pub(crate) enum SubscribeEvent {
     /// Emitted when subscription has been called with timetoken (cursor)
     /// starting from which updates should be received.
     SubscriptionRestored {
+        /// Optional list of channels.
         channels: Option<Vec<String>>,
+        /// Optional list of channel groups.
         channel_groups: Option<Vec<String>>,
+        /// Time cursor.
         cursor: SubscriptionCursor,
     },
 
@@ -36,7 +41,10 @@ pub(crate) enum SubscribeEvent {
     /// be used for subscription loop.
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    HandshakeSuccess { cursor: SubscriptionCursor },
+    HandshakeSuccess {
+        /// Time cursor.
+        cursor: SubscriptionCursor,
+    },
 
     /// Handshake completed with error.
     ///
@@ -44,7 +52,10 @@ pub(crate) enum SubscribeEvent {
     /// [`PubNub`] network (network issues or permissions).
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    HandshakeFailure { reason: PubNubError },
+    HandshakeFailure {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// Handshake reconnect completed successfully.
     ///
@@ -53,7 +64,10 @@ pub(crate) enum SubscribeEvent {
     /// loop.
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    HandshakeReconnectSuccess { cursor: SubscriptionCursor },
+    HandshakeReconnectSuccess {
+        /// Time cursor.
+        cursor: SubscriptionCursor,
+    },
 
     /// Handshake reconnect completed with error.
     ///
@@ -61,14 +75,20 @@ pub(crate) enum SubscribeEvent {
     /// response from [`PubNub`] network (network or permissions issues).
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    HandshakeReconnectFailure { reason: PubNubError },
+    HandshakeReconnectFailure {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// All handshake attempts was unsuccessful.
     ///
     /// Emitted when handshake reconnect attempts reached maximum allowed count
     /// (according to retry / reconnection policy) and all following attempts
     /// should be stopped.
-    HandshakeReconnectGiveUp { reason: PubNubError },
+    HandshakeReconnectGiveUp {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// Receive updates completed successfully.
     ///
@@ -77,8 +97,10 @@ pub(crate) enum SubscribeEvent {
     ///
     /// [`PubNub`]: https://www.pubnub.com/
     ReceiveSuccess {
+        /// Time cursor.
         cursor: SubscriptionCursor,
-        messages: Vec<Update>,
+        /// List of real-time updates.
+        messages: Updates,
     },
 
     /// Receive updates completed with error.
@@ -87,7 +109,10 @@ pub(crate) enum SubscribeEvent {
     /// [`PubNub`] network (network issues or revoked / expired permissions).
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    ReceiveFailure { reason: PubNubError },
+    ReceiveFailure {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// Receive updates reconnect completed successfully.
     ///
@@ -97,8 +122,10 @@ pub(crate) enum SubscribeEvent {
     ///
     /// [`PubNub`]: https://www.pubnub.com/
     ReceiveReconnectSuccess {
+        /// Time cursor.
         cursor: SubscriptionCursor,
-        messages: Vec<Update>,
+        /// List of real-time updates.
+        messages: Updates,
     },
 
     /// Receive updates reconnect completed with error.
@@ -108,14 +135,20 @@ pub(crate) enum SubscribeEvent {
     /// revoked permissions).
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    ReceiveReconnectFailure { reason: PubNubError },
+    ReceiveReconnectFailure {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// All receive updates attempts was unsuccessful.
     ///
     /// Emitted when receive updates reconnect attempts reached maximum allowed
     /// count (according to retry / reconnection policy) and all following
     /// attempts should be stopped.
-    ReceiveReconnectGiveUp { reason: PubNubError },
+    ReceiveReconnectGiveUp {
+        /// Failure reason.
+        reason: PubNubError,
+    },
 
     /// Disconnect from [`PubNub`] network.
     ///
@@ -129,7 +162,10 @@ pub(crate) enum SubscribeEvent {
     /// Emitted when explicitly requested to restore real-time updates receive.
     ///
     /// [`PubNub`]: https://www.pubnub.com/
-    Reconnect { cursor: Option<SubscriptionCursor> },
+    Reconnect {
+        /// Optional time cursor from which updates should be received.
+        cursor: Option<SubscriptionCursor>,
+    },
 
     /// Unsubscribe from all channels and groups.
     ///
@@ -2,13 +2,10 @@ use crate::{
     core::{event_engine::EffectInvocation, PubNubError},
     dx::subscribe::{
         event_engine::{SubscribeEffect, SubscribeEvent, SubscriptionInput},
-        result::Update,
+        result::Updates,
         ConnectionStatus, SubscriptionCursor,
     },
-    lib::{
-        alloc::vec::Vec,
-        core::fmt::{Display, Formatter, Result},
-    },
+    lib::core::fmt::{Display, Formatter, Result},
 };
 
 /// Subscribe effect invocations
@@ -109,7 +106,7 @@ pub(crate) enum SubscribeEffectInvocation {
     EmitStatus(ConnectionStatus),
 
     /// Received updates notification effect invocation.
-    EmitMessages(Vec<Update>, SubscriptionCursor),
+    EmitMessages(Updates, SubscriptionCursor),
 
     /// Terminate Subscribe Event Engine processing loop.
     TerminateEventEngine,
@@ -19,7 +19,7 @@ use crate::{
             },
             SubscribeEvent,
         },
-        result::Update,
+        result::Updates,
         ConnectionStatus, SubscriptionCursor,
     },
     lib::alloc::{string::String, vec, vec::Vec},
@@ -432,7 +432,7 @@ impl SubscribeState {
     fn receive_success_transition(
         &self,
         cursor: &SubscriptionCursor,
-        messages: &[Update],
+        messages: &Updates,
     ) -> Option<Transition<Self, SubscribeEffectInvocation>> {
         match self {
             Self::Receiving { input, .. } | Self::ReceiveReconnecting { input, .. } => {
@@ -441,7 +441,7 @@ impl SubscribeState {
                         input: input.clone(),
                         cursor: cursor.clone(),
                     }),
-                    Some(vec![EmitMessages(messages.to_vec(), cursor.clone())]),
+                    Some(vec![EmitMessages(messages.clone(), cursor.clone())]),
                 ))
             }
             _ => None,
@@ -722,12 +722,13 @@ impl State for SubscribeState {
 #[cfg(test)]
 mod should {
     // TODO: EE process tests should be async!
+    use crate::lib::sync::RwLock;
     use futures::FutureExt;
     use test_case::test_case;
 
     use super::*;
     use crate::{
-        core::{event_engine::EventEngine, RequestRetryConfiguration},
+        core::{event_engine::EventEngine, RequestRetryConfiguration, UuidProvider},
         dx::subscribe::{
             event_engine::{
                 effects::{
@@ -755,7 +756,8 @@ mod should {
             async move {
                 Ok(SubscribeResult {
                     cursor: Default::default(),
-                    messages: vec![],
+                    messages: Default::default(),
+                    metadata: Default::default(),
                 })
             }
             .boxed()
@@ -771,8 +773,9 @@ mod should {
                 call,
                 emit_status,
                 emit_message,
-                RequestRetryConfiguration::None,
+                Arc::new(RwLock::new(RequestRetryConfiguration::None)),
                 tx,
+                Arc::new(UuidProvider),
             ),
             start_state,
             RuntimeTokio,
@@ -813,7 +816,7 @@ mod should {
     #[test_case(
         SubscribeState::Unsubscribed,
         SubscribeEvent::ReceiveFailure {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: error_details!("Test"), response: None }
         },
         SubscribeState::Unsubscribed;
         "to not change on unexpected event"
@@ -884,7 +887,7 @@ mod should {
             cursor: None,
         },
         SubscribeEvent::HandshakeFailure {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -893,7 +896,7 @@ mod should {
             ),
             cursor: None,
             attempts:  1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to handshake reconnect on handshake failure"
     )]
@@ -906,7 +909,7 @@ mod should {
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
         },
         SubscribeEvent::HandshakeFailure {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -915,7 +918,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts:  1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to handshake reconnect with custom cursor on handshake failure"
     )]
@@ -1048,7 +1051,7 @@ mod should {
             cursor: None,
         },
         SubscribeEvent::HandshakeReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, }
         },
         SubscribeState::Handshaking {
             input: SubscriptionInput::new(
@@ -1081,10 +1084,10 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::HandshakeReconnectFailure {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -1093,7 +1096,7 @@ mod should {
             ),
             cursor: None,
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         };
         "to handshake reconnecting on reconnect failure"
     )]
@@ -1105,10 +1108,10 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::HandshakeReconnectFailure {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -1117,7 +1120,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason on error"), response: None, },
         };
         "to handshake reconnecting with custom cursor on reconnect failure"
     )]
@@ -1129,7 +1132,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1152,7 +1155,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1175,7 +1178,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::Disconnect,
         SubscribeState::HandshakeStopped {
@@ -1195,7 +1198,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::Disconnect,
         SubscribeState::HandshakeStopped {
@@ -1215,10 +1218,10 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::HandshakeReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test give up reason"), response: None, }
         },
         SubscribeState::HandshakeFailed {
             input: SubscriptionInput::new(
@@ -1226,7 +1229,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test give up reason"), response: None, }
         };
         "to handshake failed on give up"
     )]
@@ -1238,10 +1241,10 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::HandshakeReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test give up reason"), response: None, }
         },
         SubscribeState::HandshakeFailed {
             input: SubscriptionInput::new(
@@ -1249,7 +1252,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test give up reason"), response: None, }
         };
         "to handshake failed with custom cursor on give up"
     )]
@@ -1261,7 +1264,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::HandshakeReconnectSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 }
@@ -1283,7 +1286,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::HandshakeReconnectSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 2 }
@@ -1305,7 +1308,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1329,7 +1332,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1353,11 +1356,11 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::ReceiveSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            messages: vec![]
+            messages: Default::default()
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -1366,7 +1369,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on unexpected event"
     )]
@@ -1391,7 +1394,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1413,7 +1416,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1435,7 +1438,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::Reconnect { cursor: None },
         SubscribeState::Handshaking {
@@ -1454,7 +1457,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::Reconnect {
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 })
@@ -1475,7 +1478,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::Reconnect { cursor: None },
         SubscribeState::Handshaking {
@@ -1494,7 +1497,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::Reconnect {
             cursor: Some(SubscriptionCursor { timetoken: "10".into(), region: 2 })
@@ -1515,7 +1518,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1538,7 +1541,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1561,7 +1564,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::UnsubscribeAll,
         SubscribeState::Unsubscribed;
@@ -1574,11 +1577,11 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         },
         SubscribeEvent::ReceiveSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            messages: vec![]
+            messages: Default::default()
         },
         SubscribeState::HandshakeFailed {
             input: SubscriptionInput::new(
@@ -1586,7 +1589,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, },
         };
         "to not change on unexpected event"
     )]
@@ -1788,7 +1791,7 @@ mod should {
         },
         SubscribeEvent::ReceiveSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            messages: vec![]
+            messages: Default::default()
         },
         SubscribeState::HandshakeStopped {
             input: SubscriptionInput::new(
@@ -1866,7 +1869,7 @@ mod should {
         },
         SubscribeEvent::ReceiveSuccess {
             cursor: SubscriptionCursor { timetoken: "100".into(), region: 2 },
-            messages: vec![]
+            messages: Default::default()
         },
         SubscribeState::Receiving {
             input: SubscriptionInput::new(
@@ -1886,7 +1889,7 @@ mod should {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
         },
         SubscribeEvent::ReceiveFailure {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, }
         },
         SubscribeState::ReceiveReconnecting {
             input: SubscriptionInput::new(
@@ -1895,7 +1898,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test reason"), response: None, }
         };
         "to receive reconnecting on receive failure"
     )]
@@ -1971,10 +1974,10 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::ReceiveReconnectFailure {
-            reason: PubNubError::Transport { details: "Test reconnect error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test reconnect error"), response: None, }
         },
         SubscribeState::ReceiveReconnecting {
             input: SubscriptionInput::new(
@@ -1983,7 +1986,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reconnect error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test reconnect error"), response: None, }
         };
         "to receive reconnecting on reconnect failure"
     )]
@@ -1995,11 +1998,11 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::ReceiveReconnectSuccess {
             cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 },
-            messages: vec![]
+            messages: Default::default()
         },
         SubscribeState::Receiving {
             input: SubscriptionInput::new(
@@ -2018,7 +2021,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -2041,7 +2044,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -2065,7 +2068,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::Disconnect,
         SubscribeState::ReceiveStopped {
@@ -2085,10 +2088,10 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::ReceiveReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test give up error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test give up error"), response: None, }
         },
         SubscribeState::ReceiveFailed {
             input: SubscriptionInput::new(
@@ -2096,7 +2099,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test give up error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test give up error"), response: None, }
         };
         "to receive failed on give up"
     )]
@@ -2108,7 +2111,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::UnsubscribeAll,
         SubscribeState::Unsubscribed;
@@ -2122,7 +2125,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::HandshakeSuccess {
             cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 },
@@ -2134,7 +2137,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         };
         "to not change on unexpected event"
     )]
@@ -2159,7 +2162,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -2181,7 +2184,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -2204,7 +2207,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::Reconnect { cursor: None },
         SubscribeState::Handshaking {
@@ -2223,7 +2226,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::Reconnect {
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 3 })
@@ -2244,7 +2247,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::UnsubscribeAll,
         SubscribeState::Unsubscribed;
@@ -2257,7 +2260,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         },
         SubscribeEvent::HandshakeSuccess {
             cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 }
@@ -2268,7 +2271,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: error_details!("Test error"), response: None, }
         };
         "to not change on unexpected event"
     )]
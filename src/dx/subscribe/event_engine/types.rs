@@ -7,7 +7,7 @@
 //! [`PubNub`]:https://www.pubnub.com/
 
 use crate::{
-    core::PubNubError,
+    core::{ChannelName, PubNubError},
     lib::{
         alloc::collections::HashSet,
         core::{
@@ -34,7 +34,7 @@ pub struct SubscriptionInput {
     /// List is optional if there is at least one `channel_group` provided.
     ///
     /// [`PubNub`]:https://www.pubnub.com/
-    pub channels: Option<HashSet<String>>,
+    pub channels: Option<HashSet<ChannelName>>,
 
     /// Optional list of channel groups.
     ///
@@ -42,7 +42,7 @@ pub struct SubscriptionInput {
     /// from the [`PubNub`] network.
     ///
     /// [`PubNub`]:https://www.pubnub.com/
-    pub channel_groups: Option<HashSet<String>>,
+    pub channel_groups: Option<HashSet<ChannelName>>,
 
     /// Whether user input is empty or not.
     pub is_empty: bool,
@@ -50,18 +50,25 @@ pub struct SubscriptionInput {
 
 impl SubscriptionInput {
     pub fn new(channels: &Option<Vec<String>>, channel_groups: &Option<Vec<String>>) -> Self {
-        let channels = channels.as_ref().map(|channels| {
-            channels.iter().fold(HashSet::new(), |mut acc, channel| {
-                acc.insert(channel.clone());
-                acc
-            })
-        });
-        let channel_groups = channel_groups.as_ref().map(|groups| {
-            groups.iter().fold(HashSet::new(), |mut acc, group| {
-                acc.insert(group.clone());
-                acc
-            })
-        });
+        Self::with_names(
+            channels
+                .as_ref()
+                .map(|channels| channels.iter().map(Into::into)),
+            channel_groups
+                .as_ref()
+                .map(|groups| groups.iter().map(Into::into)),
+        )
+    }
+
+    /// Create subscription input from (interned) channel and group `names`.
+    pub(crate) fn with_names<C, G>(channels: Option<C>, channel_groups: Option<G>) -> Self
+    where
+        C: IntoIterator<Item = ChannelName>,
+        G: IntoIterator<Item = ChannelName>,
+    {
+        let channels = channels.map(|channels| channels.into_iter().collect::<HashSet<_>>());
+        let channel_groups =
+            channel_groups.map(|groups| groups.into_iter().collect::<HashSet<_>>());
 
         let channel_groups_is_empty = channel_groups.as_ref().map_or(true, |set| set.is_empty());
         let channels_is_empty = channels.as_ref().map_or(true, |set| set.is_empty());
@@ -88,7 +95,9 @@ impl SubscriptionInput {
     }
 
     pub fn channels(&self) -> Option<Vec<String>> {
-        self.channels.clone().map(|ch| ch.into_iter().collect())
+        self.channels
+            .as_ref()
+            .map(|ch| ch.iter().map(ToString::to_string).collect())
     }
 
     pub fn contains_channel(&self, channel: &str) -> bool {
@@ -99,8 +108,8 @@ impl SubscriptionInput {
 
     pub fn channel_groups(&self) -> Option<Vec<String>> {
         self.channel_groups
-            .clone()
-            .map(|ch| ch.into_iter().collect())
+            .as_ref()
+            .map(|ch| ch.iter().map(ToString::to_string).collect())
     }
 
     pub fn contains_channel_group(&self, channel_group: &str) -> bool {
@@ -113,9 +122,9 @@ impl SubscriptionInput {
 
     fn join_sets(
         &self,
-        lhs: &Option<HashSet<String>>,
-        rhs: &Option<HashSet<String>>,
-    ) -> Option<HashSet<String>> {
+        lhs: &Option<HashSet<ChannelName>>,
+        rhs: &Option<HashSet<ChannelName>>,
+    ) -> Option<HashSet<ChannelName>> {
         match (lhs, rhs) {
             (Some(lhs), Some(rhs)) => Some(lhs.iter().cloned().chain(rhs.to_owned()).collect()),
             (Some(lhs), None) => Some(lhs.to_owned()),
@@ -126,9 +135,9 @@ impl SubscriptionInput {
 
     fn sub_sets(
         &self,
-        lhs: &Option<HashSet<String>>,
-        rhs: &Option<HashSet<String>>,
-    ) -> Option<HashSet<String>> {
+        lhs: &Option<HashSet<ChannelName>>,
+        rhs: &Option<HashSet<ChannelName>>,
+    ) -> Option<HashSet<ChannelName>> {
         match (lhs.to_owned(), rhs.to_owned()) {
             (Some(lhs), Some(rhs)) => Some(&lhs - &rhs).filter(|diff| !diff.is_empty()),
             (Some(lhs), None) => Some(lhs),
@@ -0,0 +1,149 @@
+//! # JavaScript callbacks bridge module.
+//!
+//! This module contains [`JsEventEmitter`] trait which allows to register
+//! JavaScript functions as real-time updates listeners for subscriptions
+//! (everything which implements [`EventEmitter`]) in web applications built
+//! with [`wasm-bindgen`].
+//!
+//! It requires the [`wasm_bindgen` feature] to be enabled.
+//!
+//! [`wasm-bindgen`]: https://rustwasm.github.io/docs/wasm-bindgen
+//! [`wasm_bindgen` feature]: ../index.html#features
+
+use crate::{
+    core::DataStream,
+    dx::subscribe::{EventEmitter, Message},
+    lib::{alloc::string::String, core::str, log},
+};
+use futures::{
+    future::{AbortHandle, Abortable},
+    StreamExt,
+};
+use js_sys::{Function, Object, Reflect, Uint8Array, JSON};
+use wasm_bindgen::JsValue;
+
+/// JavaScript callbacks registration.
+///
+/// Trait is implemented for all types which implement [`EventEmitter`]
+/// ([`Subscription`], [`SubscriptionSet`]). Registered callback is called
+/// with single argument (object with update information) on the current
+/// thread for each received update while returned [`JsListener`] is alive.
+///
+/// Message object has the following fields: `channel`, `subscription`,
+/// `timetoken`, `publisher`, `type`, `spaceId` and `message` (parsed `JSON`
+/// value, string or `Uint8Array` with raw data).
+///
+/// # Example
+/// ```no_run
+/// use js_sys::Function;
+/// use pubnub::subscribe::{JsEventEmitter, Subscription};
+///
+/// # fn register<T, D>(subscription: &Subscription<T, D>, callback: Function)
+/// # where
+/// #     T: pubnub::core::Transport + Send + Sync + 'static,
+/// #     D: pubnub::core::Deserializer + Send + Sync + 'static,
+/// # {
+/// // `callback` is provided by web application (e.g. `(message) => {}`).
+/// let listener = subscription.on_message(callback);
+///
+/// // Later, when updates aren't needed anymore.
+/// listener.remove();
+/// # }
+/// ```
+///
+/// [`Subscription`]: crate::subscribe::Subscription
+/// [`SubscriptionSet`]: crate::subscribe::SubscriptionSet
+pub trait JsEventEmitter: EventEmitter {
+    /// Call `callback` for each received message.
+    fn on_message(&self, callback: Function) -> JsListener {
+        listen(self.messages_stream(), callback)
+    }
+
+    /// Call `callback` for each received signal.
+    fn on_signal(&self, callback: Function) -> JsListener {
+        listen(self.signals_stream(), callback)
+    }
+}
+
+impl<E> JsEventEmitter for E where E: EventEmitter {}
+
+/// Registered JavaScript callback.
+///
+/// Callback won't be called after listener has been removed or dropped.
+#[derive(Debug)]
+pub struct JsListener {
+    /// Handle to stop updates forwarding task.
+    handle: AbortHandle,
+}
+
+impl JsListener {
+    /// Stop calling registered callback.
+    pub fn remove(self) {
+        // Forwarding task is stopped on drop.
+    }
+}
+
+impl Drop for JsListener {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Forward `stream` messages to the JavaScript `callback`.
+fn listen(stream: DataStream<Message>, callback: Function) -> JsListener {
+    let (handle, registration) = AbortHandle::new_pair();
+    let forward = stream.for_each(move |message| {
+        if let Err(error) = callback.call1(&JsValue::NULL, &message_object(&message)) {
+            log::error!("JavaScript callback failed: {error:?}");
+        }
+        async {}
+    });
+
+    wasm_bindgen_futures::spawn_local(async move {
+        Abortable::new(forward, registration).await.ok();
+    });
+
+    JsListener { handle }
+}
+
+/// Create JavaScript object with `message` information.
+fn message_object(message: &Message) -> JsValue {
+    let object = Object::new();
+    set(&object, "channel", message.channel.as_str().into());
+    set(
+        &object,
+        "subscription",
+        message.subscription.as_str().into(),
+    );
+    set(&object, "timetoken", message.timestamp.into());
+    set(&object, "publisher", optional(&message.sender));
+    set(&object, "type", optional(&message.r#type));
+    set(&object, "spaceId", optional(&message.space_id));
+    set(&object, "message", message_data(&message.data));
+
+    object.into()
+}
+
+/// Convert received data to JavaScript value.
+///
+/// Data is parsed as `JSON` if possible and passed as a string or raw bytes
+/// otherwise.
+fn message_data(data: &[u8]) -> JsValue {
+    match str::from_utf8(data) {
+        Ok(data) => JSON::parse(data).unwrap_or_else(|_| data.into()),
+        Err(_) => Uint8Array::from(data).into(),
+    }
+}
+
+/// Convert optional string to JavaScript value (`undefined` if not set).
+fn optional(value: &Option<String>) -> JsValue {
+    value
+        .as_deref()
+        .map(JsValue::from)
+        .unwrap_or(JsValue::UNDEFINED)
+}
+
+/// Set `object` property with `key` to `value`.
+fn set(object: &Object, key: &str, value: JsValue) {
+    Reflect::set(object, &key.into(), &value).ok();
+}
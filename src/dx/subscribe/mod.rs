@@ -3,12 +3,9 @@
 //! Allows subscribe to real-time updates from channels and groups.
 
 #[cfg(feature = "std")]
-use futures::{
-    future::{ready, BoxFuture},
-    FutureExt,
-};
+use crate::lib::sync::RwLock;
 #[cfg(feature = "std")]
-use spin::RwLock;
+use futures::{future::ready, FutureExt};
 
 #[cfg(feature = "std")]
 use crate::{
@@ -21,8 +18,11 @@ use crate::{
 use crate::{
     core::{
         event_engine::{CancellationTask, EventEngine},
+        maybe_send::{BoxFuture, MaybeSendFutureExt},
+        network_monitor::{DynNetworkMonitor, PollingNetworkMonitor},
+        retry_policy::Endpoint,
         runtime::Runtime,
-        DataStream, PubNubEntity,
+        DataStream, NetworkStatus, PubNubEntity, TransportRequest,
     },
     lib::alloc::string::ToString,
 };
@@ -60,7 +60,7 @@ pub use builders::*;
 pub mod builders;
 
 #[doc(inline)]
-pub use result::{SubscribeResponseBody, Update};
+pub use result::{SubscribeResponseBody, Update, Updates};
 pub mod result;
 
 #[cfg(feature = "std")]
@@ -77,10 +77,18 @@ mod subscription_set;
 
 #[cfg(feature = "std")]
 #[doc(inline)]
-pub use traits::{EventEmitter, EventSubscriber, Subscribable, SubscribableType, Subscriber};
+pub use traits::{
+    EventEmitter, EventSubscriber, Subscribable, SubscribableType, Subscriber, TypedMessagesStream,
+};
 #[cfg(feature = "std")]
 pub(crate) mod traits;
 
+#[cfg(all(feature = "wasm_bindgen", feature = "std"))]
+#[doc(inline)]
+pub use js_callbacks::{JsEventEmitter, JsListener};
+#[cfg(all(feature = "wasm_bindgen", feature = "std"))]
+pub mod js_callbacks;
+
 #[cfg(feature = "std")]
 impl<T, D> PubNubClientInstance<T, D>
 where
@@ -120,7 +128,7 @@ where
     ///
     /// * `cursor` - A time cursor for next portion of events.
     /// * `events` - A slice of real-time events from multiplexed subscription.
-    pub(crate) fn handle_events(&self, cursor: SubscriptionCursor, events: &[Update]) {
+    pub(crate) fn handle_events(&self, cursor: SubscriptionCursor, events: &[Arc<Update>]) {
         let mut cursor_slot = self.cursor.write();
         if let Some(current_cursor) = cursor_slot.as_ref() {
             cursor
@@ -130,7 +138,7 @@ where
             *cursor_slot = Some(cursor);
         }
 
-        self.event_dispatcher.handle_events(events.to_vec())
+        self.event_dispatcher.handle_events(events)
     }
 
     /// Creates a clone of the [`PubNubClientInstance`] with an empty event
@@ -193,7 +201,10 @@ where
     ///
     /// # Returns
     ///
-    /// The created [`SubscriptionSet`] object.
+    /// The created [`SubscriptionSet`] object. If neither channels nor channel
+    /// groups have been provided, an empty set is returned (see
+    /// [`SubscriptionSet::is_empty`]) and calls to subscribe it are
+    /// ignored.
     ///
     /// # Example
     ///
@@ -389,7 +400,7 @@ where
             };
 
             if self.config.presence.heartbeat_interval.is_none() {
-                let mut request = self.heartbeat();
+                let mut request = self.heartbeat_builder();
                 if let Some(channels) = input.channels() {
                     request = request.channels(channels);
                 }
@@ -421,6 +432,149 @@ where
         }
     }
 
+    /// Currently subscribed channels and groups.
+    ///
+    /// Snapshot of channels and groups combined from all subscribed
+    /// [`Subscription`] and [`SubscriptionSet`]. It can be used to render
+    /// subscription state or to compare it with desired list of channels.
+    ///
+    /// ```no_run
+    /// use pubnub::{
+    ///     subscribe::{EventSubscriber, SubscriptionParams},
+    ///     Keyset, PubNubClientBuilder,
+    /// };
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: None
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// let subscription = pubnub.subscription(SubscriptionParams {
+    ///     channels: Some(&["my_channel"]),
+    ///     channel_groups: None,
+    ///     options: None,
+    /// });
+    /// subscription.subscribe();
+    ///
+    /// let subscribed = pubnub.subscribed_channels();
+    /// assert_eq!(subscribed.channels, vec!["my_channel"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribed_channels(&self) -> SubscribedChannels {
+        let Some(input) = self
+            .subscription_manager(false)
+            .read()
+            .as_ref()
+            .map(|manager| manager.current_input())
+        else {
+            return Default::default();
+        };
+
+        let sorted = |list: Option<Vec<String>>| {
+            let mut list = list.unwrap_or_default();
+            list.sort();
+            list
+        };
+
+        SubscribedChannels {
+            channels: sorted(input.channels()),
+            channel_groups: sorted(input.channel_groups()),
+        }
+    }
+
+    /// Update `user_id` used by client.
+    ///
+    /// New `user_id` will be used with next requests. If there are active
+    /// subscriptions, real-time updates and presence loops will be restarted:
+    /// `leave` will be announced for previous `user_id` (unless suppressed)
+    /// and subscription will be resumed from last received timetoken with new
+    /// `user_id`.
+    ///
+    /// ```no_run
+    /// use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let pubnub = PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #             subscribe_key: "demo",
+    /// #             publish_key: Some("demo"),
+    /// #             secret_key: None,
+    /// #         })
+    /// #         .with_user_id("anonymous")
+    /// #         .build()?;
+    /// // User logged in.
+    /// pubnub.set_user_id("user-1234");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_user_id<S>(&self, user_id: S)
+    where
+        S: Into<String>,
+    {
+        let user_id = user_id.into();
+        if self.inner.user_id.read().eq(&user_id) {
+            return;
+        }
+
+        let input = self
+            .subscription_manager(false)
+            .read()
+            .as_ref()
+            .map(|manager| manager.current_input())
+            .filter(|input| !input.is_empty);
+
+        let Some(_input) = input else {
+            *self.inner.user_id.write() = user_id;
+            return;
+        };
+
+        if let Some(manager) = self.subscription_manager(false).read().as_ref() {
+            manager.disconnect();
+        }
+
+        #[cfg(feature = "presence")]
+        {
+            let channels = Self::presence_filtered_entries(_input.channels());
+            let channel_groups = Self::presence_filtered_entries(_input.channel_groups());
+
+            // Presence event engine will be re-created for new `user_id`.
+            if let Some(presence) = self.presence_manager(false).write().take() {
+                presence.terminate();
+            }
+
+            // Leave request builder captures current (previous) `user_id`.
+            if !self.config.presence.suppress_leave_events {
+                let mut request = self.leave();
+                if let Some(channels) = channels.clone() {
+                    request = request.channels(channels);
+                }
+                if let Some(channel_groups) = channel_groups.clone() {
+                    request = request.channel_groups(channel_groups);
+                }
+
+                self.runtime.spawn(async {
+                    let _ = request.execute().await;
+                })
+            }
+
+            *self.inner.user_id.write() = user_id;
+            self.announce_join(channels, channel_groups);
+        }
+
+        #[cfg(not(feature = "presence"))]
+        {
+            *self.inner.user_id.write() = user_id;
+        }
+
+        self.reconnect(None);
+    }
+
     /// Subscription manager which maintains Subscription EE.
     ///
     /// # Arguments
@@ -455,71 +609,168 @@ where
                     self.subscribe_event_engine(),
                     #[cfg(feature = "presence")]
                     Arc::new(move |channels, groups, _all| {
-                        Self::subscribe_heartbeat_call(heartbeat_self.clone(), channels, groups);
+                        Self::subscribe_heartbeat_call(&heartbeat_self, channels, groups);
                     }),
                     #[cfg(feature = "presence")]
                     Arc::new(move |channels, groups, all| {
-                        Self::subscribe_leave_call(leave_self.clone(), channels, groups, all);
+                        Self::subscribe_leave_call(&leave_self, channels, groups, all);
                     }),
                 ));
+                self.start_network_monitor();
             }
         }
 
         self.subscription.clone()
     }
 
+    /// Start network reachability monitoring.
+    ///
+    /// Spawn task which will disconnect and reconnect event engines on network
+    /// reachability changes reported by configured [`NetworkMonitor`].
+    ///
+    /// [`NetworkMonitor`]: crate::core::NetworkMonitor
+    fn start_network_monitor(&self) {
+        let Some(monitor) = self.configured_network_monitor() else {
+            return;
+        };
+        let client = Arc::downgrade(&self.inner);
+        let cursor = self.cursor.clone();
+        let event_dispatcher = self.event_dispatcher.clone();
+
+        self.runtime.spawn(async move {
+            let mut status = NetworkStatus::Up;
+
+            loop {
+                let new_status = monitor.status_change_boxed(status).await;
+                let Some(inner) = client.upgrade() else {
+                    break;
+                };
+
+                if new_status != status {
+                    status = new_status;
+                    Self {
+                        inner,
+                        cursor: cursor.clone(),
+                        event_dispatcher: event_dispatcher.clone(),
+                    }
+                    .handle_network_status(status);
+                }
+            }
+        })
+    }
+
+    /// Configured or default network monitor.
+    fn configured_network_monitor(&self) -> Option<Arc<dyn DynNetworkMonitor>> {
+        if let Some(monitor) = self.network_monitor.clone() {
+            return Some(monitor);
+        }
+
+        let interval = self.config.transport.network_monitor_interval?;
+        let client = Arc::downgrade(&self.inner);
+
+        Some(Arc::new(PollingNetworkMonitor::new(
+            Arc::new(move || {
+                let client = client.clone();
+                async move {
+                    let client = client.upgrade()?;
+                    let request = TransportRequest {
+                        path: "/time/0".into(),
+                        timeout: *client.request_timeout.read(),
+                        ..Default::default()
+                    };
+
+                    Some(client.transport.send(request).await.is_ok())
+                }
+                .boxed_maybe_send()
+            }),
+            self.runtime.clone(),
+            interval,
+        )))
+    }
+
+    /// Handle network reachability change.
+    ///
+    /// Event engines will be disconnected while network is down (without
+    /// `leave` announcement) and reconnected when network is up again.
+    fn handle_network_status(&self, status: NetworkStatus) {
+        match status {
+            NetworkStatus::Up => self.reconnect(None),
+            NetworkStatus::Down => {
+                if let Some(manager) = self.subscription_manager(false).read().as_ref() {
+                    manager.disconnect()
+                }
+
+                #[cfg(feature = "presence")]
+                if self.config.presence.heartbeat_interval.is_some() {
+                    if let Some(presence) = self.presence_manager(false).read().as_ref() {
+                        presence.disconnect();
+                    }
+                }
+            }
+        }
+    }
+
     fn subscribe_event_engine(&self) -> Arc<SubscribeEventEngine> {
-        let channel_bound = 10; // TODO: Think about this value
-        let emit_messages_client = self.clone();
-        let emit_status_client = self.clone();
-        let subscribe_client = self.clone();
-        let request_retry = self.config.transport.retry_configuration.clone();
+        let channel_bound = self.config.event_engine.subscribe_cancel_capacity.max(1);
+        // Effect handlers share single client instance and borrow it for each
+        // invocation.
+        let client = Arc::new(self.clone());
+        let emit_messages_client = Arc::clone(&client);
+        let emit_status_client = Arc::clone(&client);
+        let request_retry = self.inner.retry_configuration.clone();
         let request_subscribe_retry = request_retry.clone();
+        let retry_notifier = self.retry_notifier.clone();
         let runtime = self.runtime.clone();
         let runtime_sleep = runtime.clone();
         let (cancel_tx, cancel_rx) = async_channel::bounded::<String>(channel_bound);
 
-        EventEngine::new(
+        EventEngine::with_capacity(
             SubscribeEffectHandler::new(
                 Arc::new(move |params| {
-                    let delay_in_microseconds = request_subscribe_retry.retry_delay(
+                    let delay_in_microseconds = request_subscribe_retry.read().retry_delay(
                         Some("/v2/subscribe".to_string()),
                         &params.attempt,
                         params.reason.as_ref(),
                     );
+                    if let Some((delay, reason)) = delay_in_microseconds.zip(params.reason.as_ref())
+                    {
+                        retry_notifier.notify(Endpoint::Subscribe, params.attempt, delay, reason);
+                    }
                     let inner_runtime_sleep = runtime_sleep.clone();
 
                     Self::subscribe_call(
-                        subscribe_client.clone(),
-                        params.clone(),
+                        &client,
+                        &params,
                         Arc::new(move || {
                             if let Some(delay) = delay_in_microseconds {
                                 inner_runtime_sleep
                                     .clone()
                                     .sleep_microseconds(delay)
-                                    .boxed()
+                                    .boxed_maybe_send()
                             } else {
-                                ready(()).boxed()
+                                ready(()).boxed_maybe_send()
                             }
                         }),
                         cancel_rx.clone(),
                     )
                 }),
-                Arc::new(move |status| Self::emit_status(emit_status_client.clone(), &status)),
+                Arc::new(move |status| Self::emit_status(&emit_status_client, &status)),
                 Arc::new(Box::new(move |updates, cursor: SubscriptionCursor| {
-                    Self::emit_messages(emit_messages_client.clone(), updates, cursor)
+                    Self::emit_messages(&emit_messages_client, updates, cursor)
                 })),
                 request_retry,
                 cancel_tx,
+                self.id_provider.clone(),
             ),
             SubscribeState::Unsubscribed,
             runtime,
+            self.config.event_engine.invocations_capacity,
         )
     }
 
     fn subscribe_call<F>(
-        client: Self,
-        params: event_engine::types::SubscriptionParams,
+        client: &Self,
+        params: &event_engine::types::SubscriptionParams,
         delay: Arc<F>,
         cancel_rx: async_channel::Receiver<String>,
     ) -> BoxFuture<'static, Result<SubscribeResult, PubNubError>>
@@ -547,10 +798,17 @@ where
         }
 
         let cancel_task = CancellationTask::new(cancel_rx, params.effect_id.to_owned()); // TODO: needs to be owned?
+        let inner = Arc::clone(&client.inner);
 
         request
             .execute_with_cancel_and_delay(delay, cancel_task)
-            .boxed()
+            .map(move |result| {
+                if let (Ok(_), Some(clock)) = (&result, &inner.clock) {
+                    *inner.last_subscribe_timestamp.write() = Some(clock.unix_timestamp());
+                }
+                result
+            })
+            .boxed_maybe_send()
     }
 
     /// Subscription event engine presence `join` announcement.
@@ -562,7 +820,7 @@ where
     ///   call.
     #[cfg(all(feature = "presence", feature = "std"))]
     fn subscribe_heartbeat_call(
-        client: Self,
+        client: &Self,
         channels: Option<Vec<String>>,
         channel_groups: Option<Vec<String>>,
     ) {
@@ -580,7 +838,7 @@ where
     /// * can't operate (heartbeat interval not set) - make direct `leave` call.
     #[cfg(all(feature = "presence", feature = "std"))]
     fn subscribe_leave_call(
-        client: Self,
+        client: &Self,
         channels: Option<Vec<String>>,
         channel_groups: Option<Vec<String>>,
         all: bool,
@@ -595,24 +853,36 @@ where
         }
     }
 
-    fn emit_status(client: Self, status: &ConnectionStatus) {
+    fn emit_status(client: &Self, status: &ConnectionStatus) {
         if let Some(manager) = client.subscription_manager(false).read().as_ref() {
             manager.notify_new_status(status)
         }
     }
 
-    fn emit_messages(client: Self, messages: Vec<Update>, cursor: SubscriptionCursor) {
-        let messages = if let Some(cryptor) = &client.cryptor {
-            messages
-                .into_iter()
-                .map(|update| update.decrypt(cryptor))
-                .collect()
-        } else {
-            messages
-        };
+    /// Deliver received real-time updates to the listeners.
+    ///
+    /// Updates are decoded, decrypted and delivered one by one, so listeners
+    /// receive first updates from large subscribe batches (up to 100 updates)
+    /// without waiting for the whole batch to be decoded.
+    fn emit_messages(client: &Self, messages: Updates, cursor: SubscriptionCursor) {
+        let manager = client.subscription_manager(false);
+
+        for update in messages {
+            let update = match update {
+                Ok(update) => update,
+                Err(error) => {
+                    log::error!("Unable to decode real-time update: {error}");
+                    continue;
+                }
+            };
+            let update = match &client.cryptor {
+                Some(cryptor) => update.decrypt(cryptor),
+                None => update,
+            };
 
-        if let Some(manager) = client.subscription_manager(false).read().as_ref() {
-            manager.notify_new_messages(cursor, messages.clone())
+            if let Some(manager) = manager.read().as_ref() {
+                manager.notify_new_messages(cursor.clone(), vec![update])
+            }
         }
     }
 
@@ -655,7 +925,7 @@ impl<T, D> PubNubClientInstance<T, D> {
     /// #      .build()?;
     /// pubnub
     ///     .subscribe_raw()
-    ///     .channels(["hello".into(), "world".into()].to_vec())
+    ///     .channels(["hello", "world"])
     ///     .execute()?
     ///     .stream()
     ///     .for_each(|update| async move {
@@ -745,7 +1015,7 @@ impl<T, D> EventEmitter for PubNubClientInstance<T, D> {
         self.event_dispatcher.presence_stream()
     }
 
-    fn stream(&self) -> DataStream<Update> {
+    fn stream(&self) -> DataStream<Arc<Update>> {
         self.event_dispatcher.stream()
     }
 }
@@ -753,8 +1023,8 @@ impl<T, D> EventEmitter for PubNubClientInstance<T, D> {
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod should {
+    use crate::lib::sync::RwLock;
     use futures::StreamExt;
-    use spin::RwLock;
 
     use super::*;
     use crate::{
@@ -783,12 +1053,14 @@ mod should {
         }
     }
 
-    #[async_trait::async_trait]
     impl Transport for MockTransport {
         async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, PubNubError> {
-            let mut count_slot = self.responses_count.write();
-            let response_body = generate_body(*count_slot);
-            *count_slot += 1;
+            let response_body = {
+                let mut count_slot = self.responses_count.write();
+                let response_body = generate_body(*count_slot);
+                *count_slot += 1;
+                response_body
+            };
 
             if response_body.is_none() {
                 tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
@@ -797,7 +1069,7 @@ mod should {
             Ok(TransportResponse {
                 status: 200,
                 headers: [].into(),
-                body: response_body,
+                body: response_body.map(Into::into),
             })
         }
     }
@@ -811,7 +1083,7 @@ mod should {
             Ok(TransportResponse {
                 status: 200,
                 headers: [].into(),
-                body: response_body,
+                body: response_body.map(Into::into),
             })
         }
     }
@@ -907,6 +1179,26 @@ mod should {
         });
     }
 
+    #[tokio::test]
+    async fn provide_subscribed_channels() {
+        let client = client();
+        assert!(client.subscribed_channels().is_empty());
+
+        let subscription = client.subscription(SubscriptionParams {
+            channels: Some(&["channel_b", "channel_a"]),
+            channel_groups: Some(&["group_a"]),
+            options: None,
+        });
+        subscription.subscribe();
+
+        let subscribed = client.subscribed_channels();
+        assert_eq!(subscribed.channels, vec!["channel_a", "channel_b"]);
+        assert_eq!(subscribed.channel_groups, vec!["group_a"]);
+
+        subscription.unsubscribe();
+        assert!(client.subscribed_channels().is_empty());
+    }
+
     #[tokio::test]
     async fn subscribe() {
         let client = client();
@@ -943,11 +1235,98 @@ mod should {
         client.unsubscribe_all();
     }
 
+    #[tokio::test]
+    async fn emit_updates_before_whole_batch_decoded() {
+        let client = client();
+        let subscription = client.channel("batch-channel").subscription(None);
+        subscription.subscribe();
+
+        let envelope = |payload: &str| {
+            format!(
+                r#"{{"a":"1","f":0,"p":{{"t":"17000000000000000","r":31}},"c":"batch-channel","d":"{payload}"}}"#
+            )
+        };
+        // Last envelope can't be decoded, but it shouldn't affect updates
+        // which has been received before it.
+        let updates: Updates = serde_json::from_str(&format!(
+            r#"[{},{},{},{{"a":"1","d":"broken"}}]"#,
+            envelope("first"),
+            envelope("second"),
+            envelope("third")
+        ))
+        .unwrap();
+        assert_eq!(updates.len(), 4);
+        PubNubClientInstance::emit_messages(&client, updates, Default::default());
+
+        let payloads = subscription
+            .messages_stream()
+            .take(3)
+            .map(|message| String::from_utf8(message.data.to_vec()).unwrap())
+            .collect::<Vec<_>>();
+        let payloads = tokio::time::timeout(tokio::time::Duration::from_secs(3), payloads).await;
+
+        assert_eq!(
+            payloads.unwrap(),
+            vec![r#""first""#, r#""second""#, r#""third""#]
+        );
+
+        client.unsubscribe_all();
+    }
+
+    #[tokio::test]
+    async fn shutdown_gracefully() {
+        let client = PubNubClientBuilder::with_transport(MockTransport::default())
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .with_suppress_leave_events(true)
+            .build()
+            .unwrap();
+        let subscription = client.subscription(SubscriptionParams {
+            channels: Some(&["my-channel"]),
+            channel_groups: None,
+            options: None,
+        });
+        subscription.subscribe();
+        let _ = subscription.messages_stream().next().await.unwrap();
+
+        let result =
+            tokio::time::timeout(tokio::time::Duration::from_secs(3), client.shutdown()).await;
+
+        assert!(result.is_ok());
+        assert!(client.subscription_manager(false).read().is_none());
+    }
+
+    #[tokio::test]
+    async fn subscribe_with_zero_cancellation_capacity() {
+        let client = PubNubClientBuilder::with_transport(MockTransport::default())
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .with_event_engine_configuration(crate::dx::pubnub_client::EventEngineConfiguration {
+                subscribe_cancel_capacity: 0,
+                presence_cancel_capacity: 0,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let subscription = client.channel("my-channel").subscription(None);
+        subscription.subscribe();
+
+        assert!(subscription.messages_stream().next().await.is_some());
+    }
+
     #[tokio::test]
     async fn subscribe_raw() {
         let subscription = client()
             .subscribe_raw()
-            .channels(["world".into()].to_vec())
+            .channels(["world"])
             .execute()
             .unwrap();
 
@@ -961,7 +1340,7 @@ mod should {
     fn subscribe_raw_blocking() {
         let subscription = client()
             .subscribe_raw()
-            .channels(["world".into()].to_vec())
+            .channels(["world"])
             .execute_blocking()
             .unwrap();
 
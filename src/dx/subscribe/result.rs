@@ -4,24 +4,29 @@
 //! The [`SubscribeResult`] type is used to represent results of subscribe
 //! operation.
 
+use bytes::Bytes;
+
 use crate::{
-    core::{service_response::APIErrorBody, PubNubError, ScalarValue},
+    core::{service_response::APIErrorBody, PubNubError, ResponseMetadata, ScalarValue, Timetoken},
     dx::subscribe::{
         types::Message,
         AppContext, File, MessageAction, Presence, {SubscribeMessageType, SubscriptionCursor},
     },
     lib::{
-        alloc::{
-            boxed::Box,
-            string::{String, ToString},
-            vec,
-            vec::Vec,
-        },
+        alloc::{boxed::Box, collections::VecDeque, string::String, vec::Vec},
         collections::HashMap,
         core::fmt::Debug,
     },
 };
 
+#[cfg(feature = "serde")]
+use crate::lib::core::{
+    fmt::{Formatter, Result as FmtResult},
+    iter,
+};
+#[cfg(feature = "serde")]
+use serde_json::value::RawValue;
+
 /// The result of a subscribe operation.
 /// It contains next subscription cursor and list of real-time updates.
 #[derive(Debug)]
@@ -43,7 +48,74 @@ pub struct SubscribeResult {
     /// * `file` – file sharing updates
     ///
     /// [`PubNub`]:https://www.pubnub.com/
-    pub messages: Vec<Update>,
+    pub messages: Updates,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(SubscribeResult);
+
+/// Real-time updates received with subscribe response.
+///
+/// Updates are stored in form in which they have been received and decoded one
+/// by one during iteration. First updates from large batch (up to 100 updates)
+/// can be delivered without waiting for the whole batch to be decoded.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(transparent))]
+pub struct Updates {
+    /// Raw envelopes of real-time updates.
+    #[cfg(feature = "serde")]
+    envelopes: VecDeque<RawPayload>,
+
+    /// Envelopes of real-time updates.
+    #[cfg(not(feature = "serde"))]
+    envelopes: VecDeque<Envelope>,
+}
+
+impl Updates {
+    /// Number of updates which is left to be decoded.
+    pub fn len(&self) -> usize {
+        self.envelopes.len()
+    }
+
+    /// Check whether there are no updates left.
+    pub fn is_empty(&self) -> bool {
+        self.envelopes.is_empty()
+    }
+
+    /// Decode real-time update from raw envelope.
+    #[cfg(feature = "serde")]
+    fn decode(envelope: RawPayload) -> Result<Update, PubNubError> {
+        envelope.decode::<Envelope>()?.try_into()
+    }
+
+    /// Decode real-time update from envelope.
+    #[cfg(not(feature = "serde"))]
+    fn decode(envelope: Envelope) -> Result<Update, PubNubError> {
+        envelope.try_into()
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+impl From<Vec<Envelope>> for Updates {
+    fn from(value: Vec<Envelope>) -> Self {
+        Self {
+            envelopes: value.into(),
+        }
+    }
+}
+
+impl Iterator for Updates {
+    type Item = Result<Update, PubNubError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.envelopes.pop_front().map(Self::decode)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
 }
 
 /// Real-time update object.
@@ -83,7 +155,10 @@ pub enum Update {
 ///
 ///
 /// [`PubNub API`]: https://www.pubnub.com/docs
-#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+///
+/// Body deserialized without buffering (unlike `untagged` enums) to keep raw
+/// payloads of real-time updates (see [`RawPayload`]) captured from the
+/// response instead of parsing them.
 pub enum SubscribeResponseBody {
     /// This is success response body for subscribe operation in the Subscriber
     /// service.
@@ -191,7 +266,7 @@ pub struct APISuccessBody {
     /// Contains list of real-time updates received using previous subscription
     /// cursor.
     #[cfg_attr(feature = "serde", serde(rename = "m"))]
-    pub messages: Vec<Envelope>,
+    pub messages: Updates,
 }
 
 /// Single entry from subscribe response
@@ -209,8 +284,11 @@ pub struct Envelope {
     /// PubNub defined event type.
     #[cfg_attr(
         feature = "serde",
-        serde(rename = "f"),
-        serde(default = "Envelope::default_message_type")
+        serde(rename = "e"),
+        serde(
+            default = "Envelope::default_message_type",
+            deserialize_with = "Envelope::deserialize_message_type"
+        )
     )]
     pub message_type: SubscribeMessageType,
 
@@ -241,8 +319,15 @@ pub struct Envelope {
 
     /// Event payload.
     ///
-    /// Depending from
+    /// Payload is kept in raw form and decoded (depending from
+    /// [`Envelope::message_type`]) only when [`Update`] is created from the
+    /// envelope.
+    #[cfg(feature = "serde")]
     #[cfg_attr(feature = "serde", serde(rename = "d"))]
+    pub payload: RawPayload,
+
+    /// Event payload.
+    #[cfg(not(feature = "serde"))]
     pub payload: EnvelopePayload,
 
     /// Actual name of subscription through which event has been delivered.
@@ -276,7 +361,6 @@ pub struct Envelope {
 /// Depending from [`Envelope::message_type`] field value payload can been
 /// represented in different ways.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
 pub enum EnvelopePayload {
     /// Presence change real-time update.
     ///
@@ -379,7 +463,7 @@ pub enum EnvelopePayload {
 
     /// Real-time message update.
     #[cfg(feature = "serde")]
-    Message(serde_json::Value),
+    Message(RawPayload),
 
     /// Real-time message update.
     #[cfg(not(feature = "serde"))]
@@ -487,11 +571,11 @@ pub enum ObjectDataBody {
 pub struct MessageActionDataBody {
     /// Timetoken of message for which action has been added / removed.
     #[cfg_attr(feature = "serde", serde(rename(deserialize = "messageTimetoken")))]
-    pub message_timetoken: String,
+    pub message_timetoken: Timetoken,
 
     /// Timetoken of message action which has been added / removed.
     #[cfg_attr(feature = "serde", serde(rename(deserialize = "actionTimetoken")))]
-    pub action_timetoken: String,
+    pub action_timetoken: Timetoken,
 
     /// Message action type.
     pub r#type: String,
@@ -516,17 +600,11 @@ impl TryFrom<SubscribeResponseBody> for SubscribeResult {
 
     fn try_from(value: SubscribeResponseBody) -> Result<Self, Self::Error> {
         match value {
-            SubscribeResponseBody::SuccessResponse(resp) => {
-                let mut messages = Vec::new();
-                for message in resp.messages {
-                    messages.push(message.try_into()?)
-                }
-
-                Ok(SubscribeResult {
-                    cursor: resp.cursor,
-                    messages,
-                })
-            }
+            SubscribeResponseBody::SuccessResponse(resp) => Ok(SubscribeResult {
+                cursor: resp.cursor,
+                messages: resp.messages,
+                metadata: Default::default(),
+            }),
             SubscribeResponseBody::ErrorResponse(resp) => Err(resp.into()),
         }
     }
@@ -538,6 +616,24 @@ impl Envelope {
     fn default_message_type() -> SubscribeMessageType {
         SubscribeMessageType::Message
     }
+
+    /// Deserialize numeric message type.
+    ///
+    /// Unknown message types are treated as regular messages.
+    fn deserialize_message_type<'de, D>(deserializer: D) -> Result<SubscribeMessageType, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let message_type = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+
+        Ok(match message_type {
+            1 => SubscribeMessageType::Signal,
+            2 => SubscribeMessageType::Object,
+            3 => SubscribeMessageType::MessageAction,
+            4 => SubscribeMessageType::File,
+            _ => SubscribeMessageType::Message,
+        })
+    }
 }
 
 #[cfg(feature = "std")]
@@ -546,13 +642,13 @@ impl Update {
     ///
     /// Name of channel or channel group on which client subscribed and through
     /// which real-time update has been delivered.
-    pub(crate) fn subscription(&self) -> String {
+    pub(crate) fn subscription(&self) -> &str {
         match self {
             Self::Presence(presence) => presence.subscription(),
             Self::AppContext(object) => object.subscription(),
-            Self::MessageAction(reaction) => reaction.subscription.clone(),
-            Self::File(file) => file.subscription.clone(),
-            Self::Message(message) | Self::Signal(message) => message.subscription.clone(),
+            Self::MessageAction(reaction) => &reaction.subscription,
+            Self::File(file) => &file.subscription,
+            Self::Message(message) | Self::Signal(message) => &message.subscription,
         }
     }
 
@@ -576,51 +672,524 @@ impl TryFrom<Envelope> for Update {
     type Error = PubNubError;
 
     fn try_from(value: Envelope) -> Result<Self, Self::Error> {
-        match value.payload {
-            EnvelopePayload::Presence { .. } => Ok(Update::Presence(value.try_into()?)),
-            EnvelopePayload::Object { .. }
-                if matches!(value.message_type, SubscribeMessageType::Object) =>
-            {
-                Ok(Update::AppContext(value.try_into()?))
-            }
-            EnvelopePayload::MessageAction { .. }
-                if matches!(value.message_type, SubscribeMessageType::MessageAction) =>
-            {
-                Ok(Update::MessageAction(value.try_into()?))
+        if value.is_presence() {
+            return Ok(Update::Presence(value.try_into()?));
+        }
+
+        match value.message_type {
+            SubscribeMessageType::Object => Ok(Update::AppContext(value.try_into()?)),
+            SubscribeMessageType::MessageAction => Ok(Update::MessageAction(value.try_into()?)),
+            SubscribeMessageType::File => Ok(Update::File(value.try_into()?)),
+            SubscribeMessageType::Signal => Ok(Update::Signal(value.try_into()?)),
+            SubscribeMessageType::Message => Ok(Update::Message(value.try_into()?)),
+        }
+    }
+}
+
+impl Envelope {
+    /// Check whether envelope contains presence event.
+    ///
+    /// Presence events are delivered through `-pnpres` channels.
+    fn is_presence(&self) -> bool {
+        self.channel.ends_with("-pnpres")
+    }
+}
+
+impl EnvelopePayload {
+    /// Decode real-time update payload.
+    ///
+    /// Payload variant is selected by envelope's `message_type` (presence
+    /// events are delivered through `-pnpres` channels). Messages and signals
+    /// payloads aren't parsed and moved into [`EnvelopePayload::Message`] as
+    /// is.
+    #[cfg(feature = "serde")]
+    pub(crate) fn decode(
+        payload: RawPayload,
+        message_type: &SubscribeMessageType,
+        channel: &str,
+    ) -> Result<Self, PubNubError> {
+        if channel.ends_with("-pnpres") {
+            return payload.decode::<PresencePayload>().map(Into::into);
+        }
+
+        match message_type {
+            SubscribeMessageType::Object => payload.decode::<ObjectPayload>().map(Into::into),
+            SubscribeMessageType::MessageAction => {
+                payload.decode::<MessageActionPayload>().map(Into::into)
             }
-            EnvelopePayload::File { .. }
-                if matches!(value.message_type, SubscribeMessageType::File) =>
-            {
-                Ok(Update::File(value.try_into()?))
+            SubscribeMessageType::File => payload.decode::<FilePayload>().map(Into::into),
+            SubscribeMessageType::Message | SubscribeMessageType::Signal => {
+                Ok(Self::Message(payload))
             }
-            EnvelopePayload::Message(_) => {
-                if matches!(value.message_type, SubscribeMessageType::Message) {
-                    Ok(Update::Message(value.try_into()?))
-                } else {
-                    Ok(Update::Signal(value.try_into()?))
+        }
+    }
+
+    /// Decode real-time update payload.
+    #[cfg(not(feature = "serde"))]
+    pub(crate) fn decode(
+        payload: Self,
+        _message_type: &SubscribeMessageType,
+        _channel: &str,
+    ) -> Result<Self, PubNubError> {
+        Ok(payload)
+    }
+}
+
+/// Raw real-time update payload.
+///
+/// JSON of the payload captured from the subscribe response as is (without
+/// parsing). Payload is decoded only when [`Update`] is created from the
+/// [`Envelope`], and messages / signals payloads are used as is.
+///
+/// Deserializers which can't provide raw JSON (like `simd-json`) are
+/// supported as well, but for them payload is parsed and serialized back.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone)]
+pub struct RawPayload(Box<RawValue>);
+
+#[cfg(feature = "serde")]
+impl RawPayload {
+    /// Raw JSON of the payload.
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+
+    /// Decode payload as `T`.
+    fn decode<T>(&self) -> Result<T, PubNubError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_str(self.get()).map_err(|error| PubNubError::Deserialization {
+            details: error_details!(error),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl PartialEq for RawPayload {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<RawPayload> for Bytes {
+    fn from(value: RawPayload) -> Self {
+        String::from(Box::<str>::from(value.0)).into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RawPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        <Box<RawValue> as serde::Deserialize>::deserialize(RawPayloadDeserializer(deserializer))
+            .map(Self)
+    }
+}
+
+/// Deserializer which provides [`RawValue`] with any deserializer.
+///
+/// `serde_json` hands raw JSON to [`RawValue`] as is. Other deserializers (and
+/// `serde` buffered content) parse value, which then is serialized back to
+/// JSON.
+#[cfg(feature = "serde")]
+struct RawPayloadDeserializer<D>(D);
+
+#[cfg(feature = "serde")]
+impl<'de, D> serde::Deserializer<'de> for RawPayloadDeserializer<D>
+where
+    D: serde::Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0.deserialize_any(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0
+            .deserialize_newtype_struct(name, RawPayloadVisitor { name, visitor })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct seq tuple tuple_struct map
+        struct enum identifier ignored_any
+    }
+}
+
+/// [`RawValue`] visitor wrapper.
+///
+/// Deserializers without raw JSON support call `visit_newtype_struct`, and
+/// serialized value is passed to [`RawValue`] visitor in the same form as
+/// `serde_json` does (map with single entry).
+#[cfg(feature = "serde")]
+struct RawPayloadVisitor<V> {
+    name: &'static str,
+    visitor: V,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V> serde::de::Visitor<'de> for RawPayloadVisitor<V>
+where
+    V: serde::de::Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("any valid JSON value")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        self.visitor.visit_map(map)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+        let json = serde_json::to_string(&value).map_err(D::Error::custom)?;
+
+        self.visitor
+            .visit_map(serde::de::value::MapDeserializer::<_, D::Error>::new(
+                iter::once((self.name, json)),
+            ))
+    }
+}
+
+/// Presence event payload.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct PresencePayload {
+    action: Option<String>,
+    timestamp: usize,
+    uuid: Option<String>,
+    occupancy: Option<usize>,
+    data: Option<serde_json::Value>,
+    join: Option<Vec<String>>,
+    leave: Option<Vec<String>>,
+    timeout: Option<Vec<String>>,
+}
+
+#[cfg(feature = "serde")]
+impl From<PresencePayload> for EnvelopePayload {
+    fn from(value: PresencePayload) -> Self {
+        Self::Presence {
+            action: value.action,
+            timestamp: value.timestamp,
+            uuid: value.uuid,
+            occupancy: value.occupancy,
+            data: value.data,
+            join: value.join,
+            leave: value.leave,
+            timeout: value.timeout,
+        }
+    }
+}
+
+/// App Context event payload.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ObjectPayload {
+    event: String,
+    r#type: String,
+    data: ObjectDataBody,
+    source: String,
+    version: String,
+}
+
+#[cfg(feature = "serde")]
+impl From<ObjectPayload> for EnvelopePayload {
+    fn from(value: ObjectPayload) -> Self {
+        Self::Object {
+            event: value.event,
+            r#type: value.r#type,
+            data: value.data,
+            source: value.source,
+            version: value.version,
+        }
+    }
+}
+
+/// Message action event payload.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MessageActionPayload {
+    event: String,
+    data: MessageActionDataBody,
+    source: String,
+    version: String,
+}
+
+#[cfg(feature = "serde")]
+impl From<MessageActionPayload> for EnvelopePayload {
+    fn from(value: MessageActionPayload) -> Self {
+        Self::MessageAction {
+            event: value.event,
+            data: value.data,
+            source: value.source,
+            version: value.version,
+        }
+    }
+}
+
+/// File event payload.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FilePayload {
+    message: String,
+    file: FileDataBody,
+}
+
+#[cfg(feature = "serde")]
+impl From<FilePayload> for EnvelopePayload {
+    fn from(value: FilePayload) -> Self {
+        Self::File {
+            message: value.message,
+            file: value.file,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SubscribeResponseBody {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SubscribeResponseBodyVisitor)
+    }
+}
+
+/// Subscribe response body visitor.
+///
+/// Response with next subscription cursor and list of updates is treated as
+/// successful. Fields of other responses are collected to parse them as
+/// [`APIErrorBody`].
+#[cfg(feature = "serde")]
+struct SubscribeResponseBodyVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for SubscribeResponseBodyVisitor {
+    type Value = SubscribeResponseBody;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("subscribe response body")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut cursor = None;
+        let mut messages = None;
+        let mut fields = serde_json::Map::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "t" => cursor = Some(map.next_value()?),
+                "m" => messages = Some(map.next_value()?),
+                _ => {
+                    fields.insert(key, map.next_value()?);
                 }
             }
-            _ => Err(PubNubError::Deserialization {
-                details: "Unable deserialize unknown payload".to_string(),
-            }),
         }
+
+        if let Some((cursor, messages)) = cursor.zip(messages) {
+            return Ok(SubscribeResponseBody::SuccessResponse(APISuccessBody {
+                cursor,
+                messages,
+            }));
+        }
+
+        <APIErrorBody as serde::Deserialize>::deserialize(serde_json::Value::Object(fields))
+            .map(SubscribeResponseBody::ErrorResponse)
+            .map_err(A::Error::custom)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let value = <serde_json::Value as serde::Deserialize>::deserialize(
+            serde::de::value::SeqAccessDeserializer::new(seq),
+        )?;
+
+        <APIErrorBody as serde::Deserialize>::deserialize(value)
+            .map(SubscribeResponseBody::ErrorResponse)
+            .map_err(A::Error::custom)
     }
 }
 
-impl From<EnvelopePayload> for Vec<u8> {
+impl From<EnvelopePayload> for Bytes {
     #[cfg(feature = "serde")]
     fn from(value: EnvelopePayload) -> Self {
         if let EnvelopePayload::Message(payload) = value {
-            return serde_json::to_vec(&payload).unwrap_or_default();
+            return payload.into();
         }
-        vec![]
+        Bytes::new()
     }
 
     #[cfg(not(feature = "serde"))]
     fn from(value: EnvelopePayload) -> Self {
         if let EnvelopePayload::Message(payload) = value {
-            return payload;
+            return payload.into();
         }
-        vec![]
+        Bytes::new()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod should {
+    use super::*;
+
+    fn envelope(channel: &str, payload: &str) -> Envelope {
+        serde_json::from_str(&format!(
+            r#"{{"a":"1","f":0,"p":{{"t":"17000000000000000","r":31}},"c":"{channel}","d":{payload}}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn capture_raw_message_payload() {
+        let payload = r#"{ "text": "hello",  "event":"custom" }"#;
+        let envelope = envelope("channel", payload);
+
+        assert_eq!(envelope.payload.get(), payload);
+    }
+
+    #[test]
+    fn keep_message_payload_with_structured_fields() {
+        let payload = r#"{"action":"join","timestamp":1700000000,"event":"custom","source":"app"}"#;
+        let update = Update::try_from(envelope("channel", payload)).unwrap();
+
+        let Update::Message(message) = update else {
+            panic!("Expected message update");
+        };
+        assert_eq!(message.data, payload.as_bytes());
+    }
+
+    #[test]
+    fn decode_presence_payload_for_presence_channel() {
+        let envelope = envelope(
+            "channel-pnpres",
+            r#"{"action":"join","timestamp":1700000000,"uuid":"user-1","occupancy":1}"#,
+        );
+
+        assert!(matches!(
+            EnvelopePayload::decode(envelope.payload, &envelope.message_type, &envelope.channel),
+            Ok(EnvelopePayload::Presence {
+                timestamp: 1_700_000_000,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn not_decode_payload_of_unexpected_type() {
+        let envelope: Envelope = serde_json::from_str(
+            r#"{"a":"1","f":0,"e":3,"p":{"t":"17000000000000000","r":31},"c":"channel","d":"ping"}"#,
+        )
+        .unwrap();
+
+        assert!(Update::try_from(envelope).is_err());
+    }
+
+    #[test]
+    fn capture_payload_with_deserializer_without_raw_value_support() {
+        let envelope: Envelope = serde_json::from_value(serde_json::json!({
+            "a": "1",
+            "f": 0,
+            "p": { "t": "17000000000000000", "r": 31 },
+            "c": "channel",
+            "d": { "text": "hello" }
+        }))
+        .unwrap();
+
+        assert_eq!(envelope.payload.get(), r#"{"text":"hello"}"#);
+    }
+
+    #[test]
+    fn decode_updates_one_by_one() {
+        let body: SubscribeResponseBody = serde_json::from_str(
+            r#"{"t":{"t":"17000000000000000","r":31},"m":[{"a":"1","f":0,"p":{"t":"17000000000000000","r":31},"c":"channel","d":"hello"},{"a":"1","d":"broken"}]}"#,
+        )
+        .unwrap();
+        let mut result = SubscribeResult::try_from(body).unwrap();
+
+        assert_eq!(result.messages.len(), 2);
+        assert!(matches!(
+            result.messages.next(),
+            Some(Ok(Update::Message(_)))
+        ));
+        assert_eq!(result.messages.len(), 1);
+        assert!(matches!(result.messages.next(), Some(Err(_))));
+        assert!(result.messages.is_empty());
+    }
+
+    #[test]
+    fn deserialize_error_response() {
+        let body: SubscribeResponseBody = serde_json::from_str(
+            r#"{"status":403,"error":true,"service":"Access Manager","message":"Forbidden"}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(body, SubscribeResponseBody::ErrorResponse(_)));
+    }
+
+    #[test]
+    fn deserialize_message_type_from_e_field() {
+        let envelope: Envelope = serde_json::from_str(
+            r#"{"a":"1","f":0,"e":1,"p":{"t":"17000000000000000","r":31},"c":"channel","d":"ping"}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            envelope.message_type,
+            SubscribeMessageType::Signal
+        ));
+    }
+
+    #[test]
+    fn not_read_message_type_from_debug_flags() {
+        let envelope: Envelope = serde_json::from_str(
+            r#"{"a":"1","f":514,"e":4,"p":{"t":"17000000000000000","r":31},"c":"channel","d":{}}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(envelope.message_type, SubscribeMessageType::File));
+    }
+
+    #[test]
+    fn default_unknown_message_type_to_message() {
+        let envelope = envelope("channel", r#""hello""#);
+
+        assert!(matches!(
+            envelope.message_type,
+            SubscribeMessageType::Message
+        ));
     }
 }
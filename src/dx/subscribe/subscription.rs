@@ -4,7 +4,8 @@
 //! subscription to the specific entity and attach listeners to process
 //! real-time events triggered for the `entity`.
 
-use spin::RwLock;
+use crate::lib::sync::RwLock;
+use futures::Stream;
 use uuid::Uuid;
 
 use crate::core::{Deserializer, Transport};
@@ -23,6 +24,8 @@ use crate::{
             cmp::PartialEq,
             fmt::{Debug, Formatter, Result},
             ops::{Add, Deref, DerefMut, Drop},
+            pin::Pin,
+            task::{Context, Poll},
         },
     },
     subscribe::{
@@ -89,6 +92,41 @@ use crate::{
 /// #     Ok(())
 /// # }
 /// ```
+///
+/// ### Stream of updates
+///
+/// [`Subscription`] is a [`Stream`] of real-time updates, so it can be used
+/// with `StreamExt` combinators directly.
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use pubnub::{
+///     subscribe::{EventSubscriber, Subscriber, Update},
+///     Keyset, PubNubClient, PubNubClientBuilder,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let pubnub = // PubNubClient
+/// #     PubNubClientBuilder::with_reqwest_transport()
+/// #         .with_keyset(Keyset {
+/// #              subscribe_key: "demo",
+/// #              publish_key: Some("demo"),
+/// #              secret_key: Some("demo")
+/// #          })
+/// #         .with_user_id("uuid")
+/// #         .build()?;
+/// let mut subscription = pubnub.channel("my_channel").subscription(None);
+/// subscription.subscribe();
+///
+/// while let Some(update) = subscription.next().await {
+///     if let Update::Message(message) = update.as_ref() {
+///         println!("message: {:?}", message.data);
+///     }
+/// }
+/// #     Ok(())
+/// # }
+/// ```
 pub struct Subscription<
     T: Transport + Send + Sync + 'static,
     D: Deserializer + Send + Sync + 'static,
@@ -98,6 +136,11 @@ pub struct Subscription<
 
     /// Whether subscription is `Clone::clone()` method call result or not.
     is_clone: bool,
+
+    /// Real-time updates stream.
+    ///
+    /// Stream created on first poll of [`Subscription`] as [`Stream`].
+    updates: Option<DataStream<Arc<Update>>>,
 }
 
 /// Subscription reference
@@ -189,6 +232,7 @@ where
         Self {
             inner: SubscriptionRef::new(client, entity, options),
             is_clone: false,
+            updates: None,
         }
     }
 
@@ -236,6 +280,7 @@ where
         Self {
             inner: self.inner.clone_empty(),
             is_clone: false,
+            updates: None,
         }
     }
 }
@@ -272,10 +317,27 @@ where
         Self {
             inner: self.inner.clone(),
             is_clone: true,
+            updates: None,
         }
     }
 }
 
+impl<T, D> Stream for Subscription<T, D>
+where
+    T: Transport + Send + Sync,
+    D: Deserializer + Send + Sync,
+{
+    type Item = Arc<Update>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let inner = &this.inner;
+        let updates = this.updates.get_or_insert_with(|| inner.stream());
+
+        Pin::new(updates).poll_next(ctx)
+    }
+}
+
 impl<T, D> Drop for Subscription<T, D>
 where
     T: Transport + Send + Sync + 'static,
@@ -457,23 +519,23 @@ where
     ///
     /// # Returns
     ///
-    /// A new `Vec<Update>` containing only the events that satisfy the
+    /// A new `Vec<Arc<Update>>` containing only the events that satisfy the
     /// following conditions:
     /// 1. The event's subscription is present in the subscription input.
     /// 2. The event's timestamp is greater than or equal to the current
     ///    timetoken.
-    fn filtered_events(&self, events: &[Update]) -> Vec<Update> {
+    fn filtered_events(&self, events: &[Arc<Update>]) -> Vec<Arc<Update>> {
         let subscription_input = self.subscription_input(true);
         let current_timetoken = self.current_timetoken();
 
         events
             .iter()
             .filter(|event| {
-                subscription_input.contains(&event.subscription())
+                subscription_input.contains(event.subscription())
                     && event.event_timestamp().ge(&current_timetoken)
             })
             .cloned()
-            .collect::<Vec<Update>>()
+            .collect::<Vec<Arc<Update>>>()
     }
 }
 
@@ -576,7 +638,7 @@ where
     T: Transport + Send + Sync + 'static,
     D: Deserializer + Send + Sync + 'static,
 {
-    fn handle_events(&self, cursor: SubscriptionCursor, events: &[Update]) {
+    fn handle_events(&self, cursor: SubscriptionCursor, events: &[Arc<Update>]) {
         if !self.is_subscribed() {
             return;
         }
@@ -595,9 +657,7 @@ where
         // Go through subscription clones and trigger events for them.
         self.clones.write().retain(|_, handler| {
             if let Some(handler) = handler.upgrade().clone() {
-                handler
-                    .event_dispatcher
-                    .handle_events(filtered_events.clone());
+                handler.event_dispatcher.handle_events(&filtered_events);
                 return true;
             }
             false
@@ -670,7 +730,7 @@ where
         self.event_dispatcher.presence_stream()
     }
 
-    fn stream(&self) -> DataStream<Update> {
+    fn stream(&self) -> DataStream<Arc<Update>> {
         self.event_dispatcher.stream()
     }
 }
@@ -695,10 +755,11 @@ where
         };
         let entity_names = entity.names(with_presence);
 
-        let input = SubscriptionInput::new(
-            &is_channel_type.then(|| entity_names.clone()),
-            &(!is_channel_type).then_some(entity_names),
-        );
+        let input = if is_channel_type {
+            SubscriptionInput::with_names(Some(entity_names), None::<Vec<_>>)
+        } else {
+            SubscriptionInput::with_names(None::<Vec<_>>, Some(entity_names))
+        };
 
         Self {
             id: Uuid::new_v4().to_string(),
@@ -729,7 +790,9 @@ where
 #[cfg(test)]
 mod it_should {
     use super::*;
-    use crate::{Channel, ChannelGroup, Keyset, PubNubClient, PubNubClientBuilder};
+    use crate::{
+        subscribe::Subscriber, Channel, ChannelGroup, Keyset, PubNubClient, PubNubClientBuilder,
+    };
 
     fn client() -> PubNubClient {
         PubNubClientBuilder::with_reqwest_transport()
@@ -782,6 +845,21 @@ mod it_should {
             .contains_channel("channel-pnpres"));
     }
 
+    #[test]
+    fn share_interned_channel_names_between_subscriptions() {
+        let client = Arc::new(client());
+        let subscription_a = client.channel("channel").subscription(None);
+        let subscription_b = client.channel("channel").subscription(None);
+
+        let name_a = subscription_a.subscription_input.channels.as_ref().unwrap();
+        let name_b = subscription_b.subscription_input.channels.as_ref().unwrap();
+        let name_a = name_a.get("channel").unwrap();
+        let name_b = name_b.get("channel").unwrap();
+
+        assert!(name_a.ptr_eq(name_b));
+        assert!(name_a.ptr_eq(&client.channel_name("channel")));
+    }
+
     #[test]
     fn create_subscription_from_channel_group_entity() {
         let client = Arc::new(client());
@@ -859,21 +937,40 @@ mod it_should {
         let _ = subscription.messages_stream();
 
         assert_eq!(
-            subscription
-                .event_dispatcher
-                .message_streams
-                .read()
-                .as_ref()
-                .unwrap()
-                .len(),
+            subscription.event_dispatcher.message_streams.load().len(),
             1
         );
         assert!(subscription
             .clone_empty()
             .event_dispatcher
             .message_streams
-            .read()
-            .as_ref()
-            .is_none());
+            .load()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn receive_updates_when_polled_as_stream() {
+        use futures::StreamExt;
+
+        let client = Arc::new(client());
+        let mut subscription = Subscription::new(
+            Arc::downgrade(&client),
+            PubNubEntity::Channel(Channel::new(&client, "channel")),
+            None,
+        );
+        let update = Update::Message(Message {
+            channel: "channel".into(),
+            subscription: "channel".into(),
+            data: b"\"hello\"".to_vec().into(),
+            ..Default::default()
+        });
+
+        subscription
+            .event_dispatcher
+            .handle_events(&[update.into()]);
+
+        assert!(
+            matches!(subscription.next().await.as_deref(), Some(Update::Message(message)) if message.channel == "channel")
+        );
     }
 }
@@ -3,18 +3,21 @@
 //! This module contains manager which is responsible for tracking and updating
 //! active subscription streams.
 
-use spin::RwLock;
+use crate::lib::sync::RwLock;
 
 use crate::core::{Deserializer, Transport};
 use crate::subscribe::traits::EventHandler;
 use crate::{
-    dx::subscribe::{
-        event_engine::{
-            event::SubscribeEvent, SubscribeEffectInvocation, SubscribeEventEngine,
-            SubscriptionInput,
+    dx::{
+        health::EventEngineStatus,
+        subscribe::{
+            event_engine::{
+                event::SubscribeEvent, SubscribeEffectInvocation, SubscribeEventEngine,
+                SubscribeState, SubscriptionInput,
+            },
+            result::Update,
+            ConnectionStatus, PubNubClientInstance, Subscription, SubscriptionCursor,
         },
-        result::Update,
-        ConnectionStatus, PubNubClientInstance, Subscription, SubscriptionCursor,
     },
     lib::{
         alloc::{
@@ -131,6 +134,9 @@ where
     }
 
     pub fn notify_new_messages(&self, cursor: SubscriptionCursor, events: Vec<Update>) {
+        // Updates are shared between all listeners.
+        let events = events.into_iter().map(Arc::new).collect::<Vec<_>>();
+
         if let Some(client) = self.client() {
             client.handle_events(cursor.clone(), &events)
         }
@@ -284,6 +290,46 @@ where
             .stop(SubscribeEffectInvocation::TerminateEventEngine);
     }
 
+    /// Whether subscription event engine stopped and all in-flight effects
+    /// finished or not.
+    pub fn is_terminated(&self) -> bool {
+        self.event_engine.is_stopped()
+    }
+
+    /// Current subscription event engine status.
+    pub(crate) fn status(&self) -> EventEngineStatus {
+        match self.event_engine.current_state() {
+            SubscribeState::Unsubscribed => EventEngineStatus::Idle,
+            SubscribeState::Handshaking { .. } => EventEngineStatus::Connecting,
+            SubscribeState::Receiving { .. } => EventEngineStatus::Connected,
+            SubscribeState::HandshakeReconnecting { .. }
+            | SubscribeState::ReceiveReconnecting { .. } => EventEngineStatus::Reconnecting,
+            SubscribeState::HandshakeStopped { .. } | SubscribeState::ReceiveStopped { .. } => {
+                EventEngineStatus::Stopped
+            }
+            SubscribeState::HandshakeFailed { .. } | SubscribeState::ReceiveFailed { .. } => {
+                EventEngineStatus::Failed
+            }
+        }
+    }
+
+    /// Number of registered event handlers which are still in use.
+    #[cfg(feature = "metrics")]
+    pub fn active_handlers_count(&self) -> usize {
+        self.event_handlers
+            .read()
+            .values()
+            .filter(|weak_handler| weak_handler.strong_count() > 0)
+            .count()
+    }
+
+    /// Number of effect invocations waiting for dispatch by subscription
+    /// event engine.
+    #[cfg(feature = "metrics")]
+    pub fn queue_depth(&self) -> usize {
+        self.event_engine.queue_depth()
+    }
+
     fn change_subscription(&self, removed: Option<&SubscriptionInput>) {
         let mut inputs = self.current_input();
 
@@ -371,7 +417,7 @@ mod should {
 
     use super::*;
     use crate::{
-        core::RequestRetryConfiguration,
+        core::{RequestRetryConfiguration, UuidProvider},
         dx::subscribe::{
             event_engine::{SubscribeEffectHandler, SubscribeState},
             result::SubscribeResult,
@@ -405,6 +451,7 @@ mod should {
                         Ok(SubscribeResult {
                             cursor: Default::default(),
                             messages: Default::default(),
+                            metadata: Default::default(),
                         })
                     }
                     .boxed()
@@ -415,8 +462,9 @@ mod should {
                 Arc::new(Box::new(|_, _| {
                     // Do nothing yet
                 })),
-                RequestRetryConfiguration::None,
+                Arc::new(RwLock::new(RequestRetryConfiguration::None)),
                 cancel_tx,
+                Arc::new(UuidProvider),
             ),
             SubscribeState::Unsubscribed,
             RuntimeTokio,
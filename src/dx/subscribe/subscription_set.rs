@@ -4,7 +4,8 @@
 //! manage subscription to the entities represented by managed subscriptions and
 //! attach listeners to the specific event types.
 
-use spin::RwLock;
+use crate::lib::sync::RwLock;
+use futures::Stream;
 use uuid::Uuid;
 
 use crate::core::{Deserializer, Transport};
@@ -23,7 +24,10 @@ use crate::{
         core::{
             fmt::{Debug, Formatter, Result},
             ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
+            pin::Pin,
+            task::{Context, Poll},
         },
+        log,
     },
     subscribe::{
         event_engine::SubscriptionInput, AppContext, EventDispatcher, EventEmitter,
@@ -97,6 +101,11 @@ pub struct SubscriptionSet<
 
     /// Whether subscription set is `Clone::clone()` method call result or not.
     is_clone: bool,
+
+    /// Real-time updates stream.
+    ///
+    /// Stream created on first poll of [`SubscriptionSet`] as [`Stream`].
+    updates: Option<DataStream<Arc<Update>>>,
 }
 
 /// Entities subscriptions set reference.
@@ -140,7 +149,10 @@ pub struct SubscriptionSetState<
     pub(super) id: String,
 
     /// [`PubNubClientInstance`] which is backing which subscription set.
-    pub(super) client: Weak<PubNubClientInstance<T, D>>,
+    ///
+    /// Empty subscription set doesn't have a client until first subscription
+    /// is added.
+    pub(super) client: RwLock<Weak<PubNubClientInstance<T, D>>>,
 
     /// Grouped subscriptions list.
     pub(crate) subscriptions: RwLock<Vec<Subscription<T, D>>>,
@@ -191,6 +203,7 @@ where
         Self {
             inner: SubscriptionSetRef::new(entities, options),
             is_clone: false,
+            updates: None,
         }
     }
 
@@ -206,10 +219,8 @@ where
     /// # Returns
     ///
     /// A new [`SubscriptionSet`] containing given subscriptions and `options`.
-    ///
-    /// # Panics
-    ///
-    /// This function will panic if the `subscriptions` vector is empty.
+    /// Empty set will be created if the `subscriptions` vector is empty (see
+    /// [`SubscriptionSet::is_empty`]).
     pub fn new_with_subscriptions(
         subscriptions: Vec<Subscription<T, D>>,
         options: Option<Vec<SubscriptionOptions>>,
@@ -217,6 +228,7 @@ where
         Self {
             inner: SubscriptionSetRef::new_with_subscriptions(subscriptions, options),
             is_clone: false,
+            updates: None,
         }
     }
 
@@ -265,9 +277,49 @@ where
         Self {
             inner: self.inner.clone_empty(),
             is_clone: false,
+            updates: None,
         }
     }
 
+    /// Checks whether the set has any subscriptions or not.
+    ///
+    /// Set created from the empty list of channels and groups won't receive
+    /// any real-time updates, so this method can be used to guard dynamically
+    /// built lists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{subscribe::SubscriptionParams, Keyset, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: Some("demo")
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// let channels: Vec<&str> = vec![];
+    /// let subscription = pubnub.subscription(SubscriptionParams {
+    ///     channels: Some(&channels),
+    ///     channel_groups: None,
+    ///     options: None,
+    /// });
+    /// assert!(subscription.is_empty());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if there are no subscriptions in set, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.subscriptions.read().is_empty()
+    }
+
     /// Adds a list of subscriptions to the subscription set.
     ///
     /// # Arguments
@@ -319,9 +371,14 @@ where
         {
             let mut subscription_input = self.subscription_input.write();
             *subscription_input += Self::subscription_input_from_list(&unique_subscriptions, true);
-            self.subscriptions
-                .write()
-                .extend(unique_subscriptions.clone());
+            let mut subscriptions_slot = self.subscriptions.write();
+            // Empty set gets client from the first added subscription.
+            if subscriptions_slot.is_empty() {
+                if let Some(subscription) = unique_subscriptions.first() {
+                    *self.client.write() = subscription.client();
+                }
+            }
+            subscriptions_slot.extend(unique_subscriptions.clone());
         }
 
         // Check whether subscription change required or not.
@@ -531,10 +588,27 @@ where
         Self {
             inner: self.inner.clone(),
             is_clone: true,
+            updates: None,
         }
     }
 }
 
+impl<T, D> Stream for SubscriptionSet<T, D>
+where
+    T: Transport + Send + Sync,
+    D: Deserializer + Send + Sync,
+{
+    type Item = Arc<Update>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let inner = &this.inner;
+        let updates = this.updates.get_or_insert_with(|| inner.stream());
+
+        Pin::new(updates).poll_next(ctx)
+    }
+}
+
 impl<T, D> Drop for SubscriptionSet<T, D>
 where
     T: Transport + Send + Sync,
@@ -688,18 +762,20 @@ where
     ///
     /// A new [`SubscriptionSet`] containing given subscriptions and `options`.
     ///
-    /// # Panics
-    ///
-    /// This function will panic if the `subscriptions` vector is empty.
+    /// Empty set will be created if `subscriptions` vector is empty. Such set
+    /// won't subscribe until subscriptions are added to it.
     pub(crate) fn new_with_subscriptions(
         subscriptions: Vec<Subscription<T, D>>,
         options: Option<Vec<SubscriptionOptions>>,
     ) -> Arc<Self> {
-        let subscription = subscriptions
+        let client = subscriptions
             .first()
-            .expect("At least one subscription expected.");
-        let subscription_state =
-            SubscriptionSetState::new(subscription.client(), subscriptions, options);
+            .map(|subscription| subscription.client())
+            .unwrap_or_else(|| {
+                log::warn!("Subscription set created without channels and groups.");
+                Weak::new()
+            });
+        let subscription_state = SubscriptionSetState::new(client, subscriptions, options);
         let subscription_set = Arc::new(Self {
             instance_id: Uuid::new_v4().to_string(),
             state: Arc::new(subscription_state),
@@ -823,23 +899,23 @@ where
     ///
     /// # Returns
     ///
-    /// A new `Vec<Update>` containing only the events that satisfy the
+    /// A new `Vec<Arc<Update>>` containing only the events that satisfy the
     /// following conditions:
     /// 1. The event's subscription is present in the subscription input.
     /// 2. The event's timestamp is greater than or equal to the current
     ///    timetoken.
-    fn filtered_events(&self, events: &[Update]) -> Vec<Update> {
+    fn filtered_events(&self, events: &[Arc<Update>]) -> Vec<Arc<Update>> {
         let subscription_input = self.subscription_input(true);
         let current_timetoken = self.current_timetoken();
 
         events
             .iter()
             .filter(|event| {
-                subscription_input.contains(&event.subscription())
+                subscription_input.contains(event.subscription())
                     && event.event_timestamp().ge(&current_timetoken)
             })
             .cloned()
-            .collect::<Vec<Update>>()
+            .collect::<Vec<Arc<Update>>>()
     }
 }
 
@@ -872,6 +948,11 @@ where
     D: Deserializer + Send + Sync + 'static,
 {
     fn subscribe(&self) {
+        if self.subscriptions.read().is_empty() {
+            log::warn!("Subscribe with empty subscription set ignored.");
+            return;
+        }
+
         let mut is_subscribed = self.is_subscribed.write();
         if *is_subscribed {
             return;
@@ -885,6 +966,11 @@ where
     where
         SC: Into<SubscriptionCursor>,
     {
+        if self.subscriptions.read().is_empty() {
+            log::warn!("Subscribe with empty subscription set ignored.");
+            return;
+        }
+
         let mut is_subscribed = self.is_subscribed.write();
         if *is_subscribed {
             return;
@@ -948,7 +1034,7 @@ where
     T: Transport + Send + Sync + 'static,
     D: Deserializer + Send + Sync + 'static,
 {
-    fn handle_events(&self, cursor: SubscriptionCursor, events: &[Update]) {
+    fn handle_events(&self, cursor: SubscriptionCursor, events: &[Arc<Update>]) {
         if !self.is_subscribed() {
             return;
         }
@@ -967,9 +1053,7 @@ where
         // Go through subscription clones and trigger events for them.
         self.clones.write().retain(|_, handler| {
             if let Some(handler) = handler.upgrade().clone() {
-                handler
-                    .event_dispatcher
-                    .handle_events(filtered_events.clone());
+                handler.event_dispatcher.handle_events(&filtered_events);
                 return true;
             }
             false
@@ -1002,7 +1086,7 @@ where
     }
 
     fn client(&self) -> Weak<PubNubClientInstance<T, D>> {
-        self.client.clone()
+        self.client.read().clone()
     }
 }
 
@@ -1035,7 +1119,7 @@ where
         self.event_dispatcher.presence_stream()
     }
 
-    fn stream(&self) -> DataStream<Update> {
+    fn stream(&self) -> DataStream<Arc<Update>> {
         self.event_dispatcher.stream()
     }
 }
@@ -1052,7 +1136,7 @@ where
     ) -> SubscriptionSetState<T, D> {
         Self {
             id: Uuid::new_v4().to_string(),
-            client,
+            client: RwLock::new(client),
             subscription_input: RwLock::new(SubscriptionSet::subscription_input_from_list(
                 &subscriptions,
                 true,
@@ -1119,6 +1203,21 @@ mod it_should {
             .contains("channel_2"));
     }
 
+    #[test]
+    fn create_empty_subscription_set() {
+        let client = Arc::new(client());
+        let mut subscription_set = SubscriptionSet::new(vec![], None);
+
+        assert!(subscription_set.is_empty());
+        subscription_set.subscribe();
+        assert!(!subscription_set.is_subscribed());
+
+        let channel = Channel::new(&client, "channel_1");
+        subscription_set.add_subscriptions(vec![channel.subscription(None)]);
+        assert!(!subscription_set.is_empty());
+        assert!(subscription_set.client().upgrade().is_some());
+    }
+
     #[test]
     fn preserve_id_between_clones() {
         let client = Arc::new(client());
@@ -1148,9 +1247,7 @@ mod it_should {
                 .clone()
                 .event_dispatcher
                 .message_streams
-                .read()
-                .as_ref()
-                .unwrap()
+                .load()
                 .len(),
             1
         );
@@ -1158,9 +1255,8 @@ mod it_should {
             .clone_empty()
             .event_dispatcher
             .message_streams
-            .read()
-            .as_ref()
-            .is_none());
+            .load()
+            .is_empty());
     }
 
     #[test]
@@ -1194,4 +1290,29 @@ mod it_should {
             .subscription_input(true)
             .contains_channel("channel_1"));
     }
+
+    #[tokio::test]
+    async fn receive_updates_when_polled_as_stream() {
+        use futures::StreamExt;
+
+        let client = Arc::new(client());
+        let mut subscription = SubscriptionSet::new(
+            vec![PubNubEntity::Channel(Channel::new(&client, "channel"))],
+            None,
+        );
+        let update = Update::Message(Message {
+            channel: "channel".into(),
+            subscription: "channel".into(),
+            data: b"\"hello\"".to_vec().into(),
+            ..Default::default()
+        });
+
+        subscription
+            .event_dispatcher
+            .handle_events(&[update.into()]);
+
+        assert!(
+            matches!(subscription.next().await.as_deref(), Some(Update::Message(message)) if message.channel == "channel")
+        );
+    }
 }
@@ -3,9 +3,20 @@
 //! This module contains the [`EventEmitter`] trait, which is used to implement
 //! a real-time event emitter.
 
+use futures::Stream;
+
 use crate::{
-    core::DataStream,
-    subscribe::{AppContext, File, Message, MessageAction, Presence, Update},
+    core::{DataStream, PubNubMessage},
+    lib::{
+        alloc::sync::Arc,
+        core::{
+            marker::PhantomData,
+            pin::Pin,
+            task::{Context, Poll},
+        },
+        log,
+    },
+    subscribe::{AppContext, File, Message, MessageAction, Presence, TypedMessage, Update},
 };
 
 /// Events emitter trait.
@@ -32,5 +43,60 @@ pub trait EventEmitter {
     fn presence_stream(&self) -> DataStream<Presence>;
 
     /// Generic stream used to notify all updates mentioned above.
-    fn stream(&self) -> DataStream<Update>;
+    ///
+    /// Updates are shared with other listeners through [`Arc`].
+    fn stream(&self) -> DataStream<Arc<Update>>;
+
+    /// Stream used to notify regular messages of specific type.
+    ///
+    /// Only messages with `type` equal to [`PubNubMessage::MESSAGE_TYPE`] are
+    /// notified. Messages which can't be decoded are skipped.
+    fn typed_messages_stream<M>(&self) -> TypedMessagesStream<M>
+    where
+        M: PubNubMessage,
+        Self: Sized,
+    {
+        TypedMessagesStream {
+            stream: self.messages_stream(),
+            _message: PhantomData,
+        }
+    }
+}
+
+/// Typed messages stream.
+///
+/// Stream decodes regular messages with `M` type tag using its
+/// [`PubNubMessage`] implementation.
+#[derive(Debug)]
+pub struct TypedMessagesStream<M> {
+    /// Regular messages stream.
+    stream: DataStream<Message>,
+
+    _message: PhantomData<fn() -> M>,
+}
+
+impl<M> Stream for TypedMessagesStream<M>
+where
+    M: PubNubMessage,
+{
+    type Item = TypedMessage<M>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let message = match Pin::new(&mut self.stream).poll_next(ctx) {
+                Poll::Ready(Some(message)) => message,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            if message.r#type.as_deref() != Some(M::MESSAGE_TYPE) {
+                continue;
+            }
+
+            match M::from_payload(&message.data) {
+                Ok(data) => return Poll::Ready(Some(TypedMessage { data, message })),
+                Err(error) => log::warn!("Unable to decode '{}' message: {error}", M::MESSAGE_TYPE),
+            }
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     dx::pubnub_client::PubNubClientInstance,
-    lib::alloc::sync::Weak,
+    lib::alloc::sync::{Arc, Weak},
     subscribe::{event_engine::SubscriptionInput, SubscriptionCursor, Update},
 };
 
@@ -14,7 +14,7 @@ pub trait EventHandler<T, D> {
     ///
     /// * `cursor` - A time cursor for next portion of events.
     /// * `events` - A slice of real-time events from multiplexed subscription.
-    fn handle_events(&self, cursor: SubscriptionCursor, events: &[Update]);
+    fn handle_events(&self, cursor: SubscriptionCursor, events: &[Arc<Update>]);
 
     /// Returns a reference to the subscription input associated with this event
     /// handler.
@@ -16,7 +16,7 @@ pub use subscribable::{Subscribable, SubscribableType};
 mod subscribable;
 
 #[doc(inline)]
-pub use event_emitter::EventEmitter;
+pub use event_emitter::{EventEmitter, TypedMessagesStream};
 mod event_emitter;
 
 #[doc(inline)]
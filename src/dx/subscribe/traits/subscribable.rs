@@ -6,8 +6,9 @@
 //! [`PubNub`]: https://www.pubnub.com
 
 use crate::{
+    core::ChannelName,
     dx::pubnub_client::PubNubClientInstance,
-    lib::alloc::{string::String, sync::Weak, vec::Vec},
+    lib::alloc::{sync::Weak, vec::Vec},
 };
 
 /// Types of subscribable objects.
@@ -32,8 +33,8 @@ pub enum SubscribableType {
 pub trait Subscribable<T, D> {
     /// Names for object to be used in subscription.
     ///
-    /// Provided strings will be used with multiplexed subscribe REST API call.
-    fn names(&self, presence: bool) -> Vec<String>;
+    /// Provided names will be used with multiplexed subscribe REST API call.
+    fn names(&self, presence: bool) -> Vec<ChannelName>;
 
     /// Type of subscription object.
     fn r#type(&self) -> SubscribableType;
@@ -1,9 +1,10 @@
 //! Subscription types module.
 
 use base64::{engine::general_purpose, Engine};
+use bytes::Bytes;
 
 use crate::{
-    core::{CryptoProvider, PubNubError, ScalarValue},
+    core::{CryptoProvider, PubNubError, ScalarValue, Timetoken},
     dx::subscribe::result::{Envelope, EnvelopePayload, ObjectDataBody, Update},
     lib::{
         alloc::{
@@ -109,6 +110,31 @@ pub struct SubscriptionParams<'subscription, N: Into<String>> {
     pub options: Option<Vec<SubscriptionOptions>>,
 }
 
+/// Snapshot of the active subscription.
+///
+/// Channels and groups for which [`PubNubClientInstance`] currently receives
+/// real-time updates (combined from all subscribed [`Subscription`] and
+/// [`SubscriptionSet`]).
+///
+/// [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`Subscription`]: crate::subscribe::Subscription
+/// [`SubscriptionSet`]: crate::subscribe::SubscriptionSet
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscribedChannels {
+    /// Sorted list of subscribed channel names.
+    pub channels: Vec<String>,
+
+    /// Sorted list of subscribed channel group names.
+    pub channel_groups: Vec<String>,
+}
+
+impl SubscribedChannels {
+    /// Check whether there are no active subscriptions.
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty() && self.channel_groups.is_empty()
+    }
+}
+
 /// Time cursor.
 ///
 /// Cursor used by subscription loop to identify point in time after
@@ -467,7 +493,7 @@ pub struct Message {
     pub subscription: String,
 
     /// Data published along with message / signal.
-    pub data: Vec<u8>,
+    pub data: Bytes,
 
     /// User provided message type (set only when [`publish`] called with
     /// `r#type`).
@@ -488,6 +514,21 @@ pub struct Message {
     pub decryption_error: Option<PubNubError>,
 }
 
+/// Typed message information.
+///
+/// [`TypedMessage`] type provides to the typed messages stream listener
+/// message decoded with its [`PubNubMessage`] implementation.
+///
+/// [`PubNubMessage`]: crate::core::PubNubMessage
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedMessage<M> {
+    /// Message decoded from received payload.
+    pub data: M,
+
+    /// Received message information.
+    pub message: Message,
+}
+
 /// Message's action update information.
 ///
 /// [`MessageAction`] type provides to the updates listener message's action
@@ -510,10 +551,10 @@ pub struct MessageAction {
     pub subscription: String,
 
     /// Timetoken of message for which action has been added / removed.
-    pub message_timetoken: String,
+    pub message_timetoken: Timetoken,
 
     /// Timetoken of message action which has been added / removed.
-    pub action_timetoken: String,
+    pub action_timetoken: Timetoken,
 
     /// Message action type.
     pub r#type: String,
@@ -600,26 +641,26 @@ impl PartialOrd for SubscriptionCursor {
     }
 
     fn lt(&self, other: &Self) -> bool {
-        let lhs = self.timetoken.parse::<u64>().expect("Invalid timetoken");
-        let rhs = other.timetoken.parse::<u64>().expect("Invalid timetoken");
+        let lhs = Timetoken::try_from(self).expect("Invalid timetoken");
+        let rhs = Timetoken::try_from(other).expect("Invalid timetoken");
         lhs < rhs
     }
 
     fn le(&self, other: &Self) -> bool {
-        let lhs = self.timetoken.parse::<u64>().expect("Invalid timetoken");
-        let rhs = other.timetoken.parse::<u64>().expect("Invalid timetoken");
+        let lhs = Timetoken::try_from(self).expect("Invalid timetoken");
+        let rhs = Timetoken::try_from(other).expect("Invalid timetoken");
         lhs <= rhs
     }
 
     fn gt(&self, other: &Self) -> bool {
-        let lhs = self.timetoken.parse::<u64>().expect("Invalid timetoken");
-        let rhs = other.timetoken.parse::<u64>().expect("Invalid timetoken");
+        let lhs = Timetoken::try_from(self).expect("Invalid timetoken");
+        let rhs = Timetoken::try_from(other).expect("Invalid timetoken");
         lhs > rhs
     }
 
     fn ge(&self, other: &Self) -> bool {
-        let lhs = self.timetoken.parse::<u64>().expect("Invalid timetoken");
-        let rhs = other.timetoken.parse::<u64>().expect("Invalid timetoken");
+        let lhs = Timetoken::try_from(self).expect("Invalid timetoken");
+        let rhs = Timetoken::try_from(other).expect("Invalid timetoken");
         lhs >= rhs
     }
 }
@@ -696,6 +737,20 @@ impl From<u64> for SubscriptionCursor {
     }
 }
 
+impl From<Timetoken> for SubscriptionCursor {
+    fn from(value: Timetoken) -> Self {
+        value.as_u64().into()
+    }
+}
+
+impl TryFrom<&SubscriptionCursor> for Timetoken {
+    type Error = PubNubError;
+
+    fn try_from(value: &SubscriptionCursor) -> Result<Self, Self::Error> {
+        value.timetoken.parse()
+    }
+}
+
 impl TryFrom<String> for ObjectEvent {
     type Error = PubNubError;
 
@@ -704,7 +759,7 @@ impl TryFrom<String> for ObjectEvent {
             "update" => Ok(Self::Update),
             "delete" => Ok(Self::Delete),
             _ => Err(PubNubError::Deserialization {
-                details: "Unable deserialize: unexpected object event type".to_string(),
+                details: error_details!("Unable deserialize: unexpected object event type"),
             }),
         }
     }
@@ -718,7 +773,7 @@ impl TryFrom<String> for MessageActionEvent {
             "update" => Ok(Self::Update),
             "delete" => Ok(Self::Delete),
             _ => Err(PubNubError::Deserialization {
-                details: "Unable deserialize: unexpected message action event type".to_string(),
+                details: error_details!("Unable deserialize: unexpected message action event type"),
             }),
         }
     }
@@ -767,13 +822,13 @@ impl Presence {
     ///
     /// Name of channel or channel group on which client subscribed and through
     /// which presence update has been delivered.
-    pub(crate) fn subscription(&self) -> String {
+    pub(crate) fn subscription(&self) -> &str {
         match self {
             Self::Join { subscription, .. }
             | Self::Leave { subscription, .. }
             | Self::Timeout { subscription, .. }
             | Self::Interval { subscription, .. }
-            | Self::StateChange { subscription, .. } => subscription.clone(),
+            | Self::StateChange { subscription, .. } => subscription,
         }
     }
 
@@ -809,11 +864,11 @@ impl AppContext {
     ///
     /// Name of channel or channel group on which client subscribed and through
     /// which object update has been triggered.
-    pub(crate) fn subscription(&self) -> String {
+    pub(crate) fn subscription(&self) -> &str {
         match self {
             Self::Channel { subscription, .. }
             | Self::Uuid { subscription, .. }
-            | Self::Membership { subscription, .. } => subscription.clone(),
+            | Self::Membership { subscription, .. } => subscription,
         }
     }
 
@@ -850,20 +905,66 @@ impl Update {
 }
 
 impl Message {
+    /// Message / signal data as JSON value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Deserialization`] if [`data`] isn't valid JSON
+    /// (for example, when it can't be decrypted).
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::subscribe::Message;
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let message = Message {
+    ///     data: br#"{"text":"hello"}"#.to_vec().into(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(message.data_as_value()?["text"], "hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`data`]: Message::data
+    #[cfg(feature = "serde")]
+    pub fn data_as_value(&self) -> Result<serde_json::Value, PubNubError> {
+        self.data_as()
+    }
+
+    /// Message / signal data deserialized as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Deserialization`] if [`data`] can't be
+    /// deserialized as `T`.
+    ///
+    /// [`data`]: Message::data
+    #[cfg(feature = "serde")]
+    pub fn data_as<T>(&self) -> Result<T, PubNubError>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        serde_json::from_slice(&self.data).map_err(|err| PubNubError::Deserialization {
+            details: error_details!(err),
+        })
+    }
+
     /// Decrypt message payload if possible.
     fn decrypt(mut self, cryptor: &Arc<dyn CryptoProvider + Send + Sync>) -> Self {
-        let lossy_string = String::from_utf8_lossy(self.data.as_slice()).to_string();
+        let lossy_string = String::from_utf8_lossy(&self.data).to_string();
         let trimmed = lossy_string.trim_matches('"');
         let decryption_result = general_purpose::STANDARD
             .decode(trimmed)
             .map_err(|err| PubNubError::Decryption {
-                details: err.to_string(),
+                details: error_details!(err),
             })
             .and_then(|base64_bytes| cryptor.decrypt(base64_bytes));
 
         match decryption_result {
             Ok(bytes) => {
-                self.data = bytes;
+                self.data = bytes.into();
             }
             Err(error) => self.decryption_error = Some(error),
         };
@@ -877,6 +978,7 @@ impl TryFrom<Envelope> for Presence {
 
     fn try_from(value: Envelope) -> Result<Self, Self::Error> {
         let event_timestamp = value.published.timetoken.parse::<usize>().ok().unwrap_or(0);
+        let payload = EnvelopePayload::decode(value.payload, &value.message_type, &value.channel)?;
         if let EnvelopePayload::Presence {
             action,
             timestamp,
@@ -886,7 +988,7 @@ impl TryFrom<Envelope> for Presence {
             join,
             leave,
             timeout,
-        } = value.payload
+        } = payload
         {
             let action = action.unwrap_or("interval".to_string());
 
@@ -951,7 +1053,7 @@ impl TryFrom<Envelope> for Presence {
             }
         } else {
             Err(PubNubError::Deserialization {
-                details: "Unable deserialize: unexpected payload for presence.".to_string(),
+                details: error_details!("Unable deserialize: unexpected payload for presence."),
             })
         }
     }
@@ -962,12 +1064,13 @@ impl TryFrom<Envelope> for AppContext {
 
     fn try_from(value: Envelope) -> Result<Self, Self::Error> {
         let timestamp = value.published.timetoken.parse::<usize>();
+        let payload = EnvelopePayload::decode(value.payload, &value.message_type, &value.channel)?;
         if let EnvelopePayload::Object {
             event,
             r#type,
             data,
             ..
-        } = value.payload
+        } = payload
         {
             let update_type = r#type;
             let subscription = resolve_subscription_value(value.subscription, &value.channel);
@@ -1065,17 +1168,17 @@ impl TryFrom<Envelope> for AppContext {
                         })
                     } else {
                         Err(PubNubError::Deserialization {
-                            details: "Unable deserialize: unknown object type.".to_string(),
+                            details: error_details!("Unable deserialize: unknown object type."),
                         })
                     }
                 }
                 _ => Err(PubNubError::Deserialization {
-                    details: "Unable deserialize: unknown object type.".to_string(),
+                    details: error_details!("Unable deserialize: unknown object type."),
                 }),
             }
         } else {
             Err(PubNubError::Deserialization {
-                details: "Unable deserialize: unexpected payload for object.".to_string(),
+                details: error_details!("Unable deserialize: unexpected payload for object."),
             })
         }
     }
@@ -1090,20 +1193,21 @@ impl TryFrom<Envelope> for Message {
         let timestamp = value.published.timetoken.parse::<usize>().ok().unwrap_or(0);
         let subscription = resolve_subscription_value(value.subscription, &value.channel);
 
-        if let EnvelopePayload::Message(_) = value.payload {
+        let payload = EnvelopePayload::decode(value.payload, &value.message_type, &value.channel)?;
+        if let EnvelopePayload::Message(_) = payload {
             Ok(Self {
                 sender: value.sender,
                 timestamp,
                 channel: value.channel,
                 subscription,
-                data: value.payload.into(),
+                data: payload.into(),
                 r#type: value.r#type,
                 space_id: value.space_id,
                 decryption_error: None,
             })
         } else {
             Err(PubNubError::Deserialization {
-                details: "Unable deserialize: unexpected payload for message.".to_string(),
+                details: error_details!("Unable deserialize: unexpected payload for message."),
             })
         }
     }
@@ -1122,7 +1226,8 @@ impl TryFrom<Envelope> for MessageAction {
 
         let subscription = resolve_subscription_value(value.subscription, &value.channel);
 
-        if let EnvelopePayload::MessageAction { event, data, .. } = value.payload {
+        let payload = EnvelopePayload::decode(value.payload, &value.message_type, &value.channel)?;
+        if let EnvelopePayload::MessageAction { event, data, .. } = payload {
             Ok(Self {
                 event: event.try_into()?,
                 sender,
@@ -1136,7 +1241,9 @@ impl TryFrom<Envelope> for MessageAction {
             })
         } else {
             Err(PubNubError::Deserialization {
-                details: "Unable deserialize: unexpected payload for message action.".to_string(),
+                details: error_details!(
+                    "Unable deserialize: unexpected payload for message action."
+                ),
             })
         }
     }
@@ -1155,7 +1262,8 @@ impl TryFrom<Envelope> for File {
 
         let subscription = resolve_subscription_value(value.subscription, &value.channel);
 
-        if let EnvelopePayload::File { message, file } = value.payload {
+        let payload = EnvelopePayload::decode(value.payload, &value.message_type, &value.channel)?;
+        if let EnvelopePayload::File { message, file } = payload {
             Ok(Self {
                 sender,
                 timestamp,
@@ -1167,7 +1275,7 @@ impl TryFrom<Envelope> for File {
             })
         } else {
             Err(PubNubError::Deserialization {
-                details: "Unable deserialize: unexpected payload for file.".to_string(),
+                details: error_details!("Unable deserialize: unexpected payload for file."),
             })
         }
     }
@@ -1184,6 +1292,26 @@ mod should {
 
     use super::*;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn return_message_data_as_value() {
+        let message = Message {
+            data: br#"{"text":"hello","count":2}"#.to_vec().into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            message.data_as_value().unwrap(),
+            serde_json::json!({"text": "hello", "count": 2})
+        );
+        assert!(Message {
+            data: b"not json".to_vec().into(),
+            ..Default::default()
+        }
+        .data_as_value()
+        .is_err());
+    }
+
     #[test_case(
         None,
         "channel" => "channel".to_string();
@@ -1238,6 +1366,18 @@ mod should {
         assert!(cursor.is_valid())
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn convert_subscription_cursor_to_and_from_timetoken() {
+        let timetoken = Timetoken::new(12345678901234567);
+        let cursor: SubscriptionCursor = timetoken.into();
+        assert!(cursor.is_valid());
+        assert_eq!(Timetoken::try_from(&cursor).unwrap(), timetoken);
+
+        let cursor: SubscriptionCursor = "123".into();
+        assert!(Timetoken::try_from(&cursor).is_err());
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn create_invalid_subscription_cursor_from_short_string() {
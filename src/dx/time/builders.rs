@@ -0,0 +1,104 @@
+//! # Time request builder.
+//!
+//! The [`TimeRequestBuilder`] lets you make and execute a request which will
+//! return current [`PubNub`] server time.
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{Deserializer, PubNubError, Transport, TransportMethod, TransportRequest},
+    dx::{
+        pubnub_client::PubNubClientInstance,
+        time::result::{TimeResponseBody, TimeResult},
+    },
+    lib::{alloc::string::String, collections::HashMap},
+};
+
+/// The Time request builder.
+///
+/// Allows you to build a Time request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`time`] method of the [`PubNubClient`].
+///
+/// [`time`]: crate::dx::pubnub_client::PubNubClientInstance::time
+/// [`PubNubClient`]: crate::dx::pubnub_client::PubNubClientInstance
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Builder, Debug)]
+#[builder(pattern = "owned", build_fn(vis = "pub(in crate::dx::time)"), no_std)]
+pub struct TimeRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::time)"), setter(custom))]
+    pub(in crate::dx::time) pubnub_client: PubNubClientInstance<T, D>,
+}
+
+impl_from_builder_error!(TimeRequestBuilderError);
+
+impl<T, D> TimeRequestBuilder<T, D> {
+    /// Build [`TimeRequest`] from builder.
+    fn request(self) -> Result<TimeRequest<T, D>, PubNubError> {
+        self.build().map_err(PubNubError::from)
+    }
+}
+
+impl<T, D> TimeRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::time) fn transport_request(&self) -> TransportRequest {
+        TransportRequest {
+            path: String::from("/time/0"),
+            query_parameters: HashMap::new(),
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: self.pubnub_client.request_timeout(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<T, D> TimeRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<TimeResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<TimeResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.retry_configuration(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+                #[cfg(feature = "std")]
+                &client.retry_notifier,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> TimeRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<TimeResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send_blocking::<TimeResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
@@ -0,0 +1,97 @@
+//! # Time module.
+//!
+//! The time module allows to retrieve current [`PubNub`] server time. Server
+//! timetoken can be used to measure device clock skew or as a cheap network
+//! reachability probe.
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use result::{TimeResponseBody, TimeResult};
+pub mod result;
+
+#[doc(inline)]
+pub use builders::{TimeRequest, TimeRequestBuilder};
+pub mod builders;
+
+use crate::{core::Deserializer, dx::pubnub_client::PubNubClientInstance};
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    D: Deserializer,
+{
+    /// Create a current server time request builder.
+    ///
+    /// This method is used to retrieve current [`PubNub`] server timetoken.
+    ///
+    /// Instance of [`TimeRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// # PubNubClientBuilder::with_reqwest_transport()
+    /// #     .with_keyset(Keyset {
+    /// #         subscribe_key: "demo",
+    /// #         publish_key: Some("demo"),
+    /// #         secret_key: None,
+    /// #     })
+    /// #     .with_user_id("uuid")
+    /// #     .build()?;
+    /// let result = pubnub.time().execute().await?;
+    ///
+    /// println!("Server timetoken: {}", result.timetoken);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PubNub`]: https://www.pubnub.com
+    pub fn time(&self) -> TimeRequestBuilder<T, D> {
+        TimeRequestBuilder {
+            pubnub_client: Some(self.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use crate::{
+        core::{PubNubError, Transport, TransportRequest, TransportResponse},
+        Keyset, PubNubClientBuilder,
+    };
+
+    #[derive(Default)]
+    struct MockTransport;
+
+    impl Transport for MockTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            assert_eq!(request.path, "/time/0");
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(b"[17000000000000000]".to_vec().into()),
+                ..Default::default()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn return_server_timetoken() {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .build()
+            .unwrap();
+
+        let result = client.time().execute().await.unwrap();
+
+        assert_eq!(result.timetoken.as_u64(), 17_000_000_000_000_000);
+    }
+}
@@ -0,0 +1,57 @@
+//! Time result module.
+//!
+//! This module contains the [`TimeResult`] type.
+//! The [`TimeResult`] type is used to represent the result of a time operation.
+
+use crate::core::{service_response::APIErrorBody, PubNubError, ResponseMetadata, Timetoken};
+
+/// The result of a time operation.
+/// It contains the current [`PubNub`] server timetoken.
+///
+/// [`PubNub`]: https://www.pubnub.com
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeResult {
+    /// Current server timetoken.
+    pub timetoken: Timetoken,
+
+    /// Service response metadata.
+    pub(crate) metadata: ResponseMetadata,
+}
+
+impl_pubnub_response!(TimeResult);
+
+/// The response body of a time operation.
+///
+/// It's used for deserialization of the time response. This type is an
+/// intermediate type between the raw response body and the [`TimeResult`]
+/// type.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeResponseBody {
+    /// The response body of a time operation in time service.
+    /// It contains only current server timetoken.
+    ///
+    /// # Example
+    /// ```json
+    /// [17000000000000000]
+    /// ```
+    SuccessResponse([Timetoken; 1]),
+
+    /// The response body of a time operation in other services.
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<TimeResponseBody> for TimeResult {
+    type Error = PubNubError;
+
+    fn try_from(value: TimeResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            TimeResponseBody::SuccessResponse([timetoken]) => Ok(TimeResult {
+                timetoken,
+                metadata: Default::default(),
+            }),
+            TimeResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
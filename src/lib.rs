@@ -51,19 +51,14 @@
 //! Try the following sample code to get up and running quickly!
 //!
 //! ```no_run
-//! use pubnub::subscribe::Subscriber;
 //! use futures::StreamExt;
 //! use tokio::time::sleep;
 //! use std::time::Duration;
 //! use serde_json;
-//! use pubnub::{
-//!     dx::subscribe::Update,
-//!     subscribe::EventSubscriber,
-//!     Keyset, PubNubClientBuilder,
-//! };
+//! use pubnub::prelude::*;
+//!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
-//!     use pubnub::subscribe::{EventEmitter, SubscriptionParams};
 //!     let publish_key = "my_publish_key";
 //!     let subscribe_key = "my_subscribe_key";
 //!     let client = PubNubClientBuilder::with_reqwest_transport()
@@ -96,10 +91,10 @@
 //!         println!("\nStatus: {:?}", status)
 //!     }));
 //!     tokio::spawn(subscription.stream().for_each(|event| async move {
-//!         match event {
+//!         match event.as_ref() {
 //!             Update::Message(message) | Update::Signal(message) => {
 //!                 // Silently log if UTF-8 conversion fails
-//!                 if let Ok(utf8_message) = String::from_utf8(message.data.clone()) {
+//!                 if let Ok(utf8_message) = String::from_utf8(message.data.to_vec()) {
 //!                     if let Ok(cleaned) = serde_json::from_str::<String>(&utf8_message) {
 //!                         println!("message: {}", cleaned);
 //!                     }
@@ -125,7 +120,7 @@
 //!         channel_entity_subscription
 //!             .messages_stream()
 //!             .for_each(|message| async move {
-//!                 if let Ok(utf8_message) = String::from_utf8(message.data.clone()) {
+//!                 if let Ok(utf8_message) = String::from_utf8(message.data.to_vec()) {
 //!                     if let Ok(cleaned) = serde_json::from_str::<String>(&utf8_message) {
 //!                         println!("message: {}", cleaned);
 //!                     }
@@ -178,18 +173,38 @@
 //!
 //! | Feature name  | Description | Available PubNub APIs |
 //! | :------------ | :---------- | :------------- |
-//! | `full`        | Enables all non-conflicting features | Configuration, Publish, Subscribe, Access Manager, Parse Token, Presence, Crypto Module |
+//! | `full`        | Enables all non-conflicting features | Configuration, Publish, Subscribe, Access Manager, Parse Token, Presence, Message Persistence, Message Actions, App Context, Crypto Module |
 //! | `default`     | Enables default features: `publish`, `subscribe`, `serde`, `reqwest`, `std` | Configuration, Publish, Subscribe |
 //! | `publish`     | Enables Publish API | Configuration, Publish |
 //! | `access`      | Enables Access Manager API | Configuration, Access Manager |
 //! | `parse_token` | Enables parsing Access Manager tokens | Configuration, Parse Token |
 //! | `subscribe`   | Enables Subscribe API | Configuration, Subscribe |
 //! | `presence`    | Enables Presence API | Configuration, Presence |
+//! | `history`     | Enables Message Persistence API | Configuration, Message Persistence |
+//! | `message_actions` | Enables Message Actions API | Configuration, Message Actions |
+//! | `objects`     | Enables App Context (Objects) API | Configuration, App Context |
 //! | `tokio`       | Enables the [tokio](https://tokio.rs/) asynchronous runtime for Subscribe and Presence APIs | n/a  |
+//! | `embassy`     | Enables the [Embassy](https://embassy.dev) executor runtime for Subscribe and Presence APIs (requires `std`) | n/a |
+//! | `wasm`        | Enables browser runtime (`spawn_local` and [gloo](https://github.com/rustwasm/gloo) timers) for Subscribe and Presence APIs and `Blob` upload with Files API on `wasm32` targets (requires `std`) | Files |
+//! | `wasm_bindgen` | Enables registration of JavaScript callbacks (`on_message`, `on_signal`) for subscriptions updates with [wasm-bindgen](https://github.com/rustwasm/wasm-bindgen) | Subscribe |
 //! | `serde`       | Uses [serde](https://github.com/serde-rs/serde) for serialization | n/a |
+//! | `cbor`        | Enables [CBOR](https://cbor.io) serializer and deserializer providers (requires `serde`) | n/a |
+//! | `msgpack`     | Enables [MessagePack](https://msgpack.org) serializer and deserializer providers (requires `serde`) | n/a |
+//! | `simd_json`   | Enables [simd-json](https://github.com/simd-lite/simd-json) accelerated deserializer provider | n/a |
+//! | `heapless`    | Enables Publish request building into caller-provided fixed-size [heapless](https://github.com/rust-embedded/heapless) buffers and bounded raw subscriptions | Publish, Subscribe |
+//! | `mock`        | Enables `MockTransport` which can be used in tests without network access | n/a |
+//! | `emulator`    | Enables in-process `Emulator` of PubNub API for tests without network access | n/a |
+//! | `derive`      | Enables `PubNubMessage` derive macro for typed messages (requires `serde`) | Publish, Subscribe |
+//! | `chat`        | Enables high-level Chat API (rooms, typed messages, typing indicators and members) | Publish, Subscribe, Presence |
+//! | `entropy_source` | Enables user-provided entropy source (e.g. hardware RNG) for targets not supported by `getrandom` | n/a |
 //! | `reqwest`     | Uses [reqwest](https://github.com/seanmonstar/reqwest) as a transport layer | n/a |
-//! | `blocking`    | Enables blocking executions of APIs | n/a |
+//! | `embedded_nal` | Uses [embedded-nal](https://github.com/rust-embedded-community/embedded-nal) TCP stack with minimal HTTP/1.1 client as a blocking transport layer | n/a |
+//! | `event_source` | Uses browser [EventSource](https://developer.mozilla.org/en-US/docs/Web/API/EventSource) stream for real-time updates on `wasm32` targets (requires subscribe events gateway) | Subscribe |
+//! | `blocking`    | Enables blocking executions of APIs (without asynchronous runtime) | n/a |
 //! | `crypto`      | Enables crypto module for data encryption and decryption | n/a |
+//! | `defmt`       | Routes internal diagnostics through [defmt](https://defmt.ferrous-systems.com) instead of `log` | n/a |
+//! | `async_trait_compat` | Enables [async-trait](https://github.com/dtolnay/async-trait) based `AsyncTransport`, `AsyncRuntime` and `AsyncNetworkMonitor` traits for existing `Transport`, `Runtime` and `NetworkMonitor` implementations | n/a |
+//! | `static_errors` | Uses static descriptions instead of formatted `String` for `PubNubError` details | n/a |
 //! | `std`         | Enables `std` library | n/a |
 //!
 //! ## Documentation
@@ -202,6 +217,22 @@
 //! The `pubnub` crate is compatible with WebAssembly. You can use it in your
 //! Wasm project.
 //!
+//! Subscribe and Presence APIs require a runtime to spawn tasks and handle
+//! delays. In the browser (`wasm32-unknown-unknown` target) enable the `wasm`
+//! feature and configure client with `RuntimeWasm` (see
+//! `pubnub::providers::futures_wasm`). On `wasm32` targets futures aren't
+//! required to be `Send` (see `pubnub::core::MaybeSend`), so custom `Transport`
+//! and `Runtime` implementations can use plain `async fn` with `!Send` futures
+//! there.
+//! With the `event_source` feature, `TransportEventSource` receives real-time
+//! updates through a persistent `EventSource` stream (served by a subscribe
+//! events gateway) instead of long-poll `fetch` requests. The `wasm_bindgen`
+//! feature allows to register JavaScript functions as subscription listeners
+//! with `JsEventEmitter::on_message` and `JsEventEmitter::on_signal`.
+//! Files API (`send_file`) uploads browser `Blob` (or `File`) with
+//! `multipart/form-data` `fetch` request when the `wasm` and `serde` features
+//! are enabled.
+//!
 //! ## `no_std` support
 //!
 //! The `pubnub` crate is `no_std` compatible. To use it in a `no_std`
@@ -225,7 +256,8 @@
 //!
 //! Some SDK features aren't supported in a `no_std` environment:
 //!
-//! * partially `access` module (because of lack of timestamp support)
+//! * partially `access` module (because of lack of timestamp support, which can
+//!   be provided with `with_time_source` or `with_clock` client configuration)
 //! * partially `reqwest` transport (because of the reqwest implementation
 //!   details)
 //! * partially `subscribe` module (because of the spawning tasks and time
@@ -234,11 +266,24 @@
 //!   dependence)
 //! * `std` feature (because of the `std` library)
 //!
+//! Targets where heap fragmentation caused by repeated request building is a
+//! problem can enable the `heapless` feature and assemble publish request path
+//! and query into caller-provided fixed-size buffers with
+//! `StaticPublishRequest` (see `pubnub::dx::publish::static_buffer`). Raw
+//! subscription can be converted into `BoundedSubscription` which caps number
+//! of channels and buffered updates at compile time. The `static_errors`
+//! feature makes `PubNubError` carry static descriptions instead of formatted
+//! details, which avoids allocation in error paths.
+//!
 //! We depend on a random number generator to generate data for debugging
 //! purposes. If you want to use the SDK in a `no_std` environment, you'll have
 //! to provide your own random number generator implementation for certain
 //! targets.
 //!
+//! Enable the `entropy_source` feature and register your generator (for example,
+//! hardware RNG) with `with_entropy_source` client configuration method or
+//! `pubnub::core::entropy::set_entropy_source` function.
+//!
 //! See more:
 //!
 //! * [`getrandom` crate](https://docs.rs/getrandom/latest/getrandom/)
@@ -279,6 +324,53 @@ pub use dx::subscribe;
 #[doc(inline)]
 pub use dx::presence;
 
+#[cfg(feature = "history")]
+#[doc(inline)]
+pub use dx::history;
+
+#[cfg(feature = "message_actions")]
+#[doc(inline)]
+pub use dx::message_actions;
+
+#[cfg(feature = "objects")]
+#[doc(inline)]
+pub use dx::objects;
+
+#[cfg(feature = "chat")]
+#[doc(inline)]
+pub use dx::chat;
+
+#[cfg(all(feature = "wasm", feature = "serde", feature = "std"))]
+#[doc(inline)]
+pub use dx::files;
+
+#[cfg(any(
+    feature = "publish",
+    feature = "access",
+    feature = "subscribe",
+    feature = "presence",
+    feature = "history",
+    feature = "message_actions",
+    feature = "objects"
+))]
+#[doc(inline)]
+pub use dx::time;
+
+#[cfg(all(
+    any(
+        feature = "publish",
+        feature = "access",
+        feature = "subscribe",
+        feature = "presence",
+        feature = "history",
+        feature = "message_actions",
+        feature = "objects"
+    ),
+    feature = "std"
+))]
+#[doc(inline)]
+pub use dx::health;
+
 #[doc(inline)]
 pub use dx::{Keyset, PubNubClientBuilder, PubNubGenericClient};
 
@@ -286,17 +378,125 @@ pub use dx::{Keyset, PubNubClientBuilder, PubNubGenericClient};
 #[doc(inline)]
 pub use dx::PubNubClient;
 
+#[cfg(all(
+    feature = "reqwest",
+    feature = "serde",
+    feature = "blocking",
+    not(target_arch = "wasm32")
+))]
+#[doc(inline)]
+pub use dx::PubNubBlockingClient;
+
 #[cfg(feature = "std")]
 #[doc(inline)]
 pub use core::RequestRetryConfiguration;
 
 #[doc(inline)]
 pub use core::{Channel, ChannelGroup, ChannelMetadata, UserMetadata};
+
+#[cfg(feature = "derive")]
+pub use pubnub_derive::PubNubMessage;
+
+// Allows `PubNubMessage` derive macro usage in the crate's own tests.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as pubnub;
+
+#[macro_use]
 pub mod core;
 pub mod dx;
+pub mod prelude;
 pub mod providers;
 pub mod transport;
 
+#[cfg(any(test, feature = "mock"))]
+pub mod test;
+
+/// Items used by code generated with crate macros.
+///
+/// Not a public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::lib::alloc::{string::String, vec::Vec};
+    #[cfg(not(feature = "std"))]
+    pub use hashbrown::HashMap;
+    #[cfg(feature = "std")]
+    pub use std::collections::HashMap;
+
+    #[cfg(feature = "derive")]
+    use crate::core::PubNubError;
+
+    /// Serialize typed message payload with `serde`.
+    #[cfg(feature = "derive")]
+    pub fn serialize_message<M>(message: &M) -> Result<Vec<u8>, PubNubError>
+    where
+        M: serde::Serialize,
+    {
+        serde_json::to_vec(message).map_err(|e| PubNubError::Serialization {
+            details: error_details!(e),
+        })
+    }
+
+    /// Deserialize typed message payload with `serde`.
+    #[cfg(feature = "derive")]
+    pub fn deserialize_message<M>(payload: &[u8]) -> Result<M, PubNubError>
+    where
+        M: for<'de> serde::Deserialize<'de>,
+    {
+        serde_json::from_slice(payload).map_err(|e| PubNubError::Deserialization {
+            details: error_details!(e),
+        })
+    }
+}
+
+/// Create metadata map from `key => value` pairs.
+///
+/// Keys are converted into [`String`] and values with [`Into`] into the value
+/// type expected by the receiving method, so the same macro can be used for
+/// [`grant_token`] `meta` (values are converted into [`MetaValue`]) and
+/// [`publish_message`] `meta` (values should be strings).
+///
+/// # Example
+/// ```no_run
+/// # use pubnub::{metadata, PubNubClientBuilder, Keyset};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut pubnub = // PubNubClient
+/// #     PubNubClientBuilder::with_reqwest_transport()
+/// #         .with_keyset(Keyset {
+/// #              subscribe_key: "demo",
+/// #              publish_key: Some("demo"),
+/// #              secret_key: None,
+/// #          })
+/// #         .with_user_id("uuid")
+/// #         .build()?;
+/// pubnub
+///     .publish_message("hello")
+///     .channel("my_channel")
+///     .meta(metadata! { "role" => "admin", "lang" => "en" })
+///     .execute()
+///     .await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`grant_token`]: crate::dx::pubnub_client::PubNubClientInstance::grant_token
+/// [`publish_message`]: crate::dx::pubnub_client::PubNubClientInstance::publish_message
+/// [`MetaValue`]: crate::access::MetaValue
+#[macro_export]
+macro_rules! metadata {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut map = $crate::__private::HashMap::new();
+        $(
+            map.insert(
+                ::core::convert::Into::<$crate::__private::String>::into($key),
+                ::core::convert::Into::into($value),
+            );
+        )*
+        map
+    }};
+}
+
 /// A facade around all the std types that we use in the library.
 /// It is used to make the library `no_std` compatible.
 mod lib {
@@ -335,17 +535,137 @@ mod lib {
             pub(crate) use std::collections::HashMap;
         }
     }
-}
 
-// Mocking random for checking if `no_std` compiles.
-// Don't use that feature in production.
-#[cfg(feature = "mock_getrandom")]
-mod mock_getrandom {
-    use getrandom::{register_custom_getrandom, Error};
+    pub(crate) mod log {
+        //! Depending of the `defmt` feature, this module will re-export
+        //! either `log` crate macros or macros with the same names which
+        //! route diagnostics through `defmt` (messages are formatted with
+        //! `core::fmt` and sent as `defmt` strings).
+
+        #[cfg(not(feature = "defmt"))]
+        #[allow(unused_imports)]
+        pub(crate) use log::{debug, error, info, trace, warn};
 
-    pub fn do_nothing(_buf: &mut [u8]) -> Result<(), Error> {
-        Ok(())
+        #[cfg(feature = "defmt")]
+        macro_rules! defmt_log {
+            ($level:ident, $($arg:tt)+) => {
+                defmt::$level!("{}", defmt::Display2Format(&format_args!($($arg)+)))
+            };
+        }
+
+        #[cfg(feature = "defmt")]
+        #[allow(unused_macros)]
+        macro_rules! defmt_debug {
+            ($($arg:tt)+) => { $crate::lib::log::defmt_log!(debug, $($arg)+) };
+        }
+
+        #[cfg(feature = "defmt")]
+        #[allow(unused_macros)]
+        macro_rules! defmt_error {
+            ($($arg:tt)+) => { $crate::lib::log::defmt_log!(error, $($arg)+) };
+        }
+
+        #[cfg(feature = "defmt")]
+        #[allow(unused_macros)]
+        macro_rules! defmt_info {
+            ($($arg:tt)+) => { $crate::lib::log::defmt_log!(info, $($arg)+) };
+        }
+
+        #[cfg(feature = "defmt")]
+        #[allow(unused_macros)]
+        macro_rules! defmt_trace {
+            ($($arg:tt)+) => { $crate::lib::log::defmt_log!(trace, $($arg)+) };
+        }
+
+        #[cfg(feature = "defmt")]
+        #[allow(unused_macros)]
+        macro_rules! defmt_warn {
+            ($($arg:tt)+) => { $crate::lib::log::defmt_log!(warn, $($arg)+) };
+        }
+
+        #[cfg(feature = "defmt")]
+        #[allow(unused_imports)]
+        pub(crate) use {
+            defmt_debug as debug, defmt_error as error, defmt_info as info, defmt_log,
+            defmt_trace as trace, defmt_warn as warn,
+        };
     }
 
-    register_custom_getrandom!(do_nothing);
+    pub(crate) mod sync {
+        //! Depending of the `std` feature, this module will re-export either
+        //! blocking locks (which park thread while waiting for lock) or
+        //! `spin` locks (which busy-wait and don't require OS support).
+        //!
+        //! Blocking locks are wrapped to provide the same API as `spin`
+        //! locks (lock poisoning is ignored, because lock holder panic
+        //! doesn't leave protected data in inconsistent state).
+
+        #[cfg(not(feature = "std"))]
+        pub(crate) use spin::{Mutex, RwLock};
+        #[cfg(not(feature = "std"))]
+        #[allow(unused_imports)]
+        pub(crate) use spin::{MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+
+        #[cfg(feature = "std")]
+        pub(crate) use blocking::{Mutex, RwLock};
+        #[cfg(feature = "std")]
+        #[allow(unused_imports)]
+        pub(crate) use std::sync::{MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+
+        #[cfg(feature = "std")]
+        mod blocking {
+            use std::{
+                fmt::{Debug, Formatter, Result},
+                sync::{self, MutexGuard, PoisonError, RwLockReadGuard, RwLockWriteGuard},
+            };
+
+            /// Reader-writer lock which parks thread while waiting for lock.
+            #[derive(Default)]
+            pub(crate) struct RwLock<T: ?Sized>(sync::RwLock<T>);
+
+            impl<T> RwLock<T> {
+                pub(crate) const fn new(value: T) -> Self {
+                    Self(sync::RwLock::new(value))
+                }
+            }
+
+            impl<T: ?Sized> RwLock<T> {
+                pub(crate) fn read(&self) -> RwLockReadGuard<'_, T> {
+                    self.0.read().unwrap_or_else(PoisonError::into_inner)
+                }
+
+                pub(crate) fn write(&self) -> RwLockWriteGuard<'_, T> {
+                    self.0.write().unwrap_or_else(PoisonError::into_inner)
+                }
+            }
+
+            impl<T: ?Sized + Debug> Debug for RwLock<T> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            /// Mutual exclusion lock which parks thread while waiting for lock.
+            #[derive(Default)]
+            pub(crate) struct Mutex<T: ?Sized>(sync::Mutex<T>);
+
+            impl<T> Mutex<T> {
+                pub(crate) const fn new(value: T) -> Self {
+                    Self(sync::Mutex::new(value))
+                }
+            }
+
+            impl<T: ?Sized> Mutex<T> {
+                pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+                    self.0.lock().unwrap_or_else(PoisonError::into_inner)
+                }
+            }
+
+            impl<T: ?Sized + Debug> Debug for Mutex<T> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    self.0.fmt(f)
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,64 @@
+//! # PubNub prelude
+//!
+//! This module re-exports traits and types which are needed for typical usage
+//! of the PubNub SDK, so they can be imported all at once:
+//!
+//! ```
+//! use pubnub::prelude::*;
+//! ```
+//!
+//! Set of re-exported items depends from enabled [`features`].
+//!
+//! [`features`]: ../index.html#features
+
+#[doc(no_inline)]
+pub use crate::{
+    core::{Deserializer, PubNubError, PubNubMessage, PubNubResponse, Serialize, Transport},
+    metadata, Channel, ChannelGroup, ChannelMetadata, Keyset, PubNubClientBuilder,
+    PubNubGenericClient, UserMetadata,
+};
+
+#[cfg(feature = "derive")]
+#[doc(no_inline)]
+pub use crate::PubNubMessage;
+
+#[cfg(feature = "blocking")]
+#[doc(no_inline)]
+pub use crate::core::blocking::Transport as BlockingTransport;
+
+#[cfg(feature = "std")]
+#[doc(no_inline)]
+pub use crate::RequestRetryConfiguration;
+
+#[cfg(feature = "reqwest")]
+#[doc(no_inline)]
+pub use crate::PubNubClient;
+
+#[cfg(all(
+    feature = "reqwest",
+    feature = "serde",
+    feature = "blocking",
+    not(target_arch = "wasm32")
+))]
+#[doc(no_inline)]
+pub use crate::PubNubBlockingClient;
+
+#[cfg(feature = "publish")]
+#[doc(no_inline)]
+pub use crate::publish::{PublishMessageBuilder, PublishMessageViaChannelBuilder, PublishResult};
+
+#[cfg(feature = "subscribe")]
+#[doc(no_inline)]
+pub use crate::subscribe::{
+    ConnectionStatus, SubscriptionCursor, SubscriptionOptions, SubscriptionParams, Update,
+};
+
+#[cfg(all(feature = "subscribe", feature = "std"))]
+#[doc(no_inline)]
+pub use crate::subscribe::{
+    EventEmitter, EventSubscriber, Subscriber, Subscription, SubscriptionSet,
+};
+
+#[cfg(feature = "access")]
+#[doc(no_inline)]
+pub use crate::access::{permissions, GrantTokenResult, RevokeTokenResult};
@@ -2,7 +2,7 @@
 
 use crate::{
     core::{CryptoProvider, Cryptor, EncryptedData, PubNubError},
-    lib::alloc::{boxed::Box, format, string::String, vec, vec::Vec},
+    lib::alloc::{boxed::Box, string::String, vec, vec::Vec},
     providers::crypto::CryptorHeader,
 };
 
@@ -165,7 +165,7 @@ impl CryptoProvider for CryptoModule {
     fn decrypt(&self, data: Vec<u8>) -> Result<Vec<u8>, PubNubError> {
         if data.is_empty() {
             return Err(PubNubError::Decryption {
-                details: "Decrypted data is empty".into(),
+                details: error_details!("Decrypted data is empty"),
             });
         }
 
@@ -177,7 +177,7 @@ impl CryptoProvider for CryptoModule {
             let identifier = header.identifier().unwrap_or(*b"UNKN");
             // Looks like payload with unknown cryptor identifier has been received.
             Err(PubNubError::UnknownCryptor {
-                details: format!(
+                details: error_details!(
                     "Decrypting data created by unknown cryptor. Please make sure to register {} \
                     or update SDK",
                     String::from_utf8(identifier.to_vec()).unwrap_or("non-utf8 identifier".into())
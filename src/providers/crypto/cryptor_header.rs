@@ -148,14 +148,14 @@ impl TryFrom<&Vec<u8>> for CryptorHeader {
 
         let Some(version) = value.len().ge(&5).then(|| &value[4]) else {
             return Err(PubNubError::Decryption {
-                details: "Decrypted data header is malformed.".into(),
+                details: error_details!("Decrypted data header is malformed."),
             });
         };
 
         // Check whether version is within known range.
         if *version == 0 || *version > MAX_VERSION {
             return Err(PubNubError::UnknownCryptor {
-                details: "Decrypting data created by unknown cryptor.".into(),
+                details: error_details!("Decrypting data created by unknown cryptor."),
             });
         }
 
@@ -167,7 +167,7 @@ impl TryFrom<&Vec<u8>> for CryptorHeader {
             identifier
         }) else {
             return Err(PubNubError::Decryption {
-                details: "Decrypted data header is malformed.".into(),
+                details: error_details!("Decrypted data header is malformed."),
             });
         };
 
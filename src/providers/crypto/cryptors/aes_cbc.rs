@@ -8,7 +8,7 @@ use sha2::{Digest, Sha256};
 
 use crate::{
     core::{Cryptor, EncryptedData, PubNubError},
-    lib::alloc::{format, string::ToString, vec, vec::Vec},
+    lib::alloc::{vec, vec::Vec},
 };
 
 type Encryptor = cbc::Encryptor<aes::Aes256>;
@@ -38,7 +38,7 @@ impl AesCbcCryptor {
 
         if cipher_key.is_empty() {
             return Err(PubNubError::CryptoInitialization {
-                details: "Cipher key is empty".into(),
+                details: error_details!("Cipher key is empty"),
             });
         }
 
@@ -77,7 +77,7 @@ impl Cryptor for AesCbcCryptor {
     fn encrypt(&self, data: Vec<u8>) -> Result<EncryptedData, PubNubError> {
         if data.is_empty() {
             return Err(PubNubError::Encryption {
-                details: "Encrypted data is empty".into(),
+                details: error_details!("Encrypted data is empty"),
             });
         }
 
@@ -87,7 +87,7 @@ impl Cryptor for AesCbcCryptor {
         let result = Encryptor::new(self.cipher_key.as_slice().into(), iv.as_slice().into())
             .encrypt_padded_b2b_mut::<Pkcs7>(&data, &mut buffer)
             .map_err(|err| PubNubError::Encryption {
-                details: err.to_string(),
+                details: error_details!(err),
             })?;
         let encrypted_len = result.len();
 
@@ -104,13 +104,13 @@ impl Cryptor for AesCbcCryptor {
         let mut buffer = vec![0u8; self.estimated_dec_buffer_size(&data.data)];
         let Some(iv) = data.metadata else {
             return Err(PubNubError::Decryption {
-                details: "Initialization vector is missing from payload".into(),
+                details: error_details!("Initialization vector is missing from payload"),
             });
         };
 
         if iv.len().ne(&AES_BLOCK_SIZE) {
             return Err(PubNubError::Decryption {
-                details: format!(
+                details: error_details!(
                     "Unexpected initialization vector size: {} bytes ({} bytes is expected)",
                     iv.len(),
                     AES_BLOCK_SIZE
@@ -121,7 +121,7 @@ impl Cryptor for AesCbcCryptor {
         let result = Decryptor::new(self.cipher_key.as_slice().into(), iv.as_slice().into())
             .decrypt_padded_b2b_mut::<Pkcs7>(&data.data, buffer.as_mut())
             .map_err(|err| PubNubError::Decryption {
-                details: err.to_string(),
+                details: error_details!(err),
             })?;
 
         // Adjust size of buffer to actual processed data length.
@@ -8,12 +8,7 @@ use sha2::{Digest, Sha256};
 
 use crate::{
     core::{Cryptor, EncryptedData, PubNubError},
-    lib::alloc::{
-        format,
-        string::{String, ToString},
-        vec,
-        vec::Vec,
-    },
+    lib::alloc::{format, string::String, vec, vec::Vec},
 };
 
 type Encryptor = cbc::Encryptor<aes::Aes256>;
@@ -51,7 +46,7 @@ impl LegacyCryptor {
 
         if cipher_key.is_empty() {
             return Err(PubNubError::CryptoInitialization {
-                details: "Cipher key is empty".into(),
+                details: error_details!("Cipher key is empty"),
             });
         }
 
@@ -112,7 +107,7 @@ impl Cryptor for LegacyCryptor {
     fn encrypt(&self, data: Vec<u8>) -> Result<EncryptedData, PubNubError> {
         if data.is_empty() {
             return Err(PubNubError::Encryption {
-                details: "Encrypted data is empty".into(),
+                details: error_details!("Encrypted data is empty"),
             });
         }
 
@@ -128,7 +123,7 @@ impl Cryptor for LegacyCryptor {
         let result = Encryptor::new(self.cipher_key.as_slice().into(), iv.as_slice().into())
             .encrypt_padded_b2b_mut::<Pkcs7>(&data, data_slice)
             .map_err(|err| PubNubError::Encryption {
-                details: err.to_string(),
+                details: error_details!(err),
             })?;
         let encrypted_len = result.len() + data_offset;
 
@@ -165,7 +160,7 @@ impl Cryptor for LegacyCryptor {
 
         if iv.len().ne(&AES_BLOCK_SIZE) {
             return Err(PubNubError::Decryption {
-                details: format!(
+                details: error_details!(
                     "Unexpected initialization vector size: {} bytes ({} bytes is expected)",
                     iv.len(),
                     AES_BLOCK_SIZE
@@ -177,14 +172,14 @@ impl Cryptor for LegacyCryptor {
 
         if data_slice.is_empty() {
             return Err(PubNubError::Decryption {
-                details: "Decrypted data is empty.".into(),
+                details: error_details!("Decrypted data is empty."),
             });
         }
 
         let result = Decryptor::new(self.cipher_key.as_slice().into(), iv.as_slice().into())
             .decrypt_padded_b2b_mut::<Pkcs7>(data_slice, buffer.as_mut())
             .map_err(|err| PubNubError::Decryption {
-                details: err.to_string(),
+                details: error_details!(err),
             })?;
 
         // Adjust size of buffer to actual processed data length.
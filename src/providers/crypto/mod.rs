@@ -40,7 +40,7 @@ impl CryptoModule {
 
         if cipher_key.is_empty() {
             return Err(PubNubError::CryptoInitialization {
-                details: "Cipher key is empty".into(),
+                details: error_details!("Cipher key is empty"),
             });
         }
 
@@ -69,7 +69,7 @@ impl CryptoModule {
 
         if cipher_key.is_empty() {
             return Err(PubNubError::CryptoInitialization {
-                details: "Cipher key is empty".into(),
+                details: error_details!("Cipher key is empty"),
             });
         }
 
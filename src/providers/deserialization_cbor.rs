@@ -0,0 +1,112 @@
+//! CBOR implementation for PubNub [`Deserializer`] and [`Serializer`] traits.
+//!
+//! This module provides a [`CBOR`] deserializer and serializer built on top of
+//! the `ciborium` crate. They can be used to parse binary payloads (like
+//! access tokens) and with custom transports which exchange data in binary
+//! form.
+//!
+//! # Examples
+//! ```
+//! use pubnub::{
+//!     core::{Deserializer, Serializer},
+//!     providers::deserialization_cbor::{DeserializerCbor, SerializerCbor},
+//! };
+//!
+//! #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+//! struct Foo {
+//!    bar: String,
+//! }
+//!
+//! # fn main() -> Result<(), pubnub::core::PubNubError> {
+//! let foo = Foo { bar: "baz".to_string() };
+//! let bytes = SerializerCbor.serialize(&foo)?;
+//!
+//! assert_eq!(DeserializerCbor.deserialize::<Foo>(&bytes)?, foo);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Deserializer`]: crate::core::Deserializer
+//! [`Serializer`]: crate::core::Serializer
+//! [`CBOR`]: https://cbor.io
+
+use crate::{
+    core::{Deserializer, PubNubError},
+    lib::alloc::vec::Vec,
+};
+
+/// CBOR implementation for PubNub [`Deserializer`] trait.
+///
+/// This struct implements the [`Deserializer`] trait with the `ciborium`
+/// crate. It is used by the [`parse_token`] function to decode access tokens.
+///
+/// [`Deserializer`]: crate::core::Deserializer
+/// [`parse_token`]: crate::parse_token
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DeserializerCbor;
+
+impl Deserializer for DeserializerCbor {
+    fn deserialize<T>(&self, bytes: &[u8]) -> Result<T, PubNubError>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        ciborium::de::from_reader(bytes).map_err(|e| PubNubError::Deserialization {
+            details: error_details!(e),
+        })
+    }
+}
+
+/// CBOR implementation for PubNub [`Serializer`] trait.
+///
+/// This struct implements the [`Serializer`] trait with the `ciborium` crate.
+///
+/// [`Serializer`]: crate::core::Serializer
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SerializerCbor;
+
+impl<'se, T> crate::core::Serializer<'se, T> for SerializerCbor
+where
+    T: serde::Serialize,
+{
+    fn serialize(&self, object: &'se T) -> Result<Vec<u8>, PubNubError> {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(object, &mut bytes).map_err(|e| PubNubError::Serialization {
+            details: error_details!(e),
+        })?;
+
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{core::Serializer, lib::alloc::string::String};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Foo {
+        bar: String,
+        baz: Option<u64>,
+    }
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let foo = Foo {
+            bar: "baz".into(),
+            baz: Some(17_000_000_000_000_000),
+        };
+
+        let bytes = SerializerCbor.serialize(&foo).unwrap();
+        let result: Foo = DeserializerCbor.deserialize(&bytes).unwrap();
+
+        assert_eq!(result, foo);
+    }
+
+    #[test]
+    fn fail_to_deserialize_malformed_payload() {
+        let result: Result<Foo, PubNubError> = DeserializerCbor.deserialize(&[0xff, 0x00]);
+
+        assert!(matches!(result, Err(PubNubError::Deserialization { .. })));
+    }
+}
@@ -0,0 +1,173 @@
+//! MessagePack implementation for PubNub [`Deserializer`] and [`Serializer`]
+//! traits.
+//!
+//! This module provides a [`MessagePack`] deserializer and serializer built on
+//! top of the `rmp-serde` crate, and [`MsgPack`] wrapper which can be used to
+//! publish messages in compact binary form.
+//!
+//! # Examples
+//! ```
+//! use pubnub::{
+//!     core::{Deserializer, Serializer},
+//!     providers::deserialization_msgpack::{DeserializerMsgPack, SerializerMsgPack},
+//! };
+//!
+//! #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+//! struct Telemetry {
+//!    temperature: f32,
+//! }
+//!
+//! # fn main() -> Result<(), pubnub::core::PubNubError> {
+//! let telemetry = Telemetry { temperature: 21.5 };
+//! let bytes = SerializerMsgPack.serialize(&telemetry)?;
+//!
+//! assert_eq!(DeserializerMsgPack.deserialize::<Telemetry>(&bytes)?, telemetry);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Deserializer`]: crate::core::Deserializer
+//! [`Serializer`]: crate::core::Serializer
+//! [`MessagePack`]: https://msgpack.org
+
+use crate::{
+    core::{utils::headers::APPLICATION_MSGPACK, Deserializer, PubNubError},
+    lib::alloc::vec::Vec,
+};
+
+/// MessagePack implementation for PubNub [`Deserializer`] trait.
+///
+/// This struct implements the [`Deserializer`] trait with the `rmp-serde`
+/// crate. It can be used to decode payloads of messages published with
+/// [`MsgPack`] wrapper.
+///
+/// [`Deserializer`]: crate::core::Deserializer
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DeserializerMsgPack;
+
+impl Deserializer for DeserializerMsgPack {
+    fn deserialize<T>(&self, bytes: &[u8]) -> Result<T, PubNubError>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        rmp_serde::from_slice(bytes).map_err(|e| PubNubError::Deserialization {
+            details: error_details!(e),
+        })
+    }
+}
+
+/// MessagePack implementation for PubNub [`Serializer`] trait.
+///
+/// This struct implements the [`Serializer`] trait with the `rmp-serde`
+/// crate. Structures are serialized as maps, so they can be decoded by
+/// MessagePack implementations in other languages.
+///
+/// [`Serializer`]: crate::core::Serializer
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SerializerMsgPack;
+
+impl<'se, T> crate::core::Serializer<'se, T> for SerializerMsgPack
+where
+    T: serde::Serialize,
+{
+    fn serialize(&self, object: &'se T) -> Result<Vec<u8>, PubNubError> {
+        rmp_serde::to_vec_named(object).map_err(|e| PubNubError::Serialization {
+            details: error_details!(e),
+        })
+    }
+}
+
+/// MessagePack message wrapper.
+///
+/// Wrapped value is serialized with [`SerializerMsgPack`] and sent with
+/// `application/msgpack` content type. Messages with binary payload can be
+/// published only with `POST` requests ([`use_post`]).
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{providers::deserialization_msgpack::MsgPack, Keyset, PubNubClientBuilder};
+///
+/// #[derive(serde::Serialize)]
+/// struct Telemetry {
+///    temperature: f32,
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = PubNubClientBuilder::with_reqwest_transport()
+/// #     .with_keyset(Keyset {
+/// #         subscribe_key: "demo",
+/// #         publish_key: Some("demo"),
+/// #         secret_key: None,
+/// #     })
+/// #     .with_user_id("user_id")
+/// #     .build()?;
+/// client
+///     .publish_message(MsgPack(Telemetry { temperature: 21.5 }))
+///     .channel("telemetry")
+///     .use_post(true)
+///     .execute()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`use_post`]: crate::dx::publish::PublishMessageViaChannelBuilder::use_post
+#[derive(Debug, Clone, PartialEq)]
+pub struct MsgPack<T>(pub T);
+
+impl<T> crate::core::Serialize for MsgPack<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize(&self) -> Result<Vec<u8>, PubNubError> {
+        crate::core::Serializer::serialize(&SerializerMsgPack, &self.0)
+    }
+
+    fn content_type(&self) -> &'static str {
+        APPLICATION_MSGPACK
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{core::Serializer, lib::alloc::string::String};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Foo {
+        bar: String,
+        baz: Option<u64>,
+    }
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let foo = Foo {
+            bar: "baz".into(),
+            baz: Some(17_000_000_000_000_000),
+        };
+
+        let bytes = SerializerMsgPack.serialize(&foo).unwrap();
+        let result: Foo = DeserializerMsgPack.deserialize(&bytes).unwrap();
+
+        assert_eq!(result, foo);
+    }
+
+    #[test]
+    fn serialize_wrapped_message_with_msgpack_content_type() {
+        let message = MsgPack(Foo {
+            bar: "baz".into(),
+            baz: None,
+        });
+
+        assert_eq!(
+            crate::core::Serialize::content_type(&message),
+            APPLICATION_MSGPACK
+        );
+        assert_eq!(
+            crate::core::Serialize::serialize(&message).unwrap(),
+            SerializerMsgPack.serialize(&message.0).unwrap()
+        );
+    }
+}
@@ -17,10 +17,7 @@
 //!
 //! [`Serialize`]: ../trait.Serialize.html
 
-use crate::{
-    core::{Deserializer, PubNubError},
-    lib::alloc::string::ToString,
-};
+use crate::core::{Deserializer, PubNubError};
 
 /// Serde implementation for PubNub [`Deserializer`] trait.
 ///
@@ -40,7 +37,7 @@ impl Deserializer for DeserializerSerde {
         T: for<'de> serde::Deserialize<'de>,
     {
         serde_json::from_slice(bytes).map_err(|e| PubNubError::Deserialization {
-            details: e.to_string(),
+            details: error_details!(e),
         })
     }
 }
@@ -53,7 +50,7 @@ where
 
     fn deserialize(bytes: &'de [u8]) -> Result<Self::Type, PubNubError> {
         serde_json::from_slice(bytes).map_err(|e| PubNubError::Deserialization {
-            details: e.to_string(),
+            details: error_details!(e),
         })
     }
 }
@@ -0,0 +1,87 @@
+//! SIMD accelerated implementation for PubNub [`Deserializer`] trait.
+//!
+//! This module provides a `simd-json` deserializer for the PubNub protocol.
+//! It uses SIMD instructions (when supported by CPU) to parse [`PubNub API`]
+//! responses and can significantly reduce CPU usage when client receives a
+//! lot of real-time updates.
+//!
+//! # Examples
+//! ```no_run
+//! use pubnub::{
+//!     providers::deserialization_simd_json::DeserializerSimdJson, Keyset, PubNubClientBuilder,
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PubNubClientBuilder::with_reqwest_transport()
+//!     .with_keyset(Keyset {
+//!         subscribe_key: "demo",
+//!         publish_key: Some("demo"),
+//!         secret_key: None,
+//!     })
+//!     .with_deserializer(DeserializerSimdJson)
+//!     .with_user_id("user_id")
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Deserializer`]: crate::core::Deserializer
+//! [`PubNub API`]: https://www.pubnub.com/docs
+
+use crate::core::{Deserializer, PubNubError};
+
+/// SIMD accelerated implementation for PubNub [`Deserializer`] trait.
+///
+/// This struct implements the [`Deserializer`] trait with the `simd-json`
+/// crate. `simd-json` parses data in place, so each response is copied into
+/// an intermediate buffer before parsing.
+///
+/// [`Deserializer`]: crate::core::Deserializer
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DeserializerSimdJson;
+
+impl Deserializer for DeserializerSimdJson {
+    fn deserialize<T>(&self, bytes: &[u8]) -> Result<T, PubNubError>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        let mut buffer = bytes.to_vec();
+        simd_json::serde::from_slice(&mut buffer).map_err(|e| PubNubError::Deserialization {
+            details: error_details!(e),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "subscribe"))]
+mod should {
+    use super::*;
+    use crate::{
+        core::Timetoken,
+        dx::subscribe::result::{APISuccessBody, SubscribeResponseBody},
+    };
+
+    #[test]
+    fn deserialize_subscribe_envelope() {
+        let body = br#"{"t":{"t":"17000000000000000","r":31},"m":[{"a":"1","f":0,"p":{"t":"16999999999999999","r":31},"k":"demo","c":"my-channel","d":{"text":"hello"},"i":"user-1"}]}"#;
+
+        let result: SubscribeResponseBody = DeserializerSimdJson.deserialize(body).unwrap();
+        let SubscribeResponseBody::SuccessResponse(APISuccessBody { cursor, messages }) = result
+        else {
+            panic!("Unexpected response type");
+        };
+
+        assert_eq!(
+            Timetoken::try_from(&cursor).ok(),
+            Some(Timetoken::new(17_000_000_000_000_000))
+        );
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn fail_to_deserialize_malformed_payload() {
+        let result: Result<SubscribeResponseBody, PubNubError> =
+            DeserializerSimdJson.deserialize(b"{\"t\":");
+
+        assert!(matches!(result, Err(PubNubError::Deserialization { .. })));
+    }
+}
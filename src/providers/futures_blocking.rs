@@ -0,0 +1,99 @@
+//! # Futures implementation for blocking environment
+//!
+//! This module contains [`RuntimeBlocking`] type.
+//!
+//! It requires the [`blocking` feature] to be enabled.
+//!
+//! [`blocking` feature]: ../index.html#features
+
+use crate::{
+    core::{runtime::Runtime, MaybeSend},
+    lib::{
+        alloc::sync::Arc,
+        core::{
+            future::Future,
+            pin::pin,
+            task::{Context, Poll, Wake, Waker},
+            thread::{self, Thread},
+            time::Duration,
+        },
+    },
+};
+
+/// Calling thread based `async` tasks executor.
+///
+/// Runtime doesn't start or require any asynchronous runtime (like `tokio`):
+/// spawned tasks are executed to completion on the calling thread and delays
+/// park that thread.
+///
+/// It is used by clients created with blocking transport (see
+/// [`PubNubClientBuilder::with_blocking_transport`]). Blocking transport can't
+/// be used by Subscribe and Presence event engines (they require asynchronous
+/// [`Transport`]), so client doesn't spawn tasks with this runtime.
+///
+/// [`PubNubClientBuilder::with_blocking_transport`]: crate::PubNubClientBuilder::with_blocking_transport
+/// [`Transport`]: crate::core::Transport
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RuntimeBlocking;
+
+impl Runtime for RuntimeBlocking {
+    fn spawn<R>(&self, future: impl Future<Output = R> + MaybeSend + 'static)
+    where
+        R: MaybeSend + 'static,
+    {
+        block_on(future);
+    }
+
+    async fn sleep(self, delay: u64) {
+        thread::sleep(Duration::from_secs(delay))
+    }
+
+    async fn sleep_microseconds(self, delay: u64) {
+        thread::sleep(Duration::from_micros(delay))
+    }
+}
+
+/// Wakes parked thread which executes future.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run `future` to completion on the calling thread.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+
+        thread::park();
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn run_spawned_task_to_completion() {
+        let completed = Arc::new(crate::lib::sync::Mutex::new(false));
+        let task_completed = completed.clone();
+
+        RuntimeBlocking.spawn(async move {
+            RuntimeBlocking.sleep_microseconds(10).await;
+            *task_completed.lock() = true;
+        });
+
+        assert!(*completed.lock());
+    }
+}
@@ -0,0 +1,100 @@
+//! # Futures implementation using Embassy executor
+//!
+//! This module contains [`RuntimeEmbassy`] type.
+//!
+//! It requires the [`embassy` feature] to be enabled.
+//!
+//! [`embassy` feature]: ../index.html#features
+
+use crate::{
+    core::runtime::Runtime,
+    lib::{
+        alloc::boxed::Box,
+        core::{
+            fmt::{Debug, Formatter, Result as FmtResult},
+            future::Future,
+        },
+        log,
+    },
+};
+use embassy_executor::SendSpawner;
+use embassy_time::{Duration, Timer};
+use futures::{future::BoxFuture, FutureExt};
+
+/// Maximum number of client tasks which can be run on executor at once.
+///
+/// Embassy allocates tasks storage statically, so task which is spawned when
+/// all slots are in use is dropped (with error in logs).
+pub const RUNTIME_EMBASSY_TASKS_POOL_SIZE: usize = 8;
+
+/// Embassy-based `async` tasks spawner.
+///
+/// Tasks are spawned on executor which created provided [`SendSpawner`] and
+/// delays are handled by [`embassy_time::Timer`], so time driver should be
+/// set up for the target.
+///
+/// # Examples
+/// ```
+/// use embassy_executor::Spawner;
+/// use pubnub::{providers::futures_embassy::RuntimeEmbassy, Keyset, PubNubClientBuilder};
+///
+/// # fn setup(spawner: Spawner) -> Result<(), pubnub::core::PubNubError> {
+/// let client = PubNubClientBuilder::with_reqwest_transport()
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("device-1")
+///     .with_runtime(RuntimeEmbassy::new(spawner.make_send()))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub struct RuntimeEmbassy {
+    /// Spawner of executor on which tasks should be run.
+    spawner: SendSpawner,
+}
+
+impl RuntimeEmbassy {
+    /// Create runtime which spawns tasks with `spawner`.
+    pub fn new(spawner: SendSpawner) -> Self {
+        Self { spawner }
+    }
+}
+
+impl Debug for RuntimeEmbassy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RuntimeEmbassy").finish_non_exhaustive()
+    }
+}
+
+impl Runtime for RuntimeEmbassy {
+    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static)
+    where
+        R: Send + 'static,
+    {
+        let future = Box::pin(future.map(|_| ()));
+
+        if self.spawner.spawn(runtime_task(future)).is_err() {
+            log::error!(
+                "Unable to spawn task: all {RUNTIME_EMBASSY_TASKS_POOL_SIZE} Embassy task slots are in use"
+            );
+        }
+    }
+
+    async fn sleep(self, delay: u64) {
+        Timer::after(Duration::from_secs(delay)).await
+    }
+
+    async fn sleep_microseconds(self, delay: u64) {
+        Timer::after(Duration::from_micros(delay)).await
+    }
+}
+
+/// Embassy task which drives client `future` to completion.
+#[embassy_executor::task(pool_size = RUNTIME_EMBASSY_TASKS_POOL_SIZE)]
+async fn runtime_task(future: BoxFuture<'static, ()>) {
+    future.await
+}
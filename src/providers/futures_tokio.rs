@@ -12,7 +12,6 @@ use crate::core::runtime::Runtime;
 #[derive(Copy, Clone, Debug)]
 pub struct RuntimeTokio;
 
-#[async_trait::async_trait]
 impl Runtime for RuntimeTokio {
     fn spawn<R>(&self, future: impl futures::Future<Output = R> + Send + 'static)
     where
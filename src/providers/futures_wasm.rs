@@ -0,0 +1,69 @@
+//! # Futures implementation for browser environment
+//!
+//! This module contains [`RuntimeWasm`] type.
+//!
+//! It requires the [`wasm` feature] to be enabled.
+//!
+//! [`wasm` feature]: ../index.html#features
+
+use crate::{
+    core::{runtime::Runtime, MaybeSend},
+    lib::core::future::Future,
+};
+use gloo_timers::future::TimeoutFuture;
+use send_wrapper::SendWrapper;
+
+/// Browser-based `async` tasks spawner.
+///
+/// Tasks are spawned on the current thread with
+/// [`wasm_bindgen_futures::spawn_local`] and delays are handled by
+/// [`gloo_timers`] (`setTimeout`), which makes it usable on
+/// `wasm32-unknown-unknown` target where `tokio` runtime isn't available.
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{providers::futures_wasm::RuntimeWasm, Keyset, PubNubClientBuilder};
+///
+/// # fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let client = PubNubClientBuilder::with_reqwest_transport()
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("browser-tab")
+///     .with_runtime(RuntimeWasm)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RuntimeWasm;
+
+impl Runtime for RuntimeWasm {
+    fn spawn<R>(&self, future: impl Future<Output = R> + MaybeSend + 'static)
+    where
+        R: MaybeSend + 'static,
+    {
+        wasm_bindgen_futures::spawn_local(async move {
+            future.await;
+        });
+    }
+
+    async fn sleep(self, delay: u64) {
+        timeout(delay.saturating_mul(1000)).await
+    }
+
+    async fn sleep_microseconds(self, delay: u64) {
+        timeout(delay.div_ceil(1000)).await
+    }
+}
+
+/// Create `setTimeout` based future which resolves after `delay`
+/// milliseconds.
+///
+/// Browser timers aren't `Send`, but `wasm32-unknown-unknown` target is
+/// single-threaded, so future is wrapped to satisfy [`Runtime`] bounds.
+fn timeout(delay: u64) -> SendWrapper<TimeoutFuture> {
+    SendWrapper::new(TimeoutFuture::new(u32::try_from(delay).unwrap_or(u32::MAX)))
+}
@@ -12,8 +12,30 @@ pub mod serialization_serde;
 #[cfg(feature = "serde")]
 pub mod deserialization_serde;
 
+#[cfg(all(feature = "serde", feature = "cbor"))]
+pub mod deserialization_cbor;
+
+#[cfg(all(feature = "serde", feature = "msgpack"))]
+pub mod deserialization_msgpack;
+
+#[cfg(feature = "simd_json")]
+pub mod deserialization_simd_json;
+
 #[cfg(feature = "crypto")]
 pub mod crypto;
 
 #[cfg(all(feature = "tokio", feature = "std"))]
 pub mod futures_tokio;
+
+#[cfg(all(
+    feature = "blocking",
+    feature = "std",
+    any(feature = "subscribe", feature = "presence")
+))]
+pub mod futures_blocking;
+
+#[cfg(all(feature = "embassy", feature = "std", feature = "subscribe"))]
+pub mod futures_embassy;
+
+#[cfg(all(feature = "wasm", feature = "std", feature = "subscribe"))]
+pub mod futures_wasm;
@@ -18,7 +18,7 @@
 //! [`Serialize`]: ../trait.Serialize.html
 
 use crate::core::PubNubError;
-use crate::lib::alloc::{string::ToString, vec::Vec};
+use crate::lib::alloc::vec::Vec;
 
 /// Serde implementation for PubNub [`Serializer`] trait.
 ///
@@ -36,7 +36,7 @@ where
 {
     fn serialize(&self, object: &'se T) -> Result<Vec<u8>, crate::core::PubNubError> {
         serde_json::to_vec(object).map_err(|e| PubNubError::Serialization {
-            details: e.to_string(),
+            details: error_details!(e),
         })
     }
 }
@@ -47,7 +47,14 @@ where
 {
     fn serialize(&self) -> Result<Vec<u8>, crate::core::PubNubError> {
         serde_json::to_vec(self).map_err(|e| PubNubError::Serialization {
-            details: e.to_string(),
+            details: error_details!(e),
+        })
+    }
+
+    #[cfg(feature = "std")]
+    fn serialize_into(&self, buffer: &mut Vec<u8>) -> Result<(), crate::core::PubNubError> {
+        serde_json::to_writer(buffer, self).map_err(|e| PubNubError::Serialization {
+            details: error_details!(e),
         })
     }
 }
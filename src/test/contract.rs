@@ -0,0 +1,304 @@
+//! # Contract tests harness module
+//!
+//! This module contains utilities which are used to run BDD contract
+//! features (from the shared SDK specifications repository) against
+//! [`PubNubClientInstance`]: contract (mock) server wiring, keysets used by
+//! feature steps and features / scenarios filtering by their tags.
+//!
+//! SDK wrappers can use them with own [cucumber](https://docs.rs/cucumber)
+//! world to run the same contract features against their integrations.
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+use crate::{
+    core::{PubNubError, Transport, TransportMethod, TransportRequest},
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    },
+    transport::TransportReqwest,
+    Keyset,
+};
+
+/// Default contract server address.
+pub const DEFAULT_CONTRACT_SERVER_URL: &str = "http://localhost:8090";
+
+/// Contract (mock) server.
+///
+/// Before each scenario server should be initialized with contract script
+/// (set in `@contract=<name>` scenario tag), which describes expected
+/// requests and responses for them.
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{test::contract::ContractServer, PubNubClientBuilder};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let server = ContractServer::default();
+/// server.init("successfulPublish").await?;
+///
+/// let client = PubNubClientBuilder::with_transport(server.transport())
+///     .with_keyset(ContractServer::demo_keyset())
+///     .with_user_id("test")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContractServer {
+    /// Contract server address.
+    url: String,
+}
+
+impl ContractServer {
+    /// Create contract server client for server at `url`.
+    pub fn new<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { url: url.into() }
+    }
+
+    /// Contract server address.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Transport which sends requests to the contract server.
+    pub fn transport(&self) -> TransportReqwest {
+        let mut transport = TransportReqwest::default();
+        transport.set_hostname(self.url.as_str());
+        transport
+    }
+
+    /// Initialize server with contract `script`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Transport`] if server is not reachable and
+    /// [`PubNubError::API`] if script can't be loaded.
+    pub async fn init(&self, script: &str) -> Result<String, PubNubError> {
+        let response = self
+            .transport()
+            .send(TransportRequest {
+                path: "/init".into(),
+                query_parameters: [("__contract__script__".to_string(), script.to_string())].into(),
+                method: TransportMethod::Get,
+                ..Default::default()
+            })
+            .await?;
+        let body = response
+            .body
+            .map(|body| String::from_utf8_lossy(&body).to_string())
+            .unwrap_or_default();
+
+        if response.status != 200 {
+            return Err(PubNubError::API {
+                status: response.status,
+                message: format!("Unable to load '{script}' contract: {body}"),
+                service: None,
+                affected_channels: None,
+                affected_channel_groups: None,
+                server_error: None,
+                response: None,
+            });
+        }
+
+        Ok(body)
+    }
+
+    /// Keyset used by `the demo keyset` step.
+    pub fn demo_keyset() -> Keyset<String> {
+        Keyset {
+            subscribe_key: "demo".into(),
+            publish_key: Some("demo".into()),
+            secret_key: None,
+        }
+    }
+
+    /// Keyset used by `I have a keyset with access manager enabled` step.
+    pub fn access_manager_keyset(with_secret_key: bool) -> Keyset<String> {
+        Keyset {
+            secret_key: with_secret_key.then(|| "demo".into()),
+            ..Self::demo_keyset()
+        }
+    }
+}
+
+impl Default for ContractServer {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONTRACT_SERVER_URL)
+    }
+}
+
+/// Contract features filter.
+///
+/// Filter decides which features and scenarios should be run based on their
+/// tags:
+/// * `@featureSet=<name>` – feature set to which feature belongs,
+/// * `@contract=<name>` – contract script for the scenario,
+/// * `@beta` and `@skip` – features and scenarios which are not ready,
+/// * `@na=rust` – features and scenarios which not applicable for Rust SDK.
+#[derive(Debug, Clone, Default)]
+pub struct ContractFilter {
+    /// Feature sets which should be tested.
+    supported: Vec<String>,
+
+    /// Feature sets for which `@beta` features and scenarios should be tested.
+    beta: Vec<String>,
+
+    /// Feature sets for which `@skip` features and scenarios should be
+    /// tested.
+    skipped: Vec<String>,
+
+    /// Feature sets for which scenarios without contract should be tested.
+    contract_less: Vec<String>,
+
+    /// Name of the only contract which should be tested.
+    contract: Option<String>,
+}
+
+impl ContractFilter {
+    /// Create filter which allows `supported` feature sets.
+    pub fn new<I, S>(supported: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            supported: supported.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Allow `@beta` features and scenarios for `features` sets.
+    pub fn with_beta<I, S>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.beta = features.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Allow `@skip` features and scenarios for `features` sets.
+    pub fn with_skipped<I, S>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.skipped = features.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Allow scenarios without `@contract` tag for `features` sets.
+    pub fn with_contract_less<I, S>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.contract_less = features.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Run only scenarios for specified `contract`.
+    pub fn with_contract<S>(mut self, contract: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.contract = Some(contract.into());
+        self
+    }
+
+    /// Whether scenario with `scenario_tags` from feature with
+    /// `feature_tags` should be run or not.
+    pub fn should_run(&self, feature_tags: &[String], scenario_tags: &[String]) -> bool {
+        let feature = feature_set(feature_tags);
+        !(self.is_ignored_feature(&feature, feature_tags)
+            || self.is_ignored_scenario(&feature, scenario_tags))
+    }
+
+    /// Whether feature from `feature` set with `tags` should be ignored.
+    fn is_ignored_feature(&self, feature: &str, tags: &[String]) -> bool {
+        let mut ignored_tags = vec!["na=rust"];
+
+        if !self.beta.iter().any(|beta| beta == feature) {
+            ignored_tags.push("beta");
+        }
+
+        if !self.skipped.iter().any(|skipped| skipped == feature) {
+            ignored_tags.push("skip");
+        }
+
+        ignored_tags
+            .iter()
+            .any(|tag| tags.iter().any(|feature_tag| feature_tag == tag))
+            || !self.supported.iter().any(|supported| supported == feature)
+    }
+
+    /// Whether scenario from `feature` set with `tags` should be ignored.
+    fn is_ignored_scenario(&self, feature: &str, tags: &[String]) -> bool {
+        let allows_beta = self.beta.iter().any(|beta| beta == feature);
+        let allows_skipped = self.skipped.iter().any(|skipped| skipped == feature);
+        let allows_contract_less = self.contract_less.iter().any(|name| name == feature);
+
+        tags.iter().any(|tag| tag == "na=rust")
+            || !allows_beta && tags.iter().any(|tag| tag.starts_with("beta"))
+            || !allows_skipped && tags.iter().any(|tag| tag.starts_with("skip"))
+            || (!allows_contract_less || self.contract.is_some())
+                && !tags.iter().any(|tag| match &self.contract {
+                    Some(contract) => tag == &format!("contract={contract}"),
+                    None => tag.starts_with("contract="),
+                })
+    }
+}
+
+/// Name of feature set from `@featureSet=<name>` tag.
+pub fn feature_set(tags: &[String]) -> String {
+    tags.iter()
+        .filter_map(|tag| tag.strip_prefix("featureSet="))
+        .collect()
+}
+
+/// Name of contract script from `@contract=<name>` tag.
+pub fn contract_script(tags: &[String]) -> Option<&str> {
+    tags.iter()
+        .find_map(|tag| tag.strip_prefix("contract="))
+        .filter(|script| !script.is_empty())
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    fn tags(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn filter_scenarios_by_tags() {
+        let filter = ContractFilter::new(["publish", "access"])
+            .with_beta(["publish"])
+            .with_contract_less(["access"]);
+        let publish = tags(&["featureSet=publish", "beta"]);
+        let access = tags(&["featureSet=access"]);
+
+        assert!(filter.should_run(&publish, &tags(&["contract=successfulPublish"])));
+        assert!(!filter.should_run(&publish, &tags(&[])));
+        assert!(!filter.should_run(&publish, &tags(&["contract=a", "na=rust"])));
+        assert!(filter.should_run(&access, &tags(&[])));
+        assert!(!filter.should_run(&access, &tags(&["beta"])));
+        assert!(!filter.should_run(&tags(&["featureSet=files"]), &tags(&["contract=a"])));
+    }
+
+    #[test]
+    fn extract_contract_script() {
+        let tags = tags(&["beta", "contract=successfulPublish"]);
+
+        assert_eq!(contract_script(&tags), Some("successfulPublish"));
+        assert_eq!(feature_set(&["featureSet=publish".into()]), "publish");
+    }
+}
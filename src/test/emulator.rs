@@ -0,0 +1,673 @@
+//! # PubNub emulator module
+//!
+//! This module contains [`Emulator`] – minimal in-memory [`PubNub API`]
+//! server which listens on localhost and understands `time`, `publish`,
+//! `subscribe` and presence (`heartbeat`, `leave` and `here_now`) requests.
+//! It can be used to run end-to-end tests and examples without demo keys or
+//! internet access.
+//!
+//! Emulator doesn't validate keys, signatures or access tokens and doesn't
+//! support channel groups, filter expressions, message persistence and
+//! presence timeouts.
+//!
+//! [`PubNub API`]: https://www.pubnub.com/docs
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    net::SocketAddr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::Notify,
+    task::JoinHandle,
+};
+
+use crate::{
+    core::{PubNubError, Timetoken},
+    lib::{alloc::sync::Arc, sync::Mutex},
+};
+
+/// Default subscribe long-poll duration.
+const DEFAULT_SUBSCRIBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Suffix of channels with presence events.
+const PRESENCE_CHANNEL_SUFFIX: &str = "-pnpres";
+
+/// In-memory PubNub API emulator.
+///
+/// Emulator listens on random localhost port and stops when dropped. Use
+/// [`Emulator::url`] as transport hostname.
+///
+/// # Examples
+/// ```
+/// use pubnub::{test::emulator::Emulator, transport::TransportReqwest, Keyset, PubNubClientBuilder};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let emulator = Emulator::start().await?;
+///
+/// let mut transport = TransportReqwest::new();
+/// transport.set_hostname(emulator.url());
+///
+/// let client = PubNubClientBuilder::with_transport(transport)
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("user_id")
+///     .build()?;
+///
+/// client
+///     .publish_message("hello")
+///     .channel("my_channel")
+///     .execute()
+///     .await?;
+///
+/// assert_eq!(emulator.published("my_channel"), vec![serde_json::json!("hello")]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Emulator {
+    /// Address on which emulator listens for requests.
+    address: SocketAddr,
+
+    /// Emulator state shared with connection handlers.
+    state: Arc<EmulatorState>,
+
+    /// Connections accepting task.
+    task: JoinHandle<()>,
+}
+
+/// Message stored by emulator.
+#[derive(Debug, Clone)]
+struct StoredMessage {
+    /// Time when message has been published.
+    timetoken: Timetoken,
+
+    /// Name of channel to which message has been published.
+    channel: String,
+
+    /// Identifier of client which published message.
+    publisher: Option<String>,
+
+    /// Published message.
+    payload: serde_json::Value,
+}
+
+/// Emulator state.
+#[derive(Debug)]
+struct EmulatorState {
+    /// Subscribe long-poll duration.
+    subscribe_timeout: Duration,
+
+    /// Most recently issued timetoken.
+    timetoken: Mutex<Timetoken>,
+
+    /// Published messages (including presence events).
+    messages: Mutex<Vec<StoredMessage>>,
+
+    /// Identifiers of users which are present on channel.
+    presence: Mutex<HashMap<String, BTreeSet<String>>>,
+
+    /// Notify waiting subscribe requests about new messages.
+    notify: Notify,
+}
+
+/// Parsed HTTP request.
+#[derive(Debug)]
+struct HttpRequest {
+    /// Request method.
+    method: String,
+
+    /// Decoded path segments.
+    segments: Vec<String>,
+
+    /// Decoded query parameters.
+    query: HashMap<String, String>,
+
+    /// Request body.
+    body: Vec<u8>,
+}
+
+impl Emulator {
+    /// Start emulator on random localhost port.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Transport`] if emulator can't bind to localhost
+    /// port.
+    pub async fn start() -> Result<Self, PubNubError> {
+        Self::start_with_subscribe_timeout(DEFAULT_SUBSCRIBE_TIMEOUT).await
+    }
+
+    /// Start emulator on random localhost port with custom subscribe
+    /// long-poll duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Transport`] if emulator can't bind to localhost
+    /// port.
+    pub async fn start_with_subscribe_timeout(timeout: Duration) -> Result<Self, PubNubError> {
+        let listener =
+            TcpListener::bind("127.0.0.1:0")
+                .await
+                .map_err(|err| PubNubError::Transport {
+                    details: error_details!("Unable to start emulator: {err}"),
+                    response: None,
+                })?;
+        let address = listener
+            .local_addr()
+            .map_err(|err| PubNubError::Transport {
+                details: error_details!("Unable to start emulator: {err}"),
+                response: None,
+            })?;
+
+        let state = Arc::new(EmulatorState {
+            subscribe_timeout: timeout,
+            timetoken: Mutex::new(Timetoken::default()),
+            messages: Default::default(),
+            presence: Default::default(),
+            notify: Notify::new(),
+        });
+
+        let task_state = state.clone();
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(handle_connection(stream, task_state.clone()));
+            }
+        });
+
+        Ok(Self {
+            address,
+            state,
+            task,
+        })
+    }
+
+    /// Emulator base URL (for example, `http://127.0.0.1:54321`).
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Messages published to the `channel`.
+    pub fn published(&self, channel: &str) -> Vec<serde_json::Value> {
+        self.state
+            .messages
+            .lock()
+            .iter()
+            .filter(|message| message.channel == channel)
+            .map(|message| message.payload.clone())
+            .collect()
+    }
+
+    /// Identifiers of users which are present on `channel`.
+    pub fn present(&self, channel: &str) -> Vec<String> {
+        self.state
+            .presence
+            .lock()
+            .get(channel)
+            .map(|users| users.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for Emulator {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl EmulatorState {
+    /// Issue next timetoken.
+    fn next_timetoken(&self) -> Timetoken {
+        let mut last = self.timetoken.lock();
+        let now = Timetoken::from_system_time(SystemTime::now());
+        *last = now.max(Timetoken::new(last.as_u64() + 1));
+        *last
+    }
+
+    /// Most recently issued timetoken.
+    fn current_timetoken(&self) -> Timetoken {
+        let last = *self.timetoken.lock();
+        last.max(Timetoken::from_system_time(SystemTime::now()))
+    }
+
+    /// Store message and notify waiting subscribe requests.
+    fn store(
+        &self,
+        channel: String,
+        publisher: Option<String>,
+        payload: serde_json::Value,
+    ) -> Timetoken {
+        let timetoken = self.next_timetoken();
+        self.messages.lock().push(StoredMessage {
+            timetoken,
+            channel,
+            publisher,
+            payload,
+        });
+        self.notify.notify_waiters();
+        timetoken
+    }
+
+    /// Messages for `channels` published after `timetoken`.
+    fn messages_after(&self, channels: &[String], timetoken: Timetoken) -> Vec<StoredMessage> {
+        self.messages
+            .lock()
+            .iter()
+            .filter(|message| message.timetoken > timetoken && channels.contains(&message.channel))
+            .cloned()
+            .collect()
+    }
+
+    /// Update presence of `user_id` on `channels` and emit presence events.
+    fn update_presence(&self, channels: &[String], user_id: &str, join: bool) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        channels.iter().for_each(|channel| {
+            let (changed, occupancy) = {
+                let mut presence = self.presence.lock();
+                let users = presence.entry(channel.clone()).or_default();
+                let changed = if join {
+                    users.insert(user_id.to_string())
+                } else {
+                    users.remove(user_id)
+                };
+                (changed, users.len())
+            };
+
+            if changed {
+                self.store(
+                    format!("{channel}{PRESENCE_CHANNEL_SUFFIX}"),
+                    None,
+                    serde_json::json!({
+                        "action": if join { "join" } else { "leave" },
+                        "timestamp": timestamp,
+                        "uuid": user_id,
+                        "occupancy": occupancy,
+                    }),
+                );
+            }
+        });
+    }
+}
+
+/// Handle requests sent through single connection.
+async fn handle_connection(stream: TcpStream, state: Arc<EmulatorState>) {
+    let mut stream = BufReader::new(stream);
+
+    while let Some(request) = read_request(&mut stream).await {
+        let (status, body) = handle_request(&request, &state).await;
+        let response = format!(
+            "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: keep-alive\r\n\r\n{body}",
+            if status == 200 { "OK" } else { "Error" },
+            body.len()
+        );
+
+        if stream
+            .get_mut()
+            .write_all(response.as_bytes())
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+/// Read and parse single HTTP request.
+async fn read_request(stream: &mut BufReader<TcpStream>) -> Option<HttpRequest> {
+    let mut line = String::new();
+    stream
+        .read_line(&mut line)
+        .await
+        .ok()
+        .filter(|len| *len > 0)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?.to_string();
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        stream
+            .read_line(&mut header)
+            .await
+            .ok()
+            .filter(|len| *len > 0)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    stream.read_exact(&mut body).await.ok()?;
+
+    let (path, query) = target.split_once('?').unwrap_or((target.as_str(), ""));
+    Some(HttpRequest {
+        method,
+        segments: path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(percent_decode)
+            .collect(),
+        query: query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (percent_decode(key), percent_decode(value)))
+            .collect(),
+        body,
+    })
+}
+
+/// Prepare response for `request`.
+async fn handle_request(request: &HttpRequest, state: &EmulatorState) -> (u16, String) {
+    let segments = request
+        .segments
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let user_id = request.query.get("uuid").cloned();
+
+    match segments.as_slice() {
+        ["time", ..] => (200, format!("[{}]", state.current_timetoken())),
+        ["publish", _, _, _, channel, _, rest @ ..] => {
+            let payload = match (request.method.as_str(), rest) {
+                ("POST", _) => String::from_utf8_lossy(&request.body).to_string(),
+                (_, [message]) => message.to_string(),
+                _ => return (400, error_body(400, "Message is missing", "Publish")),
+            };
+            let payload =
+                serde_json::from_str(&payload).unwrap_or(serde_json::Value::String(payload));
+            let timetoken = state.store(channel.to_string(), user_id, payload);
+
+            (200, format!("[1,\"Sent\",\"{timetoken}\"]"))
+        }
+        ["v2", "subscribe", subscribe_key, channels, ..] => {
+            subscribe(state, subscribe_key, &split_channels(channels), request).await
+        }
+        ["v2", "presence", _, _, "channel", channels, action @ ("heartbeat" | "leave")] => {
+            let channels = split_channels(channels);
+            if let Some(user_id) = user_id {
+                state.update_presence(&channels, &user_id, *action == "heartbeat");
+            }
+
+            (
+                200,
+                r#"{"status":200,"message":"OK","service":"Presence"}"#.into(),
+            )
+        }
+        ["v2", "presence", _, _, "channel", channels] => here_now(state, &split_channels(channels)),
+        _ => (
+            404,
+            error_body(404, "Not implemented by emulator", "Emulator"),
+        ),
+    }
+}
+
+/// Prepare subscribe response.
+///
+/// Request waits for new messages up to subscribe long-poll duration.
+async fn subscribe(
+    state: &EmulatorState,
+    subscribe_key: &str,
+    channels: &[String],
+    request: &HttpRequest,
+) -> (u16, String) {
+    let cursor = request
+        .query
+        .get("tt")
+        .and_then(|tt| tt.parse::<Timetoken>().ok())
+        .unwrap_or_default();
+
+    if cursor.as_u64() == 0 {
+        return (
+            200,
+            subscribe_body(state.current_timetoken(), subscribe_key, &[]),
+        );
+    }
+
+    let deadline = tokio::time::Instant::now() + state.subscribe_timeout;
+    loop {
+        let notified = state.notify.notified();
+        tokio::pin!(notified);
+        notified.as_mut().enable();
+
+        let messages = state.messages_after(channels, cursor);
+        if !messages.is_empty() {
+            let timetoken = messages
+                .iter()
+                .map(|message| message.timetoken)
+                .max()
+                .unwrap_or(cursor);
+            return (200, subscribe_body(timetoken, subscribe_key, &messages));
+        }
+
+        if tokio::time::timeout_at(deadline, notified).await.is_err() {
+            return (200, subscribe_body(cursor, subscribe_key, &[]));
+        }
+    }
+}
+
+/// Prepare `here_now` response.
+fn here_now(state: &EmulatorState, channels: &[String]) -> (u16, String) {
+    let presence = state.presence.lock();
+    let channels = channels
+        .iter()
+        .map(|channel| {
+            let users = presence.get(channel).cloned().unwrap_or_default();
+            (
+                channel.clone(),
+                serde_json::json!({ "occupancy": users.len(), "uuids": users }),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    let total_occupancy = channels
+        .values()
+        .map(|channel| channel["occupancy"].as_u64().unwrap_or_default())
+        .sum::<u64>();
+
+    let body = serde_json::json!({
+        "status": 200,
+        "message": "OK",
+        "service": "Presence",
+        "payload": {
+            "channels": channels,
+            "total_channels": channels.len(),
+            "total_occupancy": total_occupancy,
+        },
+    });
+
+    (200, body.to_string())
+}
+
+/// Serialize subscribe response body.
+fn subscribe_body(timetoken: Timetoken, subscribe_key: &str, messages: &[StoredMessage]) -> String {
+    let messages = messages
+        .iter()
+        .map(|message| {
+            let mut envelope = serde_json::json!({
+                "a": "1",
+                "f": 0,
+                "p": { "t": message.timetoken.to_string(), "r": 1 },
+                "k": subscribe_key,
+                "c": message.channel,
+                "d": message.payload,
+            });
+            if let Some(publisher) = &message.publisher {
+                envelope["i"] = publisher.clone().into();
+            }
+            envelope
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "t": { "t": timetoken.to_string(), "r": 1 },
+        "m": messages,
+    })
+    .to_string()
+}
+
+/// Serialize error response body.
+fn error_body(status: u16, message: &str, service: &str) -> String {
+    serde_json::json!({
+        "status": status,
+        "error": true,
+        "message": message,
+        "service": service,
+    })
+    .to_string()
+}
+
+/// Split comma-separated list of channels.
+fn split_channels(channels: &str) -> Vec<String> {
+    channels
+        .split(',')
+        .filter(|channel| !channel.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Decode percent-encoded `value`.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let hex = (bytes[idx] == b'%')
+            .then(|| bytes.get(idx + 1..idx + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                idx += 3;
+            }
+            None => {
+                decoded.push(bytes[idx]);
+                idx += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[cfg(all(
+    test,
+    feature = "reqwest",
+    feature = "publish",
+    feature = "subscribe",
+    feature = "presence"
+))]
+mod should {
+    use super::*;
+    use crate::{transport::TransportReqwest, Keyset, PubNubClientBuilder};
+
+    async fn client(emulator: &Emulator, user_id: &str) -> crate::PubNubClient {
+        let mut transport = TransportReqwest::new();
+        transport.set_hostname(emulator.url());
+
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo"),
+                secret_key: None,
+            })
+            .with_user_id(user_id)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn decode_percent_encoded_values() {
+        assert_eq!(percent_decode("%22hello%20world%22"), "\"hello world\"");
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+
+    #[tokio::test]
+    async fn publish_and_subscribe() {
+        let emulator = Emulator::start_with_subscribe_timeout(Duration::from_secs(2))
+            .await
+            .unwrap();
+        let client = client(&emulator, "user-1").await;
+
+        let handshake = client
+            .subscribe_request()
+            .channels(["channel"])
+            .execute()
+            .await
+            .unwrap();
+
+        let publish = client
+            .publish_message(serde_json::json!({"text": "hello"}))
+            .channel("channel")
+            .execute()
+            .await
+            .unwrap();
+
+        let result = client
+            .subscribe_request()
+            .channels(["channel"])
+            .cursor(handshake.cursor)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.messages.len(), 1);
+        assert!(Timetoken::try_from(&result.cursor).unwrap() >= publish.timetoken);
+        assert_eq!(
+            emulator.published("channel"),
+            vec![serde_json::json!({"text": "hello"})]
+        );
+    }
+
+    #[tokio::test]
+    async fn track_presence() {
+        let emulator = Emulator::start().await.unwrap();
+        let client = client(&emulator, "user-1").await;
+
+        client
+            .heartbeat()
+            .channels(["channel"])
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(emulator.present("channel"), vec!["user-1".to_string()]);
+
+        let here_now = client
+            .here_now()
+            .channels(["channel"])
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(here_now.total_occupancy, 1);
+
+        client
+            .leave()
+            .channels(["channel"])
+            .execute()
+            .await
+            .unwrap();
+        assert!(emulator.present("channel").is_empty());
+        assert_eq!(emulator.published("channel-pnpres").len(), 2);
+    }
+}
@@ -0,0 +1,257 @@
+//! # Event engine test harness module
+//!
+//! This module contains harnesses which can be used to drive subscribe and
+//! presence state machines with synthetic events and assert on produced
+//! effect invocations without effects execution (no transport or runtime is
+//! required).
+//!
+//! > Note: State machine events, state names and invocation descriptions are
+//! > SDK implementation details and may change between releases.
+
+use crate::{
+    core::event_engine::{EffectInvocation, State},
+    lib::{
+        alloc::{
+            string::{String, ToString},
+            vec::Vec,
+        },
+        core::fmt::Display,
+    },
+};
+
+#[cfg(feature = "subscribe")]
+#[doc(hidden)]
+pub use crate::dx::subscribe::event_engine::event::SubscribeEvent;
+#[cfg(feature = "subscribe")]
+use crate::dx::subscribe::event_engine::{SubscribeEffectInvocation, SubscribeState};
+
+#[cfg(feature = "presence")]
+#[doc(hidden)]
+pub use crate::dx::presence::event_engine::event::PresenceEvent;
+#[cfg(feature = "presence")]
+use crate::dx::presence::event_engine::{PresenceEffectInvocation, PresenceState};
+
+/// Subscribe state machine harness.
+///
+/// Harness starts in `Unsubscribed` state and records invocations produced
+/// by each processed event.
+///
+/// # Examples
+/// ```
+/// use pubnub::test::event_engine::{SubscribeEvent, SubscribeEventEngineHarness};
+///
+/// let mut harness = SubscribeEventEngineHarness::new();
+/// let invocations = harness.process(SubscribeEvent::SubscriptionChanged {
+///     channels: Some(vec!["my_channel".into()]),
+///     channel_groups: None,
+/// });
+///
+/// assert_eq!(harness.state(), "Handshaking");
+/// assert_eq!(invocations, vec!["HANDSHAKE".to_string()]);
+/// ```
+#[cfg(feature = "subscribe")]
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SubscribeEventEngineHarness {
+    /// Current state machine state.
+    state: SubscribeState,
+
+    /// Invocations produced by all processed events.
+    invocations: Vec<SubscribeEffectInvocation>,
+}
+
+#[cfg(feature = "subscribe")]
+impl SubscribeEventEngineHarness {
+    /// Create harness in `Unsubscribed` state.
+    pub fn new() -> Self {
+        Self {
+            state: SubscribeState::Unsubscribed,
+            invocations: Vec::new(),
+        }
+    }
+
+    /// Process `event` and return descriptions of produced invocations.
+    ///
+    /// Returns empty list if event is not expected in current state.
+    pub fn process(&mut self, event: SubscribeEvent) -> Vec<String> {
+        let invocations = transition(&mut self.state, &event);
+        let descriptions = describe(&invocations);
+        self.invocations.extend(invocations);
+        descriptions
+    }
+
+    /// Name of current state.
+    pub fn state(&self) -> &'static str {
+        match self.state {
+            SubscribeState::Unsubscribed => "Unsubscribed",
+            SubscribeState::Handshaking { .. } => "Handshaking",
+            SubscribeState::HandshakeReconnecting { .. } => "HandshakeReconnecting",
+            SubscribeState::HandshakeStopped { .. } => "HandshakeStopped",
+            SubscribeState::HandshakeFailed { .. } => "HandshakeFailed",
+            SubscribeState::Receiving { .. } => "Receiving",
+            SubscribeState::ReceiveReconnecting { .. } => "ReceiveReconnecting",
+            SubscribeState::ReceiveStopped { .. } => "ReceiveStopped",
+            SubscribeState::ReceiveFailed { .. } => "ReceiveFailed",
+        }
+    }
+
+    /// Descriptions of invocations produced by all processed events.
+    pub fn invocations(&self) -> Vec<String> {
+        describe(&self.invocations)
+    }
+}
+
+#[cfg(feature = "subscribe")]
+impl Default for SubscribeEventEngineHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Presence state machine harness.
+///
+/// Harness starts in `Inactive` state and records invocations produced by
+/// each processed event.
+///
+/// # Examples
+/// ```
+/// use pubnub::test::event_engine::{PresenceEvent, PresenceEventEngineHarness};
+///
+/// let mut harness = PresenceEventEngineHarness::new();
+/// harness.process(PresenceEvent::Joined {
+///     heartbeat_interval: 300,
+///     channels: Some(vec!["my_channel".into()]),
+///     channel_groups: None,
+/// });
+///
+/// assert_eq!(harness.state(), "Heartbeating");
+/// ```
+#[cfg(feature = "presence")]
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct PresenceEventEngineHarness {
+    /// Current state machine state.
+    state: PresenceState,
+
+    /// Invocations produced by all processed events.
+    invocations: Vec<PresenceEffectInvocation>,
+}
+
+#[cfg(feature = "presence")]
+impl PresenceEventEngineHarness {
+    /// Create harness in `Inactive` state.
+    pub fn new() -> Self {
+        Self {
+            state: PresenceState::Inactive,
+            invocations: Vec::new(),
+        }
+    }
+
+    /// Process `event` and return descriptions of produced invocations.
+    ///
+    /// Returns empty list if event is not expected in current state.
+    pub fn process(&mut self, event: PresenceEvent) -> Vec<String> {
+        let invocations = transition(&mut self.state, &event);
+        let descriptions = describe(&invocations);
+        self.invocations.extend(invocations);
+        descriptions
+    }
+
+    /// Name of current state.
+    pub fn state(&self) -> &'static str {
+        match self.state {
+            PresenceState::Inactive => "Inactive",
+            PresenceState::Heartbeating { .. } => "Heartbeating",
+            PresenceState::Cooldown { .. } => "Cooldown",
+            PresenceState::Reconnecting { .. } => "Reconnecting",
+            PresenceState::Stopped { .. } => "Stopped",
+            PresenceState::Failed { .. } => "Failed",
+        }
+    }
+
+    /// Descriptions of invocations produced by all processed events.
+    pub fn invocations(&self) -> Vec<String> {
+        describe(&self.invocations)
+    }
+}
+
+#[cfg(feature = "presence")]
+impl Default for PresenceEventEngineHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Perform `state` transition for `event` and return produced invocations.
+///
+/// Transition is performed the same way as by event engine, but invocations
+/// aren't dispatched for execution.
+fn transition<S>(
+    state: &mut S,
+    event: &<<S as State>::Invocation as EffectInvocation>::Event,
+) -> Vec<S::Invocation>
+where
+    S: State<State = S>,
+{
+    let Some(transition) = state.transition(event) else {
+        return Vec::new();
+    };
+
+    if let Some(new_state) = transition.state {
+        *state = new_state;
+    }
+
+    transition.invocations
+}
+
+/// Describe `invocations` with their string representation.
+fn describe<I>(invocations: &[I]) -> Vec<String>
+where
+    I: Display,
+{
+    invocations.iter().map(ToString::to_string).collect()
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[cfg(feature = "subscribe")]
+    #[test]
+    fn drive_subscribe_state_machine() {
+        let mut harness = SubscribeEventEngineHarness::new();
+
+        harness.process(SubscribeEvent::SubscriptionChanged {
+            channels: Some(vec!["ch1".into()]),
+            channel_groups: None,
+        });
+        let invocations = harness.process(SubscribeEvent::HandshakeSuccess {
+            cursor: "10".into(),
+        });
+
+        assert_eq!(harness.state(), "Receiving");
+        assert_eq!(invocations.first().unwrap(), "CANCEL_HANDSHAKE");
+        assert_eq!(invocations.last().unwrap(), "RECEIVE_MESSAGES");
+        assert!(harness
+            .process(SubscribeEvent::HandshakeSuccess {
+                cursor: "20".into(),
+            })
+            .is_empty());
+        assert_eq!(harness.invocations().len(), invocations.len() + 1);
+    }
+
+    #[cfg(feature = "presence")]
+    #[test]
+    fn drive_presence_state_machine() {
+        let mut harness = PresenceEventEngineHarness::new();
+
+        let invocations = harness.process(PresenceEvent::Joined {
+            heartbeat_interval: 10,
+            channels: Some(vec!["ch1".into()]),
+            channel_groups: None,
+        });
+
+        assert_eq!(harness.state(), "Heartbeating");
+        assert!(invocations.iter().any(|inv| inv.starts_with("HEARTBEAT")));
+    }
+}
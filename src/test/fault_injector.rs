@@ -0,0 +1,283 @@
+//! # Fault injection module
+//!
+//! This module contains [`FaultInjector`] which wraps real (or mock)
+//! transport with [`FaultInjectingTransport`] to fail requests according to
+//! controlled pattern. It can be used to validate retry policy, event engine
+//! and status events behavior under failures.
+
+use crate::{
+    core::{PubNubError, Transport, TransportRequest, TransportResponse},
+    lib::{
+        alloc::{format, string::String, sync::Arc},
+        core::{
+            sync::atomic::{AtomicUsize, Ordering},
+            time::Duration,
+        },
+    },
+};
+
+/// Fault injection configuration.
+///
+/// By default injector doesn't fail requests. Failed requests are completed
+/// with [`PubNubError::Transport`] error (like network issues), unless
+/// response status code has been set with [`with_status`].
+///
+/// # Examples
+/// ```
+/// use pubnub::{test::FaultInjector, transport::TransportReqwest, Keyset, PubNubClientBuilder};
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let transport = FaultInjector::new()
+///     .fail_every(3)
+///     .with_status(500)
+///     .with_latency(Duration::from_millis(100))
+///     .inject(TransportReqwest::default());
+///
+/// let client = PubNubClientBuilder::with_transport(transport)
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("user_id")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`with_status`]: FaultInjector::with_status
+#[derive(Debug, Clone, Default)]
+pub struct FaultInjector {
+    /// Each `n`-th request will fail.
+    fail_every: Option<usize>,
+
+    /// Number of first requests which will fail.
+    fail_first: usize,
+
+    /// Status code of failed request response.
+    status: Option<u16>,
+
+    /// Delay before each response will be returned.
+    latency: Option<Duration>,
+
+    /// Prefix of path of requests which should be affected.
+    path: Option<String>,
+}
+
+impl FaultInjector {
+    /// Create fault injector which doesn't fail requests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fail each `n`-th request (`n` equal to `0` disables periodic
+    /// failures).
+    pub fn fail_every(mut self, n: usize) -> Self {
+        self.fail_every = (n > 0).then_some(n);
+        self
+    }
+
+    /// Fail first `n` requests.
+    pub fn fail_first(mut self, n: usize) -> Self {
+        self.fail_first = n;
+        self
+    }
+
+    /// Complete failed requests with response with `status` code instead of
+    /// transport error.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Delay each response by `latency`.
+    ///
+    /// Delay is applied with `tokio` timer by asynchronous transport (requires
+    /// `tokio` feature) and with thread sleep by blocking transport.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Affect only requests which path starts with `path`.
+    ///
+    /// Other requests are passed to the wrapped transport as is and aren't
+    /// counted.
+    pub fn with_path<S>(mut self, path: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Wrap `transport` with fault injecting decorator.
+    pub fn inject<T>(self, transport: T) -> FaultInjectingTransport<T> {
+        FaultInjectingTransport {
+            transport,
+            injector: Arc::new(self),
+            requests: Default::default(),
+            injected: Default::default(),
+        }
+    }
+}
+
+/// Fault injecting transport decorator.
+///
+/// Transport fails requests according to the [`FaultInjector`] configuration
+/// and pass other requests to the wrapped transport.
+///
+/// Transport clones share requests counter.
+#[derive(Debug, Clone)]
+pub struct FaultInjectingTransport<T> {
+    /// Wrapped transport.
+    transport: T,
+
+    /// Fault injection configuration.
+    injector: Arc<FaultInjector>,
+
+    /// Number of affected requests.
+    requests: Arc<AtomicUsize>,
+
+    /// Number of failed requests.
+    injected: Arc<AtomicUsize>,
+}
+
+impl<T> FaultInjectingTransport<T> {
+    /// Number of requests which has been failed by injector.
+    pub fn injected_faults(&self) -> usize {
+        self.injected.load(Ordering::SeqCst)
+    }
+
+    /// Configured response delay.
+    fn latency(&self) -> Option<Duration> {
+        self.injector.latency
+    }
+
+    /// Check whether `request` should fail and create fault for it.
+    fn fault(&self, request: &TransportRequest) -> Option<Result<TransportResponse, PubNubError>> {
+        if let Some(path) = &self.injector.path {
+            if !request.path.starts_with(path) {
+                return None;
+            }
+        }
+
+        let number = self.requests.fetch_add(1, Ordering::SeqCst) + 1;
+        let should_fail = number <= self.injector.fail_first
+            || self
+                .injector
+                .fail_every
+                .is_some_and(|n| number.is_multiple_of(n));
+
+        if !should_fail {
+            return None;
+        }
+        self.injected.fetch_add(1, Ordering::SeqCst);
+
+        Some(match self.injector.status {
+            Some(status) => Ok(TransportResponse {
+                status,
+                body: Some(
+                    format!(
+                        r#"{{"status":{status},"error":true,"message":"Injected fault","service":"Fault injector"}}"#
+                    )
+                    .into_bytes()
+                    .into(),
+                ),
+                ..Default::default()
+            }),
+            None => Err(PubNubError::Transport {
+                details: error_details!("Injected fault for '{}' request", request.path),
+                response: None,
+            }),
+        })
+    }
+}
+
+impl<T> Transport for FaultInjectingTransport<T>
+where
+    T: Transport,
+{
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        #[cfg(feature = "tokio")]
+        if let Some(latency) = self.latency() {
+            tokio::time::sleep(latency).await;
+        }
+
+        match self.fault(&request) {
+            Some(fault) => fault,
+            None => self.transport.send(request).await,
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T> crate::core::blocking::Transport for FaultInjectingTransport<T>
+where
+    T: crate::core::blocking::Transport,
+{
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        if let Some(latency) = self.latency() {
+            std::thread::sleep(latency);
+        }
+
+        match self.fault(&request) {
+            Some(fault) => fault,
+            None => self.transport.send(request),
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::test::MockTransport;
+
+    fn request(path: &str) -> TransportRequest {
+        TransportRequest {
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    fn transport(injector: FaultInjector) -> FaultInjectingTransport<MockTransport> {
+        injector.inject(
+            MockTransport::new().with_default_response(TransportResponse {
+                status: 200,
+                ..Default::default()
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn fail_every_nth_request_with_status() {
+        let transport = transport(FaultInjector::new().fail_every(2).with_status(500));
+
+        let statuses = [
+            transport.send(request("/time/0")).await.unwrap().status,
+            transport.send(request("/time/0")).await.unwrap().status,
+            transport.send(request("/time/0")).await.unwrap().status,
+            transport.send(request("/time/0")).await.unwrap().status,
+        ];
+
+        assert_eq!(statuses, [200, 500, 200, 500]);
+        assert_eq!(transport.injected_faults(), 2);
+    }
+
+    #[tokio::test]
+    async fn fail_first_requests_for_path() {
+        let transport = transport(
+            FaultInjector::new()
+                .fail_first(1)
+                .with_path("/v2/subscribe"),
+        );
+
+        assert!(transport.send(request("/time/0")).await.is_ok());
+        assert!(matches!(
+            transport.send(request("/v2/subscribe/demo")).await,
+            Err(PubNubError::Transport { .. })
+        ));
+        assert!(transport.send(request("/v2/subscribe/demo")).await.is_ok());
+    }
+}
@@ -0,0 +1,409 @@
+//! # Subscribe fixtures module
+//!
+//! This module contains [`SubscribeResponseFixture`] and [`EnvelopeFixture`]
+//! which can be used to build valid subscribe [`PubNub API`] responses for
+//! mock transports (instead of hand-written raw JSON).
+//!
+//! [`PubNub API`]: https://www.pubnub.com/docs
+
+use crate::{
+    core::TransportResponse,
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    },
+};
+use serde_json::{json, Value};
+
+/// Suffix of channels with presence events.
+const PRESENCE_CHANNEL_SUFFIX: &str = "-pnpres";
+
+/// Subscribe response fixture.
+///
+/// Fixture contains next subscription cursor and list of real-time updates.
+/// Updates added with convenience methods use response timetoken as their
+/// publish time.
+///
+/// # Examples
+/// ```
+/// use pubnub::test::{fixtures::SubscribeResponseFixture, MockTransport};
+///
+/// let transport = MockTransport::new().with_path_response(
+///     "/v2/subscribe",
+///     SubscribeResponseFixture::new("17000000000000000")
+///         .message("my_channel", serde_json::json!({"text": "hello"}))
+///         .presence("my_channel", "join", "user-2", 2)
+///         .into_response(),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubscribeResponseFixture {
+    /// Next subscription cursor timetoken.
+    timetoken: String,
+
+    /// Next subscription cursor region.
+    region: u32,
+
+    /// List of real-time updates.
+    envelopes: Vec<EnvelopeFixture>,
+}
+
+/// Subscribe response real-time update fixture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvelopeFixture {
+    /// Name of channel where update has been received.
+    channel: String,
+
+    /// PubNub defined event type.
+    message_type: u32,
+
+    /// Event payload.
+    payload: Value,
+
+    /// Message publish timetoken.
+    published: String,
+
+    /// Identifier of client which sent message.
+    sender: Option<String>,
+
+    /// Name of subscription through which event has been delivered.
+    subscription: Option<String>,
+
+    /// User provided message type.
+    r#type: Option<String>,
+}
+
+impl SubscribeResponseFixture {
+    /// Create response fixture with `timetoken` as next subscription cursor
+    /// and without updates.
+    pub fn new<S>(timetoken: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            timetoken: timetoken.into(),
+            region: 1,
+            envelopes: vec![],
+        }
+    }
+
+    /// Set next subscription cursor `region`.
+    pub fn with_region(mut self, region: u32) -> Self {
+        self.region = region;
+        self
+    }
+
+    /// Add real-time update.
+    pub fn envelope(mut self, envelope: EnvelopeFixture) -> Self {
+        self.envelopes.push(envelope);
+        self
+    }
+
+    /// Add message published to `channel`.
+    pub fn message<S>(self, channel: S, payload: Value) -> Self
+    where
+        S: Into<String>,
+    {
+        let envelope = EnvelopeFixture::message(channel, payload);
+        self.published(envelope)
+    }
+
+    /// Add signal sent to `channel`.
+    pub fn signal<S>(self, channel: S, payload: Value) -> Self
+    where
+        S: Into<String>,
+    {
+        let envelope = EnvelopeFixture::signal(channel, payload);
+        self.published(envelope)
+    }
+
+    /// Add presence `action` (`join`, `leave`, `timeout`) of `uuid` on
+    /// `channel`.
+    pub fn presence<S>(self, channel: S, action: &str, uuid: &str, occupancy: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        let envelope = EnvelopeFixture::presence(channel, action, uuid, occupancy);
+        self.published(envelope)
+    }
+
+    /// Add file shared on `channel`.
+    pub fn file<S>(self, channel: S, message: &str, id: &str, name: &str) -> Self
+    where
+        S: Into<String>,
+    {
+        let envelope = EnvelopeFixture::file(channel, message, id, name);
+        self.published(envelope)
+    }
+
+    /// Add message action `event` (`update` or `delete`) on `channel`.
+    pub fn message_action<S>(
+        self,
+        channel: S,
+        event: &str,
+        message_timetoken: &str,
+        r#type: &str,
+        value: &str,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        let action_timetoken = self.timetoken.clone();
+        let envelope = EnvelopeFixture::message_action(
+            channel,
+            event,
+            message_timetoken,
+            &action_timetoken,
+            r#type,
+            value,
+        );
+        self.published(envelope)
+    }
+
+    /// Subscribe response JSON document.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "t": { "t": self.timetoken, "r": self.region },
+            "m": self
+                .envelopes
+                .iter()
+                .map(|envelope| envelope.to_json(self.region))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    /// Serialized subscribe response.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_json().to_string().into_bytes()
+    }
+
+    /// Successful transport response with subscribe response body.
+    pub fn into_response(self) -> TransportResponse {
+        TransportResponse {
+            status: 200,
+            body: Some(self.to_bytes().into()),
+            ..Default::default()
+        }
+    }
+
+    /// Add `envelope` which has been published with response timetoken.
+    fn published(self, envelope: EnvelopeFixture) -> Self {
+        let timetoken = self.timetoken.clone();
+        self.envelope(envelope.with_published(timetoken))
+    }
+}
+
+impl EnvelopeFixture {
+    /// Create message envelope for `channel`.
+    pub fn message<S>(channel: S, payload: Value) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(channel.into(), 0, payload)
+    }
+
+    /// Create signal envelope for `channel`.
+    pub fn signal<S>(channel: S, payload: Value) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(channel.into(), 1, payload)
+    }
+
+    /// Create presence envelope for `channel`.
+    ///
+    /// Presence channel suffix is added to the `channel` name.
+    pub fn presence<S>(channel: S, action: &str, uuid: &str, occupancy: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        let channel = channel.into();
+        let timestamp = crate::core::Clock::unix_timestamp(&crate::core::SystemClock);
+
+        Self::new(
+            format!("{channel}{PRESENCE_CHANNEL_SUFFIX}"),
+            0,
+            json!({
+                "action": action,
+                "timestamp": timestamp,
+                "uuid": uuid,
+                "occupancy": occupancy,
+            }),
+        )
+        .with_subscription(format!("{channel}{PRESENCE_CHANNEL_SUFFIX}"))
+    }
+
+    /// Create file envelope for `channel`.
+    pub fn file<S>(channel: S, message: &str, id: &str, name: &str) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(
+            channel.into(),
+            4,
+            json!({
+                "message": message,
+                "file": { "id": id, "name": name },
+            }),
+        )
+    }
+
+    /// Create message action envelope for `channel`.
+    pub fn message_action<S>(
+        channel: S,
+        event: &str,
+        message_timetoken: &str,
+        action_timetoken: &str,
+        r#type: &str,
+        value: &str,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(
+            channel.into(),
+            3,
+            json!({
+                "event": event,
+                "source": "actions",
+                "version": "1.0",
+                "data": {
+                    "messageTimetoken": message_timetoken,
+                    "actionTimetoken": action_timetoken,
+                    "type": r#type,
+                    "value": value,
+                },
+            }),
+        )
+    }
+
+    /// Set message publish timetoken.
+    pub fn with_published<S>(mut self, timetoken: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.published = timetoken.into();
+        self
+    }
+
+    /// Set identifier of client which sent message.
+    pub fn with_sender<S>(mut self, sender: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.sender = Some(sender.into());
+        self
+    }
+
+    /// Set name of subscription (channel group or wildcard) through which
+    /// event has been delivered.
+    pub fn with_subscription<S>(mut self, subscription: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.subscription = Some(subscription.into());
+        self
+    }
+
+    /// Set user provided message type.
+    pub fn with_type<S>(mut self, r#type: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.r#type = Some(r#type.into());
+        self
+    }
+
+    /// Create envelope of `message_type` with `payload` for `channel`.
+    fn new(channel: String, message_type: u32, payload: Value) -> Self {
+        Self {
+            channel,
+            message_type,
+            payload,
+            published: "0".to_string(),
+            sender: None,
+            subscription: None,
+            r#type: None,
+        }
+    }
+
+    /// Envelope JSON document.
+    fn to_json(&self, region: u32) -> Value {
+        let mut envelope = json!({
+            "a": "1",
+            "f": 0,
+            "p": { "t": self.published, "r": region },
+            "k": "demo",
+            "c": self.channel,
+            "d": self.payload,
+        });
+
+        if self.message_type != 0 {
+            envelope["e"] = self.message_type.into();
+        }
+        if let Some(sender) = &self.sender {
+            envelope["i"] = sender.clone().into();
+        }
+        if let Some(subscription) = &self.subscription {
+            envelope["b"] = subscription.clone().into();
+        }
+        if let Some(r#type) = &self.r#type {
+            envelope["mt"] = r#type.clone().into();
+        }
+
+        envelope
+    }
+}
+
+#[cfg(all(test, feature = "subscribe"))]
+mod should {
+    use super::*;
+    use crate::dx::subscribe::{result::SubscribeResult, SubscribeResponseBody, Update};
+
+    #[test]
+    fn build_parsable_subscribe_response() {
+        let fixture = SubscribeResponseFixture::new("17000000000000000")
+            .message("channel", json!({"text": "hello"}))
+            .presence("channel", "join", "user-2", 2)
+            .envelope(
+                EnvelopeFixture::message("channel", json!("hi"))
+                    .with_sender("user-1")
+                    .with_type("greeting"),
+            );
+
+        let body: SubscribeResponseBody = serde_json::from_slice(&fixture.to_bytes()).unwrap();
+        let result = SubscribeResult::try_from(body).unwrap();
+        assert_eq!(result.cursor.timetoken, "17000000000000000");
+
+        let messages = result.messages.collect::<Result<Vec<_>, _>>().unwrap();
+        assert!(matches!(messages[0], Update::Message(_)));
+        assert!(matches!(messages[1], Update::Presence(_)));
+        assert!(
+            matches!(&messages[2], Update::Message(message) if message.sender.as_deref() == Some("user-1"))
+        );
+    }
+
+    #[test]
+    fn build_typed_real_time_updates() {
+        let fixture = SubscribeResponseFixture::new("17000000000000000")
+            .signal("channel", json!("typing"))
+            .file("channel", "look", "file-id", "image.png")
+            .message_action(
+                "channel",
+                "update",
+                "16000000000000000",
+                "reaction",
+                "smile",
+            );
+
+        let body: SubscribeResponseBody = serde_json::from_slice(&fixture.to_bytes()).unwrap();
+        let result = SubscribeResult::try_from(body).unwrap();
+        let messages = result.messages.collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert!(matches!(messages[0], Update::Signal(_)));
+        assert!(matches!(&messages[1], Update::File(file) if file.name == "image.png"));
+        assert!(matches!(messages[2], Update::MessageAction(_)));
+    }
+}
@@ -0,0 +1,339 @@
+//! # Mock transport module
+//!
+//! This module contains [`MockTransport`] which can be used instead of real
+//! transport to script [`PubNub API`] responses and inspect sent requests.
+//!
+//! [`PubNub API`]: https://www.pubnub.com/docs
+
+use crate::{
+    core::{PubNubError, Transport, TransportRequest, TransportResponse},
+    lib::{
+        alloc::{collections::VecDeque, string::String, sync::Arc, vec::Vec},
+        sync::Mutex,
+    },
+};
+
+/// Scripted transport response.
+type MockResponse = Result<TransportResponse, PubNubError>;
+
+/// Mock transport.
+///
+/// Transport captures all sent requests and responds with scripted
+/// responses:
+/// * responses for requests with specific path prefix (added with
+///   [`with_path_response`]),
+/// * queued responses (added with [`with_response`] and [`with_error`]) in
+///   order in which they have been added,
+/// * default response (set with [`with_default_response`]).
+///
+/// Sent requests can be asserted on as they are sent with
+/// [`with_request_check`].
+///
+/// Request fails with [`PubNubError::Transport`] error if there is no
+/// suitable response.
+///
+/// Transport clones share scripted responses and captured requests, so one
+/// clone can be passed to the client and another used for assertions.
+///
+/// # Examples
+/// ```
+/// use pubnub::{core::TransportResponse, test::MockTransport, Keyset, PubNubClientBuilder};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let transport = MockTransport::new().with_path_response(
+///     "/publish",
+///     TransportResponse {
+///         status: 200,
+///         body: Some(br#"[1,"Sent","17000000000000000"]"#.to_vec().into()),
+///         ..Default::default()
+///     },
+/// );
+///
+/// let client = PubNubClientBuilder::with_transport(transport.clone())
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("user_id")
+///     .build()?;
+///
+/// client
+///     .publish_message("hello")
+///     .channel("my_channel")
+///     .execute()
+///     .await?;
+///
+/// assert_eq!(transport.requests().len(), 1);
+/// assert!(transport.requests()[0].path.contains("my_channel"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`with_path_response`]: MockTransport::with_path_response
+/// [`with_response`]: MockTransport::with_response
+/// [`with_error`]: MockTransport::with_error
+/// [`with_default_response`]: MockTransport::with_default_response
+/// [`with_request_check`]: MockTransport::with_request_check
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    inner: Arc<MockTransportState>,
+}
+
+/// Shared mock transport state.
+#[derive(Debug, Default)]
+struct MockTransportState {
+    /// Captured requests.
+    requests: Mutex<Vec<TransportRequest>>,
+
+    /// Queued responses.
+    responses: Mutex<VecDeque<MockResponse>>,
+
+    /// Responses for requests with specific path prefix.
+    path_responses: Mutex<Vec<(String, MockResponse)>>,
+
+    /// Response which is used when there are no other suitable responses.
+    default_response: Mutex<Option<MockResponse>>,
+
+    /// Delay before response will be returned.
+    latency: Mutex<Option<core::time::Duration>>,
+
+    /// Check which is called with each sent request.
+    request_check: Mutex<Option<RequestCheck>>,
+}
+
+/// Sent request check.
+#[derive(Clone)]
+struct RequestCheck(Arc<dyn Fn(&TransportRequest) + Send + Sync>);
+
+impl core::fmt::Debug for RequestCheck {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RequestCheck")
+    }
+}
+
+impl MockTransport {
+    /// Create mock transport without scripted responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `response`.
+    ///
+    /// Queued responses are returned in order in which they have been added.
+    pub fn with_response(self, response: TransportResponse) -> Self {
+        self.inner.responses.lock().push_back(Ok(response));
+        self
+    }
+
+    /// Queue `error`.
+    ///
+    /// Request will fail with provided `error` when it will be its turn.
+    pub fn with_error(self, error: PubNubError) -> Self {
+        self.inner.responses.lock().push_back(Err(error));
+        self
+    }
+
+    /// Respond with `response` to requests which path starts with `path`.
+    ///
+    /// Path responses take precedence over queued responses and aren't
+    /// removed after use.
+    pub fn with_path_response<S>(self, path: S, response: TransportResponse) -> Self
+    where
+        S: Into<String>,
+    {
+        self.inner
+            .path_responses
+            .lock()
+            .push((path.into(), Ok(response)));
+        self
+    }
+
+    /// Respond with `response` when there are no other suitable responses.
+    pub fn with_default_response(self, response: TransportResponse) -> Self {
+        *self.inner.default_response.lock() = Some(Ok(response));
+        self
+    }
+
+    /// Delay each response by `latency`.
+    ///
+    /// Delay is applied with `tokio` timer by asynchronous transport (requires
+    /// `tokio` feature) and with thread sleep by blocking transport.
+    pub fn with_latency(self, latency: core::time::Duration) -> Self {
+        *self.inner.latency.lock() = Some(latency);
+        self
+    }
+
+    /// Call `check` with each sent request.
+    ///
+    /// Check can be used to assert on request before response will be
+    /// returned (e.g. by panicking when request is unexpected).
+    pub fn with_request_check<F>(self, check: F) -> Self
+    where
+        F: Fn(&TransportRequest) + Send + Sync + 'static,
+    {
+        *self.inner.request_check.lock() = Some(RequestCheck(Arc::new(check)));
+        self
+    }
+
+    /// List of requests sent through transport.
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.inner.requests.lock().clone()
+    }
+
+    /// Most recent request sent through transport.
+    pub fn last_request(&self) -> Option<TransportRequest> {
+        self.inner.requests.lock().last().cloned()
+    }
+
+    /// Remove captured requests.
+    pub fn clear_requests(&self) {
+        self.inner.requests.lock().clear();
+    }
+
+    /// Capture `request` and pick scripted response for it.
+    fn respond(&self, request: TransportRequest) -> MockResponse {
+        let request_check = self.inner.request_check.lock().clone();
+        if let Some(RequestCheck(check)) = request_check {
+            check(&request);
+        }
+
+        let path_response = self
+            .inner
+            .path_responses
+            .lock()
+            .iter()
+            .find(|(path, _)| request.path.starts_with(path))
+            .map(|(_, response)| response.clone());
+        self.inner.requests.lock().push(request.clone());
+
+        path_response
+            .or_else(|| self.inner.responses.lock().pop_front())
+            .or_else(|| self.inner.default_response.lock().clone())
+            .unwrap_or_else(|| {
+                Err(PubNubError::Transport {
+                    details: error_details!("No scripted response for '{}' request", request.path),
+                    response: None,
+                })
+            })
+    }
+
+    /// Configured response delay.
+    fn latency(&self) -> Option<core::time::Duration> {
+        *self.inner.latency.lock()
+    }
+}
+
+impl Transport for MockTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        #[cfg(feature = "tokio")]
+        if let Some(latency) = self.latency() {
+            tokio::time::sleep(latency).await;
+        }
+
+        self.respond(request)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl crate::core::blocking::Transport for MockTransport {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        if let Some(latency) = self.latency() {
+            std::thread::sleep(latency);
+        }
+
+        self.respond(request)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    fn request(path: &str) -> TransportRequest {
+        TransportRequest {
+            path: path.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn response(status: u16) -> TransportResponse {
+        TransportResponse {
+            status,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn respond_with_scripted_responses() {
+        let transport = MockTransport::new()
+            .with_path_response("/time", response(200))
+            .with_response(response(201))
+            .with_error(PubNubError::Transport {
+                details: error_details!("Network unreachable"),
+                response: None,
+            })
+            .with_default_response(response(204));
+
+        assert_eq!(
+            transport.send(request("/time/0")).await.unwrap().status,
+            200
+        );
+        assert_eq!(
+            transport.send(request("/publish")).await.unwrap().status,
+            201
+        );
+        assert!(transport.send(request("/publish")).await.is_err());
+        assert_eq!(
+            transport.send(request("/publish")).await.unwrap().status,
+            204
+        );
+    }
+
+    #[tokio::test]
+    async fn capture_sent_requests() {
+        let transport = MockTransport::new();
+        let clone = transport.clone();
+
+        assert!(clone.send(request("/time/0")).await.is_err());
+        assert!(clone.send(request("/publish")).await.is_err());
+
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(transport.last_request().unwrap().path, "/publish");
+
+        transport.clear_requests();
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_sent_requests() {
+        let transport = MockTransport::new()
+            .with_default_response(response(200))
+            .with_request_check(|request| assert_eq!(request.path, "/time/0"));
+
+        assert!(transport.send(request("/time/0")).await.is_ok());
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn panic_when_request_check_fails() {
+        let transport = MockTransport::new()
+            .with_default_response(response(200))
+            .with_request_check(|request| assert_eq!(request.path, "/time/0"));
+
+        let _ = transport.send(request("/publish")).await;
+    }
+
+    #[tokio::test]
+    async fn delay_response() {
+        let transport = MockTransport::new()
+            .with_default_response(response(200))
+            .with_latency(core::time::Duration::from_millis(50));
+
+        let started_at = std::time::Instant::now();
+        transport.send(request("/time/0")).await.unwrap();
+
+        assert!(started_at.elapsed() >= core::time::Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,57 @@
+//! # Test utilities module
+//!
+//! This module contains utilities which can be used by applications to
+//! unit-test code which works with [`PubNubClientInstance`] without access to
+//! the network.
+//!
+//! [`PubNubClientInstance`]: crate::dx::pubnub_client::PubNubClientInstance
+
+#[doc(inline)]
+pub use mock_transport::MockTransport;
+pub mod mock_transport;
+
+#[doc(inline)]
+pub use fault_injector::{FaultInjectingTransport, FaultInjector};
+pub mod fault_injector;
+
+#[cfg(feature = "tokio")]
+#[doc(inline)]
+pub use virtual_time::VirtualTimeRuntime;
+#[cfg(feature = "tokio")]
+pub mod virtual_time;
+
+#[cfg(feature = "serde")]
+pub mod fixtures;
+
+#[cfg(feature = "serde")]
+pub mod record_replay;
+
+#[cfg(feature = "reqwest")]
+pub mod contract;
+
+#[cfg(feature = "emulator")]
+pub mod emulator;
+
+#[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+#[doc(hidden)]
+pub mod event_engine;
+
+/// Create client with `demo` keyset which sends requests through `transport`.
+#[cfg(all(test, feature = "serde"))]
+pub(crate) fn mock_client(
+    transport: MockTransport,
+    user_id: &str,
+) -> crate::PubNubGenericClient<
+    MockTransport,
+    crate::providers::deserialization_serde::DeserializerSerde,
+> {
+    crate::PubNubClientBuilder::with_transport(transport)
+        .with_keyset(crate::Keyset {
+            subscribe_key: "demo",
+            publish_key: Some("demo"),
+            secret_key: None,
+        })
+        .with_user_id(user_id)
+        .build()
+        .unwrap()
+}
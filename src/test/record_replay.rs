@@ -0,0 +1,363 @@
+//! # Record and replay transport module
+//!
+//! This module contains [`RecordingTransport`] which records request and
+//! response pairs sent through real transport to the file, and
+//! [`ReplayTransport`] which replays recorded responses. Together they can be
+//! used to write hermetic integration tests for publish and subscribe flows:
+//! recording is done once with access to [`PubNub API`], and CI runs replay
+//! recorded exchanges without network access.
+//!
+//! [`PubNub API`]: https://www.pubnub.com/docs
+
+use std::path::{Path, PathBuf};
+
+use base64::{engine::general_purpose, Engine};
+
+use crate::{
+    core::{PubNubError, Transport, TransportRequest, TransportResponse},
+    lib::{
+        alloc::{
+            collections::BTreeMap,
+            string::{String, ToString},
+            sync::Arc,
+            vec::Vec,
+        },
+        sync::Mutex,
+    },
+};
+
+/// Query parameters which are different for each request.
+///
+/// These parameters are ignored when recorded request is matched with sent
+/// request.
+const VOLATILE_QUERY_PARAMETERS: [&str; 5] =
+    ["requestid", "instanceid", "signature", "timestamp", "pnsdk"];
+
+/// Recorded request and response pair.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct RecordedExchange {
+    /// Request HTTP method.
+    method: String,
+
+    /// Request path.
+    path: String,
+
+    /// Request query parameters (without volatile parameters).
+    query_parameters: BTreeMap<String, String>,
+
+    /// Base64 encoded request body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_body: Option<String>,
+
+    /// Response HTTP status code.
+    #[serde(default)]
+    status: u16,
+
+    /// Response headers.
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+
+    /// Base64 encoded response body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    response_body: Option<String>,
+
+    /// Transport error details (if request failed).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl RecordedExchange {
+    /// Create exchange record from sent `request`.
+    fn new(request: &TransportRequest) -> Self {
+        Self {
+            method: request.method.to_string(),
+            path: request.path.clone(),
+            query_parameters: Self::stable_query_parameters(request),
+            request_body: request
+                .body
+                .as_ref()
+                .map(|body| general_purpose::STANDARD.encode(body)),
+            status: 0,
+            headers: Default::default(),
+            response_body: None,
+            error: None,
+        }
+    }
+
+    /// Whether record has been created for the same `request` or not.
+    fn matches(&self, request: &TransportRequest) -> bool {
+        self.method == request.method.to_string()
+            && self.path == request.path
+            && self.query_parameters == Self::stable_query_parameters(request)
+    }
+
+    /// Request query parameters without volatile parameters.
+    fn stable_query_parameters(request: &TransportRequest) -> BTreeMap<String, String> {
+        request
+            .query_parameters
+            .iter()
+            .filter(|(key, _)| !VOLATILE_QUERY_PARAMETERS.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    /// Recorded transport response.
+    fn response(&self) -> Result<TransportResponse, PubNubError> {
+        if let Some(details) = &self.error {
+            return Err(PubNubError::Transport {
+                details: error_details!(details),
+                response: None,
+            });
+        }
+
+        let body = self
+            .response_body
+            .as_ref()
+            .map(|body| general_purpose::STANDARD.decode(body))
+            .transpose()
+            .map_err(|err| PubNubError::Deserialization {
+                details: error_details!(err),
+            })?;
+
+        Ok(TransportResponse {
+            status: self.status,
+            headers: self
+                .headers
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            body: body.map(Into::into),
+        })
+    }
+}
+
+/// Recording transport decorator.
+///
+/// Transport sends requests with wrapped transport and writes each request
+/// and response pair to the file at provided path (file is rewritten after
+/// each request).
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{
+///     test::record_replay::RecordingTransport, transport::TransportReqwest, Keyset,
+///     PubNubClientBuilder,
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let transport = RecordingTransport::new(TransportReqwest::default(), "fixtures/publish.json");
+/// let client = PubNubClientBuilder::with_transport(transport)
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("user_id")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecordingTransport<T> {
+    /// Transport which is used to send requests.
+    transport: T,
+
+    /// Path to the file where exchanges should be stored.
+    path: PathBuf,
+
+    /// Recorded exchanges.
+    exchanges: Arc<Mutex<Vec<RecordedExchange>>>,
+}
+
+impl<T> RecordingTransport<T> {
+    /// Create recording decorator for `transport`, which writes exchanges to
+    /// the file at `path`.
+    pub fn new<P>(transport: T, path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Self {
+            transport,
+            path: path.as_ref().to_path_buf(),
+            exchanges: Default::default(),
+        }
+    }
+
+    /// Store `exchange` and write all recorded exchanges to the file.
+    fn record(&self, exchange: RecordedExchange) -> Result<(), PubNubError> {
+        let serialized = {
+            let mut exchanges = self.exchanges.lock();
+            exchanges.push(exchange);
+            serde_json::to_vec_pretty(&*exchanges).map_err(|err| PubNubError::Serialization {
+                details: error_details!(err),
+            })?
+        };
+
+        std::fs::write(&self.path, serialized).map_err(|err| PubNubError::Serialization {
+            details: error_details!("Unable to write records to {}: {err}", self.path.display()),
+        })
+    }
+}
+
+impl<T> Transport for RecordingTransport<T>
+where
+    T: Transport,
+{
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        let mut exchange = RecordedExchange::new(&request);
+        let result = self.transport.send(request).await;
+
+        match &result {
+            Ok(response) => {
+                exchange.status = response.status;
+                exchange.headers = response
+                    .headers
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                exchange.response_body = response
+                    .body
+                    .as_ref()
+                    .map(|body| general_purpose::STANDARD.encode(body));
+            }
+            Err(error) => exchange.error = Some(error.to_string()),
+        }
+        self.record(exchange)?;
+
+        result
+    }
+}
+
+/// Replaying transport.
+///
+/// Transport responds with responses recorded by [`RecordingTransport`].
+/// Sent request is matched with the first not used record which has the
+/// same method, path and query parameters (except parameters which are
+/// different for each request, like `requestid` and `signature`).
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{test::record_replay::ReplayTransport, Keyset, PubNubClientBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let transport = ReplayTransport::from_file("fixtures/publish.json")?;
+/// let client = PubNubClientBuilder::with_transport(transport)
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("user_id")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    /// Recorded exchanges which hasn't been used yet.
+    exchanges: Arc<Mutex<Vec<RecordedExchange>>>,
+}
+
+impl ReplayTransport {
+    /// Create replaying transport with exchanges recorded to the file at
+    /// `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::Deserialization`] if file can't be read or has
+    /// unexpected format.
+    pub fn from_file<P>(path: P) -> Result<Self, PubNubError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|err| PubNubError::Deserialization {
+            details: error_details!("Unable to read records from {}: {err}", path.display()),
+        })?;
+        let exchanges = serde_json::from_slice::<Vec<RecordedExchange>>(&bytes).map_err(|err| {
+            PubNubError::Deserialization {
+                details: error_details!(err),
+            }
+        })?;
+
+        Ok(Self {
+            exchanges: Arc::new(Mutex::new(exchanges)),
+        })
+    }
+
+    /// Number of recorded exchanges which hasn't been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.exchanges.lock().len()
+    }
+}
+
+impl Transport for ReplayTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        let exchange = {
+            let mut exchanges = self.exchanges.lock();
+            exchanges
+                .iter()
+                .position(|exchange| exchange.matches(&request))
+                .map(|idx| exchanges.remove(idx))
+        };
+
+        exchange
+            .ok_or_else(|| PubNubError::Transport {
+                details: error_details!(
+                    "No recorded response for {} '{}' request",
+                    request.method,
+                    request.path
+                ),
+                response: None,
+            })?
+            .response()
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::test::MockTransport;
+
+    fn request(requestid: &str) -> TransportRequest {
+        TransportRequest {
+            path: "/time/0".into(),
+            query_parameters: [
+                ("requestid".to_string(), requestid.to_string()),
+                ("uuid".to_string(), "user".to_string()),
+            ]
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn replay_recorded_exchanges() {
+        let path = std::env::temp_dir().join(format!(
+            "pubnub-record-replay-{}.json",
+            uuid::Uuid::new_v4()
+        ));
+        let recording = RecordingTransport::new(
+            MockTransport::new().with_response(TransportResponse {
+                status: 200,
+                body: Some(b"[17000000000000000]".to_vec().into()),
+                ..Default::default()
+            }),
+            &path,
+        );
+
+        let recorded = recording.send(request("1")).await.unwrap();
+        assert!(recording.send(request("2")).await.is_err());
+
+        let replay = ReplayTransport::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(replay.remaining(), 2);
+        assert_eq!(replay.send(request("3")).await.unwrap(), recorded);
+        assert!(matches!(
+            replay.send(request("4")).await,
+            Err(PubNubError::Transport { .. })
+        ));
+        assert_eq!(replay.remaining(), 0);
+    }
+}
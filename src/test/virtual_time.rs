@@ -0,0 +1,249 @@
+//! # Virtual time runtime module
+//!
+//! This module contains [`VirtualTimeRuntime`] which implements [`Runtime`]
+//! with virtual time. Delays requested by the client (heartbeat, wait and
+//! retry / reconnection delays) don't depend on the wall clock, so tests for
+//! reconnection and backoff complete instantly and deterministically.
+//!
+//! [`Runtime`]: crate::core::Runtime
+
+use async_channel::{bounded, Sender};
+
+use crate::{
+    core::{Clock, Runtime},
+    lib::{
+        alloc::{sync::Arc, vec::Vec},
+        core::{future::Future, time::Duration},
+        sync::Mutex,
+    },
+};
+
+/// Virtual time runtime.
+///
+/// Tasks are spawned with `tokio`, but delays are measured with virtual clock
+/// which never reads wall clock or `tokio` timer. This makes runtime usable
+/// inside `tokio` runtime with paused time (`tokio::time::pause`).
+///
+/// Runtime can work in two modes:
+/// * auto-advancing (created with [`new`]) – each requested delay completes
+///   immediately and moves virtual clock forward,
+/// * manual (created with [`manual`]) – requested delays complete only when
+///   virtual clock is moved with [`advance`].
+///
+/// Runtime clones share virtual clock. Runtime also implements [`Clock`], so
+/// the same virtual time can be used for time-based decisions.
+///
+/// # Examples
+/// ```
+/// use pubnub::{core::Runtime, test::VirtualTimeRuntime};
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let runtime = VirtualTimeRuntime::manual();
+/// let sleeping = runtime.clone();
+/// let handle = tokio::spawn(async move { sleeping.sleep(10).await });
+/// tokio::task::yield_now().await;
+///
+/// runtime.advance(Duration::from_secs(10)).await;
+/// handle.await.unwrap();
+///
+/// assert_eq!(runtime.elapsed(), Duration::from_secs(10));
+/// # }
+/// ```
+///
+/// [`new`]: VirtualTimeRuntime::new
+/// [`manual`]: VirtualTimeRuntime::manual
+/// [`advance`]: VirtualTimeRuntime::advance
+/// [`Clock`]: crate::core::Clock
+#[derive(Debug, Clone)]
+pub struct VirtualTimeRuntime {
+    inner: Arc<VirtualClock>,
+}
+
+/// Shared virtual clock.
+#[derive(Debug)]
+struct VirtualClock {
+    /// Whether requested delays should move clock forward automatically.
+    auto_advance: bool,
+
+    /// Unix timestamp (in seconds) at which virtual clock has been started.
+    started_at: i64,
+
+    /// Mutable clock state.
+    state: Mutex<VirtualClockState>,
+}
+
+/// Virtual clock state.
+#[derive(Debug, Default)]
+struct VirtualClockState {
+    /// Time passed since clock start.
+    now: Duration,
+
+    /// Pending delays with time at which they should complete.
+    timers: Vec<(Duration, Sender<()>)>,
+
+    /// All delays requested by runtime users.
+    sleeps: Vec<Duration>,
+}
+
+impl VirtualTimeRuntime {
+    /// Create auto-advancing virtual time runtime.
+    ///
+    /// Each requested delay completes immediately and moves virtual clock
+    /// forward.
+    pub fn new() -> Self {
+        Self::with_mode(true)
+    }
+
+    /// Create manual virtual time runtime.
+    ///
+    /// Requested delays complete only when virtual clock is moved with
+    /// [`VirtualTimeRuntime::advance`].
+    pub fn manual() -> Self {
+        Self::with_mode(false)
+    }
+
+    /// Move virtual clock forward by `duration`.
+    ///
+    /// Delays which should complete within `duration` are completed in order
+    /// of their deadlines. Current task yields, so woken tasks can make
+    /// progress.
+    ///
+    /// Delays requested after the call aren't affected, so make sure that
+    /// tasks requested them before advancing (see
+    /// [`VirtualTimeRuntime::pending_sleeps`]).
+    pub async fn advance(&self, duration: Duration) {
+        let expired = {
+            let mut state = self.inner.state.lock();
+            state.now += duration;
+            let now = state.now;
+
+            let (mut expired, pending) = state
+                .timers
+                .drain(..)
+                .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+            state.timers = pending;
+            expired.sort_by_key(|(deadline, _)| *deadline);
+            expired
+        };
+
+        expired.into_iter().for_each(|(_, timer)| {
+            timer.try_send(()).ok();
+        });
+
+        tokio::task::yield_now().await;
+    }
+
+    /// Time passed on virtual clock since runtime creation.
+    pub fn elapsed(&self) -> Duration {
+        self.inner.state.lock().now
+    }
+
+    /// Delays requested by runtime users in order of requests.
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.inner.state.lock().sleeps.clone()
+    }
+
+    /// Number of delays which wait for virtual clock to move forward.
+    pub fn pending_sleeps(&self) -> usize {
+        self.inner.state.lock().timers.len()
+    }
+
+    /// Create virtual time runtime in specified mode.
+    fn with_mode(auto_advance: bool) -> Self {
+        Self {
+            inner: Arc::new(VirtualClock {
+                auto_advance,
+                started_at: crate::core::SystemClock.unix_timestamp(),
+                state: Default::default(),
+            }),
+        }
+    }
+
+    /// Wait until virtual clock will be moved by `delay`.
+    async fn virtual_sleep(&self, delay: Duration) {
+        let receiver = {
+            let mut state = self.inner.state.lock();
+            state.sleeps.push(delay);
+
+            if self.inner.auto_advance {
+                state.now += delay;
+                return;
+            }
+
+            let (sender, receiver) = bounded(1);
+            let deadline = state.now + delay;
+            state.timers.push((deadline, sender));
+            receiver
+        };
+
+        receiver.recv().await.ok();
+    }
+}
+
+impl Default for VirtualTimeRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runtime for VirtualTimeRuntime {
+    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static)
+    where
+        R: Send + 'static,
+    {
+        tokio::spawn(future);
+    }
+
+    async fn sleep(self, delay: u64) {
+        self.virtual_sleep(Duration::from_secs(delay)).await
+    }
+
+    async fn sleep_microseconds(self, delay: u64) {
+        self.virtual_sleep(Duration::from_micros(delay)).await
+    }
+}
+
+impl Clock for VirtualTimeRuntime {
+    fn unix_timestamp(&self) -> i64 {
+        self.inner.started_at + self.elapsed().as_secs() as i64
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[tokio::test]
+    async fn complete_delays_immediately_in_auto_advance_mode() {
+        let runtime = VirtualTimeRuntime::new();
+        let started_at = runtime.unix_timestamp();
+
+        runtime.clone().sleep(30).await;
+        runtime.clone().sleep_microseconds(500_000).await;
+
+        assert_eq!(runtime.elapsed(), Duration::from_millis(30_500));
+        assert_eq!(
+            runtime.sleeps(),
+            vec![Duration::from_secs(30), Duration::from_millis(500)]
+        );
+        assert_eq!(runtime.unix_timestamp(), started_at + 30);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn complete_delays_when_clock_advanced() {
+        let runtime = VirtualTimeRuntime::manual();
+        let sleeping = runtime.clone();
+        let handle = tokio::spawn(async move { sleeping.sleep(10).await });
+        tokio::task::yield_now().await;
+
+        runtime.advance(Duration::from_secs(5)).await;
+        assert_eq!(runtime.pending_sleeps(), 1);
+        assert!(!handle.is_finished());
+
+        runtime.advance(Duration::from_secs(5)).await;
+        handle.await.unwrap();
+        assert_eq!(runtime.pending_sleeps(), 0);
+    }
+}
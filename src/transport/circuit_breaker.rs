@@ -0,0 +1,389 @@
+//! # Circuit breaker module
+//!
+//! This module contains the [`CircuitBreakerConfiguration`] struct and types
+//! used by [`PubNubMiddleware`] to stop sending requests to the endpoints which
+//! keep failing.
+//!
+//! [`PubNubMiddleware`]: crate::transport::middleware::PubNubMiddleware
+
+use crate::lib::sync::RwLock;
+
+use crate::{
+    core::{retry_policy::Endpoint, Clock, DataStream, PubNubError, TransportResponse},
+    lib::{
+        alloc::{sync::Arc, vec, vec::Vec},
+        collections::HashMap,
+    },
+};
+
+/// Circuit breaker configuration.
+///
+/// The configuration defines how many consecutive failures of the endpoint
+/// group should open a circuit and for how long requests to it should be
+/// rejected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CircuitBreakerConfiguration {
+    /// Number of consecutive failures after which circuit will be opened.
+    pub failure_threshold: u16,
+
+    /// Period (in seconds) during which requests to the endpoint group will be
+    /// rejected after circuit has been opened.
+    pub cool_down: u64,
+}
+
+impl Default for CircuitBreakerConfiguration {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cool_down: 30,
+        }
+    }
+}
+
+/// Endpoint group circuit state.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent as usual.
+    Closed,
+
+    /// Requests are rejected without sending them.
+    Open,
+
+    /// Cool-down period is over and single request is used to probe the
+    /// endpoint group while other requests are rejected. Probe failure will
+    /// open circuit again.
+    HalfOpen,
+}
+
+/// Circuit state change status.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CircuitBreakerStatus {
+    /// Endpoint group for which circuit state has been changed.
+    pub endpoint: Endpoint,
+
+    /// Current circuit state.
+    pub state: CircuitState,
+}
+
+/// Circuit of a single endpoint group.
+#[derive(Debug)]
+struct Circuit {
+    /// Current circuit state.
+    state: CircuitState,
+
+    /// Number of consecutive failures.
+    failures: u16,
+
+    /// Unix timestamp when circuit has been opened.
+    opened_at: i64,
+
+    /// Unix timestamp when probe request has been sent.
+    ///
+    /// Set while probe request for half-open circuit is in-flight.
+    probe_started_at: Option<i64>,
+}
+
+impl Default for Circuit {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            failures: 0,
+            opened_at: 0,
+            probe_started_at: None,
+        }
+    }
+}
+
+/// Circuit breaker.
+///
+/// Tracks outcome of requests for each endpoint group and rejects requests to
+/// endpoint groups which have an open circuit.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    /// Circuit breaker configuration.
+    configuration: CircuitBreakerConfiguration,
+
+    /// Time source used to measure cool-down period.
+    clock: Arc<dyn Clock + Send + Sync>,
+
+    /// Circuits of known endpoint groups.
+    circuits: RwLock<HashMap<Endpoint, Circuit>>,
+
+    /// Circuit state change status streams.
+    status_streams: RwLock<Option<Vec<DataStream<CircuitBreakerStatus>>>>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(
+        configuration: CircuitBreakerConfiguration,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            configuration,
+            clock,
+            circuits: RwLock::new(HashMap::new()),
+            status_streams: RwLock::new(None),
+        }
+    }
+
+    /// Stream used to notify circuit state changes.
+    pub(crate) fn status_stream(&self) -> DataStream<CircuitBreakerStatus> {
+        let stream = DataStream::new();
+        let mut streams_slot = self.status_streams.write();
+
+        if let Some(streams) = streams_slot.as_mut() {
+            streams.push(stream.clone())
+        } else {
+            *streams_slot = Some(vec![stream.clone()])
+        }
+
+        stream
+    }
+
+    /// Check whether request to the `endpoint` group can be sent.
+    ///
+    /// Only one probe request is allowed for half-open circuit until its result
+    /// is recorded. Probe which hasn't been recorded during cool-down period is
+    /// considered lost and another one is allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PubNubError::CircuitBreakerOpen`] if circuit is open and
+    /// cool-down period is not over yet or probe request is in-flight.
+    pub(crate) fn acquire(&self, endpoint: Endpoint) -> Result<(), PubNubError> {
+        let mut circuits = self.circuits.write();
+        let Some(circuit) = circuits.get_mut(&endpoint) else {
+            return Ok(());
+        };
+        let now = self.clock.unix_timestamp();
+        let cool_down = self.configuration.cool_down as i64;
+
+        match circuit.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::HalfOpen => match circuit.probe_started_at {
+                Some(started_at) if now - started_at < cool_down => {
+                    Err(PubNubError::CircuitBreakerOpen {
+                        details: error_details!(
+                            "Requests to {endpoint:?} endpoints are suspended until probe request completion"
+                        ),
+                    })
+                }
+                _ => {
+                    circuit.probe_started_at = Some(now);
+                    Ok(())
+                }
+            },
+            CircuitState::Open => {
+                let elapsed = now - circuit.opened_at;
+                if elapsed < cool_down {
+                    return Err(PubNubError::CircuitBreakerOpen {
+                        details: error_details!(
+                            "Requests to {endpoint:?} endpoints are suspended for {} seconds",
+                            cool_down - elapsed
+                        ),
+                    });
+                }
+
+                circuit.state = CircuitState::HalfOpen;
+                circuit.probe_started_at = Some(now);
+                drop(circuits);
+                self.notify(endpoint, CircuitState::HalfOpen);
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Record result of the request to the `endpoint` group.
+    pub(crate) fn record(
+        &self,
+        endpoint: Endpoint,
+        result: &Result<TransportResponse, PubNubError>,
+    ) {
+        let failed = match result {
+            Ok(response) => response.status >= 500,
+            Err(PubNubError::Transport { .. }) => true,
+            Err(_) => false,
+        };
+
+        let mut circuits = self.circuits.write();
+        let circuit = circuits.entry(endpoint).or_default();
+        let state = circuit.state;
+        circuit.probe_started_at = None;
+
+        if !failed {
+            circuit.failures = 0;
+            circuit.state = CircuitState::Closed;
+        } else {
+            circuit.failures = circuit.failures.saturating_add(1);
+
+            if matches!(state, CircuitState::HalfOpen)
+                || circuit.failures >= self.configuration.failure_threshold
+            {
+                circuit.state = CircuitState::Open;
+                circuit.opened_at = self.clock.unix_timestamp();
+            }
+        }
+
+        let new_state = circuit.state;
+        drop(circuits);
+
+        if state != new_state {
+            self.notify(endpoint, new_state);
+        }
+    }
+
+    fn notify(&self, endpoint: Endpoint, state: CircuitState) {
+        let Some(streams) = self.status_streams.read().as_ref().cloned() else {
+            return;
+        };
+
+        let status = CircuitBreakerStatus { endpoint, state };
+        streams.iter().for_each(|stream| stream.push_data(status));
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::lib::alloc::boxed::Box;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    #[derive(Debug, Default)]
+    struct MockClock {
+        now: AtomicI64,
+    }
+
+    impl Clock for Arc<MockClock> {
+        fn unix_timestamp(&self) -> i64 {
+            self.now.load(Ordering::SeqCst)
+        }
+    }
+
+    fn server_error() -> Result<TransportResponse, PubNubError> {
+        Ok(TransportResponse {
+            status: 500,
+            ..Default::default()
+        })
+    }
+
+    fn breaker(clock: &Arc<MockClock>) -> CircuitBreaker {
+        CircuitBreaker::new(
+            CircuitBreakerConfiguration {
+                failure_threshold: 2,
+                cool_down: 10,
+            },
+            Arc::new(clock.clone()),
+        )
+    }
+
+    #[test]
+    fn open_circuit_after_consecutive_failures() {
+        let clock = Arc::new(MockClock::default());
+        let breaker = breaker(&clock);
+
+        breaker.record(Endpoint::MessageSend, &server_error());
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+
+        breaker.record(Endpoint::MessageSend, &server_error());
+        assert!(matches!(
+            breaker.acquire(Endpoint::MessageSend),
+            Err(PubNubError::CircuitBreakerOpen { .. })
+        ));
+        assert!(breaker.acquire(Endpoint::Presence).is_ok());
+    }
+
+    #[test]
+    fn not_open_circuit_for_client_errors() {
+        let clock = Arc::new(MockClock::default());
+        let breaker = breaker(&clock);
+        let client_error = Err(PubNubError::general_api_error(
+            "test",
+            None,
+            Some(Box::new(TransportResponse {
+                status: 400,
+                ..Default::default()
+            })),
+        ));
+
+        breaker.record(Endpoint::MessageSend, &client_error);
+        breaker.record(Endpoint::MessageSend, &client_error);
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+    }
+
+    #[tokio::test]
+    async fn close_circuit_after_successful_probe() {
+        let clock = Arc::new(MockClock::default());
+        let breaker = breaker(&clock);
+        let mut stream = breaker.status_stream();
+
+        breaker.record(Endpoint::MessageSend, &server_error());
+        breaker.record(Endpoint::MessageSend, &server_error());
+        clock.now.store(10, Ordering::SeqCst);
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+        breaker.record(Endpoint::MessageSend, &Ok(TransportResponse::default()));
+
+        let states = [
+            CircuitState::Open,
+            CircuitState::HalfOpen,
+            CircuitState::Closed,
+        ];
+        for state in states {
+            let status = stream.next().await.unwrap();
+            assert_eq!(status.endpoint, Endpoint::MessageSend);
+            assert_eq!(status.state, state);
+        }
+    }
+
+    #[test]
+    fn allow_single_probe_for_half_open_circuit() {
+        let clock = Arc::new(MockClock::default());
+        let breaker = breaker(&clock);
+
+        breaker.record(Endpoint::MessageSend, &server_error());
+        breaker.record(Endpoint::MessageSend, &server_error());
+        clock.now.store(10, Ordering::SeqCst);
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+        assert!(matches!(
+            breaker.acquire(Endpoint::MessageSend),
+            Err(PubNubError::CircuitBreakerOpen { .. })
+        ));
+
+        breaker.record(Endpoint::MessageSend, &Ok(TransportResponse::default()));
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+    }
+
+    #[test]
+    fn allow_new_probe_when_previous_lost() {
+        let clock = Arc::new(MockClock::default());
+        let breaker = breaker(&clock);
+
+        breaker.record(Endpoint::MessageSend, &server_error());
+        breaker.record(Endpoint::MessageSend, &server_error());
+        clock.now.store(10, Ordering::SeqCst);
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+        assert!(breaker.acquire(Endpoint::MessageSend).is_err());
+
+        clock.now.store(20, Ordering::SeqCst);
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+        assert!(breaker.acquire(Endpoint::MessageSend).is_err());
+    }
+
+    #[test]
+    fn reopen_circuit_after_failed_probe() {
+        let clock = Arc::new(MockClock::default());
+        let breaker = breaker(&clock);
+
+        breaker.record(Endpoint::MessageSend, &server_error());
+        breaker.record(Endpoint::MessageSend, &server_error());
+        clock.now.store(10, Ordering::SeqCst);
+        assert!(breaker.acquire(Endpoint::MessageSend).is_ok());
+        breaker.record(Endpoint::MessageSend, &server_error());
+
+        assert!(breaker.acquire(Endpoint::MessageSend).is_err());
+    }
+}
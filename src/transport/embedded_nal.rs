@@ -0,0 +1,506 @@
+//! # Embedded NAL Transport Implementation
+//!
+//! This module contains the [`TransportEmbeddedNal`] struct.
+//! It is used to send requests to the [`PubNub API`] over TCP stack which
+//! implements [`embedded-nal`] traits (for example, `smoltcp` based stacks
+//! or AT-command Wi-Fi modules) with minimal `HTTP/1.1` client. It is
+//! intended to be used by the [`pubnub`] crate on `no_std` targets.
+//!
+//! It requires the [`embedded_nal` feature] to be enabled.
+//!
+//! [`TransportEmbeddedNal`]: ./struct.TransportEmbeddedNal.html
+//! [`PubNub API`]: https://www.pubnub.com/docs
+//! [`embedded-nal`]: https://docs.rs/embedded-nal
+//! [`pubnub`]: ../index.html
+//! [`embedded_nal` feature]: ../index.html#features
+
+use crate::{
+    core::{
+        error::PubNubError, utils::encoding::url_encode, Transport, TransportRequest,
+        TransportResponse,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
+        collections::HashMap,
+        core::{
+            fmt::{Debug, Formatter, Result as FmtResult},
+            net::SocketAddr,
+        },
+        sync::Mutex,
+    },
+};
+use embedded_nal::{nb, TcpClientStack, TcpError, TcpErrorKind};
+
+/// Default `Host` header value.
+const DEFAULT_HOSTNAME: &str = "ps.pndsn.com";
+
+/// Size of buffer used to read response.
+const RECEIVE_CHUNK_SIZE: usize = 512;
+
+/// This struct is used to send requests to the [`PubNub API`] using
+/// [`embedded-nal`] TCP stack.
+///
+/// Requests are sent over plain TCP connection (one connection per request)
+/// to `remote` address, so provided stack should handle TLS if secure
+/// connection is required. Because most embedded stacks don't provide name
+/// resolution, address should be resolved by the caller (for example, with
+/// [`embedded_nal::Dns`]).
+///
+/// Request timeout isn't supported: transport polls socket until response
+/// is received or connection is closed.
+///
+/// # Examples
+/// ```
+/// use core::net::SocketAddr;
+/// use embedded_nal::TcpClientStack;
+/// use pubnub::{transport::TransportEmbeddedNal, Keyset, PubNubClientBuilder};
+///
+/// # fn setup<S: TcpClientStack + Send + Sync + 'static>(stack: S, address: SocketAddr) -> Result<(), pubnub::core::PubNubError> {
+/// let client = PubNubClientBuilder::with_blocking_transport(TransportEmbeddedNal::new(stack, address))
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("device-1")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+/// [`embedded-nal`]: https://docs.rs/embedded-nal
+pub struct TransportEmbeddedNal<S> {
+    /// TCP stack used to open connections.
+    stack: Mutex<S>,
+
+    /// Address of the [`PubNub API`] endpoint.
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    remote: SocketAddr,
+
+    /// `Host` header value.
+    hostname: String,
+}
+
+impl<S> TransportEmbeddedNal<S>
+where
+    S: TcpClientStack,
+{
+    /// Create a new [`TransportEmbeddedNal`] which sends requests with
+    /// `stack` to `remote` address.
+    pub fn new(stack: S, remote: SocketAddr) -> Self {
+        Self {
+            stack: Mutex::new(stack),
+            remote,
+            hostname: DEFAULT_HOSTNAME.into(),
+        }
+    }
+
+    /// Set the custom hostname (`Host` header value) for request.
+    pub fn set_hostname<H>(&mut self, hostname: H)
+    where
+        H: Into<String>,
+    {
+        self.hostname = hostname.into();
+    }
+
+    /// Send `request` and wait for the response.
+    fn send_request(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        let payload = serialize_request(&self.hostname, &request);
+        let mut stack = self.stack.lock();
+        let mut socket = stack
+            .socket()
+            .map_err(|err| transport_error("create socket", err))?;
+
+        let result = exchange(&mut *stack, &mut socket, self.remote, &payload);
+        stack.close(socket).ok();
+
+        parse_response(&result?)
+    }
+}
+
+impl<S> Debug for TransportEmbeddedNal<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("TransportEmbeddedNal")
+            .field("remote", &self.remote)
+            .field("hostname", &self.hostname)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Transport for TransportEmbeddedNal<S>
+where
+    S: TcpClientStack + Send,
+{
+    /// Send request.
+    ///
+    /// > Note: Request is sent synchronously and blocks the current task
+    /// > until response is received.
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        self.send_request(request)
+    }
+}
+
+impl<S> crate::core::blocking::Transport for TransportEmbeddedNal<S>
+where
+    S: TcpClientStack,
+{
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        self.send_request(request)
+    }
+}
+
+/// Connect to `remote`, send `payload` and read response until it is
+/// complete or connection is closed.
+fn exchange<S>(
+    stack: &mut S,
+    socket: &mut S::TcpSocket,
+    remote: SocketAddr,
+    payload: &[u8],
+) -> Result<Vec<u8>, PubNubError>
+where
+    S: TcpClientStack,
+{
+    nb::block!(stack.connect(socket, remote)).map_err(|err| transport_error("connect", err))?;
+
+    let mut sent = 0;
+    while sent < payload.len() {
+        sent += nb::block!(stack.send(socket, &payload[sent..]))
+            .map_err(|err| transport_error("send request", err))?;
+    }
+
+    let mut response = Vec::new();
+    let mut chunk = [0u8; RECEIVE_CHUNK_SIZE];
+    loop {
+        match stack.receive(socket, &mut chunk) {
+            Ok(0) => break,
+            Ok(received) => {
+                response.extend_from_slice(&chunk[..received]);
+                if is_complete_response(&response) {
+                    break;
+                }
+            }
+            Err(nb::Error::WouldBlock) => continue,
+            Err(nb::Error::Other(err)) if err.kind() == TcpErrorKind::PipeClosed => break,
+            Err(nb::Error::Other(err)) => return Err(transport_error("receive response", err)),
+        }
+    }
+
+    Ok(response)
+}
+
+/// Serialize `request` as `HTTP/1.1` request.
+fn serialize_request(hostname: &str, request: &TransportRequest) -> Vec<u8> {
+    let query = request
+        .query_parameters
+        .iter()
+        .map(|(key, value)| format!("{key}={}", url_encode(value.as_bytes())))
+        .collect::<Vec<_>>()
+        .join("&");
+    let separator = if query.is_empty() { "" } else { "?" };
+
+    let mut head = format!(
+        "{} {}{separator}{query} HTTP/1.1\r\nHost: {hostname}\r\nConnection: close\r\n",
+        request.method, request.path
+    );
+    request
+        .headers
+        .iter()
+        .for_each(|(name, value)| head.push_str(&format!("{name}: {value}\r\n")));
+    if let Some(body) = &request.body {
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    head.push_str("\r\n");
+
+    let mut payload = head.into_bytes();
+    if let Some(body) = &request.body {
+        payload.extend_from_slice(body);
+    }
+
+    payload
+}
+
+/// Position of the first byte after response headers.
+fn headers_end(response: &[u8]) -> Option<usize> {
+    response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .map(|position| position + 4)
+}
+
+/// Check whether whole response has been received (without waiting for
+/// connection close).
+fn is_complete_response(response: &[u8]) -> bool {
+    let Some(body_start) = headers_end(response) else {
+        return false;
+    };
+    let headers = parse_headers(&response[..body_start]);
+    let body = &response[body_start..];
+
+    if is_chunked(&headers) {
+        body.ends_with(b"0\r\n\r\n")
+    } else if let Some(length) = content_length(&headers) {
+        body.len() >= length
+    } else {
+        false
+    }
+}
+
+/// Parse received `HTTP/1.1` response.
+fn parse_response(response: &[u8]) -> Result<TransportResponse, PubNubError> {
+    let body_start = headers_end(response).ok_or_else(|| PubNubError::Transport {
+        details: error_details!("Incomplete HTTP response"),
+        response: None,
+    })?;
+    let head = String::from_utf8_lossy(&response[..body_start]);
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| PubNubError::Transport {
+            details: error_details!("Malformed HTTP response status line"),
+            response: None,
+        })?;
+    let headers = parse_headers(&response[..body_start]);
+
+    let mut body = response[body_start..].to_vec();
+    if is_chunked(&headers) {
+        body = decode_chunked(&body)?;
+    } else if let Some(length) = content_length(&headers) {
+        body.truncate(length);
+    }
+
+    Ok(TransportResponse {
+        status,
+        headers,
+        body: (!body.is_empty()).then(|| body.into()),
+    })
+}
+
+/// Parse response headers (names are lowercased).
+fn parse_headers(head: &[u8]) -> HashMap<String, String> {
+    String::from_utf8_lossy(head)
+        .split("\r\n")
+        .skip(1)
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect()
+}
+
+/// Value of `Content-Length` response header.
+fn content_length(headers: &HashMap<String, String>) -> Option<usize> {
+    headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+}
+
+/// Whether response body uses `chunked` transfer encoding.
+fn is_chunked(headers: &HashMap<String, String>) -> bool {
+    headers
+        .get("transfer-encoding")
+        .is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked"))
+}
+
+/// Decode body with `chunked` transfer encoding.
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>, PubNubError> {
+    let malformed = || PubNubError::Transport {
+        details: error_details!("Malformed chunked HTTP response body"),
+        response: None,
+    };
+    let mut decoded = Vec::new();
+
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or_else(malformed)?;
+        let size = String::from_utf8_lossy(&body[..line_end]);
+        let size = size.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| malformed())?;
+        body = &body[line_end + 2..];
+
+        if size == 0 {
+            break;
+        }
+        if body.len() < size + 2 {
+            return Err(malformed());
+        }
+
+        decoded.extend_from_slice(&body[..size]);
+        body = &body[size + 2..];
+    }
+
+    Ok(decoded)
+}
+
+/// Create transport error for failed `operation`.
+fn transport_error<E>(operation: &str, err: E) -> PubNubError
+where
+    E: Debug,
+{
+    PubNubError::Transport {
+        details: error_details!("Unable to {operation}: {err:?}"),
+        response: None,
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::core::{blocking::Transport, TransportMethod};
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl TcpError for MockError {
+        fn kind(&self) -> TcpErrorKind {
+            TcpErrorKind::Other
+        }
+    }
+
+    /// TCP stack which records sent data and replies with chunks of
+    /// prepared response (each followed by `WouldBlock`).
+    #[derive(Default)]
+    struct MockStack {
+        sent: Vec<u8>,
+        response: Vec<Vec<u8>>,
+        would_block: bool,
+        closed: bool,
+    }
+
+    impl TcpClientStack for MockStack {
+        type TcpSocket = ();
+        type Error = MockError;
+
+        fn socket(&mut self) -> Result<(), MockError> {
+            Ok(())
+        }
+
+        fn connect(&mut self, _: &mut (), _: SocketAddr) -> nb::Result<(), MockError> {
+            Ok(())
+        }
+
+        fn send(&mut self, _: &mut (), buffer: &[u8]) -> nb::Result<usize, MockError> {
+            let sent = buffer.len().min(16);
+            self.sent.extend_from_slice(&buffer[..sent]);
+            Ok(sent)
+        }
+
+        fn receive(&mut self, _: &mut (), buffer: &mut [u8]) -> nb::Result<usize, MockError> {
+            self.would_block = !self.would_block;
+            if self.would_block {
+                return Err(nb::Error::WouldBlock);
+            }
+            if self.response.is_empty() {
+                return Ok(0);
+            }
+            let chunk = self.response.remove(0);
+            buffer[..chunk.len()].copy_from_slice(&chunk);
+            Ok(chunk.len())
+        }
+
+        fn close(&mut self, _: ()) -> Result<(), MockError> {
+            self.closed = true;
+            Ok(())
+        }
+    }
+
+    fn transport(response: &[&str]) -> TransportEmbeddedNal<MockStack> {
+        let stack = MockStack {
+            response: response
+                .iter()
+                .map(|chunk| chunk.as_bytes().to_vec())
+                .collect(),
+            ..Default::default()
+        };
+
+        TransportEmbeddedNal::new(stack, "127.0.0.1:80".parse().unwrap())
+    }
+
+    #[test]
+    fn send_request_and_parse_response() {
+        let transport = transport(&[
+            "HTTP/1.1 200 OK\r\nContent-Type: text/javascript\r\nContent-",
+            "Length: 30\r\n\r\n[1,\"Sent\",\"16",
+            "000000000000000\"]",
+        ]);
+
+        let response = transport
+            .send(TransportRequest {
+                path: "/publish/pub/sub/0/ch/0/%22hi%22".into(),
+                query_parameters: [("uuid".into(), "user id".into())].into(),
+                method: TransportMethod::Get,
+                ..Default::default()
+            })
+            .unwrap();
+
+        let stack = transport.stack.lock();
+        let sent = String::from_utf8(stack.sent.clone()).unwrap();
+        assert!(sent.starts_with(
+            "GET /publish/pub/sub/0/ch/0/%22hi%22?uuid=user%20id HTTP/1.1\r\nHost: ps.pndsn.com\r\n"
+        ));
+        assert!(sent.ends_with("\r\n\r\n"));
+        assert!(stack.closed);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.headers["content-type"], "text/javascript");
+        assert_eq!(
+            response.body.unwrap(),
+            b"[1,\"Sent\",\"16000000000000000\"]".as_slice()
+        );
+    }
+
+    #[test]
+    fn send_body_with_content_length() {
+        let transport = transport(&["HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n"]);
+
+        transport
+            .send(TransportRequest {
+                path: "/publish".into(),
+                method: TransportMethod::Post,
+                body: Some(b"\"hello\"".to_vec()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let sent = String::from_utf8(transport.stack.lock().sent.clone()).unwrap();
+        assert!(sent.starts_with("POST /publish HTTP/1.1\r\n"));
+        assert!(sent.ends_with("Content-Length: 7\r\n\r\n\"hello\""));
+    }
+
+    #[test]
+    fn decode_chunked_response() {
+        let transport = transport(&[
+            "HTTP/1.1 400 Bad Request\r\nTransfer-Encoding: chunked\r\n\r\n",
+            "5\r\n{\"a\":\r\n3\r\n 1}\r\n0\r\n\r\n",
+        ]);
+
+        let response = transport.send(TransportRequest::default()).unwrap();
+
+        assert_eq!(response.status, 400);
+        assert_eq!(response.body.unwrap(), b"{\"a\": 1}".as_slice());
+    }
+
+    #[test]
+    fn read_response_until_connection_closed() {
+        let transport = transport(&["HTTP/1.1 200 OK\r\n\r\n", "[1]"]);
+
+        let response = transport.send(TransportRequest::default()).unwrap();
+
+        assert_eq!(response.body.unwrap(), b"[1]".as_slice());
+    }
+
+    #[test]
+    fn return_error_for_malformed_response() {
+        let transport = transport(&["garbage"]);
+
+        assert!(matches!(
+            transport.send(TransportRequest::default()),
+            Err(PubNubError::Transport { .. })
+        ));
+    }
+}
@@ -0,0 +1,371 @@
+//! # Browser EventSource Transport Implementation
+//!
+//! This module contains the [`TransportEventSource`] struct.
+//! It is used in browsers (`wasm32` targets) to receive real-time updates
+//! through persistent [`EventSource`] (Server-Sent Events) connection instead
+//! of long-poll `fetch` requests, which are limited by the browser connection
+//! pool and throttled in background tabs. Other requests are sent with the
+//! wrapped transport.
+//!
+//! It requires the [`event_source` feature] to be enabled.
+//!
+//! [`TransportEventSource`]: ./struct.TransportEventSource.html
+//! [`EventSource`]: https://developer.mozilla.org/en-US/docs/Web/API/EventSource
+//! [`event_source` feature]: ../index.html#features
+
+use crate::{
+    core::{
+        error::PubNubError,
+        utils::{
+            encoding::url_encode,
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Transport, TransportRequest, TransportResponse,
+    },
+    lib::{
+        alloc::{format, string::String, vec::Vec},
+        core::fmt::{Debug, Formatter, Result as FmtResult},
+        sync::Mutex,
+    },
+};
+use async_channel::Receiver;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, EventSource, MessageEvent};
+
+/// Subscribe endpoint path prefix.
+const SUBSCRIBE_PATH: &str = "/v2/subscribe/";
+
+/// Subscribe query parameters which change with each request and shouldn't
+/// cause stream re-open.
+const CURSOR_QUERY_PARAMETERS: [&str; 2] = ["tt", "tr"];
+
+/// This struct is used to receive real-time updates from the [`PubNub API`]
+/// through browser [`EventSource`].
+///
+/// Subscribe requests are served by the `gateway` which should accept the
+/// same path and query parameters as the subscribe endpoint and stream
+/// subscribe response bodies as separate `message` events. Stream is opened
+/// on first subscribe request and re-opened when the list of channels,
+/// channel groups or other subscribe parameters change; following requests
+/// (with next time cursor) are served from already received events.
+///
+/// All other requests are sent with the wrapped `transport` (for example,
+/// [`TransportReqwest`] which uses `fetch`).
+///
+/// # Examples
+/// ```no_run
+/// use pubnub::{
+///     providers::futures_wasm::RuntimeWasm,
+///     transport::{TransportEventSource, TransportReqwest},
+///     Keyset, PubNubClientBuilder,
+/// };
+///
+/// # fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let transport = TransportEventSource::new(
+///     TransportReqwest::default(),
+///     "https://sse-gateway.example.com",
+/// );
+/// let client = PubNubClientBuilder::with_transport(transport)
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("browser-tab")
+///     .with_runtime(RuntimeWasm)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+/// [`EventSource`]: https://developer.mozilla.org/en-US/docs/Web/API/EventSource
+/// [`TransportReqwest`]: crate::transport::TransportReqwest
+pub struct TransportEventSource<T> {
+    /// Transport used for non-subscribe requests.
+    transport: T,
+
+    /// Base URL of the subscribe events gateway.
+    gateway: String,
+
+    /// Currently open subscribe events stream.
+    stream: Mutex<Option<SendWrapper<SubscribeStream>>>,
+}
+
+impl<T> TransportEventSource<T> {
+    /// Create a new [`TransportEventSource`] which receives real-time updates
+    /// from `gateway` and sends other requests with `transport`.
+    pub fn new<S>(transport: T, gateway: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            transport,
+            gateway: gateway.into(),
+            stream: Mutex::new(None),
+        }
+    }
+
+    /// Receive next subscribe response from the events stream.
+    ///
+    /// Stream is (re-)opened if there is no stream for the `request`
+    /// subscribe parameters. If request is cancelled (future dropped before
+    /// response received), stream is closed, so the browser tears down the
+    /// connection instead of keeping it open for unsubscribed channels.
+    async fn receive(&self, request: &TransportRequest) -> Result<TransportResponse, PubNubError> {
+        let key = stream_key(request);
+        let receiver = {
+            let mut stream = self.stream.lock();
+            match stream.as_ref() {
+                Some(current) if current.key == key => current.receiver.clone(),
+                _ => {
+                    // Previous stream (if any) will be closed on drop.
+                    let opened = SubscribeStream::open(&self.gateway, request, key)?;
+                    let receiver = opened.receiver.clone();
+                    *stream = Some(SendWrapper::new(opened));
+                    receiver
+                }
+            }
+        };
+
+        let guard = CloseOnCancel::new(&self.stream);
+        let received = receiver.recv().await;
+        guard.disarm();
+
+        match received {
+            Ok(Ok(body)) => Ok(TransportResponse {
+                status: 200,
+                headers: [(CONTENT_TYPE.into(), APPLICATION_JSON.into())].into(),
+                body: Some(body.into_bytes().into()),
+            }),
+            Ok(Err(details)) => {
+                self.stream.lock().take();
+                Err(PubNubError::Transport {
+                    details: error_details!("Subscribe events stream failed: {details}"),
+                    response: None,
+                })
+            }
+            Err(_) => Err(PubNubError::Transport {
+                details: error_details!("Subscribe events stream has been closed"),
+                response: None,
+            }),
+        }
+    }
+}
+
+impl<T> Debug for TransportEventSource<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("TransportEventSource")
+            .field("gateway", &self.gateway)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Transport for TransportEventSource<T>
+where
+    T: Transport,
+{
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        if request.path.starts_with(SUBSCRIBE_PATH) {
+            self.receive(&request).await
+        } else {
+            self.transport.send(request).await
+        }
+    }
+}
+
+/// Events stream close guard.
+///
+/// Closes stream when dropped before [`CloseOnCancel::disarm`] call (pending
+/// subscribe request has been cancelled). Dropped [`SubscribeStream`] closes
+/// browser [`EventSource`] connection.
+struct CloseOnCancel<'a, S> {
+    /// Guarded events stream.
+    stream: &'a Mutex<Option<S>>,
+
+    /// Whether stream should be closed on drop or not.
+    armed: bool,
+}
+
+impl<'a, S> CloseOnCancel<'a, S> {
+    fn new(stream: &'a Mutex<Option<S>>) -> Self {
+        Self {
+            stream,
+            armed: true,
+        }
+    }
+
+    /// Keep stream open after guard drop.
+    fn disarm(mut self) {
+        self.armed = false;
+    }
+}
+
+impl<S> Drop for CloseOnCancel<'_, S> {
+    fn drop(&mut self) {
+        if self.armed {
+            self.stream.lock().take();
+        }
+    }
+}
+
+/// Open subscribe events stream.
+struct SubscribeStream {
+    /// Subscribe parameters for which stream has been opened.
+    key: String,
+
+    /// Browser events source.
+    source: EventSource,
+
+    /// Received subscribe response bodies (or stream error).
+    receiver: Receiver<Result<String, String>>,
+
+    /// Listeners should be alive while stream is open.
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+impl SubscribeStream {
+    /// Open events stream on `gateway` for subscribe `request`.
+    fn open(gateway: &str, request: &TransportRequest, key: String) -> Result<Self, PubNubError> {
+        let source = EventSource::new(&prepare_url(gateway, request)).map_err(|err| {
+            PubNubError::Transport {
+                details: error_details!("Unable to open subscribe events stream: {err:?}"),
+                response: None,
+            }
+        })?;
+        let (sender, receiver) = async_channel::unbounded();
+
+        let message_sender = sender.clone();
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            if let Some(body) = event.data().as_string() {
+                message_sender.try_send(Ok(body)).ok();
+            }
+        });
+
+        let error_source = source.clone();
+        let on_error = Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+            // Browser will try to reconnect unless stream has been closed.
+            if error_source.ready_state() == EventSource::CLOSED {
+                sender.try_send(Err("connection closed".into())).ok();
+            }
+        });
+
+        source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            key,
+            source,
+            receiver,
+            _on_message: on_message,
+            _on_error: on_error,
+        })
+    }
+}
+
+impl Drop for SubscribeStream {
+    fn drop(&mut self) {
+        self.source.set_onmessage(None);
+        self.source.set_onerror(None);
+        self.source.close();
+    }
+}
+
+/// Subscribe parameters which identify events stream.
+///
+/// Time cursor isn't part of the key, because it changes with each received
+/// response.
+fn stream_key(request: &TransportRequest) -> String {
+    let mut query = request
+        .query_parameters
+        .iter()
+        .filter(|(key, _)| !CURSOR_QUERY_PARAMETERS.contains(&key.as_str()))
+        .collect::<Vec<_>>();
+    query.sort();
+
+    format!("{}?{query:?}", request.path)
+}
+
+/// Compose events stream URL from `gateway` base URL and subscribe `request`.
+fn prepare_url(gateway: &str, request: &TransportRequest) -> String {
+    let mut query = request
+        .query_parameters
+        .iter()
+        .map(|(key, value)| format!("{key}={}", url_encode(value.as_bytes())))
+        .collect::<Vec<_>>();
+    query.sort();
+
+    if query.is_empty() {
+        format!("{}{}", gateway.trim_end_matches('/'), request.path)
+    } else {
+        format!(
+            "{}{}?{}",
+            gateway.trim_end_matches('/'),
+            request.path,
+            query.join("&")
+        )
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::lib::{alloc::string::ToString, collections::HashMap};
+
+    fn subscribe_request(query: &[(&str, &str)]) -> TransportRequest {
+        TransportRequest {
+            path: "/v2/subscribe/demo/ch1,ch2/0".into(),
+            query_parameters: query
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn keep_stream_key_for_new_cursor() {
+        let first = subscribe_request(&[("uuid", "user"), ("tt", "0")]);
+        let next = subscribe_request(&[("uuid", "user"), ("tt", "17"), ("tr", "2")]);
+
+        assert_eq!(stream_key(&first), stream_key(&next));
+    }
+
+    #[test]
+    fn change_stream_key_for_new_parameters() {
+        let first = subscribe_request(&[("uuid", "user"), ("tt", "0")]);
+        let next = subscribe_request(&[("uuid", "user"), ("channel-group", "gr1")]);
+
+        assert_ne!(stream_key(&first), stream_key(&next));
+    }
+
+    #[test]
+    fn close_stream_when_request_cancelled() {
+        let stream = Mutex::new(Some("stream"));
+
+        drop(CloseOnCancel::new(&stream));
+
+        assert!(stream.lock().is_none());
+    }
+
+    #[test]
+    fn keep_stream_when_response_received() {
+        let stream = Mutex::new(Some("stream"));
+
+        CloseOnCancel::new(&stream).disarm();
+
+        assert!(stream.lock().is_some());
+    }
+
+    #[test]
+    fn prepare_stream_url() {
+        let request = subscribe_request(&[("uuid", "user id"), ("tt", "0")]);
+
+        assert_eq!(
+            prepare_url("https://gateway.example.com/", &request),
+            "https://gateway.example.com/v2/subscribe/demo/ch1,ch2/0?tt=0&uuid=user%20id"
+        );
+    }
+}
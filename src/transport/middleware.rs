@@ -5,35 +5,79 @@
 //! The middleware is used to add the `pnsdk`, `uuid`, `instanceid` and
 //! `requestid` query parameters to the requests.
 
+#[cfg(feature = "metrics")]
+use crate::core::metrics::MetricsCollector;
 #[cfg(feature = "std")]
-use crate::{
-    core::{utils::encoding::url_encode, TransportMethod},
-    lib::{alloc::vec::Vec, collections::HashMap},
-};
+use crate::{core::retry_policy::Endpoint, transport::circuit_breaker::CircuitBreaker};
 use crate::{
     core::{
-        utils::metadata::{PKG_VERSION, RUSTC_VERSION, SDK_ID, TARGET},
-        PubNubError, Transport, TransportRequest, TransportResponse,
+        utils::{
+            encoding::url_encode,
+            headers::REQUEST_ID,
+            metadata::{PKG_VERSION, RUSTC_VERSION, SDK_ID, TARGET},
+        },
+        Clock, IdProvider, PubNubError, Transport, TransportMethod, TransportRequest,
+        TransportResponse,
     },
     lib::{
         alloc::{
-            boxed::Box,
             format,
             string::{String, ToString},
             sync::Arc,
+            vec::Vec,
+        },
+        collections::HashMap,
+        core::{
+            fmt::{Debug, Formatter, Result as FmtResult},
+            ops::Deref,
         },
-        core::ops::Deref,
+        log,
+        sync::RwLock,
     },
 };
-#[cfg(feature = "std")]
 use base64::{engine::general_purpose, Engine as _};
-#[cfg(feature = "std")]
 use hmac::{Hmac, Mac};
-#[cfg(feature = "std")]
 use sha2::Sha256;
+
+/// Request hook.
+///
+/// Hook is called with prepared (signed) request right before it is passed to
+/// the transport layer.
+pub(crate) type RequestHook = dyn Fn(&TransportRequest) + Send + Sync;
+
+/// Response hook.
+///
+/// Hook is called with sent request, received response and request duration.
 #[cfg(feature = "std")]
-use time::OffsetDateTime;
-use uuid::Uuid;
+pub(crate) type ResponseHook =
+    dyn Fn(&TransportRequest, &TransportResponse, core::time::Duration) + Send + Sync;
+
+/// Request lifecycle hooks.
+#[derive(Clone, Default)]
+pub(crate) struct RequestHooks {
+    /// Hooks which should be called before request will be sent.
+    pub(crate) request: Vec<Arc<RequestHook>>,
+
+    /// Hooks which should be called after response has been received.
+    #[cfg(feature = "std")]
+    pub(crate) response: Vec<Arc<ResponseHook>>,
+}
+
+impl Debug for RequestHooks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        #[cfg(feature = "std")]
+        let response_hooks = self.response.len();
+        #[cfg(not(feature = "std"))]
+        let response_hooks = 0;
+
+        write!(
+            f,
+            "RequestHooks {{ request: {}, response: {} }}",
+            self.request.len(),
+            response_hooks
+        )
+    }
+}
 
 /// PubNub middleware.
 ///
@@ -54,22 +98,42 @@ use uuid::Uuid;
 pub struct PubNubMiddleware<T> {
     pub(crate) transport: T,
     pub(crate) instance_id: Arc<Option<String>>,
-    pub(crate) user_id: Arc<String>,
-    pub(crate) auth_key: Option<Arc<String>>,
-    pub(crate) auth_token: Arc<spin::RwLock<String>>,
-    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+
+    /// Suffix which should be appended to the SDK identifier.
+    pub(crate) user_agent_suffix: Option<String>,
+
+    /// Custom query parameters which should be added to each request.
+    pub(crate) query_params: Arc<HashMap<String, String>>,
+    pub(crate) user_id: Arc<RwLock<String>>,
+    pub(crate) auth_key: Arc<RwLock<String>>,
+    pub(crate) auth_token: Arc<RwLock<String>>,
     pub(crate) signature_keys: Option<SignatureKeySet>,
+
+    /// Identifiers provider used to generate `requestid`.
+    pub(crate) id_provider: Arc<dyn IdProvider>,
+
+    /// Time source used to get `timestamp` for signed requests.
+    pub(crate) clock: Option<Arc<dyn Clock + Send + Sync>>,
+
+    /// Circuit breaker used to suspend requests to the failing endpoints.
+    #[cfg(feature = "std")]
+    pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
+
+    /// Metrics collector used to track requests count and latency.
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: Option<Arc<MetricsCollector>>,
+
+    /// Request lifecycle hooks.
+    pub(crate) hooks: RequestHooks,
 }
 
-#[derive(Debug)]
-#[cfg_attr(not(feature = "std"), allow(dead_code))]
+#[derive(Debug, Clone)]
 pub(crate) struct SignatureKeySet {
     pub(crate) secret_key: String,
     pub(crate) publish_key: String,
     pub(crate) subscribe_key: String,
 }
 
-#[cfg(feature = "std")]
 impl SignatureKeySet {
     fn handle_query_params(query_parameters: &HashMap<String, String>) -> String {
         let mut query_params_str = query_parameters
@@ -124,13 +188,12 @@ impl SignatureKeySet {
 impl<T> PubNubMiddleware<T> {
     fn prepare_request(&self, mut req: TransportRequest) -> Result<TransportRequest, PubNubError> {
         req.query_parameters
-            .insert("requestid".into(), Uuid::new_v4().to_string());
+            .insert("requestid".into(), self.id_provider.next_id());
 
-        req.query_parameters
-            .insert("pnsdk".into(), format!("{}/{}", SDK_ID, PKG_VERSION));
+        req.query_parameters.insert("pnsdk".into(), self.sdk_id());
         req.query_parameters
             .entry("uuid".into())
-            .or_insert(self.user_id.as_ref().into());
+            .or_insert(self.user_id.read().deref().clone());
 
         if let Some(instance_id) = self.instance_id.as_deref() {
             req.query_parameters
@@ -141,41 +204,172 @@ impl<T> PubNubMiddleware<T> {
         if !self.auth_token.read().is_empty() {
             req.query_parameters
                 .insert("auth".into(), self.auth_token.read().deref().into());
-        } else if let Some(auth_key) = self.auth_key.as_deref() {
-            req.query_parameters.insert("auth".into(), auth_key.into());
+        } else if !self.auth_key.read().is_empty() {
+            req.query_parameters
+                .insert("auth".into(), self.auth_key.read().deref().into());
         }
 
-        #[cfg(feature = "std")]
+        // Custom query parameters should be added before request signature
+        // calculation.
+        self.query_params.iter().for_each(|(key, value)| {
+            req.query_parameters
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        });
+
         if let Some(signature_key_set) = &self.signature_keys {
-            req.query_parameters.insert(
-                "timestamp".into(),
-                OffsetDateTime::now_utc().unix_timestamp().to_string(),
-            );
-            req.query_parameters.insert(
-                "signature".into(),
-                signature_key_set.calculate_signature(&req),
-            );
+            if let Some(clock) = &self.clock {
+                req.query_parameters
+                    .insert("timestamp".into(), clock.unix_timestamp().to_string());
+                req.query_parameters.insert(
+                    "signature".into(),
+                    signature_key_set.calculate_signature(&req),
+                );
+            } else {
+                log::warn!("Request signature skipped: time source (clock) not configured.");
+            }
         }
 
         req.headers.insert(
             "User-Agent".into(),
-            format!("{}/{} {}/{}", RUSTC_VERSION, TARGET, SDK_ID, PKG_VERSION),
+            format!("{}/{} {}", RUSTC_VERSION, TARGET, self.sdk_id()),
         );
 
         Ok(req)
     }
+
+    /// SDK identifier with optional suffix.
+    fn sdk_id(&self) -> String {
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("{}/{} {}", SDK_ID, PKG_VERSION, suffix),
+            None => format!("{}/{}", SDK_ID, PKG_VERSION),
+        }
+    }
+
+    /// Report completed request to the metrics collector.
+    #[cfg(feature = "metrics")]
+    fn record_metrics(
+        &self,
+        endpoint: Endpoint,
+        started_at: std::time::Instant,
+        result: &Result<TransportResponse, PubNubError>,
+    ) {
+        let Some(metrics) = &self.metrics else {
+            return;
+        };
+
+        let status = match result {
+            Ok(response) => Some(response.status),
+            Err(PubNubError::API { response, .. } | PubNubError::Transport { response, .. }) => {
+                response.as_ref().map(|response| response.status)
+            }
+            Err(_) => None,
+        };
+        let latency = started_at.elapsed().as_micros() as u64;
+
+        metrics.record_request(endpoint, status, latency);
+    }
+
+    /// Notify response hooks about received response.
+    #[cfg(feature = "std")]
+    fn notify_response_hooks(
+        &self,
+        request: Option<TransportRequest>,
+        started_at: std::time::Instant,
+        result: &Result<TransportResponse, PubNubError>,
+    ) {
+        let (Some(request), Ok(response)) = (request, result) else {
+            return;
+        };
+
+        let duration = started_at.elapsed();
+        self.hooks
+            .response
+            .iter()
+            .for_each(|hook| hook(&request, response, duration));
+    }
+
+    /// Pass `requestid` of the request along with its `result`.
+    ///
+    /// Identifier stored in response headers, so it can be used as part of
+    /// the request result metadata.
+    fn attach_request_id(
+        result: Result<TransportResponse, PubNubError>,
+        request_id: Option<String>,
+    ) -> Result<TransportResponse, PubNubError> {
+        result.map(|mut response| {
+            if let Some(request_id) = request_id {
+                response.headers.insert(REQUEST_ID.into(), request_id);
+            }
+            response
+        })
+    }
+
+    /// Pass prepared `request` to the request hooks.
+    ///
+    /// Returns copy of `request` if it is required by response hooks.
+    fn notify_request_hooks(&self, request: &TransportRequest) -> Option<TransportRequest> {
+        self.hooks.request.iter().for_each(|hook| hook(request));
+
+        #[cfg(feature = "std")]
+        return (!self.hooks.response.is_empty()).then(|| request.clone());
+        #[cfg(not(feature = "std"))]
+        None
+    }
+}
+
+impl<T> PubNubMiddleware<T>
+where
+    T: Transport,
+{
+    async fn send_request(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        #[cfg(feature = "std")]
+        let endpoint = Endpoint::from(req.path.clone());
+        let req = self.prepare_request(req)?;
+
+        // Acquire only for prepared request, so half-open circuit probe will be
+        // recorded.
+        #[cfg(feature = "std")]
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.acquire(endpoint)?;
+        }
+        #[cfg(feature = "std")]
+        let started_at = std::time::Instant::now();
+
+        #[allow(unused_variables)]
+        let hooked_request = self.notify_request_hooks(&req);
+        let request_id = req.query_parameters.get("requestid").cloned();
+        let result = Self::attach_request_id(self.transport.send(req).await, request_id);
+
+        #[cfg(feature = "std")]
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.record(endpoint, &result);
+        }
+        #[cfg(feature = "metrics")]
+        self.record_metrics(endpoint, started_at, &result);
+        #[cfg(feature = "std")]
+        self.notify_response_hooks(hooked_request, started_at, &result);
+
+        result
+    }
 }
 
-#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
-#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 impl<T> Transport for PubNubMiddleware<T>
 where
     T: Transport,
 {
     async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
-        self.prepare_request(req)
-            .map(|req| self.transport.send(req))?
-            .await
+        #[cfg(feature = "tracing")]
+        {
+            let span = request_span(&req);
+            let result =
+                tracing::Instrument::instrument(self.send_request(req), span.clone()).await;
+            record_status(&span, &result);
+            result
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.send_request(req).await
     }
 }
 
@@ -185,25 +379,108 @@ where
     T: crate::core::blocking::Transport,
 {
     fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
-        self.prepare_request(req)
-            .and_then(|req| self.transport.send(req))
+        #[cfg(feature = "tracing")]
+        let span = request_span(&req);
+        #[cfg(feature = "tracing")]
+        let _guard = span.enter();
+        #[cfg(feature = "std")]
+        let endpoint = Endpoint::from(req.path.clone());
+        let req = self.prepare_request(req)?;
+
+        // Acquire only for prepared request, so half-open circuit probe will be
+        // recorded.
+        #[cfg(feature = "std")]
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.acquire(endpoint)?;
+        }
+        #[cfg(feature = "std")]
+        let started_at = std::time::Instant::now();
+
+        #[allow(unused_variables)]
+        let hooked_request = self.notify_request_hooks(&req);
+        let request_id = req.query_parameters.get("requestid").cloned();
+        let result = Self::attach_request_id(self.transport.send(req), request_id);
+
+        #[cfg(feature = "std")]
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.record(endpoint, &result);
+        }
+        #[cfg(feature = "metrics")]
+        self.record_metrics(endpoint, started_at, &result);
+        #[cfg(feature = "std")]
+        self.notify_response_hooks(hooked_request, started_at, &result);
+        #[cfg(feature = "tracing")]
+        record_status(&span, &result);
+
+        result
     }
 }
 
+/// Create span for request to the [`PubNub API`].
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[cfg(feature = "tracing")]
+fn request_span(req: &TransportRequest) -> tracing::Span {
+    tracing::info_span!(
+        "pubnub.request",
+        endpoint = ?crate::core::retry_policy::Endpoint::from(req.path.clone()),
+        method = %req.method,
+        channels = channels_count(&req.path),
+        status = tracing::field::Empty,
+    )
+}
+
+/// Record request completion status in request `span`.
+#[cfg(feature = "tracing")]
+fn record_status(span: &tracing::Span, result: &Result<TransportResponse, PubNubError>) {
+    let status = match result {
+        Ok(response) => Some(response.status),
+        Err(PubNubError::API { response, .. } | PubNubError::Transport { response, .. }) => {
+            response.as_ref().map(|response| response.status)
+        }
+        Err(_) => None,
+    };
+
+    if let Some(status) = status {
+        span.record("status", status);
+    }
+}
+
+/// Number of channels which is addressed by request `path`.
+#[cfg(feature = "tracing")]
+fn channels_count(path: &str) -> usize {
+    let segments = path.split('/').collect::<Vec<&str>>();
+    let channels = match segments.get(1..3) {
+        Some(["v2", "subscribe"]) => segments.get(4),
+        Some(["publish", _]) | Some(["signal", _]) => segments.get(5),
+        _ => segments
+            .iter()
+            .position(|segment| segment.eq(&"channel"))
+            .and_then(|idx| segments.get(idx + 1)),
+    };
+
+    channels
+        .map(|channels| {
+            channels
+                .split(',')
+                .filter(|channel| !channel.is_empty())
+                .count()
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod should {
     use super::*;
-    use crate::core::TransportResponse;
+    use crate::core::{TransportResponse, UuidProvider};
     #[cfg(feature = "std")]
     use crate::{core::TransportMethod::Get, lib::collections::HashMap};
-    use spin::rwlock::RwLock;
 
     #[tokio::test]
     async fn include_pubnub_metadata() {
         #[derive(Default)]
         struct MockTransport;
 
-        #[async_trait::async_trait]
         impl Transport for MockTransport {
             async fn send(
                 &self,
@@ -235,10 +512,69 @@ mod should {
         let middleware = PubNubMiddleware {
             transport: MockTransport,
             instance_id: Arc::new(Some(String::from("instance_id"))),
-            user_id: String::from("user_id").into(),
+            user_agent_suffix: None,
+            query_params: Default::default(),
+            user_id: Arc::new(RwLock::new(String::from("user_id"))),
             signature_keys: None,
             auth_token: Arc::new(RwLock::new(String::new())),
-            auth_key: None,
+            auth_key: Default::default(),
+            clock: None,
+            #[cfg(feature = "std")]
+            circuit_breaker: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            hooks: Default::default(),
+            id_provider: Arc::new(UuidProvider),
+        };
+
+        let result = middleware.send(TransportRequest::default()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn include_user_agent_suffix_without_instance_id() {
+        #[derive(Default)]
+        struct MockTransport;
+
+        impl Transport for MockTransport {
+            async fn send(
+                &self,
+                request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                assert!(!request.query_parameters.contains_key("instanceid"));
+                assert_eq!(
+                    format!("{}/{} my-app/1.0", SDK_ID, PKG_VERSION),
+                    request.query_parameters.get("pnsdk").unwrap().clone()
+                );
+                assert_eq!(
+                    format!(
+                        "{}/{} {}/{} my-app/1.0",
+                        RUSTC_VERSION, TARGET, SDK_ID, PKG_VERSION
+                    ),
+                    request.headers.get("User-Agent").unwrap().clone()
+                );
+
+                Ok(TransportResponse::default())
+            }
+        }
+
+        let middleware = PubNubMiddleware {
+            transport: MockTransport,
+            instance_id: Arc::new(None),
+            user_agent_suffix: Some("my-app/1.0".into()),
+            query_params: Default::default(),
+            user_id: Arc::new(RwLock::new(String::from("user_id"))),
+            signature_keys: None,
+            auth_token: Default::default(),
+            auth_key: Default::default(),
+            clock: None,
+            #[cfg(feature = "std")]
+            circuit_breaker: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            hooks: Default::default(),
+            id_provider: Arc::new(UuidProvider),
         };
 
         let result = middleware.send(TransportRequest::default()).await;
@@ -272,6 +608,136 @@ mod should {
         assert_eq!("v2.AHl5lMpzyT4qcvvlqaszCjTUqU6dPb10a4_XSaYCNIQ", signature);
     }
 
+    #[tokio::test]
+    async fn use_clock_for_signed_request_timestamp() {
+        #[derive(Debug)]
+        struct MockClock;
+
+        impl Clock for MockClock {
+            fn unix_timestamp(&self) -> i64 {
+                1679642098
+            }
+        }
+
+        #[derive(Default)]
+        struct MockTransport;
+
+        impl Transport for MockTransport {
+            async fn send(
+                &self,
+                request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                assert_eq!(
+                    "1679642098",
+                    request.query_parameters.get("timestamp").unwrap().clone()
+                );
+                assert!(request.query_parameters.contains_key("signature"));
+
+                Ok(TransportResponse::default())
+            }
+        }
+
+        let middleware = PubNubMiddleware {
+            transport: MockTransport,
+            instance_id: Arc::new(None),
+            user_agent_suffix: None,
+            query_params: Default::default(),
+            user_id: Arc::new(RwLock::new(String::from("user_id"))),
+            signature_keys: Some(SignatureKeySet {
+                secret_key: "secKey".into(),
+                publish_key: "pubKey".into(),
+                subscribe_key: "subKey".into(),
+            }),
+            auth_token: Arc::new(RwLock::new(String::new())),
+            auth_key: Default::default(),
+            clock: Some(Arc::new(MockClock)),
+            #[cfg(feature = "std")]
+            circuit_breaker: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            hooks: Default::default(),
+            id_provider: Arc::new(UuidProvider),
+        };
+
+        let result = middleware.send(TransportRequest::default()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn sign_request_with_custom_query_params() {
+        #[derive(Debug)]
+        struct MockClock;
+
+        impl Clock for MockClock {
+            fn unix_timestamp(&self) -> i64 {
+                1679642098
+            }
+        }
+
+        #[derive(Default)]
+        struct MockTransport;
+
+        impl Transport for MockTransport {
+            async fn send(
+                &self,
+                request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                assert_eq!(
+                    "tenant-1",
+                    request.query_parameters.get("tenant").unwrap().clone()
+                );
+                assert_eq!(
+                    "user_id",
+                    request.query_parameters.get("uuid").unwrap().clone()
+                );
+
+                let mut unsigned_request = request.clone();
+                let signature = unsigned_request.query_parameters.remove("signature");
+                let signature_key_set = SignatureKeySet {
+                    secret_key: "secKey".into(),
+                    publish_key: "pubKey".into(),
+                    subscribe_key: "subKey".into(),
+                };
+                assert_eq!(
+                    signature,
+                    Some(signature_key_set.calculate_signature(&unsigned_request))
+                );
+
+                Ok(TransportResponse::default())
+            }
+        }
+
+        let middleware = PubNubMiddleware {
+            transport: MockTransport,
+            instance_id: Arc::new(None),
+            user_agent_suffix: None,
+            query_params: Arc::new(HashMap::from([
+                ("tenant".to_string(), "tenant-1".to_string()),
+                ("uuid".to_string(), "tenant-user".to_string()),
+            ])),
+            user_id: Arc::new(RwLock::new(String::from("user_id"))),
+            signature_keys: Some(SignatureKeySet {
+                secret_key: "secKey".into(),
+                publish_key: "pubKey".into(),
+                subscribe_key: "subKey".into(),
+            }),
+            auth_token: Arc::new(RwLock::new(String::new())),
+            auth_key: Default::default(),
+            clock: Some(Arc::new(MockClock)),
+            #[cfg(feature = "std")]
+            circuit_breaker: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            hooks: Default::default(),
+            id_provider: Arc::new(UuidProvider),
+        };
+
+        let result = middleware.send(TransportRequest::default()).await;
+
+        assert!(result.is_ok());
+    }
+
     #[cfg(feature = "blocking")]
     #[test]
     fn blocking_transport() {
@@ -302,14 +768,39 @@ mod should {
         let middleware = PubNubMiddleware {
             transport: MockTransport,
             instance_id: Some(String::from("instance_id")).into(),
-            user_id: "user_id".to_string().into(),
+            user_agent_suffix: None,
+            query_params: Default::default(),
+            user_id: Arc::new(RwLock::new("user_id".to_string())),
             signature_keys: None,
             auth_token: Arc::new(RwLock::new(String::new())),
-            auth_key: None,
+            auth_key: Default::default(),
+            clock: None,
+            #[cfg(feature = "std")]
+            circuit_breaker: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            hooks: Default::default(),
+            id_provider: Arc::new(UuidProvider),
         };
 
         let result = middleware.send(TransportRequest::default());
 
         assert!(result.is_ok());
     }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn count_channels_in_request_path() {
+        assert_eq!(channels_count("/v2/subscribe/sub-key/ch1,ch2/0"), 2);
+        assert_eq!(channels_count("/v2/subscribe/sub-key/,/0"), 0);
+        assert_eq!(
+            channels_count("/publish/pub-key/sub-key/0/ch1/0/%22hi%22"),
+            1
+        );
+        assert_eq!(
+            channels_count("/v2/presence/sub_key/sub-key/channel/ch1,ch2,ch3/heartbeat"),
+            3
+        );
+        assert_eq!(channels_count("/time/0"), 0);
+    }
 }
@@ -8,8 +8,26 @@
 
 pub mod middleware;
 
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use circuit_breaker::{CircuitBreakerConfiguration, CircuitBreakerStatus, CircuitState};
+#[cfg(feature = "std")]
+pub mod circuit_breaker;
+
 #[cfg(feature = "reqwest")]
 #[doc(inline)]
-pub use self::reqwest::TransportReqwest;
+pub use self::reqwest::{IpAddressFamily, TransportReqwest};
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
+
+#[cfg(feature = "embedded_nal")]
+#[doc(inline)]
+pub use self::embedded_nal::TransportEmbeddedNal;
+#[cfg(feature = "embedded_nal")]
+pub mod embedded_nal;
+
+#[cfg(all(feature = "event_source", feature = "std", feature = "subscribe"))]
+#[doc(inline)]
+pub use self::event_source::TransportEventSource;
+#[cfg(all(feature = "event_source", feature = "std", feature = "subscribe"))]
+pub mod event_source;
@@ -20,7 +20,12 @@ use crate::dx::pubnub_client::PubNubClientDeserializerBuilder;
 
 #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
 use crate::dx::pubnub_client::PubNubClientRuntimeBuilder;
+#[cfg(feature = "std")]
+use crate::dx::pubnub_client::PubNubConfig;
 
+use crate::lib::log::info;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::lib::{alloc::vec::Vec, core::net::SocketAddr};
 use crate::{
     core::{
         error::PubNubError, transport::PUBNUB_DEFAULT_BASE_URL, utils::encoding::url_encode,
@@ -31,13 +36,17 @@ use crate::{
             boxed::Box,
             format,
             string::{String, ToString},
+            sync::Arc,
         },
         collections::HashMap,
+        core::{
+            fmt::{Debug, Formatter, Result as FmtResult},
+            net::{IpAddr, Ipv4Addr, Ipv6Addr},
+        },
     },
     PubNubClientBuilder,
 };
 use bytes::Bytes;
-use log::info;
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue, InvalidHeaderName, InvalidHeaderValue},
     StatusCode,
@@ -70,10 +79,146 @@ pub struct TransportReqwest {
     /// };
     /// ```
     pub hostname: String,
+
+    /// IP address family which should be used to connect to the
+    /// [`PubNub API`].
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    ip_address_family: IpAddressFamily,
+
+    /// Timeout (in seconds) for connection establishment.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    connect_timeout: Option<u64>,
+
+    /// User-provided factory of [`reqwest`] client builders.
+    ///
+    /// [`reqwest`]: https://docs.rs/reqwest
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    client_builder: Option<ClientBuilderFactory>,
+}
+
+/// IP address family.
+///
+/// Family of IP addresses which should be used by transport to connect to the
+/// [`PubNub API`]. Constraining connection to the single family may help on
+/// hosts with misconfigured dual-stack network, where attempts to connect
+/// using an unreachable family hang until the OS connection timeout.
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum IpAddressFamily {
+    /// Any address family.
+    ///
+    /// Resolved addresses of both families are used ("happy eyeballs").
+    #[default]
+    Any,
+
+    /// IPv4 addresses should be tried first.
+    ///
+    /// IPv6 addresses are used if connection can't be established using
+    /// IPv4.
+    PreferIPv4,
+
+    /// IPv6 addresses should be tried first.
+    ///
+    /// IPv4 addresses are used if connection can't be established using
+    /// IPv6.
+    PreferIPv6,
+
+    /// Only IPv4 addresses should be used.
+    IPv4,
+
+    /// Only IPv6 addresses should be used.
+    IPv6,
+}
+
+impl IpAddressFamily {
+    /// Unspecified local address of the family.
+    ///
+    /// Binding the local socket to the unspecified address limits the remote
+    /// addresses to the same family.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    fn local_address(&self) -> Option<IpAddr> {
+        match self {
+            Self::IPv4 => Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+            Self::IPv6 => Some(IpAddr::V6(Ipv6Addr::UNSPECIFIED)),
+            _ => None,
+        }
+    }
+
+    /// Configure [`reqwest`] client `builder` to use address family.
+    ///
+    /// Builder left untouched for [`IpAddressFamily::Any`], so local address
+    /// and resolver provided by user will be used.
+    ///
+    /// [`reqwest`]: https://docs.rs/reqwest
+    #[cfg(not(target_arch = "wasm32"))]
+    fn apply(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        match self {
+            Self::Any => builder,
+            Self::PreferIPv4 | Self::PreferIPv6 => {
+                builder.dns_resolver(Arc::new(PreferredFamilyResolver { family: *self }))
+            }
+            Self::IPv4 | Self::IPv6 => builder.local_address(self.local_address()),
+        }
+    }
+}
+
+/// DNS resolver which puts addresses of preferred family first.
+///
+/// Connector tries addresses of the first family from the list and falls back
+/// to addresses of another family if connection can't be established.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+struct PreferredFamilyResolver {
+    family: IpAddressFamily,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl reqwest::dns::Resolve for PreferredFamilyResolver {
+    fn resolve(&self, name: hyper::client::connect::dns::Name) -> reqwest::dns::Resolving {
+        let mut resolver = hyper::client::connect::dns::GaiResolver::new();
+        let prefer_ipv4 = self.family == IpAddressFamily::PreferIPv4;
+
+        Box::pin(async move {
+            let mut addresses: Vec<SocketAddr> = hyper::service::Service::call(&mut resolver, name)
+                .await?
+                .collect();
+            addresses.sort_by_key(|address| address.is_ipv4() != prefer_ipv4);
+
+            Ok(Box::new(addresses.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Factory of [`reqwest`] client builders.
+///
+/// Transport creates new client each time when connection configuration
+/// changes and [`reqwest::ClientBuilder`] can't be cloned, so user provides
+/// function which creates pre-configured builder instead.
+///
+/// [`reqwest`]: https://docs.rs/reqwest
+#[derive(Clone)]
+struct ClientBuilderFactory(Arc<dyn Fn() -> reqwest::ClientBuilder + Send + Sync>);
+
+impl ClientBuilderFactory {
+    /// Create client builder using user-provided factory (if set).
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    fn builder(factory: &Option<Self>) -> reqwest::ClientBuilder {
+        factory
+            .as_ref()
+            .map(|factory| (factory.0)())
+            .unwrap_or_default()
+    }
+}
+
+impl Debug for ClientBuilderFactory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("ClientBuilderFactory")
+    }
 }
 
-#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
-#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 impl Transport for TransportReqwest {
     async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
         let request_url = prepare_url(&self.hostname, &request.path, &request.query_parameters);
@@ -83,26 +228,29 @@ impl Transport for TransportReqwest {
         );
 
         let headers = prepare_headers(&request.headers)?;
-        #[cfg(feature = "std")]
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
         let timeout = request.timeout;
 
-        #[cfg(feature = "std")]
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
         let mut builder = match request.method {
             TransportMethod::Get => self.prepare_get_method(request, request_url),
             TransportMethod::Post => self.prepare_post_method(request, request_url),
             TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+            TransportMethod::Patch => self.prepare_patch_method(request, request_url),
         }?;
 
-        #[cfg(feature = "std")]
+        // Browser `fetch` doesn't support request timeout.
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
         if timeout.gt(&0) {
             builder = builder.timeout(core::time::Duration::from_secs(timeout));
         }
 
-        #[cfg(not(feature = "std"))]
+        #[cfg(any(not(feature = "std"), target_arch = "wasm32"))]
         let builder = match request.method {
             TransportMethod::Get => self.prepare_get_method(request, request_url),
             TransportMethod::Post => self.prepare_post_method(request, request_url),
             TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+            TransportMethod::Patch => self.prepare_patch_method(request, request_url),
         }?;
 
         let result = builder
@@ -110,7 +258,7 @@ impl Transport for TransportReqwest {
             .send()
             .await
             .map_err(|e| PubNubError::Transport {
-                details: e.to_string(),
+                details: error_details!(e),
                 response: None,
             })?;
 
@@ -120,7 +268,7 @@ impl Transport for TransportReqwest {
             .bytes()
             .await
             .map_err(|e| PubNubError::Transport {
-                details: e.to_string(),
+                details: error_details!(e),
                 response: Some(Box::new(TransportResponse {
                     status: status.into(),
                     headers: extract_headers(&headers),
@@ -136,7 +284,22 @@ impl Default for TransportReqwest {
         Self {
             reqwest_client: reqwest::Client::default(),
             hostname: PUBNUB_DEFAULT_BASE_URL.into(),
+            ip_address_family: Default::default(),
+            connect_timeout: None,
+            client_builder: None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&PubNubConfig> for TransportReqwest {
+    /// Create transport which uses `origin` from client configuration.
+    fn from(config: &PubNubConfig) -> Self {
+        let mut transport = Self::default();
+        if let Some(origin) = &config.transport.origin {
+            transport.set_hostname(origin);
         }
+        transport
     }
 }
 
@@ -172,6 +335,84 @@ impl TransportReqwest {
         self.hostname = hostname.into();
     }
 
+    /// Set IP address family which should be used for connection.
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::transport::{IpAddressFamily, TransportReqwest};
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let mut transport = TransportReqwest::new();
+    /// transport.set_ip_address_family(IpAddressFamily::IPv4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_ip_address_family(&mut self, family: IpAddressFamily) -> Result<(), PubNubError> {
+        self.ip_address_family = family;
+        self.rebuild_client()
+    }
+
+    /// Set timeout (in seconds) for connection establishment.
+    ///
+    /// Connection attempt will be cancelled if it won't be established within
+    /// specified time.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_connect_timeout(&mut self, timeout: u64) -> Result<(), PubNubError> {
+        self.connect_timeout = Some(timeout);
+        self.rebuild_client()
+    }
+
+    /// Set factory of pre-configured [`reqwest`] client builders.
+    ///
+    /// Transport builds [`reqwest`] client from the provided builder, so its
+    /// configuration (proxy, TLS and others) is kept when IP address family
+    /// or connection timeout applied on top of it.
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::transport::{IpAddressFamily, TransportReqwest};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let proxy = reqwest::Proxy::all("http://proxy.example.com:3128")?;
+    /// let mut transport = TransportReqwest::new();
+    /// transport.set_client_builder(move || reqwest::Client::builder().proxy(proxy.clone()))?;
+    /// transport.set_ip_address_family(IpAddressFamily::PreferIPv4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`reqwest`]: https://docs.rs/reqwest
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_client_builder<F>(&mut self, factory: F) -> Result<(), PubNubError>
+    where
+        F: Fn() -> reqwest::ClientBuilder + Send + Sync + 'static,
+    {
+        self.client_builder = Some(ClientBuilderFactory(Arc::new(factory)));
+        self.rebuild_client()
+    }
+
+    /// Create [`reqwest`] client with current connection configuration.
+    ///
+    /// [`reqwest`]: https://docs.rs/reqwest
+    #[cfg(not(target_arch = "wasm32"))]
+    fn rebuild_client(&mut self) -> Result<(), PubNubError> {
+        let mut builder = self
+            .ip_address_family
+            .apply(ClientBuilderFactory::builder(&self.client_builder));
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(core::time::Duration::from_secs(timeout));
+        }
+
+        self.reqwest_client = builder.build().map_err(|e| PubNubError::Transport {
+            details: error_details!(e),
+            response: None,
+        })?;
+
+        Ok(())
+    }
+
     fn prepare_get_method(
         &self,
         _request: TransportRequest,
@@ -188,7 +429,7 @@ impl TransportReqwest {
         request
             .body
             .ok_or(PubNubError::Transport {
-                details: "Body should not be empty for POST".into(),
+                details: error_details!("Body should not be empty for POST"),
                 response: None,
             })
             .map(|vec_bytes| self.reqwest_client.post(url).body(vec_bytes))
@@ -201,6 +442,19 @@ impl TransportReqwest {
     ) -> Result<reqwest::RequestBuilder, PubNubError> {
         Ok(self.reqwest_client.delete(url))
     }
+
+    fn prepare_patch_method(
+        &self,
+        request: TransportRequest,
+        url: String,
+    ) -> Result<reqwest::RequestBuilder, PubNubError> {
+        let builder = self.reqwest_client.patch(url);
+        let builder = match request.body {
+            Some(body) => builder.body(body),
+            None => builder,
+        };
+        Ok(builder)
+    }
 }
 
 fn prepare_headers(request_headers: &HashMap<String, String>) -> Result<HeaderMap, PubNubError> {
@@ -209,12 +463,12 @@ fn prepare_headers(request_headers: &HashMap<String, String>) -> Result<HeaderMa
         .map(|(k, v)| -> Result<(HeaderName, HeaderValue), PubNubError> {
             let name =
                 TryFrom::try_from(k).map_err(|err: InvalidHeaderName| PubNubError::Transport {
-                    details: err.to_string(),
+                    details: error_details!(err),
                     response: None,
                 })?;
             let value: HeaderValue =
                 TryFrom::try_from(v).map_err(|err: InvalidHeaderValue| PubNubError::Transport {
-                    details: err.to_string(),
+                    details: error_details!(err),
                     response: None,
                 })?;
             Ok((name, value))
@@ -254,7 +508,7 @@ fn create_result(
 ) -> Result<TransportResponse, PubNubError> {
     Ok(TransportResponse {
         status: status.as_u16(),
-        body: (!body.is_empty()).then(|| body.to_vec()),
+        body: (!body.is_empty()).then_some(body),
         headers: extract_headers(headers),
     })
 }
@@ -341,28 +595,31 @@ pub mod blocking {
     //! [`pubnub`]: ../index.html
     //! [`reqwest` feature]: ../index.html#features
 
-    #[cfg(any(
-        all(not(feature = "subscribe"), not(feature = "presence")),
-        not(feature = "std")
-    ))]
-    use crate::dx::pubnub_client::PubNubClientDeserializerBuilder;
-
     #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
-    use crate::dx::pubnub_client::PubNubClientRuntimeBuilder;
-
+    use crate::core::runtime::RuntimeSupport;
+    use crate::dx::pubnub_client::{PubNubClientDeserializerBuilder, PubNubClientInstance};
+    use crate::lib::log::{self, info};
+    #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+    use crate::providers::futures_blocking::RuntimeBlocking;
+    #[cfg(all(
+        any(feature = "subscribe", feature = "presence"),
+        feature = "std",
+        feature = "tokio"
+    ))]
+    use crate::providers::futures_tokio::RuntimeTokio;
+    use crate::transport::middleware::PubNubMiddleware;
     use crate::{
         core::{
             transport::PUBNUB_DEFAULT_BASE_URL, PubNubError, TransportMethod, TransportRequest,
             TransportResponse,
         },
-        lib::alloc::{
-            boxed::Box,
-            string::{String, ToString},
+        lib::alloc::{boxed::Box, string::String, sync::Arc},
+        transport::reqwest::{
+            create_result, extract_headers, prepare_headers, prepare_url, ClientBuilderFactory,
+            IpAddressFamily,
         },
-        transport::reqwest::{create_result, extract_headers, prepare_headers, prepare_url},
         PubNubClientBuilder,
     };
-    use log::info;
 
     /// This struct is used to send requests to the [`PubNub API`] using the
     /// [`reqwest`] crate. It is used as the transport type for the
@@ -391,6 +648,20 @@ pub mod blocking {
         /// };
         /// ```
         pub hostname: String,
+
+        /// IP address family which should be used to connect to the
+        /// [`PubNub API`].
+        ///
+        /// [`PubNub API`]: https://www.pubnub.com/docs
+        ip_address_family: IpAddressFamily,
+
+        /// Timeout (in seconds) for connection establishment.
+        connect_timeout: Option<u64>,
+
+        /// User-provided factory of [`reqwest`] client builders.
+        ///
+        /// [`reqwest`]: https://docs.rs/reqwest
+        client_builder: Option<ClientBuilderFactory>,
     }
 
     impl crate::core::blocking::Transport for TransportReqwest {
@@ -409,11 +680,12 @@ pub mod blocking {
                 TransportMethod::Get => self.prepare_get_method(request, request_url),
                 TransportMethod::Post => self.prepare_post_method(request, request_url),
                 TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+                TransportMethod::Patch => self.prepare_patch_method(request, request_url),
             }?;
 
             #[cfg(feature = "std")]
             if timeout.gt(&0) {
-                builder = builder.timeout(core::time::Duration::from_micros(timeout))
+                builder = builder.timeout(core::time::Duration::from_secs(timeout));
             }
 
             #[cfg(not(feature = "std"))]
@@ -421,13 +693,14 @@ pub mod blocking {
                 TransportMethod::Get => self.prepare_get_method(request, request_url),
                 TransportMethod::Post => self.prepare_post_method(request, request_url),
                 TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+                TransportMethod::Patch => self.prepare_patch_method(request, request_url),
             }?;
 
             let result = builder
                 .headers(headers)
                 .send()
                 .map_err(|e| PubNubError::Transport {
-                    details: e.to_string(),
+                    details: error_details!(e),
                     response: None,
                 })?;
 
@@ -436,7 +709,7 @@ pub mod blocking {
             result
                 .bytes()
                 .map_err(|e| PubNubError::Transport {
-                    details: e.to_string(),
+                    details: error_details!(e),
                     response: Some(Box::new(TransportResponse {
                         status: status.into(),
                         headers: extract_headers(&headers),
@@ -452,6 +725,9 @@ pub mod blocking {
             Self {
                 reqwest_client: reqwest::blocking::Client::default(),
                 hostname: PUBNUB_DEFAULT_BASE_URL.into(),
+                ip_address_family: Default::default(),
+                connect_timeout: None,
+                client_builder: None,
             }
         }
     }
@@ -481,6 +757,72 @@ pub mod blocking {
             Self::default()
         }
 
+        /// Set IP address family which should be used for connection.
+        ///
+        /// # Example
+        /// ```
+        /// use pubnub::transport::{reqwest::blocking::TransportReqwest, IpAddressFamily};
+        ///
+        /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+        /// let mut transport = TransportReqwest::new();
+        /// transport.set_ip_address_family(IpAddressFamily::IPv6)?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub fn set_ip_address_family(
+            &mut self,
+            family: IpAddressFamily,
+        ) -> Result<(), PubNubError> {
+            self.ip_address_family = family;
+            self.rebuild_client()
+        }
+
+        /// Set timeout (in seconds) for connection establishment.
+        ///
+        /// Connection attempt will be cancelled if it won't be established
+        /// within specified time.
+        pub fn set_connect_timeout(&mut self, timeout: u64) -> Result<(), PubNubError> {
+            self.connect_timeout = Some(timeout);
+            self.rebuild_client()
+        }
+
+        /// Set factory of pre-configured [`reqwest`] client builders.
+        ///
+        /// Blocking client is created from the asynchronous builder, so the
+        /// same factory can be used for both transports. See
+        /// [`super::TransportReqwest::set_client_builder`] for more
+        /// information.
+        ///
+        /// [`reqwest`]: https://docs.rs/reqwest
+        pub fn set_client_builder<F>(&mut self, factory: F) -> Result<(), PubNubError>
+        where
+            F: Fn() -> reqwest::ClientBuilder + Send + Sync + 'static,
+        {
+            self.client_builder = Some(ClientBuilderFactory(Arc::new(factory)));
+            self.rebuild_client()
+        }
+
+        /// Create [`reqwest`] client with current connection configuration.
+        ///
+        /// [`reqwest`]: https://docs.rs/reqwest
+        fn rebuild_client(&mut self) -> Result<(), PubNubError> {
+            let mut builder = reqwest::blocking::ClientBuilder::from(
+                self.ip_address_family
+                    .apply(ClientBuilderFactory::builder(&self.client_builder)),
+            );
+
+            if let Some(timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(core::time::Duration::from_secs(timeout));
+            }
+
+            self.reqwest_client = builder.build().map_err(|e| PubNubError::Transport {
+                details: error_details!(e),
+                response: None,
+            })?;
+
+            Ok(())
+        }
+
         fn prepare_get_method(
             &self,
             _request: TransportRequest,
@@ -510,19 +852,75 @@ pub mod blocking {
         ) -> Result<reqwest::blocking::RequestBuilder, PubNubError> {
             Ok(self.reqwest_client.delete(request_url))
         }
+
+        fn prepare_patch_method(
+            &self,
+            request: TransportRequest,
+            request_url: String,
+        ) -> Result<reqwest::blocking::RequestBuilder, PubNubError> {
+            let builder = self.reqwest_client.patch(request_url);
+            let builder = match request.body {
+                Some(body) => builder.body(body),
+                None => builder,
+            };
+            Ok(builder)
+        }
     }
 
-    impl PubNubClientBuilder {
-        /// Creates a new [`PubNubClientBuilder`] with the default
-        /// [`TransportReqwest`] transport. The default transport uses
-        /// the [`reqwest`] crate to send requests to the [`PubNub API`]. The default hostname is `https://ps.pndsn.com`.
-        /// The default [`reqwest`] client is created using
-        /// [`reqwest::Client::default()`].
+    impl From<&super::TransportReqwest> for TransportReqwest {
+        /// Create blocking transport with `transport` connection configuration.
+        fn from(transport: &super::TransportReqwest) -> Self {
+            let mut blocking = Self {
+                hostname: transport.hostname.clone(),
+                ip_address_family: transport.ip_address_family,
+                connect_timeout: transport.connect_timeout,
+                client_builder: transport.client_builder.clone(),
+                ..Default::default()
+            };
+            if let Err(error) = blocking.rebuild_client() {
+                log::warn!("Unable to apply connection configuration: {error}");
+            }
+            blocking
+        }
+    }
+
+    impl From<&TransportReqwest> for super::TransportReqwest {
+        /// Create asynchronous transport with `transport` connection
+        /// configuration.
+        fn from(transport: &TransportReqwest) -> Self {
+            let mut non_blocking = Self {
+                hostname: transport.hostname.clone(),
+                ip_address_family: transport.ip_address_family,
+                connect_timeout: transport.connect_timeout,
+                client_builder: transport.client_builder.clone(),
+                ..Default::default()
+            };
+            if let Err(error) = non_blocking.rebuild_client() {
+                log::warn!("Unable to apply connection configuration: {error}");
+            }
+            non_blocking
+        }
+    }
+
+    impl<D> PubNubClientInstance<PubNubMiddleware<super::TransportReqwest>, D> {
+        /// Create blocking client which shares configuration and state with
+        /// this client.
         ///
-        /// # Examples
-        /// ```
-        /// use pubnub::{PubNubClientBuilder, Keyset};
+        /// Both clients use the same keys, `user_id`, authorization key and
+        /// token, cryptor and middleware state (request hooks, circuit
+        /// breaker, retry configuration), so there is no need to maintain two
+        /// configurations in applications which use both `async` and blocking
+        /// API. Token set with [`set_token`] on one client is used by another.
+        ///
+        /// Blocking client has its own [`reqwest`] blocking client (with the
+        /// same origin and connection configuration) and doesn't share
+        /// subscription and presence event engines.
         ///
+        /// # Example
+        /// ```no_run
+        /// use pubnub::{Keyset, PubNubClientBuilder};
+        ///
+        /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
         /// let client = PubNubClientBuilder::with_reqwest_transport()
         ///     .with_keyset(Keyset {
         ///         subscribe_key: "sub-c-abc123",
@@ -530,30 +928,99 @@ pub mod blocking {
         ///         secret_key: None,
         ///     })
         ///     .with_user_id("user-123")
-        ///     .build();
+        ///     .build()?;
+        ///
+        /// let blocking_client = client.to_blocking();
+        /// client.set_token("access-token");
+        /// assert_eq!(blocking_client.get_token(), Some("access-token".into()));
+        /// # Ok(())
+        /// # }
         /// ```
         ///
-        /// [`TransportReqwest`]: ./struct.TransportReqwest.html
+        /// [`set_token`]: crate::dx::pubnub_client::PubNubClientInstance::set_token
         /// [`reqwest`]: https://docs.rs/reqwest
-        /// [`PubNub API`]: https://www.pubnub.com/docs
-        #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
-        pub fn with_reqwest_blocking_transport() -> PubNubClientRuntimeBuilder<TransportReqwest> {
-            PubNubClientRuntimeBuilder {
-                transport: TransportReqwest::new(),
-            }
+        pub fn to_blocking(&self) -> PubNubClientInstance<PubNubMiddleware<TransportReqwest>, D> {
+            self.with_shared_state(
+                TransportReqwest::from(&self.transport.transport),
+                #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+                RuntimeSupport::new(Arc::new(RuntimeBlocking)),
+                #[cfg(all(
+                    not(feature = "subscribe"),
+                    not(feature = "presence"),
+                    feature = "std"
+                ))]
+                self.runtime.clone(),
+            )
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    impl<D> PubNubClientInstance<PubNubMiddleware<TransportReqwest>, D> {
+        /// Create `async` client which shares configuration and state with
+        /// this blocking client.
+        ///
+        /// Both clients use the same keys, `user_id`, authorization key and
+        /// token, cryptor and middleware state (request hooks, circuit
+        /// breaker, retry configuration). Created client uses `tokio` runtime
+        /// environment.
+        ///
+        /// See [`to_blocking`] for more information.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use pubnub::{Keyset, PubNubClientBuilder};
+        ///
+        /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+        /// let blocking_client = PubNubClientBuilder::with_reqwest_blocking_transport()
+        ///     .with_keyset(Keyset {
+        ///         subscribe_key: "sub-c-abc123",
+        ///         publish_key: Some("pub-c-abc123"),
+        ///         secret_key: None,
+        ///     })
+        ///     .with_user_id("user-123")
+        ///     .build()?;
+        ///
+        /// let client = blocking_client.to_async();
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [`to_blocking`]: crate::dx::pubnub_client::PubNubClientInstance::to_blocking
+        pub fn to_async(
+            &self,
+        ) -> PubNubClientInstance<PubNubMiddleware<super::TransportReqwest>, D> {
+            self.with_shared_state(
+                super::TransportReqwest::from(&self.transport.transport),
+                #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
+                RuntimeSupport::new(Arc::new(RuntimeTokio)),
+                #[cfg(all(
+                    not(feature = "subscribe"),
+                    not(feature = "presence"),
+                    feature = "std"
+                ))]
+                self.runtime.clone(),
+            )
         }
+    }
 
-        /// Creates a new [`PubNubClientBuilder`] with the default
+    impl PubNubClientBuilder {
+        /// Creates a new [`PubNubClientBuilder`] with the default blocking
         /// [`TransportReqwest`] transport. The default transport uses
         /// the [`reqwest`] crate to send requests to the [`PubNub API`]. The default hostname is `https://ps.pndsn.com`.
         /// The default [`reqwest`] client is created using
-        /// [`reqwest::Client::default()`].
+        /// [`reqwest::blocking::Client::default()`].
+        ///
+        /// Builder steps are the same for all enabled `features` (runtime
+        /// environment doesn't need to be set). The SDK doesn't start
+        /// asynchronous runtime for the blocking client, but [`reqwest`]
+        /// blocking client manages its own internal background thread and
+        /// must not be used from within asynchronous context.
         ///
         /// # Examples
         /// ```
         /// use pubnub::{PubNubClientBuilder, Keyset};
         ///
-        /// let client = PubNubClientBuilder::with_reqwest_transport()
+        /// let client = PubNubClientBuilder::with_reqwest_blocking_transport()
         ///     .with_keyset(Keyset {
         ///         subscribe_key: "sub-c-abc123",
         ///         publish_key: Some("pub-c-abc123"),
@@ -566,15 +1033,9 @@ pub mod blocking {
         /// [`TransportReqwest`]: ./struct.TransportReqwest.html
         /// [`reqwest`]: https://docs.rs/reqwest
         /// [`PubNub API`]: https://www.pubnub.com/docs
-        #[cfg(any(
-            all(not(feature = "subscribe"), not(feature = "presence")),
-            not(feature = "std")
-        ))]
         pub fn with_reqwest_blocking_transport() -> PubNubClientDeserializerBuilder<TransportReqwest>
         {
-            PubNubClientDeserializerBuilder {
-                transport: TransportReqwest::new(),
-            }
+            PubNubClientBuilder::with_blocking_transport(TransportReqwest::new())
         }
     }
 
@@ -610,6 +1071,7 @@ pub mod blocking {
                 let transport = TransportReqwest {
                     reqwest_client: reqwest::blocking::Client::default(),
                     hostname: server.uri(),
+                    ..Default::default()
                 };
 
                 let request = TransportRequest {
@@ -648,6 +1110,7 @@ pub mod blocking {
                 let transport = TransportReqwest {
                     reqwest_client: reqwest::blocking::Client::default(),
                     hostname: server.uri(),
+                    ..Default::default()
                 };
 
                 let request = TransportRequest {
@@ -665,6 +1128,76 @@ pub mod blocking {
             .await
             .unwrap();
         }
+
+        #[tokio::test]
+        async fn return_err_when_request_timed_out() {
+            let path = "/time/0";
+
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path_macher(path))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_string("[16787176144828000]")
+                        .set_delay(std::time::Duration::from_secs(3)),
+                )
+                .mount(&server)
+                .await;
+
+            tokio::task::spawn_blocking(move || {
+                let transport = TransportReqwest {
+                    reqwest_client: reqwest::blocking::Client::default(),
+                    hostname: server.uri(),
+                    ..Default::default()
+                };
+
+                let request = TransportRequest {
+                    path: path.into(),
+                    method: TransportMethod::Get,
+                    timeout: 1,
+                    ..Default::default()
+                };
+
+                assert!(transport.send(request).is_err());
+            })
+            .await
+            .unwrap();
+        }
+
+        #[cfg(all(feature = "serde", feature = "tokio"))]
+        #[test]
+        fn share_state_between_async_and_blocking_clients() {
+            use crate::{Keyset, PubNubClientBuilder};
+
+            let mut transport = super::super::TransportReqwest::new();
+            transport.set_hostname("https://custom.origin.com");
+            let client = PubNubClientBuilder::with_transport(transport)
+                .with_keyset(Keyset {
+                    subscribe_key: "demo",
+                    publish_key: Some("demo"),
+                    secret_key: None,
+                })
+                .with_user_id("user")
+                .build()
+                .unwrap();
+
+            let blocking_client = client.to_blocking();
+            client.set_token("access-token");
+            blocking_client.set_auth_key("auth-key");
+
+            assert_eq!(
+                blocking_client.transport.transport.hostname,
+                "https://custom.origin.com"
+            );
+            assert_eq!(blocking_client.get_token(), Some("access-token".into()));
+            assert_eq!(client.get_auth_key(), Some("auth-key".into()));
+
+            let async_client = blocking_client.to_async();
+            async_client.set_token("new-token");
+
+            assert_eq!(client.get_token(), Some("new-token".into()));
+            assert_eq!(async_client.user_id(), "user");
+        }
     }
 }
 
@@ -697,6 +1230,7 @@ mod should {
         let transport = TransportReqwest {
             reqwest_client: reqwest::Client::default(),
             hostname: server.uri(),
+            ..Default::default()
         };
 
         let request = TransportRequest {
@@ -746,6 +1280,7 @@ mod should {
         let transport = TransportReqwest {
             reqwest_client: reqwest::Client::default(),
             hostname: server.uri(),
+            ..Default::default()
         };
 
         let request = TransportRequest {
@@ -780,6 +1315,7 @@ mod should {
         let transport = TransportReqwest {
             reqwest_client: reqwest::Client::default(),
             hostname: server.uri(),
+            ..Default::default()
         };
 
         let request = TransportRequest {
@@ -794,6 +1330,101 @@ mod should {
         assert_eq!(response.status, 200);
     }
 
+    #[tokio::test]
+    async fn send_using_selected_ip_address_family() {
+        let path = "/publish/sub_key/pub_key/0/chat/0";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("[1,\"Sent\",\"16787176144828000\"]"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut transport = TransportReqwest::new();
+        transport.set_hostname(server.uri());
+        transport
+            .set_ip_address_family(IpAddressFamily::IPv4)
+            .unwrap();
+        transport.set_connect_timeout(5).unwrap();
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.status, 200);
+    }
+
+    #[tokio::test]
+    async fn keep_user_client_builder_configuration() {
+        let path = "/time/0";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .and(header("x-custom-header", "custom-value"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[16787176144828000]"))
+            .mount(&server)
+            .await;
+
+        let mut transport = TransportReqwest::new();
+        transport.set_hostname(server.uri());
+        transport
+            .set_client_builder(|| {
+                let mut headers = HeaderMap::new();
+                headers.insert("x-custom-header", HeaderValue::from_static("custom-value"));
+                reqwest::Client::builder().default_headers(headers)
+            })
+            .unwrap();
+        transport
+            .set_ip_address_family(IpAddressFamily::PreferIPv4)
+            .unwrap();
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.status, 200);
+    }
+
+    #[tokio::test]
+    async fn put_preferred_family_addresses_first() {
+        use core::str::FromStr;
+        use hyper::client::connect::dns::Name;
+        use reqwest::dns::Resolve;
+
+        for (family, prefer_ipv4) in [
+            (IpAddressFamily::PreferIPv4, true),
+            (IpAddressFamily::PreferIPv6, false),
+        ] {
+            let resolver = PreferredFamilyResolver { family };
+            let addresses: Vec<SocketAddr> = resolver
+                .resolve(Name::from_str("localhost").unwrap())
+                .await
+                .unwrap()
+                .collect();
+
+            assert!(!addresses.is_empty());
+            let first_other = addresses
+                .iter()
+                .position(|address| address.is_ipv4() != prefer_ipv4)
+                .unwrap_or(addresses.len());
+            assert!(addresses[first_other..]
+                .iter()
+                .all(|address| address.is_ipv4() != prefer_ipv4));
+        }
+    }
+
     #[tokio::test]
     async fn return_err_on_post_empty_body() {
         let transport = TransportReqwest::default();
@@ -806,4 +1437,33 @@ mod should {
 
         assert!(transport.send(request).await.is_err());
     }
+
+    #[tokio::test]
+    async fn send_patch_without_body() {
+        let path = "/v2/objects/sub_key/uuids/Phoenix";
+
+        let server = MockServer::start().await;
+        Mock::given(method("PATCH"))
+            .and(path_macher(path))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{\"status\":200}"))
+            .mount(&server)
+            .await;
+
+        let transport = TransportReqwest {
+            reqwest_client: reqwest::Client::default(),
+            hostname: server.uri(),
+            ..Default::default()
+        };
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Patch,
+            body: None,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.status, 200);
+    }
 }
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 
 use pubnub::providers::deserialization_serde::DeserializerSerde;
 use pubnub::subscribe::{Subscription, SubscriptionSet};
+use pubnub::test::contract::ContractServer;
 use pubnub::transport::middleware::PubNubMiddleware;
 use pubnub::{
     core::{PubNubError, RequestRetryConfiguration},
@@ -198,16 +199,11 @@ impl PubNubWorld {
     }
 
     pub fn get_pubnub(&self, keyset: Keyset<String>) -> PubNubClient {
-        let transport = {
-            let mut transport = TransportReqwest::default();
-            transport.hostname = "http://localhost:8090".into();
-            transport
-        };
-
-        let mut builder = PubNubClientBuilder::with_transport(transport)
-            .with_keyset(keyset)
-            .with_user_id("test")
-            .with_suppress_leave_events(self.suppress_leave_events);
+        let mut builder =
+            PubNubClientBuilder::with_transport(ContractServer::default().transport())
+                .with_keyset(keyset)
+                .with_user_id("test")
+                .with_suppress_leave_events(self.suppress_leave_events);
 
         if let Some(retry_policy) = &self.retry_policy {
             builder = builder.with_retry_configuration(retry_policy.clone());
@@ -231,21 +227,13 @@ impl PubNubWorld {
 #[given("the demo keyset with event engine enabled")]
 #[given("the demo keyset with Presence EE enabled")]
 fn set_keyset(world: &mut PubNubWorld) {
-    world.keyset = Keyset {
-        subscribe_key: "demo".into(),
-        publish_key: Some("demo".into()),
-        secret_key: None,
-    }
+    world.keyset = ContractServer::demo_keyset()
 }
 
 #[given(regex = r"^a (.*) reconnection policy with ([0-9]+) retries")]
 fn set_with_retries(world: &mut PubNubWorld, retry_type: String, max_retry: u8) {
     if retry_type.eq("linear") {
-        world.retry_policy = Some(RequestRetryConfiguration::Linear {
-            max_retry,
-            delay: 0,
-            excluded_endpoints: None,
-        })
+        world.retry_policy = Some(RequestRetryConfiguration::linear(0, max_retry))
     }
 }
 
@@ -265,15 +253,7 @@ fn set_presence_options(
 
 #[given(regex = r"^I have a keyset with access manager enabled(.*)?")]
 fn i_have_keyset_with_access_manager_enabled(world: &mut PubNubWorld, info: String) {
-    world.keyset = Keyset {
-        subscribe_key: "demo".into(),
-        publish_key: Some("demo".into()),
-        secret_key: if info.contains("without") {
-            None
-        } else {
-            Some("demo".into())
-        },
-    }
+    world.keyset = ContractServer::access_manager_keyset(!info.contains("without"))
 }
 
 #[then("I receive an error response")]
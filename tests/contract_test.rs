@@ -1,4 +1,5 @@
 use cucumber::{writer, World, WriterExt};
+use pubnub::test::contract::{contract_script, ContractFilter, ContractServer};
 use std::fs::{create_dir_all, read_to_string, File, OpenOptions};
 use std::process;
 
@@ -10,80 +11,24 @@ mod publish;
 mod subscribe;
 use common::PubNubWorld;
 
-async fn init_server(script: String) -> Result<String, Box<dyn std::error::Error>> {
-    let url = format!("http://localhost:8090/init?__contract__script__={}", script);
-    let client = reqwest::Client::new();
-    let body = client.get(url).send().await?.text().await?;
-    Ok(body)
-}
-
-fn get_feature_set(tags: &[String]) -> String {
-    tags.iter()
-        .filter(|tag| tag.starts_with("featureSet"))
-        .map(|tag| tag.split('=').last().unwrap().to_string())
-        .collect::<String>()
-}
-
-fn feature_allows_beta(feature: &str) -> bool {
-    let features: Vec<&str> = vec![
+fn contract_filter() -> ContractFilter {
+    // If specific contract should be tested, it's name should be added with
+    // `with_contract`.
+    ContractFilter::new([
         "access",
         "publish",
         "eventEngine",
         "presenceEventEngine",
         "cryptoModule",
-    ];
-    features.contains(&feature)
-}
-
-fn feature_allows_skipped(feature: &str) -> bool {
-    let features: Vec<&str> = vec![];
-    features.contains(&feature)
-}
-
-fn feature_allows_contract_less(feature: &str) -> bool {
-    let features: Vec<&str> = vec!["access", "cryptoModule"];
-    features.contains(&feature)
-}
-
-fn is_ignored_feature_set_tag(feature: &str, tags: &[String]) -> bool {
-    let supported_features = [
+    ])
+    .with_beta([
         "access",
         "publish",
         "eventEngine",
         "presenceEventEngine",
         "cryptoModule",
-    ];
-    let mut ignored_tags = vec!["na=rust"];
-
-    if !feature_allows_beta(feature) {
-        ignored_tags.push("beta");
-    }
-
-    if !feature_allows_skipped(feature) {
-        ignored_tags.push("skip");
-    }
-
-    ignored_tags
-        .iter()
-        .any(|tag| tags.contains(&tag.to_string()))
-        || !supported_features.contains(&feature)
-}
-
-fn is_ignored_scenario_tag(feature: &str, tags: &[String]) -> bool {
-    // If specific contract should be tested, it's name should be added below.
-    let tested_contract = "";
-
-    tags.contains(&"na=rust".to_string())
-        || !feature_allows_beta(feature) && tags.iter().any(|tag| tag.starts_with("beta"))
-        || !feature_allows_skipped(feature) && tags.iter().any(|tag| tag.starts_with("skip"))
-        || (!feature_allows_contract_less(feature) || !tested_contract.is_empty())
-            && !tags.iter().any(|tag| {
-                if !tested_contract.is_empty() {
-                    tag == format!("contract={tested_contract}").as_str()
-                } else {
-                    tag.starts_with("contract=")
-                }
-            })
+    ])
+    .with_contract_less(["access", "cryptoModule"])
 }
 
 pub fn scenario_name(world: &mut PubNubWorld) -> String {
@@ -134,6 +79,7 @@ async fn main() {
         .unwrap();
     let _ = std::fs::create_dir_all("tests/reports");
     let file: File = File::create("tests/reports/report-required.xml").unwrap();
+    let filter = contract_filter();
     PubNubWorld::cucumber()
         .max_concurrent_scenarios(1) // sequential execution because tomato waits for a specific request at a time for which a
         // script is initialised.
@@ -142,17 +88,8 @@ async fn main() {
             world.scenario = Some(scenario.clone());
 
             futures::FutureExt::boxed(async move {
-                if scenario.tags.iter().any(|t| t.starts_with("contract=")) {
-                    let tag = scenario
-                        .tags
-                        .iter()
-                        .find(|&t| t.starts_with("contract="))
-                        .unwrap();
-                    let splitted_values: Vec<&str> = tag.split('=').collect();
-                    if !splitted_values[1].is_empty() {
-                        let script_name = splitted_values[1];
-                        init_server(script_name.to_string()).await.unwrap();
-                    }
+                if let Some(script) = contract_script(&scenario.tags) {
+                    ContractServer::default().init(script).await.unwrap();
                 }
             })
         })
@@ -169,9 +106,7 @@ async fn main() {
         .filter_run("tests/features", move |feature, _, scenario| {
             // Filter out features and scenario which doesn't have @featureSet
             // and @contract tags.
-            let current_feature = get_feature_set(&feature.tags);
-            !(is_ignored_feature_set_tag(&current_feature, &feature.tags)
-                || is_ignored_scenario_tag(&current_feature, &scenario.tags))
+            filter.should_run(&feature.tags, &scenario.tags)
         })
         .await;
 